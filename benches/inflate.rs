@@ -0,0 +1,237 @@
+use std::{
+    collections::HashMap,
+    fs,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use gimg::png::{
+    decode_png,
+    deflate::{
+        bitstream::WriteBitStream,
+        decode_zlib,
+        lzss::backreference::{
+            DISTANCE_CODE_TO_BASE_DISTANCE, DISTANCE_CODE_TO_EXTRA_BITS,
+            LENGTH_CODE_TO_BASE_LENGTH, LENGTH_CODE_TO_EXTRA_BITS,
+        },
+    },
+};
+
+const MOUNTAIN_PATH: &str = "files/mountain.png";
+const RUNS: usize = 10;
+const CL_ALPHABET: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+// the concatenated IDAT chunk data of a png, skipping the signature
+fn read_image_data(png: &[u8]) -> Vec<u8> {
+    let mut image_data = Vec::new();
+    let mut index = 8;
+
+    while index + 8 <= png.len() {
+        let length = u32::from_be_bytes(png[index..index + 4].try_into().unwrap()) as usize;
+        let chunk_type = &png[index + 4..index + 8];
+
+        if chunk_type == b"IDAT" {
+            image_data.extend_from_slice(&png[index + 8..index + 8 + length]);
+        }
+
+        // length, type, data and crc
+        index += 12 + length;
+    }
+
+    image_data
+}
+
+// the baseline: how the inflater decoded Huffman codes before it had lookup tables, reading one
+// bit at a time and looking the code so far up in a HashMap of WriteBitStream keys after every
+// bit. it trusts its input, which is fine for a file the real decoder checks too
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_index: usize,
+}
+
+impl BitReader<'_> {
+    fn read_bit(&mut self) -> u32 {
+        let bit = (self.bytes[self.bit_index >> 3] >> (self.bit_index & 0b111)) & 1;
+        self.bit_index += 1;
+        bit as u32
+    }
+
+    fn read_bits(&mut self, bit_count: usize) -> u32 {
+        (0..bit_count).fold(0, |value, bit| value | (self.read_bit() << bit))
+    }
+}
+
+fn code_table(lengths: &[u8]) -> HashMap<WriteBitStream, usize> {
+    let mut table = HashMap::new();
+    let mut code = 0;
+
+    for length in 1..=15 {
+        for (symbol, _) in lengths.iter().enumerate().filter(|(_, l)| **l == length) {
+            table.insert(WriteBitStream::from_u32_ltr(code, length), symbol);
+            code += 1;
+        }
+        code <<= 1;
+    }
+
+    table
+}
+
+fn read_code(reader: &mut BitReader, table: &HashMap<WriteBitStream, usize>) -> usize {
+    let mut code = WriteBitStream::new();
+
+    for _ in 1..=15 {
+        match reader.read_bit() {
+            0 => code.push_zero(),
+            _ => code.push_one(),
+        }
+
+        if let Some(symbol) = table.get(&code) {
+            return *symbol;
+        }
+    }
+
+    panic!("Invalid Huffman code");
+}
+
+fn read_dynamic_tables(reader: &mut BitReader) -> [HashMap<WriteBitStream, usize>; 2] {
+    let literal_length_count = reader.read_bits(5) as usize + 257;
+    let distance_count = reader.read_bits(5) as usize + 1;
+    let code_length_count = reader.read_bits(4) as usize + 4;
+
+    let mut code_length_lengths = [0; 19];
+    for symbol in &CL_ALPHABET[..code_length_count] {
+        code_length_lengths[*symbol] = reader.read_bits(3) as u8;
+    }
+    let code_length_table = code_table(&code_length_lengths);
+
+    let mut lengths: Vec<u8> = Vec::new();
+    while lengths.len() < literal_length_count + distance_count {
+        match read_code(reader, &code_length_table) {
+            16 => {
+                let previous = *lengths.last().unwrap();
+                let count = 3 + reader.read_bits(2) as usize;
+                lengths.extend(std::iter::repeat_n(previous, count));
+            }
+            17 => lengths.extend(std::iter::repeat_n(0, 3 + reader.read_bits(3) as usize)),
+            18 => lengths.extend(std::iter::repeat_n(0, 11 + reader.read_bits(7) as usize)),
+            length => lengths.push(length as u8),
+        }
+    }
+
+    let (literal_length_lengths, distance_lengths) = lengths.split_at(literal_length_count);
+    [
+        code_table(literal_length_lengths),
+        code_table(distance_lengths),
+    ]
+}
+
+fn fixed_tables() -> [HashMap<WriteBitStream, usize>; 2] {
+    let literal_length_lengths: Vec<u8> = (0..288)
+        .map(|symbol| match symbol {
+            0..144 => 8,
+            144..256 => 9,
+            256..280 => 7,
+            _ => 8,
+        })
+        .collect();
+
+    [code_table(&literal_length_lengths), code_table(&[5; 30])]
+}
+
+fn bit_by_bit_decode_zlib(zlib: &[u8]) -> Vec<u8> {
+    // skips the two byte zlib header, the checksum at the end is never read
+    let mut reader = BitReader {
+        bytes: &zlib[2..],
+        bit_index: 0,
+    };
+    let mut output = Vec::new();
+
+    loop {
+        let is_last = reader.read_bit() == 1;
+
+        match reader.read_bits(2) {
+            0 => {
+                let start = reader.bit_index.div_ceil(8);
+                let length = u16::from_le_bytes([reader.bytes[start], reader.bytes[start + 1]]);
+                let data_start = start + 4;
+                output.extend_from_slice(&reader.bytes[data_start..data_start + length as usize]);
+                reader.bit_index = (data_start + length as usize) * 8;
+            }
+            block_type => {
+                let [literal_length_table, distance_table] = match block_type {
+                    1 => fixed_tables(),
+                    _ => read_dynamic_tables(&mut reader),
+                };
+
+                loop {
+                    let symbol = read_code(&mut reader, &literal_length_table);
+
+                    match symbol {
+                        0..256 => output.push(symbol as u8),
+                        256 => break,
+                        _ => {
+                            let length = LENGTH_CODE_TO_BASE_LENGTH[symbol] as usize
+                                + reader.read_bits(LENGTH_CODE_TO_EXTRA_BITS[symbol]) as usize;
+                            let distance_code = read_code(&mut reader, &distance_table);
+                            let distance = DISTANCE_CODE_TO_BASE_DISTANCE[distance_code] as usize
+                                + reader.read_bits(DISTANCE_CODE_TO_EXTRA_BITS[distance_code])
+                                    as usize;
+
+                            let start = output.len() - distance;
+                            for i in start..start + length {
+                                output.push(output[i]);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if is_last {
+            return output;
+        }
+    }
+}
+
+// runs f a few times and reports the fastest and the median run
+fn bench(name: &str, mut f: impl FnMut()) {
+    let mut durations: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    durations.sort();
+
+    println!(
+        "{name}: fastest {:?}, median {:?} over {RUNS} runs",
+        durations[0],
+        durations[RUNS / 2]
+    );
+}
+
+fn main() {
+    let png = fs::read(MOUNTAIN_PATH).expect("Failed to read files/mountain.png");
+    let image_data = read_image_data(&png);
+    assert_eq!(
+        bit_by_bit_decode_zlib(&image_data),
+        decode_zlib(&image_data).unwrap(),
+        "the baseline must decompress to the same bytes"
+    );
+
+    bench(
+        "inflate mountain.png image data, bit by bit baseline",
+        || {
+            black_box(bit_by_bit_decode_zlib(black_box(&image_data)));
+        },
+    );
+    bench("inflate mountain.png image data", || {
+        black_box(decode_zlib(black_box(&image_data)).unwrap());
+    });
+    bench("decode mountain.png", || {
+        black_box(decode_png(black_box(&png)).unwrap());
+    });
+}
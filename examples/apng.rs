@@ -0,0 +1,39 @@
+use std::fs;
+
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_apng_with_config, encode_apng, BlendOp, DisposeOp, PartialPngConfig,
+        PngDecodeConfig, PngFrame,
+    },
+};
+
+fn main() {
+    let background = vec![vec![Rgba::new(255, 255, 255, u8::MAX); 64]; 64];
+    let square = vec![vec![Rgba::new(200, 40, 40, u8::MAX); 16]; 16];
+
+    let mut frames = vec![PngFrame::new(background).delay(1, 10)];
+    frames.extend((0..4).map(|step| {
+        PngFrame::new(square.clone())
+            .offset(step * 16, step * 16)
+            .delay(1, 10)
+            .blend_op(BlendOp::Over)
+            .dispose_op(DisposeOp::Previous)
+    }));
+
+    let png_bytes = encode_apng(frames, 0, PartialPngConfig::new()).unwrap();
+    println!("Size {}", png_bytes.len());
+    fs::write("files/animation.png", &png_bytes).expect("Failed to write my png");
+
+    let decoded_apng =
+        decode_apng_with_config(&png_bytes, PngDecodeConfig::new().composite_frames(true)).unwrap();
+
+    for (idx, frame) in decoded_apng.frames.iter().enumerate() {
+        println!(
+            "Frame {idx}: {}x{} shown for {}s",
+            frame.width(),
+            frame.height(),
+            frame.delay_seconds()
+        );
+    }
+}
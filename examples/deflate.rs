@@ -0,0 +1,26 @@
+use std::time::Instant;
+
+use gimg::png::{
+    deflate::{decode_deflate, DeflateEncoder},
+    CompressionLevel,
+};
+
+fn main() {
+    // let input = &fs::read("files/text.txt").unwrap();
+    let input = b"helhelaaaaaaaaaaa";
+
+    // compress(input, CompressionLevel::FAST);
+    let cmp = compress(input, CompressionLevel::BEST);
+    let dec = String::from_utf8(decode_deflate(&cmp).unwrap()).unwrap();
+    println!("Decoded: {dec}");
+}
+
+fn compress(input: &[u8], compression_level: CompressionLevel) -> Vec<u8> {
+    let start = Instant::now();
+    let mut enc = DeflateEncoder::new(compression_level);
+    enc.write_bytes(input);
+    let bytes = enc.finish().flush_to_bytes();
+    let end = Instant::now();
+    println!("{:?}, {} {:?}", compression_level, bytes.len(), end - start);
+    bytes
+}
@@ -0,0 +1,45 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter},
+    path::Path,
+    time::UNIX_EPOCH,
+};
+
+use gimg::png::{
+    deflate::{GzipHeader, GzipReader, GzipWriter},
+    CompressionLevel,
+};
+
+// gzip-compresses a file next to it, or decompresses it when it already ends in .gz
+fn main() {
+    let path = env::args().nth(1).expect("Usage: gzip <file>");
+
+    match path.strip_suffix(".gz") {
+        Some(output_path) => {
+            let mut reader = GzipReader::new(BufReader::new(File::open(&path).unwrap()));
+            let mut output = BufWriter::new(File::create(output_path).unwrap());
+            io::copy(&mut reader, &mut output).expect("Failed to decompress the file");
+
+            for header in reader.headers() {
+                println!("{header:?}");
+            }
+        }
+        None => {
+            let modification_time = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .map(|time| time.duration_since(UNIX_EPOCH).unwrap().as_secs() as u32)
+                .unwrap_or(0);
+            let file_name = Path::new(&path).file_name().unwrap().to_string_lossy();
+            let header = GzipHeader::new()
+                .file_name(file_name)
+                .modification_time(modification_time);
+
+            let output = BufWriter::new(File::create(format!("{path}.gz")).unwrap());
+            let mut writer = GzipWriter::new(output, header, CompressionLevel::BEST).unwrap();
+            io::copy(&mut BufReader::new(File::open(&path).unwrap()), &mut writer)
+                .expect("Failed to compress the file");
+            writer.finish().expect("Failed to write the gzip file");
+        }
+    }
+}
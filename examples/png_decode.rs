@@ -0,0 +1,19 @@
+use std::fs;
+
+use gimg::{
+    colors::YCbCr,
+    image::{Image, Resolution},
+    png::decode_png,
+};
+
+fn main() {
+    let png_file = fs::read("files/mountain.png").unwrap();
+    let decoded_png = decode_png(&png_file).unwrap();
+    let ycbcr_pixels: Vec<Vec<YCbCr>> = decoded_png
+        .into_iter()
+        .map(|row| row.into_iter().map(YCbCr::from).collect())
+        .collect();
+
+    let img = Image::new(Resolution::from_vec(&ycbcr_pixels), ycbcr_pixels);
+    println!("{:?}", img.resolution);
+}
@@ -0,0 +1,27 @@
+use std::fs;
+
+use gimg::{
+    colors::Rgba,
+    png::{encode_png, CompressionLevel, PartialPngConfig},
+    ppm::decode_ppm,
+};
+
+fn main() {
+    // let png_file = fs::read("files/mountain.png").unwrap();
+    // let rgba_pixels = decode_png(&png_file).unwrap();
+    let ppm_file = fs::read("files/small.ppm").unwrap();
+    let rgba_pixels = decode_ppm(&ppm_file)
+        .unwrap()
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|p| Rgba::new(p.r, p.g, p.b, u8::MAX))
+                .collect()
+        })
+        .collect();
+
+    let config = PartialPngConfig::new().compression_level(CompressionLevel::BEST);
+    let png_bytes = encode_png(rgba_pixels, config).unwrap();
+    println!("Size {}", png_bytes.len());
+    fs::write("files/mymountain.png", png_bytes).expect("Failed to write my png");
+}
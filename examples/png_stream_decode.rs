@@ -0,0 +1,30 @@
+use std::fs::File;
+
+use gimg::{
+    colors::Rgba16,
+    png::{read_png_rows, PngDecodeConfig},
+};
+
+fn main() {
+    let png_file = File::open("files/mountain.png").unwrap();
+    let mut rows = read_png_rows(png_file, PngDecodeConfig::new());
+    let mut preview: Vec<Vec<Rgba16>> = Vec::new();
+
+    while let Some(row) = rows.next() {
+        let row = row.unwrap();
+
+        if preview.is_empty() {
+            let (width, height) = rows.decoder().dimensions().unwrap();
+            preview = vec![vec![Rgba16::new(0, 0, 0, 0); width as usize]; height as usize];
+        }
+
+        row.draw_preview(&mut preview);
+
+        if row.y % 100 == 0 {
+            println!("Pass {} row {} decoded", row.pass, row.y);
+        }
+    }
+
+    let decoded_png = rows.finish().unwrap();
+    println!("{} text chunks", decoded_png.text.len());
+}
@@ -0,0 +1,22 @@
+use std::{fs::File, io::BufWriter};
+
+use gimg::{
+    colors::Rgba,
+    png::{ColorType, PartialPngConfig, PngStreamEncoder},
+};
+
+fn main() {
+    let (width, height) = (1920, 1080);
+    let png_file = BufWriter::new(File::create("files/mygradient.png").unwrap());
+    let config = PartialPngConfig::new().color_type(ColorType::Truecolor);
+    let mut encoder = PngStreamEncoder::new(png_file, width, height, config).unwrap();
+
+    for y in 0..height {
+        let row: Vec<Rgba> = (0..width)
+            .map(|x| Rgba::new((x * 255 / width) as u8, (y * 255 / height) as u8, 128, 255))
+            .collect();
+        encoder.write_row(&row).unwrap();
+    }
+
+    encoder.finish().unwrap();
+}
@@ -0,0 +1,11 @@
+use std::io::{self, BufWriter};
+
+use gimg::png::deflate::ZlibReader;
+
+// decompresses a zlib stream from stdin to stdout, only ever holding a window's worth of it
+fn main() {
+    let mut reader = ZlibReader::new(io::stdin().lock());
+    let mut stdout = BufWriter::new(io::stdout().lock());
+
+    io::copy(&mut reader, &mut stdout).expect("Failed to decompress stdin");
+}
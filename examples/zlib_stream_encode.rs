@@ -0,0 +1,12 @@
+use std::io::{self, BufWriter};
+
+use gimg::png::{deflate::ZlibWriter, CompressionLevel};
+
+// compresses stdin into a zlib stream on stdout without holding the whole input in memory
+fn main() {
+    let stdout = BufWriter::new(io::stdout().lock());
+    let mut writer = ZlibWriter::new(stdout, CompressionLevel::FAST);
+
+    io::copy(&mut io::stdin().lock(), &mut writer).expect("Failed to compress stdin");
+    writer.finish().expect("Failed to write the zlib stream");
+}
@@ -8,10 +8,6 @@ impl<'a> ByteReader<'a> {
         Self { bytes, offset: 0 }
     }
 
-    pub fn reset(&mut self) {
-        self.offset = 0;
-    }
-
     pub fn is_finished(&self) -> bool {
         self.number_of_bytes_left() == 0
     }
@@ -20,7 +16,7 @@ impl<'a> ByteReader<'a> {
         let bytes = &self.bytes[self.offset..];
         self.offset = self.bytes.len();
 
-        return bytes;
+        bytes
     }
 
     pub fn peek_bytes(&self, size: usize) -> Option<&'a [u8]> {
@@ -67,10 +63,10 @@ impl<'a> ByteReader<'a> {
         Some(u16::from_be_bytes(bytes.try_into().unwrap()))
     }
 
-    pub fn read_u32_be(&mut self) -> Option<u32> {
-        let bytes = self.read_bytes(4)?;
+    pub fn read_u16_le(&mut self) -> Option<u16> {
+        let bytes = self.read_bytes(2)?;
 
-        Some(u32::from_be_bytes(bytes.try_into().unwrap()))
+        Some(u16::from_le_bytes(bytes.try_into().unwrap()))
     }
 
     pub fn skip_whitespace(&mut self) {
@@ -0,0 +1,9 @@
+// latin1 maps each byte to the unicode code point with the same value
+pub fn bytes_to_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| *byte as char).collect()
+}
+
+// characters past U+00FF have no latin1 byte and keep only their lowest 8 bits
+pub fn latin1_to_bytes(text: &str) -> Vec<u8> {
+    text.chars().map(|c| c as u32 as u8).collect()
+}
@@ -1 +1,2 @@
 pub mod byte_reader;
+pub mod latin1;
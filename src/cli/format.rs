@@ -1,25 +1,183 @@
-use crate::ppm::{is_ppm_by_extension, is_ppm_by_signature};
+use gimg::{
+    colors::{Rgb, Rgb16, Rgba16},
+    gif::{decode_gif, is_gif_by_extension, is_gif_by_signature},
+    png::{
+        decode_png16, encode_png16, is_png_by_extension, is_png_by_signature, optimize_png,
+        optimize_png16, PartialPngConfig,
+    },
+    ppm::{decode_ppm16, encode_ppm, encode_ppm16, is_ppm_by_extension, is_ppm_by_signature},
+    Error,
+};
 
-pub enum FileFormat {
-    Ppm,
+use super::{
+    options::{parse_png_options, EncoderOptions, OptionsParser},
+    ArgumentParseError,
+};
+
+type Decoder = fn(&[u8]) -> Result<Vec<Vec<Rgba16>>, Error>;
+type Encoder = fn(Vec<Vec<Rgba16>>, EncoderOptions) -> Result<Vec<u8>, String>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct FileFormat {
+    pub name: &'static str,
+    is_format_by_signature: fn(&[u8]) -> bool,
+    is_format_by_extension: fn(&str) -> bool,
+    decoder: Option<Decoder>,
+    encoder: Option<Encoder>,
+    options_parser: Option<OptionsParser>,
 }
 
 impl FileFormat {
     pub fn is_format_by_signature(&self, file: &[u8]) -> bool {
-        match self {
-            FileFormat::Ppm => is_ppm_by_signature(file),
-        }
+        (self.is_format_by_signature)(file)
     }
 
     pub fn is_format_by_extension(&self, filename: &str) -> bool {
-        match self {
-            FileFormat::Ppm => is_ppm_by_extension(filename),
+        (self.is_format_by_extension)(filename)
+    }
+
+    pub fn can_decode(&self) -> bool {
+        self.decoder.is_some()
+    }
+
+    pub fn can_encode(&self) -> bool {
+        self.encoder.is_some()
+    }
+
+    pub fn decode_file(&self, file: &[u8]) -> Result<Vec<Vec<Rgba16>>, String> {
+        match self.decoder {
+            Some(decoder) => decoder(file).map_err(|err| describe_error(&err)),
+            None => Err(format!("{} decoding is not supported", self.name)),
+        }
+    }
+
+    // true when the format is png, whose ancillary chunks an optimized output should keep
+    pub fn is_png(&self) -> bool {
+        self.name == PNG.name
+    }
+
+    pub fn encode_file(
+        &self,
+        pixels: Vec<Vec<Rgba16>>,
+        options: EncoderOptions,
+    ) -> Result<Vec<u8>, String> {
+        match self.encoder {
+            Some(encoder) => encoder(pixels, options),
+            None => Err(format!("{} encoding is not supported", self.name)),
+        }
+    }
+
+    pub fn parse_encoder_options(
+        &self,
+        options: &[(&str, &str)],
+    ) -> Result<EncoderOptions, ArgumentParseError> {
+        match (self.options_parser, options.first()) {
+            (Some(options_parser), _) => options_parser(options),
+            (None, Some((key, _value))) => Err(ArgumentParseError(format!(
+                "Unrecognized option {key}, {} output does not accept any options",
+                self.name
+            ))),
+            (None, None) => Ok(EncoderOptions::Default),
         }
     }
+}
+
+pub const PNG: FileFormat = FileFormat {
+    name: "PNG",
+    is_format_by_signature: is_png_by_signature,
+    is_format_by_extension: is_png_by_extension,
+    decoder: Some(decode_png_file),
+    encoder: Some(encode_png_file),
+    options_parser: Some(parse_png_options),
+};
+
+pub const GIF: FileFormat = FileFormat {
+    name: "GIF",
+    is_format_by_signature: is_gif_by_signature,
+    is_format_by_extension: is_gif_by_extension,
+    decoder: Some(decode_gif_file),
+    encoder: None,
+    options_parser: None,
+};
+
+pub const PPM: FileFormat = FileFormat {
+    name: "PPM",
+    is_format_by_signature: is_ppm_by_signature,
+    is_format_by_extension: is_ppm_by_extension,
+    decoder: Some(decode_ppm_file),
+    encoder: Some(encode_ppm_file),
+    options_parser: None,
+};
+
+pub const SUPPORTED_FORMATS: [FileFormat; 3] = [PNG, GIF, PPM];
+
+fn describe_error(err: &dyn std::error::Error) -> String {
+    let mut description = err.to_string();
+    let mut source = err.source();
+
+    while let Some(cause) = source {
+        description.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+
+    description
+}
+
+fn decode_png_file(file: &[u8]) -> Result<Vec<Vec<Rgba16>>, Error> {
+    Ok(decode_png16(file)?)
+}
+
+fn encode_png_file(pixels: Vec<Vec<Rgba16>>, options: EncoderOptions) -> Result<Vec<u8>, String> {
+    let config = match options {
+        EncoderOptions::Png(config) => *config,
+        EncoderOptions::OptimizedPng(config) => {
+            return optimize_png16(pixels, *config).map_err(|err| describe_error(&err))
+        }
+        EncoderOptions::Default => PartialPngConfig::new(),
+    };
+
+    encode_png16(pixels, config).map_err(|err| describe_error(&err))
+}
 
-    // pub fn decode_file(&self, file: &[u8]) -> Box<dyn PixelFormat> {
-    //     todo!()
-    // }
+// re-encodes png bytes as small as possible without going through pixels, so text, color space,
+// metadata and unknown chunks survive
+pub fn optimize_png_file(file: &[u8]) -> Result<Vec<u8>, String> {
+    optimize_png(file).map_err(|err| describe_error(&err))
 }
 
-pub const SUPPORTED_FORMATS: [FileFormat; 1] = [FileFormat::Ppm];
+fn decode_gif_file(file: &[u8]) -> Result<Vec<Vec<Rgba16>>, Error> {
+    let pixels = decode_gif(file)?;
+
+    Ok(pixels
+        .into_iter()
+        .map(|row| row.iter().map(Rgba16::from).collect())
+        .collect())
+}
+
+fn decode_ppm_file(file: &[u8]) -> Result<Vec<Vec<Rgba16>>, Error> {
+    let pixels = decode_ppm16(file)?;
+
+    Ok(pixels
+        .into_iter()
+        .map(|row| row.iter().map(Rgba16::from).collect())
+        .collect())
+}
+
+fn encode_ppm_file(pixels: Vec<Vec<Rgba16>>, _options: EncoderOptions) -> Result<Vec<u8>, String> {
+    let rgb_pixels: Vec<Vec<Rgb16>> = pixels
+        .into_iter()
+        .map(|row| row.iter().map(Rgb16::from).collect())
+        .collect();
+
+    // keep 8-bit images at a maxval of 255 rather than doubling their size
+    if rgb_pixels.iter().flatten().all(Rgb16::is_8_bit_exact) {
+        let rgb_pixels: Vec<Vec<Rgb>> = rgb_pixels
+            .iter()
+            .map(|row| row.iter().map(Rgb::from).collect())
+            .collect();
+
+        return Ok(encode_ppm(&rgb_pixels));
+    }
+
+    Ok(encode_ppm16(&rgb_pixels))
+}
@@ -1,15 +1,26 @@
-use std::{collections::HashMap, env::Args, fs};
+use std::{collections::HashMap, env::Args, fs, process};
 
-use format::{FileFormat, SUPPORTED_FORMATS};
+use format::{optimize_png_file, FileFormat, SUPPORTED_FORMATS};
+use options::EncoderOptions;
 
 mod format;
+mod options;
 
 #[derive(Clone, Debug)]
 struct ArgumentParseError(String);
 
+#[derive(Clone, Debug)]
+struct ConversionError(String);
+
 pub fn parse_args() {
     let args = std::env::args();
-    let parsed_args = get_arg_flags(args).unwrap();
+    let parsed_args = match get_arg_flags(args) {
+        Ok(parsed_args) => parsed_args,
+        Err(ArgumentParseError(message)) => {
+            eprintln!("{message}");
+            process::exit(1);
+        }
+    };
 
     match parsed_args {
         GimgArguments::Help(subject) => print_help(subject),
@@ -17,19 +28,82 @@ pub fn parse_args() {
         GimgArguments::Command {
             input_file,
             output_file,
-            keyword_args: _,
+            keyword_args,
         } => {
-            let _input_format = determine_file_format_by_name(&input_file)
-                .unwrap_or_else(|| panic!("Unrecognized input file format {input_file}"));
-            let _output_format = determine_file_format_by_name(&output_file)
-                .unwrap_or_else(|| panic!("Unrecognized input file format {input_file}"));
+            let output_options = keyword_args.get("out").map(String::as_str).unwrap_or("");
+
+            if let Err(ConversionError(message)) =
+                convert_file(&input_file, &output_file, output_options)
+            {
+                eprintln!("{message}");
+                process::exit(1);
+            }
         }
     }
 }
 
+fn convert_file(
+    input_file: &str,
+    output_file: &str,
+    output_options: &str,
+) -> Result<(), ConversionError> {
+    let input_bytes = fs::read(input_file)
+        .map_err(|e| ConversionError(format!("error reading input file {input_file}: {e}")))?;
+    let input_format = determine_file_format(input_file, &input_bytes)
+        .ok_or_else(|| ConversionError(format!("Unrecognized input file format {input_file}")))?;
+    let output_format = determine_file_format_by_extension(output_file)
+        .ok_or_else(|| ConversionError(format!("Unrecognized output file format {output_file}")))?;
+
+    // checked before any decoding, so a conversion that cannot finish fails right away
+    if !input_format.can_decode() {
+        return Err(ConversionError(format!(
+            "Cannot read {input_file}, {} decoding is not supported",
+            input_format.name
+        )));
+    }
+
+    if !output_format.can_encode() {
+        return Err(ConversionError(format!(
+            "Cannot write {output_file}, {} encoding is not supported",
+            output_format.name
+        )));
+    }
+
+    let encoder_options = EncoderOptions::parse(&output_format, output_options)
+        .map_err(|ArgumentParseError(message)| ConversionError(message))?;
+
+    if input_format.is_png() && matches!(encoder_options, EncoderOptions::OptimizedPng(_)) {
+        let output_bytes = optimize_png_file(&input_bytes)
+            .map_err(|e| ConversionError(format!("Failed to optimize {input_file} as PNG: {e}")))?;
+
+        return fs::write(output_file, output_bytes)
+            .map_err(|e| ConversionError(format!("error writing output file {output_file}: {e}")));
+    }
+
+    let pixels = input_format.decode_file(&input_bytes).map_err(|e| {
+        ConversionError(format!(
+            "Failed to decode {input_file} as {}: {e}",
+            input_format.name
+        ))
+    })?;
+    let output_bytes = output_format
+        .encode_file(pixels, encoder_options)
+        .map_err(|e| {
+            ConversionError(format!(
+                "Failed to encode {output_file} as {}: {e}",
+                output_format.name
+            ))
+        })?;
+
+    fs::write(output_file, output_bytes)
+        .map_err(|e| ConversionError(format!("error writing output file {output_file}: {e}")))
+}
+
 /**
  * gimg input.ppm out.png
- * gimg input.ppm --out "interlace=true;bit_depth=10" out.png
+ * gimg input.ppm --out "interlace=true;compression_level=best" out.png
+ * gimg input.ppm --out "compression_level=6" out.png
+ * gimg input.png --out "optimize=true" out.png
  */
 fn get_arg_flags(mut args: Args) -> Result<GimgArguments, ArgumentParseError> {
     let mut unprefixed_args: Vec<String> = Vec::new();
@@ -95,32 +169,48 @@ fn get_arg_flags(mut args: Args) -> Result<GimgArguments, ArgumentParseError> {
 }
 
 fn print_help(subject: String) {
-    println!("[help for {subject}]");
+    match subject.as_str() {
+        "formats" => print_supported_formats(),
+        _ => {
+            println!("usage: gimg <input> [--out \"key=value;...\"] <output>");
+            println!("       gimg --help formats");
+            println!("       gimg --version");
+        }
+    }
+}
+
+fn print_supported_formats() {
+    for format in SUPPORTED_FORMATS {
+        let capabilities: Vec<&str> = [
+            format.can_decode().then_some("read"),
+            format.can_encode().then_some("write"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        println!("{:<6}{}", format.name, capabilities.join(", "));
+    }
 }
 
 fn print_version() {
     println!("0.0.1");
 }
 
-fn determine_file_format_by_name(filename: &str) -> Option<FileFormat> {
-    match fs::read(filename) {
-        Ok(file_bytes) => {
-            for format in SUPPORTED_FORMATS {
-                if format.is_format_by_signature(&file_bytes) {
-                    return Some(format);
-                }
-            }
-
-            for format in SUPPORTED_FORMATS {
-                if format.is_format_by_extension(filename) {
-                    return Some(format);
-                }
-            }
-
-            None
+fn determine_file_format(filename: &str, file_bytes: &[u8]) -> Option<FileFormat> {
+    for format in SUPPORTED_FORMATS {
+        if format.is_format_by_signature(file_bytes) {
+            return Some(format);
         }
-        Err(e) => panic!("error reading input file {filename}: {e}"),
     }
+
+    determine_file_format_by_extension(filename)
+}
+
+fn determine_file_format_by_extension(filename: &str) -> Option<FileFormat> {
+    SUPPORTED_FORMATS
+        .into_iter()
+        .find(|format| format.is_format_by_extension(filename))
 }
 
 #[derive(Debug)]
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use gimg::png::{
+    AdaptiveFilterType, ColorType, CompressionLevel, Dithering, FilterStrategy, InterlaceMethod,
+    PartialPngConfig, Quantization,
+};
+
+use super::{format::FileFormat, ArgumentParseError};
+
+pub type OptionsParser = fn(&[(&str, &str)]) -> Result<EncoderOptions, ArgumentParseError>;
+
+#[derive(Debug)]
+pub enum EncoderOptions {
+    Default,
+    Png(Box<PartialPngConfig>),
+    OptimizedPng(Box<PartialPngConfig>),
+}
+
+impl EncoderOptions {
+    pub fn parse(format: &FileFormat, options: &str) -> Result<Self, ArgumentParseError> {
+        format.parse_encoder_options(&split_options(options)?)
+    }
+}
+
+fn split_options(options: &str) -> Result<Vec<(&str, &str)>, ArgumentParseError> {
+    let mut seen_keys: HashSet<&str> = HashSet::new();
+    let mut key_values = Vec::new();
+
+    for option in options.split(';').map(str::trim).filter(|o| !o.is_empty()) {
+        let (key, value) = option.split_once('=').ok_or_else(|| {
+            ArgumentParseError(format!(
+                "Invalid option \"{option}\", expected the form key=value"
+            ))
+        })?;
+        let (key, value) = (key.trim(), value.trim());
+
+        if !seen_keys.insert(key) {
+            return Err(ArgumentParseError(format!(
+                "Option {key} was specified more than once"
+            )));
+        }
+
+        key_values.push((key, value));
+    }
+
+    Ok(key_values)
+}
+
+pub fn parse_png_options(options: &[(&str, &str)]) -> Result<EncoderOptions, ArgumentParseError> {
+    let mut config = PartialPngConfig::new();
+    let mut color_type: Option<ColorType> = None;
+    let mut bit_depth: Option<u8> = None;
+    let mut optimize = false;
+    let mut number_of_colors: Option<usize> = None;
+    let mut dithering: Option<Dithering> = None;
+
+    for (key, value) in options {
+        match *key {
+            "compression_level" => {
+                config = config.compression_level(parse_compression_level(value)?);
+            }
+            "color_type" => {
+                let parsed_color_type = parse_color_type(value)?;
+                color_type = Some(parsed_color_type);
+                config = config.color_type(parsed_color_type);
+            }
+            "bit_depth" => {
+                let parsed_bit_depth = parse_bit_depth(value)?;
+                bit_depth = Some(parsed_bit_depth);
+                config = config.bit_depth(parsed_bit_depth);
+            }
+            "interlace" | "interlace_method" => {
+                config = config.interlace_method(parse_interlace_method(key, value)?);
+            }
+            "filter" | "filter_strategy" => {
+                config = config.filter_strategy(parse_filter_strategy(key, value)?);
+            }
+            "optimize" => {
+                optimize = parse_bool(key, value)?;
+            }
+            "colors" => {
+                number_of_colors = Some(parse_number_of_colors(value)?);
+            }
+            "dither" => {
+                dithering = Some(parse_dithering(value)?);
+            }
+            key => {
+                return Err(ArgumentParseError(format!(
+                    "Unrecognized PNG option {key}, expected one of compression_level, color_type, bit_depth, interlace, filter, optimize, colors, dither"
+                )));
+            }
+        }
+    }
+
+    if let (Some(color_type), Some(bit_depth)) = (color_type, bit_depth) {
+        if color_type.check_bit_depth_validty(bit_depth).is_err() {
+            return Err(ArgumentParseError(format!(
+                "Invalid option bit_depth={bit_depth}, it is not allowed for color_type={}",
+                color_type_name(color_type)
+            )));
+        }
+    }
+
+    match (number_of_colors, dithering) {
+        (Some(number_of_colors), dithering) => {
+            if let Some(color_type) = color_type.filter(|ct| !matches!(ct, ColorType::IndexedColor))
+            {
+                return Err(ArgumentParseError(format!(
+                    "Option colors needs color_type=indexed, not color_type={}",
+                    color_type_name(color_type)
+                )));
+            }
+
+            config = config.quantization(
+                Quantization::new(number_of_colors).dithering(dithering.unwrap_or_default()),
+            );
+        }
+        (None, Some(_dithering)) => {
+            return Err(ArgumentParseError(
+                "Option dither needs colors to be set as well".to_string(),
+            ));
+        }
+        (None, None) => {}
+    }
+
+    if optimize {
+        if let Some((key, _value)) = options.iter().find(|(key, _value)| *key != "optimize") {
+            return Err(ArgumentParseError(format!(
+                "Option {key} cannot be combined with optimize=true, the optimizer picks it itself"
+            )));
+        }
+
+        return Ok(EncoderOptions::OptimizedPng(Box::new(config)));
+    }
+
+    Ok(EncoderOptions::Png(Box::new(config)))
+}
+
+fn parse_number_of_colors(value: &str) -> Result<usize, ArgumentParseError> {
+    match value.parse::<usize>() {
+        Ok(number_of_colors @ 1..=256) => Ok(number_of_colors),
+        _ => Err(ArgumentParseError(format!(
+            "Invalid option colors={value}, expected a number from 1 to 256"
+        ))),
+    }
+}
+
+fn parse_dithering(value: &str) -> Result<Dithering, ArgumentParseError> {
+    match value {
+        "none" => Ok(Dithering::None),
+        "floyd_steinberg" => Ok(Dithering::FloydSteinberg),
+        "ordered" => Ok(Dithering::Ordered),
+        value => Err(ArgumentParseError(format!(
+            "Invalid option dither={value}, expected one of none, floyd_steinberg, ordered"
+        ))),
+    }
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, ArgumentParseError> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        value => Err(ArgumentParseError(format!(
+            "Invalid option {key}={value}, expected one of true, false"
+        ))),
+    }
+}
+
+fn parse_compression_level(value: &str) -> Result<CompressionLevel, ArgumentParseError> {
+    let compression_level = match value {
+        "none" => Some(CompressionLevel::NONE),
+        "fast" => Some(CompressionLevel::FAST),
+        "default" => Some(CompressionLevel::DEFAULT),
+        "best" => Some(CompressionLevel::BEST),
+        "extreme" => Some(CompressionLevel::EXTREME),
+        level => level.parse().ok().and_then(CompressionLevel::new),
+    };
+
+    compression_level.ok_or_else(|| {
+        ArgumentParseError(format!(
+            "Invalid option compression_level={value}, expected 0 to 9 or one of none, fast, default, best, extreme"
+        ))
+    })
+}
+
+const FILTER_STRATEGY_NAMES: [(&str, FilterStrategy); 8] = [
+    ("none", FilterStrategy::Fixed(AdaptiveFilterType::None)),
+    ("sub", FilterStrategy::Fixed(AdaptiveFilterType::Sub)),
+    ("up", FilterStrategy::Fixed(AdaptiveFilterType::Up)),
+    (
+        "average",
+        FilterStrategy::Fixed(AdaptiveFilterType::Average),
+    ),
+    ("paeth", FilterStrategy::Fixed(AdaptiveFilterType::Paeth)),
+    ("min_sad", FilterStrategy::MinimumSumOfAbsoluteDifferences),
+    ("entropy", FilterStrategy::Entropy),
+    ("brute_force", FilterStrategy::BruteForce),
+];
+
+fn parse_filter_strategy(key: &str, value: &str) -> Result<FilterStrategy, ArgumentParseError> {
+    FILTER_STRATEGY_NAMES
+        .iter()
+        .find(|(name, _filter_strategy)| *name == value)
+        .map(|(_name, filter_strategy)| *filter_strategy)
+        .ok_or_else(|| {
+            ArgumentParseError(format!(
+                "Invalid option {key}={value}, expected one of {}",
+                FILTER_STRATEGY_NAMES
+                    .map(|(name, _filter_strategy)| name)
+                    .join(", ")
+            ))
+        })
+}
+
+const COLOR_TYPE_NAMES: [(&str, ColorType); 5] = [
+    ("greyscale", ColorType::Greyscale),
+    ("truecolor", ColorType::Truecolor),
+    ("indexed", ColorType::IndexedColor),
+    ("greyscale_alpha", ColorType::GreyscaleAlpha),
+    ("truecolor_alpha", ColorType::TrueColorAlpha),
+];
+
+fn parse_color_type(value: &str) -> Result<ColorType, ArgumentParseError> {
+    COLOR_TYPE_NAMES
+        .iter()
+        .find(|(name, _color_type)| *name == value)
+        .map(|(_name, color_type)| *color_type)
+        .ok_or_else(|| {
+            ArgumentParseError(format!(
+                "Invalid option color_type={value}, expected one of {}",
+                COLOR_TYPE_NAMES.map(|(name, _color_type)| name).join(", ")
+            ))
+        })
+}
+
+fn color_type_name(color_type: ColorType) -> &'static str {
+    COLOR_TYPE_NAMES
+        .iter()
+        .find(|(_name, ct)| u8::from(ct) == u8::from(&color_type))
+        .map(|(name, _ct)| *name)
+        .unwrap_or("unknown")
+}
+
+fn parse_bit_depth(value: &str) -> Result<u8, ArgumentParseError> {
+    match value.parse::<u8>() {
+        Ok(bit_depth @ (1 | 2 | 4 | 8 | 16)) => Ok(bit_depth),
+        _ => Err(ArgumentParseError(format!(
+            "Invalid option bit_depth={value}, expected one of 1, 2, 4, 8, 16"
+        ))),
+    }
+}
+
+fn parse_interlace_method(key: &str, value: &str) -> Result<InterlaceMethod, ArgumentParseError> {
+    match value {
+        "true" | "adam7" => Ok(InterlaceMethod::Adam7),
+        "false" | "none" => Ok(InterlaceMethod::NoInterlace),
+        value => Err(ArgumentParseError(format!(
+            "Invalid option {key}={value}, expected one of true, false, adam7, none"
+        ))),
+    }
+}
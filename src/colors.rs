@@ -169,3 +169,125 @@ impl From<&Rgba> for Rgb {
         }
     }
 }
+
+pub fn scale_u8_to_u16(value: u8) -> u16 {
+    u16::from_be_bytes([value, value])
+}
+
+pub fn scale_u16_to_u8(value: u16) -> u8 {
+    ((value as u32 * u8::MAX as u32 + (u16::MAX >> 1) as u32) / u16::MAX as u32) as u8
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rgb16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+impl Rgb16 {
+    pub fn new(r: u16, g: u16, b: u16) -> Self {
+        Self { r, g, b }
+    }
+
+    pub fn is_8_bit_exact(&self) -> bool {
+        [self.r, self.g, self.b]
+            .into_iter()
+            .all(|value| value % 257 == 0)
+    }
+}
+
+impl From<&Rgb> for Rgb16 {
+    fn from(val: &Rgb) -> Self {
+        Rgb16 {
+            r: scale_u8_to_u16(val.r),
+            g: scale_u8_to_u16(val.g),
+            b: scale_u8_to_u16(val.b),
+        }
+    }
+}
+
+impl From<&Rgb16> for Rgb {
+    fn from(val: &Rgb16) -> Self {
+        Rgb {
+            r: scale_u16_to_u8(val.r),
+            g: scale_u16_to_u8(val.g),
+            b: scale_u16_to_u8(val.b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rgba16 {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+    pub a: u16,
+}
+
+impl Rgba16 {
+    pub fn new(r: u16, g: u16, b: u16, a: u16) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        self.a == u16::MAX
+    }
+
+    pub fn is_greyscale(&self) -> bool {
+        self.r == self.g && self.r == self.b
+    }
+
+    pub fn is_8_bit_exact(&self) -> bool {
+        [self.r, self.g, self.b, self.a]
+            .into_iter()
+            .all(|value| value % 257 == 0)
+    }
+
+    pub fn luma(&self) -> u16 {
+        (0.299 * self.r as f32 + 0.587 * self.g as f32 + 0.114 * self.b as f32).round() as u16
+    }
+}
+
+impl From<&Rgba> for Rgba16 {
+    fn from(val: &Rgba) -> Self {
+        Rgba16 {
+            r: scale_u8_to_u16(val.r),
+            g: scale_u8_to_u16(val.g),
+            b: scale_u8_to_u16(val.b),
+            a: scale_u8_to_u16(val.a),
+        }
+    }
+}
+
+impl From<&Rgba16> for Rgba {
+    fn from(val: &Rgba16) -> Self {
+        Rgba {
+            r: scale_u16_to_u8(val.r),
+            g: scale_u16_to_u8(val.g),
+            b: scale_u16_to_u8(val.b),
+            a: scale_u16_to_u8(val.a),
+        }
+    }
+}
+
+impl From<&Rgb16> for Rgba16 {
+    fn from(val: &Rgb16) -> Self {
+        Rgba16 {
+            r: val.r,
+            g: val.g,
+            b: val.b,
+            a: u16::MAX,
+        }
+    }
+}
+
+impl From<&Rgba16> for Rgb16 {
+    fn from(val: &Rgba16) -> Self {
+        Rgb16 {
+            r: val.r,
+            g: val.g,
+            b: val.b,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::{error, fmt};
+
+use crate::{
+    gif::GifParseError,
+    mpegts::MpegtsParseError,
+    png::{deflate::DeflateDecodeError, PngEncodeError, PngParseError},
+    ppm::PpmParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidSignature,
+    UnexpectedEof,
+    InvalidData,
+    ChecksumMismatch,
+    Unsupported,
+    Io,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            ErrorKind::InvalidSignature => "invalid signature",
+            ErrorKind::UnexpectedEof => "unexpected end of data",
+            ErrorKind::InvalidData => "invalid data",
+            ErrorKind::ChecksumMismatch => "checksum mismatch",
+            ErrorKind::Unsupported => "unsupported feature",
+            ErrorKind::Io => "i/o error",
+        };
+
+        f.write_str(description)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Png(PngParseError),
+    PngEncode(PngEncodeError),
+    Ppm(PpmParseError),
+    Gif(GifParseError),
+    Deflate(DeflateDecodeError),
+    Mpegts(MpegtsParseError),
+}
+
+impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Png(err) => err.kind,
+            Error::PngEncode(err) => err.kind(),
+            Error::Ppm(err) => err.kind,
+            Error::Gif(err) => err.kind,
+            Error::Deflate(err) => err.kind,
+            Error::Mpegts(err) => err.kind,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Png(err) => err.fmt(f),
+            Error::PngEncode(err) => err.fmt(f),
+            Error::Ppm(err) => err.fmt(f),
+            Error::Gif(err) => err.fmt(f),
+            Error::Deflate(err) => err.fmt(f),
+            Error::Mpegts(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Png(err) => err.source(),
+            Error::PngEncode(err) => err.source(),
+            Error::Ppm(err) => err.source(),
+            Error::Gif(err) => err.source(),
+            Error::Deflate(err) => err.source(),
+            Error::Mpegts(err) => err.source(),
+        }
+    }
+}
+
+impl From<PngParseError> for Error {
+    fn from(err: PngParseError) -> Self {
+        Error::Png(err)
+    }
+}
+
+impl From<PngEncodeError> for Error {
+    fn from(err: PngEncodeError) -> Self {
+        Error::PngEncode(err)
+    }
+}
+
+impl From<PpmParseError> for Error {
+    fn from(err: PpmParseError) -> Self {
+        Error::Ppm(err)
+    }
+}
+
+impl From<GifParseError> for Error {
+    fn from(err: GifParseError) -> Self {
+        Error::Gif(err)
+    }
+}
+
+impl From<DeflateDecodeError> for Error {
+    fn from(err: DeflateDecodeError) -> Self {
+        Error::Deflate(err)
+    }
+}
+
+impl From<MpegtsParseError> for Error {
+    fn from(err: MpegtsParseError) -> Self {
+        Error::Mpegts(err)
+    }
+}
@@ -1 +1,13 @@
 pub const GIF_SIGNATURE: &[u8] = &[0x47, 0x49, 0x46, 0x38, 0x39];
+pub const GIF_VERSION_LETTER: u8 = b'a';
+
+pub const EXTENSION_INTRODUCER: u8 = 0x21;
+pub const IMAGE_SEPARATOR: u8 = 0x2c;
+pub const TRAILER: u8 = 0x3b;
+pub const GRAPHIC_CONTROL_LABEL: u8 = 0xf9;
+
+// flags shared by the logical screen and image descriptors
+pub const COLOR_TABLE_FLAG: u8 = 0b1000_0000;
+pub const COLOR_TABLE_SIZE_MASK: u8 = 0b0000_0111;
+pub const INTERLACE_FLAG: u8 = 0b0100_0000;
+pub const TRANSPARENT_COLOR_FLAG: u8 = 0b0000_0001;
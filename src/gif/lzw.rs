@@ -0,0 +1,162 @@
+use crate::{error::ErrorKind, gif_assert};
+
+use super::GifParseError;
+
+const MAX_CODE_SIZE: u8 = 12;
+const MAX_CODES: usize = 1 << MAX_CODE_SIZE;
+
+// reads codes of a changing width from the image data, least significant bit first
+struct CodeReader<'a> {
+    bytes: &'a [u8],
+    bit_offset: usize,
+}
+
+impl CodeReader<'_> {
+    fn read_code(&mut self, code_size: u8) -> Option<u16> {
+        if self.bit_offset + code_size as usize > self.bytes.len() * 8 {
+            return None;
+        }
+
+        let mut code = 0;
+        for bit in 0..code_size as usize {
+            let bit_index = self.bit_offset + bit;
+            let value = (self.bytes[bit_index >> 3] >> (bit_index & 0b111)) & 1;
+            code |= (value as u16) << bit;
+        }
+        self.bit_offset += code_size as usize;
+
+        Some(code)
+    }
+}
+
+// every code past the literals stands for the string of the code it extends plus one more index
+struct CodeTable {
+    prefixes: Vec<u16>,
+    suffixes: Vec<u8>,
+    lengths: Vec<usize>,
+}
+
+impl CodeTable {
+    fn new(clear_code: u16) -> Self {
+        let mut lengths = vec![0; MAX_CODES];
+        lengths[..clear_code as usize].fill(1);
+
+        Self {
+            prefixes: vec![0; MAX_CODES],
+            suffixes: (0..MAX_CODES).map(|code| code as u8).collect(),
+            lengths,
+        }
+    }
+
+    fn add(&mut self, code: u16, prefix: u16, suffix: u8) {
+        self.prefixes[code as usize] = prefix;
+        self.suffixes[code as usize] = suffix;
+        self.lengths[code as usize] = self.lengths[prefix as usize] + 1;
+    }
+
+    // appends the string of a code and returns its first index
+    fn write_string(&self, code: u16, indices: &mut Vec<u8>) -> u8 {
+        let start = indices.len();
+        indices.resize(start + self.lengths[code as usize], 0);
+
+        let mut code = code;
+        for index in indices[start..].iter_mut().rev() {
+            *index = self.suffixes[code as usize];
+            code = self.prefixes[code as usize];
+        }
+
+        indices[start]
+    }
+}
+
+// decodes the color indices of one image. data after the first pixel_count indices is ignored,
+// and so is a missing end code
+pub fn decode_lzw(
+    data: &[u8],
+    minimum_code_size: u8,
+    pixel_count: usize,
+) -> Result<Vec<u8>, GifParseError> {
+    gif_assert!(
+        (1..MAX_CODE_SIZE).contains(&minimum_code_size),
+        format!("GIF LZW minimum code size {minimum_code_size} is out of range")
+    );
+
+    let clear_code = 1u16 << minimum_code_size;
+    let end_code = clear_code + 1;
+    let mut table = CodeTable::new(clear_code);
+    let mut next_code = end_code + 1;
+    let mut code_size = minimum_code_size + 1;
+    let mut previous_code: Option<u16> = None;
+    let mut reader = CodeReader {
+        bytes: data,
+        bit_offset: 0,
+    };
+    let mut indices = Vec::with_capacity(pixel_count);
+
+    while indices.len() < pixel_count {
+        let Some(code) = reader.read_code(code_size) else {
+            break;
+        };
+
+        if code == clear_code {
+            next_code = end_code + 1;
+            code_size = minimum_code_size + 1;
+            previous_code = None;
+            continue;
+        }
+
+        if code == end_code {
+            break;
+        }
+
+        let Some(previous) = previous_code else {
+            gif_assert!(
+                code < clear_code,
+                format!("GIF LZW code {code} refers to an empty code table")
+            );
+            indices.push(code as u8);
+            previous_code = Some(code);
+            continue;
+        };
+
+        gif_assert!(
+            code <= next_code,
+            format!("GIF LZW code {code} is not in the code table yet")
+        );
+
+        let first_index = if code < next_code {
+            table.write_string(code, &mut indices)
+        } else {
+            // the code being defined right now, which is the previous string plus its own start
+            let first_index = table.write_string(previous, &mut indices);
+            indices.push(first_index);
+            first_index
+        };
+
+        // a full table stays as it is until the next clear code
+        if (next_code as usize) < MAX_CODES {
+            table.add(next_code, previous, first_index);
+            next_code += 1;
+
+            if next_code == 1 << code_size && code_size < MAX_CODE_SIZE {
+                code_size += 1;
+            }
+        }
+
+        previous_code = Some(code);
+    }
+
+    if indices.len() < pixel_count {
+        return Err(GifParseError::new(
+            ErrorKind::UnexpectedEof,
+            format!(
+                "GIF image data ends after {} of {pixel_count} pixels",
+                indices.len()
+            ),
+        ));
+    }
+
+    indices.truncate(pixel_count);
+
+    Ok(indices)
+}
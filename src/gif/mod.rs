@@ -1,26 +1,285 @@
-use consts::GIF_SIGNATURE;
+use std::{error, fmt, path::Path};
 
-use crate::{binary::byte_reader::ByteReader, colors::Rgba};
+use consts::{
+    COLOR_TABLE_FLAG, COLOR_TABLE_SIZE_MASK, EXTENSION_INTRODUCER, GIF_SIGNATURE,
+    GIF_VERSION_LETTER, GRAPHIC_CONTROL_LABEL, IMAGE_SEPARATOR, INTERLACE_FLAG, TRAILER,
+    TRANSPARENT_COLOR_FLAG,
+};
+use lzw::decode_lzw;
+
+use crate::{binary::byte_reader::ByteReader, colors::Rgba, error::ErrorKind};
 
 mod consts;
+mod lzw;
 
 #[derive(Debug)]
-pub struct GifParseError(String);
+pub struct GifParseError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub offset: Option<usize>,
+}
+
+impl GifParseError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            offset: None,
+        }
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl fmt::Display for GifParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GIF parse error ({}): {}", self.kind, self.message)?;
+
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {offset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for GifParseError {}
 
 #[macro_export]
 macro_rules! gif_assert {
     ($assert_value:expr, $msg:expr) => {
         if !$assert_value {
-            return Err(GifParseError(format!("GIF parse error: {}", $msg)));
+            return Err($crate::gif::GifParseError::new(
+                $crate::ErrorKind::InvalidData,
+                $msg,
+            ));
         }
     };
 }
 
+pub fn is_gif_by_signature(file: &[u8]) -> bool {
+    file.starts_with(GIF_SIGNATURE)
+}
+
+pub fn is_gif_by_extension(filename: &str) -> bool {
+    Path::new(filename)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("gif"))
+}
+
+// the first image of the gif drawn on its logical screen, which starts out transparent like it
+// does in browsers. later images of an animation are not decoded
 pub fn decode_gif(bytes: &[u8]) -> Result<Vec<Vec<Rgba>>, GifParseError> {
     let mut byte_reader = ByteReader::new(bytes);
     let signature = byte_reader.read_bytes(GIF_SIGNATURE.len()).unwrap_or(&[]);
 
-    gif_assert!(*signature == *GIF_SIGNATURE, "GIF header not found");
+    if *signature != *GIF_SIGNATURE {
+        return Err(
+            GifParseError::new(ErrorKind::InvalidSignature, "GIF header not found").at_offset(0),
+        );
+    }
+
+    let version_letter = read_byte(&mut byte_reader, "header")?;
+    gif_assert!(
+        version_letter == GIF_VERSION_LETTER,
+        "GIF header has an unknown version"
+    );
+
+    let screen_width = read_u16(&mut byte_reader, "logical screen descriptor")?;
+    let screen_height = read_u16(&mut byte_reader, "logical screen descriptor")?;
+    let flags = read_byte(&mut byte_reader, "logical screen descriptor")?;
+    // the background color index and pixel aspect ratio, which browsers ignore as well
+    read_bytes(&mut byte_reader, 2, "logical screen descriptor")?;
+    let global_color_table = read_color_table(&mut byte_reader, flags)?;
+    let mut transparent_index = None;
+
+    loop {
+        let block_offset = byte_reader.offset;
+
+        match read_byte(&mut byte_reader, "block")? {
+            EXTENSION_INTRODUCER => {
+                let label = read_byte(&mut byte_reader, "extension")?;
+                let data = read_sub_blocks(&mut byte_reader)?;
+
+                if label == GRAPHIC_CONTROL_LABEL {
+                    gif_assert!(
+                        data.len() >= 4,
+                        "GIF graphic control extension is too short"
+                    );
+                    transparent_index = (data[0] & TRANSPARENT_COLOR_FLAG != 0).then_some(data[3]);
+                }
+            }
+            IMAGE_SEPARATOR => {
+                let mut canvas = vec![
+                    vec![Rgba::new(0, 0, 0, 0); screen_width as usize];
+                    screen_height as usize
+                ];
+                draw_image(
+                    &mut byte_reader,
+                    &mut canvas,
+                    &global_color_table,
+                    transparent_index,
+                )?;
+
+                return Ok(canvas);
+            }
+            TRAILER => {
+                return Err(GifParseError::new(
+                    ErrorKind::InvalidData,
+                    "GIF has no image before its trailer",
+                )
+                .at_offset(block_offset))
+            }
+            block => {
+                return Err(GifParseError::new(
+                    ErrorKind::InvalidData,
+                    format!("GIF block {block:#04x} is not known"),
+                )
+                .at_offset(block_offset))
+            }
+        }
+    }
+}
+
+// decodes the image that follows an image separator onto the canvas, leaving transparent pixels
+// and anything outside the logical screen alone
+fn draw_image(
+    byte_reader: &mut ByteReader,
+    canvas: &mut [Vec<Rgba>],
+    global_color_table: &Option<Vec<Rgba>>,
+    transparent_index: Option<u8>,
+) -> Result<(), GifParseError> {
+    let left = read_u16(byte_reader, "image descriptor")? as usize;
+    let top = read_u16(byte_reader, "image descriptor")? as usize;
+    let width = read_u16(byte_reader, "image descriptor")? as usize;
+    let height = read_u16(byte_reader, "image descriptor")? as usize;
+    let flags = read_byte(byte_reader, "image descriptor")?;
+    let local_color_table = read_color_table(byte_reader, flags)?;
+    let color_table = local_color_table
+        .as_ref()
+        .or(global_color_table.as_ref())
+        .ok_or_else(|| {
+            GifParseError::new(ErrorKind::InvalidData, "GIF image has no color table")
+                .at_offset(byte_reader.offset)
+        })?;
+
+    let minimum_code_size = read_byte(byte_reader, "image data")?;
+    let data_offset = byte_reader.offset;
+    let data = read_sub_blocks(byte_reader)?;
+    let indices = decode_lzw(&data, minimum_code_size, width * height)
+        .map_err(|err| err.at_offset(data_offset))?;
+    let row_order = if flags & INTERLACE_FLAG != 0 {
+        interlaced_row_order(height)
+    } else {
+        (0..height).collect()
+    };
+
+    for (row_indices, y) in indices.chunks(width.max(1)).zip(row_order) {
+        let Some(canvas_row) = canvas.get_mut(top + y) else {
+            continue;
+        };
+
+        for (x, &index) in row_indices.iter().enumerate() {
+            if Some(index) == transparent_index {
+                continue;
+            }
+
+            let color = color_table.get(index as usize).ok_or_else(|| {
+                GifParseError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "GIF color index {index} is past the end of a {} color table",
+                        color_table.len()
+                    ),
+                )
+                .at_offset(data_offset)
+            })?;
+
+            if let Some(pixel) = canvas_row.get_mut(left + x) {
+                *pixel = color.clone();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// interlaced images store every 8th row from 0, every 8th from 4, every 4th from 2 and then every
+// 2nd from 1
+fn interlaced_row_order(height: usize) -> Vec<usize> {
+    [(0, 8), (4, 8), (2, 4), (1, 2)]
+        .into_iter()
+        .flat_map(|(start, step)| (start..height).step_by(step))
+        .collect()
+}
+
+fn read_color_table(
+    byte_reader: &mut ByteReader,
+    flags: u8,
+) -> Result<Option<Vec<Rgba>>, GifParseError> {
+    if flags & COLOR_TABLE_FLAG == 0 {
+        return Ok(None);
+    }
+
+    let number_of_colors = 2 << (flags & COLOR_TABLE_SIZE_MASK);
+    let color_table = read_bytes(byte_reader, number_of_colors * 3, "color table")?
+        .chunks(3)
+        .map(|rgb| Rgba::new(rgb[0], rgb[1], rgb[2], 255))
+        .collect();
+
+    Ok(Some(color_table))
+}
+
+// extensions and image data are split into blocks of up to 255 bytes, ended by an empty block
+fn read_sub_blocks(byte_reader: &mut ByteReader) -> Result<Vec<u8>, GifParseError> {
+    let mut data = Vec::new();
+
+    loop {
+        let size = read_byte(byte_reader, "data sub-block")?;
+
+        if size == 0 {
+            return Ok(data);
+        }
+
+        data.extend_from_slice(read_bytes(byte_reader, size as usize, "data sub-block")?);
+    }
+}
+
+fn unexpected_eof(offset: usize, part: &str) -> GifParseError {
+    GifParseError::new(
+        ErrorKind::UnexpectedEof,
+        format!("GIF file ends in the middle of a {part}"),
+    )
+    .at_offset(offset)
+}
+
+fn read_byte(byte_reader: &mut ByteReader, part: &str) -> Result<u8, GifParseError> {
+    let offset = byte_reader.offset;
+
+    byte_reader
+        .read_byte()
+        .ok_or_else(|| unexpected_eof(offset, part))
+}
+
+fn read_u16(byte_reader: &mut ByteReader, part: &str) -> Result<u16, GifParseError> {
+    let offset = byte_reader.offset;
+
+    byte_reader
+        .read_u16_le()
+        .ok_or_else(|| unexpected_eof(offset, part))
+}
+
+fn read_bytes<'a>(
+    byte_reader: &mut ByteReader<'a>,
+    size: usize,
+    part: &str,
+) -> Result<&'a [u8], GifParseError> {
+    let offset = byte_reader.offset;
 
-    todo!()
+    byte_reader
+        .read_bytes(size)
+        .ok_or_else(|| unexpected_eof(offset, part))
 }
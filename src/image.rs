@@ -97,4 +97,3 @@ impl Image {
 }
 
 pub const MACROBLOCKS_SIZE: usize = 16;
-type Macroblock<'a> = Vec<&'a [YCbCr]>;
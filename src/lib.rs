@@ -0,0 +1,16 @@
+#![feature(portable_simd)]
+
+mod algebra;
+mod binary;
+pub mod colors;
+mod error;
+pub mod gif;
+pub mod image;
+pub mod mpegts;
+pub mod pixel_formats;
+pub mod png;
+pub mod ppm;
+mod queue;
+mod simd_utils;
+
+pub use error::{Error, ErrorKind};
@@ -5,14 +5,14 @@ use crate::{
 
 #[derive(Default, Debug)]
 pub struct AdaptationField<'a> {
-    pcr: Option<Pcr>,
-    opcr: Option<Pcr>,
-    discontinuity_indicator: bool,
-    random_access_indicator: bool,
-    elementary_stream_priority_indicator: bool,
-    splice_countdown: Option<i8>,
-    private_data: Option<&'a [u8]>,
-    adaptation_field_extension: Option<AdaptationFieldExtension>,
+    pub pcr: Option<Pcr>,
+    pub opcr: Option<Pcr>,
+    pub discontinuity_indicator: bool,
+    pub random_access_indicator: bool,
+    pub elementary_stream_priority_indicator: bool,
+    pub splice_countdown: Option<i8>,
+    pub private_data: Option<&'a [u8]>,
+    pub adaptation_field_extension: Option<AdaptationFieldExtension>,
 }
 
 read_flag_bit!(read_discontinuity_indicator, 0);
@@ -71,16 +71,16 @@ pub fn read_adaptation_field<'a>(reader: &mut ByteReader<'a>) -> AdaptationField
 }
 
 #[derive(Default, Debug)]
-struct AdaptationFieldExtension {
-    lwt_offset: Option<u16>,
-    piecewise_rate: Option<u32>,
-    splice_info: Option<SpliceInfo>,
+pub struct AdaptationFieldExtension {
+    pub lwt_offset: Option<u16>,
+    pub piecewise_rate: Option<u32>,
+    pub splice_info: Option<SpliceInfo>,
 }
 
 #[derive(Debug)]
-struct SpliceInfo {
-    splice_type: u8,
-    dts_next_au: u64,
+pub struct SpliceInfo {
+    pub splice_type: u8,
+    pub dts_next_au: u64,
 }
 
 read_flag_bit!(read_lwt_flag, 0);
@@ -1,13 +1,17 @@
 use crate::{
     binary::byte_reader::ByteReader,
+    error::ErrorKind,
     extract_bits,
     mpegts::{
-        packet_parser::adaptation_field::{read_adaptation_field, AdaptationField},
+        packet_parser::adaptation_field::read_adaptation_field,
         utils::MpegtsParseError,
     },
     mpegts_assert, read_flag_bit,
 };
 
+pub use adaptation_field::AdaptationField;
+pub use pcr::Pcr;
+
 mod adaptation_field;
 mod pcr;
 
@@ -43,7 +47,11 @@ pub fn decode_mpegts_packet<'a>(bytes: &'a [u8]) -> Result<MpegtsPacket<'a>, Mpe
     let mut reader = ByteReader::new(bytes);
     let mpegts_header = reader.read_bytes(4).unwrap();
     let sync_byte = mpegts_header[0];
-    mpegts_assert!(sync_byte == MPEGTS_SYNC_BYTE, "invalid sync byte");
+    if sync_byte != MPEGTS_SYNC_BYTE {
+        return Err(
+            MpegtsParseError::new(ErrorKind::InvalidSignature, "invalid sync byte").at_offset(0),
+        );
+    }
     let transport_error_indicator = read_transport_error_indicator(mpegts_header[1]);
     let payload_unit_start_indicator = read_payload_unit_start_indicator(mpegts_header[1]);
     let transport_priority = read_transport_priority(mpegts_header[1]);
@@ -51,10 +59,14 @@ pub fn decode_mpegts_packet<'a>(bytes: &'a [u8]) -> Result<MpegtsPacket<'a>, Mpe
     let pid = read_pid(pid_bytes);
     let transport_scrambling_control = read_transport_scrambling_control(mpegts_header[3]);
     let adaptation_field_control = read_adaptation_field_control(mpegts_header[3]);
-    mpegts_assert!(
-        adaptation_field_control != 0b00,
-        "adaptation field control 00 is not allowed"
-    );
+    if adaptation_field_control == 0b00 {
+        return Err(MpegtsParseError::new(
+            ErrorKind::InvalidData,
+            "adaptation field control 00 is not allowed",
+        )
+        .with_pid(pid)
+        .at_offset(3));
+    }
 
     let continuity_counter = read_continuity_counter(mpegts_header[3]);
 
@@ -65,7 +77,7 @@ pub fn decode_mpegts_packet<'a>(bytes: &'a [u8]) -> Result<MpegtsPacket<'a>, Mpe
     let should_read_payload = adaptation_field_control == 0b01 || adaptation_field_control == 0b11;
     let payload = should_read_payload.then(|| reader.read_to_end());
 
-    return Ok(MpegtsPacket {
+    Ok(MpegtsPacket {
         transport_scrambling_control,
         continuity_counter,
         pid,
@@ -74,5 +86,5 @@ pub fn decode_mpegts_packet<'a>(bytes: &'a [u8]) -> Result<MpegtsPacket<'a>, Mpe
         transport_priority,
         adaptation_field,
         payload,
-    });
+    })
 }
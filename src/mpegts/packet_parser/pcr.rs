@@ -4,7 +4,7 @@ pub const PCR_BYTE_LENGTH: usize = 6;
 
 //value is saved in 27MHz ticks
 #[derive(Debug)]
-pub(crate) struct Pcr(u64);
+pub struct Pcr(pub u64);
 
 extract_bits!(read_pcr_base, u64, 16, 33);
 extract_bits!(read_pcr_extension, u64, 55, 9);
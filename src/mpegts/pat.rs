@@ -1,41 +1,97 @@
-use crate::{binary::byte_reader::ByteReader, extract_bits, read_flag_bit};
+use crate::{
+    binary::byte_reader::ByteReader, error::ErrorKind, extract_bits, mpegts_assert, read_flag_bit,
+};
 
-pub struct MpegtsProgramAssociationtable {}
+use super::MpegtsParseError;
+
+const PAT_TABLE_ID: u8 = 0x00;
+// the bytes after the section length that come before the entries
+const PAT_HEADER_LENGTH: usize = 5;
+const PAT_ENTRY_LENGTH: usize = 4;
+const CRC_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MpegtsProgramAssociationTable {
+    pub transport_stream_id: u16,
+    pub version_number: u8,
+    pub current_next_indicator: bool,
+    pub section_number: u8,
+    pub last_section_number: u8,
+    pub entries: Vec<PatEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatEntry {
+    ProgramMap { program_number: u16, pid: u16 },
+    NetworkPid(u16),
+}
 
 extract_bits!(read_section_length, u16, 4, 12);
-extract_bits!(read_version_number, u8, 5, 2);
+extract_bits!(read_version_number, u8, 2, 5);
 read_flag_bit!(read_current_next_indicator, 7);
 extract_bits!(read_pat_entry_pid, u16, 3, 13);
 
-pub fn parse_pat(bytes: &[u8]) -> MpegtsProgramAssociationtable {
+// parses a program association section starting at its table id, after the pointer field. the
+// CRC at its end is not checked
+pub fn parse_pat(bytes: &[u8]) -> Result<MpegtsProgramAssociationTable, MpegtsParseError> {
     let mut reader = ByteReader::new(bytes);
-    let table_id = reader.read_byte().unwrap();
-    let section_length_bytes = reader.read_u16_be().unwrap();
-    let section_length = read_section_length(section_length_bytes);
+    let (Some(table_id), Some(section_length_bytes)) = (reader.read_byte(), reader.read_u16_be())
+    else {
+        return Err(MpegtsParseError::new(
+            ErrorKind::UnexpectedEof,
+            "PAT section ended before its section length",
+        ));
+    };
+    mpegts_assert!(
+        table_id == PAT_TABLE_ID,
+        "PAT table id must be {}, found {}",
+        PAT_TABLE_ID,
+        table_id
+    );
+
+    let section_length = read_section_length(section_length_bytes) as usize;
+    mpegts_assert!(
+        section_length >= PAT_HEADER_LENGTH + CRC_LENGTH
+            && (section_length - PAT_HEADER_LENGTH - CRC_LENGTH).is_multiple_of(PAT_ENTRY_LENGTH),
+        "PAT section length {} does not fit whole program entries",
+        section_length
+    );
+
+    let Some(section) = reader.read_bytes(section_length) else {
+        return Err(MpegtsParseError::new(
+            ErrorKind::UnexpectedEof,
+            format!(
+                "PAT section length is {section_length} but only {} bytes follow",
+                reader.number_of_bytes_left()
+            ),
+        ));
+    };
+    let mut reader = ByteReader::new(&section[..section_length - CRC_LENGTH]);
     let transport_stream_id = reader.read_u16_be().unwrap();
     let version_byte = reader.read_byte().unwrap();
-    let version_number = read_version_number(version_byte);
-    let current_next_indicator = read_current_next_indicator(version_byte);
     let section_number = reader.read_byte().unwrap();
     let last_section_number = reader.read_byte().unwrap();
-    let number_of_programs = (bytes.len() - reader.offset - 32) / 32;
 
-    let entries: Vec<PatEntry> = (0..number_of_programs)
-        .map(|_| {
-            let program_number = reader.read_u16_be().unwrap();
-            let pid = read_pat_entry_pid(reader.read_u16_be().unwrap());
+    let mut entries = Vec::new();
+    while let (Some(program_number), Some(pid_bytes)) = (reader.read_u16_be(), reader.read_u16_be())
+    {
+        let pid = read_pat_entry_pid(pid_bytes);
 
-            match program_number {
-                0 => PatEntry::NetworkPid(pid),
-                _ => PatEntry::ProgramMap(pid),
-            }
-        })
-        .collect();
+        entries.push(match program_number {
+            0 => PatEntry::NetworkPid(pid),
+            _ => PatEntry::ProgramMap {
+                program_number,
+                pid,
+            },
+        });
+    }
 
-    todo!()
-}
-
-enum PatEntry {
-    ProgramMap(u16),
-    NetworkPid(u16),
+    Ok(MpegtsProgramAssociationTable {
+        transport_stream_id,
+        version_number: read_version_number(version_byte),
+        current_next_indicator: read_current_next_indicator(version_byte),
+        section_number,
+        last_section_number,
+        entries,
+    })
 }
@@ -1,15 +1,17 @@
 use crate::{
     binary::byte_reader::ByteReader,
     mpegts::{
-        pes_header_parser::{
-            media_pes::{parse_media_pes, MediaPesData},
-            streamid::PesStreamId,
-        },
+        pes_header_parser::media_pes::parse_media_pes,
         utils::MpegtsParseError,
     },
     mpegts_assert,
 };
 
+pub use media_pes::MediaPesData;
+pub use pes_extension::PesExtension;
+pub use streamid::PesStreamId;
+pub use trick_mode::PesTrickMode;
+
 mod media_pes;
 mod pes_extension;
 mod streamid;
@@ -31,7 +33,7 @@ pub struct PesPacket<'a> {
 // while entire_pes.length < expected_length {entire_pes.concat(new_packet)}
 // decode_pes_packet_from_full_bytes(entire_pes[..expected_length])
 pub fn get_pes_packet_length_from_header(bytes: &[u8]) -> u16 {
-    todo!()
+    u16::from_be_bytes([bytes[4], bytes[5]])
 }
 
 pub fn decode_pes_packet_from_full_bytes<'a>(
@@ -44,17 +46,17 @@ pub fn decode_pes_packet_from_full_bytes<'a>(
         "invalid pes_packet_start_code_prefix"
     );
     let streamid = PesStreamId::from(reader.read_byte().unwrap());
-    let pes_pakcet_length = reader.read_u16_be().unwrap();
+    let _pes_packet_length = reader.read_u16_be().unwrap();
 
     match streamid {
         PesStreamId::Audio(_) | PesStreamId::Video(_) | PesStreamId::Private1 => {
             let info = parse_media_pes(&mut reader)?;
 
-            return Ok(PesPacket {
+            Ok(PesPacket {
                 medie_pes_info: Some(info),
                 streamid,
                 ..Default::default()
-            });
+            })
         }
         PesStreamId::Padding => Ok(PesPacket {
             streamid,
@@ -63,11 +65,11 @@ pub fn decode_pes_packet_from_full_bytes<'a>(
         _ => {
             let payload = reader.read_to_end();
 
-            return Ok(PesPacket {
+            Ok(PesPacket {
                 streamid,
                 payload: Some(payload),
                 ..Default::default()
-            });
+            })
         }
     }
 }
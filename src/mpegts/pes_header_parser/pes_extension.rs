@@ -6,11 +6,11 @@ use crate::{
 };
 
 #[derive(Debug)]
-pub struct PesExtension<> {
-    private_data: Option<[u8; 128]>,
-    pack_header: Option<PackHeader>,
-    program_packet_sequence: Option<ProgramPacketSequence>,
-    p_std_total_buffer_size: Option<u32>,
+pub struct PesExtension {
+    pub private_data: Option<[u8; 128]>,
+    pub pack_header: Option<PackHeader>,
+    pub program_packet_sequence: Option<ProgramPacketSequence>,
+    pub p_std_total_buffer_size: Option<u32>,
 }
 
 read_flag_bit!(read_pes_private_data_flag, 0);
@@ -61,10 +61,10 @@ fn read_p_std_total_buffer_size(reader: &mut ByteReader) -> u32 {
 }
 
 #[derive(Debug)]
-struct PackHeader {
-    system_clock_reference: u64,
-    program_mux_rate: u32,
-    system_header: Option<SystemHeader>,
+pub struct PackHeader {
+    pub system_clock_reference: u64,
+    pub program_mux_rate: u32,
+    pub system_header: Option<SystemHeader>,
 }
 
 extract_bits!(read_program_mux_rate, u32, 8, 22);
@@ -96,16 +96,16 @@ pub fn read_pack_header(reader: &mut ByteReader) -> PackHeader {
 const SYSTEM_HEADER_START_CODE: &[u8] = &[0, 0, 1, 0xbb];
 
 #[derive(Debug)]
-struct SystemHeader {
-    rate_bound: u32,
-    audio_bound: u8,
-    fixed_flag: bool,
-    csps_flag: bool,
-    system_audio_lock_flag: bool,
-    system_video_lock_flag: bool,
-    video_bound: u8,
-    packet_rate_restriction_flag: bool,
-    streams: Vec<PesStream>,
+pub struct SystemHeader {
+    pub rate_bound: u32,
+    pub audio_bound: u8,
+    pub fixed_flag: bool,
+    pub csps_flag: bool,
+    pub system_audio_lock_flag: bool,
+    pub system_video_lock_flag: bool,
+    pub video_bound: u8,
+    pub packet_rate_restriction_flag: bool,
+    pub streams: Vec<PesStream>,
 }
 
 extract_bits!(read_rate_bound, u32, 9, 22);
@@ -164,9 +164,9 @@ fn read_system_header(reader: &mut ByteReader) -> SystemHeader {
 }
 
 #[derive(Debug)]
-struct PesStream {
-    stream_id: PesStreamId,
-    p_std_total_buffer_size: u32,
+pub struct PesStream {
+    pub stream_id: PesStreamId,
+    pub p_std_total_buffer_size: u32,
 }
 
 impl PesStream {
@@ -179,10 +179,10 @@ impl PesStream {
 }
 
 #[derive(Debug)]
-struct ProgramPacketSequence {
-    counter: u8,
-    mpeg1_mpeg2_identifier: bool,
-    original_stuff_length: u8,
+pub struct ProgramPacketSequence {
+    pub counter: u8,
+    pub mpeg1_mpeg2_identifier: bool,
+    pub original_stuff_length: u8,
 }
 
 extract_bits!(read_program_packet_sequence_counter, u8, 1, 7);
@@ -1,12 +1,62 @@
+use std::{error, fmt};
+
+use crate::error::ErrorKind;
+
 #[derive(Debug)]
-pub struct MpegtsParseError(pub String);
+pub struct MpegtsParseError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub pid: Option<u16>,
+    pub offset: Option<usize>,
+}
+
+impl MpegtsParseError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            pid: None,
+            offset: None,
+        }
+    }
+
+    pub fn with_pid(mut self, pid: u16) -> Self {
+        self.pid.get_or_insert(pid);
+        self
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl fmt::Display for MpegtsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mpegts parse error ({}): {}", self.kind, self.message)?;
+
+        if let Some(pid) = self.pid {
+            write!(f, " in packet with pid {pid}")?;
+        }
+
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {offset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for MpegtsParseError {}
 
 #[macro_export]
 macro_rules! mpegts_assert {
     ($assert_value:expr, $msg:expr $(, $arg:expr)*) => {
         if !$assert_value {
-            let message = format!($msg $(, $arg)*);
-            return Err(MpegtsParseError(format!("mpegts parse error: {}", message)));
+            return Err($crate::mpegts::MpegtsParseError::new(
+                $crate::ErrorKind::InvalidData,
+                format!($msg $(, $arg)*),
+            ));
         }
     };
 }
@@ -20,7 +70,6 @@ macro_rules! extract_bits {
     };
 }
 
-
 #[macro_export]
 macro_rules! read_flag_bit {
     ($name:ident, $offset:expr) => {
@@ -29,7 +78,7 @@ macro_rules! read_flag_bit {
         }
     };
 }
- 
+
 extract_bits!(read_33b_first, u64, 28, 3);
 extract_bits!(read_33b_second, u64, 32, 15);
 extract_bits!(read_33b_third, u64, 48, 15);
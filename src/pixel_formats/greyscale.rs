@@ -7,6 +7,23 @@ pub struct Greyscale {
     pixels: Vec<Vec<u8>>,
 }
 
+impl Greyscale {
+    pub fn new(pixels: Vec<Vec<u8>>) -> Self {
+        Self {
+            bit_depth: 8,
+            pixels,
+        }
+    }
+
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+    }
+
+    pub fn pixels(&self) -> &[Vec<u8>] {
+        &self.pixels
+    }
+}
+
 impl From<Greyscale> for RGB24P {
     fn from(value: Greyscale) -> Self {
         Self {
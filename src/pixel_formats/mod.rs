@@ -1,6 +1,7 @@
-use greyscale::Greyscale;
 use crate::colors::Rgb;
 
+pub use greyscale::Greyscale;
+
 mod greyscale;
 
 pub trait PixelFormat: From<RGB24P> + Into<RGB24P> {
@@ -12,23 +13,17 @@ pub trait PixelFormat: From<RGB24P> + Into<RGB24P> {
 }
 
 pub struct RGB24P {
-    pixels: Vec<Vec<Rgb>>
+    pixels: Vec<Vec<Rgb>>,
 }
 
-impl PixelFormat for RGB24P {}
-
-pub struct YCbCr420p {}
-
-impl From<RGB24P> for YCbCr420p {
-    fn from(_value: RGB24P) -> Self {
-        todo!()
+impl RGB24P {
+    pub fn new(pixels: Vec<Vec<Rgb>>) -> Self {
+        Self { pixels }
     }
-}
 
-impl From<YCbCr420p> for RGB24P {
-    fn from(_value: YCbCr420p) -> Self {
-        todo!()
+    pub fn pixels(&self) -> &[Vec<Rgb>] {
+        &self.pixels
     }
 }
 
-impl PixelFormat for YCbCr420p {}
+impl PixelFormat for RGB24P {}
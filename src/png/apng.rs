@@ -0,0 +1,373 @@
+use std::mem;
+
+use crate::{
+    colors::{Rgba, Rgba16},
+    png_assert,
+};
+
+use super::{
+    chunks::animation::{AnimationControl, BlendOp, DisposeOp, Fctl, FrameControl},
+    consts::{FCTL_CHUNK_TYPE, FDAT_CHUNK_TYPE, IDAT_CHUNK_TYPE},
+    encode_images, read_chunks, to_8_bit_pixels, ColorSpace, DecodedPng, PartialPngConfig,
+    PngDecodeConfig, PngEncodeError, PngParseError, RenderingIntent,
+};
+
+// a zero denominator means the delay is in hundredths of a second
+const DEFAULT_DELAY_DENOMINATOR: u16 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PngFrame<P = Rgba> {
+    pub pixels: Vec<Vec<P>>,
+    pub x_offset: u32,
+    pub y_offset: u32,
+    pub delay_numerator: u16,
+    pub delay_denominator: u16,
+    pub dispose_op: DisposeOp,
+    pub blend_op: BlendOp,
+}
+
+impl<P> PngFrame<P> {
+    pub fn new(pixels: Vec<Vec<P>>) -> Self {
+        Self {
+            pixels,
+            x_offset: 0,
+            y_offset: 0,
+            delay_numerator: 0,
+            delay_denominator: DEFAULT_DELAY_DENOMINATOR,
+            dispose_op: DisposeOp::None,
+            blend_op: BlendOp::Source,
+        }
+    }
+
+    pub fn offset(mut self, x_offset: u32, y_offset: u32) -> Self {
+        self.x_offset = x_offset;
+        self.y_offset = y_offset;
+        self
+    }
+
+    pub fn delay(mut self, delay_numerator: u16, delay_denominator: u16) -> Self {
+        self.delay_numerator = delay_numerator;
+        self.delay_denominator = delay_denominator;
+        self
+    }
+
+    pub fn dispose_op(mut self, dispose_op: DisposeOp) -> Self {
+        self.dispose_op = dispose_op;
+        self
+    }
+
+    pub fn blend_op(mut self, blend_op: BlendOp) -> Self {
+        self.blend_op = blend_op;
+        self
+    }
+
+    pub fn delay_seconds(&self) -> f64 {
+        let delay_denominator = match self.delay_denominator {
+            0 => DEFAULT_DELAY_DENOMINATOR,
+            delay_denominator => delay_denominator,
+        };
+
+        self.delay_numerator as f64 / delay_denominator as f64
+    }
+
+    pub fn width(&self) -> u32 {
+        self.pixels.first().map_or(0, Vec::len) as u32
+    }
+
+    pub fn height(&self) -> u32 {
+        self.pixels.len() as u32
+    }
+
+    fn frame_control(&self) -> FrameControl {
+        FrameControl {
+            sequence_number: 0,
+            width: self.width(),
+            height: self.height(),
+            x_offset: self.x_offset,
+            y_offset: self.y_offset,
+            delay_numerator: self.delay_numerator,
+            delay_denominator: self.delay_denominator,
+            dispose_op: self.dispose_op,
+            blend_op: self.blend_op,
+        }
+    }
+
+    fn map_pixels<T>(self, map: impl Fn(Vec<Vec<P>>) -> Vec<Vec<T>>) -> PngFrame<T> {
+        PngFrame {
+            pixels: map(self.pixels),
+            x_offset: self.x_offset,
+            y_offset: self.y_offset,
+            delay_numerator: self.delay_numerator,
+            delay_denominator: self.delay_denominator,
+            dispose_op: self.dispose_op,
+            blend_op: self.blend_op,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DecodedApng<P = Rgba> {
+    // the default image shown by decoders without APNG support, along with the file's other chunks
+    pub image: DecodedPng<P>,
+    pub num_plays: u32,
+    pub frames: Vec<PngFrame<P>>,
+}
+
+pub fn decode_apng(bytes: &[u8]) -> Result<DecodedApng, PngParseError> {
+    decode_apng_with_config(bytes, PngDecodeConfig::new())
+}
+
+pub fn decode_apng16(bytes: &[u8]) -> Result<DecodedApng<Rgba16>, PngParseError> {
+    decode_apng16_with_config(bytes, PngDecodeConfig::new())
+}
+
+pub fn decode_apng_with_config(
+    bytes: &[u8],
+    config: PngDecodeConfig,
+) -> Result<DecodedApng, PngParseError> {
+    let decoded_apng = decode_apng16_with_config(bytes, config)?;
+    let image = decoded_apng.image;
+
+    Ok(DecodedApng {
+        image: DecodedPng {
+            pixels: to_8_bit_pixels(image.pixels),
+            text: image.text,
+            color_space: image.color_space,
+            metadata: image.metadata,
+            unknown_chunks: image.unknown_chunks,
+            warnings: image.warnings,
+        },
+        num_plays: decoded_apng.num_plays,
+        frames: decoded_apng
+            .frames
+            .into_iter()
+            .map(|frame| frame.map_pixels(to_8_bit_pixels))
+            .collect(),
+    })
+}
+
+pub fn decode_apng16_with_config(
+    bytes: &[u8],
+    config: PngDecodeConfig,
+) -> Result<DecodedApng<Rgba16>, PngParseError> {
+    let mut png_chunks = read_chunks(bytes, &config)?;
+    let image_data = mem::take(&mut png_chunks.image_data);
+    let frames_data = mem::take(&mut png_chunks.frames);
+    let (canvas_width, canvas_height) = (png_chunks.ihdr.width, png_chunks.ihdr.height);
+    let mut image = png_chunks.decode_image(
+        &image_data,
+        canvas_width,
+        canvas_height,
+        png_chunks.image_data_offset.unwrap_or(bytes.len()),
+        IDAT_CHUNK_TYPE,
+    )?;
+
+    // a png without an acTL chunk is a still image, and any fcTL chunks in it are ignored
+    let (num_plays, mut frames) = match png_chunks.animation_control {
+        Some(AnimationControl {
+            num_frames,
+            num_plays,
+        }) => {
+            png_assert!(
+                frames_data.len() == num_frames as usize,
+                format!(
+                    "acTL chunk declares {num_frames} frames but the file has {}",
+                    frames_data.len()
+                )
+            );
+
+            let frames = frames_data
+                .into_iter()
+                .map(|frame_data| {
+                    let frame_control = frame_data.frame_control;
+                    let pixels = if frame_data.is_default_image {
+                        check_covers_canvas(&frame_control, canvas_width, canvas_height).map_err(
+                            |err| err.in_chunk(FCTL_CHUNK_TYPE).at_offset(frame_data.offset),
+                        )?;
+
+                        image.clone()
+                    } else {
+                        png_chunks.decode_image(
+                            &frame_data.compressed_data,
+                            frame_control.width,
+                            frame_control.height,
+                            frame_data.offset,
+                            FDAT_CHUNK_TYPE,
+                        )?
+                    };
+
+                    Ok(PngFrame {
+                        pixels,
+                        x_offset: frame_control.x_offset,
+                        y_offset: frame_control.y_offset,
+                        delay_numerator: frame_control.delay_numerator,
+                        delay_denominator: frame_control.delay_denominator,
+                        dispose_op: frame_control.dispose_op,
+                        blend_op: frame_control.blend_op,
+                    })
+                })
+                .collect::<Result<Vec<PngFrame<Rgba16>>, PngParseError>>()?;
+
+            (num_plays, frames)
+        }
+        None => (0, vec![PngFrame::new(image.clone())]),
+    };
+
+    if config.convert_to_srgb && png_chunks.color_space.convert_to_srgb(&mut image) {
+        for frame in frames.iter_mut() {
+            png_chunks.color_space.convert_to_srgb(&mut frame.pixels);
+        }
+        png_chunks.color_space = ColorSpace::srgb(RenderingIntent::Perceptual);
+    }
+
+    if config.composite_frames {
+        frames = composite_frames(frames, canvas_width as usize, canvas_height as usize);
+    }
+
+    Ok(DecodedApng {
+        image: png_chunks.into_decoded_png(image),
+        num_plays,
+        frames,
+    })
+}
+
+fn check_covers_canvas(
+    frame_control: &FrameControl,
+    canvas_width: u32,
+    canvas_height: u32,
+) -> Result<(), PngParseError> {
+    png_assert!(
+        frame_control.x_offset == 0
+            && frame_control.y_offset == 0
+            && frame_control.width == canvas_width
+            && frame_control.height == canvas_height,
+        "the frame stored in IDAT must cover the whole canvas"
+    );
+
+    Ok(())
+}
+
+// renders every frame onto the canvas, so each returned frame is a full image that replaces the
+// previous one
+fn composite_frames(
+    frames: Vec<PngFrame<Rgba16>>,
+    canvas_width: usize,
+    canvas_height: usize,
+) -> Vec<PngFrame<Rgba16>> {
+    let transparent_black = Rgba16::new(0, 0, 0, 0);
+    let mut canvas = vec![vec![transparent_black.clone(); canvas_width]; canvas_height];
+
+    frames
+        .into_iter()
+        .map(|frame| {
+            let (x_offset, y_offset) = (frame.x_offset as usize, frame.y_offset as usize);
+            let previous_canvas = match frame.dispose_op {
+                DisposeOp::Previous => Some(canvas.clone()),
+                _ => None,
+            };
+
+            for (canvas_row, frame_row) in canvas[y_offset..].iter_mut().zip(frame.pixels.iter()) {
+                for (canvas_pixel, frame_pixel) in
+                    canvas_row[x_offset..].iter_mut().zip(frame_row.iter())
+                {
+                    *canvas_pixel = match frame.blend_op {
+                        BlendOp::Source => frame_pixel.clone(),
+                        BlendOp::Over => blend_over(frame_pixel, canvas_pixel),
+                    };
+                }
+            }
+
+            let composited_frame =
+                PngFrame::new(canvas.clone()).delay(frame.delay_numerator, frame.delay_denominator);
+
+            match previous_canvas {
+                Some(previous_canvas) => canvas = previous_canvas,
+                None if frame.dispose_op == DisposeOp::Background => {
+                    for canvas_row in canvas[y_offset..].iter_mut().take(frame.pixels.len()) {
+                        let frame_width = frame.width() as usize;
+
+                        canvas_row[x_offset..x_offset + frame_width]
+                            .fill(transparent_black.clone());
+                    }
+                }
+                None => {}
+            }
+
+            composited_frame
+        })
+        .collect()
+}
+
+fn blend_over(source: &Rgba16, destination: &Rgba16) -> Rgba16 {
+    let max = u16::MAX as u64;
+    let source_alpha = source.a as u64;
+    let destination_alpha = destination.a as u64 * (max - source_alpha) / max;
+    let alpha = source_alpha + destination_alpha;
+
+    if alpha == 0 {
+        return Rgba16::new(0, 0, 0, 0);
+    }
+
+    let blend = |source_value: u16, destination_value: u16| {
+        ((source_value as u64 * source_alpha + destination_value as u64 * destination_alpha)
+            / alpha) as u16
+    };
+
+    Rgba16::new(
+        blend(source.r, destination.r),
+        blend(source.g, destination.g),
+        blend(source.b, destination.b),
+        alpha as u16,
+    )
+}
+
+pub fn encode_apng(
+    frames: Vec<PngFrame>,
+    num_plays: u32,
+    partial_config: PartialPngConfig,
+) -> Result<Vec<u8>, PngEncodeError> {
+    let frames = frames
+        .into_iter()
+        .map(|frame| {
+            frame.map_pixels(|pixels| {
+                pixels
+                    .into_iter()
+                    .map(|row| row.iter().map(Rgba16::from).collect())
+                    .collect()
+            })
+        })
+        .collect();
+
+    encode_apng16(frames, num_plays, partial_config)
+}
+
+pub fn encode_apng16(
+    frames: Vec<PngFrame<Rgba16>>,
+    num_plays: u32,
+    partial_config: PartialPngConfig,
+) -> Result<Vec<u8>, PngEncodeError> {
+    let Some(first_frame) = frames.first() else {
+        return Err(PngEncodeError::EmptyImage);
+    };
+    let (canvas_width, canvas_height) = (first_frame.width(), first_frame.height());
+    let frame_controls: Vec<FrameControl> = frames.iter().map(PngFrame::frame_control).collect();
+
+    check_covers_canvas(&frame_controls[0], canvas_width, canvas_height)?;
+
+    for frame_control in frame_controls.iter() {
+        Fctl::check_fits_canvas(frame_control, canvas_width, canvas_height)?;
+    }
+
+    let animation_control = AnimationControl {
+        num_frames: frames.len() as u32,
+        num_plays,
+    };
+    let images = frames.into_iter().map(|frame| frame.pixels).collect();
+
+    Ok(encode_images(
+        images,
+        Some((animation_control, frame_controls)),
+        partial_config,
+    )?
+    .bytes)
+}
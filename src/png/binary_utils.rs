@@ -1,17 +1,47 @@
-pub fn read_u32(offset: &mut usize, bytes: &[u8]) -> u32 {
-    u32::from_be_bytes(read_bytes(offset, bytes, 4).try_into().unwrap())
+use crate::error::ErrorKind;
+
+use super::PngParseError;
+
+pub fn read_u32(offset: &mut usize, bytes: &[u8]) -> Result<u32, PngParseError> {
+    Ok(u32::from_be_bytes(read_array(offset, bytes)?))
+}
+
+pub fn read_u16(offset: &mut usize, bytes: &[u8]) -> Result<u16, PngParseError> {
+    Ok(u16::from_be_bytes(read_array(offset, bytes)?))
+}
+
+pub fn read_byte(offset: &mut usize, bytes: &[u8]) -> Result<u8, PngParseError> {
+    let [byte] = read_array(offset, bytes)?;
+
+    Ok(byte)
 }
 
-pub fn read_byte(offset: &mut usize, bytes: &[u8]) -> u8 {
-    let byte = bytes[*offset];
-    *offset += 1;
+fn read_array<const N: usize>(offset: &mut usize, bytes: &[u8]) -> Result<[u8; N], PngParseError> {
+    let mut array = [0; N];
+    array.copy_from_slice(read_bytes(offset, bytes, N)?);
 
-    byte
+    Ok(array)
 }
 
-pub fn read_bytes<'a>(offset: &mut usize, bytes: &'a [u8], size: usize) -> &'a [u8] {
-    let result = &bytes[*offset..*offset + size];
+// running out of bytes is an error rather than a panic, the caller adds where it happened
+pub fn read_bytes<'a>(
+    offset: &mut usize,
+    bytes: &'a [u8],
+    size: usize,
+) -> Result<&'a [u8], PngParseError> {
+    let result = offset
+        .checked_add(size)
+        .and_then(|end| bytes.get(*offset..end))
+        .ok_or_else(|| {
+            PngParseError::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "Expected {size} more bytes, found {}",
+                    bytes.len().saturating_sub(*offset)
+                ),
+            )
+        })?;
     *offset += size;
 
-    result
+    Ok(result)
 }
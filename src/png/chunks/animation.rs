@@ -0,0 +1,238 @@
+use crate::{
+    error::ErrorKind,
+    png::{
+        binary_utils::{read_byte, read_u16, read_u32},
+        consts::{ACTL_CHUNK_TYPE, FCTL_CHUNK_TYPE, FDAT_CHUNK_TYPE, IDAT_CHUNK_MAX_SIZE},
+        crc::CrcCalculator,
+        PngParseError,
+    },
+    png_assert,
+};
+
+use super::Chunk;
+
+const SEQUENCE_NUMBER_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisposeOp {
+    #[default]
+    None,
+    Background,
+    Previous,
+}
+
+impl TryFrom<u8> for DisposeOp {
+    type Error = PngParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Background),
+            2 => Ok(Self::Previous),
+            _ => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Unrecognized dispose op {value}"),
+            )),
+        }
+    }
+}
+
+impl From<&DisposeOp> for u8 {
+    fn from(value: &DisposeOp) -> Self {
+        match value {
+            DisposeOp::None => 0,
+            DisposeOp::Background => 1,
+            DisposeOp::Previous => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlendOp {
+    #[default]
+    Source,
+    Over,
+}
+
+impl TryFrom<u8> for BlendOp {
+    type Error = PngParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Source),
+            1 => Ok(Self::Over),
+            _ => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Unrecognized blend op {value}"),
+            )),
+        }
+    }
+}
+
+impl From<&BlendOp> for u8 {
+    fn from(value: &BlendOp) -> Self {
+        match value {
+            BlendOp::Source => 0,
+            BlendOp::Over => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationControl {
+    pub num_frames: u32,
+    pub num_plays: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameControl {
+    pub sequence_number: u32,
+    pub width: u32,
+    pub height: u32,
+    pub x_offset: u32,
+    pub y_offset: u32,
+    pub delay_numerator: u16,
+    pub delay_denominator: u16,
+    pub dispose_op: DisposeOp,
+    pub blend_op: BlendOp,
+}
+
+pub struct Actl;
+
+impl Actl {
+    pub fn encode_animation_control(
+        animation_control: &AnimationControl,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&animation_control.num_frames.to_be_bytes());
+        data.extend_from_slice(&animation_control.num_plays.to_be_bytes());
+        let chunk = Chunk::new(ACTL_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_animation_control(bytes: &[u8]) -> Result<AnimationControl, PngParseError> {
+        png_assert!(
+            bytes.len() == 8,
+            format!("invalid acTL chunk size {}, expected 8", bytes.len())
+        );
+        let mut offset = 0;
+        let animation_control = AnimationControl {
+            num_frames: read_u32(&mut offset, bytes)?,
+            num_plays: read_u32(&mut offset, bytes)?,
+        };
+        png_assert!(
+            animation_control.num_frames > 0,
+            "acTL chunk must declare at least one frame"
+        );
+
+        Ok(animation_control)
+    }
+}
+
+pub struct Fctl;
+
+impl Fctl {
+    pub fn encode_frame_control(
+        frame_control: &FrameControl,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(26);
+        data.extend_from_slice(&frame_control.sequence_number.to_be_bytes());
+        data.extend_from_slice(&frame_control.width.to_be_bytes());
+        data.extend_from_slice(&frame_control.height.to_be_bytes());
+        data.extend_from_slice(&frame_control.x_offset.to_be_bytes());
+        data.extend_from_slice(&frame_control.y_offset.to_be_bytes());
+        data.extend_from_slice(&frame_control.delay_numerator.to_be_bytes());
+        data.extend_from_slice(&frame_control.delay_denominator.to_be_bytes());
+        data.push((&frame_control.dispose_op).into());
+        data.push((&frame_control.blend_op).into());
+        let chunk = Chunk::new(FCTL_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_frame_control(bytes: &[u8]) -> Result<FrameControl, PngParseError> {
+        png_assert!(
+            bytes.len() == 26,
+            format!("invalid fcTL chunk size {}, expected 26", bytes.len())
+        );
+        let mut offset = 0;
+        let frame_control = FrameControl {
+            sequence_number: read_u32(&mut offset, bytes)?,
+            width: read_u32(&mut offset, bytes)?,
+            height: read_u32(&mut offset, bytes)?,
+            x_offset: read_u32(&mut offset, bytes)?,
+            y_offset: read_u32(&mut offset, bytes)?,
+            delay_numerator: read_u16(&mut offset, bytes)?,
+            delay_denominator: read_u16(&mut offset, bytes)?,
+            dispose_op: DisposeOp::try_from(read_byte(&mut offset, bytes)?)?,
+            blend_op: BlendOp::try_from(read_byte(&mut offset, bytes)?)?,
+        };
+        png_assert!(
+            frame_control.width > 0 && frame_control.height > 0,
+            format!(
+                "frame size {}x{} must not be empty",
+                frame_control.width, frame_control.height
+            )
+        );
+
+        Ok(frame_control)
+    }
+
+    pub fn check_fits_canvas(
+        frame_control: &FrameControl,
+        canvas_width: u32,
+        canvas_height: u32,
+    ) -> Result<(), PngParseError> {
+        let fits_horizontally = frame_control
+            .x_offset
+            .checked_add(frame_control.width)
+            .is_some_and(|right| right <= canvas_width);
+        let fits_vertically = frame_control
+            .y_offset
+            .checked_add(frame_control.height)
+            .is_some_and(|bottom| bottom <= canvas_height);
+        png_assert!(
+            fits_horizontally && fits_vertically,
+            format!(
+                "frame {}x{} at ({}, {}) does not fit in the {canvas_width}x{canvas_height} canvas",
+                frame_control.width,
+                frame_control.height,
+                frame_control.x_offset,
+                frame_control.y_offset
+            )
+        );
+
+        Ok(())
+    }
+}
+
+pub struct Fdat;
+
+impl Fdat {
+    pub const MAX_DATA_SIZE: usize = IDAT_CHUNK_MAX_SIZE as usize - SEQUENCE_NUMBER_LENGTH;
+
+    pub fn encode_bytes(
+        sequence_number: u32,
+        bytes: &[u8],
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(SEQUENCE_NUMBER_LENGTH + bytes.len());
+        data.extend_from_slice(&sequence_number.to_be_bytes());
+        data.extend_from_slice(bytes);
+        let chunk = Chunk::new(FDAT_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_bytes(bytes: &[u8]) -> Result<(u32, &[u8]), PngParseError> {
+        png_assert!(
+            bytes.len() >= SEQUENCE_NUMBER_LENGTH,
+            "fdAT chunk is missing its sequence number"
+        );
+
+        Ok((read_u32(&mut 0, bytes)?, &bytes[SEQUENCE_NUMBER_LENGTH..]))
+    }
+}
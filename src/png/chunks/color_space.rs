@@ -0,0 +1,347 @@
+use crate::{
+    binary::latin1::{bytes_to_latin1, latin1_to_bytes},
+    colors::Rgba16,
+    error::ErrorKind,
+    png::{
+        binary_utils::{read_byte, read_u32},
+        consts::{CHRM_CHUNK_TYPE, GAMA_CHUNK_TYPE, ICCP_CHUNK_TYPE, SRGB_CHUNK_TYPE},
+        crc::CrcCalculator,
+        deflate::{decode_zlib, ZlibEncoder},
+        CompressionLevel, PngParseError,
+    },
+    png_assert,
+};
+
+use super::{
+    text::{
+        check_compression_method, check_keyword_validity, split_at_null, NULL_SEPARATOR,
+        ZLIB_COMPRESSION_METHOD,
+    },
+    Chunk,
+};
+
+// gAMA and cHRM store their values multiplied by 100000
+const FIXED_POINT_SCALE: f64 = 100_000.;
+const SRGB_GAMMA: u32 = 45_455;
+const SRGB_CHROMATICITIES: Chromaticities = Chromaticities {
+    white_x: 31_270,
+    white_y: 32_900,
+    red_x: 64_000,
+    red_y: 33_000,
+    green_x: 30_000,
+    green_y: 60_000,
+    blue_x: 15_000,
+    blue_y: 6_000,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chromaticities {
+    pub white_x: u32,
+    pub white_y: u32,
+    pub red_x: u32,
+    pub red_y: u32,
+    pub green_x: u32,
+    pub green_y: u32,
+    pub blue_x: u32,
+    pub blue_y: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderingIntent {
+    Perceptual,
+    RelativeColorimetric,
+    Saturation,
+    AbsoluteColorimetric,
+}
+
+impl TryFrom<u8> for RenderingIntent {
+    type Error = PngParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Perceptual),
+            1 => Ok(Self::RelativeColorimetric),
+            2 => Ok(Self::Saturation),
+            3 => Ok(Self::AbsoluteColorimetric),
+            _ => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Unrecognized rendering intent {value}"),
+            )),
+        }
+    }
+}
+
+impl From<&RenderingIntent> for u8 {
+    fn from(value: &RenderingIntent) -> Self {
+        match value {
+            RenderingIntent::Perceptual => 0,
+            RenderingIntent::RelativeColorimetric => 1,
+            RenderingIntent::Saturation => 2,
+            RenderingIntent::AbsoluteColorimetric => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IccProfile {
+    pub name: String,
+    pub profile: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColorSpace {
+    pub gamma: Option<u32>,
+    pub chromaticities: Option<Chromaticities>,
+    pub srgb_intent: Option<RenderingIntent>,
+    pub icc_profile: Option<IccProfile>,
+}
+
+impl ColorSpace {
+    pub fn srgb(rendering_intent: RenderingIntent) -> Self {
+        Self {
+            gamma: Some(SRGB_GAMMA),
+            chromaticities: Some(SRGB_CHROMATICITIES),
+            srgb_intent: Some(rendering_intent),
+            icc_profile: None,
+        }
+    }
+
+    pub fn decode_chunk(&mut self, chunk_type: &[u8], bytes: &[u8]) -> Result<(), PngParseError> {
+        let is_duplicate = match chunk_type {
+            GAMA_CHUNK_TYPE => self.gamma.replace(Gama::decode_gamma(bytes)?).is_some(),
+            CHRM_CHUNK_TYPE => self
+                .chromaticities
+                .replace(Chrm::decode_chromaticities(bytes)?)
+                .is_some(),
+            SRGB_CHUNK_TYPE => self
+                .srgb_intent
+                .replace(Srgb::decode_rendering_intent(bytes)?)
+                .is_some(),
+            _ => self
+                .icc_profile
+                .replace(Iccp::decode_profile(bytes)?)
+                .is_some(),
+        };
+        png_assert!(
+            !is_duplicate,
+            format!(
+                "{} chunk appears more than once",
+                String::from_utf8_lossy(chunk_type)
+            )
+        );
+
+        Ok(())
+    }
+
+    pub fn encode_chunks(
+        &self,
+        compression_level: CompressionLevel,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        if let Some(ref icc_profile) = self.icc_profile {
+            bytes.extend_from_slice(&Iccp::encode_profile(
+                icc_profile,
+                compression_level,
+                crc_calculator,
+            ));
+        }
+
+        if let Some(ref rendering_intent) = self.srgb_intent {
+            bytes.extend_from_slice(&Srgb::encode_rendering_intent(
+                rendering_intent,
+                crc_calculator,
+            ));
+        }
+
+        if let Some(gamma) = self.gamma {
+            bytes.extend_from_slice(&Gama::encode_gamma(gamma, crc_calculator));
+        }
+
+        if let Some(ref chromaticities) = self.chromaticities {
+            bytes.extend_from_slice(&Chrm::encode_chromaticities(chromaticities, crc_calculator));
+        }
+
+        bytes
+    }
+
+    // sRGB and iCCP take precedence over gAMA, so only a lone gAMA describes samples that need
+    // converting
+    fn gamma_to_convert(&self) -> Option<u32> {
+        match (self.gamma, &self.srgb_intent, &self.icc_profile) {
+            (Some(gamma), None, None) if gamma != 0 && gamma != SRGB_GAMMA => Some(gamma),
+            _ => None,
+        }
+    }
+
+    pub fn convert_to_srgb(&self, pixels: &mut [Vec<Rgba16>]) -> bool {
+        let Some(lookup_table) = self.srgb_lookup_table() else {
+            return false;
+        };
+
+        for pixel in pixels.iter_mut().flatten() {
+            apply_lookup_table(pixel, &lookup_table);
+        }
+
+        true
+    }
+
+    // maps every 16 bit sample to its sRGB value, or None when no conversion is needed
+    pub fn srgb_lookup_table(&self) -> Option<Vec<u16>> {
+        let gamma = self.gamma_to_convert()?;
+        let decoding_exponent = FIXED_POINT_SCALE / gamma as f64;
+
+        Some(
+            (0..=u16::MAX)
+                .map(|sample| {
+                    let linear = (sample as f64 / u16::MAX as f64).powf(decoding_exponent);
+
+                    (linear_to_srgb(linear) * u16::MAX as f64).round() as u16
+                })
+                .collect(),
+        )
+    }
+}
+
+pub fn apply_lookup_table(pixel: &mut Rgba16, lookup_table: &[u16]) {
+    pixel.r = lookup_table[pixel.r as usize];
+    pixel.g = lookup_table[pixel.g as usize];
+    pixel.b = lookup_table[pixel.b as usize];
+}
+
+fn linear_to_srgb(linear: f64) -> f64 {
+    if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1. / 2.4) - 0.055
+    }
+}
+
+pub struct Gama;
+
+impl Gama {
+    pub fn encode_gamma(gamma: u32, crc_calculator: &mut CrcCalculator) -> Vec<u8> {
+        let data = gamma.to_be_bytes();
+        let chunk = Chunk::new(GAMA_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_gamma(bytes: &[u8]) -> Result<u32, PngParseError> {
+        png_assert!(
+            bytes.len() == 4,
+            format!("invalid gAMA chunk size {}, expected 4", bytes.len())
+        );
+
+        read_u32(&mut 0, bytes)
+    }
+}
+
+pub struct Chrm;
+
+impl Chrm {
+    pub fn encode_chromaticities(
+        chromaticities: &Chromaticities,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let data: Vec<u8> = [
+            chromaticities.white_x,
+            chromaticities.white_y,
+            chromaticities.red_x,
+            chromaticities.red_y,
+            chromaticities.green_x,
+            chromaticities.green_y,
+            chromaticities.blue_x,
+            chromaticities.blue_y,
+        ]
+        .iter()
+        .flat_map(|value| value.to_be_bytes())
+        .collect();
+        let chunk = Chunk::new(CHRM_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_chromaticities(bytes: &[u8]) -> Result<Chromaticities, PngParseError> {
+        png_assert!(
+            bytes.len() == 32,
+            format!("invalid cHRM chunk size {}, expected 32", bytes.len())
+        );
+        let mut offset = 0;
+
+        Ok(Chromaticities {
+            white_x: read_u32(&mut offset, bytes)?,
+            white_y: read_u32(&mut offset, bytes)?,
+            red_x: read_u32(&mut offset, bytes)?,
+            red_y: read_u32(&mut offset, bytes)?,
+            green_x: read_u32(&mut offset, bytes)?,
+            green_y: read_u32(&mut offset, bytes)?,
+            blue_x: read_u32(&mut offset, bytes)?,
+            blue_y: read_u32(&mut offset, bytes)?,
+        })
+    }
+}
+
+pub struct Srgb;
+
+impl Srgb {
+    pub fn encode_rendering_intent(
+        rendering_intent: &RenderingIntent,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let data = [rendering_intent.into()];
+        let chunk = Chunk::new(SRGB_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_rendering_intent(bytes: &[u8]) -> Result<RenderingIntent, PngParseError> {
+        png_assert!(
+            bytes.len() == 1,
+            format!("invalid sRGB chunk size {}, expected 1", bytes.len())
+        );
+
+        RenderingIntent::try_from(read_byte(&mut 0, bytes)?)
+    }
+}
+
+pub struct Iccp;
+
+impl Iccp {
+    pub fn encode_profile(
+        icc_profile: &IccProfile,
+        compression_level: CompressionLevel,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(compression_level);
+        encoder.write_bytes(&icc_profile.profile);
+
+        let mut data = latin1_to_bytes(&icc_profile.name);
+        data.push(NULL_SEPARATOR);
+        data.push(ZLIB_COMPRESSION_METHOD);
+        data.extend_from_slice(&encoder.flush());
+        let chunk = Chunk::new(ICCP_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_profile(bytes: &[u8]) -> Result<IccProfile, PngParseError> {
+        let (name, rest) = split_at_null(bytes, "profile name")?;
+        let name = bytes_to_latin1(name);
+        check_keyword_validity(&name)?;
+        png_assert!(
+            !rest.is_empty(),
+            "iCCP chunk is missing its compression method"
+        );
+        check_compression_method(rest[0])?;
+        let profile = decode_zlib(&rest[1..]).map_err(|err| {
+            let mut png_error = PngParseError::from(err);
+            png_error.message = "failed to decompress ICC profile".to_string();
+
+            png_error
+        })?;
+
+        Ok(IccProfile { name, profile })
+    }
+}
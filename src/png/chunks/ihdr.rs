@@ -1,4 +1,5 @@
 use crate::{
+    error::ErrorKind,
     png::{
         binary_utils::{read_byte, read_u32},
         color_type::ColorType,
@@ -120,13 +121,14 @@ impl Ihdr {
         );
 
         let mut offset = 0;
-        let width = read_u32(&mut offset, chunk.chunk_data);
-        let height = read_u32(&mut offset, chunk.chunk_data);
-        let bit_depth = read_byte(&mut offset, chunk.chunk_data);
-        let color_type = ColorType::try_from(read_byte(&mut offset, chunk.chunk_data))?;
-        let compression_method = CompressionMethod::from(read_byte(&mut offset, chunk.chunk_data));
-        let filter_method = FilterMethod::from(read_byte(&mut offset, chunk.chunk_data));
-        let interlace_method = InterlaceMethod::try_from(read_byte(&mut offset, chunk.chunk_data))?;
+        let width = read_u32(&mut offset, chunk.chunk_data)?;
+        let height = read_u32(&mut offset, chunk.chunk_data)?;
+        let bit_depth = read_byte(&mut offset, chunk.chunk_data)?;
+        let color_type = ColorType::try_from(read_byte(&mut offset, chunk.chunk_data)?)?;
+        let compression_method = CompressionMethod::from(read_byte(&mut offset, chunk.chunk_data)?);
+        let filter_method = FilterMethod::from(read_byte(&mut offset, chunk.chunk_data)?);
+        let interlace_method =
+            InterlaceMethod::try_from(read_byte(&mut offset, chunk.chunk_data)?)?;
 
         Ok(Self {
             width,
@@ -143,11 +145,14 @@ impl Ihdr {
         self.color_type
             .check_bit_depth_validty(self.bit_depth)
             .map_err(|err| {
-                PngParseError(format!(
-                    "Invalid bit depth {}, for color type {}",
-                    err.1,
-                    Into::<u8>::into(&err.0),
-                ))
+                PngParseError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Invalid bit depth {}, for color type {}",
+                        err.1,
+                        Into::<u8>::into(&err.0),
+                    ),
+                )
             })
     }
 
@@ -0,0 +1,763 @@
+use std::collections::HashMap;
+
+use crate::{
+    binary::latin1::{bytes_to_latin1, latin1_to_bytes},
+    colors::{Rgb16, Rgba, Rgba16},
+    error::ErrorKind,
+    png::{
+        binary_utils::{read_byte, read_u16, read_u32},
+        color_type::ColorType,
+        consts::{
+            BKGD_CHUNK_TYPE, EXIF_CHUNK_TYPE, HIST_CHUNK_TYPE, PHYS_CHUNK_TYPE, SBIT_CHUNK_TYPE,
+            SPLT_CHUNK_TYPE, TIME_CHUNK_TYPE,
+        },
+        crc::CrcCalculator,
+        PngEncodeError, PngParseError,
+    },
+    png_assert,
+};
+
+use super::{
+    text::{check_keyword_validity, split_at_null, NULL_SEPARATOR},
+    Chunk,
+};
+
+const INCHES_PER_METER: f64 = 39.3701;
+const EXIF_ORIENTATION_TAG: u16 = 0x0112;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhysicalUnit {
+    Unknown,
+    Meter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhysicalDimensions {
+    pub pixels_per_unit_x: u32,
+    pub pixels_per_unit_y: u32,
+    pub unit: PhysicalUnit,
+}
+
+impl PhysicalDimensions {
+    pub fn from_dpi(dpi_x: f64, dpi_y: f64) -> Self {
+        Self {
+            pixels_per_unit_x: (dpi_x * INCHES_PER_METER).round() as u32,
+            pixels_per_unit_y: (dpi_y * INCHES_PER_METER).round() as u32,
+            unit: PhysicalUnit::Meter,
+        }
+    }
+
+    pub fn dpi(&self) -> Option<(f64, f64)> {
+        match self.unit {
+            PhysicalUnit::Meter => Some((
+                self.pixels_per_unit_x as f64 / INCHES_PER_METER,
+                self.pixels_per_unit_y as f64 / INCHES_PER_METER,
+            )),
+            PhysicalUnit::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModificationTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignificantBits {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: Option<u8>,
+}
+
+impl SignificantBits {
+    // the bits each channel of these colors actually uses, out of 16
+    pub fn from_colors(colors: &[Rgba16], has_alpha_channel: bool) -> Self {
+        let channel_bits = |channel: fn(&Rgba16) -> u16| {
+            colors
+                .iter()
+                .map(|color| count_significant_bits(channel(color)))
+                .max()
+                .unwrap_or(1)
+        };
+
+        Self {
+            red: channel_bits(|color| color.r),
+            green: channel_bits(|color| color.g),
+            blue: channel_bits(|color| color.b),
+            alpha: has_alpha_channel.then(|| channel_bits(|color| color.a)),
+        }
+    }
+
+    pub fn is_below(&self, sample_depth: u8) -> bool {
+        [self.red, self.green, self.blue]
+            .into_iter()
+            .chain(self.alpha)
+            .any(|bits| bits < sample_depth)
+    }
+}
+
+// the fewest high bits of a 16 bit value that scale back up to exactly that value
+fn count_significant_bits(value: u16) -> u8 {
+    (1..=16)
+        .find(|&bits| {
+            let max_sample = (1u32 << bits) - 1;
+            let sample = value as u32 >> (16 - bits);
+
+            (sample * u16::MAX as u32 + max_sample / 2) / max_sample == value as u32
+        })
+        .unwrap_or(16)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuggestedPaletteEntry {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+    pub frequency: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedPalette {
+    pub name: String,
+    pub sample_depth: u8,
+    pub entries: Vec<SuggestedPaletteEntry>,
+}
+
+impl SuggestedPalette {
+    // the name has to be a keyword, and its samples are written with 8 or 16 bits
+    pub fn check_validity(&self) -> Result<(), PngParseError> {
+        check_keyword_validity(&self.name)?;
+        png_assert!(
+            matches!(self.sample_depth, 8 | 16),
+            format!(
+                "invalid sPLT sample depth {}, expected 8 or 16",
+                self.sample_depth
+            )
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PngMetadata {
+    pub physical_dimensions: Option<PhysicalDimensions>,
+    pub modification_time: Option<ModificationTime>,
+    pub background: Option<Rgb16>,
+    pub significant_bits: Option<SignificantBits>,
+    pub histogram: Option<Vec<u16>>,
+    pub suggested_palettes: Vec<SuggestedPalette>,
+    pub exif: Option<Vec<u8>>,
+}
+
+impl PngMetadata {
+    pub fn exif_orientation(&self) -> Option<u16> {
+        let exif = self.exif.as_ref()?;
+        let is_little_endian = match exif.get(..4)? {
+            b"II*\0" => true,
+            b"MM\0*" => false,
+            _ => return None,
+        };
+        let read_u16 = |offset: usize| {
+            let bytes: [u8; 2] = exif.get(offset..offset + 2)?.try_into().ok()?;
+
+            Some(if is_little_endian {
+                u16::from_le_bytes(bytes)
+            } else {
+                u16::from_be_bytes(bytes)
+            })
+        };
+        let read_u32 = |offset: usize| {
+            let bytes: [u8; 4] = exif.get(offset..offset + 4)?.try_into().ok()?;
+
+            Some(if is_little_endian {
+                u32::from_le_bytes(bytes)
+            } else {
+                u32::from_be_bytes(bytes)
+            })
+        };
+
+        let ifd_offset = read_u32(4)? as usize;
+        let number_of_entries = read_u16(ifd_offset)? as usize;
+
+        (0..number_of_entries)
+            .map(|entry| ifd_offset + 2 + entry * 12)
+            .find(|entry_offset| read_u16(*entry_offset) == Some(EXIF_ORIENTATION_TAG))
+            .and_then(|entry_offset| read_u16(entry_offset + 8))
+    }
+
+    pub fn decode_chunk(
+        &mut self,
+        chunk_type: &[u8],
+        bytes: &[u8],
+        color_type: ColorType,
+        bit_depth: u8,
+        palette: &Option<Vec<Rgba>>,
+    ) -> Result<(), PngParseError> {
+        let is_duplicate = match chunk_type {
+            PHYS_CHUNK_TYPE => self
+                .physical_dimensions
+                .replace(Phys::decode_physical_dimensions(bytes)?)
+                .is_some(),
+            TIME_CHUNK_TYPE => self
+                .modification_time
+                .replace(Time::decode_modification_time(bytes)?)
+                .is_some(),
+            BKGD_CHUNK_TYPE => self
+                .background
+                .replace(Bkgd::decode_background(
+                    bytes, color_type, bit_depth, palette,
+                )?)
+                .is_some(),
+            SBIT_CHUNK_TYPE => self
+                .significant_bits
+                .replace(Sbit::decode_significant_bits(bytes, color_type, bit_depth)?)
+                .is_some(),
+            HIST_CHUNK_TYPE => self
+                .histogram
+                .replace(Hist::decode_histogram(bytes, palette)?)
+                .is_some(),
+            SPLT_CHUNK_TYPE => {
+                self.suggested_palettes
+                    .push(Splt::decode_suggested_palette(bytes)?);
+                false
+            }
+            _ => self.exif.replace(bytes.to_vec()).is_some(),
+        };
+        png_assert!(
+            !is_duplicate,
+            format!(
+                "{} chunk appears more than once",
+                String::from_utf8_lossy(chunk_type)
+            )
+        );
+
+        Ok(())
+    }
+
+    // chunks that have to come before PLTE
+    pub fn encode_chunks_before_palette(
+        &self,
+        color_type: ColorType,
+        bit_depth: u8,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        match self.significant_bits {
+            Some(ref significant_bits) => Sbit::encode_significant_bits(
+                significant_bits,
+                color_type,
+                bit_depth,
+                crc_calculator,
+            ),
+            None => Vec::new(),
+        }
+    }
+
+    // chunks that have to come after PLTE and before IDAT
+    pub fn encode_chunks_before_image_data(
+        &self,
+        color_type: ColorType,
+        bit_depth: u8,
+        palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
+        palette_frequencies: &Option<Vec<u16>>,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Result<Vec<u8>, PngEncodeError> {
+        let mut bytes = Vec::new();
+
+        if let Some(ref background) = self.background {
+            bytes.extend_from_slice(&Bkgd::encode_background(
+                background,
+                color_type,
+                bit_depth,
+                palette,
+                crc_calculator,
+            )?);
+        }
+
+        // the encoder builds its own palette, so the histogram is recounted rather than copied
+        if let (Some(_), Some(palette_frequencies)) = (&self.histogram, palette_frequencies) {
+            bytes.extend_from_slice(&Hist::encode_histogram(palette_frequencies, crc_calculator));
+        }
+
+        if let Some(ref physical_dimensions) = self.physical_dimensions {
+            bytes.extend_from_slice(&Phys::encode_physical_dimensions(
+                physical_dimensions,
+                crc_calculator,
+            ));
+        }
+
+        for suggested_palette in self.suggested_palettes.iter() {
+            bytes.extend_from_slice(&Splt::encode_suggested_palette(
+                suggested_palette,
+                crc_calculator,
+            ));
+        }
+
+        if let Some(ref exif) = self.exif {
+            bytes.extend_from_slice(&Chunk::new(EXIF_CHUNK_TYPE, exif, crc_calculator).to_bytes());
+        }
+
+        Ok(bytes)
+    }
+
+    pub fn encode_chunks_after_image_data(&self, crc_calculator: &mut CrcCalculator) -> Vec<u8> {
+        match self.modification_time {
+            Some(ref modification_time) => {
+                Time::encode_modification_time(modification_time, crc_calculator)
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+pub struct Phys;
+
+impl Phys {
+    pub fn encode_physical_dimensions(
+        physical_dimensions: &PhysicalDimensions,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(9);
+        data.extend_from_slice(&physical_dimensions.pixels_per_unit_x.to_be_bytes());
+        data.extend_from_slice(&physical_dimensions.pixels_per_unit_y.to_be_bytes());
+        data.push(match physical_dimensions.unit {
+            PhysicalUnit::Unknown => 0,
+            PhysicalUnit::Meter => 1,
+        });
+        let chunk = Chunk::new(PHYS_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_physical_dimensions(bytes: &[u8]) -> Result<PhysicalDimensions, PngParseError> {
+        png_assert!(
+            bytes.len() == 9,
+            format!("invalid pHYs chunk size {}, expected 9", bytes.len())
+        );
+        let mut offset = 0;
+        let pixels_per_unit_x = read_u32(&mut offset, bytes)?;
+        let pixels_per_unit_y = read_u32(&mut offset, bytes)?;
+        let unit = match read_byte(&mut offset, bytes)? {
+            0 => PhysicalUnit::Unknown,
+            1 => PhysicalUnit::Meter,
+            unit => {
+                return Err(PngParseError::new(
+                    ErrorKind::InvalidData,
+                    format!("Unrecognized pHYs unit specifier {unit}"),
+                ))
+            }
+        };
+
+        Ok(PhysicalDimensions {
+            pixels_per_unit_x,
+            pixels_per_unit_y,
+            unit,
+        })
+    }
+}
+
+pub struct Time;
+
+impl Time {
+    pub fn encode_modification_time(
+        modification_time: &ModificationTime,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(7);
+        data.extend_from_slice(&modification_time.year.to_be_bytes());
+        data.extend_from_slice(&[
+            modification_time.month,
+            modification_time.day,
+            modification_time.hour,
+            modification_time.minute,
+            modification_time.second,
+        ]);
+        let chunk = Chunk::new(TIME_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_modification_time(bytes: &[u8]) -> Result<ModificationTime, PngParseError> {
+        png_assert!(
+            bytes.len() == 7,
+            format!("invalid tIME chunk size {}, expected 7", bytes.len())
+        );
+        let mut offset = 0;
+        let modification_time = ModificationTime {
+            year: read_u16(&mut offset, bytes)?,
+            month: read_byte(&mut offset, bytes)?,
+            day: read_byte(&mut offset, bytes)?,
+            hour: read_byte(&mut offset, bytes)?,
+            minute: read_byte(&mut offset, bytes)?,
+            second: read_byte(&mut offset, bytes)?,
+        };
+        png_assert!(
+            (1..=12).contains(&modification_time.month)
+                && (1..=31).contains(&modification_time.day)
+                && modification_time.hour <= 23
+                && modification_time.minute <= 59
+                && modification_time.second <= 60,
+            format!("invalid tIME timestamp {modification_time:?}")
+        );
+
+        Ok(modification_time)
+    }
+}
+
+pub struct Bkgd;
+
+impl Bkgd {
+    pub fn encode_background(
+        background: &Rgb16,
+        color_type: ColorType,
+        bit_depth: u8,
+        palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Result<Vec<u8>, PngEncodeError> {
+        let background_color = Rgba16::from(background);
+        let data: Vec<u8> = match color_type {
+            ColorType::IndexedColor => {
+                let background_color = Rgba::from(&background_color);
+                let closest_index = palette
+                    .as_ref()
+                    .ok_or(PngEncodeError::MissingPalette)?
+                    .values()
+                    .min_by_key(|(_idx, color)| color_distance(color, &background_color))
+                    .map(|(idx, _color)| *idx as u8)
+                    .unwrap_or(0);
+
+                vec![closest_index]
+            }
+            ColorType::Greyscale | ColorType::GreyscaleAlpha => {
+                ColorType::scale_to_sample(background_color.luma(), bit_depth)
+                    .to_be_bytes()
+                    .to_vec()
+            }
+            ColorType::Truecolor | ColorType::TrueColorAlpha => {
+                [background.r, background.g, background.b]
+                    .iter()
+                    .flat_map(|value| ColorType::scale_to_sample(*value, bit_depth).to_be_bytes())
+                    .collect()
+            }
+        };
+        let chunk = Chunk::new(BKGD_CHUNK_TYPE, &data, crc_calculator);
+
+        Ok(chunk.to_bytes())
+    }
+
+    pub fn decode_background(
+        bytes: &[u8],
+        color_type: ColorType,
+        bit_depth: u8,
+        palette: &Option<Vec<Rgba>>,
+    ) -> Result<Rgb16, PngParseError> {
+        let mut offset = 0;
+
+        match color_type {
+            ColorType::IndexedColor => {
+                png_assert!(
+                    bytes.len() == 1,
+                    format!(
+                        "invalid bKGD chunk size {} for indexed image, expected 1",
+                        bytes.len()
+                    )
+                );
+                let idx = bytes[0] as usize;
+                let color = match palette {
+                    Some(palette) => palette.get(idx).ok_or_else(|| {
+                        PngParseError::new(
+                            ErrorKind::InvalidData,
+                            format!("bKGD palette index {idx} is outside of the palette"),
+                        )
+                    })?,
+                    None => {
+                        return Err(PngParseError::new(
+                            ErrorKind::InvalidData,
+                            "bKGD chunk must come after the PLTE chunk",
+                        ))
+                    }
+                };
+
+                Ok(Rgb16::from(&Rgba16::from(color)))
+            }
+            ColorType::Greyscale | ColorType::GreyscaleAlpha => {
+                png_assert!(
+                    bytes.len() == 2,
+                    format!(
+                        "invalid bKGD chunk size {} for greyscale image, expected 2",
+                        bytes.len()
+                    )
+                );
+                let grey = ColorType::scale_from_sample(read_u16(&mut offset, bytes)?, bit_depth);
+
+                Ok(Rgb16::new(grey, grey, grey))
+            }
+            ColorType::Truecolor | ColorType::TrueColorAlpha => {
+                png_assert!(
+                    bytes.len() == 6,
+                    format!(
+                        "invalid bKGD chunk size {} for truecolor image, expected 6",
+                        bytes.len()
+                    )
+                );
+
+                Ok(Rgb16::new(
+                    ColorType::scale_from_sample(read_u16(&mut offset, bytes)?, bit_depth),
+                    ColorType::scale_from_sample(read_u16(&mut offset, bytes)?, bit_depth),
+                    ColorType::scale_from_sample(read_u16(&mut offset, bytes)?, bit_depth),
+                ))
+            }
+        }
+    }
+}
+
+fn color_distance(a: &Rgba, b: &Rgba) -> u32 {
+    [(a.r, b.r), (a.g, b.g), (a.b, b.b)]
+        .iter()
+        .map(|(x, y)| (x.abs_diff(*y) as u32).pow(2))
+        .sum()
+}
+
+pub struct Sbit;
+
+impl Sbit {
+    pub fn encode_significant_bits(
+        significant_bits: &SignificantBits,
+        color_type: ColorType,
+        bit_depth: u8,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let sample_depth = match color_type {
+            ColorType::IndexedColor => 8,
+            _ => bit_depth,
+        };
+        let grey = significant_bits
+            .red
+            .max(significant_bits.green)
+            .max(significant_bits.blue);
+        let alpha = significant_bits.alpha.unwrap_or(sample_depth);
+        let data: Vec<u8> = match color_type {
+            ColorType::Greyscale => vec![grey],
+            ColorType::GreyscaleAlpha => vec![grey, alpha],
+            ColorType::Truecolor | ColorType::IndexedColor => vec![
+                significant_bits.red,
+                significant_bits.green,
+                significant_bits.blue,
+            ],
+            ColorType::TrueColorAlpha => vec![
+                significant_bits.red,
+                significant_bits.green,
+                significant_bits.blue,
+                alpha,
+            ],
+        }
+        .into_iter()
+        .map(|bits| bits.clamp(1, sample_depth))
+        .collect();
+        let chunk = Chunk::new(SBIT_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_significant_bits(
+        bytes: &[u8],
+        color_type: ColorType,
+        bit_depth: u8,
+    ) -> Result<SignificantBits, PngParseError> {
+        let (expected_size, sample_depth) = match color_type {
+            ColorType::Greyscale => (1, bit_depth),
+            ColorType::GreyscaleAlpha => (2, bit_depth),
+            ColorType::Truecolor => (3, bit_depth),
+            ColorType::IndexedColor => (3, 8),
+            ColorType::TrueColorAlpha => (4, bit_depth),
+        };
+        png_assert!(
+            bytes.len() == expected_size,
+            format!(
+                "invalid sBIT chunk size {}, expected {expected_size}",
+                bytes.len()
+            )
+        );
+        png_assert!(
+            bytes.iter().all(|bits| (1..=sample_depth).contains(bits)),
+            format!("sBIT values {bytes:?} must be between 1 and the sample depth {sample_depth}")
+        );
+
+        Ok(match color_type {
+            ColorType::Greyscale => SignificantBits {
+                red: bytes[0],
+                green: bytes[0],
+                blue: bytes[0],
+                alpha: None,
+            },
+            ColorType::GreyscaleAlpha => SignificantBits {
+                red: bytes[0],
+                green: bytes[0],
+                blue: bytes[0],
+                alpha: Some(bytes[1]),
+            },
+            ColorType::Truecolor | ColorType::IndexedColor => SignificantBits {
+                red: bytes[0],
+                green: bytes[1],
+                blue: bytes[2],
+                alpha: None,
+            },
+            ColorType::TrueColorAlpha => SignificantBits {
+                red: bytes[0],
+                green: bytes[1],
+                blue: bytes[2],
+                alpha: Some(bytes[3]),
+            },
+        })
+    }
+}
+
+pub struct Hist;
+
+impl Hist {
+    pub fn encode_histogram(frequencies: &[u16], crc_calculator: &mut CrcCalculator) -> Vec<u8> {
+        let data: Vec<u8> = frequencies
+            .iter()
+            .flat_map(|frequency| frequency.to_be_bytes())
+            .collect();
+        let chunk = Chunk::new(HIST_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_histogram(
+        bytes: &[u8],
+        palette: &Option<Vec<Rgba>>,
+    ) -> Result<Vec<u16>, PngParseError> {
+        let palette_size = match palette {
+            Some(palette) => palette.len(),
+            None => {
+                return Err(PngParseError::new(
+                    ErrorKind::InvalidData,
+                    "hIST chunk must come after the PLTE chunk",
+                ))
+            }
+        };
+        png_assert!(
+            bytes.len() == palette_size * 2,
+            format!(
+                "invalid hIST chunk size {}, expected 2 bytes for each of the {palette_size} palette entries",
+                bytes.len()
+            )
+        );
+
+        Ok(bytes
+            .chunks_exact(2)
+            .map(|frequency| u16::from_be_bytes([frequency[0], frequency[1]]))
+            .collect())
+    }
+
+    // frequencies only need to be approximate, so large counts are scaled down to fit in a u16
+    pub fn palette_frequencies(counts: &[usize]) -> Vec<u16> {
+        let max_count = counts.iter().copied().max().unwrap_or(0);
+
+        counts
+            .iter()
+            .map(|count| match (*count, max_count) {
+                (0, _) => 0,
+                (count, max_count) if max_count <= u16::MAX as usize => count as u16,
+                (count, max_count) => ((count * u16::MAX as usize) / max_count).max(1) as u16,
+            })
+            .collect()
+    }
+}
+
+pub struct Splt;
+
+impl Splt {
+    pub fn encode_suggested_palette(
+        suggested_palette: &SuggestedPalette,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = latin1_to_bytes(&suggested_palette.name);
+        data.push(NULL_SEPARATOR);
+        data.push(suggested_palette.sample_depth);
+
+        for entry in suggested_palette.entries.iter() {
+            for sample in [entry.red, entry.green, entry.blue, entry.alpha] {
+                if suggested_palette.sample_depth == 8 {
+                    data.push(sample as u8);
+                } else {
+                    data.extend_from_slice(&sample.to_be_bytes());
+                }
+            }
+            data.extend_from_slice(&entry.frequency.to_be_bytes());
+        }
+        let chunk = Chunk::new(SPLT_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_suggested_palette(bytes: &[u8]) -> Result<SuggestedPalette, PngParseError> {
+        let (name, rest) = split_at_null(bytes, "palette name")?;
+        let name = bytes_to_latin1(name);
+        check_keyword_validity(&name)?;
+        png_assert!(!rest.is_empty(), "sPLT chunk is missing its sample depth");
+        let sample_depth = rest[0];
+        let entry_size = match sample_depth {
+            8 => 6,
+            16 => 10,
+            _ => {
+                return Err(PngParseError::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid sPLT sample depth {sample_depth}, expected 8 or 16"),
+                ))
+            }
+        };
+        let entry_bytes = &rest[1..];
+        png_assert!(
+            entry_bytes.len().is_multiple_of(entry_size),
+            format!(
+                "invalid sPLT chunk size, {} bytes of entries is not a multiple of {entry_size}",
+                entry_bytes.len()
+            )
+        );
+
+        let entries = entry_bytes
+            .chunks_exact(entry_size)
+            .map(|entry| {
+                let mut offset = 0;
+                let mut read_sample = || {
+                    if sample_depth == 8 {
+                        read_byte(&mut offset, entry).map(u16::from)
+                    } else {
+                        read_u16(&mut offset, entry)
+                    }
+                };
+                let (red, green, blue, alpha) = (
+                    read_sample()?,
+                    read_sample()?,
+                    read_sample()?,
+                    read_sample()?,
+                );
+
+                Ok(SuggestedPaletteEntry {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                    frequency: read_u16(&mut offset, entry)?,
+                })
+            })
+            .collect::<Result<_, PngParseError>>()?;
+
+        Ok(SuggestedPalette {
+            name,
+            sample_depth,
+            entries,
+        })
+    }
+}
@@ -1,14 +1,22 @@
+use crate::error::ErrorKind;
+
 use super::{
     binary_utils::{read_bytes, read_u32},
-    consts::CHUNK_METADATA_LENGTH,
+    consts::{CHUNK_DATA_MAX_LENGTH, CHUNK_METADATA_LENGTH},
     crc::CrcCalculator,
     PngParseError,
 };
 
-pub mod ihdr;
-pub mod plte;
+pub mod animation;
+pub mod color_space;
 pub mod idat;
 pub mod iend;
+pub mod ihdr;
+pub mod metadata;
+pub mod plte;
+pub mod text;
+pub mod trns;
+pub mod unknown;
 
 #[derive(Debug)]
 pub struct Chunk<'a> {
@@ -37,24 +45,39 @@ impl<'a> Chunk<'a> {
     }
 
     pub fn from_bytes(bytes: &'a [u8], offset: &mut usize) -> Result<Chunk<'a>, PngParseError> {
-        if bytes.len() < CHUNK_METADATA_LENGTH {
-            return Err(PngParseError(format!(
-                "Cannot parse chunk as it is smaller than {CHUNK_METADATA_LENGTH} bytes"
-            )));
+        let chunk_offset = *offset;
+
+        if bytes.len() < chunk_offset + CHUNK_METADATA_LENGTH {
+            return Err(PngParseError::new(
+                ErrorKind::UnexpectedEof,
+                format!("Cannot parse chunk as it is smaller than {CHUNK_METADATA_LENGTH} bytes"),
+            )
+            .at_offset(chunk_offset));
         }
 
-        let length: u32 = read_u32(offset, bytes);
+        let length: u32 = read_u32(offset, bytes)?;
+
+        if length > CHUNK_DATA_MAX_LENGTH {
+            return Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Chunk length {length} is larger than {CHUNK_DATA_MAX_LENGTH}"),
+            )
+            .in_chunk(&bytes[*offset..*offset + 4])
+            .at_offset(chunk_offset));
+        }
 
-        if bytes.len() < length as usize + CHUNK_METADATA_LENGTH {
-            return Err(PngParseError(format!(
-                "Cannot parse chunk as it is smaller than specified length {length}"
-            )));
+        if bytes.len() < chunk_offset + length as usize + CHUNK_METADATA_LENGTH {
+            return Err(PngParseError::new(
+                ErrorKind::UnexpectedEof,
+                format!("Cannot parse chunk as it is smaller than specified length {length}"),
+            )
+            .in_chunk(&bytes[*offset..*offset + 4])
+            .at_offset(chunk_offset));
         }
 
-        let chunk_type = read_bytes(offset, bytes, 4);
-        let chunk_data = read_bytes(offset, bytes, length as usize);
-        //TODO: validate crc
-        let crc = read_u32(offset, bytes);
+        let chunk_type = read_bytes(offset, bytes, 4)?;
+        let chunk_data = read_bytes(offset, bytes, length as usize)?;
+        let crc = read_u32(offset, bytes)?;
 
         Ok(Chunk {
             chunk_type,
@@ -63,6 +86,16 @@ impl<'a> Chunk<'a> {
         })
     }
 
+    pub fn verify_crc(&self, crc_calculator: &mut CrcCalculator) -> Result<(), PngParseError> {
+        crc_calculator.reset();
+        crc_calculator.update_crc(self.chunk_type);
+        crc_calculator.update_crc(self.chunk_data);
+        let crc = crc_calculator.get_crc();
+        crc_calculator.reset();
+
+        check_crc(self.chunk_type, self.crc, crc)
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         let length = self.chunk_data.len();
         let mut result = Vec::with_capacity(CHUNK_METADATA_LENGTH + length);
@@ -75,3 +108,15 @@ impl<'a> Chunk<'a> {
         result
     }
 }
+
+pub fn check_crc(chunk_type: &[u8], stored_crc: u32, crc: u32) -> Result<(), PngParseError> {
+    if crc != stored_crc {
+        return Err(PngParseError::new(
+            ErrorKind::ChecksumMismatch,
+            format!("CRC mismatch, chunk stores {stored_crc:#010x} but data hashes to {crc:#010x}"),
+        )
+        .in_chunk(chunk_type));
+    }
+
+    Ok(())
+}
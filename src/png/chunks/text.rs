@@ -0,0 +1,225 @@
+use crate::{
+    binary::latin1::{bytes_to_latin1, latin1_to_bytes},
+    error::ErrorKind,
+    png::{
+        consts::{ITXT_CHUNK_TYPE, TEXT_CHUNK_TYPE, ZTXT_CHUNK_TYPE},
+        crc::CrcCalculator,
+        deflate::{decode_zlib, ZlibEncoder},
+        CompressionLevel, PngParseError,
+    },
+    png_assert,
+};
+
+use super::Chunk;
+
+const MAX_KEYWORD_LENGTH: usize = 79;
+pub const NULL_SEPARATOR: u8 = 0;
+pub const ZLIB_COMPRESSION_METHOD: u8 = 0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PngText {
+    pub keyword: String,
+    pub text: String,
+    pub compressed: bool,
+    pub language_tag: Option<String>,
+    pub translated_keyword: Option<String>,
+}
+
+impl PngText {
+    pub fn new(keyword: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            keyword: keyword.into(),
+            text: text.into(),
+            compressed: false,
+            language_tag: None,
+            translated_keyword: None,
+        }
+    }
+
+    pub fn compressed(mut self, compressed: bool) -> Self {
+        self.compressed = compressed;
+        self
+    }
+
+    pub fn language(
+        mut self,
+        language_tag: impl Into<String>,
+        translated_keyword: impl Into<String>,
+    ) -> Self {
+        self.language_tag = Some(language_tag.into());
+        self.translated_keyword = Some(translated_keyword.into());
+        self
+    }
+
+    // tEXt and zTXt can only hold Latin-1 text, anything else has to go in an iTXt chunk
+    fn is_international(&self) -> bool {
+        self.language_tag.is_some()
+            || self.translated_keyword.is_some()
+            || self.text.chars().any(|c| c as u32 > u8::MAX as u32)
+    }
+
+    pub fn check_keyword_validity(&self) -> Result<(), PngParseError> {
+        check_keyword_validity(&self.keyword)
+    }
+}
+
+pub struct Text;
+
+impl Text {
+    pub fn encode_text(
+        entry: &PngText,
+        compression_level: CompressionLevel,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let mut data = latin1_to_bytes(&entry.keyword);
+        data.push(NULL_SEPARATOR);
+
+        let chunk_type = if entry.is_international() {
+            data.push(entry.compressed as u8);
+            data.push(ZLIB_COMPRESSION_METHOD);
+            data.extend_from_slice(entry.language_tag.as_deref().unwrap_or("").as_bytes());
+            data.push(NULL_SEPARATOR);
+            data.extend_from_slice(entry.translated_keyword.as_deref().unwrap_or("").as_bytes());
+            data.push(NULL_SEPARATOR);
+            data.extend_from_slice(&compress_text(
+                entry.text.as_bytes(),
+                entry.compressed,
+                compression_level,
+            ));
+
+            ITXT_CHUNK_TYPE
+        } else if entry.compressed {
+            data.push(ZLIB_COMPRESSION_METHOD);
+            data.extend_from_slice(&compress_text(
+                &latin1_to_bytes(&entry.text),
+                true,
+                compression_level,
+            ));
+
+            ZTXT_CHUNK_TYPE
+        } else {
+            data.extend_from_slice(&latin1_to_bytes(&entry.text));
+
+            TEXT_CHUNK_TYPE
+        };
+        let chunk = Chunk::new(chunk_type, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_text(chunk_type: &[u8], bytes: &[u8]) -> Result<PngText, PngParseError> {
+        let (keyword, rest) = split_at_null(bytes, "keyword")?;
+        let keyword = bytes_to_latin1(keyword);
+
+        let entry = match chunk_type {
+            TEXT_CHUNK_TYPE => PngText::new(keyword, bytes_to_latin1(rest)),
+            ZTXT_CHUNK_TYPE => {
+                png_assert!(
+                    !rest.is_empty(),
+                    "zTXt chunk is missing its compression method"
+                );
+                check_compression_method(rest[0])?;
+                let text = decompress_text(&rest[1..])?;
+
+                PngText::new(keyword, bytes_to_latin1(&text)).compressed(true)
+            }
+            _ => {
+                png_assert!(
+                    rest.len() >= 2,
+                    "iTXt chunk is missing its compression flag and method"
+                );
+                let compressed = rest[0] == 1;
+                check_compression_method(rest[1])?;
+                let (language_tag, rest) = split_at_null(&rest[2..], "language tag")?;
+                let (translated_keyword, text) = split_at_null(rest, "translated keyword")?;
+                let text = if compressed {
+                    decompress_text(text)?
+                } else {
+                    text.to_vec()
+                };
+
+                PngText::new(keyword, utf8_to_string(text, "text")?)
+                    .compressed(compressed)
+                    .language(
+                        utf8_to_string(language_tag.to_vec(), "language tag")?,
+                        utf8_to_string(translated_keyword.to_vec(), "translated keyword")?,
+                    )
+            }
+        };
+        entry.check_keyword_validity()?;
+
+        Ok(entry)
+    }
+}
+
+pub fn check_keyword_validity(keyword: &str) -> Result<(), PngParseError> {
+    png_assert!(
+        !keyword.is_empty() && keyword.chars().count() <= MAX_KEYWORD_LENGTH,
+        format!("keyword must be 1 to {MAX_KEYWORD_LENGTH} characters long, found {keyword:?}")
+    );
+    png_assert!(
+        keyword
+            .chars()
+            .all(|c| matches!(c as u32, 32..=126 | 161..=255)),
+        format!("keyword {keyword:?} must only contain printable Latin-1 characters")
+    );
+    png_assert!(
+        !keyword.starts_with(' ') && !keyword.ends_with(' ') && !keyword.contains("  "),
+        format!("keyword {keyword:?} has leading, trailing or consecutive spaces")
+    );
+
+    Ok(())
+}
+
+pub fn split_at_null<'a>(
+    bytes: &'a [u8],
+    field: &str,
+) -> Result<(&'a [u8], &'a [u8]), PngParseError> {
+    match bytes.iter().position(|byte| *byte == NULL_SEPARATOR) {
+        Some(idx) => Ok((&bytes[..idx], &bytes[idx + 1..])),
+        None => Err(PngParseError::new(
+            ErrorKind::InvalidData,
+            format!("{field} is not null terminated"),
+        )),
+    }
+}
+
+pub fn check_compression_method(method: u8) -> Result<(), PngParseError> {
+    if method != ZLIB_COMPRESSION_METHOD {
+        return Err(PngParseError::new(
+            ErrorKind::Unsupported,
+            format!("Unrecognized compression method {method}"),
+        ));
+    }
+
+    Ok(())
+}
+
+fn decompress_text(bytes: &[u8]) -> Result<Vec<u8>, PngParseError> {
+    decode_zlib(bytes).map_err(|err| {
+        let mut png_error = PngParseError::from(err);
+        png_error.message = "failed to decompress text".to_string();
+
+        png_error
+    })
+}
+
+fn compress_text(bytes: &[u8], compressed: bool, compression_level: CompressionLevel) -> Vec<u8> {
+    if !compressed {
+        return bytes.to_vec();
+    }
+
+    let mut encoder = ZlibEncoder::new(compression_level);
+    encoder.write_bytes(bytes);
+
+    encoder.flush()
+}
+
+fn utf8_to_string(bytes: Vec<u8>, field: &str) -> Result<String, PngParseError> {
+    String::from_utf8(bytes).map_err(|_e| {
+        PngParseError::new(
+            ErrorKind::InvalidData,
+            format!("iTXt {field} is not valid utf8"),
+        )
+    })
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::{
+    colors::Rgba,
+    error::ErrorKind,
+    png::{
+        binary_utils::read_u16, color_type::ColorType, consts::TRNS_CHUNK_TYPE, crc::CrcCalculator,
+        PngParseError,
+    },
+    png_assert,
+};
+
+use super::Chunk;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transparency {
+    PaletteAlpha(Vec<u8>),
+    GreyscaleKey(u16),
+    TruecolorKey(u16, u16, u16),
+}
+
+impl Transparency {
+    pub fn from_palette(palette: &HashMap<Rgba, (usize, Rgba)>) -> Option<Self> {
+        let mut alphas: Vec<(usize, u8)> = palette
+            .values()
+            .map(|(idx, color)| (*idx, color.a))
+            .collect();
+        alphas.sort_unstable();
+        alphas.dedup();
+
+        // trailing opaque entries can be left out of the chunk
+        let last_transparent = alphas.iter().rposition(|(_idx, alpha)| *alpha != u8::MAX)?;
+        let palette_alpha = alphas[..=last_transparent]
+            .iter()
+            .map(|(_idx, alpha)| *alpha)
+            .collect();
+
+        Some(Transparency::PaletteAlpha(palette_alpha))
+    }
+
+    pub fn apply_to_palette(&self, palette: &mut [Rgba]) {
+        if let Transparency::PaletteAlpha(palette_alpha) = self {
+            for (color, alpha) in palette.iter_mut().zip(palette_alpha) {
+                color.a = *alpha;
+            }
+        }
+    }
+}
+
+pub struct Trns;
+
+impl Trns {
+    pub fn encode_transparency(
+        transparency: &Transparency,
+        crc_calculator: &mut CrcCalculator,
+    ) -> Vec<u8> {
+        let data: Vec<u8> = match transparency {
+            Transparency::PaletteAlpha(palette_alpha) => palette_alpha.clone(),
+            Transparency::GreyscaleKey(grey) => grey.to_be_bytes().to_vec(),
+            Transparency::TruecolorKey(r, g, b) => [r, g, b]
+                .iter()
+                .flat_map(|sample| sample.to_be_bytes())
+                .collect(),
+        };
+        let chunk = Chunk::new(TRNS_CHUNK_TYPE, &data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+
+    pub fn decode_transparency(
+        bytes: &[u8],
+        color_type: ColorType,
+        palette: &Option<Vec<Rgba>>,
+    ) -> Result<Transparency, PngParseError> {
+        let mut offset = 0;
+
+        match color_type {
+            ColorType::IndexedColor => {
+                let palette_size = match palette {
+                    Some(palette) => palette.len(),
+                    None => {
+                        return Err(PngParseError::new(
+                            ErrorKind::InvalidData,
+                            "tRNS chunk must come after the PLTE chunk",
+                        ))
+                    }
+                };
+                png_assert!(
+                    bytes.len() <= palette_size,
+                    format!(
+                        "tRNS chunk has {} entries but the palette only has {palette_size} colors",
+                        bytes.len()
+                    )
+                );
+
+                Ok(Transparency::PaletteAlpha(bytes.to_vec()))
+            }
+            ColorType::Greyscale => {
+                png_assert!(
+                    bytes.len() == 2,
+                    format!(
+                        "invalid tRNS chunk size {} for greyscale image, expected 2",
+                        bytes.len()
+                    )
+                );
+
+                Ok(Transparency::GreyscaleKey(read_u16(&mut offset, bytes)?))
+            }
+            ColorType::Truecolor => {
+                png_assert!(
+                    bytes.len() == 6,
+                    format!(
+                        "invalid tRNS chunk size {} for truecolor image, expected 6",
+                        bytes.len()
+                    )
+                );
+
+                Ok(Transparency::TruecolorKey(
+                    read_u16(&mut offset, bytes)?,
+                    read_u16(&mut offset, bytes)?,
+                    read_u16(&mut offset, bytes)?,
+                ))
+            }
+            ColorType::GreyscaleAlpha | ColorType::TrueColorAlpha => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                "tRNS chunk is not allowed for color types with an alpha channel",
+            )),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    png::{crc::CrcCalculator, PngParseError},
+    png_assert,
+};
+
+use super::Chunk;
+
+// bit 5 of each chunk type byte, set when the letter is lowercase
+const PROPERTY_BIT: u8 = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkPosition {
+    BeforePalette,
+    BeforeImageData,
+    AfterImageData,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownChunk {
+    pub chunk_type: [u8; 4],
+    pub data: Vec<u8>,
+    pub position: ChunkPosition,
+}
+
+impl UnknownChunk {
+    pub fn new(chunk_type: [u8; 4], data: Vec<u8>, position: ChunkPosition) -> Self {
+        Self {
+            chunk_type,
+            data,
+            position,
+        }
+    }
+
+    pub fn is_ancillary(chunk_type: &[u8]) -> bool {
+        chunk_type[0] & PROPERTY_BIT != 0
+    }
+
+    pub fn is_safe_to_copy(chunk_type: &[u8]) -> bool {
+        chunk_type[3] & PROPERTY_BIT != 0
+    }
+
+    // only chunks an editor may copy without understanding them can be written back
+    pub fn check_chunk_type_validity(&self) -> Result<(), PngParseError> {
+        let chunk_type = String::from_utf8_lossy(&self.chunk_type);
+
+        png_assert!(
+            self.chunk_type.iter().all(u8::is_ascii_alphabetic),
+            format!("chunk type {chunk_type:?} must only contain ASCII letters")
+        );
+        png_assert!(
+            self.chunk_type[2] & PROPERTY_BIT == 0,
+            format!("chunk type {chunk_type:?} has the reserved bit set")
+        );
+        png_assert!(
+            Self::is_ancillary(&self.chunk_type) && Self::is_safe_to_copy(&self.chunk_type),
+            format!("chunk type {chunk_type:?} must be ancillary and safe to copy")
+        );
+
+        Ok(())
+    }
+
+    pub fn to_bytes(&self, crc_calculator: &mut CrcCalculator) -> Vec<u8> {
+        let chunk = Chunk::new(&self.chunk_type, &self.data, crc_calculator);
+
+        chunk.to_bytes()
+    }
+}
+
+pub fn encode_unknown_chunks(
+    unknown_chunks: &[UnknownChunk],
+    position: ChunkPosition,
+    crc_calculator: &mut CrcCalculator,
+) -> Vec<u8> {
+    unknown_chunks
+        .iter()
+        .filter(|unknown_chunk| unknown_chunk.position == position)
+        .flat_map(|unknown_chunk| unknown_chunk.to_bytes(crc_calculator))
+        .collect()
+}
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
-use crate::colors::{YCbCr, Rgb, Rgba};
-
-use super::{
-    deflate::bitstream::{ReadBitStream, WriteBitStream},
-    PngParseError,
+use crate::{
+    colors::{Rgba, Rgba16},
+    error::ErrorKind,
 };
 
+use super::{chunks::trns::Transparency, PngEncodeError, PngParseError};
+
 #[derive(Debug)]
 pub struct InvalidBitDepthError(pub ColorType, pub u8);
 
@@ -22,10 +22,10 @@ pub enum ColorType {
 impl ColorType {
     pub fn create_scanlines(
         &self,
-        pixels: &[Vec<Rgba>],
+        pixels: &[Vec<Rgba16>],
         bit_depth: u8,
         palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, PngEncodeError> {
         if bit_depth < 8 {
             return self.create_scanlines_bit_aligned(pixels, bit_depth, palette);
         }
@@ -37,26 +37,14 @@ impl ColorType {
 
             for pixel in row {
                 match self {
-                    ColorType::IndexedColor => match palette {
-                        Some(palette) => {
-                            let idx = palette
-                                .get(pixel)
-                                .expect("all unique image rgb values must be present in palette")
-                                .0 as u8;
-
-                            Self::push_channel_value(&mut scanline, idx, bit_depth);
-                        }
-                        None => panic!("Palette must be created to encode indexed color image"),
-                    },
+                    ColorType::IndexedColor => {
+                        scanline.push(Self::palette_index(pixel, palette)?);
+                    }
                     ColorType::Greyscale => {
-                        let ycbcr = YCbCr::from(pixel.clone());
-
-                        Self::push_channel_value(&mut scanline, ycbcr.y, bit_depth);
+                        Self::push_channel_value(&mut scanline, pixel.luma(), bit_depth);
                     }
                     ColorType::GreyscaleAlpha => {
-                        let ycbcr = YCbCr::from(pixel.clone());
-
-                        Self::push_channel_value(&mut scanline, ycbcr.y, bit_depth);
+                        Self::push_channel_value(&mut scanline, pixel.luma(), bit_depth);
                         Self::push_channel_value(&mut scanline, pixel.a, bit_depth);
                     }
                     ColorType::Truecolor => {
@@ -76,57 +64,62 @@ impl ColorType {
             scanlines.push(scanline);
         }
 
-        scanlines
+        Ok(scanlines)
     }
 
-    fn push_channel_value(scanline: &mut Vec<u8>, value: u8, bit_depth: u8) {
+    fn palette_index(
+        pixel: &Rgba16,
+        palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
+    ) -> Result<u8, PngEncodeError> {
+        let palette = palette.as_ref().ok_or(PngEncodeError::MissingPalette)?;
+        let color = Rgba::from(pixel);
+
+        match palette.get(&color) {
+            Some((idx, _color)) => Ok(*idx as u8),
+            None => Err(PngEncodeError::ColorNotInPalette(color)),
+        }
+    }
+
+    fn push_channel_value(scanline: &mut Vec<u8>, value: u16, bit_depth: u8) {
         if bit_depth == 8 {
-            scanline.push(value);
+            scanline.push(Self::scale_to_sample(value, bit_depth) as u8);
         } else {
-            scanline.push(value);
-            scanline.push(value);
+            scanline.extend_from_slice(&value.to_be_bytes());
         }
     }
 
     fn create_scanlines_bit_aligned(
         &self,
-        pixels: &[Vec<Rgba>],
+        pixels: &[Vec<Rgba16>],
         bit_depth: u8,
         palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
-    ) -> Vec<Vec<u8>> {
+    ) -> Result<Vec<Vec<u8>>, PngEncodeError> {
         let mut scanlines: Vec<Vec<u8>> = Vec::with_capacity(pixels.len());
 
         for row in pixels {
-            let mut scanline = WriteBitStream::new();
+            let mut scanline = vec![0; (row.len() * bit_depth as usize).div_ceil(8)];
 
-            for pixel in row {
-                match self {
-                    ColorType::Greyscale => {
-                        let ycbcr = YCbCr::from(pixel.clone());
-                        let greyscale_adjusted_to_depths = ycbcr.y >> (8 - bit_depth);
-
-                        scanline.push_u8_rtl(greyscale_adjusted_to_depths, bit_depth);
-                    },
-                    ColorType::IndexedColor => match palette {
-                        Some(palette) => {
-                            let idx = palette
-                                .get(pixel)
-                                .expect("all unique image rgb values must be present in palette")
-                                .0 as u8;
-                            debug_assert!(idx < (1<<bit_depth));
-
-                            scanline.push_u8_rtl(idx, bit_depth)
-                        }
-                        None => panic!("Palette must be created to encode indexed color image"),
-                    },
-                    _ => panic!("scanline_to_pixels called with less than 8 bits for non compatible color type")
-                }
+            for (pixel_index, pixel) in row.iter().enumerate() {
+                let value = match self {
+                    ColorType::Greyscale => Self::scale_to_sample(pixel.luma(), bit_depth) as u8,
+                    ColorType::IndexedColor => {
+                        let idx = Self::palette_index(pixel, palette)?;
+                        debug_assert!(idx < (1<<bit_depth));
+
+                        idx
+                    }
+                    _ => return Err(PngEncodeError::InvalidBitDepth(*self, bit_depth)),
+                };
+
+                // the leftmost pixel goes in the highest bits of a byte
+                let bit_index = pixel_index * bit_depth as usize;
+                scanline[bit_index >> 3] |= value << (8 - bit_depth as usize - (bit_index & 0b111));
             }
 
-            scanlines.push(scanline.flush_to_bytes());
+            scanlines.push(scanline);
         }
 
-        scanlines
+        Ok(scanlines)
     }
 
     pub fn scanline_to_pixels(
@@ -135,9 +128,16 @@ impl ColorType {
         bit_depth: u8,
         width: usize,
         palette: &Option<Vec<Rgba>>,
-    ) -> Result<Vec<Vec<Rgba>>, PngParseError> {
+        transparency: &Option<Transparency>,
+    ) -> Result<Vec<Vec<Rgba16>>, PngParseError> {
         if bit_depth < 8 {
-            return self.scanline_to_pixels_bit_aligned(scanlines, bit_depth, width, palette);
+            return self.scanline_to_pixels_bit_aligned(
+                scanlines,
+                bit_depth,
+                width,
+                palette,
+                transparency,
+            );
         }
 
         let mut pixels = Vec::with_capacity(scanlines.len());
@@ -147,35 +147,25 @@ impl ColorType {
             let mut pixel_row = Vec::with_capacity(scanline.len() / bytes_per_pixel);
 
             for pixel_bytes in scanline.chunks_exact(bytes_per_pixel) {
-                let pixel = match self {
+                let mut pixel: Rgba16 = match self {
                     ColorType::Greyscale => {
                         let gamma = Self::read_channel_bytes(pixel_bytes, bit_depth, 0);
-                        let ycbcr = YCbCr::new(gamma, 127, 127);
 
-                        ycbcr.into()
+                        Rgba16::new(gamma, gamma, gamma, u16::MAX)
                     }
                     ColorType::Truecolor => {
                         let r = Self::read_channel_bytes(pixel_bytes, bit_depth, 0);
                         let g = Self::read_channel_bytes(pixel_bytes, bit_depth, 1);
                         let b = Self::read_channel_bytes(pixel_bytes, bit_depth, 2);
 
-                        Rgba::new(r, g, b, u8::MAX)
-                    }
-                    ColorType::IndexedColor => {
-                        let color = &palette
-                            .as_ref()
-                            .expect("scanline_to_pixels called with indexed color but no palette")
-                            [pixel_bytes[0] as usize];
-
-                        color.clone()
+                        Rgba16::new(r, g, b, u16::MAX)
                     }
+                    ColorType::IndexedColor => Self::palette_color(palette, pixel_bytes[0])?,
                     ColorType::GreyscaleAlpha => {
                         let gamma = Self::read_channel_bytes(pixel_bytes, bit_depth, 0);
                         let alpha = Self::read_channel_bytes(pixel_bytes, bit_depth, 1);
-                        let ycbcr = YCbCr::new(gamma, 127, 127);
-                        let rgb: Rgb = Rgb::from(&ycbcr);
 
-                        Rgba::new(rgb.r, rgb.g, rgb.b, alpha)
+                        Rgba16::new(gamma, gamma, gamma, alpha)
                     }
                     ColorType::TrueColorAlpha => {
                         let r = Self::read_channel_bytes(pixel_bytes, bit_depth, 0);
@@ -183,10 +173,14 @@ impl ColorType {
                         let b = Self::read_channel_bytes(pixel_bytes, bit_depth, 2);
                         let a = Self::read_channel_bytes(pixel_bytes, bit_depth, 3);
 
-                        Rgba::new(r, g, b, a)
+                        Rgba16::new(r, g, b, a)
                     }
                 };
 
+                if Self::matches_transparency_key(pixel_bytes, bit_depth, transparency) {
+                    pixel.a = 0;
+                }
+
                 pixel_row.push(pixel);
             }
 
@@ -218,17 +212,71 @@ impl ColorType {
         Ok(())
     }
 
-    fn read_channel_bytes(pixel_bytes: &[u8], bit_depth: u8, value_idx: usize) -> u8 {
+    fn matches_transparency_key(
+        pixel_bytes: &[u8],
+        bit_depth: u8,
+        transparency: &Option<Transparency>,
+    ) -> bool {
+        match transparency {
+            Some(Transparency::GreyscaleKey(grey)) => {
+                Self::read_sample(pixel_bytes, bit_depth, 0) == *grey
+            }
+            Some(Transparency::TruecolorKey(r, g, b)) => {
+                Self::read_sample(pixel_bytes, bit_depth, 0) == *r
+                    && Self::read_sample(pixel_bytes, bit_depth, 1) == *g
+                    && Self::read_sample(pixel_bytes, bit_depth, 2) == *b
+            }
+            _ => false,
+        }
+    }
+
+    fn read_sample(pixel_bytes: &[u8], bit_depth: u8, value_idx: usize) -> u16 {
         if bit_depth == 8 {
-            pixel_bytes[value_idx]
+            pixel_bytes[value_idx] as u16
         } else {
-            let first = pixel_bytes[value_idx * 2];
-            let second = pixel_bytes[value_idx + 1];
+            u16::from_be_bytes([pixel_bytes[value_idx * 2], pixel_bytes[value_idx * 2 + 1]])
+        }
+    }
 
-            first.saturating_add(second >> 7)
+    pub fn create_transparency_key(&self, color: &Rgba16, bit_depth: u8) -> Option<Transparency> {
+        match self {
+            ColorType::Greyscale => Some(Transparency::GreyscaleKey(Self::scale_to_sample(
+                color.luma(),
+                bit_depth,
+            ))),
+            ColorType::Truecolor => Some(Transparency::TruecolorKey(
+                Self::scale_to_sample(color.r, bit_depth),
+                Self::scale_to_sample(color.g, bit_depth),
+                Self::scale_to_sample(color.b, bit_depth),
+            )),
+            _ => None,
         }
     }
 
+    pub fn scale_to_sample(value: u16, bit_depth: u8) -> u16 {
+        let max_sample = (1u32 << bit_depth) - 1;
+
+        ((value as u32 * max_sample + (u16::MAX >> 1) as u32) / u16::MAX as u32) as u16
+    }
+
+    pub fn scale_from_sample(sample: u16, bit_depth: u8) -> u16 {
+        let max_sample = (1u32 << bit_depth) - 1;
+
+        // 65535 is divisible by 2^n - 1 for every n dividing 16, so this is exact
+        (sample as u32 * (u16::MAX as u32 / max_sample)) as u16
+    }
+
+    pub fn is_exact_at_bit_depth(value: u16, bit_depth: u8) -> bool {
+        Self::scale_from_sample(Self::scale_to_sample(value, bit_depth), bit_depth) == value
+    }
+
+    fn read_channel_bytes(pixel_bytes: &[u8], bit_depth: u8, value_idx: usize) -> u16 {
+        Self::scale_from_sample(
+            Self::read_sample(pixel_bytes, bit_depth, value_idx),
+            bit_depth,
+        )
+    }
+
     pub fn samples_per_pixel(&self) -> usize {
         match self {
             ColorType::Greyscale => 1,
@@ -239,38 +287,57 @@ impl ColorType {
         }
     }
 
+    // an index past the end of the palette is corrupt data, not a reason to panic
+    fn palette_color(palette: &Option<Vec<Rgba>>, index: u8) -> Result<Rgba16, PngParseError> {
+        let palette = palette.as_ref().ok_or_else(|| {
+            PngParseError::new(ErrorKind::InvalidData, "Indexed color image has no palette")
+        })?;
+
+        palette.get(index as usize).map(Rgba16::from).ok_or_else(|| {
+            PngParseError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Palette index {index} is out of range for a palette of {} colors",
+                    palette.len()
+                ),
+            )
+        })
+    }
+
     fn scanline_to_pixels_bit_aligned(
         &self,
         scanlines: &[Vec<u8>],
         bit_depth: u8,
         width: usize,
         palette: &Option<Vec<Rgba>>,
-    ) -> Result<Vec<Vec<Rgba>>, PngParseError> {
+        transparency: &Option<Transparency>,
+    ) -> Result<Vec<Vec<Rgba16>>, PngParseError> {
         let mut pixels = Vec::with_capacity(scanlines.len());
 
         for scanline in scanlines {
-            let row_size = (self.samples_per_pixel() * scanline.len() * 8) / bit_depth as usize;
-            let mut bitstream = ReadBitStream::new(scanline);
+            // the last byte of a row may be padded with bits that are not pixels
+            let row_size = width.min((scanline.len() * 8) / bit_depth as usize);
             let mut pixel_row = Vec::with_capacity(width);
 
             while pixel_row.len() < row_size {
-                let value = bitstream.read_number_lsb(bit_depth as usize).unwrap() as u8;
+                // the leftmost pixel is in the highest bits of a byte
+                let bit_index = pixel_row.len() * bit_depth as usize;
+                let value = (scanline[bit_index >> 3] >> (8 - bit_depth as usize - (bit_index & 0b111)))
+                    & ((1 << bit_depth) - 1);
 
                 let pixel = match self {
                     ColorType::Greyscale => {
-                        let gamma = Self::repeat_value_in_byte(value, bit_depth);
-                        let ycbcr = YCbCr::new(gamma, 127, 127);
+                        let gamma = Self::scale_from_sample(value as u16, bit_depth);
+                        let mut pixel = Rgba16::new(gamma, gamma, gamma, u16::MAX);
 
-                        ycbcr.into()
-                    }
-                    ColorType::IndexedColor => {
-                        let color = &palette
-                            .as_ref()
-                            .expect("scanline_to_pixels called with indexed color but no palette")
-                            [value as usize];
+                        if matches!(transparency, Some(Transparency::GreyscaleKey(grey)) if *grey == value as u16)
+                        {
+                            pixel.a = 0;
+                        }
 
-                        color.clone()
+                        pixel
                     }
+                    ColorType::IndexedColor => Self::palette_color(palette, value)?,
                     _ => panic!(
                     "scanline_to_pixels called with less than 8 bits for non compatible color type"
                 ),
@@ -284,17 +351,6 @@ impl ColorType {
 
         Ok(pixels)
     }
-
-    fn repeat_value_in_byte(value: u8, bit_depth: u8) -> u8 {
-        let mut byte = 0;
-
-        for _i in 0..(8 / bit_depth) {
-            byte += value;
-            byte <<= bit_depth;
-        }
-
-        byte
-    }
 }
 
 impl TryFrom<u8> for ColorType {
@@ -307,7 +363,10 @@ impl TryFrom<u8> for ColorType {
             3 => Ok(Self::IndexedColor),
             4 => Ok(Self::GreyscaleAlpha),
             6 => Ok(Self::TrueColorAlpha),
-            _ => Err(PngParseError(format!("Unrecognized color type {value}"))),
+            _ => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Unrecognized color type {value}"),
+            )),
         }
     }
 }
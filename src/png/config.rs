@@ -1,51 +1,206 @@
-use crate::colors::Rgba;
+use crate::colors::Rgba16;
 
-use super::{color_type::InvalidBitDepthError, ColorType, InterlaceMethod};
+use super::{
+    chunks::{
+        color_space::ColorSpace, metadata::PngMetadata, text::PngText, unknown::UnknownChunk,
+    },
+    consts::IDAT_CHUNK_MAX_SIZE,
+    filter::AdaptiveFilterType,
+    palette::find_transparency_key_color,
+    ColorType, InterlaceMethod, PngEncodeError,
+};
+
+// like zlib, 0 stores the data uncompressed, 1 is the fastest and 9 the smallest. EXTREME goes past
+// 9, spending a lot more time for a few more bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompressionLevel(u8);
 
-#[derive(Clone, Copy, Debug)]
-pub enum CompressionLevel {
-    None,
-    Best,
-    Fast,
-}
 impl CompressionLevel {
+    pub const NONE: Self = Self(0);
+    pub const FAST: Self = Self(1);
+    pub const DEFAULT: Self = Self(6);
+    pub const BEST: Self = Self(9);
+    pub const EXTREME: Self = Self(10);
+
+    // levels 0 to 9
+    pub fn new(level: u8) -> Option<Self> {
+        (level <= Self::BEST.0).then_some(Self(level))
+    }
+
+    // 10 for EXTREME
+    pub fn level(self) -> u8 {
+        self.0
+    }
+
+    // the FLEVEL field of the zlib header, grouping levels the way zlib does
     pub fn to_zlib_u8(self) -> u8 {
-        match self {
-            CompressionLevel::None => 0,
-            CompressionLevel::Best => 3,
-            CompressionLevel::Fast => 1,
+        match self.0 {
+            0..=1 => 0,
+            2..=5 => 1,
+            6 => 2,
+            _ => 3,
         }
     }
 }
 
 impl Default for CompressionLevel {
     fn default() -> Self {
-        Self::Fast
+        Self::FAST
+    }
+}
+
+// how the filter for each scanline is picked, trading encode time for size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterStrategy {
+    // the same filter for every scanline
+    Fixed(AdaptiveFilterType),
+    // the filter whose output has the smallest sum of absolute (signed) byte values
+    MinimumSumOfAbsoluteDifferences,
+    // the filter whose output has the lowest byte entropy
+    Entropy,
+    // the filter whose output deflates to the fewest bytes, by actually compressing every option
+    BruteForce,
+}
+
+impl FilterStrategy {
+    pub fn for_compression_level(compression_level: CompressionLevel) -> Self {
+        match compression_level.level() {
+            0 => FilterStrategy::Fixed(AdaptiveFilterType::None),
+            1..=3 => FilterStrategy::Fixed(AdaptiveFilterType::Paeth),
+            4..=8 => FilterStrategy::MinimumSumOfAbsoluteDifferences,
+            _ => FilterStrategy::BruteForce,
+        }
+    }
+}
+
+// how the entries of a generated palette are numbered, translucent entries always come first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteOrder {
+    // the most used colors get the lowest indices
+    Frequency,
+    // darkest to lightest, so neighbouring indices tend to look alike
+    Luminance,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dithering {
+    #[default]
+    None,
+    FloydSteinberg,
+    // an 8x8 bayer matrix, which keeps flat areas flat and does not smear errors across the image
+    Ordered,
+}
+
+// lossy reduction of the image to a palette of at most number_of_colors colors, built with median
+// cut and refined with k-means
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quantization {
+    pub(crate) number_of_colors: usize,
+    pub(crate) dithering: Dithering,
+    pub(crate) k_means_iterations: usize,
+}
+
+impl Quantization {
+    pub fn new(number_of_colors: usize) -> Self {
+        Self {
+            number_of_colors,
+            dithering: Dithering::None,
+            k_means_iterations: 10,
+        }
+    }
+
+    pub fn dithering(mut self, dithering: Dithering) -> Self {
+        self.dithering = dithering;
+        self
+    }
+
+    // zero keeps the median cut palette as it is
+    pub fn k_means_iterations(mut self, k_means_iterations: usize) -> Self {
+        self.k_means_iterations = k_means_iterations;
+        self
+    }
+
+    // an image with fewer colors than asked for keeps all of them
+    fn smallest_bit_depth(&self, number_of_unique_colors: usize) -> u8 {
+        let number_of_colors = self.number_of_colors.min(number_of_unique_colors);
+
+        [1, 2, 4, 8]
+            .into_iter()
+            .find(|bit_depth| number_of_colors <= 1 << bit_depth)
+            .unwrap_or(8)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChecksumPolicy {
+    #[default]
+    Error,
+    Warn,
+}
+
+#[derive(Debug, Default)]
+pub struct PngDecodeConfig {
+    pub(crate) checksum_policy: ChecksumPolicy,
+    pub(crate) convert_to_srgb: bool,
+    pub(crate) composite_frames: bool,
+}
+
+impl PngDecodeConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn checksum_policy(mut self, checksum_policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = checksum_policy;
+        self
+    }
+
+    pub fn convert_to_srgb(mut self, convert_to_srgb: bool) -> Self {
+        self.convert_to_srgb = convert_to_srgb;
+        self
+    }
+
+    pub fn composite_frames(mut self, composite_frames: bool) -> Self {
+        self.composite_frames = composite_frames;
+        self
     }
 }
 
 pub struct PngConfig {
     pub compression_level: CompressionLevel,
+    pub filter_strategy: FilterStrategy,
     pub color_type: ColorType,
     pub bit_depth: u8,
     pub interlace_method: InterlaceMethod,
+    pub transparency_key_color: Option<Rgba16>,
+    pub text: Vec<PngText>,
+    pub color_space: ColorSpace,
+    pub metadata: PngMetadata,
+    pub unknown_chunks: Vec<UnknownChunk>,
+    pub idat_size: Option<usize>,
+    pub palette_order: Option<PaletteOrder>,
+    pub quantization: Option<Quantization>,
 }
 
+#[derive(Clone, Debug, Default)]
 pub struct PartialPngConfig {
     compression_level: Option<CompressionLevel>,
+    filter_strategy: Option<FilterStrategy>,
     color_type: Option<ColorType>,
     bit_depth: Option<u8>,
     interlace_method: Option<InterlaceMethod>,
+    text: Vec<PngText>,
+    color_space: ColorSpace,
+    metadata: PngMetadata,
+    unknown_chunks: Vec<UnknownChunk>,
+    idat_size: Option<usize>,
+    palette_order: Option<PaletteOrder>,
+    quantization: Option<Quantization>,
 }
 
 impl PartialPngConfig {
     pub fn new() -> Self {
-        Self {
-            compression_level: None,
-            color_type: None,
-            bit_depth: None,
-            interlace_method: None,
-        }
+        Self::default()
     }
 
     pub fn interlace_method(mut self, interlace_method: InterlaceMethod) -> Self {
@@ -58,6 +213,12 @@ impl PartialPngConfig {
         self
     }
 
+    // defaults to a strategy that suits the compression level
+    pub fn filter_strategy(mut self, filter_strategy: FilterStrategy) -> Self {
+        self.filter_strategy = Some(filter_strategy);
+        self
+    }
+
     pub fn bit_depth(mut self, bit_depth: u8) -> Self {
         self.bit_depth = Some(bit_depth);
         self
@@ -67,6 +228,44 @@ impl PartialPngConfig {
         self.color_type = Some(color_type);
         self
     }
+
+    pub fn text(mut self, text: PngText) -> Self {
+        self.text.push(text);
+        self
+    }
+
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    pub fn metadata(mut self, metadata: PngMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn unknown_chunk(mut self, unknown_chunk: UnknownChunk) -> Self {
+        self.unknown_chunks.push(unknown_chunk);
+        self
+    }
+
+    // the most compressed bytes to put in each IDAT chunk
+    pub fn idat_size(mut self, idat_size: usize) -> Self {
+        self.idat_size = Some(idat_size);
+        self
+    }
+
+    // without an order the palette keeps the order median cut leaves it in
+    pub fn palette_order(mut self, palette_order: PaletteOrder) -> Self {
+        self.palette_order = Some(palette_order);
+        self
+    }
+
+    // makes the image indexed color, whatever colors it has
+    pub fn quantization(mut self, quantization: Quantization) -> Self {
+        self.quantization = Some(quantization);
+        self
+    }
 }
 
 impl PngConfig {
@@ -78,61 +277,187 @@ impl PngConfig {
     ) -> Self {
         Self {
             compression_level,
+            filter_strategy: FilterStrategy::for_compression_level(compression_level),
             color_type,
             bit_depth,
             interlace_method,
+            transparency_key_color: None,
+            text: Vec::new(),
+            color_space: ColorSpace::default(),
+            metadata: PngMetadata::default(),
+            unknown_chunks: Vec::new(),
+            idat_size: None,
+            palette_order: None,
+            quantization: None,
         }
     }
 
-    pub fn create_from_partial(partial_config: PartialPngConfig, unique_colors: &[Rgba]) -> Self {
+    pub fn create_from_partial(
+        partial_config: PartialPngConfig,
+        unique_colors: &[Rgba16],
+    ) -> Result<Self, PngEncodeError> {
         let number_of_unique_colors = unique_colors.len();
-        let should_colors_be_indexed = number_of_unique_colors <= u8::MAX.into();
+        let is_8_bit_exact = unique_colors.iter().all(|color| color.is_8_bit_exact());
+        let should_colors_be_indexed = number_of_unique_colors <= u8::MAX.into() && is_8_bit_exact;
         let has_alpha = unique_colors.iter().any(|color| !color.is_opaque());
         let is_greyscale = unique_colors.iter().all(|color| color.is_greyscale());
+        let transparency_key_color = find_transparency_key_color(unique_colors);
+        let needs_alpha_channel = has_alpha && transparency_key_color.is_none();
 
         let color_type: ColorType = partial_config.color_type.unwrap_or({
-            if is_greyscale {
-                if has_alpha {
+            if partial_config.quantization.is_some() {
+                ColorType::IndexedColor
+            } else if is_greyscale {
+                if needs_alpha_channel {
                     ColorType::GreyscaleAlpha
                 } else {
                     ColorType::Greyscale
                 }
             } else if should_colors_be_indexed {
                 ColorType::IndexedColor
-            } else if has_alpha {
+            } else if needs_alpha_channel {
                 ColorType::TrueColorAlpha
             } else {
                 ColorType::Truecolor
             }
         });
-        let bit_depth = partial_config.bit_depth.unwrap_or(match color_type {
-            ColorType::IndexedColor | ColorType::Greyscale => {
-                if number_of_unique_colors <= 2 {
-                    1
-                } else if number_of_unique_colors <= 4 {
-                    2
-                } else {
-                    8
-                }
+        let quantized_bit_depth = partial_config
+            .quantization
+            .as_ref()
+            .map(|quantization| quantization.smallest_bit_depth(number_of_unique_colors));
+        let bit_depth =
+            partial_config
+                .bit_depth
+                .unwrap_or(match (color_type, quantized_bit_depth) {
+                    (ColorType::IndexedColor, Some(quantized_bit_depth)) => quantized_bit_depth,
+                    (ColorType::IndexedColor, _) if is_8_bit_exact => {
+                        if number_of_unique_colors <= 2 {
+                            1
+                        } else if number_of_unique_colors <= 4 {
+                            2
+                        } else {
+                            8
+                        }
+                    }
+                    (ColorType::IndexedColor, _) => 8,
+                    // a lower depth only when every grey level survives it, 16 bits always do
+                    (ColorType::Greyscale, _) if is_greyscale => [1, 2, 4, 8, 16]
+                        .into_iter()
+                        .find(|bit_depth| {
+                            unique_colors
+                                .iter()
+                                .all(|color| ColorType::is_exact_at_bit_depth(color.r, *bit_depth))
+                        })
+                        .unwrap_or(16),
+                    _ if is_8_bit_exact => 8,
+                    _ => 16,
+                });
+
+        Self::from_partial(
+            partial_config,
+            color_type,
+            bit_depth,
+            transparency_key_color,
+        )
+    }
+
+    // the rows of a streamed image are not known up front, so it defaults to 8 bit RGBA and cannot
+    // have a palette or be interlaced
+    pub fn create_for_stream(partial_config: PartialPngConfig) -> Result<Self, PngEncodeError> {
+        let color_type = partial_config
+            .color_type
+            .unwrap_or(ColorType::TrueColorAlpha);
+        let bit_depth = partial_config.bit_depth.unwrap_or(8);
+
+        if matches!(color_type, ColorType::IndexedColor) {
+            return Err(PngEncodeError::InvalidOption(
+                "A streamed png cannot use indexed color, the palette would need every pixel"
+                    .to_string(),
+            ));
+        }
+
+        if matches!(
+            partial_config.interlace_method,
+            Some(InterlaceMethod::Adam7)
+        ) {
+            return Err(PngEncodeError::InvalidOption(
+                "A streamed png cannot be interlaced, every adam7 pass needs the whole image"
+                    .to_string(),
+            ));
+        }
+
+        if partial_config.quantization.is_some() {
+            return Err(PngEncodeError::InvalidOption(
+                "A streamed png cannot be quantized, the palette would need every pixel"
+                    .to_string(),
+            ));
+        }
+
+        Self::from_partial(partial_config, color_type, bit_depth, None)
+    }
+
+    fn from_partial(
+        partial_config: PartialPngConfig,
+        color_type: ColorType,
+        bit_depth: u8,
+        transparency_key_color: Option<Rgba16>,
+    ) -> Result<Self, PngEncodeError> {
+        color_type.check_bit_depth_validty(bit_depth)?;
+
+        for text in partial_config.text.iter() {
+            text.check_keyword_validity()?;
+        }
+
+        for suggested_palette in partial_config.metadata.suggested_palettes.iter() {
+            suggested_palette.check_validity()?;
+        }
+
+        for unknown_chunk in partial_config.unknown_chunks.iter() {
+            unknown_chunk.check_chunk_type_validity()?;
+        }
+
+        if let Some(quantization) = partial_config.quantization {
+            if !(1..=256).contains(&quantization.number_of_colors) {
+                return Err(PngEncodeError::InvalidOption(format!(
+                    "Cannot quantize to {} colors, a palette holds 1 to 256",
+                    quantization.number_of_colors
+                )));
             }
-            _ => 8,
-        });
 
-        if let Err(InvalidBitDepthError(color_type, bit_depth)) =
-            color_type.check_bit_depth_validty(bit_depth)
-        {
-            panic!(
-                "Invalid bit depth {} for color type {}",
-                bit_depth,
-                Into::<u8>::into(&color_type),
-            );
+            if !matches!(color_type, ColorType::IndexedColor) {
+                return Err(PngEncodeError::InvalidOption(format!(
+                    "Quantization needs indexed color, not color type {}",
+                    Into::<u8>::into(&color_type),
+                )));
+            }
+        }
+
+        if let Some(idat_size) = partial_config.idat_size {
+            if idat_size == 0 || idat_size > IDAT_CHUNK_MAX_SIZE as usize {
+                return Err(PngEncodeError::InvalidOption(format!(
+                    "Invalid IDAT chunk size {idat_size}"
+                )));
+            }
         }
 
-        Self {
-            compression_level: partial_config.compression_level.unwrap_or_default(),
+        let compression_level = partial_config.compression_level.unwrap_or_default();
+
+        Ok(Self {
+            compression_level,
+            filter_strategy: partial_config
+                .filter_strategy
+                .unwrap_or(FilterStrategy::for_compression_level(compression_level)),
             interlace_method: partial_config.interlace_method.unwrap_or_default(),
             color_type,
             bit_depth,
-        }
+            transparency_key_color,
+            text: partial_config.text,
+            color_space: partial_config.color_space,
+            metadata: partial_config.metadata,
+            unknown_chunks: partial_config.unknown_chunks,
+            idat_size: partial_config.idat_size,
+            palette_order: partial_config.palette_order,
+            quantization: partial_config.quantization,
+        })
     }
 }
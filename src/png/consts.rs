@@ -1,8 +1,10 @@
 pub const PNG_SIGNATURE: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
 pub const CHUNK_METADATA_LENGTH: usize = 12;
+pub const CHUNK_DATA_MAX_LENGTH: u32 = (2u32).pow(31) - 1;
 
 pub const IHDR_CHUNK_TYPE: &[u8] = &[0x49, 0x48, 0x44, 0x52];
 pub const IHDR_DATA_LENGTH: usize = 13;
+pub const IMAGE_DIMENSION_MAX: u32 = (2u32).pow(31) - 1;
 
 pub const IDAT_CHUNK_TYPE: &[u8] = &[0x49, 0x44, 0x41, 0x54];
 pub const IDAT_CHUNK_MAX_SIZE: u32 = (2u32).pow(31) - 1;
@@ -10,3 +12,26 @@ pub const IDAT_CHUNK_MAX_SIZE: u32 = (2u32).pow(31) - 1;
 pub const IEND_CHUNK_TYPE: &[u8] = &[0x49, 0x45, 0x4e, 0x44];
 
 pub const PLTE_CHUNK_TYPE: &[u8] = &[0x50, 0x4C, 0x54, 0x45];
+
+pub const TRNS_CHUNK_TYPE: &[u8] = &[0x74, 0x52, 0x4E, 0x53];
+
+pub const TEXT_CHUNK_TYPE: &[u8] = &[0x74, 0x45, 0x58, 0x74];
+pub const ZTXT_CHUNK_TYPE: &[u8] = &[0x7A, 0x54, 0x58, 0x74];
+pub const ITXT_CHUNK_TYPE: &[u8] = &[0x69, 0x54, 0x58, 0x74];
+
+pub const GAMA_CHUNK_TYPE: &[u8] = &[0x67, 0x41, 0x4D, 0x41];
+pub const CHRM_CHUNK_TYPE: &[u8] = &[0x63, 0x48, 0x52, 0x4D];
+pub const SRGB_CHUNK_TYPE: &[u8] = &[0x73, 0x52, 0x47, 0x42];
+pub const ICCP_CHUNK_TYPE: &[u8] = &[0x69, 0x43, 0x43, 0x50];
+
+pub const PHYS_CHUNK_TYPE: &[u8] = &[0x70, 0x48, 0x59, 0x73];
+pub const TIME_CHUNK_TYPE: &[u8] = &[0x74, 0x49, 0x4D, 0x45];
+pub const BKGD_CHUNK_TYPE: &[u8] = &[0x62, 0x4B, 0x47, 0x44];
+pub const SBIT_CHUNK_TYPE: &[u8] = &[0x73, 0x42, 0x49, 0x54];
+pub const HIST_CHUNK_TYPE: &[u8] = &[0x68, 0x49, 0x53, 0x54];
+pub const SPLT_CHUNK_TYPE: &[u8] = &[0x73, 0x50, 0x4C, 0x54];
+pub const EXIF_CHUNK_TYPE: &[u8] = &[0x65, 0x58, 0x49, 0x66];
+
+pub const ACTL_CHUNK_TYPE: &[u8] = &[0x61, 0x63, 0x54, 0x4C];
+pub const FCTL_CHUNK_TYPE: &[u8] = &[0x66, 0x63, 0x54, 0x4C];
+pub const FDAT_CHUNK_TYPE: &[u8] = &[0x66, 0x64, 0x41, 0x54];
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct WriteBitStream {
     pub stream: Vec<u8>,
     pub buffer: u64,
@@ -9,11 +9,7 @@ pub struct WriteBitStream {
 
 impl WriteBitStream {
     pub fn new() -> Self {
-        Self {
-            stream: Vec::new(),
-            buffer: 0,
-            current_bit_number: 0,
-        }
+        Self::default()
     }
 
     pub fn from_u32_ltr(num: u32, length: u8) -> Self {
@@ -95,14 +91,33 @@ impl WriteBitStream {
         self.flush_buffer();
     }
 
+    // pads the unfinished byte with zeros
+    pub fn align_to_next_byte(&mut self) {
+        if self.current_bit_number != 0 {
+            self.current_bit_number = 8;
+            self.flush_buffer();
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.stream.len() * 8 + (self.current_bit_number as usize)
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn reset(&mut self) {
         *self = Self::new();
     }
 
+    // takes the complete bytes and keeps the bits of an unfinished one
+    pub fn take_bytes(&mut self) -> Vec<u8> {
+        self.flush_buffer();
+
+        std::mem::take(&mut self.stream)
+    }
+
     pub fn flush_to_bytes(&mut self) -> Vec<u8> {
         self.flush_buffer();
         let mut bytes = std::mem::take(&mut self.stream);
@@ -150,6 +165,22 @@ impl<'a> ReadBitStream<'a> {
         }
     }
 
+    pub fn with_bit_index(bytes: &'a [u8], bit_index: usize) -> Self {
+        Self { bytes, bit_index }
+    }
+
+    pub fn bit_index(&self) -> usize {
+        self.bit_index
+    }
+
+    pub fn byte_offset(&self) -> usize {
+        self.bit_index >> 3
+    }
+
+    pub fn remaining_byte_count(&self) -> usize {
+        self.bytes.len().saturating_sub(self.bit_index.div_ceil(8))
+    }
+
     pub fn read_bit(&mut self) -> Option<u8> {
         let byte = self.bytes.get(self.bit_index >> 3)?;
         let bit = (byte >> (self.bit_index & 0b111)) & 1;
@@ -163,13 +194,40 @@ impl<'a> ReadBitStream<'a> {
     }
 
     pub fn read_number_lsb(&mut self, length: usize) -> Option<u16> {
-        let mut number: u16 = 0;
+        let (number, available_length) = self.peek_bits(length);
 
-        for shift in 0..length {
-            number |= (self.read_bit()? << shift) as u16;
+        if available_length < length {
+            return None;
         }
 
-        Some(number)
+        self.bit_index += length;
+
+        Some(number as u16)
+    }
+
+    // the next length bits (at most 25) without reading them, the first one in the lowest bit. bits
+    // past the end are zero, so it also returns how many of them are actually there
+    pub fn peek_bits(&self, length: usize) -> (u32, usize) {
+        let byte_index = self.bit_index >> 3;
+        let word = match self.bytes.get(byte_index..byte_index + 4) {
+            Some(word) => u32::from_le_bytes(word.try_into().unwrap()),
+            None => {
+                let mut word = [0; 4];
+                let tail = self.bytes.get(byte_index..).unwrap_or(&[]);
+                word[..tail.len()].copy_from_slice(tail);
+                u32::from_le_bytes(word)
+            }
+        };
+        let bits = (word >> (self.bit_index & 0b111)) & ((1 << length) - 1);
+        let available_length = (self.bytes.len() << 3)
+            .saturating_sub(self.bit_index)
+            .min(length);
+
+        (bits, available_length)
+    }
+
+    pub fn skip_bits(&mut self, length: usize) {
+        self.bit_index += length;
     }
 
     pub fn align_to_next_byte(&mut self) {
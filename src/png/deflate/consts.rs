@@ -1,6 +1,10 @@
 pub const MAX_UNCOMPRESSED_BLOCK_SIZE: u16 = u16::MAX;
 // pub const LZSS_WINDOW_SIZE: usize = 10_000;
 pub const LZSS_WINDOW_SIZE: usize = u16::MAX as usize / 2;
+// the furthest back a back-reference can reach
+pub const WINDOW_SIZE: usize = 32 * 1024;
+// how many bytes the encoder collects before compressing them into blocks
+pub const BLOCK_INPUT_SIZE: usize = 256 * 1024;
 pub const END_OF_BLOCK_MARKER_VALUE: u16 = 256;
 pub const MAX_SYMBOL_CODE_LENGTH: usize = 15;
 pub const MAX_CL_CODE_LENGTH: usize = 7;
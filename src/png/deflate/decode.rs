@@ -1,26 +1,64 @@
-use std::collections::HashMap;
+use std::{error, fmt, mem};
 
+use crate::error::ErrorKind;
 use crate::png::deflate::{
-    huffman::construct_canonical_tree_from_lengths,
     lzss::backreference::{
-        DISTANCE_CODE_TO_BASE_DISTANCE, DISTANCE_CODE_TO_EXTRA_BITS, LENGTH_CODE_TO_BASE_LENGTH,
-        LENGTH_CODE_TO_EXTRA_BITS,
+        DISTANCE_CODE_TO_BASE_DISTANCE, DISTANCE_CODE_TO_EXTRA_BITS, DISTANCE_TABLE_SIZE,
+        LENGTH_CODE_TO_BASE_LENGTH, LENGTH_CODE_TO_EXTRA_BITS, LL_TABLE_SIZE,
     },
     prefix_table::CLCode,
 };
 
 use super::{
     bitstream::{ReadBitStream, WriteBitStream},
-    consts::{CL_ALPHABET, END_OF_BLOCK_MARKER_VALUE},
-    lzss::{decode_lzss, LzssSymbol},
-    prefix_table::{
-        generate_static_distance_table, generate_static_lit_len_table, reverse_hashmap,
-    },
+    consts::{CL_ALPHABET, END_OF_BLOCK_MARKER_VALUE, MAX_SYMBOL_CODE_LENGTH, WINDOW_SIZE},
+    lzss::LzssSymbol,
+    prefix_table::generate_static_lit_len_table,
     DeflateBlockType,
 };
 
+// codes up to this long are resolved with one lookup, longer ones take a second lookup in a sub-table
+const PRIMARY_TABLE_BITS: usize = 9;
+const FIXED_DISTANCE_CODE_LENGTH: u8 = 5;
+// HLIT and HDIST can describe more codes than there are lengths and distances
+const MAX_LITERAL_LENGTH_CODES: u16 = 286;
+const MAX_DISTANCE_CODES: u16 = 30;
+
 #[derive(Debug)]
-pub struct DeflateDecodeError(pub String);
+pub struct DeflateDecodeError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub offset: Option<usize>,
+}
+
+impl DeflateDecodeError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            offset: None,
+        }
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl fmt::Display for DeflateDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deflate decode error ({}): {}", self.kind, self.message)?;
+
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {offset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for DeflateDecodeError {}
 
 #[macro_export]
 macro_rules! deflate_read_bits {
@@ -28,183 +66,593 @@ macro_rules! deflate_read_bits {
         match $read_value {
             Some(value) => value,
             None => {
-                return Err(DeflateDecodeError(format!(
-                    "DEFLATE bitstream ended unexpectedly: {}",
-                    $msg
-                )));
+                return Err($crate::png::deflate::DeflateDecodeError::new(
+                    $crate::ErrorKind::UnexpectedEof,
+                    format!("DEFLATE bitstream ended unexpectedly: {}", $msg),
+                ));
             }
         }
     };
 }
 
 pub fn decode_deflate(bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
-    let mut bitsream = ReadBitStream::new(bytes);
-    let mut result = Vec::new();
+    let mut inflater = DeflateInflater::new();
+    let mut result = inflater.push(bytes)?;
+    result.extend(inflater.finish()?);
 
-    loop {
-        let is_last = deflate_read_bits!(bitsream.read_bit_boolean(), "expected new block");
-        let btype = deflate_read_bits!(bitsream.read_number_lsb(2), "expected btype") as u8;
+    Ok(result)
+}
 
-        match DeflateBlockType::from_number(btype)? {
-            DeflateBlockType::None => parse_block_type_zero(&mut bitsream, &mut result)?,
-            DeflateBlockType::FixedHuffman => parse_block_type_one(&mut bitsream, &mut result)?,
-            DeflateBlockType::DynamicHuffman => parse_block_type_two(&mut bitsream, &mut result)?,
+struct HuffmanTables {
+    literal_length_table: HuffmanDecodeTable,
+    distance_table: HuffmanDecodeTable,
+}
+
+#[derive(Clone, Copy, Default)]
+struct TableEntry {
+    // the decoded symbol, or the index the sub-table starts at
+    value: u16,
+    // how many bits the code takes up, 0 if no code starts with these bits
+    length: u8,
+    // how many more bits index the sub-table, 0 for entries holding a symbol
+    sub_table_bits: u8,
+}
+
+// decodes huffman codes by looking up the next few bits at once. the entries are indexed by the bits
+// in the order they are read, so every code shorter than the index fills all the entries it is a
+// prefix of
+struct HuffmanDecodeTable {
+    primary_bits: usize,
+    // the primary table followed by the sub-tables
+    entries: Vec<TableEntry>,
+}
+
+impl HuffmanDecodeTable {
+    // the code lengths are indexed by symbol, with 0 for symbols that have no code. like zlib, codes
+    // have to use up every bit pattern, except for a lone one bit code where allows_single_code says
+    // so, which is how encoders describe a block with just one distance
+    fn from_lengths(lengths: &[u8], allows_single_code: bool) -> Result<Self, DeflateDecodeError> {
+        let mut length_counts = [0; MAX_SYMBOL_CODE_LENGTH + 1];
+        for &length in lengths {
+            if length as usize > MAX_SYMBOL_CODE_LENGTH {
+                return Err(DeflateDecodeError::new(
+                    ErrorKind::InvalidData,
+                    format!("Huffman code length {length} is too long"),
+                ));
+            }
+
+            length_counts[length as usize] += 1;
         }
+        length_counts[0] = 0;
 
-        if is_last {
-            break;
+        // canonical codes of each length follow the last code of the length before
+        let mut next_codes = [0; MAX_SYMBOL_CODE_LENGTH + 1];
+        let mut code = 0;
+        let mut unused_codes: i32 = 1;
+        for length in 1..=MAX_SYMBOL_CODE_LENGTH {
+            code = (code + length_counts[length - 1]) << 1;
+            next_codes[length] = code;
+            unused_codes = (unused_codes << 1) - length_counts[length] as i32;
+
+            if unused_codes < 0 {
+                return Err(DeflateDecodeError::new(
+                    ErrorKind::InvalidData,
+                    "Huffman code lengths describe more codes than fit",
+                ));
+            }
         }
+
+        let number_of_codes: usize = length_counts.iter().sum();
+        let is_single_code = number_of_codes == 1 && length_counts[1] == 1;
+
+        if unused_codes > 0 && number_of_codes > 0 && !(allows_single_code && is_single_code) {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::InvalidData,
+                "Huffman code lengths leave codes unused",
+            ));
+        }
+
+        let codes: Vec<(u16, usize, usize)> = lengths
+            .iter()
+            .enumerate()
+            .filter(|(_symbol, length)| **length != 0)
+            .map(|(symbol, &length)| {
+                let length = length as usize;
+                let code = next_codes[length];
+                next_codes[length] += 1;
+
+                (symbol as u16, reverse_bits(code, length), length)
+            })
+            .collect();
+
+        let max_length = codes
+            .iter()
+            .map(|(_, _, length)| *length)
+            .max()
+            .unwrap_or(1);
+        let primary_bits = max_length.min(PRIMARY_TABLE_BITS);
+        let primary_mask = (1 << primary_bits) - 1;
+        let mut entries = vec![TableEntry::default(); 1 << primary_bits];
+
+        // every sub-table is as big as the longest code sharing its prefix needs
+        let mut sub_table_bits = vec![0; 1 << primary_bits];
+        for &(_symbol, code, length) in &codes {
+            if length > primary_bits {
+                let bits = &mut sub_table_bits[code & primary_mask];
+                *bits = (*bits).max(length - primary_bits);
+            }
+        }
+
+        for (prefix, bits) in sub_table_bits.into_iter().enumerate() {
+            if bits != 0 {
+                entries[prefix] = TableEntry {
+                    value: entries.len() as u16,
+                    length: primary_bits as u8,
+                    sub_table_bits: bits as u8,
+                };
+                entries.resize(entries.len() + (1 << bits), TableEntry::default());
+            }
+        }
+
+        for (symbol, code, length) in codes {
+            let entry = TableEntry {
+                value: symbol,
+                length: length as u8,
+                sub_table_bits: 0,
+            };
+
+            if length <= primary_bits {
+                for index in (code..1 << primary_bits).step_by(1 << length) {
+                    entries[index] = entry;
+                }
+            } else {
+                let sub_table = entries[code & primary_mask];
+                let sub_table_start = sub_table.value as usize;
+                let sub_code = code >> primary_bits;
+
+                for index in
+                    (sub_code..1 << sub_table.sub_table_bits).step_by(1 << (length - primary_bits))
+                {
+                    entries[sub_table_start + index] = entry;
+                }
+            }
+        }
+
+        Ok(Self {
+            primary_bits,
+            entries,
+        })
     }
 
-    Ok(result)
+    fn from_codes(codes: impl IntoIterator<Item = (u16, WriteBitStream)>) -> Self {
+        let mut lengths = Vec::new();
+        for (symbol, code) in codes {
+            let symbol = symbol as usize;
+            lengths.resize(lengths.len().max(symbol + 1), 0);
+            lengths[symbol] = code.len() as u8;
+        }
+
+        Self::from_lengths(&lengths, false).expect("the static huffman codes are valid")
+    }
 }
 
-fn parse_block_type_zero(
-    reader: &mut ReadBitStream,
-    target: &mut Vec<u8>,
-) -> Result<(), DeflateDecodeError> {
-    reader.align_to_next_byte();
-    let len = deflate_read_bits!(reader.read_u16_lsb_le(), "expected block type 0 LEN");
-    let _nlen = deflate_read_bits!(reader.read_u16_lsb_le(), "expected block type 0 NLEN");
-    let bytes = deflate_read_bits!(
-        reader.read_bytes_aligned(len as usize),
-        format!(
-            "block with type 0 was too short, tried to read specified length: {}",
-            len
-        )
-    );
+// huffman codes are packed starting from their most significant bit
+fn reverse_bits(code: usize, length: usize) -> usize {
+    code.reverse_bits() >> (usize::BITS as usize - length)
+}
 
-    target.extend_from_slice(bytes);
+enum InflateState {
+    BlockHeader,
+    StoredBlock { remaining_length: usize },
+    CompressedBlock(Box<HuffmanTables>),
+    Finished,
+}
 
-    Ok(())
+// decompresses a deflate stream whose bytes arrive over time. it stops wherever the input runs out,
+// even in the middle of a block, and picks up from the last whole symbol once more input arrives, so
+// it only ever holds the window, the unfinished symbol and the output it has not handed out yet
+pub struct DeflateInflater {
+    // compressed bytes, starting at the byte that holds the next unread bit
+    input: Vec<u8>,
+    bit_index: usize,
+    input_offset: usize,
+    state: InflateState,
+    is_last_block: bool,
+    // the last decompressed bytes, which back-references can point into
+    window: Vec<u8>,
+    // why the input last ran out, reported if the stream turns out to end there
+    unexpected_eof: Option<DeflateDecodeError>,
 }
 
-fn parse_block_type_one(
-    reader: &mut ReadBitStream,
-    target: &mut Vec<u8>,
-) -> Result<(), DeflateDecodeError> {
-    let literal_length_table = reverse_hashmap(generate_static_lit_len_table());
-    let distance_table = reverse_hashmap(generate_static_distance_table());
+impl DeflateInflater {
+    pub fn new() -> Self {
+        Self {
+            input: Vec::new(),
+            bit_index: 0,
+            input_offset: 0,
+            state: InflateState::BlockHeader,
+            is_last_block: false,
+            window: Vec::new(),
+            unexpected_eof: None,
+        }
+    }
+
+    // returns everything that can be decompressed with the input so far
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
+        let mut output = Vec::new();
+        self.feed(bytes);
+        self.inflate(&mut output, usize::MAX)?;
+
+        Ok(output)
+    }
+
+    pub fn finish(&mut self) -> Result<Vec<u8>, DeflateDecodeError> {
+        let mut output = Vec::new();
+        self.inflate(&mut output, usize::MAX)?;
+
+        if !self.is_finished() {
+            let err = self
+                .unexpected_eof
+                .take()
+                .unwrap_or(DeflateDecodeError::new(
+                    ErrorKind::UnexpectedEof,
+                    "DEFLATE stream ended before its last block",
+                ));
 
-    decode_compressed_block(reader, target, &literal_length_table, &distance_table)
+            return Err(err.at_offset(self.input_offset + self.input.len()));
+        }
+
+        Ok(output)
+    }
+
+    // buffers compressed bytes without decompressing them yet
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.input.extend_from_slice(bytes);
+    }
+
+    // decompresses the buffered input into output until the input runs out, the stream ends or
+    // output holds at least max_output_length bytes
+    pub fn inflate(
+        &mut self,
+        output: &mut Vec<u8>,
+        max_output_length: usize,
+    ) -> Result<(), DeflateDecodeError> {
+        let input = mem::take(&mut self.input);
+        let mut reader = ReadBitStream::with_bit_index(&input, self.bit_index);
+        let result = loop {
+            if self.is_finished() || output.len() >= max_output_length {
+                break Ok(());
+            }
+
+            match self.inflate_step(&mut reader, output, max_output_length) {
+                Ok(()) => self.bit_index = reader.bit_index(),
+                Err(err) if err.kind == ErrorKind::UnexpectedEof => {
+                    self.unexpected_eof = Some(err);
+                    break Ok(());
+                }
+                Err(err) => break Err(err.at_offset(self.input_offset + reader.byte_offset())),
+            }
+        };
+
+        self.input = input;
+        let consumed_bytes = self.bit_index >> 3;
+        self.input.drain(..consumed_bytes);
+        self.input_offset += consumed_bytes;
+        self.bit_index &= 0b111;
+
+        result
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, InflateState::Finished)
+    }
+
+    // how many input bytes came before the unread ones
+    pub fn input_offset(&self) -> usize {
+        self.input_offset
+    }
+
+    // the bytes pushed after the end of the last block, such as a zlib checksum
+    pub fn trailing_bytes(&self) -> &[u8] {
+        match self.is_finished() {
+            true => &self.input,
+            false => &[],
+        }
+    }
+
+    // reads one block header, one symbol or part of a stored block. nothing changes when the input
+    // runs out partway, the step is just read again once there is more
+    fn inflate_step(
+        &mut self,
+        reader: &mut ReadBitStream,
+        output: &mut Vec<u8>,
+        max_output_length: usize,
+    ) -> Result<(), DeflateDecodeError> {
+        match &self.state {
+            InflateState::BlockHeader => {
+                let is_last = deflate_read_bits!(reader.read_bit_boolean(), "expected new block");
+                let btype = deflate_read_bits!(reader.read_number_lsb(2), "expected btype") as u8;
+
+                self.state = match DeflateBlockType::from_number(btype)? {
+                    DeflateBlockType::None => {
+                        reader.align_to_next_byte();
+                        let len = deflate_read_bits!(
+                            reader.read_u16_lsb_le(),
+                            "expected block type 0 LEN"
+                        );
+                        let nlen = deflate_read_bits!(
+                            reader.read_u16_lsb_le(),
+                            "expected block type 0 NLEN"
+                        );
+
+                        if nlen != !len {
+                            return Err(DeflateDecodeError::new(
+                                ErrorKind::InvalidData,
+                                format!("Stored block length {len} does not match its complement {nlen}"),
+                            ));
+                        }
+
+                        InflateState::StoredBlock {
+                            remaining_length: len as usize,
+                        }
+                    }
+                    DeflateBlockType::FixedHuffman => {
+                        InflateState::CompressedBlock(Box::new(read_fixed_tables()))
+                    }
+                    DeflateBlockType::DynamicHuffman => {
+                        InflateState::CompressedBlock(Box::new(read_dynamic_tables(reader)?))
+                    }
+                };
+                self.is_last_block = is_last;
+            }
+            InflateState::StoredBlock { remaining_length } => {
+                let length = (*remaining_length)
+                    .min(reader.remaining_byte_count())
+                    .min(max_output_length - output.len());
+                let bytes = deflate_read_bits!(
+                    (length > 0 || *remaining_length == 0)
+                        .then(|| reader.read_bytes_aligned(length))
+                        .flatten(),
+                    format!(
+                        "block with type 0 was too short, {remaining_length} more bytes expected"
+                    )
+                );
+                let remaining_length = remaining_length - length;
+
+                self.write_output(bytes, output);
+                self.state = match remaining_length {
+                    0 => self.end_block(reader),
+                    remaining_length => InflateState::StoredBlock { remaining_length },
+                };
+            }
+            InflateState::CompressedBlock(tables) => match read_symbol(reader, tables)? {
+                LzssSymbol::Literal(literal) => self.write_output(&[literal], output),
+                LzssSymbol::Backreference(distance, length) => {
+                    let (distance, length) = (distance as usize, length as usize);
+
+                    if distance > self.window.len() {
+                        return Err(DeflateDecodeError::new(
+                            ErrorKind::InvalidData,
+                            format!("Invalid backreference, distance {distance} is too big"),
+                        ));
+                    }
+
+                    //we must do this byte by byte in case there are repetitions
+                    let start = self.window.len() - distance;
+                    for i in start..start + length {
+                        let byte = self.window[i];
+                        self.window.push(byte);
+                        output.push(byte);
+                    }
+                    self.trim_window();
+                }
+                LzssSymbol::EndOfBlock => self.state = self.end_block(reader),
+            },
+            InflateState::Finished => {}
+        }
+
+        Ok(())
+    }
+
+    fn end_block(&self, reader: &mut ReadBitStream) -> InflateState {
+        if self.is_last_block {
+            reader.align_to_next_byte();
+
+            InflateState::Finished
+        } else {
+            InflateState::BlockHeader
+        }
+    }
+
+    fn write_output(&mut self, bytes: &[u8], output: &mut Vec<u8>) {
+        self.window.extend_from_slice(bytes);
+        output.extend_from_slice(bytes);
+        self.trim_window();
+    }
+
+    // the window is allowed to grow to twice its size, so it is not shifted after every byte
+    fn trim_window(&mut self) {
+        if self.window.len() > 2 * WINDOW_SIZE {
+            self.window.drain(..self.window.len() - WINDOW_SIZE);
+        }
+    }
 }
 
-fn parse_block_type_two(
-    reader: &mut ReadBitStream,
-    target: &mut Vec<u8>,
-) -> Result<(), DeflateDecodeError> {
+impl Default for DeflateInflater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read_fixed_tables() -> HuffmanTables {
+    HuffmanTables {
+        literal_length_table: HuffmanDecodeTable::from_codes(generate_static_lit_len_table()),
+        // the two distance codes past the last distance are part of the fixed code, they just
+        // never occur
+        distance_table: HuffmanDecodeTable::from_lengths(&[FIXED_DISTANCE_CODE_LENGTH; 32], false)
+            .expect("the static huffman codes are valid"),
+    }
+}
+
+fn read_dynamic_tables(reader: &mut ReadBitStream) -> Result<HuffmanTables, DeflateDecodeError> {
     let hlit = deflate_read_bits!(reader.read_number_lsb(5), "expected HLIT");
     let ll_table_length = hlit + 257;
 
     let hdist = deflate_read_bits!(reader.read_number_lsb(5), "expected HDIST");
     let distance_table_length = hdist + 1;
     let hclen = deflate_read_bits!(reader.read_number_lsb(4), "expected HLEN");
+
+    if ll_table_length > MAX_LITERAL_LENGTH_CODES || distance_table_length > MAX_DISTANCE_CODES {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Too many length or distance codes, {ll_table_length} and {distance_table_length}"
+            ),
+        ));
+    }
+
     let cl_table_length = hclen + 4;
 
-    let mut cl_codes_lengths: HashMap<u32, u32> = HashMap::new();
+    let mut cl_code_lengths = [0; CL_ALPHABET.len()];
     for i in 0..cl_table_length {
         let current_cl_length = deflate_read_bits!(reader.read_number_lsb(3), "expected CL code");
-
-        if current_cl_length != 0 {
-            cl_codes_lengths.insert(CL_ALPHABET[i as usize], current_cl_length as u32);
-        }
+        cl_code_lengths[CL_ALPHABET[i as usize] as usize] = current_cl_length as u8;
     }
 
-    let cl_codes = reverse_hashmap(construct_canonical_tree_from_lengths(&cl_codes_lengths));
+    let cl_table = HuffmanDecodeTable::from_lengths(&cl_code_lengths, false)?;
 
+    let number_of_lengths = (ll_table_length + distance_table_length) as usize;
     let mut ll_and_distance_lengths = Vec::new();
-    let mut current_code = 0;
-    let mut current_code_length = 0;
-    while (ll_and_distance_lengths.len() as u16) < ll_table_length + distance_table_length {
-        current_code <<= 1;
-        current_code_length += 1;
-        current_code =
-            match deflate_read_bits!(reader.read_bit(), "ll/distance CL codes ended abruptly") {
-                0 => current_code,
-                _ => current_code | 1,
-            };
-        let code = WriteBitStream::from_u32_ltr(current_code, current_code_length);
+    while ll_and_distance_lengths.len() < number_of_lengths {
+        let cl_code = CLCode::parse_from_bitstream(read_code(reader, &cl_table)? as u32, reader)?;
+        let previous_length = match (&cl_code, ll_and_distance_lengths.last()) {
+            (CLCode::Sixteen { .. }, None) => {
+                return Err(DeflateDecodeError::new(
+                    ErrorKind::InvalidData,
+                    "Code length repeat has no previous length to repeat",
+                ))
+            }
+            (_, previous_length) => previous_length.copied().unwrap_or(0),
+        };
+        ll_and_distance_lengths.extend_from_slice(&cl_code.expand(previous_length));
+    }
 
-        if let Some(cl_code) = cl_codes.get(&code) {
-            let cl_code = CLCode::parse_from_bitstream(*cl_code, reader)?;
-            ll_and_distance_lengths
-                .extend_from_slice(&cl_code.expand(*ll_and_distance_lengths.last().unwrap_or(&0)));
-            current_code = 0;
-            current_code_length = 0;
-        }
+    if ll_and_distance_lengths.len() > number_of_lengths {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            "Code length repeat runs past the last code length",
+        ));
     }
 
     let distance_code_lengths = ll_and_distance_lengths.split_off(ll_table_length as usize);
     let ll_code_lengths = ll_and_distance_lengths;
 
-    let literal_length_table = get_code_table_from_lengths(ll_code_lengths);
-    let distance_table = get_code_table_from_lengths(distance_code_lengths);
+    if ll_code_lengths[END_OF_BLOCK_MARKER_VALUE as usize] == 0 {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            "Literal/length code has no end of block marker",
+        ));
+    }
 
-    decode_compressed_block(reader, target, &literal_length_table, &distance_table)
+    Ok(HuffmanTables {
+        literal_length_table: get_code_table_from_lengths(ll_code_lengths)?,
+        distance_table: get_code_table_from_lengths(distance_code_lengths)?,
+    })
 }
 
-pub fn decode_compressed_block(
+// reads a literal, the end of block marker, or a length and distance as one symbol
+fn read_symbol(
     reader: &mut ReadBitStream,
-    target: &mut Vec<u8>,
-    literal_length_table: &HashMap<WriteBitStream, u16>,
-    distance_table: &HashMap<WriteBitStream, u16>,
-) -> Result<(), DeflateDecodeError> {
-    let mut lzss_stream: Vec<LzssSymbol> = Vec::new();
-    let mut current_length = 0;
-    let mut read_distance = false;
-    let mut code = WriteBitStream::new();
-    loop {
-        match deflate_read_bits!(reader.read_bit(), "data ended before end of block marker") {
-            0 => code.push_zero(),
-            _ => code.push_one(),
-        };
+    tables: &HuffmanTables,
+) -> Result<LzssSymbol, DeflateDecodeError> {
+    let value = read_code(reader, &tables.literal_length_table)?;
 
-        if read_distance {
-            if let Some(distance_code) = distance_table.get(&code) {
-                code.reset();
-                let base_distance = DISTANCE_CODE_TO_BASE_DISTANCE[*distance_code as usize];
-                let num_extra_bits = DISTANCE_CODE_TO_EXTRA_BITS[*distance_code as usize];
-                let extra_bits = deflate_read_bits!(
-                    reader.read_number_lsb(num_extra_bits),
-                    "data ended before end of block marker"
-                );
-                lzss_stream.push(LzssSymbol::Backreference(
-                    base_distance + extra_bits,
-                    current_length,
-                ));
-                read_distance = false;
-            }
-        } else if let Some(value) = literal_length_table.get(&code) {
-            if *value < END_OF_BLOCK_MARKER_VALUE {
-                lzss_stream.push(LzssSymbol::Literal(*value as u8));
-            } else if *value == END_OF_BLOCK_MARKER_VALUE {
-                break;
-            } else {
-                let base_length = LENGTH_CODE_TO_BASE_LENGTH[*value as usize];
-                let num_extra_bits = LENGTH_CODE_TO_EXTRA_BITS[*value as usize];
-                let extra_bits = deflate_read_bits!(
-                    reader.read_number_lsb(num_extra_bits),
-                    "data ended before end of block marker"
-                );
-                read_distance = true;
-                current_length = base_length + extra_bits;
-            }
-            code.reset();
-        }
+    if value < END_OF_BLOCK_MARKER_VALUE {
+        return Ok(LzssSymbol::Literal(value as u8));
+    } else if value == END_OF_BLOCK_MARKER_VALUE {
+        return Ok(LzssSymbol::EndOfBlock);
+    } else if value as usize >= LL_TABLE_SIZE {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            format!("Invalid length code {value}"),
+        ));
+    }
+
+    let base_length = LENGTH_CODE_TO_BASE_LENGTH[value as usize];
+    let num_extra_bits = LENGTH_CODE_TO_EXTRA_BITS[value as usize];
+    let extra_bits = deflate_read_bits!(
+        reader.read_number_lsb(num_extra_bits),
+        "data ended before end of block marker"
+    );
+    let length = base_length + extra_bits;
+
+    let distance_code = read_code(reader, &tables.distance_table)?;
+
+    if distance_code as usize >= DISTANCE_TABLE_SIZE {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            format!("Invalid distance code {distance_code}"),
+        ));
+    }
+
+    let base_distance = DISTANCE_CODE_TO_BASE_DISTANCE[distance_code as usize];
+    let num_extra_bits = DISTANCE_CODE_TO_EXTRA_BITS[distance_code as usize];
+    let extra_bits = deflate_read_bits!(
+        reader.read_number_lsb(num_extra_bits),
+        "data ended before end of block marker"
+    );
+
+    Ok(LzssSymbol::Backreference(
+        base_distance + extra_bits,
+        length,
+    ))
+}
+
+fn read_code(
+    reader: &mut ReadBitStream,
+    table: &HuffmanDecodeTable,
+) -> Result<u16, DeflateDecodeError> {
+    let (bits, available_length) = reader.peek_bits(MAX_SYMBOL_CODE_LENGTH);
+    let mut entry = table.entries[bits as usize & ((1 << table.primary_bits) - 1)];
+    let mut lookup_length = table.primary_bits;
+
+    if entry.sub_table_bits != 0 {
+        let sub_table_bits = entry.sub_table_bits as usize;
+        let sub_code = (bits as usize >> table.primary_bits) & ((1 << sub_table_bits) - 1);
+        entry = table.entries[entry.value as usize + sub_code];
+        lookup_length += sub_table_bits;
+    }
+
+    // the bits past the end of the input are zeros, so a code running into them is not one yet
+    let is_complete = match entry.length {
+        0 => available_length >= lookup_length,
+        length => available_length >= length as usize,
+    };
+
+    if !is_complete {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::UnexpectedEof,
+            "DEFLATE bitstream ended unexpectedly: data ended before end of block marker",
+        ));
+    } else if entry.length == 0 {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            "Invalid Huffman code",
+        ));
     }
 
-    decode_lzss(target, &lzss_stream)?;
+    reader.skip_bits(entry.length as usize);
 
-    Ok(())
+    Ok(entry.value)
 }
 
-fn get_code_table_from_lengths(table_lengths: Vec<u32>) -> HashMap<WriteBitStream, u16> {
-    let frequency_map: HashMap<u16, u32> = table_lengths
+fn get_code_table_from_lengths(
+    table_lengths: Vec<u32>,
+) -> Result<HuffmanDecodeTable, DeflateDecodeError> {
+    let table_lengths: Vec<u8> = table_lengths
         .into_iter()
-        .enumerate()
-        .filter(|(_i, l)| *l != 0)
-        .map(|(i, l)| (i as u16, l))
+        .map(|length| length as u8)
         .collect();
 
-    reverse_hashmap(construct_canonical_tree_from_lengths(&frequency_map))
+    HuffmanDecodeTable::from_lengths(&table_lengths, true)
 }
@@ -0,0 +1,623 @@
+use std::io;
+
+use crate::{
+    binary::latin1::{bytes_to_latin1, latin1_to_bytes},
+    error::ErrorKind,
+    png::{crc::CrcCalculator, CompressionLevel},
+};
+
+use super::{decode::DeflateDecodeError, zlib::offset_error, DeflateEncoder, DeflateInflater};
+
+const GZIP_SIGNATURE: [u8; 2] = [0x1f, 0x8b];
+const DEFLATE_COMPRESSION_METHOD: u8 = 8;
+const FTEXT: u8 = 1;
+const FHCRC: u8 = 1 << 1;
+const FEXTRA: u8 = 1 << 2;
+const FNAME: u8 = 1 << 3;
+const FCOMMENT: u8 = 1 << 4;
+const RESERVED_FLAGS: u8 = 0b1110_0000;
+const FIXED_HEADER_SIZE: usize = 10;
+const TRAILER_SIZE: usize = 8;
+const OS_UNKNOWN: u8 = 255;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GzipHeader {
+    // Latin-1, like the rest of the header
+    pub file_name: Option<String>,
+    pub comment: Option<String>,
+    // seconds since the unix epoch, 0 when unknown
+    pub modification_time: u32,
+    pub extra_field: Option<Vec<u8>>,
+    pub is_text: bool,
+    pub has_header_crc: bool,
+    pub operating_system: u8,
+}
+
+impl GzipHeader {
+    pub fn new() -> Self {
+        Self {
+            file_name: None,
+            comment: None,
+            modification_time: 0,
+            extra_field: None,
+            is_text: false,
+            has_header_crc: false,
+            operating_system: OS_UNKNOWN,
+        }
+    }
+
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    pub fn modification_time(mut self, modification_time: u32) -> Self {
+        self.modification_time = modification_time;
+        self
+    }
+
+    pub fn extra_field(mut self, extra_field: Vec<u8>) -> Self {
+        self.extra_field = Some(extra_field);
+        self
+    }
+
+    pub fn text(mut self, is_text: bool) -> Self {
+        self.is_text = is_text;
+        self
+    }
+
+    pub fn header_crc(mut self, has_header_crc: bool) -> Self {
+        self.has_header_crc = has_header_crc;
+        self
+    }
+
+    pub fn operating_system(mut self, operating_system: u8) -> Self {
+        self.operating_system = operating_system;
+        self
+    }
+
+    fn check_validity(&self) -> io::Result<()> {
+        for (field, text) in [("file name", &self.file_name), ("comment", &self.comment)] {
+            let Some(text) = text else {
+                continue;
+            };
+
+            if text.chars().any(|c| c == '\0' || c as u32 > u8::MAX as u32) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("gzip {field} {text:?} must be Latin-1 without NUL characters"),
+                ));
+            }
+        }
+
+        match self.extra_field {
+            Some(ref extra_field) if extra_field.len() > u16::MAX as usize => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "gzip extra field is {} bytes long, it can hold at most {}",
+                    extra_field.len(),
+                    u16::MAX
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn to_bytes(&self, compression_level: CompressionLevel) -> Vec<u8> {
+        let flags = [
+            (self.is_text, FTEXT),
+            (self.has_header_crc, FHCRC),
+            (self.extra_field.is_some(), FEXTRA),
+            (self.file_name.is_some(), FNAME),
+            (self.comment.is_some(), FCOMMENT),
+        ]
+        .into_iter()
+        .filter(|(is_set, _flag)| *is_set)
+        .fold(0, |flags, (_is_set, flag)| flags | flag);
+        // the extra flags tell whether the slowest or the fastest compression was used, grouping
+        // levels the way zlib does
+        let extra_flags = match compression_level.level() {
+            0..=1 => 4,
+            2..=8 => 0,
+            _ => 2,
+        };
+
+        let mut bytes = GZIP_SIGNATURE.to_vec();
+        bytes.push(DEFLATE_COMPRESSION_METHOD);
+        bytes.push(flags);
+        bytes.extend_from_slice(&self.modification_time.to_le_bytes());
+        bytes.push(extra_flags);
+        bytes.push(self.operating_system);
+
+        if let Some(ref extra_field) = self.extra_field {
+            bytes.extend_from_slice(&(extra_field.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(extra_field);
+        }
+
+        for text in [&self.file_name, &self.comment].into_iter().flatten() {
+            bytes.extend_from_slice(&latin1_to_bytes(text));
+            bytes.push(0);
+        }
+
+        if self.has_header_crc {
+            let header_crc = crc32(&bytes) as u16;
+            bytes.extend_from_slice(&header_crc.to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+impl Default for GzipHeader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc_calculator = CrcCalculator::new();
+    crc_calculator.update_crc(bytes);
+
+    crc_calculator.get_crc()
+}
+
+// reads a gzip member header, returning it with the index the compressed data starts at
+pub fn decode_gzip_header(bytes: &[u8]) -> Result<(GzipHeader, usize), DeflateDecodeError> {
+    let mut cursor = 0;
+    let fixed_header = take_bytes(bytes, &mut cursor, FIXED_HEADER_SIZE, "header")?;
+
+    if fixed_header[..2] != GZIP_SIGNATURE {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidSignature,
+            "Not a gzip stream, the signature is missing",
+        )
+        .at_offset(0));
+    }
+
+    if fixed_header[2] != DEFLATE_COMPRESSION_METHOD {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "Unsupported compression method in gzip header {}",
+                fixed_header[2]
+            ),
+        )
+        .at_offset(2));
+    }
+
+    let flags = fixed_header[3];
+
+    if flags & RESERVED_FLAGS != 0 {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            format!("Reserved gzip header flags are set: {flags:#010b}"),
+        )
+        .at_offset(3));
+    }
+
+    let mut header = GzipHeader::new()
+        .modification_time(u32::from_le_bytes(fixed_header[4..8].try_into().unwrap()))
+        .text(flags & FTEXT != 0)
+        .header_crc(flags & FHCRC != 0)
+        .operating_system(fixed_header[9]);
+
+    if flags & FEXTRA != 0 {
+        let extra_length = take_bytes(bytes, &mut cursor, 2, "extra field length")?;
+        let extra_length = u16::from_le_bytes([extra_length[0], extra_length[1]]) as usize;
+        let extra_field = take_bytes(bytes, &mut cursor, extra_length, "extra field")?;
+        header.extra_field = Some(extra_field.to_vec());
+    }
+
+    if flags & FNAME != 0 {
+        let file_name = take_until_null(bytes, &mut cursor, "file name")?;
+        header.file_name = Some(bytes_to_latin1(file_name));
+    }
+
+    if flags & FCOMMENT != 0 {
+        let comment = take_until_null(bytes, &mut cursor, "comment")?;
+        header.comment = Some(bytes_to_latin1(comment));
+    }
+
+    if flags & FHCRC != 0 {
+        let header_crc = crc32(&bytes[..cursor]) as u16;
+        let expected_header_crc = take_bytes(bytes, &mut cursor, 2, "header CRC")?;
+        let expected_header_crc =
+            u16::from_le_bytes([expected_header_crc[0], expected_header_crc[1]]);
+
+        if header_crc != expected_header_crc {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::ChecksumMismatch,
+                format!(
+                    "gzip header CRC mismatch, header stores {expected_header_crc:#06x} but hashes to {header_crc:#06x}"
+                ),
+            )
+            .at_offset(cursor - 2));
+        }
+    }
+
+    Ok((header, cursor))
+}
+
+fn take_bytes<'a>(
+    bytes: &'a [u8],
+    cursor: &mut usize,
+    length: usize,
+    field: &str,
+) -> Result<&'a [u8], DeflateDecodeError> {
+    let Some(taken_bytes) = bytes.get(*cursor..*cursor + length) else {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::UnexpectedEof,
+            format!("gzip stream ended unexpectedly: expected {field}"),
+        )
+        .at_offset(bytes.len()));
+    };
+    *cursor += length;
+
+    Ok(taken_bytes)
+}
+
+fn take_until_null<'a>(
+    bytes: &'a [u8],
+    cursor: &mut usize,
+    field: &str,
+) -> Result<&'a [u8], DeflateDecodeError> {
+    let Some(length) = bytes[*cursor..].iter().position(|byte| *byte == 0) else {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::UnexpectedEof,
+            format!("gzip stream ended unexpectedly: expected the end of the {field}"),
+        )
+        .at_offset(bytes.len()));
+    };
+    let taken_bytes = &bytes[*cursor..*cursor + length];
+    *cursor += length + 1;
+
+    Ok(taken_bytes)
+}
+
+pub fn encode_gzip(
+    bytes: &[u8],
+    header: GzipHeader,
+    compression_level: CompressionLevel,
+) -> io::Result<Vec<u8>> {
+    let mut encoder = GzipEncoder::new(header, compression_level)?;
+    encoder.write_bytes(bytes);
+
+    Ok(encoder.finish())
+}
+
+// compresses into gzip members, each finish ends one and the next write starts another with the
+// same header
+pub struct GzipEncoder {
+    header: GzipHeader,
+    compression_level: CompressionLevel,
+    deflate_encoder: DeflateEncoder,
+    crc_calculator: CrcCalculator,
+    size: u32,
+    is_header_written: bool,
+}
+
+impl GzipEncoder {
+    pub fn new(header: GzipHeader, compression_level: CompressionLevel) -> io::Result<Self> {
+        header.check_validity()?;
+
+        Ok(Self {
+            header,
+            compression_level,
+            deflate_encoder: DeflateEncoder::new(compression_level),
+            crc_calculator: CrcCalculator::new(),
+            size: 0,
+            is_header_written: false,
+        })
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.deflate_encoder.write_bytes(bytes);
+        self.crc_calculator.update_crc(bytes);
+        // ISIZE is the size modulo 2^32
+        self.size = self.size.wrapping_add(bytes.len() as u32);
+    }
+
+    pub fn take_compressed_bytes(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.take_compressed_bytes());
+
+        result
+    }
+
+    pub fn sync_flush(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.sync_flush());
+
+        result
+    }
+
+    pub fn full_flush(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.full_flush());
+
+        result
+    }
+
+    // ends the member with its CRC-32 and size
+    pub fn finish(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.finish().flush_to_bytes());
+        result.extend_from_slice(&self.crc_calculator.get_crc().to_le_bytes());
+        result.extend_from_slice(&self.size.to_le_bytes());
+
+        self.crc_calculator.reset();
+        self.size = 0;
+        self.is_header_written = false;
+
+        result
+    }
+
+    fn take_header(&mut self) -> Vec<u8> {
+        if self.is_header_written {
+            return Vec::new();
+        }
+
+        self.is_header_written = true;
+
+        self.header.to_bytes(self.compression_level)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GzipMember {
+    pub header: GzipHeader,
+    pub data: Vec<u8>,
+}
+
+// the data of every member one after the other, like gunzip
+pub fn decode_gzip(bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
+    let mut gzip_inflater = GzipInflater::new();
+    let mut result = gzip_inflater.push(bytes)?;
+    result.extend(gzip_inflater.finish()?);
+
+    Ok(result)
+}
+
+pub fn decode_gzip_members(bytes: &[u8]) -> Result<Vec<GzipMember>, DeflateDecodeError> {
+    let mut gzip_inflater = GzipInflater::new();
+    let mut data = gzip_inflater.push(bytes)?;
+    data.extend(gzip_inflater.finish()?);
+
+    let mut data_start_index = 0;
+    let members = gzip_inflater
+        .headers
+        .into_iter()
+        .zip(gzip_inflater.data_lengths)
+        .map(|(header, data_length)| {
+            let member_data = data[data_start_index..data_start_index + data_length].to_vec();
+            data_start_index += data_length;
+
+            GzipMember {
+                header,
+                data: member_data,
+            }
+        })
+        .collect();
+
+    Ok(members)
+}
+
+enum GzipState {
+    Header,
+    Data,
+    Trailer,
+    // zero bytes after the last member, which gzip -d skips as well, like the padding tape
+    // archives add to fill their last record
+    Padding,
+}
+
+// decompresses gzip members whose bytes arrive over time
+pub struct GzipInflater {
+    // header or trailer bytes that were not read yet
+    input: Vec<u8>,
+    input_offset: usize,
+    state: GzipState,
+    deflate_inflater: DeflateInflater,
+    data_start_index: usize,
+    crc_calculator: CrcCalculator,
+    data_length: usize,
+    headers: Vec<GzipHeader>,
+    data_lengths: Vec<usize>,
+}
+
+impl GzipInflater {
+    pub fn new() -> Self {
+        Self {
+            input: Vec::new(),
+            input_offset: 0,
+            state: GzipState::Header,
+            deflate_inflater: DeflateInflater::new(),
+            data_start_index: 0,
+            crc_calculator: CrcCalculator::new(),
+            data_length: 0,
+            headers: Vec::new(),
+            data_lengths: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
+        let mut output = Vec::new();
+        self.feed(bytes);
+        self.inflate(&mut output, usize::MAX)?;
+
+        Ok(output)
+    }
+
+    // the stream has to end right after a member or in the zero padding after one
+    pub fn finish(&mut self) -> Result<Vec<u8>, DeflateDecodeError> {
+        let mut output = Vec::new();
+        self.inflate(&mut output, usize::MAX)?;
+
+        match self.state {
+            GzipState::Header if self.headers.is_empty() && self.input.is_empty() => Err(
+                DeflateDecodeError::new(ErrorKind::UnexpectedEof, "gzip stream is empty")
+                    .at_offset(0),
+            ),
+            GzipState::Header if self.input.is_empty() => Ok(output),
+            GzipState::Padding => Ok(output),
+            // a header that is complete would have been read already
+            GzipState::Header => {
+                let err = decode_gzip_header(&self.input).err().unwrap_or_else(|| {
+                    DeflateDecodeError::new(
+                        ErrorKind::UnexpectedEof,
+                        "gzip stream ended in a member header",
+                    )
+                    .at_offset(self.input.len())
+                });
+
+                Err(offset_error(err, self.input_offset))
+            }
+            GzipState::Data => {
+                let err = self.deflate_inflater.finish().err().unwrap_or_else(|| {
+                    DeflateDecodeError::new(
+                        ErrorKind::UnexpectedEof,
+                        "gzip stream ended in compressed data",
+                    )
+                });
+
+                Err(offset_error(err, self.data_start_index))
+            }
+            GzipState::Trailer => Err(DeflateDecodeError::new(
+                ErrorKind::UnexpectedEof,
+                "gzip member is missing its CRC-32 and size",
+            )
+            .at_offset(self.input_offset + self.input.len())),
+        }
+    }
+
+    // buffers compressed bytes without decompressing them yet
+    pub fn feed(&mut self, bytes: &[u8]) {
+        match self.state {
+            GzipState::Data => self.deflate_inflater.feed(bytes),
+            _ => self.input.extend_from_slice(bytes),
+        }
+    }
+
+    // decompresses the buffered input into output, stopping once it holds max_output_length bytes
+    pub fn inflate(
+        &mut self,
+        output: &mut Vec<u8>,
+        max_output_length: usize,
+    ) -> Result<(), DeflateDecodeError> {
+        loop {
+            match self.state {
+                GzipState::Header => {
+                    if self.input.is_empty() {
+                        return Ok(());
+                    }
+
+                    if self.input[0] == 0 && !self.headers.is_empty() {
+                        self.state = GzipState::Padding;
+                        continue;
+                    }
+
+                    let (header, data_start_index) = match decode_gzip_header(&self.input) {
+                        Ok(header) => header,
+                        Err(err) if err.kind == ErrorKind::UnexpectedEof => return Ok(()),
+                        Err(err) => return Err(offset_error(err, self.input_offset)),
+                    };
+                    let data = self.input.split_off(data_start_index);
+                    self.input.clear();
+                    self.input_offset += data_start_index;
+                    self.data_start_index = self.input_offset;
+                    self.headers.push(header);
+                    self.deflate_inflater = DeflateInflater::new();
+                    self.deflate_inflater.feed(&data);
+                    self.state = GzipState::Data;
+                }
+                GzipState::Data => {
+                    let output_length = output.len();
+                    self.deflate_inflater
+                        .inflate(output, max_output_length)
+                        .map_err(|err| offset_error(err, self.data_start_index))?;
+                    self.crc_calculator.update_crc(&output[output_length..]);
+                    self.data_length += output.len() - output_length;
+
+                    if !self.deflate_inflater.is_finished() {
+                        return Ok(());
+                    }
+
+                    self.input = self.deflate_inflater.trailing_bytes().to_vec();
+                    self.input_offset =
+                        self.data_start_index + self.deflate_inflater.input_offset();
+                    self.state = GzipState::Trailer;
+                }
+                GzipState::Trailer => {
+                    if self.input.len() < TRAILER_SIZE {
+                        return Ok(());
+                    }
+
+                    self.check_trailer()?;
+                    self.input.drain(..TRAILER_SIZE);
+                    self.input_offset += TRAILER_SIZE;
+                    self.data_lengths.push(self.data_length);
+                    self.data_length = 0;
+                    self.crc_calculator.reset();
+                    self.state = GzipState::Header;
+                }
+                GzipState::Padding => {
+                    if let Some(index) = self.input.iter().position(|byte| *byte != 0) {
+                        return Err(DeflateDecodeError::new(
+                            ErrorKind::InvalidData,
+                            "gzip stream has data after the zero padding that follows its last member",
+                        )
+                        .at_offset(self.input_offset + index));
+                    }
+
+                    self.input_offset += self.input.len();
+                    self.input.clear();
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    pub fn headers(&self) -> &[GzipHeader] {
+        &self.headers
+    }
+
+    fn check_trailer(&self) -> Result<(), DeflateDecodeError> {
+        let expected_crc = u32::from_le_bytes(self.input[..4].try_into().unwrap());
+        let expected_size = u32::from_le_bytes(self.input[4..8].try_into().unwrap());
+        let crc = self.crc_calculator.get_crc();
+
+        if crc != expected_crc {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::ChecksumMismatch,
+                format!(
+                    "CRC-32 mismatch, gzip member stores {expected_crc:#010x} but data hashes to {crc:#010x}"
+                ),
+            )
+            .at_offset(self.input_offset));
+        }
+
+        if self.data_length as u32 != expected_size {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::ChecksumMismatch,
+                format!(
+                    "gzip member stores a size of {expected_size} bytes but holds {}",
+                    self.data_length
+                ),
+            )
+            .at_offset(self.input_offset + 4));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for GzipInflater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -13,6 +13,12 @@ pub struct HuffmanEncoder<T: Eq + Hash + Clone> {
     symbol_frequencies: HashMap<T, u32>,
 }
 
+impl<T: Eq + Hash + Clone> Default for HuffmanEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Eq + Hash + Clone> HuffmanEncoder<T> {
     pub fn new() -> Self {
         Self {
@@ -91,7 +97,3 @@ pub fn calc_kraft_mcmillen_value<T>(symbol_lengths: &HashMap<T, u32>) -> f64 {
         .map(|length| 2f64.powf(-(*length as f64)))
         .sum()
 }
-
-fn saturating_shl(lhs: u32, rhs: u32) -> u32 {
-    lhs.checked_shl(rhs).unwrap_or(0)
-}
@@ -4,6 +4,12 @@ pub struct PackageMergeEncoder<T: Eq + Hash + Clone> {
     symbol_frequencies: HashMap<T, u32>,
 }
 
+impl<T: Eq + Hash + Clone + Debug + Ord> Default for PackageMergeEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Eq + Hash + Clone + Debug + Ord> PackageMergeEncoder<T> {
     pub fn new() -> Self {
         Self {
@@ -56,7 +62,7 @@ impl<T: Eq + Hash + Clone + Debug + Ord> PackageMergeEncoder<T> {
                 let tmp: Vec<_> = current_coin_queue.drain(0..2).collect();
                 let [a, b] = tmp.try_into().unwrap();
                 last_coin_queue_packages
-                    .push((a.0.into_iter().chain(b.0.into_iter()).collect(), a.1 + b.1));
+                    .push((a.0.into_iter().chain(b.0).collect(), a.1 + b.1));
             }
         }
         let all_used_coins: Vec<T> = last_coin_queue_packages
@@ -1,18 +1,5 @@
 use crate::png::deflate::consts::{END_OF_BLOCK_MARKER_VALUE, LZSS_WINDOW_SIZE};
 
-const LENGTH_CODES: [u16; 29] = [
-    257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275,
-    276, 277, 278, 279, 280, 281, 282, 283, 284, 285,
-];
-
-const LENGTH_EXTRA_BITS: [u8; 29] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
-];
-const LENGTHS: [u16; 29] = [
-    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
-    163, 195, 227, 258,
-];
-
 pub const LENGTH_TO_CODE: [u16; 259] = [
     0, 0, 0, 257, 258, 259, 260, 261, 262, 263, 264, 265, 265, 266, 266, 267, 267, 268, 268, 269,
     269, 269, 269, 270, 270, 270, 270, 271, 271, 271, 271, 272, 272, 272, 272, 273, 273, 273, 273,
@@ -41,6 +28,7 @@ pub const LENGTH_TO_EXTRA_BITS: [(u16, u8); 259] = [(0, 0), (0, 0), (0, 0), (0,
 
 pub const LL_TABLE_SIZE: usize = 286;
 pub const DISTANCE_TABLE_SIZE: usize = 30;
+pub const LZSS_MIN_LENGTH: usize = 3;
 pub const LZSS_MAX_LENGTH: usize = 258;
 pub const LZSS_NUMBER_OF_LENGHTS: usize = LZSS_MAX_LENGTH + 1;
 pub const LZSS_NUMBER_OF_LITERALS: usize  = END_OF_BLOCK_MARKER_VALUE as usize + 1;
@@ -49,10 +37,6 @@ pub const LZSS_NUMBER_OF_DISTANCES: usize = LZSS_WINDOW_SIZE + 1;
 
 pub const LZSS_DISTANCE_CODES: [u16; 30] = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29];
 
-const DISTANCE_EXTRA_BITS: [u16; 30] = [0,0,0,0,1,1,2,2,3,3,4,4,5,5,6,6,7,7,8,8,9,9,10,10,11,11,12,12,13,13];
-
-const DISTANCES: [u16; 31] = [1,2,3,4,5,7,9,13,17,25,33,49,65,97,129,193,257,385,513,769,1025,1537,2049,3073,4097,6145,8193,12289,16385,24577, 32768];
-
 pub static DISTANCE_TO_CODE: [u16; 32768] = [0, 0, 1, 2, 3, 4, 4, 5, 5, 6, 6, 6, 6, 7, 7, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 13, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 19, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29];
 
 pub static DISTANCE_TO_EXTRA_BITS: [(u16, u8); 32768] = [(0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 1), (1, 1), (0, 1), (1, 1), (0, 2), (1, 2), (2, 2), (3, 2), (0, 2), (1, 2), (2, 2), (3, 2), (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3), (6, 3), (7, 3), (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3), (6, 3), (7, 3), (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4), (6, 4), (7, 4), (8, 4), (9, 4), (10, 4), (11, 4), (12, 4), (13, 4), (14, 4), (15, 4), (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4), (6, 4), (7, 4), (8, 4), (9, 4), (10, 4), (11, 4), (12, 4), (13, 4), (14, 4), (15, 4), (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5), (6, 5), (7, 5), (8, 5), (9, 5), (10, 5), (11, 5), (12, 5), (13, 5), (14, 5), (15, 5), (16, 5), (17, 5), (18, 5), (19, 5), (20, 5), (21, 5), (22, 5), (23, 5), (24, 5), (25, 5), (26, 5), (27, 5), (28, 5), (29, 5), (30, 5), (31, 5), (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5), (6, 5), (7, 5), (8, 5), (9, 5), (10, 5), (11, 5), (12, 5), (13, 5), (14, 5), (15, 5), (16, 5), (17, 5), (18, 5), (19, 5), (20, 5), (21, 5), (22, 5), (23, 5), (24, 5), (25, 5), (26, 5), (27, 5), (28, 5), (29, 5), (30, 5), (31, 5), (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6), (6, 6), (7, 6), (8, 6), (9, 6), (10, 6), (11, 6), (12, 6), (13, 6), (14, 6), (15, 6), (16, 6), (17, 6), (18, 6), (19, 6), (20, 6), (21, 6), (22, 6), (23, 6), (24, 6), (25, 6), (26, 6), (27, 6), (28, 6), (29, 6), (30, 6), (31, 6), (32, 6), (33, 6), (34, 6), (35, 6), (36, 6), (37, 6), (38, 6), (39, 6), (40, 6), (41, 6), (42, 6), (43, 6), (44, 6), (45, 6), (46, 6), (47, 6), (48, 6), (49, 6), (50, 6), (51, 6), (52, 6), (53, 6), (54, 6), (55, 6), (56, 6), (57, 6), (58, 6), (59, 6), (60, 6), (61, 6), (62, 6), (63, 6), (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6), (6, 6), (7, 6), (8, 6), (9, 6), (10, 6), (11, 6), (12, 6), (13, 6), (14, 6), (15, 6), (16, 6), (17, 6), (18, 6), (19, 6), (20, 6), (21, 6), (22, 6), (23, 6), (24, 6), (25, 6), (26, 6), (27, 6), (28, 6), (29, 6), (30, 6), (31, 6), (32, 6), (33, 6), (34, 6), (35, 6), (36, 6), (37, 6), (38, 6), (39, 6), (40, 6), (41, 6), (42, 6), (43, 6), (44, 6), (45, 6), (46, 6), (47, 6), (48, 6), (49, 6), (50, 6), (51, 6), (52, 6), (53, 6), (54, 6), (55, 6), (56, 6), (57, 6), (58, 6), (59, 6), (60, 6), (61, 6), (62, 6), (63, 6), (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7), (6, 7), (7, 7), (8, 7), (9, 7), (10, 7), (11, 7), (12, 7), (13, 7), (14, 7), (15, 7), (16, 7), (17, 7), (18, 7), (19, 7), (20, 7), (21, 7), (22, 7), (23, 7), (24, 7), (25, 7), (26, 7), (27, 7), (28, 7), (29, 7), (30, 7), (31, 7), (32, 7), (33, 7), (34, 7), (35, 7), (36, 7), (37, 7), (38, 7), (39, 7), (40, 7), (41, 7), (42, 7), (43, 7), (44, 7), (45, 7), (46, 7), (47, 7), (48, 7), (49, 7), (50, 7), (51, 7), (52, 7), (53, 7), (54, 7), (55, 7), (56, 7), (57, 7), (58, 7), (59, 7), (60, 7), (61, 7), (62, 7), (63, 7), (64, 7), (65, 7), (66, 7), (67, 7), (68, 7), (69, 7), (70, 7), (71, 7), (72, 7), (73, 7), (74, 7), (75, 7), (76, 7), (77, 7), (78, 7), (79, 7), (80, 7), (81, 7), (82, 7), (83, 7), (84, 7), (85, 7), (86, 7), (87, 7), (88, 7), (89, 7), (90, 7), (91, 7), (92, 7), (93, 7), (94, 7), (95, 7), (96, 7), (97, 7), (98, 7), (99, 7), (100, 7), (101, 7), (102, 7), (103, 7), (104, 7), (105, 7), (106, 7), (107, 7), (108, 7), (109, 7), (110, 7), (111, 7), (112, 7), (113, 7), (114, 7), (115, 7), (116, 7), (117, 7), (118, 7), (119, 7), (120, 7), (121, 7), (122, 7), (123, 7), (124, 7), (125, 7), (126, 7), (127, 7), (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7), (6, 7), (7, 7), (8, 7), (9, 7), (10, 7), (11, 7), (12, 7), (13, 7), (14, 7), (15, 7), (16, 7), (17, 7), (18, 7), (19, 7), (20, 7), (21, 7), (22, 7), (23, 7), (24, 7), (25, 7), (26, 7), (27, 7), (28, 7), (29, 7), (30, 7), (31, 7), (32, 7), (33, 7), (34, 7), (35, 7), (36, 7), (37, 7), (38, 7), (39, 7), (40, 7), (41, 7), (42, 7), (43, 7), (44, 7), (45, 7), (46, 7), (47, 7), (48, 7), (49, 7), (50, 7), (51, 7), (52, 7), (53, 7), (54, 7), (55, 7), (56, 7), (57, 7), (58, 7), (59, 7), (60, 7), (61, 7), (62, 7), (63, 7), (64, 7), (65, 7), (66, 7), (67, 7), (68, 7), (69, 7), (70, 7), (71, 7), (72, 7), (73, 7), (74, 7), (75, 7), (76, 7), (77, 7), (78, 7), (79, 7), (80, 7), (81, 7), (82, 7), (83, 7), (84, 7), (85, 7), (86, 7), (87, 7), (88, 7), (89, 7), (90, 7), (91, 7), (92, 7), (93, 7), (94, 7), (95, 7), (96, 7), (97, 7), (98, 7), (99, 7), (100, 7), (101, 7), (102, 7), (103, 7), (104, 7), (105, 7), (106, 7), (107, 7), (108, 7), (109, 7), (110, 7), (111, 7), (112, 7), (113, 7), (114, 7), (115, 7), (116, 7), (117, 7), (118, 7), (119, 7), (120, 7), (121, 7), (122, 7), (123, 7), (124, 7), (125, 7), (126, 7), (127, 7), (0, 8), (1, 8), (2, 8), (3, 8), (4, 8), (5, 8), (6, 8), (7, 8), (8, 8), (9, 8), (10, 8), (11, 8), (12, 8), (13, 8), (14, 8), (15, 8), (16, 8), (17, 8), (18, 8), (19, 8), (20, 8), (21, 8), (22, 8), (23, 8), (24, 8), (25, 8), (26, 8), (27, 8), (28, 8), (29, 8), (30, 8), (31, 8), (32, 8), (33, 8), (34, 8), (35, 8), (36, 8), (37, 8), (38, 8), (39, 8), (40, 8), (41, 8), (42, 8), (43, 8), (44, 8), (45, 8), (46, 8), (47, 8), (48, 8), (49, 8), (50, 8), (51, 8), (52, 8), (53, 8), (54, 8), (55, 8), (56, 8), (57, 8), (58, 8), (59, 8), (60, 8), (61, 8), (62, 8), (63, 8), (64, 8), (65, 8), (66, 8), (67, 8), (68, 8), (69, 8), (70, 8), (71, 8), (72, 8), (73, 8), (74, 8), (75, 8), (76, 8), (77, 8), (78, 8), (79, 8), (80, 8), (81, 8), (82, 8), (83, 8), (84, 8), (85, 8), (86, 8), (87, 8), (88, 8), (89, 8), (90, 8), (91, 8), (92, 8), (93, 8), (94, 8), (95, 8), (96, 8), (97, 8), (98, 8), (99, 8), (100, 8), (101, 8), (102, 8), (103, 8), (104, 8), (105, 8), (106, 8), (107, 8), (108, 8), (109, 8), (110, 8), (111, 8), (112, 8), (113, 8), (114, 8), (115, 8), (116, 8), (117, 8), (118, 8), (119, 8), (120, 8), (121, 8), (122, 8), (123, 8), (124, 8), (125, 8), (126, 8), (127, 8), (128, 8), (129, 8), (130, 8), (131, 8), (132, 8), (133, 8), (134, 8), (135, 8), (136, 8), (137, 8), (138, 8), (139, 8), (140, 8), (141, 8), (142, 8), (143, 8), (144, 8), (145, 8), (146, 8), (147, 8), (148, 8), (149, 8), (150, 8), (151, 8), (152, 8), (153, 8), (154, 8), (155, 8), (156, 8), (157, 8), (158, 8), (159, 8), (160, 8), (161, 8), (162, 8), (163, 8), (164, 8), (165, 8), (166, 8), (167, 8), (168, 8), (169, 8), (170, 8), (171, 8), (172, 8), (173, 8), (174, 8), (175, 8), (176, 8), (177, 8), (178, 8), (179, 8), (180, 8), (181, 8), (182, 8), (183, 8), (184, 8), (185, 8), (186, 8), (187, 8), (188, 8), (189, 8), (190, 8), (191, 8), (192, 8), (193, 8), (194, 8), (195, 8), (196, 8), (197, 8), (198, 8), (199, 8), (200, 8), (201, 8), (202, 8), (203, 8), (204, 8), (205, 8), (206, 8), (207, 8), (208, 8), (209, 8), (210, 8), (211, 8), (212, 8), (213, 8), (214, 8), (215, 8), (216, 8), (217, 8), (218, 8), (219, 8), (220, 8), (221, 8), (222, 8), (223, 8), (224, 8), (225, 8), (226, 8), (227, 8), (228, 8), (229, 8), (230, 8), (231, 8), (232, 8), (233, 8), (234, 8), (235, 8), (236, 8), (237, 8), (238, 8), (239, 8), (240, 8), (241, 8), (242, 8), (243, 8), (244, 8), (245, 8), (246, 8), (247, 8), (248, 8), (249, 8), (250, 8), (251, 8), (252, 8), (253, 8), (254, 8), (255, 8), (0, 8), (1, 8), (2, 8), (3, 8), (4, 8), (5, 8), (6, 8), (7, 8), (8, 8), (9, 8), (10, 8), (11, 8), (12, 8), (13, 8), (14, 8), (15, 8), (16, 8), (17, 8), (18, 8), (19, 8), (20, 8), (21, 8), (22, 8), (23, 8), (24, 8), (25, 8), (26, 8), (27, 8), (28, 8), (29, 8), (30, 8), (31, 8), (32, 8), (33, 8), (34, 8), (35, 8), (36, 8), (37, 8), (38, 8), (39, 8), (40, 8), (41, 8), (42, 8), (43, 8), (44, 8), (45, 8), (46, 8), (47, 8), (48, 8), (49, 8), (50, 8), (51, 8), (52, 8), (53, 8), (54, 8), (55, 8), (56, 8), (57, 8), (58, 8), (59, 8), (60, 8), (61, 8), (62, 8), (63, 8), (64, 8), (65, 8), (66, 8), (67, 8), (68, 8), (69, 8), (70, 8), (71, 8), (72, 8), (73, 8), (74, 8), (75, 8), (76, 8), (77, 8), (78, 8), (79, 8), (80, 8), (81, 8), (82, 8), (83, 8), (84, 8), (85, 8), (86, 8), (87, 8), (88, 8), (89, 8), (90, 8), (91, 8), (92, 8), (93, 8), (94, 8), (95, 8), (96, 8), (97, 8), (98, 8), (99, 8), (100, 8), (101, 8), (102, 8), (103, 8), (104, 8), (105, 8), (106, 8), (107, 8), (108, 8), (109, 8), (110, 8), (111, 8), (112, 8), (113, 8), (114, 8), (115, 8), (116, 8), (117, 8), (118, 8), (119, 8), (120, 8), (121, 8), (122, 8), (123, 8), (124, 8), (125, 8), (126, 8), (127, 8), (128, 8), (129, 8), (130, 8), (131, 8), (132, 8), (133, 8), (134, 8), (135, 8), (136, 8), (137, 8), (138, 8), (139, 8), (140, 8), (141, 8), (142, 8), (143, 8), (144, 8), (145, 8), (146, 8), (147, 8), (148, 8), (149, 8), (150, 8), (151, 8), (152, 8), (153, 8), (154, 8), (155, 8), (156, 8), (157, 8), (158, 8), (159, 8), (160, 8), (161, 8), (162, 8), (163, 8), (164, 8), (165, 8), (166, 8), (167, 8), (168, 8), (169, 8), (170, 8), (171, 8), (172, 8), (173, 8), (174, 8), (175, 8), (176, 8), (177, 8), (178, 8), (179, 8), (180, 8), (181, 8), (182, 8), (183, 8), (184, 8), (185, 8), (186, 8), (187, 8), (188, 8), (189, 8), (190, 8), (191, 8), (192, 8), (193, 8), (194, 8), (195, 8), (196, 8), (197, 8), (198, 8), (199, 8), (200, 8), (201, 8), (202, 8), (203, 8), (204, 8), (205, 8), (206, 8), (207, 8), (208, 8), (209, 8), (210, 8), (211, 8), (212, 8), (213, 8), (214, 8), (215, 8), (216, 8), (217, 8), (218, 8), (219, 8), (220, 8), (221, 8), (222, 8), (223, 8), (224, 8), (225, 8), (226, 8), (227, 8), (228, 8), (229, 8), (230, 8), (231, 8), (232, 8), (233, 8), (234, 8), (235, 8), (236, 8), (237, 8), (238, 8), (239, 8), (240, 8), (241, 8), (242, 8), (243, 8), (244, 8), (245, 8), (246, 8), (247, 8), (248, 8), (249, 8), (250, 8), (251, 8), (252, 8), (253, 8), (254, 8), (255, 8), (0, 9), (1, 9), (2, 9), (3, 9), (4, 9), (5, 9), (6, 9), (7, 9), (8, 9), (9, 9), (10, 9), (11, 9), (12, 9), (13, 9), (14, 9), (15, 9), (16, 9), (17, 9), (18, 9), (19, 9), (20, 9), (21, 9), (22, 9), (23, 9), (24, 9), (25, 9), (26, 9), (27, 9), (28, 9), (29, 9), (30, 9), (31, 9), (32, 9), (33, 9), (34, 9), (35, 9), (36, 9), (37, 9), (38, 9), (39, 9), (40, 9), (41, 9), (42, 9), (43, 9), (44, 9), (45, 9), (46, 9), (47, 9), (48, 9), (49, 9), (50, 9), (51, 9), (52, 9), (53, 9), (54, 9), (55, 9), (56, 9), (57, 9), (58, 9), (59, 9), (60, 9), (61, 9), (62, 9), (63, 9), (64, 9), (65, 9), (66, 9), (67, 9), (68, 9), (69, 9), (70, 9), (71, 9), (72, 9), (73, 9), (74, 9), (75, 9), (76, 9), (77, 9), (78, 9), (79, 9), (80, 9), (81, 9), (82, 9), (83, 9), (84, 9), (85, 9), (86, 9), (87, 9), (88, 9), (89, 9), (90, 9), (91, 9), (92, 9), (93, 9), (94, 9), (95, 9), (96, 9), (97, 9), (98, 9), (99, 9), (100, 9), (101, 9), (102, 9), (103, 9), (104, 9), (105, 9), (106, 9), (107, 9), (108, 9), (109, 9), (110, 9), (111, 9), (112, 9), (113, 9), (114, 9), (115, 9), (116, 9), (117, 9), (118, 9), (119, 9), (120, 9), (121, 9), (122, 9), (123, 9), (124, 9), (125, 9), (126, 9), (127, 9), (128, 9), (129, 9), (130, 9), (131, 9), (132, 9), (133, 9), (134, 9), (135, 9), (136, 9), (137, 9), (138, 9), (139, 9), (140, 9), (141, 9), (142, 9), (143, 9), (144, 9), (145, 9), (146, 9), (147, 9), (148, 9), (149, 9), (150, 9), (151, 9), (152, 9), (153, 9), (154, 9), (155, 9), (156, 9), (157, 9), (158, 9), (159, 9), (160, 9), (161, 9), (162, 9), (163, 9), (164, 9), (165, 9), (166, 9), (167, 9), (168, 9), (169, 9), (170, 9), (171, 9), (172, 9), (173, 9), (174, 9), (175, 9), (176, 9), (177, 9), (178, 9), (179, 9), (180, 9), (181, 9), (182, 9), (183, 9), (184, 9), (185, 9), (186, 9), (187, 9), (188, 9), (189, 9), (190, 9), (191, 9), (192, 9), (193, 9), (194, 9), (195, 9), (196, 9), (197, 9), (198, 9), (199, 9), (200, 9), (201, 9), (202, 9), (203, 9), (204, 9), (205, 9), (206, 9), (207, 9), (208, 9), (209, 9), (210, 9), (211, 9), (212, 9), (213, 9), (214, 9), (215, 9), (216, 9), (217, 9), (218, 9), (219, 9), (220, 9), (221, 9), (222, 9), (223, 9), (224, 9), (225, 9), (226, 9), (227, 9), (228, 9), (229, 9), (230, 9), (231, 9), (232, 9), (233, 9), (234, 9), (235, 9), (236, 9), (237, 9), (238, 9), (239, 9), (240, 9), (241, 9), (242, 9), (243, 9), (244, 9), (245, 9), (246, 9), (247, 9), (248, 9), (249, 9), (250, 9), (251, 9), (252, 9), (253, 9), (254, 9), (255, 9), (256, 9), (257, 9), (258, 9), (259, 9), (260, 9), (261, 9), (262, 9), (263, 9), (264, 9), (265, 9), (266, 9), (267, 9), (268, 9), (269, 9), (270, 9), (271, 9), (272, 9), (273, 9), (274, 9), (275, 9), (276, 9), (277, 9), (278, 9), (279, 9), (280, 9), (281, 9), (282, 9), (283, 9), (284, 9), (285, 9), (286, 9), (287, 9), (288, 9), (289, 9), (290, 9), (291, 9), (292, 9), (293, 9), (294, 9), (295, 9), (296, 9), (297, 9), (298, 9), (299, 9), (300, 9), (301, 9), (302, 9), (303, 9), (304, 9), (305, 9), (306, 9), (307, 9), (308, 9), (309, 9), (310, 9), (311, 9), (312, 9), (313, 9), (314, 9), (315, 9), (316, 9), (317, 9), (318, 9), (319, 9), (320, 9), (321, 9), (322, 9), (323, 9), (324, 9), (325, 9), (326, 9), (327, 9), (328, 9), (329, 9), (330, 9), (331, 9), (332, 9), (333, 9), (334, 9), (335, 9), (336, 9), (337, 9), (338, 9), (339, 9), (340, 9), (341, 9), (342, 9), (343, 9), (344, 9), (345, 9), (346, 9), (347, 9), (348, 9), (349, 9), (350, 9), (351, 9), (352, 9), (353, 9), (354, 9), (355, 9), (356, 9), (357, 9), (358, 9), (359, 9), (360, 9), (361, 9), (362, 9), (363, 9), (364, 9), (365, 9), (366, 9), (367, 9), (368, 9), (369, 9), (370, 9), (371, 9), (372, 9), (373, 9), (374, 9), (375, 9), (376, 9), (377, 9), (378, 9), (379, 9), (380, 9), (381, 9), (382, 9), (383, 9), (384, 9), (385, 9), (386, 9), (387, 9), (388, 9), (389, 9), (390, 9), (391, 9), (392, 9), (393, 9), (394, 9), (395, 9), (396, 9), (397, 9), (398, 9), (399, 9), (400, 9), (401, 9), (402, 9), (403, 9), (404, 9), (405, 9), (406, 9), (407, 9), (408, 9), (409, 9), (410, 9), (411, 9), (412, 9), (413, 9), (414, 9), (415, 9), (416, 9), (417, 9), (418, 9), (419, 9), (420, 9), (421, 9), (422, 9), (423, 9), (424, 9), (425, 9), (426, 9), (427, 9), (428, 9), (429, 9), (430, 9), (431, 9), (432, 9), (433, 9), (434, 9), (435, 9), (436, 9), (437, 9), (438, 9), (439, 9), (440, 9), (441, 9), (442, 9), (443, 9), (444, 9), (445, 9), (446, 9), (447, 9), (448, 9), (449, 9), (450, 9), (451, 9), (452, 9), (453, 9), (454, 9), (455, 9), (456, 9), (457, 9), (458, 9), (459, 9), (460, 9), (461, 9), (462, 9), (463, 9), (464, 9), (465, 9), (466, 9), (467, 9), (468, 9), (469, 9), (470, 9), (471, 9), (472, 9), (473, 9), (474, 9), (475, 9), (476, 9), (477, 9), (478, 9), (479, 9), (480, 9), (481, 9), (482, 9), (483, 9), (484, 9), (485, 9), (486, 9), (487, 9), (488, 9), (489, 9), (490, 9), (491, 9), (492, 9), (493, 9), (494, 9), (495, 9), (496, 9), (497, 9), (498, 9), (499, 9), (500, 9), (501, 9), (502, 9), (503, 9), (504, 9), (505, 9), (506, 9), (507, 9), (508, 9), (509, 9), (510, 9), (511, 9), (0, 9), (1, 9), (2, 9), (3, 9), (4, 9), (5, 9), (6, 9), (7, 9), (8, 9), (9, 9), (10, 9), (11, 9), (12, 9), (13, 9), (14, 9), (15, 9), (16, 9), (17, 9), (18, 9), (19, 9), (20, 9), (21, 9), (22, 9), (23, 9), (24, 9), (25, 9), (26, 9), (27, 9), (28, 9), (29, 9), (30, 9), (31, 9), (32, 9), (33, 9), (34, 9), (35, 9), (36, 9), (37, 9), (38, 9), (39, 9), (40, 9), (41, 9), (42, 9), (43, 9), (44, 9), (45, 9), (46, 9), (47, 9), (48, 9), (49, 9), (50, 9), (51, 9), (52, 9), (53, 9), (54, 9), (55, 9), (56, 9), (57, 9), (58, 9), (59, 9), (60, 9), (61, 9), (62, 9), (63, 9), (64, 9), (65, 9), (66, 9), (67, 9), (68, 9), (69, 9), (70, 9), (71, 9), (72, 9), (73, 9), (74, 9), (75, 9), (76, 9), (77, 9), (78, 9), (79, 9), (80, 9), (81, 9), (82, 9), (83, 9), (84, 9), (85, 9), (86, 9), (87, 9), (88, 9), (89, 9), (90, 9), (91, 9), (92, 9), (93, 9), (94, 9), (95, 9), (96, 9), (97, 9), (98, 9), (99, 9), (100, 9), (101, 9), (102, 9), (103, 9), (104, 9), (105, 9), (106, 9), (107, 9), (108, 9), (109, 9), (110, 9), (111, 9), (112, 9), (113, 9), (114, 9), (115, 9), (116, 9), (117, 9), (118, 9), (119, 9), (120, 9), (121, 9), (122, 9), (123, 9), (124, 9), (125, 9), (126, 9), (127, 9), (128, 9), (129, 9), (130, 9), (131, 9), (132, 9), (133, 9), (134, 9), (135, 9), (136, 9), (137, 9), (138, 9), (139, 9), (140, 9), (141, 9), (142, 9), (143, 9), (144, 9), (145, 9), (146, 9), (147, 9), (148, 9), (149, 9), (150, 9), (151, 9), (152, 9), (153, 9), (154, 9), (155, 9), (156, 9), (157, 9), (158, 9), (159, 9), (160, 9), (161, 9), (162, 9), (163, 9), (164, 9), (165, 9), (166, 9), (167, 9), (168, 9), (169, 9), (170, 9), (171, 9), (172, 9), (173, 9), (174, 9), (175, 9), (176, 9), (177, 9), (178, 9), (179, 9), (180, 9), (181, 9), (182, 9), (183, 9), (184, 9), (185, 9), (186, 9), (187, 9), (188, 9), (189, 9), (190, 9), (191, 9), (192, 9), (193, 9), (194, 9), (195, 9), (196, 9), (197, 9), (198, 9), (199, 9), (200, 9), (201, 9), (202, 9), (203, 9), (204, 9), (205, 9), (206, 9), (207, 9), (208, 9), (209, 9), (210, 9), (211, 9), (212, 9), (213, 9), (214, 9), (215, 9), (216, 9), (217, 9), (218, 9), (219, 9), (220, 9), (221, 9), (222, 9), (223, 9), (224, 9), (225, 9), (226, 9), (227, 9), (228, 9), (229, 9), (230, 9), (231, 9), (232, 9), (233, 9), (234, 9), (235, 9), (236, 9), (237, 9), (238, 9), (239, 9), (240, 9), (241, 9), (242, 9), (243, 9), (244, 9), (245, 9), (246, 9), (247, 9), (248, 9), (249, 9), (250, 9), (251, 9), (252, 9), (253, 9), (254, 9), (255, 9), (256, 9), (257, 9), (258, 9), (259, 9), (260, 9), (261, 9), (262, 9), (263, 9), (264, 9), (265, 9), (266, 9), (267, 9), (268, 9), (269, 9), (270, 9), (271, 9), (272, 9), (273, 9), (274, 9), (275, 9), (276, 9), (277, 9), (278, 9), (279, 9), (280, 9), (281, 9), (282, 9), (283, 9), (284, 9), (285, 9), (286, 9), (287, 9), (288, 9), (289, 9), (290, 9), (291, 9), (292, 9), (293, 9), (294, 9), (295, 9), (296, 9), (297, 9), (298, 9), (299, 9), (300, 9), (301, 9), (302, 9), (303, 9), (304, 9), (305, 9), (306, 9), (307, 9), (308, 9), (309, 9), (310, 9), (311, 9), (312, 9), (313, 9), (314, 9), (315, 9), (316, 9), (317, 9), (318, 9), (319, 9), (320, 9), (321, 9), (322, 9), (323, 9), (324, 9), (325, 9), (326, 9), (327, 9), (328, 9), (329, 9), (330, 9), (331, 9), (332, 9), (333, 9), (334, 9), (335, 9), (336, 9), (337, 9), (338, 9), (339, 9), (340, 9), (341, 9), (342, 9), (343, 9), (344, 9), (345, 9), (346, 9), (347, 9), (348, 9), (349, 9), (350, 9), (351, 9), (352, 9), (353, 9), (354, 9), (355, 9), (356, 9), (357, 9), (358, 9), (359, 9), (360, 9), (361, 9), (362, 9), (363, 9), (364, 9), (365, 9), (366, 9), (367, 9), (368, 9), (369, 9), (370, 9), (371, 9), (372, 9), (373, 9), (374, 9), (375, 9), (376, 9), (377, 9), (378, 9), (379, 9), (380, 9), (381, 9), (382, 9), (383, 9), (384, 9), (385, 9), (386, 9), (387, 9), (388, 9), (389, 9), (390, 9), (391, 9), (392, 9), (393, 9), (394, 9), (395, 9), (396, 9), (397, 9), (398, 9), (399, 9), (400, 9), (401, 9), (402, 9), (403, 9), (404, 9), (405, 9), (406, 9), (407, 9), (408, 9), (409, 9), (410, 9), (411, 9), (412, 9), (413, 9), (414, 9), (415, 9), (416, 9), (417, 9), (418, 9), (419, 9), (420, 9), (421, 9), (422, 9), (423, 9), (424, 9), (425, 9), (426, 9), (427, 9), (428, 9), (429, 9), (430, 9), (431, 9), (432, 9), (433, 9), (434, 9), (435, 9), (436, 9), (437, 9), (438, 9), (439, 9), (440, 9), (441, 9), (442, 9), (443, 9), (444, 9), (445, 9), (446, 9), (447, 9), (448, 9), (449, 9), (450, 9), (451, 9), (452, 9), (453, 9), (454, 9), (455, 9), (456, 9), (457, 9), (458, 9), (459, 9), (460, 9), (461, 9), (462, 9), (463, 9), (464, 9), (465, 9), (466, 9), (467, 9), (468, 9), (469, 9), (470, 9), (471, 9), (472, 9), (473, 9), (474, 9), (475, 9), (476, 9), (477, 9), (478, 9), (479, 9), (480, 9), (481, 9), (482, 9), (483, 9), (484, 9), (485, 9), (486, 9), (487, 9), (488, 9), (489, 9), (490, 9), (491, 9), (492, 9), (493, 9), (494, 9), (495, 9), (496, 9), (497, 9), (498, 9), (499, 9), (500, 9), (501, 9), (502, 9), (503, 9), (504, 9), (505, 9), (506, 9), (507, 9), (508, 9), (509, 9), (510, 9), (511, 9), (0, 10), (1, 10), (2, 10), (3, 10), (4, 10), (5, 10), (6, 10), (7, 10), (8, 10), (9, 10), (10, 10), (11, 10), (12, 10), (13, 10), (14, 10), (15, 10), (16, 10), (17, 10), (18, 10), (19, 10), (20, 10), (21, 10), (22, 10), (23, 10), (24, 10), (25, 10), (26, 10), (27, 10), (28, 10), (29, 10), (30, 10), (31, 10), (32, 10), (33, 10), (34, 10), (35, 10), (36, 10), (37, 10), (38, 10), (39, 10), (40, 10), (41, 10), (42, 10), (43, 10), (44, 10), (45, 10), (46, 10), (47, 10), (48, 10), (49, 10), (50, 10), (51, 10), (52, 10), (53, 10), (54, 10), (55, 10), (56, 10), (57, 10), (58, 10), (59, 10), (60, 10), (61, 10), (62, 10), (63, 10), (64, 10), (65, 10), (66, 10), (67, 10), (68, 10), (69, 10), (70, 10), (71, 10), (72, 10), (73, 10), (74, 10), (75, 10), (76, 10), (77, 10), (78, 10), (79, 10), (80, 10), (81, 10), (82, 10), (83, 10), (84, 10), (85, 10), (86, 10), (87, 10), (88, 10), (89, 10), (90, 10), (91, 10), (92, 10), (93, 10), (94, 10), (95, 10), (96, 10), (97, 10), (98, 10), (99, 10), (100, 10), (101, 10), (102, 10), (103, 10), (104, 10), (105, 10), (106, 10), (107, 10), (108, 10), (109, 10), (110, 10), (111, 10), (112, 10), (113, 10), (114, 10), (115, 10), (116, 10), (117, 10), (118, 10), (119, 10), (120, 10), (121, 10), (122, 10), (123, 10), (124, 10), (125, 10), (126, 10), (127, 10), (128, 10), (129, 10), (130, 10), (131, 10), (132, 10), (133, 10), (134, 10), (135, 10), (136, 10), (137, 10), (138, 10), (139, 10), (140, 10), (141, 10), (142, 10), (143, 10), (144, 10), (145, 10), (146, 10), (147, 10), (148, 10), (149, 10), (150, 10), (151, 10), (152, 10), (153, 10), (154, 10), (155, 10), (156, 10), (157, 10), (158, 10), (159, 10), (160, 10), (161, 10), (162, 10), (163, 10), (164, 10), (165, 10), (166, 10), (167, 10), (168, 10), (169, 10), (170, 10), (171, 10), (172, 10), (173, 10), (174, 10), (175, 10), (176, 10), (177, 10), (178, 10), (179, 10), (180, 10), (181, 10), (182, 10), (183, 10), (184, 10), (185, 10), (186, 10), (187, 10), (188, 10), (189, 10), (190, 10), (191, 10), (192, 10), (193, 10), (194, 10), (195, 10), (196, 10), (197, 10), (198, 10), (199, 10), (200, 10), (201, 10), (202, 10), (203, 10), (204, 10), (205, 10), (206, 10), (207, 10), (208, 10), (209, 10), (210, 10), (211, 10), (212, 10), (213, 10), (214, 10), (215, 10), (216, 10), (217, 10), (218, 10), (219, 10), (220, 10), (221, 10), (222, 10), (223, 10), (224, 10), (225, 10), (226, 10), (227, 10), (228, 10), (229, 10), (230, 10), (231, 10), (232, 10), (233, 10), (234, 10), (235, 10), (236, 10), (237, 10), (238, 10), (239, 10), (240, 10), (241, 10), (242, 10), (243, 10), (244, 10), (245, 10), (246, 10), (247, 10), (248, 10), (249, 10), (250, 10), (251, 10), (252, 10), (253, 10), (254, 10), (255, 10), (256, 10), (257, 10), (258, 10), (259, 10), (260, 10), (261, 10), (262, 10), (263, 10), (264, 10), (265, 10), (266, 10), (267, 10), (268, 10), (269, 10), (270, 10), (271, 10), (272, 10), (273, 10), (274, 10), (275, 10), (276, 10), (277, 10), (278, 10), (279, 10), (280, 10), (281, 10), (282, 10), (283, 10), (284, 10), (285, 10), (286, 10), (287, 10), (288, 10), (289, 10), (290, 10), (291, 10), (292, 10), (293, 10), (294, 10), (295, 10), (296, 10), (297, 10), (298, 10), (299, 10), (300, 10), (301, 10), (302, 10), (303, 10), (304, 10), (305, 10), (306, 10), (307, 10), (308, 10), (309, 10), (310, 10), (311, 10), (312, 10), (313, 10), (314, 10), (315, 10), (316, 10), (317, 10), (318, 10), (319, 10), (320, 10), (321, 10), (322, 10), (323, 10), (324, 10), (325, 10), (326, 10), (327, 10), (328, 10), (329, 10), (330, 10), (331, 10), (332, 10), (333, 10), (334, 10), (335, 10), (336, 10), (337, 10), (338, 10), (339, 10), (340, 10), (341, 10), (342, 10), (343, 10), (344, 10), (345, 10), (346, 10), (347, 10), (348, 10), (349, 10), (350, 10), (351, 10), (352, 10), (353, 10), (354, 10), (355, 10), (356, 10), (357, 10), (358, 10), (359, 10), (360, 10), (361, 10), (362, 10), (363, 10), (364, 10), (365, 10), (366, 10), (367, 10), (368, 10), (369, 10), (370, 10), (371, 10), (372, 10), (373, 10), (374, 10), (375, 10), (376, 10), (377, 10), (378, 10), (379, 10), (380, 10), (381, 10), (382, 10), (383, 10), (384, 10), (385, 10), (386, 10), (387, 10), (388, 10), (389, 10), (390, 10), (391, 10), (392, 10), (393, 10), (394, 10), (395, 10), (396, 10), (397, 10), (398, 10), (399, 10), (400, 10), (401, 10), (402, 10), (403, 10), (404, 10), (405, 10), (406, 10), (407, 10), (408, 10), (409, 10), (410, 10), (411, 10), (412, 10), (413, 10), (414, 10), (415, 10), (416, 10), (417, 10), (418, 10), (419, 10), (420, 10), (421, 10), (422, 10), (423, 10), (424, 10), (425, 10), (426, 10), (427, 10), (428, 10), (429, 10), (430, 10), (431, 10), (432, 10), (433, 10), (434, 10), (435, 10), (436, 10), (437, 10), (438, 10), (439, 10), (440, 10), (441, 10), (442, 10), (443, 10), (444, 10), (445, 10), (446, 10), (447, 10), (448, 10), (449, 10), (450, 10), (451, 10), (452, 10), (453, 10), (454, 10), (455, 10), (456, 10), (457, 10), (458, 10), (459, 10), (460, 10), (461, 10), (462, 10), (463, 10), (464, 10), (465, 10), (466, 10), (467, 10), (468, 10), (469, 10), (470, 10), (471, 10), (472, 10), (473, 10), (474, 10), (475, 10), (476, 10), (477, 10), (478, 10), (479, 10), (480, 10), (481, 10), (482, 10), (483, 10), (484, 10), (485, 10), (486, 10), (487, 10), (488, 10), (489, 10), (490, 10), (491, 10), (492, 10), (493, 10), (494, 10), (495, 10), (496, 10), (497, 10), (498, 10), (499, 10), (500, 10), (501, 10), (502, 10), (503, 10), (504, 10), (505, 10), (506, 10), (507, 10), (508, 10), (509, 10), (510, 10), (511, 10), (512, 10), (513, 10), (514, 10), (515, 10), (516, 10), (517, 10), (518, 10), (519, 10), (520, 10), (521, 10), (522, 10), (523, 10), (524, 10), (525, 10), (526, 10), (527, 10), (528, 10), (529, 10), (530, 10), (531, 10), (532, 10), (533, 10), (534, 10), (535, 10), (536, 10), (537, 10), (538, 10), (539, 10), (540, 10), (541, 10), (542, 10), (543, 10), (544, 10), (545, 10), (546, 10), (547, 10), (548, 10), (549, 10), (550, 10), (551, 10), (552, 10), (553, 10), (554, 10), (555, 10), (556, 10), (557, 10), (558, 10), (559, 10), (560, 10), (561, 10), (562, 10), (563, 10), (564, 10), (565, 10), (566, 10), (567, 10), (568, 10), (569, 10), (570, 10), (571, 10), (572, 10), (573, 10), (574, 10), (575, 10), (576, 10), (577, 10), (578, 10), (579, 10), (580, 10), (581, 10), (582, 10), (583, 10), (584, 10), (585, 10), (586, 10), (587, 10), (588, 10), (589, 10), (590, 10), (591, 10), (592, 10), (593, 10), (594, 10), (595, 10), (596, 10), (597, 10), (598, 10), (599, 10), (600, 10), (601, 10), (602, 10), (603, 10), (604, 10), (605, 10), (606, 10), (607, 10), (608, 10), (609, 10), (610, 10), (611, 10), (612, 10), (613, 10), (614, 10), (615, 10), (616, 10), (617, 10), (618, 10), (619, 10), (620, 10), (621, 10), (622, 10), (623, 10), (624, 10), (625, 10), (626, 10), (627, 10), (628, 10), (629, 10), (630, 10), (631, 10), (632, 10), (633, 10), (634, 10), (635, 10), (636, 10), (637, 10), (638, 10), (639, 10), (640, 10), (641, 10), (642, 10), (643, 10), (644, 10), (645, 10), (646, 10), (647, 10), (648, 10), (649, 10), (650, 10), (651, 10), (652, 10), (653, 10), (654, 10), (655, 10), (656, 10), (657, 10), (658, 10), (659, 10), (660, 10), (661, 10), (662, 10), (663, 10), (664, 10), (665, 10), (666, 10), (667, 10), (668, 10), (669, 10), (670, 10), (671, 10), (672, 10), (673, 10), (674, 10), (675, 10), (676, 10), (677, 10), (678, 10), (679, 10), (680, 10), (681, 10), (682, 10), (683, 10), (684, 10), (685, 10), (686, 10), (687, 10), (688, 10), (689, 10), (690, 10), (691, 10), (692, 10), (693, 10), (694, 10), (695, 10), (696, 10), (697, 10), (698, 10), (699, 10), (700, 10), (701, 10), (702, 10), (703, 10), (704, 10), (705, 10), (706, 10), (707, 10), (708, 10), (709, 10), (710, 10), (711, 10), (712, 10), (713, 10), (714, 10), (715, 10), (716, 10), (717, 10), (718, 10), (719, 10), (720, 10), (721, 10), (722, 10), (723, 10), (724, 10), (725, 10), (726, 10), (727, 10), (728, 10), (729, 10), (730, 10), (731, 10), (732, 10), (733, 10), (734, 10), (735, 10), (736, 10), (737, 10), (738, 10), (739, 10), (740, 10), (741, 10), (742, 10), (743, 10), (744, 10), (745, 10), (746, 10), (747, 10), (748, 10), (749, 10), (750, 10), (751, 10), (752, 10), (753, 10), (754, 10), (755, 10), (756, 10), (757, 10), (758, 10), (759, 10), (760, 10), (761, 10), (762, 10), (763, 10), (764, 10), (765, 10), (766, 10), (767, 10), (768, 10), (769, 10), (770, 10), (771, 10), (772, 10), (773, 10), (774, 10), (775, 10), (776, 10), (777, 10), (778, 10), (779, 10), (780, 10), (781, 10), (782, 10), (783, 10), (784, 10), (785, 10), (786, 10), (787, 10), (788, 10), (789, 10), (790, 10), (791, 10), (792, 10), (793, 10), (794, 10), (795, 10), (796, 10), (797, 10), (798, 10), (799, 10), (800, 10), (801, 10), (802, 10), (803, 10), (804, 10), (805, 10), (806, 10), (807, 10), (808, 10), (809, 10), (810, 10), (811, 10), (812, 10), (813, 10), (814, 10), (815, 10), (816, 10), (817, 10), (818, 10), (819, 10), (820, 10), (821, 10), (822, 10), (823, 10), (824, 10), (825, 10), (826, 10), (827, 10), (828, 10), (829, 10), (830, 10), (831, 10), (832, 10), (833, 10), (834, 10), (835, 10), (836, 10), (837, 10), (838, 10), (839, 10), (840, 10), (841, 10), (842, 10), (843, 10), (844, 10), (845, 10), (846, 10), (847, 10), (848, 10), (849, 10), (850, 10), (851, 10), (852, 10), (853, 10), (854, 10), (855, 10), (856, 10), (857, 10), (858, 10), (859, 10), (860, 10), (861, 10), (862, 10), (863, 10), (864, 10), (865, 10), (866, 10), (867, 10), (868, 10), (869, 10), (870, 10), (871, 10), (872, 10), (873, 10), (874, 10), (875, 10), (876, 10), (877, 10), (878, 10), (879, 10), (880, 10), (881, 10), (882, 10), (883, 10), (884, 10), (885, 10), (886, 10), (887, 10), (888, 10), (889, 10), (890, 10), (891, 10), (892, 10), (893, 10), (894, 10), (895, 10), (896, 10), (897, 10), (898, 10), (899, 10), (900, 10), (901, 10), (902, 10), (903, 10), (904, 10), (905, 10), (906, 10), (907, 10), (908, 10), (909, 10), (910, 10), (911, 10), (912, 10), (913, 10), (914, 10), (915, 10), (916, 10), (917, 10), (918, 10), (919, 10), (920, 10), (921, 10), (922, 10), (923, 10), (924, 10), (925, 10), (926, 10), (927, 10), (928, 10), (929, 10), (930, 10), (931, 10), (932, 10), (933, 10), (934, 10), (935, 10), (936, 10), (937, 10), (938, 10), (939, 10), (940, 10), (941, 10), (942, 10), (943, 10), (944, 10), (945, 10), (946, 10), (947, 10), (948, 10), (949, 10), (950, 10), (951, 10), (952, 10), (953, 10), (954, 10), (955, 10), (956, 10), (957, 10), (958, 10), (959, 10), (960, 10), (961, 10), (962, 10), (963, 10), (964, 10), (965, 10), (966, 10), (967, 10), (968, 10), (969, 10), (970, 10), (971, 10), (972, 10), (973, 10), (974, 10), (975, 10), (976, 10), (977, 10), (978, 10), (979, 10), (980, 10), (981, 10), (982, 10), (983, 10), (984, 10), (985, 10), (986, 10), (987, 10), (988, 10), (989, 10), (990, 10), (991, 10), (992, 10), (993, 10), (994, 10), (995, 10), (996, 10), (997, 10), (998, 10), (999, 10), (1000, 10), (1001, 10), (1002, 10), (1003, 10), (1004, 10), (1005, 10), (1006, 10), (1007, 10), (1008, 10), (1009, 10), (1010, 10), (1011, 10), (1012, 10), (1013, 10), (1014, 10), (1015, 10), (1016, 10), (1017, 10), (1018, 10), (1019, 10), (1020, 10), (1021, 10), (1022, 10), (1023, 10), (0, 10), (1, 10), (2, 10), (3, 10), (4, 10), (5, 10), (6, 10), (7, 10), (8, 10), (9, 10), (10, 10), (11, 10), (12, 10), (13, 10), (14, 10), (15, 10), (16, 10), (17, 10), (18, 10), (19, 10), (20, 10), (21, 10), (22, 10), (23, 10), (24, 10), (25, 10), (26, 10), (27, 10), (28, 10), (29, 10), (30, 10), (31, 10), (32, 10), (33, 10), (34, 10), (35, 10), (36, 10), (37, 10), (38, 10), (39, 10), (40, 10), (41, 10), (42, 10), (43, 10), (44, 10), (45, 10), (46, 10), (47, 10), (48, 10), (49, 10), (50, 10), (51, 10), (52, 10), (53, 10), (54, 10), (55, 10), (56, 10), (57, 10), (58, 10), (59, 10), (60, 10), (61, 10), (62, 10), (63, 10), (64, 10), (65, 10), (66, 10), (67, 10), (68, 10), (69, 10), (70, 10), (71, 10), (72, 10), (73, 10), (74, 10), (75, 10), (76, 10), (77, 10), (78, 10), (79, 10), (80, 10), (81, 10), (82, 10), (83, 10), (84, 10), (85, 10), (86, 10), (87, 10), (88, 10), (89, 10), (90, 10), (91, 10), (92, 10), (93, 10), (94, 10), (95, 10), (96, 10), (97, 10), (98, 10), (99, 10), (100, 10), (101, 10), (102, 10), (103, 10), (104, 10), (105, 10), (106, 10), (107, 10), (108, 10), (109, 10), (110, 10), (111, 10), (112, 10), (113, 10), (114, 10), (115, 10), (116, 10), (117, 10), (118, 10), (119, 10), (120, 10), (121, 10), (122, 10), (123, 10), (124, 10), (125, 10), (126, 10), (127, 10), (128, 10), (129, 10), (130, 10), (131, 10), (132, 10), (133, 10), (134, 10), (135, 10), (136, 10), (137, 10), (138, 10), (139, 10), (140, 10), (141, 10), (142, 10), (143, 10), (144, 10), (145, 10), (146, 10), (147, 10), (148, 10), (149, 10), (150, 10), (151, 10), (152, 10), (153, 10), (154, 10), (155, 10), (156, 10), (157, 10), (158, 10), (159, 10), (160, 10), (161, 10), (162, 10), (163, 10), (164, 10), (165, 10), (166, 10), (167, 10), (168, 10), (169, 10), (170, 10), (171, 10), (172, 10), (173, 10), (174, 10), (175, 10), (176, 10), (177, 10), (178, 10), (179, 10), (180, 10), (181, 10), (182, 10), (183, 10), (184, 10), (185, 10), (186, 10), (187, 10), (188, 10), (189, 10), (190, 10), (191, 10), (192, 10), (193, 10), (194, 10), (195, 10), (196, 10), (197, 10), (198, 10), (199, 10), (200, 10), (201, 10), (202, 10), (203, 10), (204, 10), (205, 10), (206, 10), (207, 10), (208, 10), (209, 10), (210, 10), (211, 10), (212, 10), (213, 10), (214, 10), (215, 10), (216, 10), (217, 10), (218, 10), (219, 10), (220, 10), (221, 10), (222, 10), (223, 10), (224, 10), (225, 10), (226, 10), (227, 10), (228, 10), (229, 10), (230, 10), (231, 10), (232, 10), (233, 10), (234, 10), (235, 10), (236, 10), (237, 10), (238, 10), (239, 10), (240, 10), (241, 10), (242, 10), (243, 10), (244, 10), (245, 10), (246, 10), (247, 10), (248, 10), (249, 10), (250, 10), (251, 10), (252, 10), (253, 10), (254, 10), (255, 10), (256, 10), (257, 10), (258, 10), (259, 10), (260, 10), (261, 10), (262, 10), (263, 10), (264, 10), (265, 10), (266, 10), (267, 10), (268, 10), (269, 10), (270, 10), (271, 10), (272, 10), (273, 10), (274, 10), (275, 10), (276, 10), (277, 10), (278, 10), (279, 10), (280, 10), (281, 10), (282, 10), (283, 10), (284, 10), (285, 10), (286, 10), (287, 10), (288, 10), (289, 10), (290, 10), (291, 10), (292, 10), (293, 10), (294, 10), (295, 10), (296, 10), (297, 10), (298, 10), (299, 10), (300, 10), (301, 10), (302, 10), (303, 10), (304, 10), (305, 10), (306, 10), (307, 10), (308, 10), (309, 10), (310, 10), (311, 10), (312, 10), (313, 10), (314, 10), (315, 10), (316, 10), (317, 10), (318, 10), (319, 10), (320, 10), (321, 10), (322, 10), (323, 10), (324, 10), (325, 10), (326, 10), (327, 10), (328, 10), (329, 10), (330, 10), (331, 10), (332, 10), (333, 10), (334, 10), (335, 10), (336, 10), (337, 10), (338, 10), (339, 10), (340, 10), (341, 10), (342, 10), (343, 10), (344, 10), (345, 10), (346, 10), (347, 10), (348, 10), (349, 10), (350, 10), (351, 10), (352, 10), (353, 10), (354, 10), (355, 10), (356, 10), (357, 10), (358, 10), (359, 10), (360, 10), (361, 10), (362, 10), (363, 10), (364, 10), (365, 10), (366, 10), (367, 10), (368, 10), (369, 10), (370, 10), (371, 10), (372, 10), (373, 10), (374, 10), (375, 10), (376, 10), (377, 10), (378, 10), (379, 10), (380, 10), (381, 10), (382, 10), (383, 10), (384, 10), (385, 10), (386, 10), (387, 10), (388, 10), (389, 10), (390, 10), (391, 10), (392, 10), (393, 10), (394, 10), (395, 10), (396, 10), (397, 10), (398, 10), (399, 10), (400, 10), (401, 10), (402, 10), (403, 10), (404, 10), (405, 10), (406, 10), (407, 10), (408, 10), (409, 10), (410, 10), (411, 10), (412, 10), (413, 10), (414, 10), (415, 10), (416, 10), (417, 10), (418, 10), (419, 10), (420, 10), (421, 10), (422, 10), (423, 10), (424, 10), (425, 10), (426, 10), (427, 10), (428, 10), (429, 10), (430, 10), (431, 10), (432, 10), (433, 10), (434, 10), (435, 10), (436, 10), (437, 10), (438, 10), (439, 10), (440, 10), (441, 10), (442, 10), (443, 10), (444, 10), (445, 10), (446, 10), (447, 10), (448, 10), (449, 10), (450, 10), (451, 10), (452, 10), (453, 10), (454, 10), (455, 10), (456, 10), (457, 10), (458, 10), (459, 10), (460, 10), (461, 10), (462, 10), (463, 10), (464, 10), (465, 10), (466, 10), (467, 10), (468, 10), (469, 10), (470, 10), (471, 10), (472, 10), (473, 10), (474, 10), (475, 10), (476, 10), (477, 10), (478, 10), (479, 10), (480, 10), (481, 10), (482, 10), (483, 10), (484, 10), (485, 10), (486, 10), (487, 10), (488, 10), (489, 10), (490, 10), (491, 10), (492, 10), (493, 10), (494, 10), (495, 10), (496, 10), (497, 10), (498, 10), (499, 10), (500, 10), (501, 10), (502, 10), (503, 10), (504, 10), (505, 10), (506, 10), (507, 10), (508, 10), (509, 10), (510, 10), (511, 10), (512, 10), (513, 10), (514, 10), (515, 10), (516, 10), (517, 10), (518, 10), (519, 10), (520, 10), (521, 10), (522, 10), (523, 10), (524, 10), (525, 10), (526, 10), (527, 10), (528, 10), (529, 10), (530, 10), (531, 10), (532, 10), (533, 10), (534, 10), (535, 10), (536, 10), (537, 10), (538, 10), (539, 10), (540, 10), (541, 10), (542, 10), (543, 10), (544, 10), (545, 10), (546, 10), (547, 10), (548, 10), (549, 10), (550, 10), (551, 10), (552, 10), (553, 10), (554, 10), (555, 10), (556, 10), (557, 10), (558, 10), (559, 10), (560, 10), (561, 10), (562, 10), (563, 10), (564, 10), (565, 10), (566, 10), (567, 10), (568, 10), (569, 10), (570, 10), (571, 10), (572, 10), (573, 10), (574, 10), (575, 10), (576, 10), (577, 10), (578, 10), (579, 10), (580, 10), (581, 10), (582, 10), (583, 10), (584, 10), (585, 10), (586, 10), (587, 10), (588, 10), (589, 10), (590, 10), (591, 10), (592, 10), (593, 10), (594, 10), (595, 10), (596, 10), (597, 10), (598, 10), (599, 10), (600, 10), (601, 10), (602, 10), (603, 10), (604, 10), (605, 10), (606, 10), (607, 10), (608, 10), (609, 10), (610, 10), (611, 10), (612, 10), (613, 10), (614, 10), (615, 10), (616, 10), (617, 10), (618, 10), (619, 10), (620, 10), (621, 10), (622, 10), (623, 10), (624, 10), (625, 10), (626, 10), (627, 10), (628, 10), (629, 10), (630, 10), (631, 10), (632, 10), (633, 10), (634, 10), (635, 10), (636, 10), (637, 10), (638, 10), (639, 10), (640, 10), (641, 10), (642, 10), (643, 10), (644, 10), (645, 10), (646, 10), (647, 10), (648, 10), (649, 10), (650, 10), (651, 10), (652, 10), (653, 10), (654, 10), (655, 10), (656, 10), (657, 10), (658, 10), (659, 10), (660, 10), (661, 10), (662, 10), (663, 10), (664, 10), (665, 10), (666, 10), (667, 10), (668, 10), (669, 10), (670, 10), (671, 10), (672, 10), (673, 10), (674, 10), (675, 10), (676, 10), (677, 10), (678, 10), (679, 10), (680, 10), (681, 10), (682, 10), (683, 10), (684, 10), (685, 10), (686, 10), (687, 10), (688, 10), (689, 10), (690, 10), (691, 10), (692, 10), (693, 10), (694, 10), (695, 10), (696, 10), (697, 10), (698, 10), (699, 10), (700, 10), (701, 10), (702, 10), (703, 10), (704, 10), (705, 10), (706, 10), (707, 10), (708, 10), (709, 10), (710, 10), (711, 10), (712, 10), (713, 10), (714, 10), (715, 10), (716, 10), (717, 10), (718, 10), (719, 10), (720, 10), (721, 10), (722, 10), (723, 10), (724, 10), (725, 10), (726, 10), (727, 10), (728, 10), (729, 10), (730, 10), (731, 10), (732, 10), (733, 10), (734, 10), (735, 10), (736, 10), (737, 10), (738, 10), (739, 10), (740, 10), (741, 10), (742, 10), (743, 10), (744, 10), (745, 10), (746, 10), (747, 10), (748, 10), (749, 10), (750, 10), (751, 10), (752, 10), (753, 10), (754, 10), (755, 10), (756, 10), (757, 10), (758, 10), (759, 10), (760, 10), (761, 10), (762, 10), (763, 10), (764, 10), (765, 10), (766, 10), (767, 10), (768, 10), (769, 10), (770, 10), (771, 10), (772, 10), (773, 10), (774, 10), (775, 10), (776, 10), (777, 10), (778, 10), (779, 10), (780, 10), (781, 10), (782, 10), (783, 10), (784, 10), (785, 10), (786, 10), (787, 10), (788, 10), (789, 10), (790, 10), (791, 10), (792, 10), (793, 10), (794, 10), (795, 10), (796, 10), (797, 10), (798, 10), (799, 10), (800, 10), (801, 10), (802, 10), (803, 10), (804, 10), (805, 10), (806, 10), (807, 10), (808, 10), (809, 10), (810, 10), (811, 10), (812, 10), (813, 10), (814, 10), (815, 10), (816, 10), (817, 10), (818, 10), (819, 10), (820, 10), (821, 10), (822, 10), (823, 10), (824, 10), (825, 10), (826, 10), (827, 10), (828, 10), (829, 10), (830, 10), (831, 10), (832, 10), (833, 10), (834, 10), (835, 10), (836, 10), (837, 10), (838, 10), (839, 10), (840, 10), (841, 10), (842, 10), (843, 10), (844, 10), (845, 10), (846, 10), (847, 10), (848, 10), (849, 10), (850, 10), (851, 10), (852, 10), (853, 10), (854, 10), (855, 10), (856, 10), (857, 10), (858, 10), (859, 10), (860, 10), (861, 10), (862, 10), (863, 10), (864, 10), (865, 10), (866, 10), (867, 10), (868, 10), (869, 10), (870, 10), (871, 10), (872, 10), (873, 10), (874, 10), (875, 10), (876, 10), (877, 10), (878, 10), (879, 10), (880, 10), (881, 10), (882, 10), (883, 10), (884, 10), (885, 10), (886, 10), (887, 10), (888, 10), (889, 10), (890, 10), (891, 10), (892, 10), (893, 10), (894, 10), (895, 10), (896, 10), (897, 10), (898, 10), (899, 10), (900, 10), (901, 10), (902, 10), (903, 10), (904, 10), (905, 10), (906, 10), (907, 10), (908, 10), (909, 10), (910, 10), (911, 10), (912, 10), (913, 10), (914, 10), (915, 10), (916, 10), (917, 10), (918, 10), (919, 10), (920, 10), (921, 10), (922, 10), (923, 10), (924, 10), (925, 10), (926, 10), (927, 10), (928, 10), (929, 10), (930, 10), (931, 10), (932, 10), (933, 10), (934, 10), (935, 10), (936, 10), (937, 10), (938, 10), (939, 10), (940, 10), (941, 10), (942, 10), (943, 10), (944, 10), (945, 10), (946, 10), (947, 10), (948, 10), (949, 10), (950, 10), (951, 10), (952, 10), (953, 10), (954, 10), (955, 10), (956, 10), (957, 10), (958, 10), (959, 10), (960, 10), (961, 10), (962, 10), (963, 10), (964, 10), (965, 10), (966, 10), (967, 10), (968, 10), (969, 10), (970, 10), (971, 10), (972, 10), (973, 10), (974, 10), (975, 10), (976, 10), (977, 10), (978, 10), (979, 10), (980, 10), (981, 10), (982, 10), (983, 10), (984, 10), (985, 10), (986, 10), (987, 10), (988, 10), (989, 10), (990, 10), (991, 10), (992, 10), (993, 10), (994, 10), (995, 10), (996, 10), (997, 10), (998, 10), (999, 10), (1000, 10), (1001, 10), (1002, 10), (1003, 10), (1004, 10), (1005, 10), (1006, 10), (1007, 10), (1008, 10), (1009, 10), (1010, 10), (1011, 10), (1012, 10), (1013, 10), (1014, 10), (1015, 10), (1016, 10), (1017, 10), (1018, 10), (1019, 10), (1020, 10), (1021, 10), (1022, 10), (1023, 10), (0, 11), (1, 11), (2, 11), (3, 11), (4, 11), (5, 11), (6, 11), (7, 11), (8, 11), (9, 11), (10, 11), (11, 11), (12, 11), (13, 11), (14, 11), (15, 11), (16, 11), (17, 11), (18, 11), (19, 11), (20, 11), (21, 11), (22, 11), (23, 11), (24, 11), (25, 11), (26, 11), (27, 11), (28, 11), (29, 11), (30, 11), (31, 11), (32, 11), (33, 11), (34, 11), (35, 11), (36, 11), (37, 11), (38, 11), (39, 11), (40, 11), (41, 11), (42, 11), (43, 11), (44, 11), (45, 11), (46, 11), (47, 11), (48, 11), (49, 11), (50, 11), (51, 11), (52, 11), (53, 11), (54, 11), (55, 11), (56, 11), (57, 11), (58, 11), (59, 11), (60, 11), (61, 11), (62, 11), (63, 11), (64, 11), (65, 11), (66, 11), (67, 11), (68, 11), (69, 11), (70, 11), (71, 11), (72, 11), (73, 11), (74, 11), (75, 11), (76, 11), (77, 11), (78, 11), (79, 11), (80, 11), (81, 11), (82, 11), (83, 11), (84, 11), (85, 11), (86, 11), (87, 11), (88, 11), (89, 11), (90, 11), (91, 11), (92, 11), (93, 11), (94, 11), (95, 11), (96, 11), (97, 11), (98, 11), (99, 11), (100, 11), (101, 11), (102, 11), (103, 11), (104, 11), (105, 11), (106, 11), (107, 11), (108, 11), (109, 11), (110, 11), (111, 11), (112, 11), (113, 11), (114, 11), (115, 11), (116, 11), (117, 11), (118, 11), (119, 11), (120, 11), (121, 11), (122, 11), (123, 11), (124, 11), (125, 11), (126, 11), (127, 11), (128, 11), (129, 11), (130, 11), (131, 11), (132, 11), (133, 11), (134, 11), (135, 11), (136, 11), (137, 11), (138, 11), (139, 11), (140, 11), (141, 11), (142, 11), (143, 11), (144, 11), (145, 11), (146, 11), (147, 11), (148, 11), (149, 11), (150, 11), (151, 11), (152, 11), (153, 11), (154, 11), (155, 11), (156, 11), (157, 11), (158, 11), (159, 11), (160, 11), (161, 11), (162, 11), (163, 11), (164, 11), (165, 11), (166, 11), (167, 11), (168, 11), (169, 11), (170, 11), (171, 11), (172, 11), (173, 11), (174, 11), (175, 11), (176, 11), (177, 11), (178, 11), (179, 11), (180, 11), (181, 11), (182, 11), (183, 11), (184, 11), (185, 11), (186, 11), (187, 11), (188, 11), (189, 11), (190, 11), (191, 11), (192, 11), (193, 11), (194, 11), (195, 11), (196, 11), (197, 11), (198, 11), (199, 11), (200, 11), (201, 11), (202, 11), (203, 11), (204, 11), (205, 11), (206, 11), (207, 11), (208, 11), (209, 11), (210, 11), (211, 11), (212, 11), (213, 11), (214, 11), (215, 11), (216, 11), (217, 11), (218, 11), (219, 11), (220, 11), (221, 11), (222, 11), (223, 11), (224, 11), (225, 11), (226, 11), (227, 11), (228, 11), (229, 11), (230, 11), (231, 11), (232, 11), (233, 11), (234, 11), (235, 11), (236, 11), (237, 11), (238, 11), (239, 11), (240, 11), (241, 11), (242, 11), (243, 11), (244, 11), (245, 11), (246, 11), (247, 11), (248, 11), (249, 11), (250, 11), (251, 11), (252, 11), (253, 11), (254, 11), (255, 11), (256, 11), (257, 11), (258, 11), (259, 11), (260, 11), (261, 11), (262, 11), (263, 11), (264, 11), (265, 11), (266, 11), (267, 11), (268, 11), (269, 11), (270, 11), (271, 11), (272, 11), (273, 11), (274, 11), (275, 11), (276, 11), (277, 11), (278, 11), (279, 11), (280, 11), (281, 11), (282, 11), (283, 11), (284, 11), (285, 11), (286, 11), (287, 11), (288, 11), (289, 11), (290, 11), (291, 11), (292, 11), (293, 11), (294, 11), (295, 11), (296, 11), (297, 11), (298, 11), (299, 11), (300, 11), (301, 11), (302, 11), (303, 11), (304, 11), (305, 11), (306, 11), (307, 11), (308, 11), (309, 11), (310, 11), (311, 11), (312, 11), (313, 11), (314, 11), (315, 11), (316, 11), (317, 11), (318, 11), (319, 11), (320, 11), (321, 11), (322, 11), (323, 11), (324, 11), (325, 11), (326, 11), (327, 11), (328, 11), (329, 11), (330, 11), (331, 11), (332, 11), (333, 11), (334, 11), (335, 11), (336, 11), (337, 11), (338, 11), (339, 11), (340, 11), (341, 11), (342, 11), (343, 11), (344, 11), (345, 11), (346, 11), (347, 11), (348, 11), (349, 11), (350, 11), (351, 11), (352, 11), (353, 11), (354, 11), (355, 11), (356, 11), (357, 11), (358, 11), (359, 11), (360, 11), (361, 11), (362, 11), (363, 11), (364, 11), (365, 11), (366, 11), (367, 11), (368, 11), (369, 11), (370, 11), (371, 11), (372, 11), (373, 11), (374, 11), (375, 11), (376, 11), (377, 11), (378, 11), (379, 11), (380, 11), (381, 11), (382, 11), (383, 11), (384, 11), (385, 11), (386, 11), (387, 11), (388, 11), (389, 11), (390, 11), (391, 11), (392, 11), (393, 11), (394, 11), (395, 11), (396, 11), (397, 11), (398, 11), (399, 11), (400, 11), (401, 11), (402, 11), (403, 11), (404, 11), (405, 11), (406, 11), (407, 11), (408, 11), (409, 11), (410, 11), (411, 11), (412, 11), (413, 11), (414, 11), (415, 11), (416, 11), (417, 11), (418, 11), (419, 11), (420, 11), (421, 11), (422, 11), (423, 11), (424, 11), (425, 11), (426, 11), (427, 11), (428, 11), (429, 11), (430, 11), (431, 11), (432, 11), (433, 11), (434, 11), (435, 11), (436, 11), (437, 11), (438, 11), (439, 11), (440, 11), (441, 11), (442, 11), (443, 11), (444, 11), (445, 11), (446, 11), (447, 11), (448, 11), (449, 11), (450, 11), (451, 11), (452, 11), (453, 11), (454, 11), (455, 11), (456, 11), (457, 11), (458, 11), (459, 11), (460, 11), (461, 11), (462, 11), (463, 11), (464, 11), (465, 11), (466, 11), (467, 11), (468, 11), (469, 11), (470, 11), (471, 11), (472, 11), (473, 11), (474, 11), (475, 11), (476, 11), (477, 11), (478, 11), (479, 11), (480, 11), (481, 11), (482, 11), (483, 11), (484, 11), (485, 11), (486, 11), (487, 11), (488, 11), (489, 11), (490, 11), (491, 11), (492, 11), (493, 11), (494, 11), (495, 11), (496, 11), (497, 11), (498, 11), (499, 11), (500, 11), (501, 11), (502, 11), (503, 11), (504, 11), (505, 11), (506, 11), (507, 11), (508, 11), (509, 11), (510, 11), (511, 11), (512, 11), (513, 11), (514, 11), (515, 11), (516, 11), (517, 11), (518, 11), (519, 11), (520, 11), (521, 11), (522, 11), (523, 11), (524, 11), (525, 11), (526, 11), (527, 11), (528, 11), (529, 11), (530, 11), (531, 11), (532, 11), (533, 11), (534, 11), (535, 11), (536, 11), (537, 11), (538, 11), (539, 11), (540, 11), (541, 11), (542, 11), (543, 11), (544, 11), (545, 11), (546, 11), (547, 11), (548, 11), (549, 11), (550, 11), (551, 11), (552, 11), (553, 11), (554, 11), (555, 11), (556, 11), (557, 11), (558, 11), (559, 11), (560, 11), (561, 11), (562, 11), (563, 11), (564, 11), (565, 11), (566, 11), (567, 11), (568, 11), (569, 11), (570, 11), (571, 11), (572, 11), (573, 11), (574, 11), (575, 11), (576, 11), (577, 11), (578, 11), (579, 11), (580, 11), (581, 11), (582, 11), (583, 11), (584, 11), (585, 11), (586, 11), (587, 11), (588, 11), (589, 11), (590, 11), (591, 11), (592, 11), (593, 11), (594, 11), (595, 11), (596, 11), (597, 11), (598, 11), (599, 11), (600, 11), (601, 11), (602, 11), (603, 11), (604, 11), (605, 11), (606, 11), (607, 11), (608, 11), (609, 11), (610, 11), (611, 11), (612, 11), (613, 11), (614, 11), (615, 11), (616, 11), (617, 11), (618, 11), (619, 11), (620, 11), (621, 11), (622, 11), (623, 11), (624, 11), (625, 11), (626, 11), (627, 11), (628, 11), (629, 11), (630, 11), (631, 11), (632, 11), (633, 11), (634, 11), (635, 11), (636, 11), (637, 11), (638, 11), (639, 11), (640, 11), (641, 11), (642, 11), (643, 11), (644, 11), (645, 11), (646, 11), (647, 11), (648, 11), (649, 11), (650, 11), (651, 11), (652, 11), (653, 11), (654, 11), (655, 11), (656, 11), (657, 11), (658, 11), (659, 11), (660, 11), (661, 11), (662, 11), (663, 11), (664, 11), (665, 11), (666, 11), (667, 11), (668, 11), (669, 11), (670, 11), (671, 11), (672, 11), (673, 11), (674, 11), (675, 11), (676, 11), (677, 11), (678, 11), (679, 11), (680, 11), (681, 11), (682, 11), (683, 11), (684, 11), (685, 11), (686, 11), (687, 11), (688, 11), (689, 11), (690, 11), (691, 11), (692, 11), (693, 11), (694, 11), (695, 11), (696, 11), (697, 11), (698, 11), (699, 11), (700, 11), (701, 11), (702, 11), (703, 11), (704, 11), (705, 11), (706, 11), (707, 11), (708, 11), (709, 11), (710, 11), (711, 11), (712, 11), (713, 11), (714, 11), (715, 11), (716, 11), (717, 11), (718, 11), (719, 11), (720, 11), (721, 11), (722, 11), (723, 11), (724, 11), (725, 11), (726, 11), (727, 11), (728, 11), (729, 11), (730, 11), (731, 11), (732, 11), (733, 11), (734, 11), (735, 11), (736, 11), (737, 11), (738, 11), (739, 11), (740, 11), (741, 11), (742, 11), (743, 11), (744, 11), (745, 11), (746, 11), (747, 11), (748, 11), (749, 11), (750, 11), (751, 11), (752, 11), (753, 11), (754, 11), (755, 11), (756, 11), (757, 11), (758, 11), (759, 11), (760, 11), (761, 11), (762, 11), (763, 11), (764, 11), (765, 11), (766, 11), (767, 11), (768, 11), (769, 11), (770, 11), (771, 11), (772, 11), (773, 11), (774, 11), (775, 11), (776, 11), (777, 11), (778, 11), (779, 11), (780, 11), (781, 11), (782, 11), (783, 11), (784, 11), (785, 11), (786, 11), (787, 11), (788, 11), (789, 11), (790, 11), (791, 11), (792, 11), (793, 11), (794, 11), (795, 11), (796, 11), (797, 11), (798, 11), (799, 11), (800, 11), (801, 11), (802, 11), (803, 11), (804, 11), (805, 11), (806, 11), (807, 11), (808, 11), (809, 11), (810, 11), (811, 11), (812, 11), (813, 11), (814, 11), (815, 11), (816, 11), (817, 11), (818, 11), (819, 11), (820, 11), (821, 11), (822, 11), (823, 11), (824, 11), (825, 11), (826, 11), (827, 11), (828, 11), (829, 11), (830, 11), (831, 11), (832, 11), (833, 11), (834, 11), (835, 11), (836, 11), (837, 11), (838, 11), (839, 11), (840, 11), (841, 11), (842, 11), (843, 11), (844, 11), (845, 11), (846, 11), (847, 11), (848, 11), (849, 11), (850, 11), (851, 11), (852, 11), (853, 11), (854, 11), (855, 11), (856, 11), (857, 11), (858, 11), (859, 11), (860, 11), (861, 11), (862, 11), (863, 11), (864, 11), (865, 11), (866, 11), (867, 11), (868, 11), (869, 11), (870, 11), (871, 11), (872, 11), (873, 11), (874, 11), (875, 11), (876, 11), (877, 11), (878, 11), (879, 11), (880, 11), (881, 11), (882, 11), (883, 11), (884, 11), (885, 11), (886, 11), (887, 11), (888, 11), (889, 11), (890, 11), (891, 11), (892, 11), (893, 11), (894, 11), (895, 11), (896, 11), (897, 11), (898, 11), (899, 11), (900, 11), (901, 11), (902, 11), (903, 11), (904, 11), (905, 11), (906, 11), (907, 11), (908, 11), (909, 11), (910, 11), (911, 11), (912, 11), (913, 11), (914, 11), (915, 11), (916, 11), (917, 11), (918, 11), (919, 11), (920, 11), (921, 11), (922, 11), (923, 11), (924, 11), (925, 11), (926, 11), (927, 11), (928, 11), (929, 11), (930, 11), (931, 11), (932, 11), (933, 11), (934, 11), (935, 11), (936, 11), (937, 11), (938, 11), (939, 11), (940, 11), (941, 11), (942, 11), (943, 11), (944, 11), (945, 11), (946, 11), (947, 11), (948, 11), (949, 11), (950, 11), (951, 11), (952, 11), (953, 11), (954, 11), (955, 11), (956, 11), (957, 11), (958, 11), (959, 11), (960, 11), (961, 11), (962, 11), (963, 11), (964, 11), (965, 11), (966, 11), (967, 11), (968, 11), (969, 11), (970, 11), (971, 11), (972, 11), (973, 11), (974, 11), (975, 11), (976, 11), (977, 11), (978, 11), (979, 11), (980, 11), (981, 11), (982, 11), (983, 11), (984, 11), (985, 11), (986, 11), (987, 11), (988, 11), (989, 11), (990, 11), (991, 11), (992, 11), (993, 11), (994, 11), (995, 11), (996, 11), (997, 11), (998, 11), (999, 11), (1000, 11), (1001, 11), (1002, 11), (1003, 11), (1004, 11), (1005, 11), (1006, 11), (1007, 11), (1008, 11), (1009, 11), (1010, 11), (1011, 11), (1012, 11), (1013, 11), (1014, 11), (1015, 11), (1016, 11), (1017, 11), (1018, 11), (1019, 11), (1020, 11), (1021, 11), (1022, 11), (1023, 11), (1024, 11), (1025, 11), (1026, 11), (1027, 11), (1028, 11), (1029, 11), (1030, 11), (1031, 11), (1032, 11), (1033, 11), (1034, 11), (1035, 11), (1036, 11), (1037, 11), (1038, 11), (1039, 11), (1040, 11), (1041, 11), (1042, 11), (1043, 11), (1044, 11), (1045, 11), (1046, 11), (1047, 11), (1048, 11), (1049, 11), (1050, 11), (1051, 11), (1052, 11), (1053, 11), (1054, 11), (1055, 11), (1056, 11), (1057, 11), (1058, 11), (1059, 11), (1060, 11), (1061, 11), (1062, 11), (1063, 11), (1064, 11), (1065, 11), (1066, 11), (1067, 11), (1068, 11), (1069, 11), (1070, 11), (1071, 11), (1072, 11), (1073, 11), (1074, 11), (1075, 11), (1076, 11), (1077, 11), (1078, 11), (1079, 11), (1080, 11), (1081, 11), (1082, 11), (1083, 11), (1084, 11), (1085, 11), (1086, 11), (1087, 11), (1088, 11), (1089, 11), (1090, 11), (1091, 11), (1092, 11), (1093, 11), (1094, 11), (1095, 11), (1096, 11), (1097, 11), (1098, 11), (1099, 11), (1100, 11), (1101, 11), (1102, 11), (1103, 11), (1104, 11), (1105, 11), (1106, 11), (1107, 11), (1108, 11), (1109, 11), (1110, 11), (1111, 11), (1112, 11), (1113, 11), (1114, 11), (1115, 11), (1116, 11), (1117, 11), (1118, 11), (1119, 11), (1120, 11), (1121, 11), (1122, 11), (1123, 11), (1124, 11), (1125, 11), (1126, 11), (1127, 11), (1128, 11), (1129, 11), (1130, 11), (1131, 11), (1132, 11), (1133, 11), (1134, 11), (1135, 11), (1136, 11), (1137, 11), (1138, 11), (1139, 11), (1140, 11), (1141, 11), (1142, 11), (1143, 11), (1144, 11), (1145, 11), (1146, 11), (1147, 11), (1148, 11), (1149, 11), (1150, 11), (1151, 11), (1152, 11), (1153, 11), (1154, 11), (1155, 11), (1156, 11), (1157, 11), (1158, 11), (1159, 11), (1160, 11), (1161, 11), (1162, 11), (1163, 11), (1164, 11), (1165, 11), (1166, 11), (1167, 11), (1168, 11), (1169, 11), (1170, 11), (1171, 11), (1172, 11), (1173, 11), (1174, 11), (1175, 11), (1176, 11), (1177, 11), (1178, 11), (1179, 11), (1180, 11), (1181, 11), (1182, 11), (1183, 11), (1184, 11), (1185, 11), (1186, 11), (1187, 11), (1188, 11), (1189, 11), (1190, 11), (1191, 11), (1192, 11), (1193, 11), (1194, 11), (1195, 11), (1196, 11), (1197, 11), (1198, 11), (1199, 11), (1200, 11), (1201, 11), (1202, 11), (1203, 11), (1204, 11), (1205, 11), (1206, 11), (1207, 11), (1208, 11), (1209, 11), (1210, 11), (1211, 11), (1212, 11), (1213, 11), (1214, 11), (1215, 11), (1216, 11), (1217, 11), (1218, 11), (1219, 11), (1220, 11), (1221, 11), (1222, 11), (1223, 11), (1224, 11), (1225, 11), (1226, 11), (1227, 11), (1228, 11), (1229, 11), (1230, 11), (1231, 11), (1232, 11), (1233, 11), (1234, 11), (1235, 11), (1236, 11), (1237, 11), (1238, 11), (1239, 11), (1240, 11), (1241, 11), (1242, 11), (1243, 11), (1244, 11), (1245, 11), (1246, 11), (1247, 11), (1248, 11), (1249, 11), (1250, 11), (1251, 11), (1252, 11), (1253, 11), (1254, 11), (1255, 11), (1256, 11), (1257, 11), (1258, 11), (1259, 11), (1260, 11), (1261, 11), (1262, 11), (1263, 11), (1264, 11), (1265, 11), (1266, 11), (1267, 11), (1268, 11), (1269, 11), (1270, 11), (1271, 11), (1272, 11), (1273, 11), (1274, 11), (1275, 11), (1276, 11), (1277, 11), (1278, 11), (1279, 11), (1280, 11), (1281, 11), (1282, 11), (1283, 11), (1284, 11), (1285, 11), (1286, 11), (1287, 11), (1288, 11), (1289, 11), (1290, 11), (1291, 11), (1292, 11), (1293, 11), (1294, 11), (1295, 11), (1296, 11), (1297, 11), (1298, 11), (1299, 11), (1300, 11), (1301, 11), (1302, 11), (1303, 11), (1304, 11), (1305, 11), (1306, 11), (1307, 11), (1308, 11), (1309, 11), (1310, 11), (1311, 11), (1312, 11), (1313, 11), (1314, 11), (1315, 11), (1316, 11), (1317, 11), (1318, 11), (1319, 11), (1320, 11), (1321, 11), (1322, 11), (1323, 11), (1324, 11), (1325, 11), (1326, 11), (1327, 11), (1328, 11), (1329, 11), (1330, 11), (1331, 11), (1332, 11), (1333, 11), (1334, 11), (1335, 11), (1336, 11), (1337, 11), (1338, 11), (1339, 11), (1340, 11), (1341, 11), (1342, 11), (1343, 11), (1344, 11), (1345, 11), (1346, 11), (1347, 11), (1348, 11), (1349, 11), (1350, 11), (1351, 11), (1352, 11), (1353, 11), (1354, 11), (1355, 11), (1356, 11), (1357, 11), (1358, 11), (1359, 11), (1360, 11), (1361, 11), (1362, 11), (1363, 11), (1364, 11), (1365, 11), (1366, 11), (1367, 11), (1368, 11), (1369, 11), (1370, 11), (1371, 11), (1372, 11), (1373, 11), (1374, 11), (1375, 11), (1376, 11), (1377, 11), (1378, 11), (1379, 11), (1380, 11), (1381, 11), (1382, 11), (1383, 11), (1384, 11), (1385, 11), (1386, 11), (1387, 11), (1388, 11), (1389, 11), (1390, 11), (1391, 11), (1392, 11), (1393, 11), (1394, 11), (1395, 11), (1396, 11), (1397, 11), (1398, 11), (1399, 11), (1400, 11), (1401, 11), (1402, 11), (1403, 11), (1404, 11), (1405, 11), (1406, 11), (1407, 11), (1408, 11), (1409, 11), (1410, 11), (1411, 11), (1412, 11), (1413, 11), (1414, 11), (1415, 11), (1416, 11), (1417, 11), (1418, 11), (1419, 11), (1420, 11), (1421, 11), (1422, 11), (1423, 11), (1424, 11), (1425, 11), (1426, 11), (1427, 11), (1428, 11), (1429, 11), (1430, 11), (1431, 11), (1432, 11), (1433, 11), (1434, 11), (1435, 11), (1436, 11), (1437, 11), (1438, 11), (1439, 11), (1440, 11), (1441, 11), (1442, 11), (1443, 11), (1444, 11), (1445, 11), (1446, 11), (1447, 11), (1448, 11), (1449, 11), (1450, 11), (1451, 11), (1452, 11), (1453, 11), (1454, 11), (1455, 11), (1456, 11), (1457, 11), (1458, 11), (1459, 11), (1460, 11), (1461, 11), (1462, 11), (1463, 11), (1464, 11), (1465, 11), (1466, 11), (1467, 11), (1468, 11), (1469, 11), (1470, 11), (1471, 11), (1472, 11), (1473, 11), (1474, 11), (1475, 11), (1476, 11), (1477, 11), (1478, 11), (1479, 11), (1480, 11), (1481, 11), (1482, 11), (1483, 11), (1484, 11), (1485, 11), (1486, 11), (1487, 11), (1488, 11), (1489, 11), (1490, 11), (1491, 11), (1492, 11), (1493, 11), (1494, 11), (1495, 11), (1496, 11), (1497, 11), (1498, 11), (1499, 11), (1500, 11), (1501, 11), (1502, 11), (1503, 11), (1504, 11), (1505, 11), (1506, 11), (1507, 11), (1508, 11), (1509, 11), (1510, 11), (1511, 11), (1512, 11), (1513, 11), (1514, 11), (1515, 11), (1516, 11), (1517, 11), (1518, 11), (1519, 11), (1520, 11), (1521, 11), (1522, 11), (1523, 11), (1524, 11), (1525, 11), (1526, 11), (1527, 11), (1528, 11), (1529, 11), (1530, 11), (1531, 11), (1532, 11), (1533, 11), (1534, 11), (1535, 11), (1536, 11), (1537, 11), (1538, 11), (1539, 11), (1540, 11), (1541, 11), (1542, 11), (1543, 11), (1544, 11), (1545, 11), (1546, 11), (1547, 11), (1548, 11), (1549, 11), (1550, 11), (1551, 11), (1552, 11), (1553, 11), (1554, 11), (1555, 11), (1556, 11), (1557, 11), (1558, 11), (1559, 11), (1560, 11), (1561, 11), (1562, 11), (1563, 11), (1564, 11), (1565, 11), (1566, 11), (1567, 11), (1568, 11), (1569, 11), (1570, 11), (1571, 11), (1572, 11), (1573, 11), (1574, 11), (1575, 11), (1576, 11), (1577, 11), (1578, 11), (1579, 11), (1580, 11), (1581, 11), (1582, 11), (1583, 11), (1584, 11), (1585, 11), (1586, 11), (1587, 11), (1588, 11), (1589, 11), (1590, 11), (1591, 11), (1592, 11), (1593, 11), (1594, 11), (1595, 11), (1596, 11), (1597, 11), (1598, 11), (1599, 11), (1600, 11), (1601, 11), (1602, 11), (1603, 11), (1604, 11), (1605, 11), (1606, 11), (1607, 11), (1608, 11), (1609, 11), (1610, 11), (1611, 11), (1612, 11), (1613, 11), (1614, 11), (1615, 11), (1616, 11), (1617, 11), (1618, 11), (1619, 11), (1620, 11), (1621, 11), (1622, 11), (1623, 11), (1624, 11), (1625, 11), (1626, 11), (1627, 11), (1628, 11), (1629, 11), (1630, 11), (1631, 11), (1632, 11), (1633, 11), (1634, 11), (1635, 11), (1636, 11), (1637, 11), (1638, 11), (1639, 11), (1640, 11), (1641, 11), (1642, 11), (1643, 11), (1644, 11), (1645, 11), (1646, 11), (1647, 11), (1648, 11), (1649, 11), (1650, 11), (1651, 11), (1652, 11), (1653, 11), (1654, 11), (1655, 11), (1656, 11), (1657, 11), (1658, 11), (1659, 11), (1660, 11), (1661, 11), (1662, 11), (1663, 11), (1664, 11), (1665, 11), (1666, 11), (1667, 11), (1668, 11), (1669, 11), (1670, 11), (1671, 11), (1672, 11), (1673, 11), (1674, 11), (1675, 11), (1676, 11), (1677, 11), (1678, 11), (1679, 11), (1680, 11), (1681, 11), (1682, 11), (1683, 11), (1684, 11), (1685, 11), (1686, 11), (1687, 11), (1688, 11), (1689, 11), (1690, 11), (1691, 11), (1692, 11), (1693, 11), (1694, 11), (1695, 11), (1696, 11), (1697, 11), (1698, 11), (1699, 11), (1700, 11), (1701, 11), (1702, 11), (1703, 11), (1704, 11), (1705, 11), (1706, 11), (1707, 11), (1708, 11), (1709, 11), (1710, 11), (1711, 11), (1712, 11), (1713, 11), (1714, 11), (1715, 11), (1716, 11), (1717, 11), (1718, 11), (1719, 11), (1720, 11), (1721, 11), (1722, 11), (1723, 11), (1724, 11), (1725, 11), (1726, 11), (1727, 11), (1728, 11), (1729, 11), (1730, 11), (1731, 11), (1732, 11), (1733, 11), (1734, 11), (1735, 11), (1736, 11), (1737, 11), (1738, 11), (1739, 11), (1740, 11), (1741, 11), (1742, 11), (1743, 11), (1744, 11), (1745, 11), (1746, 11), (1747, 11), (1748, 11), (1749, 11), (1750, 11), (1751, 11), (1752, 11), (1753, 11), (1754, 11), (1755, 11), (1756, 11), (1757, 11), (1758, 11), (1759, 11), (1760, 11), (1761, 11), (1762, 11), (1763, 11), (1764, 11), (1765, 11), (1766, 11), (1767, 11), (1768, 11), (1769, 11), (1770, 11), (1771, 11), (1772, 11), (1773, 11), (1774, 11), (1775, 11), (1776, 11), (1777, 11), (1778, 11), (1779, 11), (1780, 11), (1781, 11), (1782, 11), (1783, 11), (1784, 11), (1785, 11), (1786, 11), (1787, 11), (1788, 11), (1789, 11), (1790, 11), (1791, 11), (1792, 11), (1793, 11), (1794, 11), (1795, 11), (1796, 11), (1797, 11), (1798, 11), (1799, 11), (1800, 11), (1801, 11), (1802, 11), (1803, 11), (1804, 11), (1805, 11), (1806, 11), (1807, 11), (1808, 11), (1809, 11), (1810, 11), (1811, 11), (1812, 11), (1813, 11), (1814, 11), (1815, 11), (1816, 11), (1817, 11), (1818, 11), (1819, 11), (1820, 11), (1821, 11), (1822, 11), (1823, 11), (1824, 11), (1825, 11), (1826, 11), (1827, 11), (1828, 11), (1829, 11), (1830, 11), (1831, 11), (1832, 11), (1833, 11), (1834, 11), (1835, 11), (1836, 11), (1837, 11), (1838, 11), (1839, 11), (1840, 11), (1841, 11), (1842, 11), (1843, 11), (1844, 11), (1845, 11), (1846, 11), (1847, 11), (1848, 11), (1849, 11), (1850, 11), (1851, 11), (1852, 11), (1853, 11), (1854, 11), (1855, 11), (1856, 11), (1857, 11), (1858, 11), (1859, 11), (1860, 11), (1861, 11), (1862, 11), (1863, 11), (1864, 11), (1865, 11), (1866, 11), (1867, 11), (1868, 11), (1869, 11), (1870, 11), (1871, 11), (1872, 11), (1873, 11), (1874, 11), (1875, 11), (1876, 11), (1877, 11), (1878, 11), (1879, 11), (1880, 11), (1881, 11), (1882, 11), (1883, 11), (1884, 11), (1885, 11), (1886, 11), (1887, 11), (1888, 11), (1889, 11), (1890, 11), (1891, 11), (1892, 11), (1893, 11), (1894, 11), (1895, 11), (1896, 11), (1897, 11), (1898, 11), (1899, 11), (1900, 11), (1901, 11), (1902, 11), (1903, 11), (1904, 11), (1905, 11), (1906, 11), (1907, 11), (1908, 11), (1909, 11), (1910, 11), (1911, 11), (1912, 11), (1913, 11), (1914, 11), (1915, 11), (1916, 11), (1917, 11), (1918, 11), (1919, 11), (1920, 11), (1921, 11), (1922, 11), (1923, 11), (1924, 11), (1925, 11), (1926, 11), (1927, 11), (1928, 11), (1929, 11), (1930, 11), (1931, 11), (1932, 11), (1933, 11), (1934, 11), (1935, 11), (1936, 11), (1937, 11), (1938, 11), (1939, 11), (1940, 11), (1941, 11), (1942, 11), (1943, 11), (1944, 11), (1945, 11), (1946, 11), (1947, 11), (1948, 11), (1949, 11), (1950, 11), (1951, 11), (1952, 11), (1953, 11), (1954, 11), (1955, 11), (1956, 11), (1957, 11), (1958, 11), (1959, 11), (1960, 11), (1961, 11), (1962, 11), (1963, 11), (1964, 11), (1965, 11), (1966, 11), (1967, 11), (1968, 11), (1969, 11), (1970, 11), (1971, 11), (1972, 11), (1973, 11), (1974, 11), (1975, 11), (1976, 11), (1977, 11), (1978, 11), (1979, 11), (1980, 11), (1981, 11), (1982, 11), (1983, 11), (1984, 11), (1985, 11), (1986, 11), (1987, 11), (1988, 11), (1989, 11), (1990, 11), (1991, 11), (1992, 11), (1993, 11), (1994, 11), (1995, 11), (1996, 11), (1997, 11), (1998, 11), (1999, 11), (2000, 11), (2001, 11), (2002, 11), (2003, 11), (2004, 11), (2005, 11), (2006, 11), (2007, 11), (2008, 11), (2009, 11), (2010, 11), (2011, 11), (2012, 11), (2013, 11), (2014, 11), (2015, 11), (2016, 11), (2017, 11), (2018, 11), (2019, 11), (2020, 11), (2021, 11), (2022, 11), (2023, 11), (2024, 11), (2025, 11), (2026, 11), (2027, 11), (2028, 11), (2029, 11), (2030, 11), (2031, 11), (2032, 11), (2033, 11), (2034, 11), (2035, 11), (2036, 11), (2037, 11), (2038, 11), (2039, 11), (2040, 11), (2041, 11), (2042, 11), (2043, 11), (2044, 11), (2045, 11), (2046, 11), (2047, 11), (0, 11), (1, 11), (2, 11), (3, 11), (4, 11), (5, 11), (6, 11), (7, 11), (8, 11), (9, 11), (10, 11), (11, 11), (12, 11), (13, 11), (14, 11), (15, 11), (16, 11), (17, 11), (18, 11), (19, 11), (20, 11), (21, 11), (22, 11), (23, 11), (24, 11), (25, 11), (26, 11), (27, 11), (28, 11), (29, 11), (30, 11), (31, 11), (32, 11), (33, 11), (34, 11), (35, 11), (36, 11), (37, 11), (38, 11), (39, 11), (40, 11), (41, 11), (42, 11), (43, 11), (44, 11), (45, 11), (46, 11), (47, 11), (48, 11), (49, 11), (50, 11), (51, 11), (52, 11), (53, 11), (54, 11), (55, 11), (56, 11), (57, 11), (58, 11), (59, 11), (60, 11), (61, 11), (62, 11), (63, 11), (64, 11), (65, 11), (66, 11), (67, 11), (68, 11), (69, 11), (70, 11), (71, 11), (72, 11), (73, 11), (74, 11), (75, 11), (76, 11), (77, 11), (78, 11), (79, 11), (80, 11), (81, 11), (82, 11), (83, 11), (84, 11), (85, 11), (86, 11), (87, 11), (88, 11), (89, 11), (90, 11), (91, 11), (92, 11), (93, 11), (94, 11), (95, 11), (96, 11), (97, 11), (98, 11), (99, 11), (100, 11), (101, 11), (102, 11), (103, 11), (104, 11), (105, 11), (106, 11), (107, 11), (108, 11), (109, 11), (110, 11), (111, 11), (112, 11), (113, 11), (114, 11), (115, 11), (116, 11), (117, 11), (118, 11), (119, 11), (120, 11), (121, 11), (122, 11), (123, 11), (124, 11), (125, 11), (126, 11), (127, 11), (128, 11), (129, 11), (130, 11), (131, 11), (132, 11), (133, 11), (134, 11), (135, 11), (136, 11), (137, 11), (138, 11), (139, 11), (140, 11), (141, 11), (142, 11), (143, 11), (144, 11), (145, 11), (146, 11), (147, 11), (148, 11), (149, 11), (150, 11), (151, 11), (152, 11), (153, 11), (154, 11), (155, 11), (156, 11), (157, 11), (158, 11), (159, 11), (160, 11), (161, 11), (162, 11), (163, 11), (164, 11), (165, 11), (166, 11), (167, 11), (168, 11), (169, 11), (170, 11), (171, 11), (172, 11), (173, 11), (174, 11), (175, 11), (176, 11), (177, 11), (178, 11), (179, 11), (180, 11), (181, 11), (182, 11), (183, 11), (184, 11), (185, 11), (186, 11), (187, 11), (188, 11), (189, 11), (190, 11), (191, 11), (192, 11), (193, 11), (194, 11), (195, 11), (196, 11), (197, 11), (198, 11), (199, 11), (200, 11), (201, 11), (202, 11), (203, 11), (204, 11), (205, 11), (206, 11), (207, 11), (208, 11), (209, 11), (210, 11), (211, 11), (212, 11), (213, 11), (214, 11), (215, 11), (216, 11), (217, 11), (218, 11), (219, 11), (220, 11), (221, 11), (222, 11), (223, 11), (224, 11), (225, 11), (226, 11), (227, 11), (228, 11), (229, 11), (230, 11), (231, 11), (232, 11), (233, 11), (234, 11), (235, 11), (236, 11), (237, 11), (238, 11), (239, 11), (240, 11), (241, 11), (242, 11), (243, 11), (244, 11), (245, 11), (246, 11), (247, 11), (248, 11), (249, 11), (250, 11), (251, 11), (252, 11), (253, 11), (254, 11), (255, 11), (256, 11), (257, 11), (258, 11), (259, 11), (260, 11), (261, 11), (262, 11), (263, 11), (264, 11), (265, 11), (266, 11), (267, 11), (268, 11), (269, 11), (270, 11), (271, 11), (272, 11), (273, 11), (274, 11), (275, 11), (276, 11), (277, 11), (278, 11), (279, 11), (280, 11), (281, 11), (282, 11), (283, 11), (284, 11), (285, 11), (286, 11), (287, 11), (288, 11), (289, 11), (290, 11), (291, 11), (292, 11), (293, 11), (294, 11), (295, 11), (296, 11), (297, 11), (298, 11), (299, 11), (300, 11), (301, 11), (302, 11), (303, 11), (304, 11), (305, 11), (306, 11), (307, 11), (308, 11), (309, 11), (310, 11), (311, 11), (312, 11), (313, 11), (314, 11), (315, 11), (316, 11), (317, 11), (318, 11), (319, 11), (320, 11), (321, 11), (322, 11), (323, 11), (324, 11), (325, 11), (326, 11), (327, 11), (328, 11), (329, 11), (330, 11), (331, 11), (332, 11), (333, 11), (334, 11), (335, 11), (336, 11), (337, 11), (338, 11), (339, 11), (340, 11), (341, 11), (342, 11), (343, 11), (344, 11), (345, 11), (346, 11), (347, 11), (348, 11), (349, 11), (350, 11), (351, 11), (352, 11), (353, 11), (354, 11), (355, 11), (356, 11), (357, 11), (358, 11), (359, 11), (360, 11), (361, 11), (362, 11), (363, 11), (364, 11), (365, 11), (366, 11), (367, 11), (368, 11), (369, 11), (370, 11), (371, 11), (372, 11), (373, 11), (374, 11), (375, 11), (376, 11), (377, 11), (378, 11), (379, 11), (380, 11), (381, 11), (382, 11), (383, 11), (384, 11), (385, 11), (386, 11), (387, 11), (388, 11), (389, 11), (390, 11), (391, 11), (392, 11), (393, 11), (394, 11), (395, 11), (396, 11), (397, 11), (398, 11), (399, 11), (400, 11), (401, 11), (402, 11), (403, 11), (404, 11), (405, 11), (406, 11), (407, 11), (408, 11), (409, 11), (410, 11), (411, 11), (412, 11), (413, 11), (414, 11), (415, 11), (416, 11), (417, 11), (418, 11), (419, 11), (420, 11), (421, 11), (422, 11), (423, 11), (424, 11), (425, 11), (426, 11), (427, 11), (428, 11), (429, 11), (430, 11), (431, 11), (432, 11), (433, 11), (434, 11), (435, 11), (436, 11), (437, 11), (438, 11), (439, 11), (440, 11), (441, 11), (442, 11), (443, 11), (444, 11), (445, 11), (446, 11), (447, 11), (448, 11), (449, 11), (450, 11), (451, 11), (452, 11), (453, 11), (454, 11), (455, 11), (456, 11), (457, 11), (458, 11), (459, 11), (460, 11), (461, 11), (462, 11), (463, 11), (464, 11), (465, 11), (466, 11), (467, 11), (468, 11), (469, 11), (470, 11), (471, 11), (472, 11), (473, 11), (474, 11), (475, 11), (476, 11), (477, 11), (478, 11), (479, 11), (480, 11), (481, 11), (482, 11), (483, 11), (484, 11), (485, 11), (486, 11), (487, 11), (488, 11), (489, 11), (490, 11), (491, 11), (492, 11), (493, 11), (494, 11), (495, 11), (496, 11), (497, 11), (498, 11), (499, 11), (500, 11), (501, 11), (502, 11), (503, 11), (504, 11), (505, 11), (506, 11), (507, 11), (508, 11), (509, 11), (510, 11), (511, 11), (512, 11), (513, 11), (514, 11), (515, 11), (516, 11), (517, 11), (518, 11), (519, 11), (520, 11), (521, 11), (522, 11), (523, 11), (524, 11), (525, 11), (526, 11), (527, 11), (528, 11), (529, 11), (530, 11), (531, 11), (532, 11), (533, 11), (534, 11), (535, 11), (536, 11), (537, 11), (538, 11), (539, 11), (540, 11), (541, 11), (542, 11), (543, 11), (544, 11), (545, 11), (546, 11), (547, 11), (548, 11), (549, 11), (550, 11), (551, 11), (552, 11), (553, 11), (554, 11), (555, 11), (556, 11), (557, 11), (558, 11), (559, 11), (560, 11), (561, 11), (562, 11), (563, 11), (564, 11), (565, 11), (566, 11), (567, 11), (568, 11), (569, 11), (570, 11), (571, 11), (572, 11), (573, 11), (574, 11), (575, 11), (576, 11), (577, 11), (578, 11), (579, 11), (580, 11), (581, 11), (582, 11), (583, 11), (584, 11), (585, 11), (586, 11), (587, 11), (588, 11), (589, 11), (590, 11), (591, 11), (592, 11), (593, 11), (594, 11), (595, 11), (596, 11), (597, 11), (598, 11), (599, 11), (600, 11), (601, 11), (602, 11), (603, 11), (604, 11), (605, 11), (606, 11), (607, 11), (608, 11), (609, 11), (610, 11), (611, 11), (612, 11), (613, 11), (614, 11), (615, 11), (616, 11), (617, 11), (618, 11), (619, 11), (620, 11), (621, 11), (622, 11), (623, 11), (624, 11), (625, 11), (626, 11), (627, 11), (628, 11), (629, 11), (630, 11), (631, 11), (632, 11), (633, 11), (634, 11), (635, 11), (636, 11), (637, 11), (638, 11), (639, 11), (640, 11), (641, 11), (642, 11), (643, 11), (644, 11), (645, 11), (646, 11), (647, 11), (648, 11), (649, 11), (650, 11), (651, 11), (652, 11), (653, 11), (654, 11), (655, 11), (656, 11), (657, 11), (658, 11), (659, 11), (660, 11), (661, 11), (662, 11), (663, 11), (664, 11), (665, 11), (666, 11), (667, 11), (668, 11), (669, 11), (670, 11), (671, 11), (672, 11), (673, 11), (674, 11), (675, 11), (676, 11), (677, 11), (678, 11), (679, 11), (680, 11), (681, 11), (682, 11), (683, 11), (684, 11), (685, 11), (686, 11), (687, 11), (688, 11), (689, 11), (690, 11), (691, 11), (692, 11), (693, 11), (694, 11), (695, 11), (696, 11), (697, 11), (698, 11), (699, 11), (700, 11), (701, 11), (702, 11), (703, 11), (704, 11), (705, 11), (706, 11), (707, 11), (708, 11), (709, 11), (710, 11), (711, 11), (712, 11), (713, 11), (714, 11), (715, 11), (716, 11), (717, 11), (718, 11), (719, 11), (720, 11), (721, 11), (722, 11), (723, 11), (724, 11), (725, 11), (726, 11), (727, 11), (728, 11), (729, 11), (730, 11), (731, 11), (732, 11), (733, 11), (734, 11), (735, 11), (736, 11), (737, 11), (738, 11), (739, 11), (740, 11), (741, 11), (742, 11), (743, 11), (744, 11), (745, 11), (746, 11), (747, 11), (748, 11), (749, 11), (750, 11), (751, 11), (752, 11), (753, 11), (754, 11), (755, 11), (756, 11), (757, 11), (758, 11), (759, 11), (760, 11), (761, 11), (762, 11), (763, 11), (764, 11), (765, 11), (766, 11), (767, 11), (768, 11), (769, 11), (770, 11), (771, 11), (772, 11), (773, 11), (774, 11), (775, 11), (776, 11), (777, 11), (778, 11), (779, 11), (780, 11), (781, 11), (782, 11), (783, 11), (784, 11), (785, 11), (786, 11), (787, 11), (788, 11), (789, 11), (790, 11), (791, 11), (792, 11), (793, 11), (794, 11), (795, 11), (796, 11), (797, 11), (798, 11), (799, 11), (800, 11), (801, 11), (802, 11), (803, 11), (804, 11), (805, 11), (806, 11), (807, 11), (808, 11), (809, 11), (810, 11), (811, 11), (812, 11), (813, 11), (814, 11), (815, 11), (816, 11), (817, 11), (818, 11), (819, 11), (820, 11), (821, 11), (822, 11), (823, 11), (824, 11), (825, 11), (826, 11), (827, 11), (828, 11), (829, 11), (830, 11), (831, 11), (832, 11), (833, 11), (834, 11), (835, 11), (836, 11), (837, 11), (838, 11), (839, 11), (840, 11), (841, 11), (842, 11), (843, 11), (844, 11), (845, 11), (846, 11), (847, 11), (848, 11), (849, 11), (850, 11), (851, 11), (852, 11), (853, 11), (854, 11), (855, 11), (856, 11), (857, 11), (858, 11), (859, 11), (860, 11), (861, 11), (862, 11), (863, 11), (864, 11), (865, 11), (866, 11), (867, 11), (868, 11), (869, 11), (870, 11), (871, 11), (872, 11), (873, 11), (874, 11), (875, 11), (876, 11), (877, 11), (878, 11), (879, 11), (880, 11), (881, 11), (882, 11), (883, 11), (884, 11), (885, 11), (886, 11), (887, 11), (888, 11), (889, 11), (890, 11), (891, 11), (892, 11), (893, 11), (894, 11), (895, 11), (896, 11), (897, 11), (898, 11), (899, 11), (900, 11), (901, 11), (902, 11), (903, 11), (904, 11), (905, 11), (906, 11), (907, 11), (908, 11), (909, 11), (910, 11), (911, 11), (912, 11), (913, 11), (914, 11), (915, 11), (916, 11), (917, 11), (918, 11), (919, 11), (920, 11), (921, 11), (922, 11), (923, 11), (924, 11), (925, 11), (926, 11), (927, 11), (928, 11), (929, 11), (930, 11), (931, 11), (932, 11), (933, 11), (934, 11), (935, 11), (936, 11), (937, 11), (938, 11), (939, 11), (940, 11), (941, 11), (942, 11), (943, 11), (944, 11), (945, 11), (946, 11), (947, 11), (948, 11), (949, 11), (950, 11), (951, 11), (952, 11), (953, 11), (954, 11), (955, 11), (956, 11), (957, 11), (958, 11), (959, 11), (960, 11), (961, 11), (962, 11), (963, 11), (964, 11), (965, 11), (966, 11), (967, 11), (968, 11), (969, 11), (970, 11), (971, 11), (972, 11), (973, 11), (974, 11), (975, 11), (976, 11), (977, 11), (978, 11), (979, 11), (980, 11), (981, 11), (982, 11), (983, 11), (984, 11), (985, 11), (986, 11), (987, 11), (988, 11), (989, 11), (990, 11), (991, 11), (992, 11), (993, 11), (994, 11), (995, 11), (996, 11), (997, 11), (998, 11), (999, 11), (1000, 11), (1001, 11), (1002, 11), (1003, 11), (1004, 11), (1005, 11), (1006, 11), (1007, 11), (1008, 11), (1009, 11), (1010, 11), (1011, 11), (1012, 11), (1013, 11), (1014, 11), (1015, 11), (1016, 11), (1017, 11), (1018, 11), (1019, 11), (1020, 11), (1021, 11), (1022, 11), (1023, 11), (1024, 11), (1025, 11), (1026, 11), (1027, 11), (1028, 11), (1029, 11), (1030, 11), (1031, 11), (1032, 11), (1033, 11), (1034, 11), (1035, 11), (1036, 11), (1037, 11), (1038, 11), (1039, 11), (1040, 11), (1041, 11), (1042, 11), (1043, 11), (1044, 11), (1045, 11), (1046, 11), (1047, 11), (1048, 11), (1049, 11), (1050, 11), (1051, 11), (1052, 11), (1053, 11), (1054, 11), (1055, 11), (1056, 11), (1057, 11), (1058, 11), (1059, 11), (1060, 11), (1061, 11), (1062, 11), (1063, 11), (1064, 11), (1065, 11), (1066, 11), (1067, 11), (1068, 11), (1069, 11), (1070, 11), (1071, 11), (1072, 11), (1073, 11), (1074, 11), (1075, 11), (1076, 11), (1077, 11), (1078, 11), (1079, 11), (1080, 11), (1081, 11), (1082, 11), (1083, 11), (1084, 11), (1085, 11), (1086, 11), (1087, 11), (1088, 11), (1089, 11), (1090, 11), (1091, 11), (1092, 11), (1093, 11), (1094, 11), (1095, 11), (1096, 11), (1097, 11), (1098, 11), (1099, 11), (1100, 11), (1101, 11), (1102, 11), (1103, 11), (1104, 11), (1105, 11), (1106, 11), (1107, 11), (1108, 11), (1109, 11), (1110, 11), (1111, 11), (1112, 11), (1113, 11), (1114, 11), (1115, 11), (1116, 11), (1117, 11), (1118, 11), (1119, 11), (1120, 11), (1121, 11), (1122, 11), (1123, 11), (1124, 11), (1125, 11), (1126, 11), (1127, 11), (1128, 11), (1129, 11), (1130, 11), (1131, 11), (1132, 11), (1133, 11), (1134, 11), (1135, 11), (1136, 11), (1137, 11), (1138, 11), (1139, 11), (1140, 11), (1141, 11), (1142, 11), (1143, 11), (1144, 11), (1145, 11), (1146, 11), (1147, 11), (1148, 11), (1149, 11), (1150, 11), (1151, 11), (1152, 11), (1153, 11), (1154, 11), (1155, 11), (1156, 11), (1157, 11), (1158, 11), (1159, 11), (1160, 11), (1161, 11), (1162, 11), (1163, 11), (1164, 11), (1165, 11), (1166, 11), (1167, 11), (1168, 11), (1169, 11), (1170, 11), (1171, 11), (1172, 11), (1173, 11), (1174, 11), (1175, 11), (1176, 11), (1177, 11), (1178, 11), (1179, 11), (1180, 11), (1181, 11), (1182, 11), (1183, 11), (1184, 11), (1185, 11), (1186, 11), (1187, 11), (1188, 11), (1189, 11), (1190, 11), (1191, 11), (1192, 11), (1193, 11), (1194, 11), (1195, 11), (1196, 11), (1197, 11), (1198, 11), (1199, 11), (1200, 11), (1201, 11), (1202, 11), (1203, 11), (1204, 11), (1205, 11), (1206, 11), (1207, 11), (1208, 11), (1209, 11), (1210, 11), (1211, 11), (1212, 11), (1213, 11), (1214, 11), (1215, 11), (1216, 11), (1217, 11), (1218, 11), (1219, 11), (1220, 11), (1221, 11), (1222, 11), (1223, 11), (1224, 11), (1225, 11), (1226, 11), (1227, 11), (1228, 11), (1229, 11), (1230, 11), (1231, 11), (1232, 11), (1233, 11), (1234, 11), (1235, 11), (1236, 11), (1237, 11), (1238, 11), (1239, 11), (1240, 11), (1241, 11), (1242, 11), (1243, 11), (1244, 11), (1245, 11), (1246, 11), (1247, 11), (1248, 11), (1249, 11), (1250, 11), (1251, 11), (1252, 11), (1253, 11), (1254, 11), (1255, 11), (1256, 11), (1257, 11), (1258, 11), (1259, 11), (1260, 11), (1261, 11), (1262, 11), (1263, 11), (1264, 11), (1265, 11), (1266, 11), (1267, 11), (1268, 11), (1269, 11), (1270, 11), (1271, 11), (1272, 11), (1273, 11), (1274, 11), (1275, 11), (1276, 11), (1277, 11), (1278, 11), (1279, 11), (1280, 11), (1281, 11), (1282, 11), (1283, 11), (1284, 11), (1285, 11), (1286, 11), (1287, 11), (1288, 11), (1289, 11), (1290, 11), (1291, 11), (1292, 11), (1293, 11), (1294, 11), (1295, 11), (1296, 11), (1297, 11), (1298, 11), (1299, 11), (1300, 11), (1301, 11), (1302, 11), (1303, 11), (1304, 11), (1305, 11), (1306, 11), (1307, 11), (1308, 11), (1309, 11), (1310, 11), (1311, 11), (1312, 11), (1313, 11), (1314, 11), (1315, 11), (1316, 11), (1317, 11), (1318, 11), (1319, 11), (1320, 11), (1321, 11), (1322, 11), (1323, 11), (1324, 11), (1325, 11), (1326, 11), (1327, 11), (1328, 11), (1329, 11), (1330, 11), (1331, 11), (1332, 11), (1333, 11), (1334, 11), (1335, 11), (1336, 11), (1337, 11), (1338, 11), (1339, 11), (1340, 11), (1341, 11), (1342, 11), (1343, 11), (1344, 11), (1345, 11), (1346, 11), (1347, 11), (1348, 11), (1349, 11), (1350, 11), (1351, 11), (1352, 11), (1353, 11), (1354, 11), (1355, 11), (1356, 11), (1357, 11), (1358, 11), (1359, 11), (1360, 11), (1361, 11), (1362, 11), (1363, 11), (1364, 11), (1365, 11), (1366, 11), (1367, 11), (1368, 11), (1369, 11), (1370, 11), (1371, 11), (1372, 11), (1373, 11), (1374, 11), (1375, 11), (1376, 11), (1377, 11), (1378, 11), (1379, 11), (1380, 11), (1381, 11), (1382, 11), (1383, 11), (1384, 11), (1385, 11), (1386, 11), (1387, 11), (1388, 11), (1389, 11), (1390, 11), (1391, 11), (1392, 11), (1393, 11), (1394, 11), (1395, 11), (1396, 11), (1397, 11), (1398, 11), (1399, 11), (1400, 11), (1401, 11), (1402, 11), (1403, 11), (1404, 11), (1405, 11), (1406, 11), (1407, 11), (1408, 11), (1409, 11), (1410, 11), (1411, 11), (1412, 11), (1413, 11), (1414, 11), (1415, 11), (1416, 11), (1417, 11), (1418, 11), (1419, 11), (1420, 11), (1421, 11), (1422, 11), (1423, 11), (1424, 11), (1425, 11), (1426, 11), (1427, 11), (1428, 11), (1429, 11), (1430, 11), (1431, 11), (1432, 11), (1433, 11), (1434, 11), (1435, 11), (1436, 11), (1437, 11), (1438, 11), (1439, 11), (1440, 11), (1441, 11), (1442, 11), (1443, 11), (1444, 11), (1445, 11), (1446, 11), (1447, 11), (1448, 11), (1449, 11), (1450, 11), (1451, 11), (1452, 11), (1453, 11), (1454, 11), (1455, 11), (1456, 11), (1457, 11), (1458, 11), (1459, 11), (1460, 11), (1461, 11), (1462, 11), (1463, 11), (1464, 11), (1465, 11), (1466, 11), (1467, 11), (1468, 11), (1469, 11), (1470, 11), (1471, 11), (1472, 11), (1473, 11), (1474, 11), (1475, 11), (1476, 11), (1477, 11), (1478, 11), (1479, 11), (1480, 11), (1481, 11), (1482, 11), (1483, 11), (1484, 11), (1485, 11), (1486, 11), (1487, 11), (1488, 11), (1489, 11), (1490, 11), (1491, 11), (1492, 11), (1493, 11), (1494, 11), (1495, 11), (1496, 11), (1497, 11), (1498, 11), (1499, 11), (1500, 11), (1501, 11), (1502, 11), (1503, 11), (1504, 11), (1505, 11), (1506, 11), (1507, 11), (1508, 11), (1509, 11), (1510, 11), (1511, 11), (1512, 11), (1513, 11), (1514, 11), (1515, 11), (1516, 11), (1517, 11), (1518, 11), (1519, 11), (1520, 11), (1521, 11), (1522, 11), (1523, 11), (1524, 11), (1525, 11), (1526, 11), (1527, 11), (1528, 11), (1529, 11), (1530, 11), (1531, 11), (1532, 11), (1533, 11), (1534, 11), (1535, 11), (1536, 11), (1537, 11), (1538, 11), (1539, 11), (1540, 11), (1541, 11), (1542, 11), (1543, 11), (1544, 11), (1545, 11), (1546, 11), (1547, 11), (1548, 11), (1549, 11), (1550, 11), (1551, 11), (1552, 11), (1553, 11), (1554, 11), (1555, 11), (1556, 11), (1557, 11), (1558, 11), (1559, 11), (1560, 11), (1561, 11), (1562, 11), (1563, 11), (1564, 11), (1565, 11), (1566, 11), (1567, 11), (1568, 11), (1569, 11), (1570, 11), (1571, 11), (1572, 11), (1573, 11), (1574, 11), (1575, 11), (1576, 11), (1577, 11), (1578, 11), (1579, 11), (1580, 11), (1581, 11), (1582, 11), (1583, 11), (1584, 11), (1585, 11), (1586, 11), (1587, 11), (1588, 11), (1589, 11), (1590, 11), (1591, 11), (1592, 11), (1593, 11), (1594, 11), (1595, 11), (1596, 11), (1597, 11), (1598, 11), (1599, 11), (1600, 11), (1601, 11), (1602, 11), (1603, 11), (1604, 11), (1605, 11), (1606, 11), (1607, 11), (1608, 11), (1609, 11), (1610, 11), (1611, 11), (1612, 11), (1613, 11), (1614, 11), (1615, 11), (1616, 11), (1617, 11), (1618, 11), (1619, 11), (1620, 11), (1621, 11), (1622, 11), (1623, 11), (1624, 11), (1625, 11), (1626, 11), (1627, 11), (1628, 11), (1629, 11), (1630, 11), (1631, 11), (1632, 11), (1633, 11), (1634, 11), (1635, 11), (1636, 11), (1637, 11), (1638, 11), (1639, 11), (1640, 11), (1641, 11), (1642, 11), (1643, 11), (1644, 11), (1645, 11), (1646, 11), (1647, 11), (1648, 11), (1649, 11), (1650, 11), (1651, 11), (1652, 11), (1653, 11), (1654, 11), (1655, 11), (1656, 11), (1657, 11), (1658, 11), (1659, 11), (1660, 11), (1661, 11), (1662, 11), (1663, 11), (1664, 11), (1665, 11), (1666, 11), (1667, 11), (1668, 11), (1669, 11), (1670, 11), (1671, 11), (1672, 11), (1673, 11), (1674, 11), (1675, 11), (1676, 11), (1677, 11), (1678, 11), (1679, 11), (1680, 11), (1681, 11), (1682, 11), (1683, 11), (1684, 11), (1685, 11), (1686, 11), (1687, 11), (1688, 11), (1689, 11), (1690, 11), (1691, 11), (1692, 11), (1693, 11), (1694, 11), (1695, 11), (1696, 11), (1697, 11), (1698, 11), (1699, 11), (1700, 11), (1701, 11), (1702, 11), (1703, 11), (1704, 11), (1705, 11), (1706, 11), (1707, 11), (1708, 11), (1709, 11), (1710, 11), (1711, 11), (1712, 11), (1713, 11), (1714, 11), (1715, 11), (1716, 11), (1717, 11), (1718, 11), (1719, 11), (1720, 11), (1721, 11), (1722, 11), (1723, 11), (1724, 11), (1725, 11), (1726, 11), (1727, 11), (1728, 11), (1729, 11), (1730, 11), (1731, 11), (1732, 11), (1733, 11), (1734, 11), (1735, 11), (1736, 11), (1737, 11), (1738, 11), (1739, 11), (1740, 11), (1741, 11), (1742, 11), (1743, 11), (1744, 11), (1745, 11), (1746, 11), (1747, 11), (1748, 11), (1749, 11), (1750, 11), (1751, 11), (1752, 11), (1753, 11), (1754, 11), (1755, 11), (1756, 11), (1757, 11), (1758, 11), (1759, 11), (1760, 11), (1761, 11), (1762, 11), (1763, 11), (1764, 11), (1765, 11), (1766, 11), (1767, 11), (1768, 11), (1769, 11), (1770, 11), (1771, 11), (1772, 11), (1773, 11), (1774, 11), (1775, 11), (1776, 11), (1777, 11), (1778, 11), (1779, 11), (1780, 11), (1781, 11), (1782, 11), (1783, 11), (1784, 11), (1785, 11), (1786, 11), (1787, 11), (1788, 11), (1789, 11), (1790, 11), (1791, 11), (1792, 11), (1793, 11), (1794, 11), (1795, 11), (1796, 11), (1797, 11), (1798, 11), (1799, 11), (1800, 11), (1801, 11), (1802, 11), (1803, 11), (1804, 11), (1805, 11), (1806, 11), (1807, 11), (1808, 11), (1809, 11), (1810, 11), (1811, 11), (1812, 11), (1813, 11), (1814, 11), (1815, 11), (1816, 11), (1817, 11), (1818, 11), (1819, 11), (1820, 11), (1821, 11), (1822, 11), (1823, 11), (1824, 11), (1825, 11), (1826, 11), (1827, 11), (1828, 11), (1829, 11), (1830, 11), (1831, 11), (1832, 11), (1833, 11), (1834, 11), (1835, 11), (1836, 11), (1837, 11), (1838, 11), (1839, 11), (1840, 11), (1841, 11), (1842, 11), (1843, 11), (1844, 11), (1845, 11), (1846, 11), (1847, 11), (1848, 11), (1849, 11), (1850, 11), (1851, 11), (1852, 11), (1853, 11), (1854, 11), (1855, 11), (1856, 11), (1857, 11), (1858, 11), (1859, 11), (1860, 11), (1861, 11), (1862, 11), (1863, 11), (1864, 11), (1865, 11), (1866, 11), (1867, 11), (1868, 11), (1869, 11), (1870, 11), (1871, 11), (1872, 11), (1873, 11), (1874, 11), (1875, 11), (1876, 11), (1877, 11), (1878, 11), (1879, 11), (1880, 11), (1881, 11), (1882, 11), (1883, 11), (1884, 11), (1885, 11), (1886, 11), (1887, 11), (1888, 11), (1889, 11), (1890, 11), (1891, 11), (1892, 11), (1893, 11), (1894, 11), (1895, 11), (1896, 11), (1897, 11), (1898, 11), (1899, 11), (1900, 11), (1901, 11), (1902, 11), (1903, 11), (1904, 11), (1905, 11), (1906, 11), (1907, 11), (1908, 11), (1909, 11), (1910, 11), (1911, 11), (1912, 11), (1913, 11), (1914, 11), (1915, 11), (1916, 11), (1917, 11), (1918, 11), (1919, 11), (1920, 11), (1921, 11), (1922, 11), (1923, 11), (1924, 11), (1925, 11), (1926, 11), (1927, 11), (1928, 11), (1929, 11), (1930, 11), (1931, 11), (1932, 11), (1933, 11), (1934, 11), (1935, 11), (1936, 11), (1937, 11), (1938, 11), (1939, 11), (1940, 11), (1941, 11), (1942, 11), (1943, 11), (1944, 11), (1945, 11), (1946, 11), (1947, 11), (1948, 11), (1949, 11), (1950, 11), (1951, 11), (1952, 11), (1953, 11), (1954, 11), (1955, 11), (1956, 11), (1957, 11), (1958, 11), (1959, 11), (1960, 11), (1961, 11), (1962, 11), (1963, 11), (1964, 11), (1965, 11), (1966, 11), (1967, 11), (1968, 11), (1969, 11), (1970, 11), (1971, 11), (1972, 11), (1973, 11), (1974, 11), (1975, 11), (1976, 11), (1977, 11), (1978, 11), (1979, 11), (1980, 11), (1981, 11), (1982, 11), (1983, 11), (1984, 11), (1985, 11), (1986, 11), (1987, 11), (1988, 11), (1989, 11), (1990, 11), (1991, 11), (1992, 11), (1993, 11), (1994, 11), (1995, 11), (1996, 11), (1997, 11), (1998, 11), (1999, 11), (2000, 11), (2001, 11), (2002, 11), (2003, 11), (2004, 11), (2005, 11), (2006, 11), (2007, 11), (2008, 11), (2009, 11), (2010, 11), (2011, 11), (2012, 11), (2013, 11), (2014, 11), (2015, 11), (2016, 11), (2017, 11), (2018, 11), (2019, 11), (2020, 11), (2021, 11), (2022, 11), (2023, 11), (2024, 11), (2025, 11), (2026, 11), (2027, 11), (2028, 11), (2029, 11), (2030, 11), (2031, 11), (2032, 11), (2033, 11), (2034, 11), (2035, 11), (2036, 11), (2037, 11), (2038, 11), (2039, 11), (2040, 11), (2041, 11), (2042, 11), (2043, 11), (2044, 11), (2045, 11), (2046, 11), (2047, 11), (0, 12), (1, 12), (2, 12), (3, 12), (4, 12), (5, 12), (6, 12), (7, 12), (8, 12), (9, 12), (10, 12), (11, 12), (12, 12), (13, 12), (14, 12), (15, 12), (16, 12), (17, 12), (18, 12), (19, 12), (20, 12), (21, 12), (22, 12), (23, 12), (24, 12), (25, 12), (26, 12), (27, 12), (28, 12), (29, 12), (30, 12), (31, 12), (32, 12), (33, 12), (34, 12), (35, 12), (36, 12), (37, 12), (38, 12), (39, 12), (40, 12), (41, 12), (42, 12), (43, 12), (44, 12), (45, 12), (46, 12), (47, 12), (48, 12), (49, 12), (50, 12), (51, 12), (52, 12), (53, 12), (54, 12), (55, 12), (56, 12), (57, 12), (58, 12), (59, 12), (60, 12), (61, 12), (62, 12), (63, 12), (64, 12), (65, 12), (66, 12), (67, 12), (68, 12), (69, 12), (70, 12), (71, 12), (72, 12), (73, 12), (74, 12), (75, 12), (76, 12), (77, 12), (78, 12), (79, 12), (80, 12), (81, 12), (82, 12), (83, 12), (84, 12), (85, 12), (86, 12), (87, 12), (88, 12), (89, 12), (90, 12), (91, 12), (92, 12), (93, 12), (94, 12), (95, 12), (96, 12), (97, 12), (98, 12), (99, 12), (100, 12), (101, 12), (102, 12), (103, 12), (104, 12), (105, 12), (106, 12), (107, 12), (108, 12), (109, 12), (110, 12), (111, 12), (112, 12), (113, 12), (114, 12), (115, 12), (116, 12), (117, 12), (118, 12), (119, 12), (120, 12), (121, 12), (122, 12), (123, 12), (124, 12), (125, 12), (126, 12), (127, 12), (128, 12), (129, 12), (130, 12), (131, 12), (132, 12), (133, 12), (134, 12), (135, 12), (136, 12), (137, 12), (138, 12), (139, 12), (140, 12), (141, 12), (142, 12), (143, 12), (144, 12), (145, 12), (146, 12), (147, 12), (148, 12), (149, 12), (150, 12), (151, 12), (152, 12), (153, 12), (154, 12), (155, 12), (156, 12), (157, 12), (158, 12), (159, 12), (160, 12), (161, 12), (162, 12), (163, 12), (164, 12), (165, 12), (166, 12), (167, 12), (168, 12), (169, 12), (170, 12), (171, 12), (172, 12), (173, 12), (174, 12), (175, 12), (176, 12), (177, 12), (178, 12), (179, 12), (180, 12), (181, 12), (182, 12), (183, 12), (184, 12), (185, 12), (186, 12), (187, 12), (188, 12), (189, 12), (190, 12), (191, 12), (192, 12), (193, 12), (194, 12), (195, 12), (196, 12), (197, 12), (198, 12), (199, 12), (200, 12), (201, 12), (202, 12), (203, 12), (204, 12), (205, 12), (206, 12), (207, 12), (208, 12), (209, 12), (210, 12), (211, 12), (212, 12), (213, 12), (214, 12), (215, 12), (216, 12), (217, 12), (218, 12), (219, 12), (220, 12), (221, 12), (222, 12), (223, 12), (224, 12), (225, 12), (226, 12), (227, 12), (228, 12), (229, 12), (230, 12), (231, 12), (232, 12), (233, 12), (234, 12), (235, 12), (236, 12), (237, 12), (238, 12), (239, 12), (240, 12), (241, 12), (242, 12), (243, 12), (244, 12), (245, 12), (246, 12), (247, 12), (248, 12), (249, 12), (250, 12), (251, 12), (252, 12), (253, 12), (254, 12), (255, 12), (256, 12), (257, 12), (258, 12), (259, 12), (260, 12), (261, 12), (262, 12), (263, 12), (264, 12), (265, 12), (266, 12), (267, 12), (268, 12), (269, 12), (270, 12), (271, 12), (272, 12), (273, 12), (274, 12), (275, 12), (276, 12), (277, 12), (278, 12), (279, 12), (280, 12), (281, 12), (282, 12), (283, 12), (284, 12), (285, 12), (286, 12), (287, 12), (288, 12), (289, 12), (290, 12), (291, 12), (292, 12), (293, 12), (294, 12), (295, 12), (296, 12), (297, 12), (298, 12), (299, 12), (300, 12), (301, 12), (302, 12), (303, 12), (304, 12), (305, 12), (306, 12), (307, 12), (308, 12), (309, 12), (310, 12), (311, 12), (312, 12), (313, 12), (314, 12), (315, 12), (316, 12), (317, 12), (318, 12), (319, 12), (320, 12), (321, 12), (322, 12), (323, 12), (324, 12), (325, 12), (326, 12), (327, 12), (328, 12), (329, 12), (330, 12), (331, 12), (332, 12), (333, 12), (334, 12), (335, 12), (336, 12), (337, 12), (338, 12), (339, 12), (340, 12), (341, 12), (342, 12), (343, 12), (344, 12), (345, 12), (346, 12), (347, 12), (348, 12), (349, 12), (350, 12), (351, 12), (352, 12), (353, 12), (354, 12), (355, 12), (356, 12), (357, 12), (358, 12), (359, 12), (360, 12), (361, 12), (362, 12), (363, 12), (364, 12), (365, 12), (366, 12), (367, 12), (368, 12), (369, 12), (370, 12), (371, 12), (372, 12), (373, 12), (374, 12), (375, 12), (376, 12), (377, 12), (378, 12), (379, 12), (380, 12), (381, 12), (382, 12), (383, 12), (384, 12), (385, 12), (386, 12), (387, 12), (388, 12), (389, 12), (390, 12), (391, 12), (392, 12), (393, 12), (394, 12), (395, 12), (396, 12), (397, 12), (398, 12), (399, 12), (400, 12), (401, 12), (402, 12), (403, 12), (404, 12), (405, 12), (406, 12), (407, 12), (408, 12), (409, 12), (410, 12), (411, 12), (412, 12), (413, 12), (414, 12), (415, 12), (416, 12), (417, 12), (418, 12), (419, 12), (420, 12), (421, 12), (422, 12), (423, 12), (424, 12), (425, 12), (426, 12), (427, 12), (428, 12), (429, 12), (430, 12), (431, 12), (432, 12), (433, 12), (434, 12), (435, 12), (436, 12), (437, 12), (438, 12), (439, 12), (440, 12), (441, 12), (442, 12), (443, 12), (444, 12), (445, 12), (446, 12), (447, 12), (448, 12), (449, 12), (450, 12), (451, 12), (452, 12), (453, 12), (454, 12), (455, 12), (456, 12), (457, 12), (458, 12), (459, 12), (460, 12), (461, 12), (462, 12), (463, 12), (464, 12), (465, 12), (466, 12), (467, 12), (468, 12), (469, 12), (470, 12), (471, 12), (472, 12), (473, 12), (474, 12), (475, 12), (476, 12), (477, 12), (478, 12), (479, 12), (480, 12), (481, 12), (482, 12), (483, 12), (484, 12), (485, 12), (486, 12), (487, 12), (488, 12), (489, 12), (490, 12), (491, 12), (492, 12), (493, 12), (494, 12), (495, 12), (496, 12), (497, 12), (498, 12), (499, 12), (500, 12), (501, 12), (502, 12), (503, 12), (504, 12), (505, 12), (506, 12), (507, 12), (508, 12), (509, 12), (510, 12), (511, 12), (512, 12), (513, 12), (514, 12), (515, 12), (516, 12), (517, 12), (518, 12), (519, 12), (520, 12), (521, 12), (522, 12), (523, 12), (524, 12), (525, 12), (526, 12), (527, 12), (528, 12), (529, 12), (530, 12), (531, 12), (532, 12), (533, 12), (534, 12), (535, 12), (536, 12), (537, 12), (538, 12), (539, 12), (540, 12), (541, 12), (542, 12), (543, 12), (544, 12), (545, 12), (546, 12), (547, 12), (548, 12), (549, 12), (550, 12), (551, 12), (552, 12), (553, 12), (554, 12), (555, 12), (556, 12), (557, 12), (558, 12), (559, 12), (560, 12), (561, 12), (562, 12), (563, 12), (564, 12), (565, 12), (566, 12), (567, 12), (568, 12), (569, 12), (570, 12), (571, 12), (572, 12), (573, 12), (574, 12), (575, 12), (576, 12), (577, 12), (578, 12), (579, 12), (580, 12), (581, 12), (582, 12), (583, 12), (584, 12), (585, 12), (586, 12), (587, 12), (588, 12), (589, 12), (590, 12), (591, 12), (592, 12), (593, 12), (594, 12), (595, 12), (596, 12), (597, 12), (598, 12), (599, 12), (600, 12), (601, 12), (602, 12), (603, 12), (604, 12), (605, 12), (606, 12), (607, 12), (608, 12), (609, 12), (610, 12), (611, 12), (612, 12), (613, 12), (614, 12), (615, 12), (616, 12), (617, 12), (618, 12), (619, 12), (620, 12), (621, 12), (622, 12), (623, 12), (624, 12), (625, 12), (626, 12), (627, 12), (628, 12), (629, 12), (630, 12), (631, 12), (632, 12), (633, 12), (634, 12), (635, 12), (636, 12), (637, 12), (638, 12), (639, 12), (640, 12), (641, 12), (642, 12), (643, 12), (644, 12), (645, 12), (646, 12), (647, 12), (648, 12), (649, 12), (650, 12), (651, 12), (652, 12), (653, 12), (654, 12), (655, 12), (656, 12), (657, 12), (658, 12), (659, 12), (660, 12), (661, 12), (662, 12), (663, 12), (664, 12), (665, 12), (666, 12), (667, 12), (668, 12), (669, 12), (670, 12), (671, 12), (672, 12), (673, 12), (674, 12), (675, 12), (676, 12), (677, 12), (678, 12), (679, 12), (680, 12), (681, 12), (682, 12), (683, 12), (684, 12), (685, 12), (686, 12), (687, 12), (688, 12), (689, 12), (690, 12), (691, 12), (692, 12), (693, 12), (694, 12), (695, 12), (696, 12), (697, 12), (698, 12), (699, 12), (700, 12), (701, 12), (702, 12), (703, 12), (704, 12), (705, 12), (706, 12), (707, 12), (708, 12), (709, 12), (710, 12), (711, 12), (712, 12), (713, 12), (714, 12), (715, 12), (716, 12), (717, 12), (718, 12), (719, 12), (720, 12), (721, 12), (722, 12), (723, 12), (724, 12), (725, 12), (726, 12), (727, 12), (728, 12), (729, 12), (730, 12), (731, 12), (732, 12), (733, 12), (734, 12), (735, 12), (736, 12), (737, 12), (738, 12), (739, 12), (740, 12), (741, 12), (742, 12), (743, 12), (744, 12), (745, 12), (746, 12), (747, 12), (748, 12), (749, 12), (750, 12), (751, 12), (752, 12), (753, 12), (754, 12), (755, 12), (756, 12), (757, 12), (758, 12), (759, 12), (760, 12), (761, 12), (762, 12), (763, 12), (764, 12), (765, 12), (766, 12), (767, 12), (768, 12), (769, 12), (770, 12), (771, 12), (772, 12), (773, 12), (774, 12), (775, 12), (776, 12), (777, 12), (778, 12), (779, 12), (780, 12), (781, 12), (782, 12), (783, 12), (784, 12), (785, 12), (786, 12), (787, 12), (788, 12), (789, 12), (790, 12), (791, 12), (792, 12), (793, 12), (794, 12), (795, 12), (796, 12), (797, 12), (798, 12), (799, 12), (800, 12), (801, 12), (802, 12), (803, 12), (804, 12), (805, 12), (806, 12), (807, 12), (808, 12), (809, 12), (810, 12), (811, 12), (812, 12), (813, 12), (814, 12), (815, 12), (816, 12), (817, 12), (818, 12), (819, 12), (820, 12), (821, 12), (822, 12), (823, 12), (824, 12), (825, 12), (826, 12), (827, 12), (828, 12), (829, 12), (830, 12), (831, 12), (832, 12), (833, 12), (834, 12), (835, 12), (836, 12), (837, 12), (838, 12), (839, 12), (840, 12), (841, 12), (842, 12), (843, 12), (844, 12), (845, 12), (846, 12), (847, 12), (848, 12), (849, 12), (850, 12), (851, 12), (852, 12), (853, 12), (854, 12), (855, 12), (856, 12), (857, 12), (858, 12), (859, 12), (860, 12), (861, 12), (862, 12), (863, 12), (864, 12), (865, 12), (866, 12), (867, 12), (868, 12), (869, 12), (870, 12), (871, 12), (872, 12), (873, 12), (874, 12), (875, 12), (876, 12), (877, 12), (878, 12), (879, 12), (880, 12), (881, 12), (882, 12), (883, 12), (884, 12), (885, 12), (886, 12), (887, 12), (888, 12), (889, 12), (890, 12), (891, 12), (892, 12), (893, 12), (894, 12), (895, 12), (896, 12), (897, 12), (898, 12), (899, 12), (900, 12), (901, 12), (902, 12), (903, 12), (904, 12), (905, 12), (906, 12), (907, 12), (908, 12), (909, 12), (910, 12), (911, 12), (912, 12), (913, 12), (914, 12), (915, 12), (916, 12), (917, 12), (918, 12), (919, 12), (920, 12), (921, 12), (922, 12), (923, 12), (924, 12), (925, 12), (926, 12), (927, 12), (928, 12), (929, 12), (930, 12), (931, 12), (932, 12), (933, 12), (934, 12), (935, 12), (936, 12), (937, 12), (938, 12), (939, 12), (940, 12), (941, 12), (942, 12), (943, 12), (944, 12), (945, 12), (946, 12), (947, 12), (948, 12), (949, 12), (950, 12), (951, 12), (952, 12), (953, 12), (954, 12), (955, 12), (956, 12), (957, 12), (958, 12), (959, 12), (960, 12), (961, 12), (962, 12), (963, 12), (964, 12), (965, 12), (966, 12), (967, 12), (968, 12), (969, 12), (970, 12), (971, 12), (972, 12), (973, 12), (974, 12), (975, 12), (976, 12), (977, 12), (978, 12), (979, 12), (980, 12), (981, 12), (982, 12), (983, 12), (984, 12), (985, 12), (986, 12), (987, 12), (988, 12), (989, 12), (990, 12), (991, 12), (992, 12), (993, 12), (994, 12), (995, 12), (996, 12), (997, 12), (998, 12), (999, 12), (1000, 12), (1001, 12), (1002, 12), (1003, 12), (1004, 12), (1005, 12), (1006, 12), (1007, 12), (1008, 12), (1009, 12), (1010, 12), (1011, 12), (1012, 12), (1013, 12), (1014, 12), (1015, 12), (1016, 12), (1017, 12), (1018, 12), (1019, 12), (1020, 12), (1021, 12), (1022, 12), (1023, 12), (1024, 12), (1025, 12), (1026, 12), (1027, 12), (1028, 12), (1029, 12), (1030, 12), (1031, 12), (1032, 12), (1033, 12), (1034, 12), (1035, 12), (1036, 12), (1037, 12), (1038, 12), (1039, 12), (1040, 12), (1041, 12), (1042, 12), (1043, 12), (1044, 12), (1045, 12), (1046, 12), (1047, 12), (1048, 12), (1049, 12), (1050, 12), (1051, 12), (1052, 12), (1053, 12), (1054, 12), (1055, 12), (1056, 12), (1057, 12), (1058, 12), (1059, 12), (1060, 12), (1061, 12), (1062, 12), (1063, 12), (1064, 12), (1065, 12), (1066, 12), (1067, 12), (1068, 12), (1069, 12), (1070, 12), (1071, 12), (1072, 12), (1073, 12), (1074, 12), (1075, 12), (1076, 12), (1077, 12), (1078, 12), (1079, 12), (1080, 12), (1081, 12), (1082, 12), (1083, 12), (1084, 12), (1085, 12), (1086, 12), (1087, 12), (1088, 12), (1089, 12), (1090, 12), (1091, 12), (1092, 12), (1093, 12), (1094, 12), (1095, 12), (1096, 12), (1097, 12), (1098, 12), (1099, 12), (1100, 12), (1101, 12), (1102, 12), (1103, 12), (1104, 12), (1105, 12), (1106, 12), (1107, 12), (1108, 12), (1109, 12), (1110, 12), (1111, 12), (1112, 12), (1113, 12), (1114, 12), (1115, 12), (1116, 12), (1117, 12), (1118, 12), (1119, 12), (1120, 12), (1121, 12), (1122, 12), (1123, 12), (1124, 12), (1125, 12), (1126, 12), (1127, 12), (1128, 12), (1129, 12), (1130, 12), (1131, 12), (1132, 12), (1133, 12), (1134, 12), (1135, 12), (1136, 12), (1137, 12), (1138, 12), (1139, 12), (1140, 12), (1141, 12), (1142, 12), (1143, 12), (1144, 12), (1145, 12), (1146, 12), (1147, 12), (1148, 12), (1149, 12), (1150, 12), (1151, 12), (1152, 12), (1153, 12), (1154, 12), (1155, 12), (1156, 12), (1157, 12), (1158, 12), (1159, 12), (1160, 12), (1161, 12), (1162, 12), (1163, 12), (1164, 12), (1165, 12), (1166, 12), (1167, 12), (1168, 12), (1169, 12), (1170, 12), (1171, 12), (1172, 12), (1173, 12), (1174, 12), (1175, 12), (1176, 12), (1177, 12), (1178, 12), (1179, 12), (1180, 12), (1181, 12), (1182, 12), (1183, 12), (1184, 12), (1185, 12), (1186, 12), (1187, 12), (1188, 12), (1189, 12), (1190, 12), (1191, 12), (1192, 12), (1193, 12), (1194, 12), (1195, 12), (1196, 12), (1197, 12), (1198, 12), (1199, 12), (1200, 12), (1201, 12), (1202, 12), (1203, 12), (1204, 12), (1205, 12), (1206, 12), (1207, 12), (1208, 12), (1209, 12), (1210, 12), (1211, 12), (1212, 12), (1213, 12), (1214, 12), (1215, 12), (1216, 12), (1217, 12), (1218, 12), (1219, 12), (1220, 12), (1221, 12), (1222, 12), (1223, 12), (1224, 12), (1225, 12), (1226, 12), (1227, 12), (1228, 12), (1229, 12), (1230, 12), (1231, 12), (1232, 12), (1233, 12), (1234, 12), (1235, 12), (1236, 12), (1237, 12), (1238, 12), (1239, 12), (1240, 12), (1241, 12), (1242, 12), (1243, 12), (1244, 12), (1245, 12), (1246, 12), (1247, 12), (1248, 12), (1249, 12), (1250, 12), (1251, 12), (1252, 12), (1253, 12), (1254, 12), (1255, 12), (1256, 12), (1257, 12), (1258, 12), (1259, 12), (1260, 12), (1261, 12), (1262, 12), (1263, 12), (1264, 12), (1265, 12), (1266, 12), (1267, 12), (1268, 12), (1269, 12), (1270, 12), (1271, 12), (1272, 12), (1273, 12), (1274, 12), (1275, 12), (1276, 12), (1277, 12), (1278, 12), (1279, 12), (1280, 12), (1281, 12), (1282, 12), (1283, 12), (1284, 12), (1285, 12), (1286, 12), (1287, 12), (1288, 12), (1289, 12), (1290, 12), (1291, 12), (1292, 12), (1293, 12), (1294, 12), (1295, 12), (1296, 12), (1297, 12), (1298, 12), (1299, 12), (1300, 12), (1301, 12), (1302, 12), (1303, 12), (1304, 12), (1305, 12), (1306, 12), (1307, 12), (1308, 12), (1309, 12), (1310, 12), (1311, 12), (1312, 12), (1313, 12), (1314, 12), (1315, 12), (1316, 12), (1317, 12), (1318, 12), (1319, 12), (1320, 12), (1321, 12), (1322, 12), (1323, 12), (1324, 12), (1325, 12), (1326, 12), (1327, 12), (1328, 12), (1329, 12), (1330, 12), (1331, 12), (1332, 12), (1333, 12), (1334, 12), (1335, 12), (1336, 12), (1337, 12), (1338, 12), (1339, 12), (1340, 12), (1341, 12), (1342, 12), (1343, 12), (1344, 12), (1345, 12), (1346, 12), (1347, 12), (1348, 12), (1349, 12), (1350, 12), (1351, 12), (1352, 12), (1353, 12), (1354, 12), (1355, 12), (1356, 12), (1357, 12), (1358, 12), (1359, 12), (1360, 12), (1361, 12), (1362, 12), (1363, 12), (1364, 12), (1365, 12), (1366, 12), (1367, 12), (1368, 12), (1369, 12), (1370, 12), (1371, 12), (1372, 12), (1373, 12), (1374, 12), (1375, 12), (1376, 12), (1377, 12), (1378, 12), (1379, 12), (1380, 12), (1381, 12), (1382, 12), (1383, 12), (1384, 12), (1385, 12), (1386, 12), (1387, 12), (1388, 12), (1389, 12), (1390, 12), (1391, 12), (1392, 12), (1393, 12), (1394, 12), (1395, 12), (1396, 12), (1397, 12), (1398, 12), (1399, 12), (1400, 12), (1401, 12), (1402, 12), (1403, 12), (1404, 12), (1405, 12), (1406, 12), (1407, 12), (1408, 12), (1409, 12), (1410, 12), (1411, 12), (1412, 12), (1413, 12), (1414, 12), (1415, 12), (1416, 12), (1417, 12), (1418, 12), (1419, 12), (1420, 12), (1421, 12), (1422, 12), (1423, 12), (1424, 12), (1425, 12), (1426, 12), (1427, 12), (1428, 12), (1429, 12), (1430, 12), (1431, 12), (1432, 12), (1433, 12), (1434, 12), (1435, 12), (1436, 12), (1437, 12), (1438, 12), (1439, 12), (1440, 12), (1441, 12), (1442, 12), (1443, 12), (1444, 12), (1445, 12), (1446, 12), (1447, 12), (1448, 12), (1449, 12), (1450, 12), (1451, 12), (1452, 12), (1453, 12), (1454, 12), (1455, 12), (1456, 12), (1457, 12), (1458, 12), (1459, 12), (1460, 12), (1461, 12), (1462, 12), (1463, 12), (1464, 12), (1465, 12), (1466, 12), (1467, 12), (1468, 12), (1469, 12), (1470, 12), (1471, 12), (1472, 12), (1473, 12), (1474, 12), (1475, 12), (1476, 12), (1477, 12), (1478, 12), (1479, 12), (1480, 12), (1481, 12), (1482, 12), (1483, 12), (1484, 12), (1485, 12), (1486, 12), (1487, 12), (1488, 12), (1489, 12), (1490, 12), (1491, 12), (1492, 12), (1493, 12), (1494, 12), (1495, 12), (1496, 12), (1497, 12), (1498, 12), (1499, 12), (1500, 12), (1501, 12), (1502, 12), (1503, 12), (1504, 12), (1505, 12), (1506, 12), (1507, 12), (1508, 12), (1509, 12), (1510, 12), (1511, 12), (1512, 12), (1513, 12), (1514, 12), (1515, 12), (1516, 12), (1517, 12), (1518, 12), (1519, 12), (1520, 12), (1521, 12), (1522, 12), (1523, 12), (1524, 12), (1525, 12), (1526, 12), (1527, 12), (1528, 12), (1529, 12), (1530, 12), (1531, 12), (1532, 12), (1533, 12), (1534, 12), (1535, 12), (1536, 12), (1537, 12), (1538, 12), (1539, 12), (1540, 12), (1541, 12), (1542, 12), (1543, 12), (1544, 12), (1545, 12), (1546, 12), (1547, 12), (1548, 12), (1549, 12), (1550, 12), (1551, 12), (1552, 12), (1553, 12), (1554, 12), (1555, 12), (1556, 12), (1557, 12), (1558, 12), (1559, 12), (1560, 12), (1561, 12), (1562, 12), (1563, 12), (1564, 12), (1565, 12), (1566, 12), (1567, 12), (1568, 12), (1569, 12), (1570, 12), (1571, 12), (1572, 12), (1573, 12), (1574, 12), (1575, 12), (1576, 12), (1577, 12), (1578, 12), (1579, 12), (1580, 12), (1581, 12), (1582, 12), (1583, 12), (1584, 12), (1585, 12), (1586, 12), (1587, 12), (1588, 12), (1589, 12), (1590, 12), (1591, 12), (1592, 12), (1593, 12), (1594, 12), (1595, 12), (1596, 12), (1597, 12), (1598, 12), (1599, 12), (1600, 12), (1601, 12), (1602, 12), (1603, 12), (1604, 12), (1605, 12), (1606, 12), (1607, 12), (1608, 12), (1609, 12), (1610, 12), (1611, 12), (1612, 12), (1613, 12), (1614, 12), (1615, 12), (1616, 12), (1617, 12), (1618, 12), (1619, 12), (1620, 12), (1621, 12), (1622, 12), (1623, 12), (1624, 12), (1625, 12), (1626, 12), (1627, 12), (1628, 12), (1629, 12), (1630, 12), (1631, 12), (1632, 12), (1633, 12), (1634, 12), (1635, 12), (1636, 12), (1637, 12), (1638, 12), (1639, 12), (1640, 12), (1641, 12), (1642, 12), (1643, 12), (1644, 12), (1645, 12), (1646, 12), (1647, 12), (1648, 12), (1649, 12), (1650, 12), (1651, 12), (1652, 12), (1653, 12), (1654, 12), (1655, 12), (1656, 12), (1657, 12), (1658, 12), (1659, 12), (1660, 12), (1661, 12), (1662, 12), (1663, 12), (1664, 12), (1665, 12), (1666, 12), (1667, 12), (1668, 12), (1669, 12), (1670, 12), (1671, 12), (1672, 12), (1673, 12), (1674, 12), (1675, 12), (1676, 12), (1677, 12), (1678, 12), (1679, 12), (1680, 12), (1681, 12), (1682, 12), (1683, 12), (1684, 12), (1685, 12), (1686, 12), (1687, 12), (1688, 12), (1689, 12), (1690, 12), (1691, 12), (1692, 12), (1693, 12), (1694, 12), (1695, 12), (1696, 12), (1697, 12), (1698, 12), (1699, 12), (1700, 12), (1701, 12), (1702, 12), (1703, 12), (1704, 12), (1705, 12), (1706, 12), (1707, 12), (1708, 12), (1709, 12), (1710, 12), (1711, 12), (1712, 12), (1713, 12), (1714, 12), (1715, 12), (1716, 12), (1717, 12), (1718, 12), (1719, 12), (1720, 12), (1721, 12), (1722, 12), (1723, 12), (1724, 12), (1725, 12), (1726, 12), (1727, 12), (1728, 12), (1729, 12), (1730, 12), (1731, 12), (1732, 12), (1733, 12), (1734, 12), (1735, 12), (1736, 12), (1737, 12), (1738, 12), (1739, 12), (1740, 12), (1741, 12), (1742, 12), (1743, 12), (1744, 12), (1745, 12), (1746, 12), (1747, 12), (1748, 12), (1749, 12), (1750, 12), (1751, 12), (1752, 12), (1753, 12), (1754, 12), (1755, 12), (1756, 12), (1757, 12), (1758, 12), (1759, 12), (1760, 12), (1761, 12), (1762, 12), (1763, 12), (1764, 12), (1765, 12), (1766, 12), (1767, 12), (1768, 12), (1769, 12), (1770, 12), (1771, 12), (1772, 12), (1773, 12), (1774, 12), (1775, 12), (1776, 12), (1777, 12), (1778, 12), (1779, 12), (1780, 12), (1781, 12), (1782, 12), (1783, 12), (1784, 12), (1785, 12), (1786, 12), (1787, 12), (1788, 12), (1789, 12), (1790, 12), (1791, 12), (1792, 12), (1793, 12), (1794, 12), (1795, 12), (1796, 12), (1797, 12), (1798, 12), (1799, 12), (1800, 12), (1801, 12), (1802, 12), (1803, 12), (1804, 12), (1805, 12), (1806, 12), (1807, 12), (1808, 12), (1809, 12), (1810, 12), (1811, 12), (1812, 12), (1813, 12), (1814, 12), (1815, 12), (1816, 12), (1817, 12), (1818, 12), (1819, 12), (1820, 12), (1821, 12), (1822, 12), (1823, 12), (1824, 12), (1825, 12), (1826, 12), (1827, 12), (1828, 12), (1829, 12), (1830, 12), (1831, 12), (1832, 12), (1833, 12), (1834, 12), (1835, 12), (1836, 12), (1837, 12), (1838, 12), (1839, 12), (1840, 12), (1841, 12), (1842, 12), (1843, 12), (1844, 12), (1845, 12), (1846, 12), (1847, 12), (1848, 12), (1849, 12), (1850, 12), (1851, 12), (1852, 12), (1853, 12), (1854, 12), (1855, 12), (1856, 12), (1857, 12), (1858, 12), (1859, 12), (1860, 12), (1861, 12), (1862, 12), (1863, 12), (1864, 12), (1865, 12), (1866, 12), (1867, 12), (1868, 12), (1869, 12), (1870, 12), (1871, 12), (1872, 12), (1873, 12), (1874, 12), (1875, 12), (1876, 12), (1877, 12), (1878, 12), (1879, 12), (1880, 12), (1881, 12), (1882, 12), (1883, 12), (1884, 12), (1885, 12), (1886, 12), (1887, 12), (1888, 12), (1889, 12), (1890, 12), (1891, 12), (1892, 12), (1893, 12), (1894, 12), (1895, 12), (1896, 12), (1897, 12), (1898, 12), (1899, 12), (1900, 12), (1901, 12), (1902, 12), (1903, 12), (1904, 12), (1905, 12), (1906, 12), (1907, 12), (1908, 12), (1909, 12), (1910, 12), (1911, 12), (1912, 12), (1913, 12), (1914, 12), (1915, 12), (1916, 12), (1917, 12), (1918, 12), (1919, 12), (1920, 12), (1921, 12), (1922, 12), (1923, 12), (1924, 12), (1925, 12), (1926, 12), (1927, 12), (1928, 12), (1929, 12), (1930, 12), (1931, 12), (1932, 12), (1933, 12), (1934, 12), (1935, 12), (1936, 12), (1937, 12), (1938, 12), (1939, 12), (1940, 12), (1941, 12), (1942, 12), (1943, 12), (1944, 12), (1945, 12), (1946, 12), (1947, 12), (1948, 12), (1949, 12), (1950, 12), (1951, 12), (1952, 12), (1953, 12), (1954, 12), (1955, 12), (1956, 12), (1957, 12), (1958, 12), (1959, 12), (1960, 12), (1961, 12), (1962, 12), (1963, 12), (1964, 12), (1965, 12), (1966, 12), (1967, 12), (1968, 12), (1969, 12), (1970, 12), (1971, 12), (1972, 12), (1973, 12), (1974, 12), (1975, 12), (1976, 12), (1977, 12), (1978, 12), (1979, 12), (1980, 12), (1981, 12), (1982, 12), (1983, 12), (1984, 12), (1985, 12), (1986, 12), (1987, 12), (1988, 12), (1989, 12), (1990, 12), (1991, 12), (1992, 12), (1993, 12), (1994, 12), (1995, 12), (1996, 12), (1997, 12), (1998, 12), (1999, 12), (2000, 12), (2001, 12), (2002, 12), (2003, 12), (2004, 12), (2005, 12), (2006, 12), (2007, 12), (2008, 12), (2009, 12), (2010, 12), (2011, 12), (2012, 12), (2013, 12), (2014, 12), (2015, 12), (2016, 12), (2017, 12), (2018, 12), (2019, 12), (2020, 12), (2021, 12), (2022, 12), (2023, 12), (2024, 12), (2025, 12), (2026, 12), (2027, 12), (2028, 12), (2029, 12), (2030, 12), (2031, 12), (2032, 12), (2033, 12), (2034, 12), (2035, 12), (2036, 12), (2037, 12), (2038, 12), (2039, 12), (2040, 12), (2041, 12), (2042, 12), (2043, 12), (2044, 12), (2045, 12), (2046, 12), (2047, 12), (2048, 12), (2049, 12), (2050, 12), (2051, 12), (2052, 12), (2053, 12), (2054, 12), (2055, 12), (2056, 12), (2057, 12), (2058, 12), (2059, 12), (2060, 12), (2061, 12), (2062, 12), (2063, 12), (2064, 12), (2065, 12), (2066, 12), (2067, 12), (2068, 12), (2069, 12), (2070, 12), (2071, 12), (2072, 12), (2073, 12), (2074, 12), (2075, 12), (2076, 12), (2077, 12), (2078, 12), (2079, 12), (2080, 12), (2081, 12), (2082, 12), (2083, 12), (2084, 12), (2085, 12), (2086, 12), (2087, 12), (2088, 12), (2089, 12), (2090, 12), (2091, 12), (2092, 12), (2093, 12), (2094, 12), (2095, 12), (2096, 12), (2097, 12), (2098, 12), (2099, 12), (2100, 12), (2101, 12), (2102, 12), (2103, 12), (2104, 12), (2105, 12), (2106, 12), (2107, 12), (2108, 12), (2109, 12), (2110, 12), (2111, 12), (2112, 12), (2113, 12), (2114, 12), (2115, 12), (2116, 12), (2117, 12), (2118, 12), (2119, 12), (2120, 12), (2121, 12), (2122, 12), (2123, 12), (2124, 12), (2125, 12), (2126, 12), (2127, 12), (2128, 12), (2129, 12), (2130, 12), (2131, 12), (2132, 12), (2133, 12), (2134, 12), (2135, 12), (2136, 12), (2137, 12), (2138, 12), (2139, 12), (2140, 12), (2141, 12), (2142, 12), (2143, 12), (2144, 12), (2145, 12), (2146, 12), (2147, 12), (2148, 12), (2149, 12), (2150, 12), (2151, 12), (2152, 12), (2153, 12), (2154, 12), (2155, 12), (2156, 12), (2157, 12), (2158, 12), (2159, 12), (2160, 12), (2161, 12), (2162, 12), (2163, 12), (2164, 12), (2165, 12), (2166, 12), (2167, 12), (2168, 12), (2169, 12), (2170, 12), (2171, 12), (2172, 12), (2173, 12), (2174, 12), (2175, 12), (2176, 12), (2177, 12), (2178, 12), (2179, 12), (2180, 12), (2181, 12), (2182, 12), (2183, 12), (2184, 12), (2185, 12), (2186, 12), (2187, 12), (2188, 12), (2189, 12), (2190, 12), (2191, 12), (2192, 12), (2193, 12), (2194, 12), (2195, 12), (2196, 12), (2197, 12), (2198, 12), (2199, 12), (2200, 12), (2201, 12), (2202, 12), (2203, 12), (2204, 12), (2205, 12), (2206, 12), (2207, 12), (2208, 12), (2209, 12), (2210, 12), (2211, 12), (2212, 12), (2213, 12), (2214, 12), (2215, 12), (2216, 12), (2217, 12), (2218, 12), (2219, 12), (2220, 12), (2221, 12), (2222, 12), (2223, 12), (2224, 12), (2225, 12), (2226, 12), (2227, 12), (2228, 12), (2229, 12), (2230, 12), (2231, 12), (2232, 12), (2233, 12), (2234, 12), (2235, 12), (2236, 12), (2237, 12), (2238, 12), (2239, 12), (2240, 12), (2241, 12), (2242, 12), (2243, 12), (2244, 12), (2245, 12), (2246, 12), (2247, 12), (2248, 12), (2249, 12), (2250, 12), (2251, 12), (2252, 12), (2253, 12), (2254, 12), (2255, 12), (2256, 12), (2257, 12), (2258, 12), (2259, 12), (2260, 12), (2261, 12), (2262, 12), (2263, 12), (2264, 12), (2265, 12), (2266, 12), (2267, 12), (2268, 12), (2269, 12), (2270, 12), (2271, 12), (2272, 12), (2273, 12), (2274, 12), (2275, 12), (2276, 12), (2277, 12), (2278, 12), (2279, 12), (2280, 12), (2281, 12), (2282, 12), (2283, 12), (2284, 12), (2285, 12), (2286, 12), (2287, 12), (2288, 12), (2289, 12), (2290, 12), (2291, 12), (2292, 12), (2293, 12), (2294, 12), (2295, 12), (2296, 12), (2297, 12), (2298, 12), (2299, 12), (2300, 12), (2301, 12), (2302, 12), (2303, 12), (2304, 12), (2305, 12), (2306, 12), (2307, 12), (2308, 12), (2309, 12), (2310, 12), (2311, 12), (2312, 12), (2313, 12), (2314, 12), (2315, 12), (2316, 12), (2317, 12), (2318, 12), (2319, 12), (2320, 12), (2321, 12), (2322, 12), (2323, 12), (2324, 12), (2325, 12), (2326, 12), (2327, 12), (2328, 12), (2329, 12), (2330, 12), (2331, 12), (2332, 12), (2333, 12), (2334, 12), (2335, 12), (2336, 12), (2337, 12), (2338, 12), (2339, 12), (2340, 12), (2341, 12), (2342, 12), (2343, 12), (2344, 12), (2345, 12), (2346, 12), (2347, 12), (2348, 12), (2349, 12), (2350, 12), (2351, 12), (2352, 12), (2353, 12), (2354, 12), (2355, 12), (2356, 12), (2357, 12), (2358, 12), (2359, 12), (2360, 12), (2361, 12), (2362, 12), (2363, 12), (2364, 12), (2365, 12), (2366, 12), (2367, 12), (2368, 12), (2369, 12), (2370, 12), (2371, 12), (2372, 12), (2373, 12), (2374, 12), (2375, 12), (2376, 12), (2377, 12), (2378, 12), (2379, 12), (2380, 12), (2381, 12), (2382, 12), (2383, 12), (2384, 12), (2385, 12), (2386, 12), (2387, 12), (2388, 12), (2389, 12), (2390, 12), (2391, 12), (2392, 12), (2393, 12), (2394, 12), (2395, 12), (2396, 12), (2397, 12), (2398, 12), (2399, 12), (2400, 12), (2401, 12), (2402, 12), (2403, 12), (2404, 12), (2405, 12), (2406, 12), (2407, 12), (2408, 12), (2409, 12), (2410, 12), (2411, 12), (2412, 12), (2413, 12), (2414, 12), (2415, 12), (2416, 12), (2417, 12), (2418, 12), (2419, 12), (2420, 12), (2421, 12), (2422, 12), (2423, 12), (2424, 12), (2425, 12), (2426, 12), (2427, 12), (2428, 12), (2429, 12), (2430, 12), (2431, 12), (2432, 12), (2433, 12), (2434, 12), (2435, 12), (2436, 12), (2437, 12), (2438, 12), (2439, 12), (2440, 12), (2441, 12), (2442, 12), (2443, 12), (2444, 12), (2445, 12), (2446, 12), (2447, 12), (2448, 12), (2449, 12), (2450, 12), (2451, 12), (2452, 12), (2453, 12), (2454, 12), (2455, 12), (2456, 12), (2457, 12), (2458, 12), (2459, 12), (2460, 12), (2461, 12), (2462, 12), (2463, 12), (2464, 12), (2465, 12), (2466, 12), (2467, 12), (2468, 12), (2469, 12), (2470, 12), (2471, 12), (2472, 12), (2473, 12), (2474, 12), (2475, 12), (2476, 12), (2477, 12), (2478, 12), (2479, 12), (2480, 12), (2481, 12), (2482, 12), (2483, 12), (2484, 12), (2485, 12), (2486, 12), (2487, 12), (2488, 12), (2489, 12), (2490, 12), (2491, 12), (2492, 12), (2493, 12), (2494, 12), (2495, 12), (2496, 12), (2497, 12), (2498, 12), (2499, 12), (2500, 12), (2501, 12), (2502, 12), (2503, 12), (2504, 12), (2505, 12), (2506, 12), (2507, 12), (2508, 12), (2509, 12), (2510, 12), (2511, 12), (2512, 12), (2513, 12), (2514, 12), (2515, 12), (2516, 12), (2517, 12), (2518, 12), (2519, 12), (2520, 12), (2521, 12), (2522, 12), (2523, 12), (2524, 12), (2525, 12), (2526, 12), (2527, 12), (2528, 12), (2529, 12), (2530, 12), (2531, 12), (2532, 12), (2533, 12), (2534, 12), (2535, 12), (2536, 12), (2537, 12), (2538, 12), (2539, 12), (2540, 12), (2541, 12), (2542, 12), (2543, 12), (2544, 12), (2545, 12), (2546, 12), (2547, 12), (2548, 12), (2549, 12), (2550, 12), (2551, 12), (2552, 12), (2553, 12), (2554, 12), (2555, 12), (2556, 12), (2557, 12), (2558, 12), (2559, 12), (2560, 12), (2561, 12), (2562, 12), (2563, 12), (2564, 12), (2565, 12), (2566, 12), (2567, 12), (2568, 12), (2569, 12), (2570, 12), (2571, 12), (2572, 12), (2573, 12), (2574, 12), (2575, 12), (2576, 12), (2577, 12), (2578, 12), (2579, 12), (2580, 12), (2581, 12), (2582, 12), (2583, 12), (2584, 12), (2585, 12), (2586, 12), (2587, 12), (2588, 12), (2589, 12), (2590, 12), (2591, 12), (2592, 12), (2593, 12), (2594, 12), (2595, 12), (2596, 12), (2597, 12), (2598, 12), (2599, 12), (2600, 12), (2601, 12), (2602, 12), (2603, 12), (2604, 12), (2605, 12), (2606, 12), (2607, 12), (2608, 12), (2609, 12), (2610, 12), (2611, 12), (2612, 12), (2613, 12), (2614, 12), (2615, 12), (2616, 12), (2617, 12), (2618, 12), (2619, 12), (2620, 12), (2621, 12), (2622, 12), (2623, 12), (2624, 12), (2625, 12), (2626, 12), (2627, 12), (2628, 12), (2629, 12), (2630, 12), (2631, 12), (2632, 12), (2633, 12), (2634, 12), (2635, 12), (2636, 12), (2637, 12), (2638, 12), (2639, 12), (2640, 12), (2641, 12), (2642, 12), (2643, 12), (2644, 12), (2645, 12), (2646, 12), (2647, 12), (2648, 12), (2649, 12), (2650, 12), (2651, 12), (2652, 12), (2653, 12), (2654, 12), (2655, 12), (2656, 12), (2657, 12), (2658, 12), (2659, 12), (2660, 12), (2661, 12), (2662, 12), (2663, 12), (2664, 12), (2665, 12), (2666, 12), (2667, 12), (2668, 12), (2669, 12), (2670, 12), (2671, 12), (2672, 12), (2673, 12), (2674, 12), (2675, 12), (2676, 12), (2677, 12), (2678, 12), (2679, 12), (2680, 12), (2681, 12), (2682, 12), (2683, 12), (2684, 12), (2685, 12), (2686, 12), (2687, 12), (2688, 12), (2689, 12), (2690, 12), (2691, 12), (2692, 12), (2693, 12), (2694, 12), (2695, 12), (2696, 12), (2697, 12), (2698, 12), (2699, 12), (2700, 12), (2701, 12), (2702, 12), (2703, 12), (2704, 12), (2705, 12), (2706, 12), (2707, 12), (2708, 12), (2709, 12), (2710, 12), (2711, 12), (2712, 12), (2713, 12), (2714, 12), (2715, 12), (2716, 12), (2717, 12), (2718, 12), (2719, 12), (2720, 12), (2721, 12), (2722, 12), (2723, 12), (2724, 12), (2725, 12), (2726, 12), (2727, 12), (2728, 12), (2729, 12), (2730, 12), (2731, 12), (2732, 12), (2733, 12), (2734, 12), (2735, 12), (2736, 12), (2737, 12), (2738, 12), (2739, 12), (2740, 12), (2741, 12), (2742, 12), (2743, 12), (2744, 12), (2745, 12), (2746, 12), (2747, 12), (2748, 12), (2749, 12), (2750, 12), (2751, 12), (2752, 12), (2753, 12), (2754, 12), (2755, 12), (2756, 12), (2757, 12), (2758, 12), (2759, 12), (2760, 12), (2761, 12), (2762, 12), (2763, 12), (2764, 12), (2765, 12), (2766, 12), (2767, 12), (2768, 12), (2769, 12), (2770, 12), (2771, 12), (2772, 12), (2773, 12), (2774, 12), (2775, 12), (2776, 12), (2777, 12), (2778, 12), (2779, 12), (2780, 12), (2781, 12), (2782, 12), (2783, 12), (2784, 12), (2785, 12), (2786, 12), (2787, 12), (2788, 12), (2789, 12), (2790, 12), (2791, 12), (2792, 12), (2793, 12), (2794, 12), (2795, 12), (2796, 12), (2797, 12), (2798, 12), (2799, 12), (2800, 12), (2801, 12), (2802, 12), (2803, 12), (2804, 12), (2805, 12), (2806, 12), (2807, 12), (2808, 12), (2809, 12), (2810, 12), (2811, 12), (2812, 12), (2813, 12), (2814, 12), (2815, 12), (2816, 12), (2817, 12), (2818, 12), (2819, 12), (2820, 12), (2821, 12), (2822, 12), (2823, 12), (2824, 12), (2825, 12), (2826, 12), (2827, 12), (2828, 12), (2829, 12), (2830, 12), (2831, 12), (2832, 12), (2833, 12), (2834, 12), (2835, 12), (2836, 12), (2837, 12), (2838, 12), (2839, 12), (2840, 12), (2841, 12), (2842, 12), (2843, 12), (2844, 12), (2845, 12), (2846, 12), (2847, 12), (2848, 12), (2849, 12), (2850, 12), (2851, 12), (2852, 12), (2853, 12), (2854, 12), (2855, 12), (2856, 12), (2857, 12), (2858, 12), (2859, 12), (2860, 12), (2861, 12), (2862, 12), (2863, 12), (2864, 12), (2865, 12), (2866, 12), (2867, 12), (2868, 12), (2869, 12), (2870, 12), (2871, 12), (2872, 12), (2873, 12), (2874, 12), (2875, 12), (2876, 12), (2877, 12), (2878, 12), (2879, 12), (2880, 12), (2881, 12), (2882, 12), (2883, 12), (2884, 12), (2885, 12), (2886, 12), (2887, 12), (2888, 12), (2889, 12), (2890, 12), (2891, 12), (2892, 12), (2893, 12), (2894, 12), (2895, 12), (2896, 12), (2897, 12), (2898, 12), (2899, 12), (2900, 12), (2901, 12), (2902, 12), (2903, 12), (2904, 12), (2905, 12), (2906, 12), (2907, 12), (2908, 12), (2909, 12), (2910, 12), (2911, 12), (2912, 12), (2913, 12), (2914, 12), (2915, 12), (2916, 12), (2917, 12), (2918, 12), (2919, 12), (2920, 12), (2921, 12), (2922, 12), (2923, 12), (2924, 12), (2925, 12), (2926, 12), (2927, 12), (2928, 12), (2929, 12), (2930, 12), (2931, 12), (2932, 12), (2933, 12), (2934, 12), (2935, 12), (2936, 12), (2937, 12), (2938, 12), (2939, 12), (2940, 12), (2941, 12), (2942, 12), (2943, 12), (2944, 12), (2945, 12), (2946, 12), (2947, 12), (2948, 12), (2949, 12), (2950, 12), (2951, 12), (2952, 12), (2953, 12), (2954, 12), (2955, 12), (2956, 12), (2957, 12), (2958, 12), (2959, 12), (2960, 12), (2961, 12), (2962, 12), (2963, 12), (2964, 12), (2965, 12), (2966, 12), (2967, 12), (2968, 12), (2969, 12), (2970, 12), (2971, 12), (2972, 12), (2973, 12), (2974, 12), (2975, 12), (2976, 12), (2977, 12), (2978, 12), (2979, 12), (2980, 12), (2981, 12), (2982, 12), (2983, 12), (2984, 12), (2985, 12), (2986, 12), (2987, 12), (2988, 12), (2989, 12), (2990, 12), (2991, 12), (2992, 12), (2993, 12), (2994, 12), (2995, 12), (2996, 12), (2997, 12), (2998, 12), (2999, 12), (3000, 12), (3001, 12), (3002, 12), (3003, 12), (3004, 12), (3005, 12), (3006, 12), (3007, 12), (3008, 12), (3009, 12), (3010, 12), (3011, 12), (3012, 12), (3013, 12), (3014, 12), (3015, 12), (3016, 12), (3017, 12), (3018, 12), (3019, 12), (3020, 12), (3021, 12), (3022, 12), (3023, 12), (3024, 12), (3025, 12), (3026, 12), (3027, 12), (3028, 12), (3029, 12), (3030, 12), (3031, 12), (3032, 12), (3033, 12), (3034, 12), (3035, 12), (3036, 12), (3037, 12), (3038, 12), (3039, 12), (3040, 12), (3041, 12), (3042, 12), (3043, 12), (3044, 12), (3045, 12), (3046, 12), (3047, 12), (3048, 12), (3049, 12), (3050, 12), (3051, 12), (3052, 12), (3053, 12), (3054, 12), (3055, 12), (3056, 12), (3057, 12), (3058, 12), (3059, 12), (3060, 12), (3061, 12), (3062, 12), (3063, 12), (3064, 12), (3065, 12), (3066, 12), (3067, 12), (3068, 12), (3069, 12), (3070, 12), (3071, 12), (3072, 12), (3073, 12), (3074, 12), (3075, 12), (3076, 12), (3077, 12), (3078, 12), (3079, 12), (3080, 12), (3081, 12), (3082, 12), (3083, 12), (3084, 12), (3085, 12), (3086, 12), (3087, 12), (3088, 12), (3089, 12), (3090, 12), (3091, 12), (3092, 12), (3093, 12), (3094, 12), (3095, 12), (3096, 12), (3097, 12), (3098, 12), (3099, 12), (3100, 12), (3101, 12), (3102, 12), (3103, 12), (3104, 12), (3105, 12), (3106, 12), (3107, 12), (3108, 12), (3109, 12), (3110, 12), (3111, 12), (3112, 12), (3113, 12), (3114, 12), (3115, 12), (3116, 12), (3117, 12), (3118, 12), (3119, 12), (3120, 12), (3121, 12), (3122, 12), (3123, 12), (3124, 12), (3125, 12), (3126, 12), (3127, 12), (3128, 12), (3129, 12), (3130, 12), (3131, 12), (3132, 12), (3133, 12), (3134, 12), (3135, 12), (3136, 12), (3137, 12), (3138, 12), (3139, 12), (3140, 12), (3141, 12), (3142, 12), (3143, 12), (3144, 12), (3145, 12), (3146, 12), (3147, 12), (3148, 12), (3149, 12), (3150, 12), (3151, 12), (3152, 12), (3153, 12), (3154, 12), (3155, 12), (3156, 12), (3157, 12), (3158, 12), (3159, 12), (3160, 12), (3161, 12), (3162, 12), (3163, 12), (3164, 12), (3165, 12), (3166, 12), (3167, 12), (3168, 12), (3169, 12), (3170, 12), (3171, 12), (3172, 12), (3173, 12), (3174, 12), (3175, 12), (3176, 12), (3177, 12), (3178, 12), (3179, 12), (3180, 12), (3181, 12), (3182, 12), (3183, 12), (3184, 12), (3185, 12), (3186, 12), (3187, 12), (3188, 12), (3189, 12), (3190, 12), (3191, 12), (3192, 12), (3193, 12), (3194, 12), (3195, 12), (3196, 12), (3197, 12), (3198, 12), (3199, 12), (3200, 12), (3201, 12), (3202, 12), (3203, 12), (3204, 12), (3205, 12), (3206, 12), (3207, 12), (3208, 12), (3209, 12), (3210, 12), (3211, 12), (3212, 12), (3213, 12), (3214, 12), (3215, 12), (3216, 12), (3217, 12), (3218, 12), (3219, 12), (3220, 12), (3221, 12), (3222, 12), (3223, 12), (3224, 12), (3225, 12), (3226, 12), (3227, 12), (3228, 12), (3229, 12), (3230, 12), (3231, 12), (3232, 12), (3233, 12), (3234, 12), (3235, 12), (3236, 12), (3237, 12), (3238, 12), (3239, 12), (3240, 12), (3241, 12), (3242, 12), (3243, 12), (3244, 12), (3245, 12), (3246, 12), (3247, 12), (3248, 12), (3249, 12), (3250, 12), (3251, 12), (3252, 12), (3253, 12), (3254, 12), (3255, 12), (3256, 12), (3257, 12), (3258, 12), (3259, 12), (3260, 12), (3261, 12), (3262, 12), (3263, 12), (3264, 12), (3265, 12), (3266, 12), (3267, 12), (3268, 12), (3269, 12), (3270, 12), (3271, 12), (3272, 12), (3273, 12), (3274, 12), (3275, 12), (3276, 12), (3277, 12), (3278, 12), (3279, 12), (3280, 12), (3281, 12), (3282, 12), (3283, 12), (3284, 12), (3285, 12), (3286, 12), (3287, 12), (3288, 12), (3289, 12), (3290, 12), (3291, 12), (3292, 12), (3293, 12), (3294, 12), (3295, 12), (3296, 12), (3297, 12), (3298, 12), (3299, 12), (3300, 12), (3301, 12), (3302, 12), (3303, 12), (3304, 12), (3305, 12), (3306, 12), (3307, 12), (3308, 12), (3309, 12), (3310, 12), (3311, 12), (3312, 12), (3313, 12), (3314, 12), (3315, 12), (3316, 12), (3317, 12), (3318, 12), (3319, 12), (3320, 12), (3321, 12), (3322, 12), (3323, 12), (3324, 12), (3325, 12), (3326, 12), (3327, 12), (3328, 12), (3329, 12), (3330, 12), (3331, 12), (3332, 12), (3333, 12), (3334, 12), (3335, 12), (3336, 12), (3337, 12), (3338, 12), (3339, 12), (3340, 12), (3341, 12), (3342, 12), (3343, 12), (3344, 12), (3345, 12), (3346, 12), (3347, 12), (3348, 12), (3349, 12), (3350, 12), (3351, 12), (3352, 12), (3353, 12), (3354, 12), (3355, 12), (3356, 12), (3357, 12), (3358, 12), (3359, 12), (3360, 12), (3361, 12), (3362, 12), (3363, 12), (3364, 12), (3365, 12), (3366, 12), (3367, 12), (3368, 12), (3369, 12), (3370, 12), (3371, 12), (3372, 12), (3373, 12), (3374, 12), (3375, 12), (3376, 12), (3377, 12), (3378, 12), (3379, 12), (3380, 12), (3381, 12), (3382, 12), (3383, 12), (3384, 12), (3385, 12), (3386, 12), (3387, 12), (3388, 12), (3389, 12), (3390, 12), (3391, 12), (3392, 12), (3393, 12), (3394, 12), (3395, 12), (3396, 12), (3397, 12), (3398, 12), (3399, 12), (3400, 12), (3401, 12), (3402, 12), (3403, 12), (3404, 12), (3405, 12), (3406, 12), (3407, 12), (3408, 12), (3409, 12), (3410, 12), (3411, 12), (3412, 12), (3413, 12), (3414, 12), (3415, 12), (3416, 12), (3417, 12), (3418, 12), (3419, 12), (3420, 12), (3421, 12), (3422, 12), (3423, 12), (3424, 12), (3425, 12), (3426, 12), (3427, 12), (3428, 12), (3429, 12), (3430, 12), (3431, 12), (3432, 12), (3433, 12), (3434, 12), (3435, 12), (3436, 12), (3437, 12), (3438, 12), (3439, 12), (3440, 12), (3441, 12), (3442, 12), (3443, 12), (3444, 12), (3445, 12), (3446, 12), (3447, 12), (3448, 12), (3449, 12), (3450, 12), (3451, 12), (3452, 12), (3453, 12), (3454, 12), (3455, 12), (3456, 12), (3457, 12), (3458, 12), (3459, 12), (3460, 12), (3461, 12), (3462, 12), (3463, 12), (3464, 12), (3465, 12), (3466, 12), (3467, 12), (3468, 12), (3469, 12), (3470, 12), (3471, 12), (3472, 12), (3473, 12), (3474, 12), (3475, 12), (3476, 12), (3477, 12), (3478, 12), (3479, 12), (3480, 12), (3481, 12), (3482, 12), (3483, 12), (3484, 12), (3485, 12), (3486, 12), (3487, 12), (3488, 12), (3489, 12), (3490, 12), (3491, 12), (3492, 12), (3493, 12), (3494, 12), (3495, 12), (3496, 12), (3497, 12), (3498, 12), (3499, 12), (3500, 12), (3501, 12), (3502, 12), (3503, 12), (3504, 12), (3505, 12), (3506, 12), (3507, 12), (3508, 12), (3509, 12), (3510, 12), (3511, 12), (3512, 12), (3513, 12), (3514, 12), (3515, 12), (3516, 12), (3517, 12), (3518, 12), (3519, 12), (3520, 12), (3521, 12), (3522, 12), (3523, 12), (3524, 12), (3525, 12), (3526, 12), (3527, 12), (3528, 12), (3529, 12), (3530, 12), (3531, 12), (3532, 12), (3533, 12), (3534, 12), (3535, 12), (3536, 12), (3537, 12), (3538, 12), (3539, 12), (3540, 12), (3541, 12), (3542, 12), (3543, 12), (3544, 12), (3545, 12), (3546, 12), (3547, 12), (3548, 12), (3549, 12), (3550, 12), (3551, 12), (3552, 12), (3553, 12), (3554, 12), (3555, 12), (3556, 12), (3557, 12), (3558, 12), (3559, 12), (3560, 12), (3561, 12), (3562, 12), (3563, 12), (3564, 12), (3565, 12), (3566, 12), (3567, 12), (3568, 12), (3569, 12), (3570, 12), (3571, 12), (3572, 12), (3573, 12), (3574, 12), (3575, 12), (3576, 12), (3577, 12), (3578, 12), (3579, 12), (3580, 12), (3581, 12), (3582, 12), (3583, 12), (3584, 12), (3585, 12), (3586, 12), (3587, 12), (3588, 12), (3589, 12), (3590, 12), (3591, 12), (3592, 12), (3593, 12), (3594, 12), (3595, 12), (3596, 12), (3597, 12), (3598, 12), (3599, 12), (3600, 12), (3601, 12), (3602, 12), (3603, 12), (3604, 12), (3605, 12), (3606, 12), (3607, 12), (3608, 12), (3609, 12), (3610, 12), (3611, 12), (3612, 12), (3613, 12), (3614, 12), (3615, 12), (3616, 12), (3617, 12), (3618, 12), (3619, 12), (3620, 12), (3621, 12), (3622, 12), (3623, 12), (3624, 12), (3625, 12), (3626, 12), (3627, 12), (3628, 12), (3629, 12), (3630, 12), (3631, 12), (3632, 12), (3633, 12), (3634, 12), (3635, 12), (3636, 12), (3637, 12), (3638, 12), (3639, 12), (3640, 12), (3641, 12), (3642, 12), (3643, 12), (3644, 12), (3645, 12), (3646, 12), (3647, 12), (3648, 12), (3649, 12), (3650, 12), (3651, 12), (3652, 12), (3653, 12), (3654, 12), (3655, 12), (3656, 12), (3657, 12), (3658, 12), (3659, 12), (3660, 12), (3661, 12), (3662, 12), (3663, 12), (3664, 12), (3665, 12), (3666, 12), (3667, 12), (3668, 12), (3669, 12), (3670, 12), (3671, 12), (3672, 12), (3673, 12), (3674, 12), (3675, 12), (3676, 12), (3677, 12), (3678, 12), (3679, 12), (3680, 12), (3681, 12), (3682, 12), (3683, 12), (3684, 12), (3685, 12), (3686, 12), (3687, 12), (3688, 12), (3689, 12), (3690, 12), (3691, 12), (3692, 12), (3693, 12), (3694, 12), (3695, 12), (3696, 12), (3697, 12), (3698, 12), (3699, 12), (3700, 12), (3701, 12), (3702, 12), (3703, 12), (3704, 12), (3705, 12), (3706, 12), (3707, 12), (3708, 12), (3709, 12), (3710, 12), (3711, 12), (3712, 12), (3713, 12), (3714, 12), (3715, 12), (3716, 12), (3717, 12), (3718, 12), (3719, 12), (3720, 12), (3721, 12), (3722, 12), (3723, 12), (3724, 12), (3725, 12), (3726, 12), (3727, 12), (3728, 12), (3729, 12), (3730, 12), (3731, 12), (3732, 12), (3733, 12), (3734, 12), (3735, 12), (3736, 12), (3737, 12), (3738, 12), (3739, 12), (3740, 12), (3741, 12), (3742, 12), (3743, 12), (3744, 12), (3745, 12), (3746, 12), (3747, 12), (3748, 12), (3749, 12), (3750, 12), (3751, 12), (3752, 12), (3753, 12), (3754, 12), (3755, 12), (3756, 12), (3757, 12), (3758, 12), (3759, 12), (3760, 12), (3761, 12), (3762, 12), (3763, 12), (3764, 12), (3765, 12), (3766, 12), (3767, 12), (3768, 12), (3769, 12), (3770, 12), (3771, 12), (3772, 12), (3773, 12), (3774, 12), (3775, 12), (3776, 12), (3777, 12), (3778, 12), (3779, 12), (3780, 12), (3781, 12), (3782, 12), (3783, 12), (3784, 12), (3785, 12), (3786, 12), (3787, 12), (3788, 12), (3789, 12), (3790, 12), (3791, 12), (3792, 12), (3793, 12), (3794, 12), (3795, 12), (3796, 12), (3797, 12), (3798, 12), (3799, 12), (3800, 12), (3801, 12), (3802, 12), (3803, 12), (3804, 12), (3805, 12), (3806, 12), (3807, 12), (3808, 12), (3809, 12), (3810, 12), (3811, 12), (3812, 12), (3813, 12), (3814, 12), (3815, 12), (3816, 12), (3817, 12), (3818, 12), (3819, 12), (3820, 12), (3821, 12), (3822, 12), (3823, 12), (3824, 12), (3825, 12), (3826, 12), (3827, 12), (3828, 12), (3829, 12), (3830, 12), (3831, 12), (3832, 12), (3833, 12), (3834, 12), (3835, 12), (3836, 12), (3837, 12), (3838, 12), (3839, 12), (3840, 12), (3841, 12), (3842, 12), (3843, 12), (3844, 12), (3845, 12), (3846, 12), (3847, 12), (3848, 12), (3849, 12), (3850, 12), (3851, 12), (3852, 12), (3853, 12), (3854, 12), (3855, 12), (3856, 12), (3857, 12), (3858, 12), (3859, 12), (3860, 12), (3861, 12), (3862, 12), (3863, 12), (3864, 12), (3865, 12), (3866, 12), (3867, 12), (3868, 12), (3869, 12), (3870, 12), (3871, 12), (3872, 12), (3873, 12), (3874, 12), (3875, 12), (3876, 12), (3877, 12), (3878, 12), (3879, 12), (3880, 12), (3881, 12), (3882, 12), (3883, 12), (3884, 12), (3885, 12), (3886, 12), (3887, 12), (3888, 12), (3889, 12), (3890, 12), (3891, 12), (3892, 12), (3893, 12), (3894, 12), (3895, 12), (3896, 12), (3897, 12), (3898, 12), (3899, 12), (3900, 12), (3901, 12), (3902, 12), (3903, 12), (3904, 12), (3905, 12), (3906, 12), (3907, 12), (3908, 12), (3909, 12), (3910, 12), (3911, 12), (3912, 12), (3913, 12), (3914, 12), (3915, 12), (3916, 12), (3917, 12), (3918, 12), (3919, 12), (3920, 12), (3921, 12), (3922, 12), (3923, 12), (3924, 12), (3925, 12), (3926, 12), (3927, 12), (3928, 12), (3929, 12), (3930, 12), (3931, 12), (3932, 12), (3933, 12), (3934, 12), (3935, 12), (3936, 12), (3937, 12), (3938, 12), (3939, 12), (3940, 12), (3941, 12), (3942, 12), (3943, 12), (3944, 12), (3945, 12), (3946, 12), (3947, 12), (3948, 12), (3949, 12), (3950, 12), (3951, 12), (3952, 12), (3953, 12), (3954, 12), (3955, 12), (3956, 12), (3957, 12), (3958, 12), (3959, 12), (3960, 12), (3961, 12), (3962, 12), (3963, 12), (3964, 12), (3965, 12), (3966, 12), (3967, 12), (3968, 12), (3969, 12), (3970, 12), (3971, 12), (3972, 12), (3973, 12), (3974, 12), (3975, 12), (3976, 12), (3977, 12), (3978, 12), (3979, 12), (3980, 12), (3981, 12), (3982, 12), (3983, 12), (3984, 12), (3985, 12), (3986, 12), (3987, 12), (3988, 12), (3989, 12), (3990, 12), (3991, 12), (3992, 12), (3993, 12), (3994, 12), (3995, 12), (3996, 12), (3997, 12), (3998, 12), (3999, 12), (4000, 12), (4001, 12), (4002, 12), (4003, 12), (4004, 12), (4005, 12), (4006, 12), (4007, 12), (4008, 12), (4009, 12), (4010, 12), (4011, 12), (4012, 12), (4013, 12), (4014, 12), (4015, 12), (4016, 12), (4017, 12), (4018, 12), (4019, 12), (4020, 12), (4021, 12), (4022, 12), (4023, 12), (4024, 12), (4025, 12), (4026, 12), (4027, 12), (4028, 12), (4029, 12), (4030, 12), (4031, 12), (4032, 12), (4033, 12), (4034, 12), (4035, 12), (4036, 12), (4037, 12), (4038, 12), (4039, 12), (4040, 12), (4041, 12), (4042, 12), (4043, 12), (4044, 12), (4045, 12), (4046, 12), (4047, 12), (4048, 12), (4049, 12), (4050, 12), (4051, 12), (4052, 12), (4053, 12), (4054, 12), (4055, 12), (4056, 12), (4057, 12), (4058, 12), (4059, 12), (4060, 12), (4061, 12), (4062, 12), (4063, 12), (4064, 12), (4065, 12), (4066, 12), (4067, 12), (4068, 12), (4069, 12), (4070, 12), (4071, 12), (4072, 12), (4073, 12), (4074, 12), (4075, 12), (4076, 12), (4077, 12), (4078, 12), (4079, 12), (4080, 12), (4081, 12), (4082, 12), (4083, 12), (4084, 12), (4085, 12), (4086, 12), (4087, 12), (4088, 12), (4089, 12), (4090, 12), (4091, 12), (4092, 12), (4093, 12), (4094, 12), (4095, 12), (0, 12), (1, 12), (2, 12), (3, 12), (4, 12), (5, 12), (6, 12), (7, 12), (8, 12), (9, 12), (10, 12), (11, 12), (12, 12), (13, 12), (14, 12), (15, 12), (16, 12), (17, 12), (18, 12), (19, 12), (20, 12), (21, 12), (22, 12), (23, 12), (24, 12), (25, 12), (26, 12), (27, 12), (28, 12), (29, 12), (30, 12), (31, 12), (32, 12), (33, 12), (34, 12), (35, 12), (36, 12), (37, 12), (38, 12), (39, 12), (40, 12), (41, 12), (42, 12), (43, 12), (44, 12), (45, 12), (46, 12), (47, 12), (48, 12), (49, 12), (50, 12), (51, 12), (52, 12), (53, 12), (54, 12), (55, 12), (56, 12), (57, 12), (58, 12), (59, 12), (60, 12), (61, 12), (62, 12), (63, 12), (64, 12), (65, 12), (66, 12), (67, 12), (68, 12), (69, 12), (70, 12), (71, 12), (72, 12), (73, 12), (74, 12), (75, 12), (76, 12), (77, 12), (78, 12), (79, 12), (80, 12), (81, 12), (82, 12), (83, 12), (84, 12), (85, 12), (86, 12), (87, 12), (88, 12), (89, 12), (90, 12), (91, 12), (92, 12), (93, 12), (94, 12), (95, 12), (96, 12), (97, 12), (98, 12), (99, 12), (100, 12), (101, 12), (102, 12), (103, 12), (104, 12), (105, 12), (106, 12), (107, 12), (108, 12), (109, 12), (110, 12), (111, 12), (112, 12), (113, 12), (114, 12), (115, 12), (116, 12), (117, 12), (118, 12), (119, 12), (120, 12), (121, 12), (122, 12), (123, 12), (124, 12), (125, 12), (126, 12), (127, 12), (128, 12), (129, 12), (130, 12), (131, 12), (132, 12), (133, 12), (134, 12), (135, 12), (136, 12), (137, 12), (138, 12), (139, 12), (140, 12), (141, 12), (142, 12), (143, 12), (144, 12), (145, 12), (146, 12), (147, 12), (148, 12), (149, 12), (150, 12), (151, 12), (152, 12), (153, 12), (154, 12), (155, 12), (156, 12), (157, 12), (158, 12), (159, 12), (160, 12), (161, 12), (162, 12), (163, 12), (164, 12), (165, 12), (166, 12), (167, 12), (168, 12), (169, 12), (170, 12), (171, 12), (172, 12), (173, 12), (174, 12), (175, 12), (176, 12), (177, 12), (178, 12), (179, 12), (180, 12), (181, 12), (182, 12), (183, 12), (184, 12), (185, 12), (186, 12), (187, 12), (188, 12), (189, 12), (190, 12), (191, 12), (192, 12), (193, 12), (194, 12), (195, 12), (196, 12), (197, 12), (198, 12), (199, 12), (200, 12), (201, 12), (202, 12), (203, 12), (204, 12), (205, 12), (206, 12), (207, 12), (208, 12), (209, 12), (210, 12), (211, 12), (212, 12), (213, 12), (214, 12), (215, 12), (216, 12), (217, 12), (218, 12), (219, 12), (220, 12), (221, 12), (222, 12), (223, 12), (224, 12), (225, 12), (226, 12), (227, 12), (228, 12), (229, 12), (230, 12), (231, 12), (232, 12), (233, 12), (234, 12), (235, 12), (236, 12), (237, 12), (238, 12), (239, 12), (240, 12), (241, 12), (242, 12), (243, 12), (244, 12), (245, 12), (246, 12), (247, 12), (248, 12), (249, 12), (250, 12), (251, 12), (252, 12), (253, 12), (254, 12), (255, 12), (256, 12), (257, 12), (258, 12), (259, 12), (260, 12), (261, 12), (262, 12), (263, 12), (264, 12), (265, 12), (266, 12), (267, 12), (268, 12), (269, 12), (270, 12), (271, 12), (272, 12), (273, 12), (274, 12), (275, 12), (276, 12), (277, 12), (278, 12), (279, 12), (280, 12), (281, 12), (282, 12), (283, 12), (284, 12), (285, 12), (286, 12), (287, 12), (288, 12), (289, 12), (290, 12), (291, 12), (292, 12), (293, 12), (294, 12), (295, 12), (296, 12), (297, 12), (298, 12), (299, 12), (300, 12), (301, 12), (302, 12), (303, 12), (304, 12), (305, 12), (306, 12), (307, 12), (308, 12), (309, 12), (310, 12), (311, 12), (312, 12), (313, 12), (314, 12), (315, 12), (316, 12), (317, 12), (318, 12), (319, 12), (320, 12), (321, 12), (322, 12), (323, 12), (324, 12), (325, 12), (326, 12), (327, 12), (328, 12), (329, 12), (330, 12), (331, 12), (332, 12), (333, 12), (334, 12), (335, 12), (336, 12), (337, 12), (338, 12), (339, 12), (340, 12), (341, 12), (342, 12), (343, 12), (344, 12), (345, 12), (346, 12), (347, 12), (348, 12), (349, 12), (350, 12), (351, 12), (352, 12), (353, 12), (354, 12), (355, 12), (356, 12), (357, 12), (358, 12), (359, 12), (360, 12), (361, 12), (362, 12), (363, 12), (364, 12), (365, 12), (366, 12), (367, 12), (368, 12), (369, 12), (370, 12), (371, 12), (372, 12), (373, 12), (374, 12), (375, 12), (376, 12), (377, 12), (378, 12), (379, 12), (380, 12), (381, 12), (382, 12), (383, 12), (384, 12), (385, 12), (386, 12), (387, 12), (388, 12), (389, 12), (390, 12), (391, 12), (392, 12), (393, 12), (394, 12), (395, 12), (396, 12), (397, 12), (398, 12), (399, 12), (400, 12), (401, 12), (402, 12), (403, 12), (404, 12), (405, 12), (406, 12), (407, 12), (408, 12), (409, 12), (410, 12), (411, 12), (412, 12), (413, 12), (414, 12), (415, 12), (416, 12), (417, 12), (418, 12), (419, 12), (420, 12), (421, 12), (422, 12), (423, 12), (424, 12), (425, 12), (426, 12), (427, 12), (428, 12), (429, 12), (430, 12), (431, 12), (432, 12), (433, 12), (434, 12), (435, 12), (436, 12), (437, 12), (438, 12), (439, 12), (440, 12), (441, 12), (442, 12), (443, 12), (444, 12), (445, 12), (446, 12), (447, 12), (448, 12), (449, 12), (450, 12), (451, 12), (452, 12), (453, 12), (454, 12), (455, 12), (456, 12), (457, 12), (458, 12), (459, 12), (460, 12), (461, 12), (462, 12), (463, 12), (464, 12), (465, 12), (466, 12), (467, 12), (468, 12), (469, 12), (470, 12), (471, 12), (472, 12), (473, 12), (474, 12), (475, 12), (476, 12), (477, 12), (478, 12), (479, 12), (480, 12), (481, 12), (482, 12), (483, 12), (484, 12), (485, 12), (486, 12), (487, 12), (488, 12), (489, 12), (490, 12), (491, 12), (492, 12), (493, 12), (494, 12), (495, 12), (496, 12), (497, 12), (498, 12), (499, 12), (500, 12), (501, 12), (502, 12), (503, 12), (504, 12), (505, 12), (506, 12), (507, 12), (508, 12), (509, 12), (510, 12), (511, 12), (512, 12), (513, 12), (514, 12), (515, 12), (516, 12), (517, 12), (518, 12), (519, 12), (520, 12), (521, 12), (522, 12), (523, 12), (524, 12), (525, 12), (526, 12), (527, 12), (528, 12), (529, 12), (530, 12), (531, 12), (532, 12), (533, 12), (534, 12), (535, 12), (536, 12), (537, 12), (538, 12), (539, 12), (540, 12), (541, 12), (542, 12), (543, 12), (544, 12), (545, 12), (546, 12), (547, 12), (548, 12), (549, 12), (550, 12), (551, 12), (552, 12), (553, 12), (554, 12), (555, 12), (556, 12), (557, 12), (558, 12), (559, 12), (560, 12), (561, 12), (562, 12), (563, 12), (564, 12), (565, 12), (566, 12), (567, 12), (568, 12), (569, 12), (570, 12), (571, 12), (572, 12), (573, 12), (574, 12), (575, 12), (576, 12), (577, 12), (578, 12), (579, 12), (580, 12), (581, 12), (582, 12), (583, 12), (584, 12), (585, 12), (586, 12), (587, 12), (588, 12), (589, 12), (590, 12), (591, 12), (592, 12), (593, 12), (594, 12), (595, 12), (596, 12), (597, 12), (598, 12), (599, 12), (600, 12), (601, 12), (602, 12), (603, 12), (604, 12), (605, 12), (606, 12), (607, 12), (608, 12), (609, 12), (610, 12), (611, 12), (612, 12), (613, 12), (614, 12), (615, 12), (616, 12), (617, 12), (618, 12), (619, 12), (620, 12), (621, 12), (622, 12), (623, 12), (624, 12), (625, 12), (626, 12), (627, 12), (628, 12), (629, 12), (630, 12), (631, 12), (632, 12), (633, 12), (634, 12), (635, 12), (636, 12), (637, 12), (638, 12), (639, 12), (640, 12), (641, 12), (642, 12), (643, 12), (644, 12), (645, 12), (646, 12), (647, 12), (648, 12), (649, 12), (650, 12), (651, 12), (652, 12), (653, 12), (654, 12), (655, 12), (656, 12), (657, 12), (658, 12), (659, 12), (660, 12), (661, 12), (662, 12), (663, 12), (664, 12), (665, 12), (666, 12), (667, 12), (668, 12), (669, 12), (670, 12), (671, 12), (672, 12), (673, 12), (674, 12), (675, 12), (676, 12), (677, 12), (678, 12), (679, 12), (680, 12), (681, 12), (682, 12), (683, 12), (684, 12), (685, 12), (686, 12), (687, 12), (688, 12), (689, 12), (690, 12), (691, 12), (692, 12), (693, 12), (694, 12), (695, 12), (696, 12), (697, 12), (698, 12), (699, 12), (700, 12), (701, 12), (702, 12), (703, 12), (704, 12), (705, 12), (706, 12), (707, 12), (708, 12), (709, 12), (710, 12), (711, 12), (712, 12), (713, 12), (714, 12), (715, 12), (716, 12), (717, 12), (718, 12), (719, 12), (720, 12), (721, 12), (722, 12), (723, 12), (724, 12), (725, 12), (726, 12), (727, 12), (728, 12), (729, 12), (730, 12), (731, 12), (732, 12), (733, 12), (734, 12), (735, 12), (736, 12), (737, 12), (738, 12), (739, 12), (740, 12), (741, 12), (742, 12), (743, 12), (744, 12), (745, 12), (746, 12), (747, 12), (748, 12), (749, 12), (750, 12), (751, 12), (752, 12), (753, 12), (754, 12), (755, 12), (756, 12), (757, 12), (758, 12), (759, 12), (760, 12), (761, 12), (762, 12), (763, 12), (764, 12), (765, 12), (766, 12), (767, 12), (768, 12), (769, 12), (770, 12), (771, 12), (772, 12), (773, 12), (774, 12), (775, 12), (776, 12), (777, 12), (778, 12), (779, 12), (780, 12), (781, 12), (782, 12), (783, 12), (784, 12), (785, 12), (786, 12), (787, 12), (788, 12), (789, 12), (790, 12), (791, 12), (792, 12), (793, 12), (794, 12), (795, 12), (796, 12), (797, 12), (798, 12), (799, 12), (800, 12), (801, 12), (802, 12), (803, 12), (804, 12), (805, 12), (806, 12), (807, 12), (808, 12), (809, 12), (810, 12), (811, 12), (812, 12), (813, 12), (814, 12), (815, 12), (816, 12), (817, 12), (818, 12), (819, 12), (820, 12), (821, 12), (822, 12), (823, 12), (824, 12), (825, 12), (826, 12), (827, 12), (828, 12), (829, 12), (830, 12), (831, 12), (832, 12), (833, 12), (834, 12), (835, 12), (836, 12), (837, 12), (838, 12), (839, 12), (840, 12), (841, 12), (842, 12), (843, 12), (844, 12), (845, 12), (846, 12), (847, 12), (848, 12), (849, 12), (850, 12), (851, 12), (852, 12), (853, 12), (854, 12), (855, 12), (856, 12), (857, 12), (858, 12), (859, 12), (860, 12), (861, 12), (862, 12), (863, 12), (864, 12), (865, 12), (866, 12), (867, 12), (868, 12), (869, 12), (870, 12), (871, 12), (872, 12), (873, 12), (874, 12), (875, 12), (876, 12), (877, 12), (878, 12), (879, 12), (880, 12), (881, 12), (882, 12), (883, 12), (884, 12), (885, 12), (886, 12), (887, 12), (888, 12), (889, 12), (890, 12), (891, 12), (892, 12), (893, 12), (894, 12), (895, 12), (896, 12), (897, 12), (898, 12), (899, 12), (900, 12), (901, 12), (902, 12), (903, 12), (904, 12), (905, 12), (906, 12), (907, 12), (908, 12), (909, 12), (910, 12), (911, 12), (912, 12), (913, 12), (914, 12), (915, 12), (916, 12), (917, 12), (918, 12), (919, 12), (920, 12), (921, 12), (922, 12), (923, 12), (924, 12), (925, 12), (926, 12), (927, 12), (928, 12), (929, 12), (930, 12), (931, 12), (932, 12), (933, 12), (934, 12), (935, 12), (936, 12), (937, 12), (938, 12), (939, 12), (940, 12), (941, 12), (942, 12), (943, 12), (944, 12), (945, 12), (946, 12), (947, 12), (948, 12), (949, 12), (950, 12), (951, 12), (952, 12), (953, 12), (954, 12), (955, 12), (956, 12), (957, 12), (958, 12), (959, 12), (960, 12), (961, 12), (962, 12), (963, 12), (964, 12), (965, 12), (966, 12), (967, 12), (968, 12), (969, 12), (970, 12), (971, 12), (972, 12), (973, 12), (974, 12), (975, 12), (976, 12), (977, 12), (978, 12), (979, 12), (980, 12), (981, 12), (982, 12), (983, 12), (984, 12), (985, 12), (986, 12), (987, 12), (988, 12), (989, 12), (990, 12), (991, 12), (992, 12), (993, 12), (994, 12), (995, 12), (996, 12), (997, 12), (998, 12), (999, 12), (1000, 12), (1001, 12), (1002, 12), (1003, 12), (1004, 12), (1005, 12), (1006, 12), (1007, 12), (1008, 12), (1009, 12), (1010, 12), (1011, 12), (1012, 12), (1013, 12), (1014, 12), (1015, 12), (1016, 12), (1017, 12), (1018, 12), (1019, 12), (1020, 12), (1021, 12), (1022, 12), (1023, 12), (1024, 12), (1025, 12), (1026, 12), (1027, 12), (1028, 12), (1029, 12), (1030, 12), (1031, 12), (1032, 12), (1033, 12), (1034, 12), (1035, 12), (1036, 12), (1037, 12), (1038, 12), (1039, 12), (1040, 12), (1041, 12), (1042, 12), (1043, 12), (1044, 12), (1045, 12), (1046, 12), (1047, 12), (1048, 12), (1049, 12), (1050, 12), (1051, 12), (1052, 12), (1053, 12), (1054, 12), (1055, 12), (1056, 12), (1057, 12), (1058, 12), (1059, 12), (1060, 12), (1061, 12), (1062, 12), (1063, 12), (1064, 12), (1065, 12), (1066, 12), (1067, 12), (1068, 12), (1069, 12), (1070, 12), (1071, 12), (1072, 12), (1073, 12), (1074, 12), (1075, 12), (1076, 12), (1077, 12), (1078, 12), (1079, 12), (1080, 12), (1081, 12), (1082, 12), (1083, 12), (1084, 12), (1085, 12), (1086, 12), (1087, 12), (1088, 12), (1089, 12), (1090, 12), (1091, 12), (1092, 12), (1093, 12), (1094, 12), (1095, 12), (1096, 12), (1097, 12), (1098, 12), (1099, 12), (1100, 12), (1101, 12), (1102, 12), (1103, 12), (1104, 12), (1105, 12), (1106, 12), (1107, 12), (1108, 12), (1109, 12), (1110, 12), (1111, 12), (1112, 12), (1113, 12), (1114, 12), (1115, 12), (1116, 12), (1117, 12), (1118, 12), (1119, 12), (1120, 12), (1121, 12), (1122, 12), (1123, 12), (1124, 12), (1125, 12), (1126, 12), (1127, 12), (1128, 12), (1129, 12), (1130, 12), (1131, 12), (1132, 12), (1133, 12), (1134, 12), (1135, 12), (1136, 12), (1137, 12), (1138, 12), (1139, 12), (1140, 12), (1141, 12), (1142, 12), (1143, 12), (1144, 12), (1145, 12), (1146, 12), (1147, 12), (1148, 12), (1149, 12), (1150, 12), (1151, 12), (1152, 12), (1153, 12), (1154, 12), (1155, 12), (1156, 12), (1157, 12), (1158, 12), (1159, 12), (1160, 12), (1161, 12), (1162, 12), (1163, 12), (1164, 12), (1165, 12), (1166, 12), (1167, 12), (1168, 12), (1169, 12), (1170, 12), (1171, 12), (1172, 12), (1173, 12), (1174, 12), (1175, 12), (1176, 12), (1177, 12), (1178, 12), (1179, 12), (1180, 12), (1181, 12), (1182, 12), (1183, 12), (1184, 12), (1185, 12), (1186, 12), (1187, 12), (1188, 12), (1189, 12), (1190, 12), (1191, 12), (1192, 12), (1193, 12), (1194, 12), (1195, 12), (1196, 12), (1197, 12), (1198, 12), (1199, 12), (1200, 12), (1201, 12), (1202, 12), (1203, 12), (1204, 12), (1205, 12), (1206, 12), (1207, 12), (1208, 12), (1209, 12), (1210, 12), (1211, 12), (1212, 12), (1213, 12), (1214, 12), (1215, 12), (1216, 12), (1217, 12), (1218, 12), (1219, 12), (1220, 12), (1221, 12), (1222, 12), (1223, 12), (1224, 12), (1225, 12), (1226, 12), (1227, 12), (1228, 12), (1229, 12), (1230, 12), (1231, 12), (1232, 12), (1233, 12), (1234, 12), (1235, 12), (1236, 12), (1237, 12), (1238, 12), (1239, 12), (1240, 12), (1241, 12), (1242, 12), (1243, 12), (1244, 12), (1245, 12), (1246, 12), (1247, 12), (1248, 12), (1249, 12), (1250, 12), (1251, 12), (1252, 12), (1253, 12), (1254, 12), (1255, 12), (1256, 12), (1257, 12), (1258, 12), (1259, 12), (1260, 12), (1261, 12), (1262, 12), (1263, 12), (1264, 12), (1265, 12), (1266, 12), (1267, 12), (1268, 12), (1269, 12), (1270, 12), (1271, 12), (1272, 12), (1273, 12), (1274, 12), (1275, 12), (1276, 12), (1277, 12), (1278, 12), (1279, 12), (1280, 12), (1281, 12), (1282, 12), (1283, 12), (1284, 12), (1285, 12), (1286, 12), (1287, 12), (1288, 12), (1289, 12), (1290, 12), (1291, 12), (1292, 12), (1293, 12), (1294, 12), (1295, 12), (1296, 12), (1297, 12), (1298, 12), (1299, 12), (1300, 12), (1301, 12), (1302, 12), (1303, 12), (1304, 12), (1305, 12), (1306, 12), (1307, 12), (1308, 12), (1309, 12), (1310, 12), (1311, 12), (1312, 12), (1313, 12), (1314, 12), (1315, 12), (1316, 12), (1317, 12), (1318, 12), (1319, 12), (1320, 12), (1321, 12), (1322, 12), (1323, 12), (1324, 12), (1325, 12), (1326, 12), (1327, 12), (1328, 12), (1329, 12), (1330, 12), (1331, 12), (1332, 12), (1333, 12), (1334, 12), (1335, 12), (1336, 12), (1337, 12), (1338, 12), (1339, 12), (1340, 12), (1341, 12), (1342, 12), (1343, 12), (1344, 12), (1345, 12), (1346, 12), (1347, 12), (1348, 12), (1349, 12), (1350, 12), (1351, 12), (1352, 12), (1353, 12), (1354, 12), (1355, 12), (1356, 12), (1357, 12), (1358, 12), (1359, 12), (1360, 12), (1361, 12), (1362, 12), (1363, 12), (1364, 12), (1365, 12), (1366, 12), (1367, 12), (1368, 12), (1369, 12), (1370, 12), (1371, 12), (1372, 12), (1373, 12), (1374, 12), (1375, 12), (1376, 12), (1377, 12), (1378, 12), (1379, 12), (1380, 12), (1381, 12), (1382, 12), (1383, 12), (1384, 12), (1385, 12), (1386, 12), (1387, 12), (1388, 12), (1389, 12), (1390, 12), (1391, 12), (1392, 12), (1393, 12), (1394, 12), (1395, 12), (1396, 12), (1397, 12), (1398, 12), (1399, 12), (1400, 12), (1401, 12), (1402, 12), (1403, 12), (1404, 12), (1405, 12), (1406, 12), (1407, 12), (1408, 12), (1409, 12), (1410, 12), (1411, 12), (1412, 12), (1413, 12), (1414, 12), (1415, 12), (1416, 12), (1417, 12), (1418, 12), (1419, 12), (1420, 12), (1421, 12), (1422, 12), (1423, 12), (1424, 12), (1425, 12), (1426, 12), (1427, 12), (1428, 12), (1429, 12), (1430, 12), (1431, 12), (1432, 12), (1433, 12), (1434, 12), (1435, 12), (1436, 12), (1437, 12), (1438, 12), (1439, 12), (1440, 12), (1441, 12), (1442, 12), (1443, 12), (1444, 12), (1445, 12), (1446, 12), (1447, 12), (1448, 12), (1449, 12), (1450, 12), (1451, 12), (1452, 12), (1453, 12), (1454, 12), (1455, 12), (1456, 12), (1457, 12), (1458, 12), (1459, 12), (1460, 12), (1461, 12), (1462, 12), (1463, 12), (1464, 12), (1465, 12), (1466, 12), (1467, 12), (1468, 12), (1469, 12), (1470, 12), (1471, 12), (1472, 12), (1473, 12), (1474, 12), (1475, 12), (1476, 12), (1477, 12), (1478, 12), (1479, 12), (1480, 12), (1481, 12), (1482, 12), (1483, 12), (1484, 12), (1485, 12), (1486, 12), (1487, 12), (1488, 12), (1489, 12), (1490, 12), (1491, 12), (1492, 12), (1493, 12), (1494, 12), (1495, 12), (1496, 12), (1497, 12), (1498, 12), (1499, 12), (1500, 12), (1501, 12), (1502, 12), (1503, 12), (1504, 12), (1505, 12), (1506, 12), (1507, 12), (1508, 12), (1509, 12), (1510, 12), (1511, 12), (1512, 12), (1513, 12), (1514, 12), (1515, 12), (1516, 12), (1517, 12), (1518, 12), (1519, 12), (1520, 12), (1521, 12), (1522, 12), (1523, 12), (1524, 12), (1525, 12), (1526, 12), (1527, 12), (1528, 12), (1529, 12), (1530, 12), (1531, 12), (1532, 12), (1533, 12), (1534, 12), (1535, 12), (1536, 12), (1537, 12), (1538, 12), (1539, 12), (1540, 12), (1541, 12), (1542, 12), (1543, 12), (1544, 12), (1545, 12), (1546, 12), (1547, 12), (1548, 12), (1549, 12), (1550, 12), (1551, 12), (1552, 12), (1553, 12), (1554, 12), (1555, 12), (1556, 12), (1557, 12), (1558, 12), (1559, 12), (1560, 12), (1561, 12), (1562, 12), (1563, 12), (1564, 12), (1565, 12), (1566, 12), (1567, 12), (1568, 12), (1569, 12), (1570, 12), (1571, 12), (1572, 12), (1573, 12), (1574, 12), (1575, 12), (1576, 12), (1577, 12), (1578, 12), (1579, 12), (1580, 12), (1581, 12), (1582, 12), (1583, 12), (1584, 12), (1585, 12), (1586, 12), (1587, 12), (1588, 12), (1589, 12), (1590, 12), (1591, 12), (1592, 12), (1593, 12), (1594, 12), (1595, 12), (1596, 12), (1597, 12), (1598, 12), (1599, 12), (1600, 12), (1601, 12), (1602, 12), (1603, 12), (1604, 12), (1605, 12), (1606, 12), (1607, 12), (1608, 12), (1609, 12), (1610, 12), (1611, 12), (1612, 12), (1613, 12), (1614, 12), (1615, 12), (1616, 12), (1617, 12), (1618, 12), (1619, 12), (1620, 12), (1621, 12), (1622, 12), (1623, 12), (1624, 12), (1625, 12), (1626, 12), (1627, 12), (1628, 12), (1629, 12), (1630, 12), (1631, 12), (1632, 12), (1633, 12), (1634, 12), (1635, 12), (1636, 12), (1637, 12), (1638, 12), (1639, 12), (1640, 12), (1641, 12), (1642, 12), (1643, 12), (1644, 12), (1645, 12), (1646, 12), (1647, 12), (1648, 12), (1649, 12), (1650, 12), (1651, 12), (1652, 12), (1653, 12), (1654, 12), (1655, 12), (1656, 12), (1657, 12), (1658, 12), (1659, 12), (1660, 12), (1661, 12), (1662, 12), (1663, 12), (1664, 12), (1665, 12), (1666, 12), (1667, 12), (1668, 12), (1669, 12), (1670, 12), (1671, 12), (1672, 12), (1673, 12), (1674, 12), (1675, 12), (1676, 12), (1677, 12), (1678, 12), (1679, 12), (1680, 12), (1681, 12), (1682, 12), (1683, 12), (1684, 12), (1685, 12), (1686, 12), (1687, 12), (1688, 12), (1689, 12), (1690, 12), (1691, 12), (1692, 12), (1693, 12), (1694, 12), (1695, 12), (1696, 12), (1697, 12), (1698, 12), (1699, 12), (1700, 12), (1701, 12), (1702, 12), (1703, 12), (1704, 12), (1705, 12), (1706, 12), (1707, 12), (1708, 12), (1709, 12), (1710, 12), (1711, 12), (1712, 12), (1713, 12), (1714, 12), (1715, 12), (1716, 12), (1717, 12), (1718, 12), (1719, 12), (1720, 12), (1721, 12), (1722, 12), (1723, 12), (1724, 12), (1725, 12), (1726, 12), (1727, 12), (1728, 12), (1729, 12), (1730, 12), (1731, 12), (1732, 12), (1733, 12), (1734, 12), (1735, 12), (1736, 12), (1737, 12), (1738, 12), (1739, 12), (1740, 12), (1741, 12), (1742, 12), (1743, 12), (1744, 12), (1745, 12), (1746, 12), (1747, 12), (1748, 12), (1749, 12), (1750, 12), (1751, 12), (1752, 12), (1753, 12), (1754, 12), (1755, 12), (1756, 12), (1757, 12), (1758, 12), (1759, 12), (1760, 12), (1761, 12), (1762, 12), (1763, 12), (1764, 12), (1765, 12), (1766, 12), (1767, 12), (1768, 12), (1769, 12), (1770, 12), (1771, 12), (1772, 12), (1773, 12), (1774, 12), (1775, 12), (1776, 12), (1777, 12), (1778, 12), (1779, 12), (1780, 12), (1781, 12), (1782, 12), (1783, 12), (1784, 12), (1785, 12), (1786, 12), (1787, 12), (1788, 12), (1789, 12), (1790, 12), (1791, 12), (1792, 12), (1793, 12), (1794, 12), (1795, 12), (1796, 12), (1797, 12), (1798, 12), (1799, 12), (1800, 12), (1801, 12), (1802, 12), (1803, 12), (1804, 12), (1805, 12), (1806, 12), (1807, 12), (1808, 12), (1809, 12), (1810, 12), (1811, 12), (1812, 12), (1813, 12), (1814, 12), (1815, 12), (1816, 12), (1817, 12), (1818, 12), (1819, 12), (1820, 12), (1821, 12), (1822, 12), (1823, 12), (1824, 12), (1825, 12), (1826, 12), (1827, 12), (1828, 12), (1829, 12), (1830, 12), (1831, 12), (1832, 12), (1833, 12), (1834, 12), (1835, 12), (1836, 12), (1837, 12), (1838, 12), (1839, 12), (1840, 12), (1841, 12), (1842, 12), (1843, 12), (1844, 12), (1845, 12), (1846, 12), (1847, 12), (1848, 12), (1849, 12), (1850, 12), (1851, 12), (1852, 12), (1853, 12), (1854, 12), (1855, 12), (1856, 12), (1857, 12), (1858, 12), (1859, 12), (1860, 12), (1861, 12), (1862, 12), (1863, 12), (1864, 12), (1865, 12), (1866, 12), (1867, 12), (1868, 12), (1869, 12), (1870, 12), (1871, 12), (1872, 12), (1873, 12), (1874, 12), (1875, 12), (1876, 12), (1877, 12), (1878, 12), (1879, 12), (1880, 12), (1881, 12), (1882, 12), (1883, 12), (1884, 12), (1885, 12), (1886, 12), (1887, 12), (1888, 12), (1889, 12), (1890, 12), (1891, 12), (1892, 12), (1893, 12), (1894, 12), (1895, 12), (1896, 12), (1897, 12), (1898, 12), (1899, 12), (1900, 12), (1901, 12), (1902, 12), (1903, 12), (1904, 12), (1905, 12), (1906, 12), (1907, 12), (1908, 12), (1909, 12), (1910, 12), (1911, 12), (1912, 12), (1913, 12), (1914, 12), (1915, 12), (1916, 12), (1917, 12), (1918, 12), (1919, 12), (1920, 12), (1921, 12), (1922, 12), (1923, 12), (1924, 12), (1925, 12), (1926, 12), (1927, 12), (1928, 12), (1929, 12), (1930, 12), (1931, 12), (1932, 12), (1933, 12), (1934, 12), (1935, 12), (1936, 12), (1937, 12), (1938, 12), (1939, 12), (1940, 12), (1941, 12), (1942, 12), (1943, 12), (1944, 12), (1945, 12), (1946, 12), (1947, 12), (1948, 12), (1949, 12), (1950, 12), (1951, 12), (1952, 12), (1953, 12), (1954, 12), (1955, 12), (1956, 12), (1957, 12), (1958, 12), (1959, 12), (1960, 12), (1961, 12), (1962, 12), (1963, 12), (1964, 12), (1965, 12), (1966, 12), (1967, 12), (1968, 12), (1969, 12), (1970, 12), (1971, 12), (1972, 12), (1973, 12), (1974, 12), (1975, 12), (1976, 12), (1977, 12), (1978, 12), (1979, 12), (1980, 12), (1981, 12), (1982, 12), (1983, 12), (1984, 12), (1985, 12), (1986, 12), (1987, 12), (1988, 12), (1989, 12), (1990, 12), (1991, 12), (1992, 12), (1993, 12), (1994, 12), (1995, 12), (1996, 12), (1997, 12), (1998, 12), (1999, 12), (2000, 12), (2001, 12), (2002, 12), (2003, 12), (2004, 12), (2005, 12), (2006, 12), (2007, 12), (2008, 12), (2009, 12), (2010, 12), (2011, 12), (2012, 12), (2013, 12), (2014, 12), (2015, 12), (2016, 12), (2017, 12), (2018, 12), (2019, 12), (2020, 12), (2021, 12), (2022, 12), (2023, 12), (2024, 12), (2025, 12), (2026, 12), (2027, 12), (2028, 12), (2029, 12), (2030, 12), (2031, 12), (2032, 12), (2033, 12), (2034, 12), (2035, 12), (2036, 12), (2037, 12), (2038, 12), (2039, 12), (2040, 12), (2041, 12), (2042, 12), (2043, 12), (2044, 12), (2045, 12), (2046, 12), (2047, 12), (2048, 12), (2049, 12), (2050, 12), (2051, 12), (2052, 12), (2053, 12), (2054, 12), (2055, 12), (2056, 12), (2057, 12), (2058, 12), (2059, 12), (2060, 12), (2061, 12), (2062, 12), (2063, 12), (2064, 12), (2065, 12), (2066, 12), (2067, 12), (2068, 12), (2069, 12), (2070, 12), (2071, 12), (2072, 12), (2073, 12), (2074, 12), (2075, 12), (2076, 12), (2077, 12), (2078, 12), (2079, 12), (2080, 12), (2081, 12), (2082, 12), (2083, 12), (2084, 12), (2085, 12), (2086, 12), (2087, 12), (2088, 12), (2089, 12), (2090, 12), (2091, 12), (2092, 12), (2093, 12), (2094, 12), (2095, 12), (2096, 12), (2097, 12), (2098, 12), (2099, 12), (2100, 12), (2101, 12), (2102, 12), (2103, 12), (2104, 12), (2105, 12), (2106, 12), (2107, 12), (2108, 12), (2109, 12), (2110, 12), (2111, 12), (2112, 12), (2113, 12), (2114, 12), (2115, 12), (2116, 12), (2117, 12), (2118, 12), (2119, 12), (2120, 12), (2121, 12), (2122, 12), (2123, 12), (2124, 12), (2125, 12), (2126, 12), (2127, 12), (2128, 12), (2129, 12), (2130, 12), (2131, 12), (2132, 12), (2133, 12), (2134, 12), (2135, 12), (2136, 12), (2137, 12), (2138, 12), (2139, 12), (2140, 12), (2141, 12), (2142, 12), (2143, 12), (2144, 12), (2145, 12), (2146, 12), (2147, 12), (2148, 12), (2149, 12), (2150, 12), (2151, 12), (2152, 12), (2153, 12), (2154, 12), (2155, 12), (2156, 12), (2157, 12), (2158, 12), (2159, 12), (2160, 12), (2161, 12), (2162, 12), (2163, 12), (2164, 12), (2165, 12), (2166, 12), (2167, 12), (2168, 12), (2169, 12), (2170, 12), (2171, 12), (2172, 12), (2173, 12), (2174, 12), (2175, 12), (2176, 12), (2177, 12), (2178, 12), (2179, 12), (2180, 12), (2181, 12), (2182, 12), (2183, 12), (2184, 12), (2185, 12), (2186, 12), (2187, 12), (2188, 12), (2189, 12), (2190, 12), (2191, 12), (2192, 12), (2193, 12), (2194, 12), (2195, 12), (2196, 12), (2197, 12), (2198, 12), (2199, 12), (2200, 12), (2201, 12), (2202, 12), (2203, 12), (2204, 12), (2205, 12), (2206, 12), (2207, 12), (2208, 12), (2209, 12), (2210, 12), (2211, 12), (2212, 12), (2213, 12), (2214, 12), (2215, 12), (2216, 12), (2217, 12), (2218, 12), (2219, 12), (2220, 12), (2221, 12), (2222, 12), (2223, 12), (2224, 12), (2225, 12), (2226, 12), (2227, 12), (2228, 12), (2229, 12), (2230, 12), (2231, 12), (2232, 12), (2233, 12), (2234, 12), (2235, 12), (2236, 12), (2237, 12), (2238, 12), (2239, 12), (2240, 12), (2241, 12), (2242, 12), (2243, 12), (2244, 12), (2245, 12), (2246, 12), (2247, 12), (2248, 12), (2249, 12), (2250, 12), (2251, 12), (2252, 12), (2253, 12), (2254, 12), (2255, 12), (2256, 12), (2257, 12), (2258, 12), (2259, 12), (2260, 12), (2261, 12), (2262, 12), (2263, 12), (2264, 12), (2265, 12), (2266, 12), (2267, 12), (2268, 12), (2269, 12), (2270, 12), (2271, 12), (2272, 12), (2273, 12), (2274, 12), (2275, 12), (2276, 12), (2277, 12), (2278, 12), (2279, 12), (2280, 12), (2281, 12), (2282, 12), (2283, 12), (2284, 12), (2285, 12), (2286, 12), (2287, 12), (2288, 12), (2289, 12), (2290, 12), (2291, 12), (2292, 12), (2293, 12), (2294, 12), (2295, 12), (2296, 12), (2297, 12), (2298, 12), (2299, 12), (2300, 12), (2301, 12), (2302, 12), (2303, 12), (2304, 12), (2305, 12), (2306, 12), (2307, 12), (2308, 12), (2309, 12), (2310, 12), (2311, 12), (2312, 12), (2313, 12), (2314, 12), (2315, 12), (2316, 12), (2317, 12), (2318, 12), (2319, 12), (2320, 12), (2321, 12), (2322, 12), (2323, 12), (2324, 12), (2325, 12), (2326, 12), (2327, 12), (2328, 12), (2329, 12), (2330, 12), (2331, 12), (2332, 12), (2333, 12), (2334, 12), (2335, 12), (2336, 12), (2337, 12), (2338, 12), (2339, 12), (2340, 12), (2341, 12), (2342, 12), (2343, 12), (2344, 12), (2345, 12), (2346, 12), (2347, 12), (2348, 12), (2349, 12), (2350, 12), (2351, 12), (2352, 12), (2353, 12), (2354, 12), (2355, 12), (2356, 12), (2357, 12), (2358, 12), (2359, 12), (2360, 12), (2361, 12), (2362, 12), (2363, 12), (2364, 12), (2365, 12), (2366, 12), (2367, 12), (2368, 12), (2369, 12), (2370, 12), (2371, 12), (2372, 12), (2373, 12), (2374, 12), (2375, 12), (2376, 12), (2377, 12), (2378, 12), (2379, 12), (2380, 12), (2381, 12), (2382, 12), (2383, 12), (2384, 12), (2385, 12), (2386, 12), (2387, 12), (2388, 12), (2389, 12), (2390, 12), (2391, 12), (2392, 12), (2393, 12), (2394, 12), (2395, 12), (2396, 12), (2397, 12), (2398, 12), (2399, 12), (2400, 12), (2401, 12), (2402, 12), (2403, 12), (2404, 12), (2405, 12), (2406, 12), (2407, 12), (2408, 12), (2409, 12), (2410, 12), (2411, 12), (2412, 12), (2413, 12), (2414, 12), (2415, 12), (2416, 12), (2417, 12), (2418, 12), (2419, 12), (2420, 12), (2421, 12), (2422, 12), (2423, 12), (2424, 12), (2425, 12), (2426, 12), (2427, 12), (2428, 12), (2429, 12), (2430, 12), (2431, 12), (2432, 12), (2433, 12), (2434, 12), (2435, 12), (2436, 12), (2437, 12), (2438, 12), (2439, 12), (2440, 12), (2441, 12), (2442, 12), (2443, 12), (2444, 12), (2445, 12), (2446, 12), (2447, 12), (2448, 12), (2449, 12), (2450, 12), (2451, 12), (2452, 12), (2453, 12), (2454, 12), (2455, 12), (2456, 12), (2457, 12), (2458, 12), (2459, 12), (2460, 12), (2461, 12), (2462, 12), (2463, 12), (2464, 12), (2465, 12), (2466, 12), (2467, 12), (2468, 12), (2469, 12), (2470, 12), (2471, 12), (2472, 12), (2473, 12), (2474, 12), (2475, 12), (2476, 12), (2477, 12), (2478, 12), (2479, 12), (2480, 12), (2481, 12), (2482, 12), (2483, 12), (2484, 12), (2485, 12), (2486, 12), (2487, 12), (2488, 12), (2489, 12), (2490, 12), (2491, 12), (2492, 12), (2493, 12), (2494, 12), (2495, 12), (2496, 12), (2497, 12), (2498, 12), (2499, 12), (2500, 12), (2501, 12), (2502, 12), (2503, 12), (2504, 12), (2505, 12), (2506, 12), (2507, 12), (2508, 12), (2509, 12), (2510, 12), (2511, 12), (2512, 12), (2513, 12), (2514, 12), (2515, 12), (2516, 12), (2517, 12), (2518, 12), (2519, 12), (2520, 12), (2521, 12), (2522, 12), (2523, 12), (2524, 12), (2525, 12), (2526, 12), (2527, 12), (2528, 12), (2529, 12), (2530, 12), (2531, 12), (2532, 12), (2533, 12), (2534, 12), (2535, 12), (2536, 12), (2537, 12), (2538, 12), (2539, 12), (2540, 12), (2541, 12), (2542, 12), (2543, 12), (2544, 12), (2545, 12), (2546, 12), (2547, 12), (2548, 12), (2549, 12), (2550, 12), (2551, 12), (2552, 12), (2553, 12), (2554, 12), (2555, 12), (2556, 12), (2557, 12), (2558, 12), (2559, 12), (2560, 12), (2561, 12), (2562, 12), (2563, 12), (2564, 12), (2565, 12), (2566, 12), (2567, 12), (2568, 12), (2569, 12), (2570, 12), (2571, 12), (2572, 12), (2573, 12), (2574, 12), (2575, 12), (2576, 12), (2577, 12), (2578, 12), (2579, 12), (2580, 12), (2581, 12), (2582, 12), (2583, 12), (2584, 12), (2585, 12), (2586, 12), (2587, 12), (2588, 12), (2589, 12), (2590, 12), (2591, 12), (2592, 12), (2593, 12), (2594, 12), (2595, 12), (2596, 12), (2597, 12), (2598, 12), (2599, 12), (2600, 12), (2601, 12), (2602, 12), (2603, 12), (2604, 12), (2605, 12), (2606, 12), (2607, 12), (2608, 12), (2609, 12), (2610, 12), (2611, 12), (2612, 12), (2613, 12), (2614, 12), (2615, 12), (2616, 12), (2617, 12), (2618, 12), (2619, 12), (2620, 12), (2621, 12), (2622, 12), (2623, 12), (2624, 12), (2625, 12), (2626, 12), (2627, 12), (2628, 12), (2629, 12), (2630, 12), (2631, 12), (2632, 12), (2633, 12), (2634, 12), (2635, 12), (2636, 12), (2637, 12), (2638, 12), (2639, 12), (2640, 12), (2641, 12), (2642, 12), (2643, 12), (2644, 12), (2645, 12), (2646, 12), (2647, 12), (2648, 12), (2649, 12), (2650, 12), (2651, 12), (2652, 12), (2653, 12), (2654, 12), (2655, 12), (2656, 12), (2657, 12), (2658, 12), (2659, 12), (2660, 12), (2661, 12), (2662, 12), (2663, 12), (2664, 12), (2665, 12), (2666, 12), (2667, 12), (2668, 12), (2669, 12), (2670, 12), (2671, 12), (2672, 12), (2673, 12), (2674, 12), (2675, 12), (2676, 12), (2677, 12), (2678, 12), (2679, 12), (2680, 12), (2681, 12), (2682, 12), (2683, 12), (2684, 12), (2685, 12), (2686, 12), (2687, 12), (2688, 12), (2689, 12), (2690, 12), (2691, 12), (2692, 12), (2693, 12), (2694, 12), (2695, 12), (2696, 12), (2697, 12), (2698, 12), (2699, 12), (2700, 12), (2701, 12), (2702, 12), (2703, 12), (2704, 12), (2705, 12), (2706, 12), (2707, 12), (2708, 12), (2709, 12), (2710, 12), (2711, 12), (2712, 12), (2713, 12), (2714, 12), (2715, 12), (2716, 12), (2717, 12), (2718, 12), (2719, 12), (2720, 12), (2721, 12), (2722, 12), (2723, 12), (2724, 12), (2725, 12), (2726, 12), (2727, 12), (2728, 12), (2729, 12), (2730, 12), (2731, 12), (2732, 12), (2733, 12), (2734, 12), (2735, 12), (2736, 12), (2737, 12), (2738, 12), (2739, 12), (2740, 12), (2741, 12), (2742, 12), (2743, 12), (2744, 12), (2745, 12), (2746, 12), (2747, 12), (2748, 12), (2749, 12), (2750, 12), (2751, 12), (2752, 12), (2753, 12), (2754, 12), (2755, 12), (2756, 12), (2757, 12), (2758, 12), (2759, 12), (2760, 12), (2761, 12), (2762, 12), (2763, 12), (2764, 12), (2765, 12), (2766, 12), (2767, 12), (2768, 12), (2769, 12), (2770, 12), (2771, 12), (2772, 12), (2773, 12), (2774, 12), (2775, 12), (2776, 12), (2777, 12), (2778, 12), (2779, 12), (2780, 12), (2781, 12), (2782, 12), (2783, 12), (2784, 12), (2785, 12), (2786, 12), (2787, 12), (2788, 12), (2789, 12), (2790, 12), (2791, 12), (2792, 12), (2793, 12), (2794, 12), (2795, 12), (2796, 12), (2797, 12), (2798, 12), (2799, 12), (2800, 12), (2801, 12), (2802, 12), (2803, 12), (2804, 12), (2805, 12), (2806, 12), (2807, 12), (2808, 12), (2809, 12), (2810, 12), (2811, 12), (2812, 12), (2813, 12), (2814, 12), (2815, 12), (2816, 12), (2817, 12), (2818, 12), (2819, 12), (2820, 12), (2821, 12), (2822, 12), (2823, 12), (2824, 12), (2825, 12), (2826, 12), (2827, 12), (2828, 12), (2829, 12), (2830, 12), (2831, 12), (2832, 12), (2833, 12), (2834, 12), (2835, 12), (2836, 12), (2837, 12), (2838, 12), (2839, 12), (2840, 12), (2841, 12), (2842, 12), (2843, 12), (2844, 12), (2845, 12), (2846, 12), (2847, 12), (2848, 12), (2849, 12), (2850, 12), (2851, 12), (2852, 12), (2853, 12), (2854, 12), (2855, 12), (2856, 12), (2857, 12), (2858, 12), (2859, 12), (2860, 12), (2861, 12), (2862, 12), (2863, 12), (2864, 12), (2865, 12), (2866, 12), (2867, 12), (2868, 12), (2869, 12), (2870, 12), (2871, 12), (2872, 12), (2873, 12), (2874, 12), (2875, 12), (2876, 12), (2877, 12), (2878, 12), (2879, 12), (2880, 12), (2881, 12), (2882, 12), (2883, 12), (2884, 12), (2885, 12), (2886, 12), (2887, 12), (2888, 12), (2889, 12), (2890, 12), (2891, 12), (2892, 12), (2893, 12), (2894, 12), (2895, 12), (2896, 12), (2897, 12), (2898, 12), (2899, 12), (2900, 12), (2901, 12), (2902, 12), (2903, 12), (2904, 12), (2905, 12), (2906, 12), (2907, 12), (2908, 12), (2909, 12), (2910, 12), (2911, 12), (2912, 12), (2913, 12), (2914, 12), (2915, 12), (2916, 12), (2917, 12), (2918, 12), (2919, 12), (2920, 12), (2921, 12), (2922, 12), (2923, 12), (2924, 12), (2925, 12), (2926, 12), (2927, 12), (2928, 12), (2929, 12), (2930, 12), (2931, 12), (2932, 12), (2933, 12), (2934, 12), (2935, 12), (2936, 12), (2937, 12), (2938, 12), (2939, 12), (2940, 12), (2941, 12), (2942, 12), (2943, 12), (2944, 12), (2945, 12), (2946, 12), (2947, 12), (2948, 12), (2949, 12), (2950, 12), (2951, 12), (2952, 12), (2953, 12), (2954, 12), (2955, 12), (2956, 12), (2957, 12), (2958, 12), (2959, 12), (2960, 12), (2961, 12), (2962, 12), (2963, 12), (2964, 12), (2965, 12), (2966, 12), (2967, 12), (2968, 12), (2969, 12), (2970, 12), (2971, 12), (2972, 12), (2973, 12), (2974, 12), (2975, 12), (2976, 12), (2977, 12), (2978, 12), (2979, 12), (2980, 12), (2981, 12), (2982, 12), (2983, 12), (2984, 12), (2985, 12), (2986, 12), (2987, 12), (2988, 12), (2989, 12), (2990, 12), (2991, 12), (2992, 12), (2993, 12), (2994, 12), (2995, 12), (2996, 12), (2997, 12), (2998, 12), (2999, 12), (3000, 12), (3001, 12), (3002, 12), (3003, 12), (3004, 12), (3005, 12), (3006, 12), (3007, 12), (3008, 12), (3009, 12), (3010, 12), (3011, 12), (3012, 12), (3013, 12), (3014, 12), (3015, 12), (3016, 12), (3017, 12), (3018, 12), (3019, 12), (3020, 12), (3021, 12), (3022, 12), (3023, 12), (3024, 12), (3025, 12), (3026, 12), (3027, 12), (3028, 12), (3029, 12), (3030, 12), (3031, 12), (3032, 12), (3033, 12), (3034, 12), (3035, 12), (3036, 12), (3037, 12), (3038, 12), (3039, 12), (3040, 12), (3041, 12), (3042, 12), (3043, 12), (3044, 12), (3045, 12), (3046, 12), (3047, 12), (3048, 12), (3049, 12), (3050, 12), (3051, 12), (3052, 12), (3053, 12), (3054, 12), (3055, 12), (3056, 12), (3057, 12), (3058, 12), (3059, 12), (3060, 12), (3061, 12), (3062, 12), (3063, 12), (3064, 12), (3065, 12), (3066, 12), (3067, 12), (3068, 12), (3069, 12), (3070, 12), (3071, 12), (3072, 12), (3073, 12), (3074, 12), (3075, 12), (3076, 12), (3077, 12), (3078, 12), (3079, 12), (3080, 12), (3081, 12), (3082, 12), (3083, 12), (3084, 12), (3085, 12), (3086, 12), (3087, 12), (3088, 12), (3089, 12), (3090, 12), (3091, 12), (3092, 12), (3093, 12), (3094, 12), (3095, 12), (3096, 12), (3097, 12), (3098, 12), (3099, 12), (3100, 12), (3101, 12), (3102, 12), (3103, 12), (3104, 12), (3105, 12), (3106, 12), (3107, 12), (3108, 12), (3109, 12), (3110, 12), (3111, 12), (3112, 12), (3113, 12), (3114, 12), (3115, 12), (3116, 12), (3117, 12), (3118, 12), (3119, 12), (3120, 12), (3121, 12), (3122, 12), (3123, 12), (3124, 12), (3125, 12), (3126, 12), (3127, 12), (3128, 12), (3129, 12), (3130, 12), (3131, 12), (3132, 12), (3133, 12), (3134, 12), (3135, 12), (3136, 12), (3137, 12), (3138, 12), (3139, 12), (3140, 12), (3141, 12), (3142, 12), (3143, 12), (3144, 12), (3145, 12), (3146, 12), (3147, 12), (3148, 12), (3149, 12), (3150, 12), (3151, 12), (3152, 12), (3153, 12), (3154, 12), (3155, 12), (3156, 12), (3157, 12), (3158, 12), (3159, 12), (3160, 12), (3161, 12), (3162, 12), (3163, 12), (3164, 12), (3165, 12), (3166, 12), (3167, 12), (3168, 12), (3169, 12), (3170, 12), (3171, 12), (3172, 12), (3173, 12), (3174, 12), (3175, 12), (3176, 12), (3177, 12), (3178, 12), (3179, 12), (3180, 12), (3181, 12), (3182, 12), (3183, 12), (3184, 12), (3185, 12), (3186, 12), (3187, 12), (3188, 12), (3189, 12), (3190, 12), (3191, 12), (3192, 12), (3193, 12), (3194, 12), (3195, 12), (3196, 12), (3197, 12), (3198, 12), (3199, 12), (3200, 12), (3201, 12), (3202, 12), (3203, 12), (3204, 12), (3205, 12), (3206, 12), (3207, 12), (3208, 12), (3209, 12), (3210, 12), (3211, 12), (3212, 12), (3213, 12), (3214, 12), (3215, 12), (3216, 12), (3217, 12), (3218, 12), (3219, 12), (3220, 12), (3221, 12), (3222, 12), (3223, 12), (3224, 12), (3225, 12), (3226, 12), (3227, 12), (3228, 12), (3229, 12), (3230, 12), (3231, 12), (3232, 12), (3233, 12), (3234, 12), (3235, 12), (3236, 12), (3237, 12), (3238, 12), (3239, 12), (3240, 12), (3241, 12), (3242, 12), (3243, 12), (3244, 12), (3245, 12), (3246, 12), (3247, 12), (3248, 12), (3249, 12), (3250, 12), (3251, 12), (3252, 12), (3253, 12), (3254, 12), (3255, 12), (3256, 12), (3257, 12), (3258, 12), (3259, 12), (3260, 12), (3261, 12), (3262, 12), (3263, 12), (3264, 12), (3265, 12), (3266, 12), (3267, 12), (3268, 12), (3269, 12), (3270, 12), (3271, 12), (3272, 12), (3273, 12), (3274, 12), (3275, 12), (3276, 12), (3277, 12), (3278, 12), (3279, 12), (3280, 12), (3281, 12), (3282, 12), (3283, 12), (3284, 12), (3285, 12), (3286, 12), (3287, 12), (3288, 12), (3289, 12), (3290, 12), (3291, 12), (3292, 12), (3293, 12), (3294, 12), (3295, 12), (3296, 12), (3297, 12), (3298, 12), (3299, 12), (3300, 12), (3301, 12), (3302, 12), (3303, 12), (3304, 12), (3305, 12), (3306, 12), (3307, 12), (3308, 12), (3309, 12), (3310, 12), (3311, 12), (3312, 12), (3313, 12), (3314, 12), (3315, 12), (3316, 12), (3317, 12), (3318, 12), (3319, 12), (3320, 12), (3321, 12), (3322, 12), (3323, 12), (3324, 12), (3325, 12), (3326, 12), (3327, 12), (3328, 12), (3329, 12), (3330, 12), (3331, 12), (3332, 12), (3333, 12), (3334, 12), (3335, 12), (3336, 12), (3337, 12), (3338, 12), (3339, 12), (3340, 12), (3341, 12), (3342, 12), (3343, 12), (3344, 12), (3345, 12), (3346, 12), (3347, 12), (3348, 12), (3349, 12), (3350, 12), (3351, 12), (3352, 12), (3353, 12), (3354, 12), (3355, 12), (3356, 12), (3357, 12), (3358, 12), (3359, 12), (3360, 12), (3361, 12), (3362, 12), (3363, 12), (3364, 12), (3365, 12), (3366, 12), (3367, 12), (3368, 12), (3369, 12), (3370, 12), (3371, 12), (3372, 12), (3373, 12), (3374, 12), (3375, 12), (3376, 12), (3377, 12), (3378, 12), (3379, 12), (3380, 12), (3381, 12), (3382, 12), (3383, 12), (3384, 12), (3385, 12), (3386, 12), (3387, 12), (3388, 12), (3389, 12), (3390, 12), (3391, 12), (3392, 12), (3393, 12), (3394, 12), (3395, 12), (3396, 12), (3397, 12), (3398, 12), (3399, 12), (3400, 12), (3401, 12), (3402, 12), (3403, 12), (3404, 12), (3405, 12), (3406, 12), (3407, 12), (3408, 12), (3409, 12), (3410, 12), (3411, 12), (3412, 12), (3413, 12), (3414, 12), (3415, 12), (3416, 12), (3417, 12), (3418, 12), (3419, 12), (3420, 12), (3421, 12), (3422, 12), (3423, 12), (3424, 12), (3425, 12), (3426, 12), (3427, 12), (3428, 12), (3429, 12), (3430, 12), (3431, 12), (3432, 12), (3433, 12), (3434, 12), (3435, 12), (3436, 12), (3437, 12), (3438, 12), (3439, 12), (3440, 12), (3441, 12), (3442, 12), (3443, 12), (3444, 12), (3445, 12), (3446, 12), (3447, 12), (3448, 12), (3449, 12), (3450, 12), (3451, 12), (3452, 12), (3453, 12), (3454, 12), (3455, 12), (3456, 12), (3457, 12), (3458, 12), (3459, 12), (3460, 12), (3461, 12), (3462, 12), (3463, 12), (3464, 12), (3465, 12), (3466, 12), (3467, 12), (3468, 12), (3469, 12), (3470, 12), (3471, 12), (3472, 12), (3473, 12), (3474, 12), (3475, 12), (3476, 12), (3477, 12), (3478, 12), (3479, 12), (3480, 12), (3481, 12), (3482, 12), (3483, 12), (3484, 12), (3485, 12), (3486, 12), (3487, 12), (3488, 12), (3489, 12), (3490, 12), (3491, 12), (3492, 12), (3493, 12), (3494, 12), (3495, 12), (3496, 12), (3497, 12), (3498, 12), (3499, 12), (3500, 12), (3501, 12), (3502, 12), (3503, 12), (3504, 12), (3505, 12), (3506, 12), (3507, 12), (3508, 12), (3509, 12), (3510, 12), (3511, 12), (3512, 12), (3513, 12), (3514, 12), (3515, 12), (3516, 12), (3517, 12), (3518, 12), (3519, 12), (3520, 12), (3521, 12), (3522, 12), (3523, 12), (3524, 12), (3525, 12), (3526, 12), (3527, 12), (3528, 12), (3529, 12), (3530, 12), (3531, 12), (3532, 12), (3533, 12), (3534, 12), (3535, 12), (3536, 12), (3537, 12), (3538, 12), (3539, 12), (3540, 12), (3541, 12), (3542, 12), (3543, 12), (3544, 12), (3545, 12), (3546, 12), (3547, 12), (3548, 12), (3549, 12), (3550, 12), (3551, 12), (3552, 12), (3553, 12), (3554, 12), (3555, 12), (3556, 12), (3557, 12), (3558, 12), (3559, 12), (3560, 12), (3561, 12), (3562, 12), (3563, 12), (3564, 12), (3565, 12), (3566, 12), (3567, 12), (3568, 12), (3569, 12), (3570, 12), (3571, 12), (3572, 12), (3573, 12), (3574, 12), (3575, 12), (3576, 12), (3577, 12), (3578, 12), (3579, 12), (3580, 12), (3581, 12), (3582, 12), (3583, 12), (3584, 12), (3585, 12), (3586, 12), (3587, 12), (3588, 12), (3589, 12), (3590, 12), (3591, 12), (3592, 12), (3593, 12), (3594, 12), (3595, 12), (3596, 12), (3597, 12), (3598, 12), (3599, 12), (3600, 12), (3601, 12), (3602, 12), (3603, 12), (3604, 12), (3605, 12), (3606, 12), (3607, 12), (3608, 12), (3609, 12), (3610, 12), (3611, 12), (3612, 12), (3613, 12), (3614, 12), (3615, 12), (3616, 12), (3617, 12), (3618, 12), (3619, 12), (3620, 12), (3621, 12), (3622, 12), (3623, 12), (3624, 12), (3625, 12), (3626, 12), (3627, 12), (3628, 12), (3629, 12), (3630, 12), (3631, 12), (3632, 12), (3633, 12), (3634, 12), (3635, 12), (3636, 12), (3637, 12), (3638, 12), (3639, 12), (3640, 12), (3641, 12), (3642, 12), (3643, 12), (3644, 12), (3645, 12), (3646, 12), (3647, 12), (3648, 12), (3649, 12), (3650, 12), (3651, 12), (3652, 12), (3653, 12), (3654, 12), (3655, 12), (3656, 12), (3657, 12), (3658, 12), (3659, 12), (3660, 12), (3661, 12), (3662, 12), (3663, 12), (3664, 12), (3665, 12), (3666, 12), (3667, 12), (3668, 12), (3669, 12), (3670, 12), (3671, 12), (3672, 12), (3673, 12), (3674, 12), (3675, 12), (3676, 12), (3677, 12), (3678, 12), (3679, 12), (3680, 12), (3681, 12), (3682, 12), (3683, 12), (3684, 12), (3685, 12), (3686, 12), (3687, 12), (3688, 12), (3689, 12), (3690, 12), (3691, 12), (3692, 12), (3693, 12), (3694, 12), (3695, 12), (3696, 12), (3697, 12), (3698, 12), (3699, 12), (3700, 12), (3701, 12), (3702, 12), (3703, 12), (3704, 12), (3705, 12), (3706, 12), (3707, 12), (3708, 12), (3709, 12), (3710, 12), (3711, 12), (3712, 12), (3713, 12), (3714, 12), (3715, 12), (3716, 12), (3717, 12), (3718, 12), (3719, 12), (3720, 12), (3721, 12), (3722, 12), (3723, 12), (3724, 12), (3725, 12), (3726, 12), (3727, 12), (3728, 12), (3729, 12), (3730, 12), (3731, 12), (3732, 12), (3733, 12), (3734, 12), (3735, 12), (3736, 12), (3737, 12), (3738, 12), (3739, 12), (3740, 12), (3741, 12), (3742, 12), (3743, 12), (3744, 12), (3745, 12), (3746, 12), (3747, 12), (3748, 12), (3749, 12), (3750, 12), (3751, 12), (3752, 12), (3753, 12), (3754, 12), (3755, 12), (3756, 12), (3757, 12), (3758, 12), (3759, 12), (3760, 12), (3761, 12), (3762, 12), (3763, 12), (3764, 12), (3765, 12), (3766, 12), (3767, 12), (3768, 12), (3769, 12), (3770, 12), (3771, 12), (3772, 12), (3773, 12), (3774, 12), (3775, 12), (3776, 12), (3777, 12), (3778, 12), (3779, 12), (3780, 12), (3781, 12), (3782, 12), (3783, 12), (3784, 12), (3785, 12), (3786, 12), (3787, 12), (3788, 12), (3789, 12), (3790, 12), (3791, 12), (3792, 12), (3793, 12), (3794, 12), (3795, 12), (3796, 12), (3797, 12), (3798, 12), (3799, 12), (3800, 12), (3801, 12), (3802, 12), (3803, 12), (3804, 12), (3805, 12), (3806, 12), (3807, 12), (3808, 12), (3809, 12), (3810, 12), (3811, 12), (3812, 12), (3813, 12), (3814, 12), (3815, 12), (3816, 12), (3817, 12), (3818, 12), (3819, 12), (3820, 12), (3821, 12), (3822, 12), (3823, 12), (3824, 12), (3825, 12), (3826, 12), (3827, 12), (3828, 12), (3829, 12), (3830, 12), (3831, 12), (3832, 12), (3833, 12), (3834, 12), (3835, 12), (3836, 12), (3837, 12), (3838, 12), (3839, 12), (3840, 12), (3841, 12), (3842, 12), (3843, 12), (3844, 12), (3845, 12), (3846, 12), (3847, 12), (3848, 12), (3849, 12), (3850, 12), (3851, 12), (3852, 12), (3853, 12), (3854, 12), (3855, 12), (3856, 12), (3857, 12), (3858, 12), (3859, 12), (3860, 12), (3861, 12), (3862, 12), (3863, 12), (3864, 12), (3865, 12), (3866, 12), (3867, 12), (3868, 12), (3869, 12), (3870, 12), (3871, 12), (3872, 12), (3873, 12), (3874, 12), (3875, 12), (3876, 12), (3877, 12), (3878, 12), (3879, 12), (3880, 12), (3881, 12), (3882, 12), (3883, 12), (3884, 12), (3885, 12), (3886, 12), (3887, 12), (3888, 12), (3889, 12), (3890, 12), (3891, 12), (3892, 12), (3893, 12), (3894, 12), (3895, 12), (3896, 12), (3897, 12), (3898, 12), (3899, 12), (3900, 12), (3901, 12), (3902, 12), (3903, 12), (3904, 12), (3905, 12), (3906, 12), (3907, 12), (3908, 12), (3909, 12), (3910, 12), (3911, 12), (3912, 12), (3913, 12), (3914, 12), (3915, 12), (3916, 12), (3917, 12), (3918, 12), (3919, 12), (3920, 12), (3921, 12), (3922, 12), (3923, 12), (3924, 12), (3925, 12), (3926, 12), (3927, 12), (3928, 12), (3929, 12), (3930, 12), (3931, 12), (3932, 12), (3933, 12), (3934, 12), (3935, 12), (3936, 12), (3937, 12), (3938, 12), (3939, 12), (3940, 12), (3941, 12), (3942, 12), (3943, 12), (3944, 12), (3945, 12), (3946, 12), (3947, 12), (3948, 12), (3949, 12), (3950, 12), (3951, 12), (3952, 12), (3953, 12), (3954, 12), (3955, 12), (3956, 12), (3957, 12), (3958, 12), (3959, 12), (3960, 12), (3961, 12), (3962, 12), (3963, 12), (3964, 12), (3965, 12), (3966, 12), (3967, 12), (3968, 12), (3969, 12), (3970, 12), (3971, 12), (3972, 12), (3973, 12), (3974, 12), (3975, 12), (3976, 12), (3977, 12), (3978, 12), (3979, 12), (3980, 12), (3981, 12), (3982, 12), (3983, 12), (3984, 12), (3985, 12), (3986, 12), (3987, 12), (3988, 12), (3989, 12), (3990, 12), (3991, 12), (3992, 12), (3993, 12), (3994, 12), (3995, 12), (3996, 12), (3997, 12), (3998, 12), (3999, 12), (4000, 12), (4001, 12), (4002, 12), (4003, 12), (4004, 12), (4005, 12), (4006, 12), (4007, 12), (4008, 12), (4009, 12), (4010, 12), (4011, 12), (4012, 12), (4013, 12), (4014, 12), (4015, 12), (4016, 12), (4017, 12), (4018, 12), (4019, 12), (4020, 12), (4021, 12), (4022, 12), (4023, 12), (4024, 12), (4025, 12), (4026, 12), (4027, 12), (4028, 12), (4029, 12), (4030, 12), (4031, 12), (4032, 12), (4033, 12), (4034, 12), (4035, 12), (4036, 12), (4037, 12), (4038, 12), (4039, 12), (4040, 12), (4041, 12), (4042, 12), (4043, 12), (4044, 12), (4045, 12), (4046, 12), (4047, 12), (4048, 12), (4049, 12), (4050, 12), (4051, 12), (4052, 12), (4053, 12), (4054, 12), (4055, 12), (4056, 12), (4057, 12), (4058, 12), (4059, 12), (4060, 12), (4061, 12), (4062, 12), (4063, 12), (4064, 12), (4065, 12), (4066, 12), (4067, 12), (4068, 12), (4069, 12), (4070, 12), (4071, 12), (4072, 12), (4073, 12), (4074, 12), (4075, 12), (4076, 12), (4077, 12), (4078, 12), (4079, 12), (4080, 12), (4081, 12), (4082, 12), (4083, 12), (4084, 12), (4085, 12), (4086, 12), (4087, 12), (4088, 12), (4089, 12), (4090, 12), (4091, 12), (4092, 12), (4093, 12), (4094, 12), (4095, 12), (0, 13), (1, 13), (2, 13), (3, 13), (4, 13), (5, 13), (6, 13), (7, 13), (8, 13), (9, 13), (10, 13), (11, 13), (12, 13), (13, 13), (14, 13), (15, 13), (16, 13), (17, 13), (18, 13), (19, 13), (20, 13), (21, 13), (22, 13), (23, 13), (24, 13), (25, 13), (26, 13), (27, 13), (28, 13), (29, 13), (30, 13), (31, 13), (32, 13), (33, 13), (34, 13), (35, 13), (36, 13), (37, 13), (38, 13), (39, 13), (40, 13), (41, 13), (42, 13), (43, 13), (44, 13), (45, 13), (46, 13), (47, 13), (48, 13), (49, 13), (50, 13), (51, 13), (52, 13), (53, 13), (54, 13), (55, 13), (56, 13), (57, 13), (58, 13), (59, 13), (60, 13), (61, 13), (62, 13), (63, 13), (64, 13), (65, 13), (66, 13), (67, 13), (68, 13), (69, 13), (70, 13), (71, 13), (72, 13), (73, 13), (74, 13), (75, 13), (76, 13), (77, 13), (78, 13), (79, 13), (80, 13), (81, 13), (82, 13), (83, 13), (84, 13), (85, 13), (86, 13), (87, 13), (88, 13), (89, 13), (90, 13), (91, 13), (92, 13), (93, 13), (94, 13), (95, 13), (96, 13), (97, 13), (98, 13), (99, 13), (100, 13), (101, 13), (102, 13), (103, 13), (104, 13), (105, 13), (106, 13), (107, 13), (108, 13), (109, 13), (110, 13), (111, 13), (112, 13), (113, 13), (114, 13), (115, 13), (116, 13), (117, 13), (118, 13), (119, 13), (120, 13), (121, 13), (122, 13), (123, 13), (124, 13), (125, 13), (126, 13), (127, 13), (128, 13), (129, 13), (130, 13), (131, 13), (132, 13), (133, 13), (134, 13), (135, 13), (136, 13), (137, 13), (138, 13), (139, 13), (140, 13), (141, 13), (142, 13), (143, 13), (144, 13), (145, 13), (146, 13), (147, 13), (148, 13), (149, 13), (150, 13), (151, 13), (152, 13), (153, 13), (154, 13), (155, 13), (156, 13), (157, 13), (158, 13), (159, 13), (160, 13), (161, 13), (162, 13), (163, 13), (164, 13), (165, 13), (166, 13), (167, 13), (168, 13), (169, 13), (170, 13), (171, 13), (172, 13), (173, 13), (174, 13), (175, 13), (176, 13), (177, 13), (178, 13), (179, 13), (180, 13), (181, 13), (182, 13), (183, 13), (184, 13), (185, 13), (186, 13), (187, 13), (188, 13), (189, 13), (190, 13), (191, 13), (192, 13), (193, 13), (194, 13), (195, 13), (196, 13), (197, 13), (198, 13), (199, 13), (200, 13), (201, 13), (202, 13), (203, 13), (204, 13), (205, 13), (206, 13), (207, 13), (208, 13), (209, 13), (210, 13), (211, 13), (212, 13), (213, 13), (214, 13), (215, 13), (216, 13), (217, 13), (218, 13), (219, 13), (220, 13), (221, 13), (222, 13), (223, 13), (224, 13), (225, 13), (226, 13), (227, 13), (228, 13), (229, 13), (230, 13), (231, 13), (232, 13), (233, 13), (234, 13), (235, 13), (236, 13), (237, 13), (238, 13), (239, 13), (240, 13), (241, 13), (242, 13), (243, 13), (244, 13), (245, 13), (246, 13), (247, 13), (248, 13), (249, 13), (250, 13), (251, 13), (252, 13), (253, 13), (254, 13), (255, 13), (256, 13), (257, 13), (258, 13), (259, 13), (260, 13), (261, 13), (262, 13), (263, 13), (264, 13), (265, 13), (266, 13), (267, 13), (268, 13), (269, 13), (270, 13), (271, 13), (272, 13), (273, 13), (274, 13), (275, 13), (276, 13), (277, 13), (278, 13), (279, 13), (280, 13), (281, 13), (282, 13), (283, 13), (284, 13), (285, 13), (286, 13), (287, 13), (288, 13), (289, 13), (290, 13), (291, 13), (292, 13), (293, 13), (294, 13), (295, 13), (296, 13), (297, 13), (298, 13), (299, 13), (300, 13), (301, 13), (302, 13), (303, 13), (304, 13), (305, 13), (306, 13), (307, 13), (308, 13), (309, 13), (310, 13), (311, 13), (312, 13), (313, 13), (314, 13), (315, 13), (316, 13), (317, 13), (318, 13), (319, 13), (320, 13), (321, 13), (322, 13), (323, 13), (324, 13), (325, 13), (326, 13), (327, 13), (328, 13), (329, 13), (330, 13), (331, 13), (332, 13), (333, 13), (334, 13), (335, 13), (336, 13), (337, 13), (338, 13), (339, 13), (340, 13), (341, 13), (342, 13), (343, 13), (344, 13), (345, 13), (346, 13), (347, 13), (348, 13), (349, 13), (350, 13), (351, 13), (352, 13), (353, 13), (354, 13), (355, 13), (356, 13), (357, 13), (358, 13), (359, 13), (360, 13), (361, 13), (362, 13), (363, 13), (364, 13), (365, 13), (366, 13), (367, 13), (368, 13), (369, 13), (370, 13), (371, 13), (372, 13), (373, 13), (374, 13), (375, 13), (376, 13), (377, 13), (378, 13), (379, 13), (380, 13), (381, 13), (382, 13), (383, 13), (384, 13), (385, 13), (386, 13), (387, 13), (388, 13), (389, 13), (390, 13), (391, 13), (392, 13), (393, 13), (394, 13), (395, 13), (396, 13), (397, 13), (398, 13), (399, 13), (400, 13), (401, 13), (402, 13), (403, 13), (404, 13), (405, 13), (406, 13), (407, 13), (408, 13), (409, 13), (410, 13), (411, 13), (412, 13), (413, 13), (414, 13), (415, 13), (416, 13), (417, 13), (418, 13), (419, 13), (420, 13), (421, 13), (422, 13), (423, 13), (424, 13), (425, 13), (426, 13), (427, 13), (428, 13), (429, 13), (430, 13), (431, 13), (432, 13), (433, 13), (434, 13), (435, 13), (436, 13), (437, 13), (438, 13), (439, 13), (440, 13), (441, 13), (442, 13), (443, 13), (444, 13), (445, 13), (446, 13), (447, 13), (448, 13), (449, 13), (450, 13), (451, 13), (452, 13), (453, 13), (454, 13), (455, 13), (456, 13), (457, 13), (458, 13), (459, 13), (460, 13), (461, 13), (462, 13), (463, 13), (464, 13), (465, 13), (466, 13), (467, 13), (468, 13), (469, 13), (470, 13), (471, 13), (472, 13), (473, 13), (474, 13), (475, 13), (476, 13), (477, 13), (478, 13), (479, 13), (480, 13), (481, 13), (482, 13), (483, 13), (484, 13), (485, 13), (486, 13), (487, 13), (488, 13), (489, 13), (490, 13), (491, 13), (492, 13), (493, 13), (494, 13), (495, 13), (496, 13), (497, 13), (498, 13), (499, 13), (500, 13), (501, 13), (502, 13), (503, 13), (504, 13), (505, 13), (506, 13), (507, 13), (508, 13), (509, 13), (510, 13), (511, 13), (512, 13), (513, 13), (514, 13), (515, 13), (516, 13), (517, 13), (518, 13), (519, 13), (520, 13), (521, 13), (522, 13), (523, 13), (524, 13), (525, 13), (526, 13), (527, 13), (528, 13), (529, 13), (530, 13), (531, 13), (532, 13), (533, 13), (534, 13), (535, 13), (536, 13), (537, 13), (538, 13), (539, 13), (540, 13), (541, 13), (542, 13), (543, 13), (544, 13), (545, 13), (546, 13), (547, 13), (548, 13), (549, 13), (550, 13), (551, 13), (552, 13), (553, 13), (554, 13), (555, 13), (556, 13), (557, 13), (558, 13), (559, 13), (560, 13), (561, 13), (562, 13), (563, 13), (564, 13), (565, 13), (566, 13), (567, 13), (568, 13), (569, 13), (570, 13), (571, 13), (572, 13), (573, 13), (574, 13), (575, 13), (576, 13), (577, 13), (578, 13), (579, 13), (580, 13), (581, 13), (582, 13), (583, 13), (584, 13), (585, 13), (586, 13), (587, 13), (588, 13), (589, 13), (590, 13), (591, 13), (592, 13), (593, 13), (594, 13), (595, 13), (596, 13), (597, 13), (598, 13), (599, 13), (600, 13), (601, 13), (602, 13), (603, 13), (604, 13), (605, 13), (606, 13), (607, 13), (608, 13), (609, 13), (610, 13), (611, 13), (612, 13), (613, 13), (614, 13), (615, 13), (616, 13), (617, 13), (618, 13), (619, 13), (620, 13), (621, 13), (622, 13), (623, 13), (624, 13), (625, 13), (626, 13), (627, 13), (628, 13), (629, 13), (630, 13), (631, 13), (632, 13), (633, 13), (634, 13), (635, 13), (636, 13), (637, 13), (638, 13), (639, 13), (640, 13), (641, 13), (642, 13), (643, 13), (644, 13), (645, 13), (646, 13), (647, 13), (648, 13), (649, 13), (650, 13), (651, 13), (652, 13), (653, 13), (654, 13), (655, 13), (656, 13), (657, 13), (658, 13), (659, 13), (660, 13), (661, 13), (662, 13), (663, 13), (664, 13), (665, 13), (666, 13), (667, 13), (668, 13), (669, 13), (670, 13), (671, 13), (672, 13), (673, 13), (674, 13), (675, 13), (676, 13), (677, 13), (678, 13), (679, 13), (680, 13), (681, 13), (682, 13), (683, 13), (684, 13), (685, 13), (686, 13), (687, 13), (688, 13), (689, 13), (690, 13), (691, 13), (692, 13), (693, 13), (694, 13), (695, 13), (696, 13), (697, 13), (698, 13), (699, 13), (700, 13), (701, 13), (702, 13), (703, 13), (704, 13), (705, 13), (706, 13), (707, 13), (708, 13), (709, 13), (710, 13), (711, 13), (712, 13), (713, 13), (714, 13), (715, 13), (716, 13), (717, 13), (718, 13), (719, 13), (720, 13), (721, 13), (722, 13), (723, 13), (724, 13), (725, 13), (726, 13), (727, 13), (728, 13), (729, 13), (730, 13), (731, 13), (732, 13), (733, 13), (734, 13), (735, 13), (736, 13), (737, 13), (738, 13), (739, 13), (740, 13), (741, 13), (742, 13), (743, 13), (744, 13), (745, 13), (746, 13), (747, 13), (748, 13), (749, 13), (750, 13), (751, 13), (752, 13), (753, 13), (754, 13), (755, 13), (756, 13), (757, 13), (758, 13), (759, 13), (760, 13), (761, 13), (762, 13), (763, 13), (764, 13), (765, 13), (766, 13), (767, 13), (768, 13), (769, 13), (770, 13), (771, 13), (772, 13), (773, 13), (774, 13), (775, 13), (776, 13), (777, 13), (778, 13), (779, 13), (780, 13), (781, 13), (782, 13), (783, 13), (784, 13), (785, 13), (786, 13), (787, 13), (788, 13), (789, 13), (790, 13), (791, 13), (792, 13), (793, 13), (794, 13), (795, 13), (796, 13), (797, 13), (798, 13), (799, 13), (800, 13), (801, 13), (802, 13), (803, 13), (804, 13), (805, 13), (806, 13), (807, 13), (808, 13), (809, 13), (810, 13), (811, 13), (812, 13), (813, 13), (814, 13), (815, 13), (816, 13), (817, 13), (818, 13), (819, 13), (820, 13), (821, 13), (822, 13), (823, 13), (824, 13), (825, 13), (826, 13), (827, 13), (828, 13), (829, 13), (830, 13), (831, 13), (832, 13), (833, 13), (834, 13), (835, 13), (836, 13), (837, 13), (838, 13), (839, 13), (840, 13), (841, 13), (842, 13), (843, 13), (844, 13), (845, 13), (846, 13), (847, 13), (848, 13), (849, 13), (850, 13), (851, 13), (852, 13), (853, 13), (854, 13), (855, 13), (856, 13), (857, 13), (858, 13), (859, 13), (860, 13), (861, 13), (862, 13), (863, 13), (864, 13), (865, 13), (866, 13), (867, 13), (868, 13), (869, 13), (870, 13), (871, 13), (872, 13), (873, 13), (874, 13), (875, 13), (876, 13), (877, 13), (878, 13), (879, 13), (880, 13), (881, 13), (882, 13), (883, 13), (884, 13), (885, 13), (886, 13), (887, 13), (888, 13), (889, 13), (890, 13), (891, 13), (892, 13), (893, 13), (894, 13), (895, 13), (896, 13), (897, 13), (898, 13), (899, 13), (900, 13), (901, 13), (902, 13), (903, 13), (904, 13), (905, 13), (906, 13), (907, 13), (908, 13), (909, 13), (910, 13), (911, 13), (912, 13), (913, 13), (914, 13), (915, 13), (916, 13), (917, 13), (918, 13), (919, 13), (920, 13), (921, 13), (922, 13), (923, 13), (924, 13), (925, 13), (926, 13), (927, 13), (928, 13), (929, 13), (930, 13), (931, 13), (932, 13), (933, 13), (934, 13), (935, 13), (936, 13), (937, 13), (938, 13), (939, 13), (940, 13), (941, 13), (942, 13), (943, 13), (944, 13), (945, 13), (946, 13), (947, 13), (948, 13), (949, 13), (950, 13), (951, 13), (952, 13), (953, 13), (954, 13), (955, 13), (956, 13), (957, 13), (958, 13), (959, 13), (960, 13), (961, 13), (962, 13), (963, 13), (964, 13), (965, 13), (966, 13), (967, 13), (968, 13), (969, 13), (970, 13), (971, 13), (972, 13), (973, 13), (974, 13), (975, 13), (976, 13), (977, 13), (978, 13), (979, 13), (980, 13), (981, 13), (982, 13), (983, 13), (984, 13), (985, 13), (986, 13), (987, 13), (988, 13), (989, 13), (990, 13), (991, 13), (992, 13), (993, 13), (994, 13), (995, 13), (996, 13), (997, 13), (998, 13), (999, 13), (1000, 13), (1001, 13), (1002, 13), (1003, 13), (1004, 13), (1005, 13), (1006, 13), (1007, 13), (1008, 13), (1009, 13), (1010, 13), (1011, 13), (1012, 13), (1013, 13), (1014, 13), (1015, 13), (1016, 13), (1017, 13), (1018, 13), (1019, 13), (1020, 13), (1021, 13), (1022, 13), (1023, 13), (1024, 13), (1025, 13), (1026, 13), (1027, 13), (1028, 13), (1029, 13), (1030, 13), (1031, 13), (1032, 13), (1033, 13), (1034, 13), (1035, 13), (1036, 13), (1037, 13), (1038, 13), (1039, 13), (1040, 13), (1041, 13), (1042, 13), (1043, 13), (1044, 13), (1045, 13), (1046, 13), (1047, 13), (1048, 13), (1049, 13), (1050, 13), (1051, 13), (1052, 13), (1053, 13), (1054, 13), (1055, 13), (1056, 13), (1057, 13), (1058, 13), (1059, 13), (1060, 13), (1061, 13), (1062, 13), (1063, 13), (1064, 13), (1065, 13), (1066, 13), (1067, 13), (1068, 13), (1069, 13), (1070, 13), (1071, 13), (1072, 13), (1073, 13), (1074, 13), (1075, 13), (1076, 13), (1077, 13), (1078, 13), (1079, 13), (1080, 13), (1081, 13), (1082, 13), (1083, 13), (1084, 13), (1085, 13), (1086, 13), (1087, 13), (1088, 13), (1089, 13), (1090, 13), (1091, 13), (1092, 13), (1093, 13), (1094, 13), (1095, 13), (1096, 13), (1097, 13), (1098, 13), (1099, 13), (1100, 13), (1101, 13), (1102, 13), (1103, 13), (1104, 13), (1105, 13), (1106, 13), (1107, 13), (1108, 13), (1109, 13), (1110, 13), (1111, 13), (1112, 13), (1113, 13), (1114, 13), (1115, 13), (1116, 13), (1117, 13), (1118, 13), (1119, 13), (1120, 13), (1121, 13), (1122, 13), (1123, 13), (1124, 13), (1125, 13), (1126, 13), (1127, 13), (1128, 13), (1129, 13), (1130, 13), (1131, 13), (1132, 13), (1133, 13), (1134, 13), (1135, 13), (1136, 13), (1137, 13), (1138, 13), (1139, 13), (1140, 13), (1141, 13), (1142, 13), (1143, 13), (1144, 13), (1145, 13), (1146, 13), (1147, 13), (1148, 13), (1149, 13), (1150, 13), (1151, 13), (1152, 13), (1153, 13), (1154, 13), (1155, 13), (1156, 13), (1157, 13), (1158, 13), (1159, 13), (1160, 13), (1161, 13), (1162, 13), (1163, 13), (1164, 13), (1165, 13), (1166, 13), (1167, 13), (1168, 13), (1169, 13), (1170, 13), (1171, 13), (1172, 13), (1173, 13), (1174, 13), (1175, 13), (1176, 13), (1177, 13), (1178, 13), (1179, 13), (1180, 13), (1181, 13), (1182, 13), (1183, 13), (1184, 13), (1185, 13), (1186, 13), (1187, 13), (1188, 13), (1189, 13), (1190, 13), (1191, 13), (1192, 13), (1193, 13), (1194, 13), (1195, 13), (1196, 13), (1197, 13), (1198, 13), (1199, 13), (1200, 13), (1201, 13), (1202, 13), (1203, 13), (1204, 13), (1205, 13), (1206, 13), (1207, 13), (1208, 13), (1209, 13), (1210, 13), (1211, 13), (1212, 13), (1213, 13), (1214, 13), (1215, 13), (1216, 13), (1217, 13), (1218, 13), (1219, 13), (1220, 13), (1221, 13), (1222, 13), (1223, 13), (1224, 13), (1225, 13), (1226, 13), (1227, 13), (1228, 13), (1229, 13), (1230, 13), (1231, 13), (1232, 13), (1233, 13), (1234, 13), (1235, 13), (1236, 13), (1237, 13), (1238, 13), (1239, 13), (1240, 13), (1241, 13), (1242, 13), (1243, 13), (1244, 13), (1245, 13), (1246, 13), (1247, 13), (1248, 13), (1249, 13), (1250, 13), (1251, 13), (1252, 13), (1253, 13), (1254, 13), (1255, 13), (1256, 13), (1257, 13), (1258, 13), (1259, 13), (1260, 13), (1261, 13), (1262, 13), (1263, 13), (1264, 13), (1265, 13), (1266, 13), (1267, 13), (1268, 13), (1269, 13), (1270, 13), (1271, 13), (1272, 13), (1273, 13), (1274, 13), (1275, 13), (1276, 13), (1277, 13), (1278, 13), (1279, 13), (1280, 13), (1281, 13), (1282, 13), (1283, 13), (1284, 13), (1285, 13), (1286, 13), (1287, 13), (1288, 13), (1289, 13), (1290, 13), (1291, 13), (1292, 13), (1293, 13), (1294, 13), (1295, 13), (1296, 13), (1297, 13), (1298, 13), (1299, 13), (1300, 13), (1301, 13), (1302, 13), (1303, 13), (1304, 13), (1305, 13), (1306, 13), (1307, 13), (1308, 13), (1309, 13), (1310, 13), (1311, 13), (1312, 13), (1313, 13), (1314, 13), (1315, 13), (1316, 13), (1317, 13), (1318, 13), (1319, 13), (1320, 13), (1321, 13), (1322, 13), (1323, 13), (1324, 13), (1325, 13), (1326, 13), (1327, 13), (1328, 13), (1329, 13), (1330, 13), (1331, 13), (1332, 13), (1333, 13), (1334, 13), (1335, 13), (1336, 13), (1337, 13), (1338, 13), (1339, 13), (1340, 13), (1341, 13), (1342, 13), (1343, 13), (1344, 13), (1345, 13), (1346, 13), (1347, 13), (1348, 13), (1349, 13), (1350, 13), (1351, 13), (1352, 13), (1353, 13), (1354, 13), (1355, 13), (1356, 13), (1357, 13), (1358, 13), (1359, 13), (1360, 13), (1361, 13), (1362, 13), (1363, 13), (1364, 13), (1365, 13), (1366, 13), (1367, 13), (1368, 13), (1369, 13), (1370, 13), (1371, 13), (1372, 13), (1373, 13), (1374, 13), (1375, 13), (1376, 13), (1377, 13), (1378, 13), (1379, 13), (1380, 13), (1381, 13), (1382, 13), (1383, 13), (1384, 13), (1385, 13), (1386, 13), (1387, 13), (1388, 13), (1389, 13), (1390, 13), (1391, 13), (1392, 13), (1393, 13), (1394, 13), (1395, 13), (1396, 13), (1397, 13), (1398, 13), (1399, 13), (1400, 13), (1401, 13), (1402, 13), (1403, 13), (1404, 13), (1405, 13), (1406, 13), (1407, 13), (1408, 13), (1409, 13), (1410, 13), (1411, 13), (1412, 13), (1413, 13), (1414, 13), (1415, 13), (1416, 13), (1417, 13), (1418, 13), (1419, 13), (1420, 13), (1421, 13), (1422, 13), (1423, 13), (1424, 13), (1425, 13), (1426, 13), (1427, 13), (1428, 13), (1429, 13), (1430, 13), (1431, 13), (1432, 13), (1433, 13), (1434, 13), (1435, 13), (1436, 13), (1437, 13), (1438, 13), (1439, 13), (1440, 13), (1441, 13), (1442, 13), (1443, 13), (1444, 13), (1445, 13), (1446, 13), (1447, 13), (1448, 13), (1449, 13), (1450, 13), (1451, 13), (1452, 13), (1453, 13), (1454, 13), (1455, 13), (1456, 13), (1457, 13), (1458, 13), (1459, 13), (1460, 13), (1461, 13), (1462, 13), (1463, 13), (1464, 13), (1465, 13), (1466, 13), (1467, 13), (1468, 13), (1469, 13), (1470, 13), (1471, 13), (1472, 13), (1473, 13), (1474, 13), (1475, 13), (1476, 13), (1477, 13), (1478, 13), (1479, 13), (1480, 13), (1481, 13), (1482, 13), (1483, 13), (1484, 13), (1485, 13), (1486, 13), (1487, 13), (1488, 13), (1489, 13), (1490, 13), (1491, 13), (1492, 13), (1493, 13), (1494, 13), (1495, 13), (1496, 13), (1497, 13), (1498, 13), (1499, 13), (1500, 13), (1501, 13), (1502, 13), (1503, 13), (1504, 13), (1505, 13), (1506, 13), (1507, 13), (1508, 13), (1509, 13), (1510, 13), (1511, 13), (1512, 13), (1513, 13), (1514, 13), (1515, 13), (1516, 13), (1517, 13), (1518, 13), (1519, 13), (1520, 13), (1521, 13), (1522, 13), (1523, 13), (1524, 13), (1525, 13), (1526, 13), (1527, 13), (1528, 13), (1529, 13), (1530, 13), (1531, 13), (1532, 13), (1533, 13), (1534, 13), (1535, 13), (1536, 13), (1537, 13), (1538, 13), (1539, 13), (1540, 13), (1541, 13), (1542, 13), (1543, 13), (1544, 13), (1545, 13), (1546, 13), (1547, 13), (1548, 13), (1549, 13), (1550, 13), (1551, 13), (1552, 13), (1553, 13), (1554, 13), (1555, 13), (1556, 13), (1557, 13), (1558, 13), (1559, 13), (1560, 13), (1561, 13), (1562, 13), (1563, 13), (1564, 13), (1565, 13), (1566, 13), (1567, 13), (1568, 13), (1569, 13), (1570, 13), (1571, 13), (1572, 13), (1573, 13), (1574, 13), (1575, 13), (1576, 13), (1577, 13), (1578, 13), (1579, 13), (1580, 13), (1581, 13), (1582, 13), (1583, 13), (1584, 13), (1585, 13), (1586, 13), (1587, 13), (1588, 13), (1589, 13), (1590, 13), (1591, 13), (1592, 13), (1593, 13), (1594, 13), (1595, 13), (1596, 13), (1597, 13), (1598, 13), (1599, 13), (1600, 13), (1601, 13), (1602, 13), (1603, 13), (1604, 13), (1605, 13), (1606, 13), (1607, 13), (1608, 13), (1609, 13), (1610, 13), (1611, 13), (1612, 13), (1613, 13), (1614, 13), (1615, 13), (1616, 13), (1617, 13), (1618, 13), (1619, 13), (1620, 13), (1621, 13), (1622, 13), (1623, 13), (1624, 13), (1625, 13), (1626, 13), (1627, 13), (1628, 13), (1629, 13), (1630, 13), (1631, 13), (1632, 13), (1633, 13), (1634, 13), (1635, 13), (1636, 13), (1637, 13), (1638, 13), (1639, 13), (1640, 13), (1641, 13), (1642, 13), (1643, 13), (1644, 13), (1645, 13), (1646, 13), (1647, 13), (1648, 13), (1649, 13), (1650, 13), (1651, 13), (1652, 13), (1653, 13), (1654, 13), (1655, 13), (1656, 13), (1657, 13), (1658, 13), (1659, 13), (1660, 13), (1661, 13), (1662, 13), (1663, 13), (1664, 13), (1665, 13), (1666, 13), (1667, 13), (1668, 13), (1669, 13), (1670, 13), (1671, 13), (1672, 13), (1673, 13), (1674, 13), (1675, 13), (1676, 13), (1677, 13), (1678, 13), (1679, 13), (1680, 13), (1681, 13), (1682, 13), (1683, 13), (1684, 13), (1685, 13), (1686, 13), (1687, 13), (1688, 13), (1689, 13), (1690, 13), (1691, 13), (1692, 13), (1693, 13), (1694, 13), (1695, 13), (1696, 13), (1697, 13), (1698, 13), (1699, 13), (1700, 13), (1701, 13), (1702, 13), (1703, 13), (1704, 13), (1705, 13), (1706, 13), (1707, 13), (1708, 13), (1709, 13), (1710, 13), (1711, 13), (1712, 13), (1713, 13), (1714, 13), (1715, 13), (1716, 13), (1717, 13), (1718, 13), (1719, 13), (1720, 13), (1721, 13), (1722, 13), (1723, 13), (1724, 13), (1725, 13), (1726, 13), (1727, 13), (1728, 13), (1729, 13), (1730, 13), (1731, 13), (1732, 13), (1733, 13), (1734, 13), (1735, 13), (1736, 13), (1737, 13), (1738, 13), (1739, 13), (1740, 13), (1741, 13), (1742, 13), (1743, 13), (1744, 13), (1745, 13), (1746, 13), (1747, 13), (1748, 13), (1749, 13), (1750, 13), (1751, 13), (1752, 13), (1753, 13), (1754, 13), (1755, 13), (1756, 13), (1757, 13), (1758, 13), (1759, 13), (1760, 13), (1761, 13), (1762, 13), (1763, 13), (1764, 13), (1765, 13), (1766, 13), (1767, 13), (1768, 13), (1769, 13), (1770, 13), (1771, 13), (1772, 13), (1773, 13), (1774, 13), (1775, 13), (1776, 13), (1777, 13), (1778, 13), (1779, 13), (1780, 13), (1781, 13), (1782, 13), (1783, 13), (1784, 13), (1785, 13), (1786, 13), (1787, 13), (1788, 13), (1789, 13), (1790, 13), (1791, 13), (1792, 13), (1793, 13), (1794, 13), (1795, 13), (1796, 13), (1797, 13), (1798, 13), (1799, 13), (1800, 13), (1801, 13), (1802, 13), (1803, 13), (1804, 13), (1805, 13), (1806, 13), (1807, 13), (1808, 13), (1809, 13), (1810, 13), (1811, 13), (1812, 13), (1813, 13), (1814, 13), (1815, 13), (1816, 13), (1817, 13), (1818, 13), (1819, 13), (1820, 13), (1821, 13), (1822, 13), (1823, 13), (1824, 13), (1825, 13), (1826, 13), (1827, 13), (1828, 13), (1829, 13), (1830, 13), (1831, 13), (1832, 13), (1833, 13), (1834, 13), (1835, 13), (1836, 13), (1837, 13), (1838, 13), (1839, 13), (1840, 13), (1841, 13), (1842, 13), (1843, 13), (1844, 13), (1845, 13), (1846, 13), (1847, 13), (1848, 13), (1849, 13), (1850, 13), (1851, 13), (1852, 13), (1853, 13), (1854, 13), (1855, 13), (1856, 13), (1857, 13), (1858, 13), (1859, 13), (1860, 13), (1861, 13), (1862, 13), (1863, 13), (1864, 13), (1865, 13), (1866, 13), (1867, 13), (1868, 13), (1869, 13), (1870, 13), (1871, 13), (1872, 13), (1873, 13), (1874, 13), (1875, 13), (1876, 13), (1877, 13), (1878, 13), (1879, 13), (1880, 13), (1881, 13), (1882, 13), (1883, 13), (1884, 13), (1885, 13), (1886, 13), (1887, 13), (1888, 13), (1889, 13), (1890, 13), (1891, 13), (1892, 13), (1893, 13), (1894, 13), (1895, 13), (1896, 13), (1897, 13), (1898, 13), (1899, 13), (1900, 13), (1901, 13), (1902, 13), (1903, 13), (1904, 13), (1905, 13), (1906, 13), (1907, 13), (1908, 13), (1909, 13), (1910, 13), (1911, 13), (1912, 13), (1913, 13), (1914, 13), (1915, 13), (1916, 13), (1917, 13), (1918, 13), (1919, 13), (1920, 13), (1921, 13), (1922, 13), (1923, 13), (1924, 13), (1925, 13), (1926, 13), (1927, 13), (1928, 13), (1929, 13), (1930, 13), (1931, 13), (1932, 13), (1933, 13), (1934, 13), (1935, 13), (1936, 13), (1937, 13), (1938, 13), (1939, 13), (1940, 13), (1941, 13), (1942, 13), (1943, 13), (1944, 13), (1945, 13), (1946, 13), (1947, 13), (1948, 13), (1949, 13), (1950, 13), (1951, 13), (1952, 13), (1953, 13), (1954, 13), (1955, 13), (1956, 13), (1957, 13), (1958, 13), (1959, 13), (1960, 13), (1961, 13), (1962, 13), (1963, 13), (1964, 13), (1965, 13), (1966, 13), (1967, 13), (1968, 13), (1969, 13), (1970, 13), (1971, 13), (1972, 13), (1973, 13), (1974, 13), (1975, 13), (1976, 13), (1977, 13), (1978, 13), (1979, 13), (1980, 13), (1981, 13), (1982, 13), (1983, 13), (1984, 13), (1985, 13), (1986, 13), (1987, 13), (1988, 13), (1989, 13), (1990, 13), (1991, 13), (1992, 13), (1993, 13), (1994, 13), (1995, 13), (1996, 13), (1997, 13), (1998, 13), (1999, 13), (2000, 13), (2001, 13), (2002, 13), (2003, 13), (2004, 13), (2005, 13), (2006, 13), (2007, 13), (2008, 13), (2009, 13), (2010, 13), (2011, 13), (2012, 13), (2013, 13), (2014, 13), (2015, 13), (2016, 13), (2017, 13), (2018, 13), (2019, 13), (2020, 13), (2021, 13), (2022, 13), (2023, 13), (2024, 13), (2025, 13), (2026, 13), (2027, 13), (2028, 13), (2029, 13), (2030, 13), (2031, 13), (2032, 13), (2033, 13), (2034, 13), (2035, 13), (2036, 13), (2037, 13), (2038, 13), (2039, 13), (2040, 13), (2041, 13), (2042, 13), (2043, 13), (2044, 13), (2045, 13), (2046, 13), (2047, 13), (2048, 13), (2049, 13), (2050, 13), (2051, 13), (2052, 13), (2053, 13), (2054, 13), (2055, 13), (2056, 13), (2057, 13), (2058, 13), (2059, 13), (2060, 13), (2061, 13), (2062, 13), (2063, 13), (2064, 13), (2065, 13), (2066, 13), (2067, 13), (2068, 13), (2069, 13), (2070, 13), (2071, 13), (2072, 13), (2073, 13), (2074, 13), (2075, 13), (2076, 13), (2077, 13), (2078, 13), (2079, 13), (2080, 13), (2081, 13), (2082, 13), (2083, 13), (2084, 13), (2085, 13), (2086, 13), (2087, 13), (2088, 13), (2089, 13), (2090, 13), (2091, 13), (2092, 13), (2093, 13), (2094, 13), (2095, 13), (2096, 13), (2097, 13), (2098, 13), (2099, 13), (2100, 13), (2101, 13), (2102, 13), (2103, 13), (2104, 13), (2105, 13), (2106, 13), (2107, 13), (2108, 13), (2109, 13), (2110, 13), (2111, 13), (2112, 13), (2113, 13), (2114, 13), (2115, 13), (2116, 13), (2117, 13), (2118, 13), (2119, 13), (2120, 13), (2121, 13), (2122, 13), (2123, 13), (2124, 13), (2125, 13), (2126, 13), (2127, 13), (2128, 13), (2129, 13), (2130, 13), (2131, 13), (2132, 13), (2133, 13), (2134, 13), (2135, 13), (2136, 13), (2137, 13), (2138, 13), (2139, 13), (2140, 13), (2141, 13), (2142, 13), (2143, 13), (2144, 13), (2145, 13), (2146, 13), (2147, 13), (2148, 13), (2149, 13), (2150, 13), (2151, 13), (2152, 13), (2153, 13), (2154, 13), (2155, 13), (2156, 13), (2157, 13), (2158, 13), (2159, 13), (2160, 13), (2161, 13), (2162, 13), (2163, 13), (2164, 13), (2165, 13), (2166, 13), (2167, 13), (2168, 13), (2169, 13), (2170, 13), (2171, 13), (2172, 13), (2173, 13), (2174, 13), (2175, 13), (2176, 13), (2177, 13), (2178, 13), (2179, 13), (2180, 13), (2181, 13), (2182, 13), (2183, 13), (2184, 13), (2185, 13), (2186, 13), (2187, 13), (2188, 13), (2189, 13), (2190, 13), (2191, 13), (2192, 13), (2193, 13), (2194, 13), (2195, 13), (2196, 13), (2197, 13), (2198, 13), (2199, 13), (2200, 13), (2201, 13), (2202, 13), (2203, 13), (2204, 13), (2205, 13), (2206, 13), (2207, 13), (2208, 13), (2209, 13), (2210, 13), (2211, 13), (2212, 13), (2213, 13), (2214, 13), (2215, 13), (2216, 13), (2217, 13), (2218, 13), (2219, 13), (2220, 13), (2221, 13), (2222, 13), (2223, 13), (2224, 13), (2225, 13), (2226, 13), (2227, 13), (2228, 13), (2229, 13), (2230, 13), (2231, 13), (2232, 13), (2233, 13), (2234, 13), (2235, 13), (2236, 13), (2237, 13), (2238, 13), (2239, 13), (2240, 13), (2241, 13), (2242, 13), (2243, 13), (2244, 13), (2245, 13), (2246, 13), (2247, 13), (2248, 13), (2249, 13), (2250, 13), (2251, 13), (2252, 13), (2253, 13), (2254, 13), (2255, 13), (2256, 13), (2257, 13), (2258, 13), (2259, 13), (2260, 13), (2261, 13), (2262, 13), (2263, 13), (2264, 13), (2265, 13), (2266, 13), (2267, 13), (2268, 13), (2269, 13), (2270, 13), (2271, 13), (2272, 13), (2273, 13), (2274, 13), (2275, 13), (2276, 13), (2277, 13), (2278, 13), (2279, 13), (2280, 13), (2281, 13), (2282, 13), (2283, 13), (2284, 13), (2285, 13), (2286, 13), (2287, 13), (2288, 13), (2289, 13), (2290, 13), (2291, 13), (2292, 13), (2293, 13), (2294, 13), (2295, 13), (2296, 13), (2297, 13), (2298, 13), (2299, 13), (2300, 13), (2301, 13), (2302, 13), (2303, 13), (2304, 13), (2305, 13), (2306, 13), (2307, 13), (2308, 13), (2309, 13), (2310, 13), (2311, 13), (2312, 13), (2313, 13), (2314, 13), (2315, 13), (2316, 13), (2317, 13), (2318, 13), (2319, 13), (2320, 13), (2321, 13), (2322, 13), (2323, 13), (2324, 13), (2325, 13), (2326, 13), (2327, 13), (2328, 13), (2329, 13), (2330, 13), (2331, 13), (2332, 13), (2333, 13), (2334, 13), (2335, 13), (2336, 13), (2337, 13), (2338, 13), (2339, 13), (2340, 13), (2341, 13), (2342, 13), (2343, 13), (2344, 13), (2345, 13), (2346, 13), (2347, 13), (2348, 13), (2349, 13), (2350, 13), (2351, 13), (2352, 13), (2353, 13), (2354, 13), (2355, 13), (2356, 13), (2357, 13), (2358, 13), (2359, 13), (2360, 13), (2361, 13), (2362, 13), (2363, 13), (2364, 13), (2365, 13), (2366, 13), (2367, 13), (2368, 13), (2369, 13), (2370, 13), (2371, 13), (2372, 13), (2373, 13), (2374, 13), (2375, 13), (2376, 13), (2377, 13), (2378, 13), (2379, 13), (2380, 13), (2381, 13), (2382, 13), (2383, 13), (2384, 13), (2385, 13), (2386, 13), (2387, 13), (2388, 13), (2389, 13), (2390, 13), (2391, 13), (2392, 13), (2393, 13), (2394, 13), (2395, 13), (2396, 13), (2397, 13), (2398, 13), (2399, 13), (2400, 13), (2401, 13), (2402, 13), (2403, 13), (2404, 13), (2405, 13), (2406, 13), (2407, 13), (2408, 13), (2409, 13), (2410, 13), (2411, 13), (2412, 13), (2413, 13), (2414, 13), (2415, 13), (2416, 13), (2417, 13), (2418, 13), (2419, 13), (2420, 13), (2421, 13), (2422, 13), (2423, 13), (2424, 13), (2425, 13), (2426, 13), (2427, 13), (2428, 13), (2429, 13), (2430, 13), (2431, 13), (2432, 13), (2433, 13), (2434, 13), (2435, 13), (2436, 13), (2437, 13), (2438, 13), (2439, 13), (2440, 13), (2441, 13), (2442, 13), (2443, 13), (2444, 13), (2445, 13), (2446, 13), (2447, 13), (2448, 13), (2449, 13), (2450, 13), (2451, 13), (2452, 13), (2453, 13), (2454, 13), (2455, 13), (2456, 13), (2457, 13), (2458, 13), (2459, 13), (2460, 13), (2461, 13), (2462, 13), (2463, 13), (2464, 13), (2465, 13), (2466, 13), (2467, 13), (2468, 13), (2469, 13), (2470, 13), (2471, 13), (2472, 13), (2473, 13), (2474, 13), (2475, 13), (2476, 13), (2477, 13), (2478, 13), (2479, 13), (2480, 13), (2481, 13), (2482, 13), (2483, 13), (2484, 13), (2485, 13), (2486, 13), (2487, 13), (2488, 13), (2489, 13), (2490, 13), (2491, 13), (2492, 13), (2493, 13), (2494, 13), (2495, 13), (2496, 13), (2497, 13), (2498, 13), (2499, 13), (2500, 13), (2501, 13), (2502, 13), (2503, 13), (2504, 13), (2505, 13), (2506, 13), (2507, 13), (2508, 13), (2509, 13), (2510, 13), (2511, 13), (2512, 13), (2513, 13), (2514, 13), (2515, 13), (2516, 13), (2517, 13), (2518, 13), (2519, 13), (2520, 13), (2521, 13), (2522, 13), (2523, 13), (2524, 13), (2525, 13), (2526, 13), (2527, 13), (2528, 13), (2529, 13), (2530, 13), (2531, 13), (2532, 13), (2533, 13), (2534, 13), (2535, 13), (2536, 13), (2537, 13), (2538, 13), (2539, 13), (2540, 13), (2541, 13), (2542, 13), (2543, 13), (2544, 13), (2545, 13), (2546, 13), (2547, 13), (2548, 13), (2549, 13), (2550, 13), (2551, 13), (2552, 13), (2553, 13), (2554, 13), (2555, 13), (2556, 13), (2557, 13), (2558, 13), (2559, 13), (2560, 13), (2561, 13), (2562, 13), (2563, 13), (2564, 13), (2565, 13), (2566, 13), (2567, 13), (2568, 13), (2569, 13), (2570, 13), (2571, 13), (2572, 13), (2573, 13), (2574, 13), (2575, 13), (2576, 13), (2577, 13), (2578, 13), (2579, 13), (2580, 13), (2581, 13), (2582, 13), (2583, 13), (2584, 13), (2585, 13), (2586, 13), (2587, 13), (2588, 13), (2589, 13), (2590, 13), (2591, 13), (2592, 13), (2593, 13), (2594, 13), (2595, 13), (2596, 13), (2597, 13), (2598, 13), (2599, 13), (2600, 13), (2601, 13), (2602, 13), (2603, 13), (2604, 13), (2605, 13), (2606, 13), (2607, 13), (2608, 13), (2609, 13), (2610, 13), (2611, 13), (2612, 13), (2613, 13), (2614, 13), (2615, 13), (2616, 13), (2617, 13), (2618, 13), (2619, 13), (2620, 13), (2621, 13), (2622, 13), (2623, 13), (2624, 13), (2625, 13), (2626, 13), (2627, 13), (2628, 13), (2629, 13), (2630, 13), (2631, 13), (2632, 13), (2633, 13), (2634, 13), (2635, 13), (2636, 13), (2637, 13), (2638, 13), (2639, 13), (2640, 13), (2641, 13), (2642, 13), (2643, 13), (2644, 13), (2645, 13), (2646, 13), (2647, 13), (2648, 13), (2649, 13), (2650, 13), (2651, 13), (2652, 13), (2653, 13), (2654, 13), (2655, 13), (2656, 13), (2657, 13), (2658, 13), (2659, 13), (2660, 13), (2661, 13), (2662, 13), (2663, 13), (2664, 13), (2665, 13), (2666, 13), (2667, 13), (2668, 13), (2669, 13), (2670, 13), (2671, 13), (2672, 13), (2673, 13), (2674, 13), (2675, 13), (2676, 13), (2677, 13), (2678, 13), (2679, 13), (2680, 13), (2681, 13), (2682, 13), (2683, 13), (2684, 13), (2685, 13), (2686, 13), (2687, 13), (2688, 13), (2689, 13), (2690, 13), (2691, 13), (2692, 13), (2693, 13), (2694, 13), (2695, 13), (2696, 13), (2697, 13), (2698, 13), (2699, 13), (2700, 13), (2701, 13), (2702, 13), (2703, 13), (2704, 13), (2705, 13), (2706, 13), (2707, 13), (2708, 13), (2709, 13), (2710, 13), (2711, 13), (2712, 13), (2713, 13), (2714, 13), (2715, 13), (2716, 13), (2717, 13), (2718, 13), (2719, 13), (2720, 13), (2721, 13), (2722, 13), (2723, 13), (2724, 13), (2725, 13), (2726, 13), (2727, 13), (2728, 13), (2729, 13), (2730, 13), (2731, 13), (2732, 13), (2733, 13), (2734, 13), (2735, 13), (2736, 13), (2737, 13), (2738, 13), (2739, 13), (2740, 13), (2741, 13), (2742, 13), (2743, 13), (2744, 13), (2745, 13), (2746, 13), (2747, 13), (2748, 13), (2749, 13), (2750, 13), (2751, 13), (2752, 13), (2753, 13), (2754, 13), (2755, 13), (2756, 13), (2757, 13), (2758, 13), (2759, 13), (2760, 13), (2761, 13), (2762, 13), (2763, 13), (2764, 13), (2765, 13), (2766, 13), (2767, 13), (2768, 13), (2769, 13), (2770, 13), (2771, 13), (2772, 13), (2773, 13), (2774, 13), (2775, 13), (2776, 13), (2777, 13), (2778, 13), (2779, 13), (2780, 13), (2781, 13), (2782, 13), (2783, 13), (2784, 13), (2785, 13), (2786, 13), (2787, 13), (2788, 13), (2789, 13), (2790, 13), (2791, 13), (2792, 13), (2793, 13), (2794, 13), (2795, 13), (2796, 13), (2797, 13), (2798, 13), (2799, 13), (2800, 13), (2801, 13), (2802, 13), (2803, 13), (2804, 13), (2805, 13), (2806, 13), (2807, 13), (2808, 13), (2809, 13), (2810, 13), (2811, 13), (2812, 13), (2813, 13), (2814, 13), (2815, 13), (2816, 13), (2817, 13), (2818, 13), (2819, 13), (2820, 13), (2821, 13), (2822, 13), (2823, 13), (2824, 13), (2825, 13), (2826, 13), (2827, 13), (2828, 13), (2829, 13), (2830, 13), (2831, 13), (2832, 13), (2833, 13), (2834, 13), (2835, 13), (2836, 13), (2837, 13), (2838, 13), (2839, 13), (2840, 13), (2841, 13), (2842, 13), (2843, 13), (2844, 13), (2845, 13), (2846, 13), (2847, 13), (2848, 13), (2849, 13), (2850, 13), (2851, 13), (2852, 13), (2853, 13), (2854, 13), (2855, 13), (2856, 13), (2857, 13), (2858, 13), (2859, 13), (2860, 13), (2861, 13), (2862, 13), (2863, 13), (2864, 13), (2865, 13), (2866, 13), (2867, 13), (2868, 13), (2869, 13), (2870, 13), (2871, 13), (2872, 13), (2873, 13), (2874, 13), (2875, 13), (2876, 13), (2877, 13), (2878, 13), (2879, 13), (2880, 13), (2881, 13), (2882, 13), (2883, 13), (2884, 13), (2885, 13), (2886, 13), (2887, 13), (2888, 13), (2889, 13), (2890, 13), (2891, 13), (2892, 13), (2893, 13), (2894, 13), (2895, 13), (2896, 13), (2897, 13), (2898, 13), (2899, 13), (2900, 13), (2901, 13), (2902, 13), (2903, 13), (2904, 13), (2905, 13), (2906, 13), (2907, 13), (2908, 13), (2909, 13), (2910, 13), (2911, 13), (2912, 13), (2913, 13), (2914, 13), (2915, 13), (2916, 13), (2917, 13), (2918, 13), (2919, 13), (2920, 13), (2921, 13), (2922, 13), (2923, 13), (2924, 13), (2925, 13), (2926, 13), (2927, 13), (2928, 13), (2929, 13), (2930, 13), (2931, 13), (2932, 13), (2933, 13), (2934, 13), (2935, 13), (2936, 13), (2937, 13), (2938, 13), (2939, 13), (2940, 13), (2941, 13), (2942, 13), (2943, 13), (2944, 13), (2945, 13), (2946, 13), (2947, 13), (2948, 13), (2949, 13), (2950, 13), (2951, 13), (2952, 13), (2953, 13), (2954, 13), (2955, 13), (2956, 13), (2957, 13), (2958, 13), (2959, 13), (2960, 13), (2961, 13), (2962, 13), (2963, 13), (2964, 13), (2965, 13), (2966, 13), (2967, 13), (2968, 13), (2969, 13), (2970, 13), (2971, 13), (2972, 13), (2973, 13), (2974, 13), (2975, 13), (2976, 13), (2977, 13), (2978, 13), (2979, 13), (2980, 13), (2981, 13), (2982, 13), (2983, 13), (2984, 13), (2985, 13), (2986, 13), (2987, 13), (2988, 13), (2989, 13), (2990, 13), (2991, 13), (2992, 13), (2993, 13), (2994, 13), (2995, 13), (2996, 13), (2997, 13), (2998, 13), (2999, 13), (3000, 13), (3001, 13), (3002, 13), (3003, 13), (3004, 13), (3005, 13), (3006, 13), (3007, 13), (3008, 13), (3009, 13), (3010, 13), (3011, 13), (3012, 13), (3013, 13), (3014, 13), (3015, 13), (3016, 13), (3017, 13), (3018, 13), (3019, 13), (3020, 13), (3021, 13), (3022, 13), (3023, 13), (3024, 13), (3025, 13), (3026, 13), (3027, 13), (3028, 13), (3029, 13), (3030, 13), (3031, 13), (3032, 13), (3033, 13), (3034, 13), (3035, 13), (3036, 13), (3037, 13), (3038, 13), (3039, 13), (3040, 13), (3041, 13), (3042, 13), (3043, 13), (3044, 13), (3045, 13), (3046, 13), (3047, 13), (3048, 13), (3049, 13), (3050, 13), (3051, 13), (3052, 13), (3053, 13), (3054, 13), (3055, 13), (3056, 13), (3057, 13), (3058, 13), (3059, 13), (3060, 13), (3061, 13), (3062, 13), (3063, 13), (3064, 13), (3065, 13), (3066, 13), (3067, 13), (3068, 13), (3069, 13), (3070, 13), (3071, 13), (3072, 13), (3073, 13), (3074, 13), (3075, 13), (3076, 13), (3077, 13), (3078, 13), (3079, 13), (3080, 13), (3081, 13), (3082, 13), (3083, 13), (3084, 13), (3085, 13), (3086, 13), (3087, 13), (3088, 13), (3089, 13), (3090, 13), (3091, 13), (3092, 13), (3093, 13), (3094, 13), (3095, 13), (3096, 13), (3097, 13), (3098, 13), (3099, 13), (3100, 13), (3101, 13), (3102, 13), (3103, 13), (3104, 13), (3105, 13), (3106, 13), (3107, 13), (3108, 13), (3109, 13), (3110, 13), (3111, 13), (3112, 13), (3113, 13), (3114, 13), (3115, 13), (3116, 13), (3117, 13), (3118, 13), (3119, 13), (3120, 13), (3121, 13), (3122, 13), (3123, 13), (3124, 13), (3125, 13), (3126, 13), (3127, 13), (3128, 13), (3129, 13), (3130, 13), (3131, 13), (3132, 13), (3133, 13), (3134, 13), (3135, 13), (3136, 13), (3137, 13), (3138, 13), (3139, 13), (3140, 13), (3141, 13), (3142, 13), (3143, 13), (3144, 13), (3145, 13), (3146, 13), (3147, 13), (3148, 13), (3149, 13), (3150, 13), (3151, 13), (3152, 13), (3153, 13), (3154, 13), (3155, 13), (3156, 13), (3157, 13), (3158, 13), (3159, 13), (3160, 13), (3161, 13), (3162, 13), (3163, 13), (3164, 13), (3165, 13), (3166, 13), (3167, 13), (3168, 13), (3169, 13), (3170, 13), (3171, 13), (3172, 13), (3173, 13), (3174, 13), (3175, 13), (3176, 13), (3177, 13), (3178, 13), (3179, 13), (3180, 13), (3181, 13), (3182, 13), (3183, 13), (3184, 13), (3185, 13), (3186, 13), (3187, 13), (3188, 13), (3189, 13), (3190, 13), (3191, 13), (3192, 13), (3193, 13), (3194, 13), (3195, 13), (3196, 13), (3197, 13), (3198, 13), (3199, 13), (3200, 13), (3201, 13), (3202, 13), (3203, 13), (3204, 13), (3205, 13), (3206, 13), (3207, 13), (3208, 13), (3209, 13), (3210, 13), (3211, 13), (3212, 13), (3213, 13), (3214, 13), (3215, 13), (3216, 13), (3217, 13), (3218, 13), (3219, 13), (3220, 13), (3221, 13), (3222, 13), (3223, 13), (3224, 13), (3225, 13), (3226, 13), (3227, 13), (3228, 13), (3229, 13), (3230, 13), (3231, 13), (3232, 13), (3233, 13), (3234, 13), (3235, 13), (3236, 13), (3237, 13), (3238, 13), (3239, 13), (3240, 13), (3241, 13), (3242, 13), (3243, 13), (3244, 13), (3245, 13), (3246, 13), (3247, 13), (3248, 13), (3249, 13), (3250, 13), (3251, 13), (3252, 13), (3253, 13), (3254, 13), (3255, 13), (3256, 13), (3257, 13), (3258, 13), (3259, 13), (3260, 13), (3261, 13), (3262, 13), (3263, 13), (3264, 13), (3265, 13), (3266, 13), (3267, 13), (3268, 13), (3269, 13), (3270, 13), (3271, 13), (3272, 13), (3273, 13), (3274, 13), (3275, 13), (3276, 13), (3277, 13), (3278, 13), (3279, 13), (3280, 13), (3281, 13), (3282, 13), (3283, 13), (3284, 13), (3285, 13), (3286, 13), (3287, 13), (3288, 13), (3289, 13), (3290, 13), (3291, 13), (3292, 13), (3293, 13), (3294, 13), (3295, 13), (3296, 13), (3297, 13), (3298, 13), (3299, 13), (3300, 13), (3301, 13), (3302, 13), (3303, 13), (3304, 13), (3305, 13), (3306, 13), (3307, 13), (3308, 13), (3309, 13), (3310, 13), (3311, 13), (3312, 13), (3313, 13), (3314, 13), (3315, 13), (3316, 13), (3317, 13), (3318, 13), (3319, 13), (3320, 13), (3321, 13), (3322, 13), (3323, 13), (3324, 13), (3325, 13), (3326, 13), (3327, 13), (3328, 13), (3329, 13), (3330, 13), (3331, 13), (3332, 13), (3333, 13), (3334, 13), (3335, 13), (3336, 13), (3337, 13), (3338, 13), (3339, 13), (3340, 13), (3341, 13), (3342, 13), (3343, 13), (3344, 13), (3345, 13), (3346, 13), (3347, 13), (3348, 13), (3349, 13), (3350, 13), (3351, 13), (3352, 13), (3353, 13), (3354, 13), (3355, 13), (3356, 13), (3357, 13), (3358, 13), (3359, 13), (3360, 13), (3361, 13), (3362, 13), (3363, 13), (3364, 13), (3365, 13), (3366, 13), (3367, 13), (3368, 13), (3369, 13), (3370, 13), (3371, 13), (3372, 13), (3373, 13), (3374, 13), (3375, 13), (3376, 13), (3377, 13), (3378, 13), (3379, 13), (3380, 13), (3381, 13), (3382, 13), (3383, 13), (3384, 13), (3385, 13), (3386, 13), (3387, 13), (3388, 13), (3389, 13), (3390, 13), (3391, 13), (3392, 13), (3393, 13), (3394, 13), (3395, 13), (3396, 13), (3397, 13), (3398, 13), (3399, 13), (3400, 13), (3401, 13), (3402, 13), (3403, 13), (3404, 13), (3405, 13), (3406, 13), (3407, 13), (3408, 13), (3409, 13), (3410, 13), (3411, 13), (3412, 13), (3413, 13), (3414, 13), (3415, 13), (3416, 13), (3417, 13), (3418, 13), (3419, 13), (3420, 13), (3421, 13), (3422, 13), (3423, 13), (3424, 13), (3425, 13), (3426, 13), (3427, 13), (3428, 13), (3429, 13), (3430, 13), (3431, 13), (3432, 13), (3433, 13), (3434, 13), (3435, 13), (3436, 13), (3437, 13), (3438, 13), (3439, 13), (3440, 13), (3441, 13), (3442, 13), (3443, 13), (3444, 13), (3445, 13), (3446, 13), (3447, 13), (3448, 13), (3449, 13), (3450, 13), (3451, 13), (3452, 13), (3453, 13), (3454, 13), (3455, 13), (3456, 13), (3457, 13), (3458, 13), (3459, 13), (3460, 13), (3461, 13), (3462, 13), (3463, 13), (3464, 13), (3465, 13), (3466, 13), (3467, 13), (3468, 13), (3469, 13), (3470, 13), (3471, 13), (3472, 13), (3473, 13), (3474, 13), (3475, 13), (3476, 13), (3477, 13), (3478, 13), (3479, 13), (3480, 13), (3481, 13), (3482, 13), (3483, 13), (3484, 13), (3485, 13), (3486, 13), (3487, 13), (3488, 13), (3489, 13), (3490, 13), (3491, 13), (3492, 13), (3493, 13), (3494, 13), (3495, 13), (3496, 13), (3497, 13), (3498, 13), (3499, 13), (3500, 13), (3501, 13), (3502, 13), (3503, 13), (3504, 13), (3505, 13), (3506, 13), (3507, 13), (3508, 13), (3509, 13), (3510, 13), (3511, 13), (3512, 13), (3513, 13), (3514, 13), (3515, 13), (3516, 13), (3517, 13), (3518, 13), (3519, 13), (3520, 13), (3521, 13), (3522, 13), (3523, 13), (3524, 13), (3525, 13), (3526, 13), (3527, 13), (3528, 13), (3529, 13), (3530, 13), (3531, 13), (3532, 13), (3533, 13), (3534, 13), (3535, 13), (3536, 13), (3537, 13), (3538, 13), (3539, 13), (3540, 13), (3541, 13), (3542, 13), (3543, 13), (3544, 13), (3545, 13), (3546, 13), (3547, 13), (3548, 13), (3549, 13), (3550, 13), (3551, 13), (3552, 13), (3553, 13), (3554, 13), (3555, 13), (3556, 13), (3557, 13), (3558, 13), (3559, 13), (3560, 13), (3561, 13), (3562, 13), (3563, 13), (3564, 13), (3565, 13), (3566, 13), (3567, 13), (3568, 13), (3569, 13), (3570, 13), (3571, 13), (3572, 13), (3573, 13), (3574, 13), (3575, 13), (3576, 13), (3577, 13), (3578, 13), (3579, 13), (3580, 13), (3581, 13), (3582, 13), (3583, 13), (3584, 13), (3585, 13), (3586, 13), (3587, 13), (3588, 13), (3589, 13), (3590, 13), (3591, 13), (3592, 13), (3593, 13), (3594, 13), (3595, 13), (3596, 13), (3597, 13), (3598, 13), (3599, 13), (3600, 13), (3601, 13), (3602, 13), (3603, 13), (3604, 13), (3605, 13), (3606, 13), (3607, 13), (3608, 13), (3609, 13), (3610, 13), (3611, 13), (3612, 13), (3613, 13), (3614, 13), (3615, 13), (3616, 13), (3617, 13), (3618, 13), (3619, 13), (3620, 13), (3621, 13), (3622, 13), (3623, 13), (3624, 13), (3625, 13), (3626, 13), (3627, 13), (3628, 13), (3629, 13), (3630, 13), (3631, 13), (3632, 13), (3633, 13), (3634, 13), (3635, 13), (3636, 13), (3637, 13), (3638, 13), (3639, 13), (3640, 13), (3641, 13), (3642, 13), (3643, 13), (3644, 13), (3645, 13), (3646, 13), (3647, 13), (3648, 13), (3649, 13), (3650, 13), (3651, 13), (3652, 13), (3653, 13), (3654, 13), (3655, 13), (3656, 13), (3657, 13), (3658, 13), (3659, 13), (3660, 13), (3661, 13), (3662, 13), (3663, 13), (3664, 13), (3665, 13), (3666, 13), (3667, 13), (3668, 13), (3669, 13), (3670, 13), (3671, 13), (3672, 13), (3673, 13), (3674, 13), (3675, 13), (3676, 13), (3677, 13), (3678, 13), (3679, 13), (3680, 13), (3681, 13), (3682, 13), (3683, 13), (3684, 13), (3685, 13), (3686, 13), (3687, 13), (3688, 13), (3689, 13), (3690, 13), (3691, 13), (3692, 13), (3693, 13), (3694, 13), (3695, 13), (3696, 13), (3697, 13), (3698, 13), (3699, 13), (3700, 13), (3701, 13), (3702, 13), (3703, 13), (3704, 13), (3705, 13), (3706, 13), (3707, 13), (3708, 13), (3709, 13), (3710, 13), (3711, 13), (3712, 13), (3713, 13), (3714, 13), (3715, 13), (3716, 13), (3717, 13), (3718, 13), (3719, 13), (3720, 13), (3721, 13), (3722, 13), (3723, 13), (3724, 13), (3725, 13), (3726, 13), (3727, 13), (3728, 13), (3729, 13), (3730, 13), (3731, 13), (3732, 13), (3733, 13), (3734, 13), (3735, 13), (3736, 13), (3737, 13), (3738, 13), (3739, 13), (3740, 13), (3741, 13), (3742, 13), (3743, 13), (3744, 13), (3745, 13), (3746, 13), (3747, 13), (3748, 13), (3749, 13), (3750, 13), (3751, 13), (3752, 13), (3753, 13), (3754, 13), (3755, 13), (3756, 13), (3757, 13), (3758, 13), (3759, 13), (3760, 13), (3761, 13), (3762, 13), (3763, 13), (3764, 13), (3765, 13), (3766, 13), (3767, 13), (3768, 13), (3769, 13), (3770, 13), (3771, 13), (3772, 13), (3773, 13), (3774, 13), (3775, 13), (3776, 13), (3777, 13), (3778, 13), (3779, 13), (3780, 13), (3781, 13), (3782, 13), (3783, 13), (3784, 13), (3785, 13), (3786, 13), (3787, 13), (3788, 13), (3789, 13), (3790, 13), (3791, 13), (3792, 13), (3793, 13), (3794, 13), (3795, 13), (3796, 13), (3797, 13), (3798, 13), (3799, 13), (3800, 13), (3801, 13), (3802, 13), (3803, 13), (3804, 13), (3805, 13), (3806, 13), (3807, 13), (3808, 13), (3809, 13), (3810, 13), (3811, 13), (3812, 13), (3813, 13), (3814, 13), (3815, 13), (3816, 13), (3817, 13), (3818, 13), (3819, 13), (3820, 13), (3821, 13), (3822, 13), (3823, 13), (3824, 13), (3825, 13), (3826, 13), (3827, 13), (3828, 13), (3829, 13), (3830, 13), (3831, 13), (3832, 13), (3833, 13), (3834, 13), (3835, 13), (3836, 13), (3837, 13), (3838, 13), (3839, 13), (3840, 13), (3841, 13), (3842, 13), (3843, 13), (3844, 13), (3845, 13), (3846, 13), (3847, 13), (3848, 13), (3849, 13), (3850, 13), (3851, 13), (3852, 13), (3853, 13), (3854, 13), (3855, 13), (3856, 13), (3857, 13), (3858, 13), (3859, 13), (3860, 13), (3861, 13), (3862, 13), (3863, 13), (3864, 13), (3865, 13), (3866, 13), (3867, 13), (3868, 13), (3869, 13), (3870, 13), (3871, 13), (3872, 13), (3873, 13), (3874, 13), (3875, 13), (3876, 13), (3877, 13), (3878, 13), (3879, 13), (3880, 13), (3881, 13), (3882, 13), (3883, 13), (3884, 13), (3885, 13), (3886, 13), (3887, 13), (3888, 13), (3889, 13), (3890, 13), (3891, 13), (3892, 13), (3893, 13), (3894, 13), (3895, 13), (3896, 13), (3897, 13), (3898, 13), (3899, 13), (3900, 13), (3901, 13), (3902, 13), (3903, 13), (3904, 13), (3905, 13), (3906, 13), (3907, 13), (3908, 13), (3909, 13), (3910, 13), (3911, 13), (3912, 13), (3913, 13), (3914, 13), (3915, 13), (3916, 13), (3917, 13), (3918, 13), (3919, 13), (3920, 13), (3921, 13), (3922, 13), (3923, 13), (3924, 13), (3925, 13), (3926, 13), (3927, 13), (3928, 13), (3929, 13), (3930, 13), (3931, 13), (3932, 13), (3933, 13), (3934, 13), (3935, 13), (3936, 13), (3937, 13), (3938, 13), (3939, 13), (3940, 13), (3941, 13), (3942, 13), (3943, 13), (3944, 13), (3945, 13), (3946, 13), (3947, 13), (3948, 13), (3949, 13), (3950, 13), (3951, 13), (3952, 13), (3953, 13), (3954, 13), (3955, 13), (3956, 13), (3957, 13), (3958, 13), (3959, 13), (3960, 13), (3961, 13), (3962, 13), (3963, 13), (3964, 13), (3965, 13), (3966, 13), (3967, 13), (3968, 13), (3969, 13), (3970, 13), (3971, 13), (3972, 13), (3973, 13), (3974, 13), (3975, 13), (3976, 13), (3977, 13), (3978, 13), (3979, 13), (3980, 13), (3981, 13), (3982, 13), (3983, 13), (3984, 13), (3985, 13), (3986, 13), (3987, 13), (3988, 13), (3989, 13), (3990, 13), (3991, 13), (3992, 13), (3993, 13), (3994, 13), (3995, 13), (3996, 13), (3997, 13), (3998, 13), (3999, 13), (4000, 13), (4001, 13), (4002, 13), (4003, 13), (4004, 13), (4005, 13), (4006, 13), (4007, 13), (4008, 13), (4009, 13), (4010, 13), (4011, 13), (4012, 13), (4013, 13), (4014, 13), (4015, 13), (4016, 13), (4017, 13), (4018, 13), (4019, 13), (4020, 13), (4021, 13), (4022, 13), (4023, 13), (4024, 13), (4025, 13), (4026, 13), (4027, 13), (4028, 13), (4029, 13), (4030, 13), (4031, 13), (4032, 13), (4033, 13), (4034, 13), (4035, 13), (4036, 13), (4037, 13), (4038, 13), (4039, 13), (4040, 13), (4041, 13), (4042, 13), (4043, 13), (4044, 13), (4045, 13), (4046, 13), (4047, 13), (4048, 13), (4049, 13), (4050, 13), (4051, 13), (4052, 13), (4053, 13), (4054, 13), (4055, 13), (4056, 13), (4057, 13), (4058, 13), (4059, 13), (4060, 13), (4061, 13), (4062, 13), (4063, 13), (4064, 13), (4065, 13), (4066, 13), (4067, 13), (4068, 13), (4069, 13), (4070, 13), (4071, 13), (4072, 13), (4073, 13), (4074, 13), (4075, 13), (4076, 13), (4077, 13), (4078, 13), (4079, 13), (4080, 13), (4081, 13), (4082, 13), (4083, 13), (4084, 13), (4085, 13), (4086, 13), (4087, 13), (4088, 13), (4089, 13), (4090, 13), (4091, 13), (4092, 13), (4093, 13), (4094, 13), (4095, 13), (4096, 13), (4097, 13), (4098, 13), (4099, 13), (4100, 13), (4101, 13), (4102, 13), (4103, 13), (4104, 13), (4105, 13), (4106, 13), (4107, 13), (4108, 13), (4109, 13), (4110, 13), (4111, 13), (4112, 13), (4113, 13), (4114, 13), (4115, 13), (4116, 13), (4117, 13), (4118, 13), (4119, 13), (4120, 13), (4121, 13), (4122, 13), (4123, 13), (4124, 13), (4125, 13), (4126, 13), (4127, 13), (4128, 13), (4129, 13), (4130, 13), (4131, 13), (4132, 13), (4133, 13), (4134, 13), (4135, 13), (4136, 13), (4137, 13), (4138, 13), (4139, 13), (4140, 13), (4141, 13), (4142, 13), (4143, 13), (4144, 13), (4145, 13), (4146, 13), (4147, 13), (4148, 13), (4149, 13), (4150, 13), (4151, 13), (4152, 13), (4153, 13), (4154, 13), (4155, 13), (4156, 13), (4157, 13), (4158, 13), (4159, 13), (4160, 13), (4161, 13), (4162, 13), (4163, 13), (4164, 13), (4165, 13), (4166, 13), (4167, 13), (4168, 13), (4169, 13), (4170, 13), (4171, 13), (4172, 13), (4173, 13), (4174, 13), (4175, 13), (4176, 13), (4177, 13), (4178, 13), (4179, 13), (4180, 13), (4181, 13), (4182, 13), (4183, 13), (4184, 13), (4185, 13), (4186, 13), (4187, 13), (4188, 13), (4189, 13), (4190, 13), (4191, 13), (4192, 13), (4193, 13), (4194, 13), (4195, 13), (4196, 13), (4197, 13), (4198, 13), (4199, 13), (4200, 13), (4201, 13), (4202, 13), (4203, 13), (4204, 13), (4205, 13), (4206, 13), (4207, 13), (4208, 13), (4209, 13), (4210, 13), (4211, 13), (4212, 13), (4213, 13), (4214, 13), (4215, 13), (4216, 13), (4217, 13), (4218, 13), (4219, 13), (4220, 13), (4221, 13), (4222, 13), (4223, 13), (4224, 13), (4225, 13), (4226, 13), (4227, 13), (4228, 13), (4229, 13), (4230, 13), (4231, 13), (4232, 13), (4233, 13), (4234, 13), (4235, 13), (4236, 13), (4237, 13), (4238, 13), (4239, 13), (4240, 13), (4241, 13), (4242, 13), (4243, 13), (4244, 13), (4245, 13), (4246, 13), (4247, 13), (4248, 13), (4249, 13), (4250, 13), (4251, 13), (4252, 13), (4253, 13), (4254, 13), (4255, 13), (4256, 13), (4257, 13), (4258, 13), (4259, 13), (4260, 13), (4261, 13), (4262, 13), (4263, 13), (4264, 13), (4265, 13), (4266, 13), (4267, 13), (4268, 13), (4269, 13), (4270, 13), (4271, 13), (4272, 13), (4273, 13), (4274, 13), (4275, 13), (4276, 13), (4277, 13), (4278, 13), (4279, 13), (4280, 13), (4281, 13), (4282, 13), (4283, 13), (4284, 13), (4285, 13), (4286, 13), (4287, 13), (4288, 13), (4289, 13), (4290, 13), (4291, 13), (4292, 13), (4293, 13), (4294, 13), (4295, 13), (4296, 13), (4297, 13), (4298, 13), (4299, 13), (4300, 13), (4301, 13), (4302, 13), (4303, 13), (4304, 13), (4305, 13), (4306, 13), (4307, 13), (4308, 13), (4309, 13), (4310, 13), (4311, 13), (4312, 13), (4313, 13), (4314, 13), (4315, 13), (4316, 13), (4317, 13), (4318, 13), (4319, 13), (4320, 13), (4321, 13), (4322, 13), (4323, 13), (4324, 13), (4325, 13), (4326, 13), (4327, 13), (4328, 13), (4329, 13), (4330, 13), (4331, 13), (4332, 13), (4333, 13), (4334, 13), (4335, 13), (4336, 13), (4337, 13), (4338, 13), (4339, 13), (4340, 13), (4341, 13), (4342, 13), (4343, 13), (4344, 13), (4345, 13), (4346, 13), (4347, 13), (4348, 13), (4349, 13), (4350, 13), (4351, 13), (4352, 13), (4353, 13), (4354, 13), (4355, 13), (4356, 13), (4357, 13), (4358, 13), (4359, 13), (4360, 13), (4361, 13), (4362, 13), (4363, 13), (4364, 13), (4365, 13), (4366, 13), (4367, 13), (4368, 13), (4369, 13), (4370, 13), (4371, 13), (4372, 13), (4373, 13), (4374, 13), (4375, 13), (4376, 13), (4377, 13), (4378, 13), (4379, 13), (4380, 13), (4381, 13), (4382, 13), (4383, 13), (4384, 13), (4385, 13), (4386, 13), (4387, 13), (4388, 13), (4389, 13), (4390, 13), (4391, 13), (4392, 13), (4393, 13), (4394, 13), (4395, 13), (4396, 13), (4397, 13), (4398, 13), (4399, 13), (4400, 13), (4401, 13), (4402, 13), (4403, 13), (4404, 13), (4405, 13), (4406, 13), (4407, 13), (4408, 13), (4409, 13), (4410, 13), (4411, 13), (4412, 13), (4413, 13), (4414, 13), (4415, 13), (4416, 13), (4417, 13), (4418, 13), (4419, 13), (4420, 13), (4421, 13), (4422, 13), (4423, 13), (4424, 13), (4425, 13), (4426, 13), (4427, 13), (4428, 13), (4429, 13), (4430, 13), (4431, 13), (4432, 13), (4433, 13), (4434, 13), (4435, 13), (4436, 13), (4437, 13), (4438, 13), (4439, 13), (4440, 13), (4441, 13), (4442, 13), (4443, 13), (4444, 13), (4445, 13), (4446, 13), (4447, 13), (4448, 13), (4449, 13), (4450, 13), (4451, 13), (4452, 13), (4453, 13), (4454, 13), (4455, 13), (4456, 13), (4457, 13), (4458, 13), (4459, 13), (4460, 13), (4461, 13), (4462, 13), (4463, 13), (4464, 13), (4465, 13), (4466, 13), (4467, 13), (4468, 13), (4469, 13), (4470, 13), (4471, 13), (4472, 13), (4473, 13), (4474, 13), (4475, 13), (4476, 13), (4477, 13), (4478, 13), (4479, 13), (4480, 13), (4481, 13), (4482, 13), (4483, 13), (4484, 13), (4485, 13), (4486, 13), (4487, 13), (4488, 13), (4489, 13), (4490, 13), (4491, 13), (4492, 13), (4493, 13), (4494, 13), (4495, 13), (4496, 13), (4497, 13), (4498, 13), (4499, 13), (4500, 13), (4501, 13), (4502, 13), (4503, 13), (4504, 13), (4505, 13), (4506, 13), (4507, 13), (4508, 13), (4509, 13), (4510, 13), (4511, 13), (4512, 13), (4513, 13), (4514, 13), (4515, 13), (4516, 13), (4517, 13), (4518, 13), (4519, 13), (4520, 13), (4521, 13), (4522, 13), (4523, 13), (4524, 13), (4525, 13), (4526, 13), (4527, 13), (4528, 13), (4529, 13), (4530, 13), (4531, 13), (4532, 13), (4533, 13), (4534, 13), (4535, 13), (4536, 13), (4537, 13), (4538, 13), (4539, 13), (4540, 13), (4541, 13), (4542, 13), (4543, 13), (4544, 13), (4545, 13), (4546, 13), (4547, 13), (4548, 13), (4549, 13), (4550, 13), (4551, 13), (4552, 13), (4553, 13), (4554, 13), (4555, 13), (4556, 13), (4557, 13), (4558, 13), (4559, 13), (4560, 13), (4561, 13), (4562, 13), (4563, 13), (4564, 13), (4565, 13), (4566, 13), (4567, 13), (4568, 13), (4569, 13), (4570, 13), (4571, 13), (4572, 13), (4573, 13), (4574, 13), (4575, 13), (4576, 13), (4577, 13), (4578, 13), (4579, 13), (4580, 13), (4581, 13), (4582, 13), (4583, 13), (4584, 13), (4585, 13), (4586, 13), (4587, 13), (4588, 13), (4589, 13), (4590, 13), (4591, 13), (4592, 13), (4593, 13), (4594, 13), (4595, 13), (4596, 13), (4597, 13), (4598, 13), (4599, 13), (4600, 13), (4601, 13), (4602, 13), (4603, 13), (4604, 13), (4605, 13), (4606, 13), (4607, 13), (4608, 13), (4609, 13), (4610, 13), (4611, 13), (4612, 13), (4613, 13), (4614, 13), (4615, 13), (4616, 13), (4617, 13), (4618, 13), (4619, 13), (4620, 13), (4621, 13), (4622, 13), (4623, 13), (4624, 13), (4625, 13), (4626, 13), (4627, 13), (4628, 13), (4629, 13), (4630, 13), (4631, 13), (4632, 13), (4633, 13), (4634, 13), (4635, 13), (4636, 13), (4637, 13), (4638, 13), (4639, 13), (4640, 13), (4641, 13), (4642, 13), (4643, 13), (4644, 13), (4645, 13), (4646, 13), (4647, 13), (4648, 13), (4649, 13), (4650, 13), (4651, 13), (4652, 13), (4653, 13), (4654, 13), (4655, 13), (4656, 13), (4657, 13), (4658, 13), (4659, 13), (4660, 13), (4661, 13), (4662, 13), (4663, 13), (4664, 13), (4665, 13), (4666, 13), (4667, 13), (4668, 13), (4669, 13), (4670, 13), (4671, 13), (4672, 13), (4673, 13), (4674, 13), (4675, 13), (4676, 13), (4677, 13), (4678, 13), (4679, 13), (4680, 13), (4681, 13), (4682, 13), (4683, 13), (4684, 13), (4685, 13), (4686, 13), (4687, 13), (4688, 13), (4689, 13), (4690, 13), (4691, 13), (4692, 13), (4693, 13), (4694, 13), (4695, 13), (4696, 13), (4697, 13), (4698, 13), (4699, 13), (4700, 13), (4701, 13), (4702, 13), (4703, 13), (4704, 13), (4705, 13), (4706, 13), (4707, 13), (4708, 13), (4709, 13), (4710, 13), (4711, 13), (4712, 13), (4713, 13), (4714, 13), (4715, 13), (4716, 13), (4717, 13), (4718, 13), (4719, 13), (4720, 13), (4721, 13), (4722, 13), (4723, 13), (4724, 13), (4725, 13), (4726, 13), (4727, 13), (4728, 13), (4729, 13), (4730, 13), (4731, 13), (4732, 13), (4733, 13), (4734, 13), (4735, 13), (4736, 13), (4737, 13), (4738, 13), (4739, 13), (4740, 13), (4741, 13), (4742, 13), (4743, 13), (4744, 13), (4745, 13), (4746, 13), (4747, 13), (4748, 13), (4749, 13), (4750, 13), (4751, 13), (4752, 13), (4753, 13), (4754, 13), (4755, 13), (4756, 13), (4757, 13), (4758, 13), (4759, 13), (4760, 13), (4761, 13), (4762, 13), (4763, 13), (4764, 13), (4765, 13), (4766, 13), (4767, 13), (4768, 13), (4769, 13), (4770, 13), (4771, 13), (4772, 13), (4773, 13), (4774, 13), (4775, 13), (4776, 13), (4777, 13), (4778, 13), (4779, 13), (4780, 13), (4781, 13), (4782, 13), (4783, 13), (4784, 13), (4785, 13), (4786, 13), (4787, 13), (4788, 13), (4789, 13), (4790, 13), (4791, 13), (4792, 13), (4793, 13), (4794, 13), (4795, 13), (4796, 13), (4797, 13), (4798, 13), (4799, 13), (4800, 13), (4801, 13), (4802, 13), (4803, 13), (4804, 13), (4805, 13), (4806, 13), (4807, 13), (4808, 13), (4809, 13), (4810, 13), (4811, 13), (4812, 13), (4813, 13), (4814, 13), (4815, 13), (4816, 13), (4817, 13), (4818, 13), (4819, 13), (4820, 13), (4821, 13), (4822, 13), (4823, 13), (4824, 13), (4825, 13), (4826, 13), (4827, 13), (4828, 13), (4829, 13), (4830, 13), (4831, 13), (4832, 13), (4833, 13), (4834, 13), (4835, 13), (4836, 13), (4837, 13), (4838, 13), (4839, 13), (4840, 13), (4841, 13), (4842, 13), (4843, 13), (4844, 13), (4845, 13), (4846, 13), (4847, 13), (4848, 13), (4849, 13), (4850, 13), (4851, 13), (4852, 13), (4853, 13), (4854, 13), (4855, 13), (4856, 13), (4857, 13), (4858, 13), (4859, 13), (4860, 13), (4861, 13), (4862, 13), (4863, 13), (4864, 13), (4865, 13), (4866, 13), (4867, 13), (4868, 13), (4869, 13), (4870, 13), (4871, 13), (4872, 13), (4873, 13), (4874, 13), (4875, 13), (4876, 13), (4877, 13), (4878, 13), (4879, 13), (4880, 13), (4881, 13), (4882, 13), (4883, 13), (4884, 13), (4885, 13), (4886, 13), (4887, 13), (4888, 13), (4889, 13), (4890, 13), (4891, 13), (4892, 13), (4893, 13), (4894, 13), (4895, 13), (4896, 13), (4897, 13), (4898, 13), (4899, 13), (4900, 13), (4901, 13), (4902, 13), (4903, 13), (4904, 13), (4905, 13), (4906, 13), (4907, 13), (4908, 13), (4909, 13), (4910, 13), (4911, 13), (4912, 13), (4913, 13), (4914, 13), (4915, 13), (4916, 13), (4917, 13), (4918, 13), (4919, 13), (4920, 13), (4921, 13), (4922, 13), (4923, 13), (4924, 13), (4925, 13), (4926, 13), (4927, 13), (4928, 13), (4929, 13), (4930, 13), (4931, 13), (4932, 13), (4933, 13), (4934, 13), (4935, 13), (4936, 13), (4937, 13), (4938, 13), (4939, 13), (4940, 13), (4941, 13), (4942, 13), (4943, 13), (4944, 13), (4945, 13), (4946, 13), (4947, 13), (4948, 13), (4949, 13), (4950, 13), (4951, 13), (4952, 13), (4953, 13), (4954, 13), (4955, 13), (4956, 13), (4957, 13), (4958, 13), (4959, 13), (4960, 13), (4961, 13), (4962, 13), (4963, 13), (4964, 13), (4965, 13), (4966, 13), (4967, 13), (4968, 13), (4969, 13), (4970, 13), (4971, 13), (4972, 13), (4973, 13), (4974, 13), (4975, 13), (4976, 13), (4977, 13), (4978, 13), (4979, 13), (4980, 13), (4981, 13), (4982, 13), (4983, 13), (4984, 13), (4985, 13), (4986, 13), (4987, 13), (4988, 13), (4989, 13), (4990, 13), (4991, 13), (4992, 13), (4993, 13), (4994, 13), (4995, 13), (4996, 13), (4997, 13), (4998, 13), (4999, 13), (5000, 13), (5001, 13), (5002, 13), (5003, 13), (5004, 13), (5005, 13), (5006, 13), (5007, 13), (5008, 13), (5009, 13), (5010, 13), (5011, 13), (5012, 13), (5013, 13), (5014, 13), (5015, 13), (5016, 13), (5017, 13), (5018, 13), (5019, 13), (5020, 13), (5021, 13), (5022, 13), (5023, 13), (5024, 13), (5025, 13), (5026, 13), (5027, 13), (5028, 13), (5029, 13), (5030, 13), (5031, 13), (5032, 13), (5033, 13), (5034, 13), (5035, 13), (5036, 13), (5037, 13), (5038, 13), (5039, 13), (5040, 13), (5041, 13), (5042, 13), (5043, 13), (5044, 13), (5045, 13), (5046, 13), (5047, 13), (5048, 13), (5049, 13), (5050, 13), (5051, 13), (5052, 13), (5053, 13), (5054, 13), (5055, 13), (5056, 13), (5057, 13), (5058, 13), (5059, 13), (5060, 13), (5061, 13), (5062, 13), (5063, 13), (5064, 13), (5065, 13), (5066, 13), (5067, 13), (5068, 13), (5069, 13), (5070, 13), (5071, 13), (5072, 13), (5073, 13), (5074, 13), (5075, 13), (5076, 13), (5077, 13), (5078, 13), (5079, 13), (5080, 13), (5081, 13), (5082, 13), (5083, 13), (5084, 13), (5085, 13), (5086, 13), (5087, 13), (5088, 13), (5089, 13), (5090, 13), (5091, 13), (5092, 13), (5093, 13), (5094, 13), (5095, 13), (5096, 13), (5097, 13), (5098, 13), (5099, 13), (5100, 13), (5101, 13), (5102, 13), (5103, 13), (5104, 13), (5105, 13), (5106, 13), (5107, 13), (5108, 13), (5109, 13), (5110, 13), (5111, 13), (5112, 13), (5113, 13), (5114, 13), (5115, 13), (5116, 13), (5117, 13), (5118, 13), (5119, 13), (5120, 13), (5121, 13), (5122, 13), (5123, 13), (5124, 13), (5125, 13), (5126, 13), (5127, 13), (5128, 13), (5129, 13), (5130, 13), (5131, 13), (5132, 13), (5133, 13), (5134, 13), (5135, 13), (5136, 13), (5137, 13), (5138, 13), (5139, 13), (5140, 13), (5141, 13), (5142, 13), (5143, 13), (5144, 13), (5145, 13), (5146, 13), (5147, 13), (5148, 13), (5149, 13), (5150, 13), (5151, 13), (5152, 13), (5153, 13), (5154, 13), (5155, 13), (5156, 13), (5157, 13), (5158, 13), (5159, 13), (5160, 13), (5161, 13), (5162, 13), (5163, 13), (5164, 13), (5165, 13), (5166, 13), (5167, 13), (5168, 13), (5169, 13), (5170, 13), (5171, 13), (5172, 13), (5173, 13), (5174, 13), (5175, 13), (5176, 13), (5177, 13), (5178, 13), (5179, 13), (5180, 13), (5181, 13), (5182, 13), (5183, 13), (5184, 13), (5185, 13), (5186, 13), (5187, 13), (5188, 13), (5189, 13), (5190, 13), (5191, 13), (5192, 13), (5193, 13), (5194, 13), (5195, 13), (5196, 13), (5197, 13), (5198, 13), (5199, 13), (5200, 13), (5201, 13), (5202, 13), (5203, 13), (5204, 13), (5205, 13), (5206, 13), (5207, 13), (5208, 13), (5209, 13), (5210, 13), (5211, 13), (5212, 13), (5213, 13), (5214, 13), (5215, 13), (5216, 13), (5217, 13), (5218, 13), (5219, 13), (5220, 13), (5221, 13), (5222, 13), (5223, 13), (5224, 13), (5225, 13), (5226, 13), (5227, 13), (5228, 13), (5229, 13), (5230, 13), (5231, 13), (5232, 13), (5233, 13), (5234, 13), (5235, 13), (5236, 13), (5237, 13), (5238, 13), (5239, 13), (5240, 13), (5241, 13), (5242, 13), (5243, 13), (5244, 13), (5245, 13), (5246, 13), (5247, 13), (5248, 13), (5249, 13), (5250, 13), (5251, 13), (5252, 13), (5253, 13), (5254, 13), (5255, 13), (5256, 13), (5257, 13), (5258, 13), (5259, 13), (5260, 13), (5261, 13), (5262, 13), (5263, 13), (5264, 13), (5265, 13), (5266, 13), (5267, 13), (5268, 13), (5269, 13), (5270, 13), (5271, 13), (5272, 13), (5273, 13), (5274, 13), (5275, 13), (5276, 13), (5277, 13), (5278, 13), (5279, 13), (5280, 13), (5281, 13), (5282, 13), (5283, 13), (5284, 13), (5285, 13), (5286, 13), (5287, 13), (5288, 13), (5289, 13), (5290, 13), (5291, 13), (5292, 13), (5293, 13), (5294, 13), (5295, 13), (5296, 13), (5297, 13), (5298, 13), (5299, 13), (5300, 13), (5301, 13), (5302, 13), (5303, 13), (5304, 13), (5305, 13), (5306, 13), (5307, 13), (5308, 13), (5309, 13), (5310, 13), (5311, 13), (5312, 13), (5313, 13), (5314, 13), (5315, 13), (5316, 13), (5317, 13), (5318, 13), (5319, 13), (5320, 13), (5321, 13), (5322, 13), (5323, 13), (5324, 13), (5325, 13), (5326, 13), (5327, 13), (5328, 13), (5329, 13), (5330, 13), (5331, 13), (5332, 13), (5333, 13), (5334, 13), (5335, 13), (5336, 13), (5337, 13), (5338, 13), (5339, 13), (5340, 13), (5341, 13), (5342, 13), (5343, 13), (5344, 13), (5345, 13), (5346, 13), (5347, 13), (5348, 13), (5349, 13), (5350, 13), (5351, 13), (5352, 13), (5353, 13), (5354, 13), (5355, 13), (5356, 13), (5357, 13), (5358, 13), (5359, 13), (5360, 13), (5361, 13), (5362, 13), (5363, 13), (5364, 13), (5365, 13), (5366, 13), (5367, 13), (5368, 13), (5369, 13), (5370, 13), (5371, 13), (5372, 13), (5373, 13), (5374, 13), (5375, 13), (5376, 13), (5377, 13), (5378, 13), (5379, 13), (5380, 13), (5381, 13), (5382, 13), (5383, 13), (5384, 13), (5385, 13), (5386, 13), (5387, 13), (5388, 13), (5389, 13), (5390, 13), (5391, 13), (5392, 13), (5393, 13), (5394, 13), (5395, 13), (5396, 13), (5397, 13), (5398, 13), (5399, 13), (5400, 13), (5401, 13), (5402, 13), (5403, 13), (5404, 13), (5405, 13), (5406, 13), (5407, 13), (5408, 13), (5409, 13), (5410, 13), (5411, 13), (5412, 13), (5413, 13), (5414, 13), (5415, 13), (5416, 13), (5417, 13), (5418, 13), (5419, 13), (5420, 13), (5421, 13), (5422, 13), (5423, 13), (5424, 13), (5425, 13), (5426, 13), (5427, 13), (5428, 13), (5429, 13), (5430, 13), (5431, 13), (5432, 13), (5433, 13), (5434, 13), (5435, 13), (5436, 13), (5437, 13), (5438, 13), (5439, 13), (5440, 13), (5441, 13), (5442, 13), (5443, 13), (5444, 13), (5445, 13), (5446, 13), (5447, 13), (5448, 13), (5449, 13), (5450, 13), (5451, 13), (5452, 13), (5453, 13), (5454, 13), (5455, 13), (5456, 13), (5457, 13), (5458, 13), (5459, 13), (5460, 13), (5461, 13), (5462, 13), (5463, 13), (5464, 13), (5465, 13), (5466, 13), (5467, 13), (5468, 13), (5469, 13), (5470, 13), (5471, 13), (5472, 13), (5473, 13), (5474, 13), (5475, 13), (5476, 13), (5477, 13), (5478, 13), (5479, 13), (5480, 13), (5481, 13), (5482, 13), (5483, 13), (5484, 13), (5485, 13), (5486, 13), (5487, 13), (5488, 13), (5489, 13), (5490, 13), (5491, 13), (5492, 13), (5493, 13), (5494, 13), (5495, 13), (5496, 13), (5497, 13), (5498, 13), (5499, 13), (5500, 13), (5501, 13), (5502, 13), (5503, 13), (5504, 13), (5505, 13), (5506, 13), (5507, 13), (5508, 13), (5509, 13), (5510, 13), (5511, 13), (5512, 13), (5513, 13), (5514, 13), (5515, 13), (5516, 13), (5517, 13), (5518, 13), (5519, 13), (5520, 13), (5521, 13), (5522, 13), (5523, 13), (5524, 13), (5525, 13), (5526, 13), (5527, 13), (5528, 13), (5529, 13), (5530, 13), (5531, 13), (5532, 13), (5533, 13), (5534, 13), (5535, 13), (5536, 13), (5537, 13), (5538, 13), (5539, 13), (5540, 13), (5541, 13), (5542, 13), (5543, 13), (5544, 13), (5545, 13), (5546, 13), (5547, 13), (5548, 13), (5549, 13), (5550, 13), (5551, 13), (5552, 13), (5553, 13), (5554, 13), (5555, 13), (5556, 13), (5557, 13), (5558, 13), (5559, 13), (5560, 13), (5561, 13), (5562, 13), (5563, 13), (5564, 13), (5565, 13), (5566, 13), (5567, 13), (5568, 13), (5569, 13), (5570, 13), (5571, 13), (5572, 13), (5573, 13), (5574, 13), (5575, 13), (5576, 13), (5577, 13), (5578, 13), (5579, 13), (5580, 13), (5581, 13), (5582, 13), (5583, 13), (5584, 13), (5585, 13), (5586, 13), (5587, 13), (5588, 13), (5589, 13), (5590, 13), (5591, 13), (5592, 13), (5593, 13), (5594, 13), (5595, 13), (5596, 13), (5597, 13), (5598, 13), (5599, 13), (5600, 13), (5601, 13), (5602, 13), (5603, 13), (5604, 13), (5605, 13), (5606, 13), (5607, 13), (5608, 13), (5609, 13), (5610, 13), (5611, 13), (5612, 13), (5613, 13), (5614, 13), (5615, 13), (5616, 13), (5617, 13), (5618, 13), (5619, 13), (5620, 13), (5621, 13), (5622, 13), (5623, 13), (5624, 13), (5625, 13), (5626, 13), (5627, 13), (5628, 13), (5629, 13), (5630, 13), (5631, 13), (5632, 13), (5633, 13), (5634, 13), (5635, 13), (5636, 13), (5637, 13), (5638, 13), (5639, 13), (5640, 13), (5641, 13), (5642, 13), (5643, 13), (5644, 13), (5645, 13), (5646, 13), (5647, 13), (5648, 13), (5649, 13), (5650, 13), (5651, 13), (5652, 13), (5653, 13), (5654, 13), (5655, 13), (5656, 13), (5657, 13), (5658, 13), (5659, 13), (5660, 13), (5661, 13), (5662, 13), (5663, 13), (5664, 13), (5665, 13), (5666, 13), (5667, 13), (5668, 13), (5669, 13), (5670, 13), (5671, 13), (5672, 13), (5673, 13), (5674, 13), (5675, 13), (5676, 13), (5677, 13), (5678, 13), (5679, 13), (5680, 13), (5681, 13), (5682, 13), (5683, 13), (5684, 13), (5685, 13), (5686, 13), (5687, 13), (5688, 13), (5689, 13), (5690, 13), (5691, 13), (5692, 13), (5693, 13), (5694, 13), (5695, 13), (5696, 13), (5697, 13), (5698, 13), (5699, 13), (5700, 13), (5701, 13), (5702, 13), (5703, 13), (5704, 13), (5705, 13), (5706, 13), (5707, 13), (5708, 13), (5709, 13), (5710, 13), (5711, 13), (5712, 13), (5713, 13), (5714, 13), (5715, 13), (5716, 13), (5717, 13), (5718, 13), (5719, 13), (5720, 13), (5721, 13), (5722, 13), (5723, 13), (5724, 13), (5725, 13), (5726, 13), (5727, 13), (5728, 13), (5729, 13), (5730, 13), (5731, 13), (5732, 13), (5733, 13), (5734, 13), (5735, 13), (5736, 13), (5737, 13), (5738, 13), (5739, 13), (5740, 13), (5741, 13), (5742, 13), (5743, 13), (5744, 13), (5745, 13), (5746, 13), (5747, 13), (5748, 13), (5749, 13), (5750, 13), (5751, 13), (5752, 13), (5753, 13), (5754, 13), (5755, 13), (5756, 13), (5757, 13), (5758, 13), (5759, 13), (5760, 13), (5761, 13), (5762, 13), (5763, 13), (5764, 13), (5765, 13), (5766, 13), (5767, 13), (5768, 13), (5769, 13), (5770, 13), (5771, 13), (5772, 13), (5773, 13), (5774, 13), (5775, 13), (5776, 13), (5777, 13), (5778, 13), (5779, 13), (5780, 13), (5781, 13), (5782, 13), (5783, 13), (5784, 13), (5785, 13), (5786, 13), (5787, 13), (5788, 13), (5789, 13), (5790, 13), (5791, 13), (5792, 13), (5793, 13), (5794, 13), (5795, 13), (5796, 13), (5797, 13), (5798, 13), (5799, 13), (5800, 13), (5801, 13), (5802, 13), (5803, 13), (5804, 13), (5805, 13), (5806, 13), (5807, 13), (5808, 13), (5809, 13), (5810, 13), (5811, 13), (5812, 13), (5813, 13), (5814, 13), (5815, 13), (5816, 13), (5817, 13), (5818, 13), (5819, 13), (5820, 13), (5821, 13), (5822, 13), (5823, 13), (5824, 13), (5825, 13), (5826, 13), (5827, 13), (5828, 13), (5829, 13), (5830, 13), (5831, 13), (5832, 13), (5833, 13), (5834, 13), (5835, 13), (5836, 13), (5837, 13), (5838, 13), (5839, 13), (5840, 13), (5841, 13), (5842, 13), (5843, 13), (5844, 13), (5845, 13), (5846, 13), (5847, 13), (5848, 13), (5849, 13), (5850, 13), (5851, 13), (5852, 13), (5853, 13), (5854, 13), (5855, 13), (5856, 13), (5857, 13), (5858, 13), (5859, 13), (5860, 13), (5861, 13), (5862, 13), (5863, 13), (5864, 13), (5865, 13), (5866, 13), (5867, 13), (5868, 13), (5869, 13), (5870, 13), (5871, 13), (5872, 13), (5873, 13), (5874, 13), (5875, 13), (5876, 13), (5877, 13), (5878, 13), (5879, 13), (5880, 13), (5881, 13), (5882, 13), (5883, 13), (5884, 13), (5885, 13), (5886, 13), (5887, 13), (5888, 13), (5889, 13), (5890, 13), (5891, 13), (5892, 13), (5893, 13), (5894, 13), (5895, 13), (5896, 13), (5897, 13), (5898, 13), (5899, 13), (5900, 13), (5901, 13), (5902, 13), (5903, 13), (5904, 13), (5905, 13), (5906, 13), (5907, 13), (5908, 13), (5909, 13), (5910, 13), (5911, 13), (5912, 13), (5913, 13), (5914, 13), (5915, 13), (5916, 13), (5917, 13), (5918, 13), (5919, 13), (5920, 13), (5921, 13), (5922, 13), (5923, 13), (5924, 13), (5925, 13), (5926, 13), (5927, 13), (5928, 13), (5929, 13), (5930, 13), (5931, 13), (5932, 13), (5933, 13), (5934, 13), (5935, 13), (5936, 13), (5937, 13), (5938, 13), (5939, 13), (5940, 13), (5941, 13), (5942, 13), (5943, 13), (5944, 13), (5945, 13), (5946, 13), (5947, 13), (5948, 13), (5949, 13), (5950, 13), (5951, 13), (5952, 13), (5953, 13), (5954, 13), (5955, 13), (5956, 13), (5957, 13), (5958, 13), (5959, 13), (5960, 13), (5961, 13), (5962, 13), (5963, 13), (5964, 13), (5965, 13), (5966, 13), (5967, 13), (5968, 13), (5969, 13), (5970, 13), (5971, 13), (5972, 13), (5973, 13), (5974, 13), (5975, 13), (5976, 13), (5977, 13), (5978, 13), (5979, 13), (5980, 13), (5981, 13), (5982, 13), (5983, 13), (5984, 13), (5985, 13), (5986, 13), (5987, 13), (5988, 13), (5989, 13), (5990, 13), (5991, 13), (5992, 13), (5993, 13), (5994, 13), (5995, 13), (5996, 13), (5997, 13), (5998, 13), (5999, 13), (6000, 13), (6001, 13), (6002, 13), (6003, 13), (6004, 13), (6005, 13), (6006, 13), (6007, 13), (6008, 13), (6009, 13), (6010, 13), (6011, 13), (6012, 13), (6013, 13), (6014, 13), (6015, 13), (6016, 13), (6017, 13), (6018, 13), (6019, 13), (6020, 13), (6021, 13), (6022, 13), (6023, 13), (6024, 13), (6025, 13), (6026, 13), (6027, 13), (6028, 13), (6029, 13), (6030, 13), (6031, 13), (6032, 13), (6033, 13), (6034, 13), (6035, 13), (6036, 13), (6037, 13), (6038, 13), (6039, 13), (6040, 13), (6041, 13), (6042, 13), (6043, 13), (6044, 13), (6045, 13), (6046, 13), (6047, 13), (6048, 13), (6049, 13), (6050, 13), (6051, 13), (6052, 13), (6053, 13), (6054, 13), (6055, 13), (6056, 13), (6057, 13), (6058, 13), (6059, 13), (6060, 13), (6061, 13), (6062, 13), (6063, 13), (6064, 13), (6065, 13), (6066, 13), (6067, 13), (6068, 13), (6069, 13), (6070, 13), (6071, 13), (6072, 13), (6073, 13), (6074, 13), (6075, 13), (6076, 13), (6077, 13), (6078, 13), (6079, 13), (6080, 13), (6081, 13), (6082, 13), (6083, 13), (6084, 13), (6085, 13), (6086, 13), (6087, 13), (6088, 13), (6089, 13), (6090, 13), (6091, 13), (6092, 13), (6093, 13), (6094, 13), (6095, 13), (6096, 13), (6097, 13), (6098, 13), (6099, 13), (6100, 13), (6101, 13), (6102, 13), (6103, 13), (6104, 13), (6105, 13), (6106, 13), (6107, 13), (6108, 13), (6109, 13), (6110, 13), (6111, 13), (6112, 13), (6113, 13), (6114, 13), (6115, 13), (6116, 13), (6117, 13), (6118, 13), (6119, 13), (6120, 13), (6121, 13), (6122, 13), (6123, 13), (6124, 13), (6125, 13), (6126, 13), (6127, 13), (6128, 13), (6129, 13), (6130, 13), (6131, 13), (6132, 13), (6133, 13), (6134, 13), (6135, 13), (6136, 13), (6137, 13), (6138, 13), (6139, 13), (6140, 13), (6141, 13), (6142, 13), (6143, 13), (6144, 13), (6145, 13), (6146, 13), (6147, 13), (6148, 13), (6149, 13), (6150, 13), (6151, 13), (6152, 13), (6153, 13), (6154, 13), (6155, 13), (6156, 13), (6157, 13), (6158, 13), (6159, 13), (6160, 13), (6161, 13), (6162, 13), (6163, 13), (6164, 13), (6165, 13), (6166, 13), (6167, 13), (6168, 13), (6169, 13), (6170, 13), (6171, 13), (6172, 13), (6173, 13), (6174, 13), (6175, 13), (6176, 13), (6177, 13), (6178, 13), (6179, 13), (6180, 13), (6181, 13), (6182, 13), (6183, 13), (6184, 13), (6185, 13), (6186, 13), (6187, 13), (6188, 13), (6189, 13), (6190, 13), (6191, 13), (6192, 13), (6193, 13), (6194, 13), (6195, 13), (6196, 13), (6197, 13), (6198, 13), (6199, 13), (6200, 13), (6201, 13), (6202, 13), (6203, 13), (6204, 13), (6205, 13), (6206, 13), (6207, 13), (6208, 13), (6209, 13), (6210, 13), (6211, 13), (6212, 13), (6213, 13), (6214, 13), (6215, 13), (6216, 13), (6217, 13), (6218, 13), (6219, 13), (6220, 13), (6221, 13), (6222, 13), (6223, 13), (6224, 13), (6225, 13), (6226, 13), (6227, 13), (6228, 13), (6229, 13), (6230, 13), (6231, 13), (6232, 13), (6233, 13), (6234, 13), (6235, 13), (6236, 13), (6237, 13), (6238, 13), (6239, 13), (6240, 13), (6241, 13), (6242, 13), (6243, 13), (6244, 13), (6245, 13), (6246, 13), (6247, 13), (6248, 13), (6249, 13), (6250, 13), (6251, 13), (6252, 13), (6253, 13), (6254, 13), (6255, 13), (6256, 13), (6257, 13), (6258, 13), (6259, 13), (6260, 13), (6261, 13), (6262, 13), (6263, 13), (6264, 13), (6265, 13), (6266, 13), (6267, 13), (6268, 13), (6269, 13), (6270, 13), (6271, 13), (6272, 13), (6273, 13), (6274, 13), (6275, 13), (6276, 13), (6277, 13), (6278, 13), (6279, 13), (6280, 13), (6281, 13), (6282, 13), (6283, 13), (6284, 13), (6285, 13), (6286, 13), (6287, 13), (6288, 13), (6289, 13), (6290, 13), (6291, 13), (6292, 13), (6293, 13), (6294, 13), (6295, 13), (6296, 13), (6297, 13), (6298, 13), (6299, 13), (6300, 13), (6301, 13), (6302, 13), (6303, 13), (6304, 13), (6305, 13), (6306, 13), (6307, 13), (6308, 13), (6309, 13), (6310, 13), (6311, 13), (6312, 13), (6313, 13), (6314, 13), (6315, 13), (6316, 13), (6317, 13), (6318, 13), (6319, 13), (6320, 13), (6321, 13), (6322, 13), (6323, 13), (6324, 13), (6325, 13), (6326, 13), (6327, 13), (6328, 13), (6329, 13), (6330, 13), (6331, 13), (6332, 13), (6333, 13), (6334, 13), (6335, 13), (6336, 13), (6337, 13), (6338, 13), (6339, 13), (6340, 13), (6341, 13), (6342, 13), (6343, 13), (6344, 13), (6345, 13), (6346, 13), (6347, 13), (6348, 13), (6349, 13), (6350, 13), (6351, 13), (6352, 13), (6353, 13), (6354, 13), (6355, 13), (6356, 13), (6357, 13), (6358, 13), (6359, 13), (6360, 13), (6361, 13), (6362, 13), (6363, 13), (6364, 13), (6365, 13), (6366, 13), (6367, 13), (6368, 13), (6369, 13), (6370, 13), (6371, 13), (6372, 13), (6373, 13), (6374, 13), (6375, 13), (6376, 13), (6377, 13), (6378, 13), (6379, 13), (6380, 13), (6381, 13), (6382, 13), (6383, 13), (6384, 13), (6385, 13), (6386, 13), (6387, 13), (6388, 13), (6389, 13), (6390, 13), (6391, 13), (6392, 13), (6393, 13), (6394, 13), (6395, 13), (6396, 13), (6397, 13), (6398, 13), (6399, 13), (6400, 13), (6401, 13), (6402, 13), (6403, 13), (6404, 13), (6405, 13), (6406, 13), (6407, 13), (6408, 13), (6409, 13), (6410, 13), (6411, 13), (6412, 13), (6413, 13), (6414, 13), (6415, 13), (6416, 13), (6417, 13), (6418, 13), (6419, 13), (6420, 13), (6421, 13), (6422, 13), (6423, 13), (6424, 13), (6425, 13), (6426, 13), (6427, 13), (6428, 13), (6429, 13), (6430, 13), (6431, 13), (6432, 13), (6433, 13), (6434, 13), (6435, 13), (6436, 13), (6437, 13), (6438, 13), (6439, 13), (6440, 13), (6441, 13), (6442, 13), (6443, 13), (6444, 13), (6445, 13), (6446, 13), (6447, 13), (6448, 13), (6449, 13), (6450, 13), (6451, 13), (6452, 13), (6453, 13), (6454, 13), (6455, 13), (6456, 13), (6457, 13), (6458, 13), (6459, 13), (6460, 13), (6461, 13), (6462, 13), (6463, 13), (6464, 13), (6465, 13), (6466, 13), (6467, 13), (6468, 13), (6469, 13), (6470, 13), (6471, 13), (6472, 13), (6473, 13), (6474, 13), (6475, 13), (6476, 13), (6477, 13), (6478, 13), (6479, 13), (6480, 13), (6481, 13), (6482, 13), (6483, 13), (6484, 13), (6485, 13), (6486, 13), (6487, 13), (6488, 13), (6489, 13), (6490, 13), (6491, 13), (6492, 13), (6493, 13), (6494, 13), (6495, 13), (6496, 13), (6497, 13), (6498, 13), (6499, 13), (6500, 13), (6501, 13), (6502, 13), (6503, 13), (6504, 13), (6505, 13), (6506, 13), (6507, 13), (6508, 13), (6509, 13), (6510, 13), (6511, 13), (6512, 13), (6513, 13), (6514, 13), (6515, 13), (6516, 13), (6517, 13), (6518, 13), (6519, 13), (6520, 13), (6521, 13), (6522, 13), (6523, 13), (6524, 13), (6525, 13), (6526, 13), (6527, 13), (6528, 13), (6529, 13), (6530, 13), (6531, 13), (6532, 13), (6533, 13), (6534, 13), (6535, 13), (6536, 13), (6537, 13), (6538, 13), (6539, 13), (6540, 13), (6541, 13), (6542, 13), (6543, 13), (6544, 13), (6545, 13), (6546, 13), (6547, 13), (6548, 13), (6549, 13), (6550, 13), (6551, 13), (6552, 13), (6553, 13), (6554, 13), (6555, 13), (6556, 13), (6557, 13), (6558, 13), (6559, 13), (6560, 13), (6561, 13), (6562, 13), (6563, 13), (6564, 13), (6565, 13), (6566, 13), (6567, 13), (6568, 13), (6569, 13), (6570, 13), (6571, 13), (6572, 13), (6573, 13), (6574, 13), (6575, 13), (6576, 13), (6577, 13), (6578, 13), (6579, 13), (6580, 13), (6581, 13), (6582, 13), (6583, 13), (6584, 13), (6585, 13), (6586, 13), (6587, 13), (6588, 13), (6589, 13), (6590, 13), (6591, 13), (6592, 13), (6593, 13), (6594, 13), (6595, 13), (6596, 13), (6597, 13), (6598, 13), (6599, 13), (6600, 13), (6601, 13), (6602, 13), (6603, 13), (6604, 13), (6605, 13), (6606, 13), (6607, 13), (6608, 13), (6609, 13), (6610, 13), (6611, 13), (6612, 13), (6613, 13), (6614, 13), (6615, 13), (6616, 13), (6617, 13), (6618, 13), (6619, 13), (6620, 13), (6621, 13), (6622, 13), (6623, 13), (6624, 13), (6625, 13), (6626, 13), (6627, 13), (6628, 13), (6629, 13), (6630, 13), (6631, 13), (6632, 13), (6633, 13), (6634, 13), (6635, 13), (6636, 13), (6637, 13), (6638, 13), (6639, 13), (6640, 13), (6641, 13), (6642, 13), (6643, 13), (6644, 13), (6645, 13), (6646, 13), (6647, 13), (6648, 13), (6649, 13), (6650, 13), (6651, 13), (6652, 13), (6653, 13), (6654, 13), (6655, 13), (6656, 13), (6657, 13), (6658, 13), (6659, 13), (6660, 13), (6661, 13), (6662, 13), (6663, 13), (6664, 13), (6665, 13), (6666, 13), (6667, 13), (6668, 13), (6669, 13), (6670, 13), (6671, 13), (6672, 13), (6673, 13), (6674, 13), (6675, 13), (6676, 13), (6677, 13), (6678, 13), (6679, 13), (6680, 13), (6681, 13), (6682, 13), (6683, 13), (6684, 13), (6685, 13), (6686, 13), (6687, 13), (6688, 13), (6689, 13), (6690, 13), (6691, 13), (6692, 13), (6693, 13), (6694, 13), (6695, 13), (6696, 13), (6697, 13), (6698, 13), (6699, 13), (6700, 13), (6701, 13), (6702, 13), (6703, 13), (6704, 13), (6705, 13), (6706, 13), (6707, 13), (6708, 13), (6709, 13), (6710, 13), (6711, 13), (6712, 13), (6713, 13), (6714, 13), (6715, 13), (6716, 13), (6717, 13), (6718, 13), (6719, 13), (6720, 13), (6721, 13), (6722, 13), (6723, 13), (6724, 13), (6725, 13), (6726, 13), (6727, 13), (6728, 13), (6729, 13), (6730, 13), (6731, 13), (6732, 13), (6733, 13), (6734, 13), (6735, 13), (6736, 13), (6737, 13), (6738, 13), (6739, 13), (6740, 13), (6741, 13), (6742, 13), (6743, 13), (6744, 13), (6745, 13), (6746, 13), (6747, 13), (6748, 13), (6749, 13), (6750, 13), (6751, 13), (6752, 13), (6753, 13), (6754, 13), (6755, 13), (6756, 13), (6757, 13), (6758, 13), (6759, 13), (6760, 13), (6761, 13), (6762, 13), (6763, 13), (6764, 13), (6765, 13), (6766, 13), (6767, 13), (6768, 13), (6769, 13), (6770, 13), (6771, 13), (6772, 13), (6773, 13), (6774, 13), (6775, 13), (6776, 13), (6777, 13), (6778, 13), (6779, 13), (6780, 13), (6781, 13), (6782, 13), (6783, 13), (6784, 13), (6785, 13), (6786, 13), (6787, 13), (6788, 13), (6789, 13), (6790, 13), (6791, 13), (6792, 13), (6793, 13), (6794, 13), (6795, 13), (6796, 13), (6797, 13), (6798, 13), (6799, 13), (6800, 13), (6801, 13), (6802, 13), (6803, 13), (6804, 13), (6805, 13), (6806, 13), (6807, 13), (6808, 13), (6809, 13), (6810, 13), (6811, 13), (6812, 13), (6813, 13), (6814, 13), (6815, 13), (6816, 13), (6817, 13), (6818, 13), (6819, 13), (6820, 13), (6821, 13), (6822, 13), (6823, 13), (6824, 13), (6825, 13), (6826, 13), (6827, 13), (6828, 13), (6829, 13), (6830, 13), (6831, 13), (6832, 13), (6833, 13), (6834, 13), (6835, 13), (6836, 13), (6837, 13), (6838, 13), (6839, 13), (6840, 13), (6841, 13), (6842, 13), (6843, 13), (6844, 13), (6845, 13), (6846, 13), (6847, 13), (6848, 13), (6849, 13), (6850, 13), (6851, 13), (6852, 13), (6853, 13), (6854, 13), (6855, 13), (6856, 13), (6857, 13), (6858, 13), (6859, 13), (6860, 13), (6861, 13), (6862, 13), (6863, 13), (6864, 13), (6865, 13), (6866, 13), (6867, 13), (6868, 13), (6869, 13), (6870, 13), (6871, 13), (6872, 13), (6873, 13), (6874, 13), (6875, 13), (6876, 13), (6877, 13), (6878, 13), (6879, 13), (6880, 13), (6881, 13), (6882, 13), (6883, 13), (6884, 13), (6885, 13), (6886, 13), (6887, 13), (6888, 13), (6889, 13), (6890, 13), (6891, 13), (6892, 13), (6893, 13), (6894, 13), (6895, 13), (6896, 13), (6897, 13), (6898, 13), (6899, 13), (6900, 13), (6901, 13), (6902, 13), (6903, 13), (6904, 13), (6905, 13), (6906, 13), (6907, 13), (6908, 13), (6909, 13), (6910, 13), (6911, 13), (6912, 13), (6913, 13), (6914, 13), (6915, 13), (6916, 13), (6917, 13), (6918, 13), (6919, 13), (6920, 13), (6921, 13), (6922, 13), (6923, 13), (6924, 13), (6925, 13), (6926, 13), (6927, 13), (6928, 13), (6929, 13), (6930, 13), (6931, 13), (6932, 13), (6933, 13), (6934, 13), (6935, 13), (6936, 13), (6937, 13), (6938, 13), (6939, 13), (6940, 13), (6941, 13), (6942, 13), (6943, 13), (6944, 13), (6945, 13), (6946, 13), (6947, 13), (6948, 13), (6949, 13), (6950, 13), (6951, 13), (6952, 13), (6953, 13), (6954, 13), (6955, 13), (6956, 13), (6957, 13), (6958, 13), (6959, 13), (6960, 13), (6961, 13), (6962, 13), (6963, 13), (6964, 13), (6965, 13), (6966, 13), (6967, 13), (6968, 13), (6969, 13), (6970, 13), (6971, 13), (6972, 13), (6973, 13), (6974, 13), (6975, 13), (6976, 13), (6977, 13), (6978, 13), (6979, 13), (6980, 13), (6981, 13), (6982, 13), (6983, 13), (6984, 13), (6985, 13), (6986, 13), (6987, 13), (6988, 13), (6989, 13), (6990, 13), (6991, 13), (6992, 13), (6993, 13), (6994, 13), (6995, 13), (6996, 13), (6997, 13), (6998, 13), (6999, 13), (7000, 13), (7001, 13), (7002, 13), (7003, 13), (7004, 13), (7005, 13), (7006, 13), (7007, 13), (7008, 13), (7009, 13), (7010, 13), (7011, 13), (7012, 13), (7013, 13), (7014, 13), (7015, 13), (7016, 13), (7017, 13), (7018, 13), (7019, 13), (7020, 13), (7021, 13), (7022, 13), (7023, 13), (7024, 13), (7025, 13), (7026, 13), (7027, 13), (7028, 13), (7029, 13), (7030, 13), (7031, 13), (7032, 13), (7033, 13), (7034, 13), (7035, 13), (7036, 13), (7037, 13), (7038, 13), (7039, 13), (7040, 13), (7041, 13), (7042, 13), (7043, 13), (7044, 13), (7045, 13), (7046, 13), (7047, 13), (7048, 13), (7049, 13), (7050, 13), (7051, 13), (7052, 13), (7053, 13), (7054, 13), (7055, 13), (7056, 13), (7057, 13), (7058, 13), (7059, 13), (7060, 13), (7061, 13), (7062, 13), (7063, 13), (7064, 13), (7065, 13), (7066, 13), (7067, 13), (7068, 13), (7069, 13), (7070, 13), (7071, 13), (7072, 13), (7073, 13), (7074, 13), (7075, 13), (7076, 13), (7077, 13), (7078, 13), (7079, 13), (7080, 13), (7081, 13), (7082, 13), (7083, 13), (7084, 13), (7085, 13), (7086, 13), (7087, 13), (7088, 13), (7089, 13), (7090, 13), (7091, 13), (7092, 13), (7093, 13), (7094, 13), (7095, 13), (7096, 13), (7097, 13), (7098, 13), (7099, 13), (7100, 13), (7101, 13), (7102, 13), (7103, 13), (7104, 13), (7105, 13), (7106, 13), (7107, 13), (7108, 13), (7109, 13), (7110, 13), (7111, 13), (7112, 13), (7113, 13), (7114, 13), (7115, 13), (7116, 13), (7117, 13), (7118, 13), (7119, 13), (7120, 13), (7121, 13), (7122, 13), (7123, 13), (7124, 13), (7125, 13), (7126, 13), (7127, 13), (7128, 13), (7129, 13), (7130, 13), (7131, 13), (7132, 13), (7133, 13), (7134, 13), (7135, 13), (7136, 13), (7137, 13), (7138, 13), (7139, 13), (7140, 13), (7141, 13), (7142, 13), (7143, 13), (7144, 13), (7145, 13), (7146, 13), (7147, 13), (7148, 13), (7149, 13), (7150, 13), (7151, 13), (7152, 13), (7153, 13), (7154, 13), (7155, 13), (7156, 13), (7157, 13), (7158, 13), (7159, 13), (7160, 13), (7161, 13), (7162, 13), (7163, 13), (7164, 13), (7165, 13), (7166, 13), (7167, 13), (7168, 13), (7169, 13), (7170, 13), (7171, 13), (7172, 13), (7173, 13), (7174, 13), (7175, 13), (7176, 13), (7177, 13), (7178, 13), (7179, 13), (7180, 13), (7181, 13), (7182, 13), (7183, 13), (7184, 13), (7185, 13), (7186, 13), (7187, 13), (7188, 13), (7189, 13), (7190, 13), (7191, 13), (7192, 13), (7193, 13), (7194, 13), (7195, 13), (7196, 13), (7197, 13), (7198, 13), (7199, 13), (7200, 13), (7201, 13), (7202, 13), (7203, 13), (7204, 13), (7205, 13), (7206, 13), (7207, 13), (7208, 13), (7209, 13), (7210, 13), (7211, 13), (7212, 13), (7213, 13), (7214, 13), (7215, 13), (7216, 13), (7217, 13), (7218, 13), (7219, 13), (7220, 13), (7221, 13), (7222, 13), (7223, 13), (7224, 13), (7225, 13), (7226, 13), (7227, 13), (7228, 13), (7229, 13), (7230, 13), (7231, 13), (7232, 13), (7233, 13), (7234, 13), (7235, 13), (7236, 13), (7237, 13), (7238, 13), (7239, 13), (7240, 13), (7241, 13), (7242, 13), (7243, 13), (7244, 13), (7245, 13), (7246, 13), (7247, 13), (7248, 13), (7249, 13), (7250, 13), (7251, 13), (7252, 13), (7253, 13), (7254, 13), (7255, 13), (7256, 13), (7257, 13), (7258, 13), (7259, 13), (7260, 13), (7261, 13), (7262, 13), (7263, 13), (7264, 13), (7265, 13), (7266, 13), (7267, 13), (7268, 13), (7269, 13), (7270, 13), (7271, 13), (7272, 13), (7273, 13), (7274, 13), (7275, 13), (7276, 13), (7277, 13), (7278, 13), (7279, 13), (7280, 13), (7281, 13), (7282, 13), (7283, 13), (7284, 13), (7285, 13), (7286, 13), (7287, 13), (7288, 13), (7289, 13), (7290, 13), (7291, 13), (7292, 13), (7293, 13), (7294, 13), (7295, 13), (7296, 13), (7297, 13), (7298, 13), (7299, 13), (7300, 13), (7301, 13), (7302, 13), (7303, 13), (7304, 13), (7305, 13), (7306, 13), (7307, 13), (7308, 13), (7309, 13), (7310, 13), (7311, 13), (7312, 13), (7313, 13), (7314, 13), (7315, 13), (7316, 13), (7317, 13), (7318, 13), (7319, 13), (7320, 13), (7321, 13), (7322, 13), (7323, 13), (7324, 13), (7325, 13), (7326, 13), (7327, 13), (7328, 13), (7329, 13), (7330, 13), (7331, 13), (7332, 13), (7333, 13), (7334, 13), (7335, 13), (7336, 13), (7337, 13), (7338, 13), (7339, 13), (7340, 13), (7341, 13), (7342, 13), (7343, 13), (7344, 13), (7345, 13), (7346, 13), (7347, 13), (7348, 13), (7349, 13), (7350, 13), (7351, 13), (7352, 13), (7353, 13), (7354, 13), (7355, 13), (7356, 13), (7357, 13), (7358, 13), (7359, 13), (7360, 13), (7361, 13), (7362, 13), (7363, 13), (7364, 13), (7365, 13), (7366, 13), (7367, 13), (7368, 13), (7369, 13), (7370, 13), (7371, 13), (7372, 13), (7373, 13), (7374, 13), (7375, 13), (7376, 13), (7377, 13), (7378, 13), (7379, 13), (7380, 13), (7381, 13), (7382, 13), (7383, 13), (7384, 13), (7385, 13), (7386, 13), (7387, 13), (7388, 13), (7389, 13), (7390, 13), (7391, 13), (7392, 13), (7393, 13), (7394, 13), (7395, 13), (7396, 13), (7397, 13), (7398, 13), (7399, 13), (7400, 13), (7401, 13), (7402, 13), (7403, 13), (7404, 13), (7405, 13), (7406, 13), (7407, 13), (7408, 13), (7409, 13), (7410, 13), (7411, 13), (7412, 13), (7413, 13), (7414, 13), (7415, 13), (7416, 13), (7417, 13), (7418, 13), (7419, 13), (7420, 13), (7421, 13), (7422, 13), (7423, 13), (7424, 13), (7425, 13), (7426, 13), (7427, 13), (7428, 13), (7429, 13), (7430, 13), (7431, 13), (7432, 13), (7433, 13), (7434, 13), (7435, 13), (7436, 13), (7437, 13), (7438, 13), (7439, 13), (7440, 13), (7441, 13), (7442, 13), (7443, 13), (7444, 13), (7445, 13), (7446, 13), (7447, 13), (7448, 13), (7449, 13), (7450, 13), (7451, 13), (7452, 13), (7453, 13), (7454, 13), (7455, 13), (7456, 13), (7457, 13), (7458, 13), (7459, 13), (7460, 13), (7461, 13), (7462, 13), (7463, 13), (7464, 13), (7465, 13), (7466, 13), (7467, 13), (7468, 13), (7469, 13), (7470, 13), (7471, 13), (7472, 13), (7473, 13), (7474, 13), (7475, 13), (7476, 13), (7477, 13), (7478, 13), (7479, 13), (7480, 13), (7481, 13), (7482, 13), (7483, 13), (7484, 13), (7485, 13), (7486, 13), (7487, 13), (7488, 13), (7489, 13), (7490, 13), (7491, 13), (7492, 13), (7493, 13), (7494, 13), (7495, 13), (7496, 13), (7497, 13), (7498, 13), (7499, 13), (7500, 13), (7501, 13), (7502, 13), (7503, 13), (7504, 13), (7505, 13), (7506, 13), (7507, 13), (7508, 13), (7509, 13), (7510, 13), (7511, 13), (7512, 13), (7513, 13), (7514, 13), (7515, 13), (7516, 13), (7517, 13), (7518, 13), (7519, 13), (7520, 13), (7521, 13), (7522, 13), (7523, 13), (7524, 13), (7525, 13), (7526, 13), (7527, 13), (7528, 13), (7529, 13), (7530, 13), (7531, 13), (7532, 13), (7533, 13), (7534, 13), (7535, 13), (7536, 13), (7537, 13), (7538, 13), (7539, 13), (7540, 13), (7541, 13), (7542, 13), (7543, 13), (7544, 13), (7545, 13), (7546, 13), (7547, 13), (7548, 13), (7549, 13), (7550, 13), (7551, 13), (7552, 13), (7553, 13), (7554, 13), (7555, 13), (7556, 13), (7557, 13), (7558, 13), (7559, 13), (7560, 13), (7561, 13), (7562, 13), (7563, 13), (7564, 13), (7565, 13), (7566, 13), (7567, 13), (7568, 13), (7569, 13), (7570, 13), (7571, 13), (7572, 13), (7573, 13), (7574, 13), (7575, 13), (7576, 13), (7577, 13), (7578, 13), (7579, 13), (7580, 13), (7581, 13), (7582, 13), (7583, 13), (7584, 13), (7585, 13), (7586, 13), (7587, 13), (7588, 13), (7589, 13), (7590, 13), (7591, 13), (7592, 13), (7593, 13), (7594, 13), (7595, 13), (7596, 13), (7597, 13), (7598, 13), (7599, 13), (7600, 13), (7601, 13), (7602, 13), (7603, 13), (7604, 13), (7605, 13), (7606, 13), (7607, 13), (7608, 13), (7609, 13), (7610, 13), (7611, 13), (7612, 13), (7613, 13), (7614, 13), (7615, 13), (7616, 13), (7617, 13), (7618, 13), (7619, 13), (7620, 13), (7621, 13), (7622, 13), (7623, 13), (7624, 13), (7625, 13), (7626, 13), (7627, 13), (7628, 13), (7629, 13), (7630, 13), (7631, 13), (7632, 13), (7633, 13), (7634, 13), (7635, 13), (7636, 13), (7637, 13), (7638, 13), (7639, 13), (7640, 13), (7641, 13), (7642, 13), (7643, 13), (7644, 13), (7645, 13), (7646, 13), (7647, 13), (7648, 13), (7649, 13), (7650, 13), (7651, 13), (7652, 13), (7653, 13), (7654, 13), (7655, 13), (7656, 13), (7657, 13), (7658, 13), (7659, 13), (7660, 13), (7661, 13), (7662, 13), (7663, 13), (7664, 13), (7665, 13), (7666, 13), (7667, 13), (7668, 13), (7669, 13), (7670, 13), (7671, 13), (7672, 13), (7673, 13), (7674, 13), (7675, 13), (7676, 13), (7677, 13), (7678, 13), (7679, 13), (7680, 13), (7681, 13), (7682, 13), (7683, 13), (7684, 13), (7685, 13), (7686, 13), (7687, 13), (7688, 13), (7689, 13), (7690, 13), (7691, 13), (7692, 13), (7693, 13), (7694, 13), (7695, 13), (7696, 13), (7697, 13), (7698, 13), (7699, 13), (7700, 13), (7701, 13), (7702, 13), (7703, 13), (7704, 13), (7705, 13), (7706, 13), (7707, 13), (7708, 13), (7709, 13), (7710, 13), (7711, 13), (7712, 13), (7713, 13), (7714, 13), (7715, 13), (7716, 13), (7717, 13), (7718, 13), (7719, 13), (7720, 13), (7721, 13), (7722, 13), (7723, 13), (7724, 13), (7725, 13), (7726, 13), (7727, 13), (7728, 13), (7729, 13), (7730, 13), (7731, 13), (7732, 13), (7733, 13), (7734, 13), (7735, 13), (7736, 13), (7737, 13), (7738, 13), (7739, 13), (7740, 13), (7741, 13), (7742, 13), (7743, 13), (7744, 13), (7745, 13), (7746, 13), (7747, 13), (7748, 13), (7749, 13), (7750, 13), (7751, 13), (7752, 13), (7753, 13), (7754, 13), (7755, 13), (7756, 13), (7757, 13), (7758, 13), (7759, 13), (7760, 13), (7761, 13), (7762, 13), (7763, 13), (7764, 13), (7765, 13), (7766, 13), (7767, 13), (7768, 13), (7769, 13), (7770, 13), (7771, 13), (7772, 13), (7773, 13), (7774, 13), (7775, 13), (7776, 13), (7777, 13), (7778, 13), (7779, 13), (7780, 13), (7781, 13), (7782, 13), (7783, 13), (7784, 13), (7785, 13), (7786, 13), (7787, 13), (7788, 13), (7789, 13), (7790, 13), (7791, 13), (7792, 13), (7793, 13), (7794, 13), (7795, 13), (7796, 13), (7797, 13), (7798, 13), (7799, 13), (7800, 13), (7801, 13), (7802, 13), (7803, 13), (7804, 13), (7805, 13), (7806, 13), (7807, 13), (7808, 13), (7809, 13), (7810, 13), (7811, 13), (7812, 13), (7813, 13), (7814, 13), (7815, 13), (7816, 13), (7817, 13), (7818, 13), (7819, 13), (7820, 13), (7821, 13), (7822, 13), (7823, 13), (7824, 13), (7825, 13), (7826, 13), (7827, 13), (7828, 13), (7829, 13), (7830, 13), (7831, 13), (7832, 13), (7833, 13), (7834, 13), (7835, 13), (7836, 13), (7837, 13), (7838, 13), (7839, 13), (7840, 13), (7841, 13), (7842, 13), (7843, 13), (7844, 13), (7845, 13), (7846, 13), (7847, 13), (7848, 13), (7849, 13), (7850, 13), (7851, 13), (7852, 13), (7853, 13), (7854, 13), (7855, 13), (7856, 13), (7857, 13), (7858, 13), (7859, 13), (7860, 13), (7861, 13), (7862, 13), (7863, 13), (7864, 13), (7865, 13), (7866, 13), (7867, 13), (7868, 13), (7869, 13), (7870, 13), (7871, 13), (7872, 13), (7873, 13), (7874, 13), (7875, 13), (7876, 13), (7877, 13), (7878, 13), (7879, 13), (7880, 13), (7881, 13), (7882, 13), (7883, 13), (7884, 13), (7885, 13), (7886, 13), (7887, 13), (7888, 13), (7889, 13), (7890, 13), (7891, 13), (7892, 13), (7893, 13), (7894, 13), (7895, 13), (7896, 13), (7897, 13), (7898, 13), (7899, 13), (7900, 13), (7901, 13), (7902, 13), (7903, 13), (7904, 13), (7905, 13), (7906, 13), (7907, 13), (7908, 13), (7909, 13), (7910, 13), (7911, 13), (7912, 13), (7913, 13), (7914, 13), (7915, 13), (7916, 13), (7917, 13), (7918, 13), (7919, 13), (7920, 13), (7921, 13), (7922, 13), (7923, 13), (7924, 13), (7925, 13), (7926, 13), (7927, 13), (7928, 13), (7929, 13), (7930, 13), (7931, 13), (7932, 13), (7933, 13), (7934, 13), (7935, 13), (7936, 13), (7937, 13), (7938, 13), (7939, 13), (7940, 13), (7941, 13), (7942, 13), (7943, 13), (7944, 13), (7945, 13), (7946, 13), (7947, 13), (7948, 13), (7949, 13), (7950, 13), (7951, 13), (7952, 13), (7953, 13), (7954, 13), (7955, 13), (7956, 13), (7957, 13), (7958, 13), (7959, 13), (7960, 13), (7961, 13), (7962, 13), (7963, 13), (7964, 13), (7965, 13), (7966, 13), (7967, 13), (7968, 13), (7969, 13), (7970, 13), (7971, 13), (7972, 13), (7973, 13), (7974, 13), (7975, 13), (7976, 13), (7977, 13), (7978, 13), (7979, 13), (7980, 13), (7981, 13), (7982, 13), (7983, 13), (7984, 13), (7985, 13), (7986, 13), (7987, 13), (7988, 13), (7989, 13), (7990, 13), (7991, 13), (7992, 13), (7993, 13), (7994, 13), (7995, 13), (7996, 13), (7997, 13), (7998, 13), (7999, 13), (8000, 13), (8001, 13), (8002, 13), (8003, 13), (8004, 13), (8005, 13), (8006, 13), (8007, 13), (8008, 13), (8009, 13), (8010, 13), (8011, 13), (8012, 13), (8013, 13), (8014, 13), (8015, 13), (8016, 13), (8017, 13), (8018, 13), (8019, 13), (8020, 13), (8021, 13), (8022, 13), (8023, 13), (8024, 13), (8025, 13), (8026, 13), (8027, 13), (8028, 13), (8029, 13), (8030, 13), (8031, 13), (8032, 13), (8033, 13), (8034, 13), (8035, 13), (8036, 13), (8037, 13), (8038, 13), (8039, 13), (8040, 13), (8041, 13), (8042, 13), (8043, 13), (8044, 13), (8045, 13), (8046, 13), (8047, 13), (8048, 13), (8049, 13), (8050, 13), (8051, 13), (8052, 13), (8053, 13), (8054, 13), (8055, 13), (8056, 13), (8057, 13), (8058, 13), (8059, 13), (8060, 13), (8061, 13), (8062, 13), (8063, 13), (8064, 13), (8065, 13), (8066, 13), (8067, 13), (8068, 13), (8069, 13), (8070, 13), (8071, 13), (8072, 13), (8073, 13), (8074, 13), (8075, 13), (8076, 13), (8077, 13), (8078, 13), (8079, 13), (8080, 13), (8081, 13), (8082, 13), (8083, 13), (8084, 13), (8085, 13), (8086, 13), (8087, 13), (8088, 13), (8089, 13), (8090, 13), (8091, 13), (8092, 13), (8093, 13), (8094, 13), (8095, 13), (8096, 13), (8097, 13), (8098, 13), (8099, 13), (8100, 13), (8101, 13), (8102, 13), (8103, 13), (8104, 13), (8105, 13), (8106, 13), (8107, 13), (8108, 13), (8109, 13), (8110, 13), (8111, 13), (8112, 13), (8113, 13), (8114, 13), (8115, 13), (8116, 13), (8117, 13), (8118, 13), (8119, 13), (8120, 13), (8121, 13), (8122, 13), (8123, 13), (8124, 13), (8125, 13), (8126, 13), (8127, 13), (8128, 13), (8129, 13), (8130, 13), (8131, 13), (8132, 13), (8133, 13), (8134, 13), (8135, 13), (8136, 13), (8137, 13), (8138, 13), (8139, 13), (8140, 13), (8141, 13), (8142, 13), (8143, 13), (8144, 13), (8145, 13), (8146, 13), (8147, 13), (8148, 13), (8149, 13), (8150, 13), (8151, 13), (8152, 13), (8153, 13), (8154, 13), (8155, 13), (8156, 13), (8157, 13), (8158, 13), (8159, 13), (8160, 13), (8161, 13), (8162, 13), (8163, 13), (8164, 13), (8165, 13), (8166, 13), (8167, 13), (8168, 13), (8169, 13), (8170, 13), (8171, 13), (8172, 13), (8173, 13), (8174, 13), (8175, 13), (8176, 13), (8177, 13), (8178, 13), (8179, 13), (8180, 13), (8181, 13), (8182, 13), (8183, 13), (8184, 13), (8185, 13), (8186, 13), (8187, 13), (8188, 13), (8189, 13), (8190, 13), (8191, 13), (0, 13), (1, 13), (2, 13), (3, 13), (4, 13), (5, 13), (6, 13), (7, 13), (8, 13), (9, 13), (10, 13), (11, 13), (12, 13), (13, 13), (14, 13), (15, 13), (16, 13), (17, 13), (18, 13), (19, 13), (20, 13), (21, 13), (22, 13), (23, 13), (24, 13), (25, 13), (26, 13), (27, 13), (28, 13), (29, 13), (30, 13), (31, 13), (32, 13), (33, 13), (34, 13), (35, 13), (36, 13), (37, 13), (38, 13), (39, 13), (40, 13), (41, 13), (42, 13), (43, 13), (44, 13), (45, 13), (46, 13), (47, 13), (48, 13), (49, 13), (50, 13), (51, 13), (52, 13), (53, 13), (54, 13), (55, 13), (56, 13), (57, 13), (58, 13), (59, 13), (60, 13), (61, 13), (62, 13), (63, 13), (64, 13), (65, 13), (66, 13), (67, 13), (68, 13), (69, 13), (70, 13), (71, 13), (72, 13), (73, 13), (74, 13), (75, 13), (76, 13), (77, 13), (78, 13), (79, 13), (80, 13), (81, 13), (82, 13), (83, 13), (84, 13), (85, 13), (86, 13), (87, 13), (88, 13), (89, 13), (90, 13), (91, 13), (92, 13), (93, 13), (94, 13), (95, 13), (96, 13), (97, 13), (98, 13), (99, 13), (100, 13), (101, 13), (102, 13), (103, 13), (104, 13), (105, 13), (106, 13), (107, 13), (108, 13), (109, 13), (110, 13), (111, 13), (112, 13), (113, 13), (114, 13), (115, 13), (116, 13), (117, 13), (118, 13), (119, 13), (120, 13), (121, 13), (122, 13), (123, 13), (124, 13), (125, 13), (126, 13), (127, 13), (128, 13), (129, 13), (130, 13), (131, 13), (132, 13), (133, 13), (134, 13), (135, 13), (136, 13), (137, 13), (138, 13), (139, 13), (140, 13), (141, 13), (142, 13), (143, 13), (144, 13), (145, 13), (146, 13), (147, 13), (148, 13), (149, 13), (150, 13), (151, 13), (152, 13), (153, 13), (154, 13), (155, 13), (156, 13), (157, 13), (158, 13), (159, 13), (160, 13), (161, 13), (162, 13), (163, 13), (164, 13), (165, 13), (166, 13), (167, 13), (168, 13), (169, 13), (170, 13), (171, 13), (172, 13), (173, 13), (174, 13), (175, 13), (176, 13), (177, 13), (178, 13), (179, 13), (180, 13), (181, 13), (182, 13), (183, 13), (184, 13), (185, 13), (186, 13), (187, 13), (188, 13), (189, 13), (190, 13), (191, 13), (192, 13), (193, 13), (194, 13), (195, 13), (196, 13), (197, 13), (198, 13), (199, 13), (200, 13), (201, 13), (202, 13), (203, 13), (204, 13), (205, 13), (206, 13), (207, 13), (208, 13), (209, 13), (210, 13), (211, 13), (212, 13), (213, 13), (214, 13), (215, 13), (216, 13), (217, 13), (218, 13), (219, 13), (220, 13), (221, 13), (222, 13), (223, 13), (224, 13), (225, 13), (226, 13), (227, 13), (228, 13), (229, 13), (230, 13), (231, 13), (232, 13), (233, 13), (234, 13), (235, 13), (236, 13), (237, 13), (238, 13), (239, 13), (240, 13), (241, 13), (242, 13), (243, 13), (244, 13), (245, 13), (246, 13), (247, 13), (248, 13), (249, 13), (250, 13), (251, 13), (252, 13), (253, 13), (254, 13), (255, 13), (256, 13), (257, 13), (258, 13), (259, 13), (260, 13), (261, 13), (262, 13), (263, 13), (264, 13), (265, 13), (266, 13), (267, 13), (268, 13), (269, 13), (270, 13), (271, 13), (272, 13), (273, 13), (274, 13), (275, 13), (276, 13), (277, 13), (278, 13), (279, 13), (280, 13), (281, 13), (282, 13), (283, 13), (284, 13), (285, 13), (286, 13), (287, 13), (288, 13), (289, 13), (290, 13), (291, 13), (292, 13), (293, 13), (294, 13), (295, 13), (296, 13), (297, 13), (298, 13), (299, 13), (300, 13), (301, 13), (302, 13), (303, 13), (304, 13), (305, 13), (306, 13), (307, 13), (308, 13), (309, 13), (310, 13), (311, 13), (312, 13), (313, 13), (314, 13), (315, 13), (316, 13), (317, 13), (318, 13), (319, 13), (320, 13), (321, 13), (322, 13), (323, 13), (324, 13), (325, 13), (326, 13), (327, 13), (328, 13), (329, 13), (330, 13), (331, 13), (332, 13), (333, 13), (334, 13), (335, 13), (336, 13), (337, 13), (338, 13), (339, 13), (340, 13), (341, 13), (342, 13), (343, 13), (344, 13), (345, 13), (346, 13), (347, 13), (348, 13), (349, 13), (350, 13), (351, 13), (352, 13), (353, 13), (354, 13), (355, 13), (356, 13), (357, 13), (358, 13), (359, 13), (360, 13), (361, 13), (362, 13), (363, 13), (364, 13), (365, 13), (366, 13), (367, 13), (368, 13), (369, 13), (370, 13), (371, 13), (372, 13), (373, 13), (374, 13), (375, 13), (376, 13), (377, 13), (378, 13), (379, 13), (380, 13), (381, 13), (382, 13), (383, 13), (384, 13), (385, 13), (386, 13), (387, 13), (388, 13), (389, 13), (390, 13), (391, 13), (392, 13), (393, 13), (394, 13), (395, 13), (396, 13), (397, 13), (398, 13), (399, 13), (400, 13), (401, 13), (402, 13), (403, 13), (404, 13), (405, 13), (406, 13), (407, 13), (408, 13), (409, 13), (410, 13), (411, 13), (412, 13), (413, 13), (414, 13), (415, 13), (416, 13), (417, 13), (418, 13), (419, 13), (420, 13), (421, 13), (422, 13), (423, 13), (424, 13), (425, 13), (426, 13), (427, 13), (428, 13), (429, 13), (430, 13), (431, 13), (432, 13), (433, 13), (434, 13), (435, 13), (436, 13), (437, 13), (438, 13), (439, 13), (440, 13), (441, 13), (442, 13), (443, 13), (444, 13), (445, 13), (446, 13), (447, 13), (448, 13), (449, 13), (450, 13), (451, 13), (452, 13), (453, 13), (454, 13), (455, 13), (456, 13), (457, 13), (458, 13), (459, 13), (460, 13), (461, 13), (462, 13), (463, 13), (464, 13), (465, 13), (466, 13), (467, 13), (468, 13), (469, 13), (470, 13), (471, 13), (472, 13), (473, 13), (474, 13), (475, 13), (476, 13), (477, 13), (478, 13), (479, 13), (480, 13), (481, 13), (482, 13), (483, 13), (484, 13), (485, 13), (486, 13), (487, 13), (488, 13), (489, 13), (490, 13), (491, 13), (492, 13), (493, 13), (494, 13), (495, 13), (496, 13), (497, 13), (498, 13), (499, 13), (500, 13), (501, 13), (502, 13), (503, 13), (504, 13), (505, 13), (506, 13), (507, 13), (508, 13), (509, 13), (510, 13), (511, 13), (512, 13), (513, 13), (514, 13), (515, 13), (516, 13), (517, 13), (518, 13), (519, 13), (520, 13), (521, 13), (522, 13), (523, 13), (524, 13), (525, 13), (526, 13), (527, 13), (528, 13), (529, 13), (530, 13), (531, 13), (532, 13), (533, 13), (534, 13), (535, 13), (536, 13), (537, 13), (538, 13), (539, 13), (540, 13), (541, 13), (542, 13), (543, 13), (544, 13), (545, 13), (546, 13), (547, 13), (548, 13), (549, 13), (550, 13), (551, 13), (552, 13), (553, 13), (554, 13), (555, 13), (556, 13), (557, 13), (558, 13), (559, 13), (560, 13), (561, 13), (562, 13), (563, 13), (564, 13), (565, 13), (566, 13), (567, 13), (568, 13), (569, 13), (570, 13), (571, 13), (572, 13), (573, 13), (574, 13), (575, 13), (576, 13), (577, 13), (578, 13), (579, 13), (580, 13), (581, 13), (582, 13), (583, 13), (584, 13), (585, 13), (586, 13), (587, 13), (588, 13), (589, 13), (590, 13), (591, 13), (592, 13), (593, 13), (594, 13), (595, 13), (596, 13), (597, 13), (598, 13), (599, 13), (600, 13), (601, 13), (602, 13), (603, 13), (604, 13), (605, 13), (606, 13), (607, 13), (608, 13), (609, 13), (610, 13), (611, 13), (612, 13), (613, 13), (614, 13), (615, 13), (616, 13), (617, 13), (618, 13), (619, 13), (620, 13), (621, 13), (622, 13), (623, 13), (624, 13), (625, 13), (626, 13), (627, 13), (628, 13), (629, 13), (630, 13), (631, 13), (632, 13), (633, 13), (634, 13), (635, 13), (636, 13), (637, 13), (638, 13), (639, 13), (640, 13), (641, 13), (642, 13), (643, 13), (644, 13), (645, 13), (646, 13), (647, 13), (648, 13), (649, 13), (650, 13), (651, 13), (652, 13), (653, 13), (654, 13), (655, 13), (656, 13), (657, 13), (658, 13), (659, 13), (660, 13), (661, 13), (662, 13), (663, 13), (664, 13), (665, 13), (666, 13), (667, 13), (668, 13), (669, 13), (670, 13), (671, 13), (672, 13), (673, 13), (674, 13), (675, 13), (676, 13), (677, 13), (678, 13), (679, 13), (680, 13), (681, 13), (682, 13), (683, 13), (684, 13), (685, 13), (686, 13), (687, 13), (688, 13), (689, 13), (690, 13), (691, 13), (692, 13), (693, 13), (694, 13), (695, 13), (696, 13), (697, 13), (698, 13), (699, 13), (700, 13), (701, 13), (702, 13), (703, 13), (704, 13), (705, 13), (706, 13), (707, 13), (708, 13), (709, 13), (710, 13), (711, 13), (712, 13), (713, 13), (714, 13), (715, 13), (716, 13), (717, 13), (718, 13), (719, 13), (720, 13), (721, 13), (722, 13), (723, 13), (724, 13), (725, 13), (726, 13), (727, 13), (728, 13), (729, 13), (730, 13), (731, 13), (732, 13), (733, 13), (734, 13), (735, 13), (736, 13), (737, 13), (738, 13), (739, 13), (740, 13), (741, 13), (742, 13), (743, 13), (744, 13), (745, 13), (746, 13), (747, 13), (748, 13), (749, 13), (750, 13), (751, 13), (752, 13), (753, 13), (754, 13), (755, 13), (756, 13), (757, 13), (758, 13), (759, 13), (760, 13), (761, 13), (762, 13), (763, 13), (764, 13), (765, 13), (766, 13), (767, 13), (768, 13), (769, 13), (770, 13), (771, 13), (772, 13), (773, 13), (774, 13), (775, 13), (776, 13), (777, 13), (778, 13), (779, 13), (780, 13), (781, 13), (782, 13), (783, 13), (784, 13), (785, 13), (786, 13), (787, 13), (788, 13), (789, 13), (790, 13), (791, 13), (792, 13), (793, 13), (794, 13), (795, 13), (796, 13), (797, 13), (798, 13), (799, 13), (800, 13), (801, 13), (802, 13), (803, 13), (804, 13), (805, 13), (806, 13), (807, 13), (808, 13), (809, 13), (810, 13), (811, 13), (812, 13), (813, 13), (814, 13), (815, 13), (816, 13), (817, 13), (818, 13), (819, 13), (820, 13), (821, 13), (822, 13), (823, 13), (824, 13), (825, 13), (826, 13), (827, 13), (828, 13), (829, 13), (830, 13), (831, 13), (832, 13), (833, 13), (834, 13), (835, 13), (836, 13), (837, 13), (838, 13), (839, 13), (840, 13), (841, 13), (842, 13), (843, 13), (844, 13), (845, 13), (846, 13), (847, 13), (848, 13), (849, 13), (850, 13), (851, 13), (852, 13), (853, 13), (854, 13), (855, 13), (856, 13), (857, 13), (858, 13), (859, 13), (860, 13), (861, 13), (862, 13), (863, 13), (864, 13), (865, 13), (866, 13), (867, 13), (868, 13), (869, 13), (870, 13), (871, 13), (872, 13), (873, 13), (874, 13), (875, 13), (876, 13), (877, 13), (878, 13), (879, 13), (880, 13), (881, 13), (882, 13), (883, 13), (884, 13), (885, 13), (886, 13), (887, 13), (888, 13), (889, 13), (890, 13), (891, 13), (892, 13), (893, 13), (894, 13), (895, 13), (896, 13), (897, 13), (898, 13), (899, 13), (900, 13), (901, 13), (902, 13), (903, 13), (904, 13), (905, 13), (906, 13), (907, 13), (908, 13), (909, 13), (910, 13), (911, 13), (912, 13), (913, 13), (914, 13), (915, 13), (916, 13), (917, 13), (918, 13), (919, 13), (920, 13), (921, 13), (922, 13), (923, 13), (924, 13), (925, 13), (926, 13), (927, 13), (928, 13), (929, 13), (930, 13), (931, 13), (932, 13), (933, 13), (934, 13), (935, 13), (936, 13), (937, 13), (938, 13), (939, 13), (940, 13), (941, 13), (942, 13), (943, 13), (944, 13), (945, 13), (946, 13), (947, 13), (948, 13), (949, 13), (950, 13), (951, 13), (952, 13), (953, 13), (954, 13), (955, 13), (956, 13), (957, 13), (958, 13), (959, 13), (960, 13), (961, 13), (962, 13), (963, 13), (964, 13), (965, 13), (966, 13), (967, 13), (968, 13), (969, 13), (970, 13), (971, 13), (972, 13), (973, 13), (974, 13), (975, 13), (976, 13), (977, 13), (978, 13), (979, 13), (980, 13), (981, 13), (982, 13), (983, 13), (984, 13), (985, 13), (986, 13), (987, 13), (988, 13), (989, 13), (990, 13), (991, 13), (992, 13), (993, 13), (994, 13), (995, 13), (996, 13), (997, 13), (998, 13), (999, 13), (1000, 13), (1001, 13), (1002, 13), (1003, 13), (1004, 13), (1005, 13), (1006, 13), (1007, 13), (1008, 13), (1009, 13), (1010, 13), (1011, 13), (1012, 13), (1013, 13), (1014, 13), (1015, 13), (1016, 13), (1017, 13), (1018, 13), (1019, 13), (1020, 13), (1021, 13), (1022, 13), (1023, 13), (1024, 13), (1025, 13), (1026, 13), (1027, 13), (1028, 13), (1029, 13), (1030, 13), (1031, 13), (1032, 13), (1033, 13), (1034, 13), (1035, 13), (1036, 13), (1037, 13), (1038, 13), (1039, 13), (1040, 13), (1041, 13), (1042, 13), (1043, 13), (1044, 13), (1045, 13), (1046, 13), (1047, 13), (1048, 13), (1049, 13), (1050, 13), (1051, 13), (1052, 13), (1053, 13), (1054, 13), (1055, 13), (1056, 13), (1057, 13), (1058, 13), (1059, 13), (1060, 13), (1061, 13), (1062, 13), (1063, 13), (1064, 13), (1065, 13), (1066, 13), (1067, 13), (1068, 13), (1069, 13), (1070, 13), (1071, 13), (1072, 13), (1073, 13), (1074, 13), (1075, 13), (1076, 13), (1077, 13), (1078, 13), (1079, 13), (1080, 13), (1081, 13), (1082, 13), (1083, 13), (1084, 13), (1085, 13), (1086, 13), (1087, 13), (1088, 13), (1089, 13), (1090, 13), (1091, 13), (1092, 13), (1093, 13), (1094, 13), (1095, 13), (1096, 13), (1097, 13), (1098, 13), (1099, 13), (1100, 13), (1101, 13), (1102, 13), (1103, 13), (1104, 13), (1105, 13), (1106, 13), (1107, 13), (1108, 13), (1109, 13), (1110, 13), (1111, 13), (1112, 13), (1113, 13), (1114, 13), (1115, 13), (1116, 13), (1117, 13), (1118, 13), (1119, 13), (1120, 13), (1121, 13), (1122, 13), (1123, 13), (1124, 13), (1125, 13), (1126, 13), (1127, 13), (1128, 13), (1129, 13), (1130, 13), (1131, 13), (1132, 13), (1133, 13), (1134, 13), (1135, 13), (1136, 13), (1137, 13), (1138, 13), (1139, 13), (1140, 13), (1141, 13), (1142, 13), (1143, 13), (1144, 13), (1145, 13), (1146, 13), (1147, 13), (1148, 13), (1149, 13), (1150, 13), (1151, 13), (1152, 13), (1153, 13), (1154, 13), (1155, 13), (1156, 13), (1157, 13), (1158, 13), (1159, 13), (1160, 13), (1161, 13), (1162, 13), (1163, 13), (1164, 13), (1165, 13), (1166, 13), (1167, 13), (1168, 13), (1169, 13), (1170, 13), (1171, 13), (1172, 13), (1173, 13), (1174, 13), (1175, 13), (1176, 13), (1177, 13), (1178, 13), (1179, 13), (1180, 13), (1181, 13), (1182, 13), (1183, 13), (1184, 13), (1185, 13), (1186, 13), (1187, 13), (1188, 13), (1189, 13), (1190, 13), (1191, 13), (1192, 13), (1193, 13), (1194, 13), (1195, 13), (1196, 13), (1197, 13), (1198, 13), (1199, 13), (1200, 13), (1201, 13), (1202, 13), (1203, 13), (1204, 13), (1205, 13), (1206, 13), (1207, 13), (1208, 13), (1209, 13), (1210, 13), (1211, 13), (1212, 13), (1213, 13), (1214, 13), (1215, 13), (1216, 13), (1217, 13), (1218, 13), (1219, 13), (1220, 13), (1221, 13), (1222, 13), (1223, 13), (1224, 13), (1225, 13), (1226, 13), (1227, 13), (1228, 13), (1229, 13), (1230, 13), (1231, 13), (1232, 13), (1233, 13), (1234, 13), (1235, 13), (1236, 13), (1237, 13), (1238, 13), (1239, 13), (1240, 13), (1241, 13), (1242, 13), (1243, 13), (1244, 13), (1245, 13), (1246, 13), (1247, 13), (1248, 13), (1249, 13), (1250, 13), (1251, 13), (1252, 13), (1253, 13), (1254, 13), (1255, 13), (1256, 13), (1257, 13), (1258, 13), (1259, 13), (1260, 13), (1261, 13), (1262, 13), (1263, 13), (1264, 13), (1265, 13), (1266, 13), (1267, 13), (1268, 13), (1269, 13), (1270, 13), (1271, 13), (1272, 13), (1273, 13), (1274, 13), (1275, 13), (1276, 13), (1277, 13), (1278, 13), (1279, 13), (1280, 13), (1281, 13), (1282, 13), (1283, 13), (1284, 13), (1285, 13), (1286, 13), (1287, 13), (1288, 13), (1289, 13), (1290, 13), (1291, 13), (1292, 13), (1293, 13), (1294, 13), (1295, 13), (1296, 13), (1297, 13), (1298, 13), (1299, 13), (1300, 13), (1301, 13), (1302, 13), (1303, 13), (1304, 13), (1305, 13), (1306, 13), (1307, 13), (1308, 13), (1309, 13), (1310, 13), (1311, 13), (1312, 13), (1313, 13), (1314, 13), (1315, 13), (1316, 13), (1317, 13), (1318, 13), (1319, 13), (1320, 13), (1321, 13), (1322, 13), (1323, 13), (1324, 13), (1325, 13), (1326, 13), (1327, 13), (1328, 13), (1329, 13), (1330, 13), (1331, 13), (1332, 13), (1333, 13), (1334, 13), (1335, 13), (1336, 13), (1337, 13), (1338, 13), (1339, 13), (1340, 13), (1341, 13), (1342, 13), (1343, 13), (1344, 13), (1345, 13), (1346, 13), (1347, 13), (1348, 13), (1349, 13), (1350, 13), (1351, 13), (1352, 13), (1353, 13), (1354, 13), (1355, 13), (1356, 13), (1357, 13), (1358, 13), (1359, 13), (1360, 13), (1361, 13), (1362, 13), (1363, 13), (1364, 13), (1365, 13), (1366, 13), (1367, 13), (1368, 13), (1369, 13), (1370, 13), (1371, 13), (1372, 13), (1373, 13), (1374, 13), (1375, 13), (1376, 13), (1377, 13), (1378, 13), (1379, 13), (1380, 13), (1381, 13), (1382, 13), (1383, 13), (1384, 13), (1385, 13), (1386, 13), (1387, 13), (1388, 13), (1389, 13), (1390, 13), (1391, 13), (1392, 13), (1393, 13), (1394, 13), (1395, 13), (1396, 13), (1397, 13), (1398, 13), (1399, 13), (1400, 13), (1401, 13), (1402, 13), (1403, 13), (1404, 13), (1405, 13), (1406, 13), (1407, 13), (1408, 13), (1409, 13), (1410, 13), (1411, 13), (1412, 13), (1413, 13), (1414, 13), (1415, 13), (1416, 13), (1417, 13), (1418, 13), (1419, 13), (1420, 13), (1421, 13), (1422, 13), (1423, 13), (1424, 13), (1425, 13), (1426, 13), (1427, 13), (1428, 13), (1429, 13), (1430, 13), (1431, 13), (1432, 13), (1433, 13), (1434, 13), (1435, 13), (1436, 13), (1437, 13), (1438, 13), (1439, 13), (1440, 13), (1441, 13), (1442, 13), (1443, 13), (1444, 13), (1445, 13), (1446, 13), (1447, 13), (1448, 13), (1449, 13), (1450, 13), (1451, 13), (1452, 13), (1453, 13), (1454, 13), (1455, 13), (1456, 13), (1457, 13), (1458, 13), (1459, 13), (1460, 13), (1461, 13), (1462, 13), (1463, 13), (1464, 13), (1465, 13), (1466, 13), (1467, 13), (1468, 13), (1469, 13), (1470, 13), (1471, 13), (1472, 13), (1473, 13), (1474, 13), (1475, 13), (1476, 13), (1477, 13), (1478, 13), (1479, 13), (1480, 13), (1481, 13), (1482, 13), (1483, 13), (1484, 13), (1485, 13), (1486, 13), (1487, 13), (1488, 13), (1489, 13), (1490, 13), (1491, 13), (1492, 13), (1493, 13), (1494, 13), (1495, 13), (1496, 13), (1497, 13), (1498, 13), (1499, 13), (1500, 13), (1501, 13), (1502, 13), (1503, 13), (1504, 13), (1505, 13), (1506, 13), (1507, 13), (1508, 13), (1509, 13), (1510, 13), (1511, 13), (1512, 13), (1513, 13), (1514, 13), (1515, 13), (1516, 13), (1517, 13), (1518, 13), (1519, 13), (1520, 13), (1521, 13), (1522, 13), (1523, 13), (1524, 13), (1525, 13), (1526, 13), (1527, 13), (1528, 13), (1529, 13), (1530, 13), (1531, 13), (1532, 13), (1533, 13), (1534, 13), (1535, 13), (1536, 13), (1537, 13), (1538, 13), (1539, 13), (1540, 13), (1541, 13), (1542, 13), (1543, 13), (1544, 13), (1545, 13), (1546, 13), (1547, 13), (1548, 13), (1549, 13), (1550, 13), (1551, 13), (1552, 13), (1553, 13), (1554, 13), (1555, 13), (1556, 13), (1557, 13), (1558, 13), (1559, 13), (1560, 13), (1561, 13), (1562, 13), (1563, 13), (1564, 13), (1565, 13), (1566, 13), (1567, 13), (1568, 13), (1569, 13), (1570, 13), (1571, 13), (1572, 13), (1573, 13), (1574, 13), (1575, 13), (1576, 13), (1577, 13), (1578, 13), (1579, 13), (1580, 13), (1581, 13), (1582, 13), (1583, 13), (1584, 13), (1585, 13), (1586, 13), (1587, 13), (1588, 13), (1589, 13), (1590, 13), (1591, 13), (1592, 13), (1593, 13), (1594, 13), (1595, 13), (1596, 13), (1597, 13), (1598, 13), (1599, 13), (1600, 13), (1601, 13), (1602, 13), (1603, 13), (1604, 13), (1605, 13), (1606, 13), (1607, 13), (1608, 13), (1609, 13), (1610, 13), (1611, 13), (1612, 13), (1613, 13), (1614, 13), (1615, 13), (1616, 13), (1617, 13), (1618, 13), (1619, 13), (1620, 13), (1621, 13), (1622, 13), (1623, 13), (1624, 13), (1625, 13), (1626, 13), (1627, 13), (1628, 13), (1629, 13), (1630, 13), (1631, 13), (1632, 13), (1633, 13), (1634, 13), (1635, 13), (1636, 13), (1637, 13), (1638, 13), (1639, 13), (1640, 13), (1641, 13), (1642, 13), (1643, 13), (1644, 13), (1645, 13), (1646, 13), (1647, 13), (1648, 13), (1649, 13), (1650, 13), (1651, 13), (1652, 13), (1653, 13), (1654, 13), (1655, 13), (1656, 13), (1657, 13), (1658, 13), (1659, 13), (1660, 13), (1661, 13), (1662, 13), (1663, 13), (1664, 13), (1665, 13), (1666, 13), (1667, 13), (1668, 13), (1669, 13), (1670, 13), (1671, 13), (1672, 13), (1673, 13), (1674, 13), (1675, 13), (1676, 13), (1677, 13), (1678, 13), (1679, 13), (1680, 13), (1681, 13), (1682, 13), (1683, 13), (1684, 13), (1685, 13), (1686, 13), (1687, 13), (1688, 13), (1689, 13), (1690, 13), (1691, 13), (1692, 13), (1693, 13), (1694, 13), (1695, 13), (1696, 13), (1697, 13), (1698, 13), (1699, 13), (1700, 13), (1701, 13), (1702, 13), (1703, 13), (1704, 13), (1705, 13), (1706, 13), (1707, 13), (1708, 13), (1709, 13), (1710, 13), (1711, 13), (1712, 13), (1713, 13), (1714, 13), (1715, 13), (1716, 13), (1717, 13), (1718, 13), (1719, 13), (1720, 13), (1721, 13), (1722, 13), (1723, 13), (1724, 13), (1725, 13), (1726, 13), (1727, 13), (1728, 13), (1729, 13), (1730, 13), (1731, 13), (1732, 13), (1733, 13), (1734, 13), (1735, 13), (1736, 13), (1737, 13), (1738, 13), (1739, 13), (1740, 13), (1741, 13), (1742, 13), (1743, 13), (1744, 13), (1745, 13), (1746, 13), (1747, 13), (1748, 13), (1749, 13), (1750, 13), (1751, 13), (1752, 13), (1753, 13), (1754, 13), (1755, 13), (1756, 13), (1757, 13), (1758, 13), (1759, 13), (1760, 13), (1761, 13), (1762, 13), (1763, 13), (1764, 13), (1765, 13), (1766, 13), (1767, 13), (1768, 13), (1769, 13), (1770, 13), (1771, 13), (1772, 13), (1773, 13), (1774, 13), (1775, 13), (1776, 13), (1777, 13), (1778, 13), (1779, 13), (1780, 13), (1781, 13), (1782, 13), (1783, 13), (1784, 13), (1785, 13), (1786, 13), (1787, 13), (1788, 13), (1789, 13), (1790, 13), (1791, 13), (1792, 13), (1793, 13), (1794, 13), (1795, 13), (1796, 13), (1797, 13), (1798, 13), (1799, 13), (1800, 13), (1801, 13), (1802, 13), (1803, 13), (1804, 13), (1805, 13), (1806, 13), (1807, 13), (1808, 13), (1809, 13), (1810, 13), (1811, 13), (1812, 13), (1813, 13), (1814, 13), (1815, 13), (1816, 13), (1817, 13), (1818, 13), (1819, 13), (1820, 13), (1821, 13), (1822, 13), (1823, 13), (1824, 13), (1825, 13), (1826, 13), (1827, 13), (1828, 13), (1829, 13), (1830, 13), (1831, 13), (1832, 13), (1833, 13), (1834, 13), (1835, 13), (1836, 13), (1837, 13), (1838, 13), (1839, 13), (1840, 13), (1841, 13), (1842, 13), (1843, 13), (1844, 13), (1845, 13), (1846, 13), (1847, 13), (1848, 13), (1849, 13), (1850, 13), (1851, 13), (1852, 13), (1853, 13), (1854, 13), (1855, 13), (1856, 13), (1857, 13), (1858, 13), (1859, 13), (1860, 13), (1861, 13), (1862, 13), (1863, 13), (1864, 13), (1865, 13), (1866, 13), (1867, 13), (1868, 13), (1869, 13), (1870, 13), (1871, 13), (1872, 13), (1873, 13), (1874, 13), (1875, 13), (1876, 13), (1877, 13), (1878, 13), (1879, 13), (1880, 13), (1881, 13), (1882, 13), (1883, 13), (1884, 13), (1885, 13), (1886, 13), (1887, 13), (1888, 13), (1889, 13), (1890, 13), (1891, 13), (1892, 13), (1893, 13), (1894, 13), (1895, 13), (1896, 13), (1897, 13), (1898, 13), (1899, 13), (1900, 13), (1901, 13), (1902, 13), (1903, 13), (1904, 13), (1905, 13), (1906, 13), (1907, 13), (1908, 13), (1909, 13), (1910, 13), (1911, 13), (1912, 13), (1913, 13), (1914, 13), (1915, 13), (1916, 13), (1917, 13), (1918, 13), (1919, 13), (1920, 13), (1921, 13), (1922, 13), (1923, 13), (1924, 13), (1925, 13), (1926, 13), (1927, 13), (1928, 13), (1929, 13), (1930, 13), (1931, 13), (1932, 13), (1933, 13), (1934, 13), (1935, 13), (1936, 13), (1937, 13), (1938, 13), (1939, 13), (1940, 13), (1941, 13), (1942, 13), (1943, 13), (1944, 13), (1945, 13), (1946, 13), (1947, 13), (1948, 13), (1949, 13), (1950, 13), (1951, 13), (1952, 13), (1953, 13), (1954, 13), (1955, 13), (1956, 13), (1957, 13), (1958, 13), (1959, 13), (1960, 13), (1961, 13), (1962, 13), (1963, 13), (1964, 13), (1965, 13), (1966, 13), (1967, 13), (1968, 13), (1969, 13), (1970, 13), (1971, 13), (1972, 13), (1973, 13), (1974, 13), (1975, 13), (1976, 13), (1977, 13), (1978, 13), (1979, 13), (1980, 13), (1981, 13), (1982, 13), (1983, 13), (1984, 13), (1985, 13), (1986, 13), (1987, 13), (1988, 13), (1989, 13), (1990, 13), (1991, 13), (1992, 13), (1993, 13), (1994, 13), (1995, 13), (1996, 13), (1997, 13), (1998, 13), (1999, 13), (2000, 13), (2001, 13), (2002, 13), (2003, 13), (2004, 13), (2005, 13), (2006, 13), (2007, 13), (2008, 13), (2009, 13), (2010, 13), (2011, 13), (2012, 13), (2013, 13), (2014, 13), (2015, 13), (2016, 13), (2017, 13), (2018, 13), (2019, 13), (2020, 13), (2021, 13), (2022, 13), (2023, 13), (2024, 13), (2025, 13), (2026, 13), (2027, 13), (2028, 13), (2029, 13), (2030, 13), (2031, 13), (2032, 13), (2033, 13), (2034, 13), (2035, 13), (2036, 13), (2037, 13), (2038, 13), (2039, 13), (2040, 13), (2041, 13), (2042, 13), (2043, 13), (2044, 13), (2045, 13), (2046, 13), (2047, 13), (2048, 13), (2049, 13), (2050, 13), (2051, 13), (2052, 13), (2053, 13), (2054, 13), (2055, 13), (2056, 13), (2057, 13), (2058, 13), (2059, 13), (2060, 13), (2061, 13), (2062, 13), (2063, 13), (2064, 13), (2065, 13), (2066, 13), (2067, 13), (2068, 13), (2069, 13), (2070, 13), (2071, 13), (2072, 13), (2073, 13), (2074, 13), (2075, 13), (2076, 13), (2077, 13), (2078, 13), (2079, 13), (2080, 13), (2081, 13), (2082, 13), (2083, 13), (2084, 13), (2085, 13), (2086, 13), (2087, 13), (2088, 13), (2089, 13), (2090, 13), (2091, 13), (2092, 13), (2093, 13), (2094, 13), (2095, 13), (2096, 13), (2097, 13), (2098, 13), (2099, 13), (2100, 13), (2101, 13), (2102, 13), (2103, 13), (2104, 13), (2105, 13), (2106, 13), (2107, 13), (2108, 13), (2109, 13), (2110, 13), (2111, 13), (2112, 13), (2113, 13), (2114, 13), (2115, 13), (2116, 13), (2117, 13), (2118, 13), (2119, 13), (2120, 13), (2121, 13), (2122, 13), (2123, 13), (2124, 13), (2125, 13), (2126, 13), (2127, 13), (2128, 13), (2129, 13), (2130, 13), (2131, 13), (2132, 13), (2133, 13), (2134, 13), (2135, 13), (2136, 13), (2137, 13), (2138, 13), (2139, 13), (2140, 13), (2141, 13), (2142, 13), (2143, 13), (2144, 13), (2145, 13), (2146, 13), (2147, 13), (2148, 13), (2149, 13), (2150, 13), (2151, 13), (2152, 13), (2153, 13), (2154, 13), (2155, 13), (2156, 13), (2157, 13), (2158, 13), (2159, 13), (2160, 13), (2161, 13), (2162, 13), (2163, 13), (2164, 13), (2165, 13), (2166, 13), (2167, 13), (2168, 13), (2169, 13), (2170, 13), (2171, 13), (2172, 13), (2173, 13), (2174, 13), (2175, 13), (2176, 13), (2177, 13), (2178, 13), (2179, 13), (2180, 13), (2181, 13), (2182, 13), (2183, 13), (2184, 13), (2185, 13), (2186, 13), (2187, 13), (2188, 13), (2189, 13), (2190, 13), (2191, 13), (2192, 13), (2193, 13), (2194, 13), (2195, 13), (2196, 13), (2197, 13), (2198, 13), (2199, 13), (2200, 13), (2201, 13), (2202, 13), (2203, 13), (2204, 13), (2205, 13), (2206, 13), (2207, 13), (2208, 13), (2209, 13), (2210, 13), (2211, 13), (2212, 13), (2213, 13), (2214, 13), (2215, 13), (2216, 13), (2217, 13), (2218, 13), (2219, 13), (2220, 13), (2221, 13), (2222, 13), (2223, 13), (2224, 13), (2225, 13), (2226, 13), (2227, 13), (2228, 13), (2229, 13), (2230, 13), (2231, 13), (2232, 13), (2233, 13), (2234, 13), (2235, 13), (2236, 13), (2237, 13), (2238, 13), (2239, 13), (2240, 13), (2241, 13), (2242, 13), (2243, 13), (2244, 13), (2245, 13), (2246, 13), (2247, 13), (2248, 13), (2249, 13), (2250, 13), (2251, 13), (2252, 13), (2253, 13), (2254, 13), (2255, 13), (2256, 13), (2257, 13), (2258, 13), (2259, 13), (2260, 13), (2261, 13), (2262, 13), (2263, 13), (2264, 13), (2265, 13), (2266, 13), (2267, 13), (2268, 13), (2269, 13), (2270, 13), (2271, 13), (2272, 13), (2273, 13), (2274, 13), (2275, 13), (2276, 13), (2277, 13), (2278, 13), (2279, 13), (2280, 13), (2281, 13), (2282, 13), (2283, 13), (2284, 13), (2285, 13), (2286, 13), (2287, 13), (2288, 13), (2289, 13), (2290, 13), (2291, 13), (2292, 13), (2293, 13), (2294, 13), (2295, 13), (2296, 13), (2297, 13), (2298, 13), (2299, 13), (2300, 13), (2301, 13), (2302, 13), (2303, 13), (2304, 13), (2305, 13), (2306, 13), (2307, 13), (2308, 13), (2309, 13), (2310, 13), (2311, 13), (2312, 13), (2313, 13), (2314, 13), (2315, 13), (2316, 13), (2317, 13), (2318, 13), (2319, 13), (2320, 13), (2321, 13), (2322, 13), (2323, 13), (2324, 13), (2325, 13), (2326, 13), (2327, 13), (2328, 13), (2329, 13), (2330, 13), (2331, 13), (2332, 13), (2333, 13), (2334, 13), (2335, 13), (2336, 13), (2337, 13), (2338, 13), (2339, 13), (2340, 13), (2341, 13), (2342, 13), (2343, 13), (2344, 13), (2345, 13), (2346, 13), (2347, 13), (2348, 13), (2349, 13), (2350, 13), (2351, 13), (2352, 13), (2353, 13), (2354, 13), (2355, 13), (2356, 13), (2357, 13), (2358, 13), (2359, 13), (2360, 13), (2361, 13), (2362, 13), (2363, 13), (2364, 13), (2365, 13), (2366, 13), (2367, 13), (2368, 13), (2369, 13), (2370, 13), (2371, 13), (2372, 13), (2373, 13), (2374, 13), (2375, 13), (2376, 13), (2377, 13), (2378, 13), (2379, 13), (2380, 13), (2381, 13), (2382, 13), (2383, 13), (2384, 13), (2385, 13), (2386, 13), (2387, 13), (2388, 13), (2389, 13), (2390, 13), (2391, 13), (2392, 13), (2393, 13), (2394, 13), (2395, 13), (2396, 13), (2397, 13), (2398, 13), (2399, 13), (2400, 13), (2401, 13), (2402, 13), (2403, 13), (2404, 13), (2405, 13), (2406, 13), (2407, 13), (2408, 13), (2409, 13), (2410, 13), (2411, 13), (2412, 13), (2413, 13), (2414, 13), (2415, 13), (2416, 13), (2417, 13), (2418, 13), (2419, 13), (2420, 13), (2421, 13), (2422, 13), (2423, 13), (2424, 13), (2425, 13), (2426, 13), (2427, 13), (2428, 13), (2429, 13), (2430, 13), (2431, 13), (2432, 13), (2433, 13), (2434, 13), (2435, 13), (2436, 13), (2437, 13), (2438, 13), (2439, 13), (2440, 13), (2441, 13), (2442, 13), (2443, 13), (2444, 13), (2445, 13), (2446, 13), (2447, 13), (2448, 13), (2449, 13), (2450, 13), (2451, 13), (2452, 13), (2453, 13), (2454, 13), (2455, 13), (2456, 13), (2457, 13), (2458, 13), (2459, 13), (2460, 13), (2461, 13), (2462, 13), (2463, 13), (2464, 13), (2465, 13), (2466, 13), (2467, 13), (2468, 13), (2469, 13), (2470, 13), (2471, 13), (2472, 13), (2473, 13), (2474, 13), (2475, 13), (2476, 13), (2477, 13), (2478, 13), (2479, 13), (2480, 13), (2481, 13), (2482, 13), (2483, 13), (2484, 13), (2485, 13), (2486, 13), (2487, 13), (2488, 13), (2489, 13), (2490, 13), (2491, 13), (2492, 13), (2493, 13), (2494, 13), (2495, 13), (2496, 13), (2497, 13), (2498, 13), (2499, 13), (2500, 13), (2501, 13), (2502, 13), (2503, 13), (2504, 13), (2505, 13), (2506, 13), (2507, 13), (2508, 13), (2509, 13), (2510, 13), (2511, 13), (2512, 13), (2513, 13), (2514, 13), (2515, 13), (2516, 13), (2517, 13), (2518, 13), (2519, 13), (2520, 13), (2521, 13), (2522, 13), (2523, 13), (2524, 13), (2525, 13), (2526, 13), (2527, 13), (2528, 13), (2529, 13), (2530, 13), (2531, 13), (2532, 13), (2533, 13), (2534, 13), (2535, 13), (2536, 13), (2537, 13), (2538, 13), (2539, 13), (2540, 13), (2541, 13), (2542, 13), (2543, 13), (2544, 13), (2545, 13), (2546, 13), (2547, 13), (2548, 13), (2549, 13), (2550, 13), (2551, 13), (2552, 13), (2553, 13), (2554, 13), (2555, 13), (2556, 13), (2557, 13), (2558, 13), (2559, 13), (2560, 13), (2561, 13), (2562, 13), (2563, 13), (2564, 13), (2565, 13), (2566, 13), (2567, 13), (2568, 13), (2569, 13), (2570, 13), (2571, 13), (2572, 13), (2573, 13), (2574, 13), (2575, 13), (2576, 13), (2577, 13), (2578, 13), (2579, 13), (2580, 13), (2581, 13), (2582, 13), (2583, 13), (2584, 13), (2585, 13), (2586, 13), (2587, 13), (2588, 13), (2589, 13), (2590, 13), (2591, 13), (2592, 13), (2593, 13), (2594, 13), (2595, 13), (2596, 13), (2597, 13), (2598, 13), (2599, 13), (2600, 13), (2601, 13), (2602, 13), (2603, 13), (2604, 13), (2605, 13), (2606, 13), (2607, 13), (2608, 13), (2609, 13), (2610, 13), (2611, 13), (2612, 13), (2613, 13), (2614, 13), (2615, 13), (2616, 13), (2617, 13), (2618, 13), (2619, 13), (2620, 13), (2621, 13), (2622, 13), (2623, 13), (2624, 13), (2625, 13), (2626, 13), (2627, 13), (2628, 13), (2629, 13), (2630, 13), (2631, 13), (2632, 13), (2633, 13), (2634, 13), (2635, 13), (2636, 13), (2637, 13), (2638, 13), (2639, 13), (2640, 13), (2641, 13), (2642, 13), (2643, 13), (2644, 13), (2645, 13), (2646, 13), (2647, 13), (2648, 13), (2649, 13), (2650, 13), (2651, 13), (2652, 13), (2653, 13), (2654, 13), (2655, 13), (2656, 13), (2657, 13), (2658, 13), (2659, 13), (2660, 13), (2661, 13), (2662, 13), (2663, 13), (2664, 13), (2665, 13), (2666, 13), (2667, 13), (2668, 13), (2669, 13), (2670, 13), (2671, 13), (2672, 13), (2673, 13), (2674, 13), (2675, 13), (2676, 13), (2677, 13), (2678, 13), (2679, 13), (2680, 13), (2681, 13), (2682, 13), (2683, 13), (2684, 13), (2685, 13), (2686, 13), (2687, 13), (2688, 13), (2689, 13), (2690, 13), (2691, 13), (2692, 13), (2693, 13), (2694, 13), (2695, 13), (2696, 13), (2697, 13), (2698, 13), (2699, 13), (2700, 13), (2701, 13), (2702, 13), (2703, 13), (2704, 13), (2705, 13), (2706, 13), (2707, 13), (2708, 13), (2709, 13), (2710, 13), (2711, 13), (2712, 13), (2713, 13), (2714, 13), (2715, 13), (2716, 13), (2717, 13), (2718, 13), (2719, 13), (2720, 13), (2721, 13), (2722, 13), (2723, 13), (2724, 13), (2725, 13), (2726, 13), (2727, 13), (2728, 13), (2729, 13), (2730, 13), (2731, 13), (2732, 13), (2733, 13), (2734, 13), (2735, 13), (2736, 13), (2737, 13), (2738, 13), (2739, 13), (2740, 13), (2741, 13), (2742, 13), (2743, 13), (2744, 13), (2745, 13), (2746, 13), (2747, 13), (2748, 13), (2749, 13), (2750, 13), (2751, 13), (2752, 13), (2753, 13), (2754, 13), (2755, 13), (2756, 13), (2757, 13), (2758, 13), (2759, 13), (2760, 13), (2761, 13), (2762, 13), (2763, 13), (2764, 13), (2765, 13), (2766, 13), (2767, 13), (2768, 13), (2769, 13), (2770, 13), (2771, 13), (2772, 13), (2773, 13), (2774, 13), (2775, 13), (2776, 13), (2777, 13), (2778, 13), (2779, 13), (2780, 13), (2781, 13), (2782, 13), (2783, 13), (2784, 13), (2785, 13), (2786, 13), (2787, 13), (2788, 13), (2789, 13), (2790, 13), (2791, 13), (2792, 13), (2793, 13), (2794, 13), (2795, 13), (2796, 13), (2797, 13), (2798, 13), (2799, 13), (2800, 13), (2801, 13), (2802, 13), (2803, 13), (2804, 13), (2805, 13), (2806, 13), (2807, 13), (2808, 13), (2809, 13), (2810, 13), (2811, 13), (2812, 13), (2813, 13), (2814, 13), (2815, 13), (2816, 13), (2817, 13), (2818, 13), (2819, 13), (2820, 13), (2821, 13), (2822, 13), (2823, 13), (2824, 13), (2825, 13), (2826, 13), (2827, 13), (2828, 13), (2829, 13), (2830, 13), (2831, 13), (2832, 13), (2833, 13), (2834, 13), (2835, 13), (2836, 13), (2837, 13), (2838, 13), (2839, 13), (2840, 13), (2841, 13), (2842, 13), (2843, 13), (2844, 13), (2845, 13), (2846, 13), (2847, 13), (2848, 13), (2849, 13), (2850, 13), (2851, 13), (2852, 13), (2853, 13), (2854, 13), (2855, 13), (2856, 13), (2857, 13), (2858, 13), (2859, 13), (2860, 13), (2861, 13), (2862, 13), (2863, 13), (2864, 13), (2865, 13), (2866, 13), (2867, 13), (2868, 13), (2869, 13), (2870, 13), (2871, 13), (2872, 13), (2873, 13), (2874, 13), (2875, 13), (2876, 13), (2877, 13), (2878, 13), (2879, 13), (2880, 13), (2881, 13), (2882, 13), (2883, 13), (2884, 13), (2885, 13), (2886, 13), (2887, 13), (2888, 13), (2889, 13), (2890, 13), (2891, 13), (2892, 13), (2893, 13), (2894, 13), (2895, 13), (2896, 13), (2897, 13), (2898, 13), (2899, 13), (2900, 13), (2901, 13), (2902, 13), (2903, 13), (2904, 13), (2905, 13), (2906, 13), (2907, 13), (2908, 13), (2909, 13), (2910, 13), (2911, 13), (2912, 13), (2913, 13), (2914, 13), (2915, 13), (2916, 13), (2917, 13), (2918, 13), (2919, 13), (2920, 13), (2921, 13), (2922, 13), (2923, 13), (2924, 13), (2925, 13), (2926, 13), (2927, 13), (2928, 13), (2929, 13), (2930, 13), (2931, 13), (2932, 13), (2933, 13), (2934, 13), (2935, 13), (2936, 13), (2937, 13), (2938, 13), (2939, 13), (2940, 13), (2941, 13), (2942, 13), (2943, 13), (2944, 13), (2945, 13), (2946, 13), (2947, 13), (2948, 13), (2949, 13), (2950, 13), (2951, 13), (2952, 13), (2953, 13), (2954, 13), (2955, 13), (2956, 13), (2957, 13), (2958, 13), (2959, 13), (2960, 13), (2961, 13), (2962, 13), (2963, 13), (2964, 13), (2965, 13), (2966, 13), (2967, 13), (2968, 13), (2969, 13), (2970, 13), (2971, 13), (2972, 13), (2973, 13), (2974, 13), (2975, 13), (2976, 13), (2977, 13), (2978, 13), (2979, 13), (2980, 13), (2981, 13), (2982, 13), (2983, 13), (2984, 13), (2985, 13), (2986, 13), (2987, 13), (2988, 13), (2989, 13), (2990, 13), (2991, 13), (2992, 13), (2993, 13), (2994, 13), (2995, 13), (2996, 13), (2997, 13), (2998, 13), (2999, 13), (3000, 13), (3001, 13), (3002, 13), (3003, 13), (3004, 13), (3005, 13), (3006, 13), (3007, 13), (3008, 13), (3009, 13), (3010, 13), (3011, 13), (3012, 13), (3013, 13), (3014, 13), (3015, 13), (3016, 13), (3017, 13), (3018, 13), (3019, 13), (3020, 13), (3021, 13), (3022, 13), (3023, 13), (3024, 13), (3025, 13), (3026, 13), (3027, 13), (3028, 13), (3029, 13), (3030, 13), (3031, 13), (3032, 13), (3033, 13), (3034, 13), (3035, 13), (3036, 13), (3037, 13), (3038, 13), (3039, 13), (3040, 13), (3041, 13), (3042, 13), (3043, 13), (3044, 13), (3045, 13), (3046, 13), (3047, 13), (3048, 13), (3049, 13), (3050, 13), (3051, 13), (3052, 13), (3053, 13), (3054, 13), (3055, 13), (3056, 13), (3057, 13), (3058, 13), (3059, 13), (3060, 13), (3061, 13), (3062, 13), (3063, 13), (3064, 13), (3065, 13), (3066, 13), (3067, 13), (3068, 13), (3069, 13), (3070, 13), (3071, 13), (3072, 13), (3073, 13), (3074, 13), (3075, 13), (3076, 13), (3077, 13), (3078, 13), (3079, 13), (3080, 13), (3081, 13), (3082, 13), (3083, 13), (3084, 13), (3085, 13), (3086, 13), (3087, 13), (3088, 13), (3089, 13), (3090, 13), (3091, 13), (3092, 13), (3093, 13), (3094, 13), (3095, 13), (3096, 13), (3097, 13), (3098, 13), (3099, 13), (3100, 13), (3101, 13), (3102, 13), (3103, 13), (3104, 13), (3105, 13), (3106, 13), (3107, 13), (3108, 13), (3109, 13), (3110, 13), (3111, 13), (3112, 13), (3113, 13), (3114, 13), (3115, 13), (3116, 13), (3117, 13), (3118, 13), (3119, 13), (3120, 13), (3121, 13), (3122, 13), (3123, 13), (3124, 13), (3125, 13), (3126, 13), (3127, 13), (3128, 13), (3129, 13), (3130, 13), (3131, 13), (3132, 13), (3133, 13), (3134, 13), (3135, 13), (3136, 13), (3137, 13), (3138, 13), (3139, 13), (3140, 13), (3141, 13), (3142, 13), (3143, 13), (3144, 13), (3145, 13), (3146, 13), (3147, 13), (3148, 13), (3149, 13), (3150, 13), (3151, 13), (3152, 13), (3153, 13), (3154, 13), (3155, 13), (3156, 13), (3157, 13), (3158, 13), (3159, 13), (3160, 13), (3161, 13), (3162, 13), (3163, 13), (3164, 13), (3165, 13), (3166, 13), (3167, 13), (3168, 13), (3169, 13), (3170, 13), (3171, 13), (3172, 13), (3173, 13), (3174, 13), (3175, 13), (3176, 13), (3177, 13), (3178, 13), (3179, 13), (3180, 13), (3181, 13), (3182, 13), (3183, 13), (3184, 13), (3185, 13), (3186, 13), (3187, 13), (3188, 13), (3189, 13), (3190, 13), (3191, 13), (3192, 13), (3193, 13), (3194, 13), (3195, 13), (3196, 13), (3197, 13), (3198, 13), (3199, 13), (3200, 13), (3201, 13), (3202, 13), (3203, 13), (3204, 13), (3205, 13), (3206, 13), (3207, 13), (3208, 13), (3209, 13), (3210, 13), (3211, 13), (3212, 13), (3213, 13), (3214, 13), (3215, 13), (3216, 13), (3217, 13), (3218, 13), (3219, 13), (3220, 13), (3221, 13), (3222, 13), (3223, 13), (3224, 13), (3225, 13), (3226, 13), (3227, 13), (3228, 13), (3229, 13), (3230, 13), (3231, 13), (3232, 13), (3233, 13), (3234, 13), (3235, 13), (3236, 13), (3237, 13), (3238, 13), (3239, 13), (3240, 13), (3241, 13), (3242, 13), (3243, 13), (3244, 13), (3245, 13), (3246, 13), (3247, 13), (3248, 13), (3249, 13), (3250, 13), (3251, 13), (3252, 13), (3253, 13), (3254, 13), (3255, 13), (3256, 13), (3257, 13), (3258, 13), (3259, 13), (3260, 13), (3261, 13), (3262, 13), (3263, 13), (3264, 13), (3265, 13), (3266, 13), (3267, 13), (3268, 13), (3269, 13), (3270, 13), (3271, 13), (3272, 13), (3273, 13), (3274, 13), (3275, 13), (3276, 13), (3277, 13), (3278, 13), (3279, 13), (3280, 13), (3281, 13), (3282, 13), (3283, 13), (3284, 13), (3285, 13), (3286, 13), (3287, 13), (3288, 13), (3289, 13), (3290, 13), (3291, 13), (3292, 13), (3293, 13), (3294, 13), (3295, 13), (3296, 13), (3297, 13), (3298, 13), (3299, 13), (3300, 13), (3301, 13), (3302, 13), (3303, 13), (3304, 13), (3305, 13), (3306, 13), (3307, 13), (3308, 13), (3309, 13), (3310, 13), (3311, 13), (3312, 13), (3313, 13), (3314, 13), (3315, 13), (3316, 13), (3317, 13), (3318, 13), (3319, 13), (3320, 13), (3321, 13), (3322, 13), (3323, 13), (3324, 13), (3325, 13), (3326, 13), (3327, 13), (3328, 13), (3329, 13), (3330, 13), (3331, 13), (3332, 13), (3333, 13), (3334, 13), (3335, 13), (3336, 13), (3337, 13), (3338, 13), (3339, 13), (3340, 13), (3341, 13), (3342, 13), (3343, 13), (3344, 13), (3345, 13), (3346, 13), (3347, 13), (3348, 13), (3349, 13), (3350, 13), (3351, 13), (3352, 13), (3353, 13), (3354, 13), (3355, 13), (3356, 13), (3357, 13), (3358, 13), (3359, 13), (3360, 13), (3361, 13), (3362, 13), (3363, 13), (3364, 13), (3365, 13), (3366, 13), (3367, 13), (3368, 13), (3369, 13), (3370, 13), (3371, 13), (3372, 13), (3373, 13), (3374, 13), (3375, 13), (3376, 13), (3377, 13), (3378, 13), (3379, 13), (3380, 13), (3381, 13), (3382, 13), (3383, 13), (3384, 13), (3385, 13), (3386, 13), (3387, 13), (3388, 13), (3389, 13), (3390, 13), (3391, 13), (3392, 13), (3393, 13), (3394, 13), (3395, 13), (3396, 13), (3397, 13), (3398, 13), (3399, 13), (3400, 13), (3401, 13), (3402, 13), (3403, 13), (3404, 13), (3405, 13), (3406, 13), (3407, 13), (3408, 13), (3409, 13), (3410, 13), (3411, 13), (3412, 13), (3413, 13), (3414, 13), (3415, 13), (3416, 13), (3417, 13), (3418, 13), (3419, 13), (3420, 13), (3421, 13), (3422, 13), (3423, 13), (3424, 13), (3425, 13), (3426, 13), (3427, 13), (3428, 13), (3429, 13), (3430, 13), (3431, 13), (3432, 13), (3433, 13), (3434, 13), (3435, 13), (3436, 13), (3437, 13), (3438, 13), (3439, 13), (3440, 13), (3441, 13), (3442, 13), (3443, 13), (3444, 13), (3445, 13), (3446, 13), (3447, 13), (3448, 13), (3449, 13), (3450, 13), (3451, 13), (3452, 13), (3453, 13), (3454, 13), (3455, 13), (3456, 13), (3457, 13), (3458, 13), (3459, 13), (3460, 13), (3461, 13), (3462, 13), (3463, 13), (3464, 13), (3465, 13), (3466, 13), (3467, 13), (3468, 13), (3469, 13), (3470, 13), (3471, 13), (3472, 13), (3473, 13), (3474, 13), (3475, 13), (3476, 13), (3477, 13), (3478, 13), (3479, 13), (3480, 13), (3481, 13), (3482, 13), (3483, 13), (3484, 13), (3485, 13), (3486, 13), (3487, 13), (3488, 13), (3489, 13), (3490, 13), (3491, 13), (3492, 13), (3493, 13), (3494, 13), (3495, 13), (3496, 13), (3497, 13), (3498, 13), (3499, 13), (3500, 13), (3501, 13), (3502, 13), (3503, 13), (3504, 13), (3505, 13), (3506, 13), (3507, 13), (3508, 13), (3509, 13), (3510, 13), (3511, 13), (3512, 13), (3513, 13), (3514, 13), (3515, 13), (3516, 13), (3517, 13), (3518, 13), (3519, 13), (3520, 13), (3521, 13), (3522, 13), (3523, 13), (3524, 13), (3525, 13), (3526, 13), (3527, 13), (3528, 13), (3529, 13), (3530, 13), (3531, 13), (3532, 13), (3533, 13), (3534, 13), (3535, 13), (3536, 13), (3537, 13), (3538, 13), (3539, 13), (3540, 13), (3541, 13), (3542, 13), (3543, 13), (3544, 13), (3545, 13), (3546, 13), (3547, 13), (3548, 13), (3549, 13), (3550, 13), (3551, 13), (3552, 13), (3553, 13), (3554, 13), (3555, 13), (3556, 13), (3557, 13), (3558, 13), (3559, 13), (3560, 13), (3561, 13), (3562, 13), (3563, 13), (3564, 13), (3565, 13), (3566, 13), (3567, 13), (3568, 13), (3569, 13), (3570, 13), (3571, 13), (3572, 13), (3573, 13), (3574, 13), (3575, 13), (3576, 13), (3577, 13), (3578, 13), (3579, 13), (3580, 13), (3581, 13), (3582, 13), (3583, 13), (3584, 13), (3585, 13), (3586, 13), (3587, 13), (3588, 13), (3589, 13), (3590, 13), (3591, 13), (3592, 13), (3593, 13), (3594, 13), (3595, 13), (3596, 13), (3597, 13), (3598, 13), (3599, 13), (3600, 13), (3601, 13), (3602, 13), (3603, 13), (3604, 13), (3605, 13), (3606, 13), (3607, 13), (3608, 13), (3609, 13), (3610, 13), (3611, 13), (3612, 13), (3613, 13), (3614, 13), (3615, 13), (3616, 13), (3617, 13), (3618, 13), (3619, 13), (3620, 13), (3621, 13), (3622, 13), (3623, 13), (3624, 13), (3625, 13), (3626, 13), (3627, 13), (3628, 13), (3629, 13), (3630, 13), (3631, 13), (3632, 13), (3633, 13), (3634, 13), (3635, 13), (3636, 13), (3637, 13), (3638, 13), (3639, 13), (3640, 13), (3641, 13), (3642, 13), (3643, 13), (3644, 13), (3645, 13), (3646, 13), (3647, 13), (3648, 13), (3649, 13), (3650, 13), (3651, 13), (3652, 13), (3653, 13), (3654, 13), (3655, 13), (3656, 13), (3657, 13), (3658, 13), (3659, 13), (3660, 13), (3661, 13), (3662, 13), (3663, 13), (3664, 13), (3665, 13), (3666, 13), (3667, 13), (3668, 13), (3669, 13), (3670, 13), (3671, 13), (3672, 13), (3673, 13), (3674, 13), (3675, 13), (3676, 13), (3677, 13), (3678, 13), (3679, 13), (3680, 13), (3681, 13), (3682, 13), (3683, 13), (3684, 13), (3685, 13), (3686, 13), (3687, 13), (3688, 13), (3689, 13), (3690, 13), (3691, 13), (3692, 13), (3693, 13), (3694, 13), (3695, 13), (3696, 13), (3697, 13), (3698, 13), (3699, 13), (3700, 13), (3701, 13), (3702, 13), (3703, 13), (3704, 13), (3705, 13), (3706, 13), (3707, 13), (3708, 13), (3709, 13), (3710, 13), (3711, 13), (3712, 13), (3713, 13), (3714, 13), (3715, 13), (3716, 13), (3717, 13), (3718, 13), (3719, 13), (3720, 13), (3721, 13), (3722, 13), (3723, 13), (3724, 13), (3725, 13), (3726, 13), (3727, 13), (3728, 13), (3729, 13), (3730, 13), (3731, 13), (3732, 13), (3733, 13), (3734, 13), (3735, 13), (3736, 13), (3737, 13), (3738, 13), (3739, 13), (3740, 13), (3741, 13), (3742, 13), (3743, 13), (3744, 13), (3745, 13), (3746, 13), (3747, 13), (3748, 13), (3749, 13), (3750, 13), (3751, 13), (3752, 13), (3753, 13), (3754, 13), (3755, 13), (3756, 13), (3757, 13), (3758, 13), (3759, 13), (3760, 13), (3761, 13), (3762, 13), (3763, 13), (3764, 13), (3765, 13), (3766, 13), (3767, 13), (3768, 13), (3769, 13), (3770, 13), (3771, 13), (3772, 13), (3773, 13), (3774, 13), (3775, 13), (3776, 13), (3777, 13), (3778, 13), (3779, 13), (3780, 13), (3781, 13), (3782, 13), (3783, 13), (3784, 13), (3785, 13), (3786, 13), (3787, 13), (3788, 13), (3789, 13), (3790, 13), (3791, 13), (3792, 13), (3793, 13), (3794, 13), (3795, 13), (3796, 13), (3797, 13), (3798, 13), (3799, 13), (3800, 13), (3801, 13), (3802, 13), (3803, 13), (3804, 13), (3805, 13), (3806, 13), (3807, 13), (3808, 13), (3809, 13), (3810, 13), (3811, 13), (3812, 13), (3813, 13), (3814, 13), (3815, 13), (3816, 13), (3817, 13), (3818, 13), (3819, 13), (3820, 13), (3821, 13), (3822, 13), (3823, 13), (3824, 13), (3825, 13), (3826, 13), (3827, 13), (3828, 13), (3829, 13), (3830, 13), (3831, 13), (3832, 13), (3833, 13), (3834, 13), (3835, 13), (3836, 13), (3837, 13), (3838, 13), (3839, 13), (3840, 13), (3841, 13), (3842, 13), (3843, 13), (3844, 13), (3845, 13), (3846, 13), (3847, 13), (3848, 13), (3849, 13), (3850, 13), (3851, 13), (3852, 13), (3853, 13), (3854, 13), (3855, 13), (3856, 13), (3857, 13), (3858, 13), (3859, 13), (3860, 13), (3861, 13), (3862, 13), (3863, 13), (3864, 13), (3865, 13), (3866, 13), (3867, 13), (3868, 13), (3869, 13), (3870, 13), (3871, 13), (3872, 13), (3873, 13), (3874, 13), (3875, 13), (3876, 13), (3877, 13), (3878, 13), (3879, 13), (3880, 13), (3881, 13), (3882, 13), (3883, 13), (3884, 13), (3885, 13), (3886, 13), (3887, 13), (3888, 13), (3889, 13), (3890, 13), (3891, 13), (3892, 13), (3893, 13), (3894, 13), (3895, 13), (3896, 13), (3897, 13), (3898, 13), (3899, 13), (3900, 13), (3901, 13), (3902, 13), (3903, 13), (3904, 13), (3905, 13), (3906, 13), (3907, 13), (3908, 13), (3909, 13), (3910, 13), (3911, 13), (3912, 13), (3913, 13), (3914, 13), (3915, 13), (3916, 13), (3917, 13), (3918, 13), (3919, 13), (3920, 13), (3921, 13), (3922, 13), (3923, 13), (3924, 13), (3925, 13), (3926, 13), (3927, 13), (3928, 13), (3929, 13), (3930, 13), (3931, 13), (3932, 13), (3933, 13), (3934, 13), (3935, 13), (3936, 13), (3937, 13), (3938, 13), (3939, 13), (3940, 13), (3941, 13), (3942, 13), (3943, 13), (3944, 13), (3945, 13), (3946, 13), (3947, 13), (3948, 13), (3949, 13), (3950, 13), (3951, 13), (3952, 13), (3953, 13), (3954, 13), (3955, 13), (3956, 13), (3957, 13), (3958, 13), (3959, 13), (3960, 13), (3961, 13), (3962, 13), (3963, 13), (3964, 13), (3965, 13), (3966, 13), (3967, 13), (3968, 13), (3969, 13), (3970, 13), (3971, 13), (3972, 13), (3973, 13), (3974, 13), (3975, 13), (3976, 13), (3977, 13), (3978, 13), (3979, 13), (3980, 13), (3981, 13), (3982, 13), (3983, 13), (3984, 13), (3985, 13), (3986, 13), (3987, 13), (3988, 13), (3989, 13), (3990, 13), (3991, 13), (3992, 13), (3993, 13), (3994, 13), (3995, 13), (3996, 13), (3997, 13), (3998, 13), (3999, 13), (4000, 13), (4001, 13), (4002, 13), (4003, 13), (4004, 13), (4005, 13), (4006, 13), (4007, 13), (4008, 13), (4009, 13), (4010, 13), (4011, 13), (4012, 13), (4013, 13), (4014, 13), (4015, 13), (4016, 13), (4017, 13), (4018, 13), (4019, 13), (4020, 13), (4021, 13), (4022, 13), (4023, 13), (4024, 13), (4025, 13), (4026, 13), (4027, 13), (4028, 13), (4029, 13), (4030, 13), (4031, 13), (4032, 13), (4033, 13), (4034, 13), (4035, 13), (4036, 13), (4037, 13), (4038, 13), (4039, 13), (4040, 13), (4041, 13), (4042, 13), (4043, 13), (4044, 13), (4045, 13), (4046, 13), (4047, 13), (4048, 13), (4049, 13), (4050, 13), (4051, 13), (4052, 13), (4053, 13), (4054, 13), (4055, 13), (4056, 13), (4057, 13), (4058, 13), (4059, 13), (4060, 13), (4061, 13), (4062, 13), (4063, 13), (4064, 13), (4065, 13), (4066, 13), (4067, 13), (4068, 13), (4069, 13), (4070, 13), (4071, 13), (4072, 13), (4073, 13), (4074, 13), (4075, 13), (4076, 13), (4077, 13), (4078, 13), (4079, 13), (4080, 13), (4081, 13), (4082, 13), (4083, 13), (4084, 13), (4085, 13), (4086, 13), (4087, 13), (4088, 13), (4089, 13), (4090, 13), (4091, 13), (4092, 13), (4093, 13), (4094, 13), (4095, 13), (4096, 13), (4097, 13), (4098, 13), (4099, 13), (4100, 13), (4101, 13), (4102, 13), (4103, 13), (4104, 13), (4105, 13), (4106, 13), (4107, 13), (4108, 13), (4109, 13), (4110, 13), (4111, 13), (4112, 13), (4113, 13), (4114, 13), (4115, 13), (4116, 13), (4117, 13), (4118, 13), (4119, 13), (4120, 13), (4121, 13), (4122, 13), (4123, 13), (4124, 13), (4125, 13), (4126, 13), (4127, 13), (4128, 13), (4129, 13), (4130, 13), (4131, 13), (4132, 13), (4133, 13), (4134, 13), (4135, 13), (4136, 13), (4137, 13), (4138, 13), (4139, 13), (4140, 13), (4141, 13), (4142, 13), (4143, 13), (4144, 13), (4145, 13), (4146, 13), (4147, 13), (4148, 13), (4149, 13), (4150, 13), (4151, 13), (4152, 13), (4153, 13), (4154, 13), (4155, 13), (4156, 13), (4157, 13), (4158, 13), (4159, 13), (4160, 13), (4161, 13), (4162, 13), (4163, 13), (4164, 13), (4165, 13), (4166, 13), (4167, 13), (4168, 13), (4169, 13), (4170, 13), (4171, 13), (4172, 13), (4173, 13), (4174, 13), (4175, 13), (4176, 13), (4177, 13), (4178, 13), (4179, 13), (4180, 13), (4181, 13), (4182, 13), (4183, 13), (4184, 13), (4185, 13), (4186, 13), (4187, 13), (4188, 13), (4189, 13), (4190, 13), (4191, 13), (4192, 13), (4193, 13), (4194, 13), (4195, 13), (4196, 13), (4197, 13), (4198, 13), (4199, 13), (4200, 13), (4201, 13), (4202, 13), (4203, 13), (4204, 13), (4205, 13), (4206, 13), (4207, 13), (4208, 13), (4209, 13), (4210, 13), (4211, 13), (4212, 13), (4213, 13), (4214, 13), (4215, 13), (4216, 13), (4217, 13), (4218, 13), (4219, 13), (4220, 13), (4221, 13), (4222, 13), (4223, 13), (4224, 13), (4225, 13), (4226, 13), (4227, 13), (4228, 13), (4229, 13), (4230, 13), (4231, 13), (4232, 13), (4233, 13), (4234, 13), (4235, 13), (4236, 13), (4237, 13), (4238, 13), (4239, 13), (4240, 13), (4241, 13), (4242, 13), (4243, 13), (4244, 13), (4245, 13), (4246, 13), (4247, 13), (4248, 13), (4249, 13), (4250, 13), (4251, 13), (4252, 13), (4253, 13), (4254, 13), (4255, 13), (4256, 13), (4257, 13), (4258, 13), (4259, 13), (4260, 13), (4261, 13), (4262, 13), (4263, 13), (4264, 13), (4265, 13), (4266, 13), (4267, 13), (4268, 13), (4269, 13), (4270, 13), (4271, 13), (4272, 13), (4273, 13), (4274, 13), (4275, 13), (4276, 13), (4277, 13), (4278, 13), (4279, 13), (4280, 13), (4281, 13), (4282, 13), (4283, 13), (4284, 13), (4285, 13), (4286, 13), (4287, 13), (4288, 13), (4289, 13), (4290, 13), (4291, 13), (4292, 13), (4293, 13), (4294, 13), (4295, 13), (4296, 13), (4297, 13), (4298, 13), (4299, 13), (4300, 13), (4301, 13), (4302, 13), (4303, 13), (4304, 13), (4305, 13), (4306, 13), (4307, 13), (4308, 13), (4309, 13), (4310, 13), (4311, 13), (4312, 13), (4313, 13), (4314, 13), (4315, 13), (4316, 13), (4317, 13), (4318, 13), (4319, 13), (4320, 13), (4321, 13), (4322, 13), (4323, 13), (4324, 13), (4325, 13), (4326, 13), (4327, 13), (4328, 13), (4329, 13), (4330, 13), (4331, 13), (4332, 13), (4333, 13), (4334, 13), (4335, 13), (4336, 13), (4337, 13), (4338, 13), (4339, 13), (4340, 13), (4341, 13), (4342, 13), (4343, 13), (4344, 13), (4345, 13), (4346, 13), (4347, 13), (4348, 13), (4349, 13), (4350, 13), (4351, 13), (4352, 13), (4353, 13), (4354, 13), (4355, 13), (4356, 13), (4357, 13), (4358, 13), (4359, 13), (4360, 13), (4361, 13), (4362, 13), (4363, 13), (4364, 13), (4365, 13), (4366, 13), (4367, 13), (4368, 13), (4369, 13), (4370, 13), (4371, 13), (4372, 13), (4373, 13), (4374, 13), (4375, 13), (4376, 13), (4377, 13), (4378, 13), (4379, 13), (4380, 13), (4381, 13), (4382, 13), (4383, 13), (4384, 13), (4385, 13), (4386, 13), (4387, 13), (4388, 13), (4389, 13), (4390, 13), (4391, 13), (4392, 13), (4393, 13), (4394, 13), (4395, 13), (4396, 13), (4397, 13), (4398, 13), (4399, 13), (4400, 13), (4401, 13), (4402, 13), (4403, 13), (4404, 13), (4405, 13), (4406, 13), (4407, 13), (4408, 13), (4409, 13), (4410, 13), (4411, 13), (4412, 13), (4413, 13), (4414, 13), (4415, 13), (4416, 13), (4417, 13), (4418, 13), (4419, 13), (4420, 13), (4421, 13), (4422, 13), (4423, 13), (4424, 13), (4425, 13), (4426, 13), (4427, 13), (4428, 13), (4429, 13), (4430, 13), (4431, 13), (4432, 13), (4433, 13), (4434, 13), (4435, 13), (4436, 13), (4437, 13), (4438, 13), (4439, 13), (4440, 13), (4441, 13), (4442, 13), (4443, 13), (4444, 13), (4445, 13), (4446, 13), (4447, 13), (4448, 13), (4449, 13), (4450, 13), (4451, 13), (4452, 13), (4453, 13), (4454, 13), (4455, 13), (4456, 13), (4457, 13), (4458, 13), (4459, 13), (4460, 13), (4461, 13), (4462, 13), (4463, 13), (4464, 13), (4465, 13), (4466, 13), (4467, 13), (4468, 13), (4469, 13), (4470, 13), (4471, 13), (4472, 13), (4473, 13), (4474, 13), (4475, 13), (4476, 13), (4477, 13), (4478, 13), (4479, 13), (4480, 13), (4481, 13), (4482, 13), (4483, 13), (4484, 13), (4485, 13), (4486, 13), (4487, 13), (4488, 13), (4489, 13), (4490, 13), (4491, 13), (4492, 13), (4493, 13), (4494, 13), (4495, 13), (4496, 13), (4497, 13), (4498, 13), (4499, 13), (4500, 13), (4501, 13), (4502, 13), (4503, 13), (4504, 13), (4505, 13), (4506, 13), (4507, 13), (4508, 13), (4509, 13), (4510, 13), (4511, 13), (4512, 13), (4513, 13), (4514, 13), (4515, 13), (4516, 13), (4517, 13), (4518, 13), (4519, 13), (4520, 13), (4521, 13), (4522, 13), (4523, 13), (4524, 13), (4525, 13), (4526, 13), (4527, 13), (4528, 13), (4529, 13), (4530, 13), (4531, 13), (4532, 13), (4533, 13), (4534, 13), (4535, 13), (4536, 13), (4537, 13), (4538, 13), (4539, 13), (4540, 13), (4541, 13), (4542, 13), (4543, 13), (4544, 13), (4545, 13), (4546, 13), (4547, 13), (4548, 13), (4549, 13), (4550, 13), (4551, 13), (4552, 13), (4553, 13), (4554, 13), (4555, 13), (4556, 13), (4557, 13), (4558, 13), (4559, 13), (4560, 13), (4561, 13), (4562, 13), (4563, 13), (4564, 13), (4565, 13), (4566, 13), (4567, 13), (4568, 13), (4569, 13), (4570, 13), (4571, 13), (4572, 13), (4573, 13), (4574, 13), (4575, 13), (4576, 13), (4577, 13), (4578, 13), (4579, 13), (4580, 13), (4581, 13), (4582, 13), (4583, 13), (4584, 13), (4585, 13), (4586, 13), (4587, 13), (4588, 13), (4589, 13), (4590, 13), (4591, 13), (4592, 13), (4593, 13), (4594, 13), (4595, 13), (4596, 13), (4597, 13), (4598, 13), (4599, 13), (4600, 13), (4601, 13), (4602, 13), (4603, 13), (4604, 13), (4605, 13), (4606, 13), (4607, 13), (4608, 13), (4609, 13), (4610, 13), (4611, 13), (4612, 13), (4613, 13), (4614, 13), (4615, 13), (4616, 13), (4617, 13), (4618, 13), (4619, 13), (4620, 13), (4621, 13), (4622, 13), (4623, 13), (4624, 13), (4625, 13), (4626, 13), (4627, 13), (4628, 13), (4629, 13), (4630, 13), (4631, 13), (4632, 13), (4633, 13), (4634, 13), (4635, 13), (4636, 13), (4637, 13), (4638, 13), (4639, 13), (4640, 13), (4641, 13), (4642, 13), (4643, 13), (4644, 13), (4645, 13), (4646, 13), (4647, 13), (4648, 13), (4649, 13), (4650, 13), (4651, 13), (4652, 13), (4653, 13), (4654, 13), (4655, 13), (4656, 13), (4657, 13), (4658, 13), (4659, 13), (4660, 13), (4661, 13), (4662, 13), (4663, 13), (4664, 13), (4665, 13), (4666, 13), (4667, 13), (4668, 13), (4669, 13), (4670, 13), (4671, 13), (4672, 13), (4673, 13), (4674, 13), (4675, 13), (4676, 13), (4677, 13), (4678, 13), (4679, 13), (4680, 13), (4681, 13), (4682, 13), (4683, 13), (4684, 13), (4685, 13), (4686, 13), (4687, 13), (4688, 13), (4689, 13), (4690, 13), (4691, 13), (4692, 13), (4693, 13), (4694, 13), (4695, 13), (4696, 13), (4697, 13), (4698, 13), (4699, 13), (4700, 13), (4701, 13), (4702, 13), (4703, 13), (4704, 13), (4705, 13), (4706, 13), (4707, 13), (4708, 13), (4709, 13), (4710, 13), (4711, 13), (4712, 13), (4713, 13), (4714, 13), (4715, 13), (4716, 13), (4717, 13), (4718, 13), (4719, 13), (4720, 13), (4721, 13), (4722, 13), (4723, 13), (4724, 13), (4725, 13), (4726, 13), (4727, 13), (4728, 13), (4729, 13), (4730, 13), (4731, 13), (4732, 13), (4733, 13), (4734, 13), (4735, 13), (4736, 13), (4737, 13), (4738, 13), (4739, 13), (4740, 13), (4741, 13), (4742, 13), (4743, 13), (4744, 13), (4745, 13), (4746, 13), (4747, 13), (4748, 13), (4749, 13), (4750, 13), (4751, 13), (4752, 13), (4753, 13), (4754, 13), (4755, 13), (4756, 13), (4757, 13), (4758, 13), (4759, 13), (4760, 13), (4761, 13), (4762, 13), (4763, 13), (4764, 13), (4765, 13), (4766, 13), (4767, 13), (4768, 13), (4769, 13), (4770, 13), (4771, 13), (4772, 13), (4773, 13), (4774, 13), (4775, 13), (4776, 13), (4777, 13), (4778, 13), (4779, 13), (4780, 13), (4781, 13), (4782, 13), (4783, 13), (4784, 13), (4785, 13), (4786, 13), (4787, 13), (4788, 13), (4789, 13), (4790, 13), (4791, 13), (4792, 13), (4793, 13), (4794, 13), (4795, 13), (4796, 13), (4797, 13), (4798, 13), (4799, 13), (4800, 13), (4801, 13), (4802, 13), (4803, 13), (4804, 13), (4805, 13), (4806, 13), (4807, 13), (4808, 13), (4809, 13), (4810, 13), (4811, 13), (4812, 13), (4813, 13), (4814, 13), (4815, 13), (4816, 13), (4817, 13), (4818, 13), (4819, 13), (4820, 13), (4821, 13), (4822, 13), (4823, 13), (4824, 13), (4825, 13), (4826, 13), (4827, 13), (4828, 13), (4829, 13), (4830, 13), (4831, 13), (4832, 13), (4833, 13), (4834, 13), (4835, 13), (4836, 13), (4837, 13), (4838, 13), (4839, 13), (4840, 13), (4841, 13), (4842, 13), (4843, 13), (4844, 13), (4845, 13), (4846, 13), (4847, 13), (4848, 13), (4849, 13), (4850, 13), (4851, 13), (4852, 13), (4853, 13), (4854, 13), (4855, 13), (4856, 13), (4857, 13), (4858, 13), (4859, 13), (4860, 13), (4861, 13), (4862, 13), (4863, 13), (4864, 13), (4865, 13), (4866, 13), (4867, 13), (4868, 13), (4869, 13), (4870, 13), (4871, 13), (4872, 13), (4873, 13), (4874, 13), (4875, 13), (4876, 13), (4877, 13), (4878, 13), (4879, 13), (4880, 13), (4881, 13), (4882, 13), (4883, 13), (4884, 13), (4885, 13), (4886, 13), (4887, 13), (4888, 13), (4889, 13), (4890, 13), (4891, 13), (4892, 13), (4893, 13), (4894, 13), (4895, 13), (4896, 13), (4897, 13), (4898, 13), (4899, 13), (4900, 13), (4901, 13), (4902, 13), (4903, 13), (4904, 13), (4905, 13), (4906, 13), (4907, 13), (4908, 13), (4909, 13), (4910, 13), (4911, 13), (4912, 13), (4913, 13), (4914, 13), (4915, 13), (4916, 13), (4917, 13), (4918, 13), (4919, 13), (4920, 13), (4921, 13), (4922, 13), (4923, 13), (4924, 13), (4925, 13), (4926, 13), (4927, 13), (4928, 13), (4929, 13), (4930, 13), (4931, 13), (4932, 13), (4933, 13), (4934, 13), (4935, 13), (4936, 13), (4937, 13), (4938, 13), (4939, 13), (4940, 13), (4941, 13), (4942, 13), (4943, 13), (4944, 13), (4945, 13), (4946, 13), (4947, 13), (4948, 13), (4949, 13), (4950, 13), (4951, 13), (4952, 13), (4953, 13), (4954, 13), (4955, 13), (4956, 13), (4957, 13), (4958, 13), (4959, 13), (4960, 13), (4961, 13), (4962, 13), (4963, 13), (4964, 13), (4965, 13), (4966, 13), (4967, 13), (4968, 13), (4969, 13), (4970, 13), (4971, 13), (4972, 13), (4973, 13), (4974, 13), (4975, 13), (4976, 13), (4977, 13), (4978, 13), (4979, 13), (4980, 13), (4981, 13), (4982, 13), (4983, 13), (4984, 13), (4985, 13), (4986, 13), (4987, 13), (4988, 13), (4989, 13), (4990, 13), (4991, 13), (4992, 13), (4993, 13), (4994, 13), (4995, 13), (4996, 13), (4997, 13), (4998, 13), (4999, 13), (5000, 13), (5001, 13), (5002, 13), (5003, 13), (5004, 13), (5005, 13), (5006, 13), (5007, 13), (5008, 13), (5009, 13), (5010, 13), (5011, 13), (5012, 13), (5013, 13), (5014, 13), (5015, 13), (5016, 13), (5017, 13), (5018, 13), (5019, 13), (5020, 13), (5021, 13), (5022, 13), (5023, 13), (5024, 13), (5025, 13), (5026, 13), (5027, 13), (5028, 13), (5029, 13), (5030, 13), (5031, 13), (5032, 13), (5033, 13), (5034, 13), (5035, 13), (5036, 13), (5037, 13), (5038, 13), (5039, 13), (5040, 13), (5041, 13), (5042, 13), (5043, 13), (5044, 13), (5045, 13), (5046, 13), (5047, 13), (5048, 13), (5049, 13), (5050, 13), (5051, 13), (5052, 13), (5053, 13), (5054, 13), (5055, 13), (5056, 13), (5057, 13), (5058, 13), (5059, 13), (5060, 13), (5061, 13), (5062, 13), (5063, 13), (5064, 13), (5065, 13), (5066, 13), (5067, 13), (5068, 13), (5069, 13), (5070, 13), (5071, 13), (5072, 13), (5073, 13), (5074, 13), (5075, 13), (5076, 13), (5077, 13), (5078, 13), (5079, 13), (5080, 13), (5081, 13), (5082, 13), (5083, 13), (5084, 13), (5085, 13), (5086, 13), (5087, 13), (5088, 13), (5089, 13), (5090, 13), (5091, 13), (5092, 13), (5093, 13), (5094, 13), (5095, 13), (5096, 13), (5097, 13), (5098, 13), (5099, 13), (5100, 13), (5101, 13), (5102, 13), (5103, 13), (5104, 13), (5105, 13), (5106, 13), (5107, 13), (5108, 13), (5109, 13), (5110, 13), (5111, 13), (5112, 13), (5113, 13), (5114, 13), (5115, 13), (5116, 13), (5117, 13), (5118, 13), (5119, 13), (5120, 13), (5121, 13), (5122, 13), (5123, 13), (5124, 13), (5125, 13), (5126, 13), (5127, 13), (5128, 13), (5129, 13), (5130, 13), (5131, 13), (5132, 13), (5133, 13), (5134, 13), (5135, 13), (5136, 13), (5137, 13), (5138, 13), (5139, 13), (5140, 13), (5141, 13), (5142, 13), (5143, 13), (5144, 13), (5145, 13), (5146, 13), (5147, 13), (5148, 13), (5149, 13), (5150, 13), (5151, 13), (5152, 13), (5153, 13), (5154, 13), (5155, 13), (5156, 13), (5157, 13), (5158, 13), (5159, 13), (5160, 13), (5161, 13), (5162, 13), (5163, 13), (5164, 13), (5165, 13), (5166, 13), (5167, 13), (5168, 13), (5169, 13), (5170, 13), (5171, 13), (5172, 13), (5173, 13), (5174, 13), (5175, 13), (5176, 13), (5177, 13), (5178, 13), (5179, 13), (5180, 13), (5181, 13), (5182, 13), (5183, 13), (5184, 13), (5185, 13), (5186, 13), (5187, 13), (5188, 13), (5189, 13), (5190, 13), (5191, 13), (5192, 13), (5193, 13), (5194, 13), (5195, 13), (5196, 13), (5197, 13), (5198, 13), (5199, 13), (5200, 13), (5201, 13), (5202, 13), (5203, 13), (5204, 13), (5205, 13), (5206, 13), (5207, 13), (5208, 13), (5209, 13), (5210, 13), (5211, 13), (5212, 13), (5213, 13), (5214, 13), (5215, 13), (5216, 13), (5217, 13), (5218, 13), (5219, 13), (5220, 13), (5221, 13), (5222, 13), (5223, 13), (5224, 13), (5225, 13), (5226, 13), (5227, 13), (5228, 13), (5229, 13), (5230, 13), (5231, 13), (5232, 13), (5233, 13), (5234, 13), (5235, 13), (5236, 13), (5237, 13), (5238, 13), (5239, 13), (5240, 13), (5241, 13), (5242, 13), (5243, 13), (5244, 13), (5245, 13), (5246, 13), (5247, 13), (5248, 13), (5249, 13), (5250, 13), (5251, 13), (5252, 13), (5253, 13), (5254, 13), (5255, 13), (5256, 13), (5257, 13), (5258, 13), (5259, 13), (5260, 13), (5261, 13), (5262, 13), (5263, 13), (5264, 13), (5265, 13), (5266, 13), (5267, 13), (5268, 13), (5269, 13), (5270, 13), (5271, 13), (5272, 13), (5273, 13), (5274, 13), (5275, 13), (5276, 13), (5277, 13), (5278, 13), (5279, 13), (5280, 13), (5281, 13), (5282, 13), (5283, 13), (5284, 13), (5285, 13), (5286, 13), (5287, 13), (5288, 13), (5289, 13), (5290, 13), (5291, 13), (5292, 13), (5293, 13), (5294, 13), (5295, 13), (5296, 13), (5297, 13), (5298, 13), (5299, 13), (5300, 13), (5301, 13), (5302, 13), (5303, 13), (5304, 13), (5305, 13), (5306, 13), (5307, 13), (5308, 13), (5309, 13), (5310, 13), (5311, 13), (5312, 13), (5313, 13), (5314, 13), (5315, 13), (5316, 13), (5317, 13), (5318, 13), (5319, 13), (5320, 13), (5321, 13), (5322, 13), (5323, 13), (5324, 13), (5325, 13), (5326, 13), (5327, 13), (5328, 13), (5329, 13), (5330, 13), (5331, 13), (5332, 13), (5333, 13), (5334, 13), (5335, 13), (5336, 13), (5337, 13), (5338, 13), (5339, 13), (5340, 13), (5341, 13), (5342, 13), (5343, 13), (5344, 13), (5345, 13), (5346, 13), (5347, 13), (5348, 13), (5349, 13), (5350, 13), (5351, 13), (5352, 13), (5353, 13), (5354, 13), (5355, 13), (5356, 13), (5357, 13), (5358, 13), (5359, 13), (5360, 13), (5361, 13), (5362, 13), (5363, 13), (5364, 13), (5365, 13), (5366, 13), (5367, 13), (5368, 13), (5369, 13), (5370, 13), (5371, 13), (5372, 13), (5373, 13), (5374, 13), (5375, 13), (5376, 13), (5377, 13), (5378, 13), (5379, 13), (5380, 13), (5381, 13), (5382, 13), (5383, 13), (5384, 13), (5385, 13), (5386, 13), (5387, 13), (5388, 13), (5389, 13), (5390, 13), (5391, 13), (5392, 13), (5393, 13), (5394, 13), (5395, 13), (5396, 13), (5397, 13), (5398, 13), (5399, 13), (5400, 13), (5401, 13), (5402, 13), (5403, 13), (5404, 13), (5405, 13), (5406, 13), (5407, 13), (5408, 13), (5409, 13), (5410, 13), (5411, 13), (5412, 13), (5413, 13), (5414, 13), (5415, 13), (5416, 13), (5417, 13), (5418, 13), (5419, 13), (5420, 13), (5421, 13), (5422, 13), (5423, 13), (5424, 13), (5425, 13), (5426, 13), (5427, 13), (5428, 13), (5429, 13), (5430, 13), (5431, 13), (5432, 13), (5433, 13), (5434, 13), (5435, 13), (5436, 13), (5437, 13), (5438, 13), (5439, 13), (5440, 13), (5441, 13), (5442, 13), (5443, 13), (5444, 13), (5445, 13), (5446, 13), (5447, 13), (5448, 13), (5449, 13), (5450, 13), (5451, 13), (5452, 13), (5453, 13), (5454, 13), (5455, 13), (5456, 13), (5457, 13), (5458, 13), (5459, 13), (5460, 13), (5461, 13), (5462, 13), (5463, 13), (5464, 13), (5465, 13), (5466, 13), (5467, 13), (5468, 13), (5469, 13), (5470, 13), (5471, 13), (5472, 13), (5473, 13), (5474, 13), (5475, 13), (5476, 13), (5477, 13), (5478, 13), (5479, 13), (5480, 13), (5481, 13), (5482, 13), (5483, 13), (5484, 13), (5485, 13), (5486, 13), (5487, 13), (5488, 13), (5489, 13), (5490, 13), (5491, 13), (5492, 13), (5493, 13), (5494, 13), (5495, 13), (5496, 13), (5497, 13), (5498, 13), (5499, 13), (5500, 13), (5501, 13), (5502, 13), (5503, 13), (5504, 13), (5505, 13), (5506, 13), (5507, 13), (5508, 13), (5509, 13), (5510, 13), (5511, 13), (5512, 13), (5513, 13), (5514, 13), (5515, 13), (5516, 13), (5517, 13), (5518, 13), (5519, 13), (5520, 13), (5521, 13), (5522, 13), (5523, 13), (5524, 13), (5525, 13), (5526, 13), (5527, 13), (5528, 13), (5529, 13), (5530, 13), (5531, 13), (5532, 13), (5533, 13), (5534, 13), (5535, 13), (5536, 13), (5537, 13), (5538, 13), (5539, 13), (5540, 13), (5541, 13), (5542, 13), (5543, 13), (5544, 13), (5545, 13), (5546, 13), (5547, 13), (5548, 13), (5549, 13), (5550, 13), (5551, 13), (5552, 13), (5553, 13), (5554, 13), (5555, 13), (5556, 13), (5557, 13), (5558, 13), (5559, 13), (5560, 13), (5561, 13), (5562, 13), (5563, 13), (5564, 13), (5565, 13), (5566, 13), (5567, 13), (5568, 13), (5569, 13), (5570, 13), (5571, 13), (5572, 13), (5573, 13), (5574, 13), (5575, 13), (5576, 13), (5577, 13), (5578, 13), (5579, 13), (5580, 13), (5581, 13), (5582, 13), (5583, 13), (5584, 13), (5585, 13), (5586, 13), (5587, 13), (5588, 13), (5589, 13), (5590, 13), (5591, 13), (5592, 13), (5593, 13), (5594, 13), (5595, 13), (5596, 13), (5597, 13), (5598, 13), (5599, 13), (5600, 13), (5601, 13), (5602, 13), (5603, 13), (5604, 13), (5605, 13), (5606, 13), (5607, 13), (5608, 13), (5609, 13), (5610, 13), (5611, 13), (5612, 13), (5613, 13), (5614, 13), (5615, 13), (5616, 13), (5617, 13), (5618, 13), (5619, 13), (5620, 13), (5621, 13), (5622, 13), (5623, 13), (5624, 13), (5625, 13), (5626, 13), (5627, 13), (5628, 13), (5629, 13), (5630, 13), (5631, 13), (5632, 13), (5633, 13), (5634, 13), (5635, 13), (5636, 13), (5637, 13), (5638, 13), (5639, 13), (5640, 13), (5641, 13), (5642, 13), (5643, 13), (5644, 13), (5645, 13), (5646, 13), (5647, 13), (5648, 13), (5649, 13), (5650, 13), (5651, 13), (5652, 13), (5653, 13), (5654, 13), (5655, 13), (5656, 13), (5657, 13), (5658, 13), (5659, 13), (5660, 13), (5661, 13), (5662, 13), (5663, 13), (5664, 13), (5665, 13), (5666, 13), (5667, 13), (5668, 13), (5669, 13), (5670, 13), (5671, 13), (5672, 13), (5673, 13), (5674, 13), (5675, 13), (5676, 13), (5677, 13), (5678, 13), (5679, 13), (5680, 13), (5681, 13), (5682, 13), (5683, 13), (5684, 13), (5685, 13), (5686, 13), (5687, 13), (5688, 13), (5689, 13), (5690, 13), (5691, 13), (5692, 13), (5693, 13), (5694, 13), (5695, 13), (5696, 13), (5697, 13), (5698, 13), (5699, 13), (5700, 13), (5701, 13), (5702, 13), (5703, 13), (5704, 13), (5705, 13), (5706, 13), (5707, 13), (5708, 13), (5709, 13), (5710, 13), (5711, 13), (5712, 13), (5713, 13), (5714, 13), (5715, 13), (5716, 13), (5717, 13), (5718, 13), (5719, 13), (5720, 13), (5721, 13), (5722, 13), (5723, 13), (5724, 13), (5725, 13), (5726, 13), (5727, 13), (5728, 13), (5729, 13), (5730, 13), (5731, 13), (5732, 13), (5733, 13), (5734, 13), (5735, 13), (5736, 13), (5737, 13), (5738, 13), (5739, 13), (5740, 13), (5741, 13), (5742, 13), (5743, 13), (5744, 13), (5745, 13), (5746, 13), (5747, 13), (5748, 13), (5749, 13), (5750, 13), (5751, 13), (5752, 13), (5753, 13), (5754, 13), (5755, 13), (5756, 13), (5757, 13), (5758, 13), (5759, 13), (5760, 13), (5761, 13), (5762, 13), (5763, 13), (5764, 13), (5765, 13), (5766, 13), (5767, 13), (5768, 13), (5769, 13), (5770, 13), (5771, 13), (5772, 13), (5773, 13), (5774, 13), (5775, 13), (5776, 13), (5777, 13), (5778, 13), (5779, 13), (5780, 13), (5781, 13), (5782, 13), (5783, 13), (5784, 13), (5785, 13), (5786, 13), (5787, 13), (5788, 13), (5789, 13), (5790, 13), (5791, 13), (5792, 13), (5793, 13), (5794, 13), (5795, 13), (5796, 13), (5797, 13), (5798, 13), (5799, 13), (5800, 13), (5801, 13), (5802, 13), (5803, 13), (5804, 13), (5805, 13), (5806, 13), (5807, 13), (5808, 13), (5809, 13), (5810, 13), (5811, 13), (5812, 13), (5813, 13), (5814, 13), (5815, 13), (5816, 13), (5817, 13), (5818, 13), (5819, 13), (5820, 13), (5821, 13), (5822, 13), (5823, 13), (5824, 13), (5825, 13), (5826, 13), (5827, 13), (5828, 13), (5829, 13), (5830, 13), (5831, 13), (5832, 13), (5833, 13), (5834, 13), (5835, 13), (5836, 13), (5837, 13), (5838, 13), (5839, 13), (5840, 13), (5841, 13), (5842, 13), (5843, 13), (5844, 13), (5845, 13), (5846, 13), (5847, 13), (5848, 13), (5849, 13), (5850, 13), (5851, 13), (5852, 13), (5853, 13), (5854, 13), (5855, 13), (5856, 13), (5857, 13), (5858, 13), (5859, 13), (5860, 13), (5861, 13), (5862, 13), (5863, 13), (5864, 13), (5865, 13), (5866, 13), (5867, 13), (5868, 13), (5869, 13), (5870, 13), (5871, 13), (5872, 13), (5873, 13), (5874, 13), (5875, 13), (5876, 13), (5877, 13), (5878, 13), (5879, 13), (5880, 13), (5881, 13), (5882, 13), (5883, 13), (5884, 13), (5885, 13), (5886, 13), (5887, 13), (5888, 13), (5889, 13), (5890, 13), (5891, 13), (5892, 13), (5893, 13), (5894, 13), (5895, 13), (5896, 13), (5897, 13), (5898, 13), (5899, 13), (5900, 13), (5901, 13), (5902, 13), (5903, 13), (5904, 13), (5905, 13), (5906, 13), (5907, 13), (5908, 13), (5909, 13), (5910, 13), (5911, 13), (5912, 13), (5913, 13), (5914, 13), (5915, 13), (5916, 13), (5917, 13), (5918, 13), (5919, 13), (5920, 13), (5921, 13), (5922, 13), (5923, 13), (5924, 13), (5925, 13), (5926, 13), (5927, 13), (5928, 13), (5929, 13), (5930, 13), (5931, 13), (5932, 13), (5933, 13), (5934, 13), (5935, 13), (5936, 13), (5937, 13), (5938, 13), (5939, 13), (5940, 13), (5941, 13), (5942, 13), (5943, 13), (5944, 13), (5945, 13), (5946, 13), (5947, 13), (5948, 13), (5949, 13), (5950, 13), (5951, 13), (5952, 13), (5953, 13), (5954, 13), (5955, 13), (5956, 13), (5957, 13), (5958, 13), (5959, 13), (5960, 13), (5961, 13), (5962, 13), (5963, 13), (5964, 13), (5965, 13), (5966, 13), (5967, 13), (5968, 13), (5969, 13), (5970, 13), (5971, 13), (5972, 13), (5973, 13), (5974, 13), (5975, 13), (5976, 13), (5977, 13), (5978, 13), (5979, 13), (5980, 13), (5981, 13), (5982, 13), (5983, 13), (5984, 13), (5985, 13), (5986, 13), (5987, 13), (5988, 13), (5989, 13), (5990, 13), (5991, 13), (5992, 13), (5993, 13), (5994, 13), (5995, 13), (5996, 13), (5997, 13), (5998, 13), (5999, 13), (6000, 13), (6001, 13), (6002, 13), (6003, 13), (6004, 13), (6005, 13), (6006, 13), (6007, 13), (6008, 13), (6009, 13), (6010, 13), (6011, 13), (6012, 13), (6013, 13), (6014, 13), (6015, 13), (6016, 13), (6017, 13), (6018, 13), (6019, 13), (6020, 13), (6021, 13), (6022, 13), (6023, 13), (6024, 13), (6025, 13), (6026, 13), (6027, 13), (6028, 13), (6029, 13), (6030, 13), (6031, 13), (6032, 13), (6033, 13), (6034, 13), (6035, 13), (6036, 13), (6037, 13), (6038, 13), (6039, 13), (6040, 13), (6041, 13), (6042, 13), (6043, 13), (6044, 13), (6045, 13), (6046, 13), (6047, 13), (6048, 13), (6049, 13), (6050, 13), (6051, 13), (6052, 13), (6053, 13), (6054, 13), (6055, 13), (6056, 13), (6057, 13), (6058, 13), (6059, 13), (6060, 13), (6061, 13), (6062, 13), (6063, 13), (6064, 13), (6065, 13), (6066, 13), (6067, 13), (6068, 13), (6069, 13), (6070, 13), (6071, 13), (6072, 13), (6073, 13), (6074, 13), (6075, 13), (6076, 13), (6077, 13), (6078, 13), (6079, 13), (6080, 13), (6081, 13), (6082, 13), (6083, 13), (6084, 13), (6085, 13), (6086, 13), (6087, 13), (6088, 13), (6089, 13), (6090, 13), (6091, 13), (6092, 13), (6093, 13), (6094, 13), (6095, 13), (6096, 13), (6097, 13), (6098, 13), (6099, 13), (6100, 13), (6101, 13), (6102, 13), (6103, 13), (6104, 13), (6105, 13), (6106, 13), (6107, 13), (6108, 13), (6109, 13), (6110, 13), (6111, 13), (6112, 13), (6113, 13), (6114, 13), (6115, 13), (6116, 13), (6117, 13), (6118, 13), (6119, 13), (6120, 13), (6121, 13), (6122, 13), (6123, 13), (6124, 13), (6125, 13), (6126, 13), (6127, 13), (6128, 13), (6129, 13), (6130, 13), (6131, 13), (6132, 13), (6133, 13), (6134, 13), (6135, 13), (6136, 13), (6137, 13), (6138, 13), (6139, 13), (6140, 13), (6141, 13), (6142, 13), (6143, 13), (6144, 13), (6145, 13), (6146, 13), (6147, 13), (6148, 13), (6149, 13), (6150, 13), (6151, 13), (6152, 13), (6153, 13), (6154, 13), (6155, 13), (6156, 13), (6157, 13), (6158, 13), (6159, 13), (6160, 13), (6161, 13), (6162, 13), (6163, 13), (6164, 13), (6165, 13), (6166, 13), (6167, 13), (6168, 13), (6169, 13), (6170, 13), (6171, 13), (6172, 13), (6173, 13), (6174, 13), (6175, 13), (6176, 13), (6177, 13), (6178, 13), (6179, 13), (6180, 13), (6181, 13), (6182, 13), (6183, 13), (6184, 13), (6185, 13), (6186, 13), (6187, 13), (6188, 13), (6189, 13), (6190, 13), (6191, 13), (6192, 13), (6193, 13), (6194, 13), (6195, 13), (6196, 13), (6197, 13), (6198, 13), (6199, 13), (6200, 13), (6201, 13), (6202, 13), (6203, 13), (6204, 13), (6205, 13), (6206, 13), (6207, 13), (6208, 13), (6209, 13), (6210, 13), (6211, 13), (6212, 13), (6213, 13), (6214, 13), (6215, 13), (6216, 13), (6217, 13), (6218, 13), (6219, 13), (6220, 13), (6221, 13), (6222, 13), (6223, 13), (6224, 13), (6225, 13), (6226, 13), (6227, 13), (6228, 13), (6229, 13), (6230, 13), (6231, 13), (6232, 13), (6233, 13), (6234, 13), (6235, 13), (6236, 13), (6237, 13), (6238, 13), (6239, 13), (6240, 13), (6241, 13), (6242, 13), (6243, 13), (6244, 13), (6245, 13), (6246, 13), (6247, 13), (6248, 13), (6249, 13), (6250, 13), (6251, 13), (6252, 13), (6253, 13), (6254, 13), (6255, 13), (6256, 13), (6257, 13), (6258, 13), (6259, 13), (6260, 13), (6261, 13), (6262, 13), (6263, 13), (6264, 13), (6265, 13), (6266, 13), (6267, 13), (6268, 13), (6269, 13), (6270, 13), (6271, 13), (6272, 13), (6273, 13), (6274, 13), (6275, 13), (6276, 13), (6277, 13), (6278, 13), (6279, 13), (6280, 13), (6281, 13), (6282, 13), (6283, 13), (6284, 13), (6285, 13), (6286, 13), (6287, 13), (6288, 13), (6289, 13), (6290, 13), (6291, 13), (6292, 13), (6293, 13), (6294, 13), (6295, 13), (6296, 13), (6297, 13), (6298, 13), (6299, 13), (6300, 13), (6301, 13), (6302, 13), (6303, 13), (6304, 13), (6305, 13), (6306, 13), (6307, 13), (6308, 13), (6309, 13), (6310, 13), (6311, 13), (6312, 13), (6313, 13), (6314, 13), (6315, 13), (6316, 13), (6317, 13), (6318, 13), (6319, 13), (6320, 13), (6321, 13), (6322, 13), (6323, 13), (6324, 13), (6325, 13), (6326, 13), (6327, 13), (6328, 13), (6329, 13), (6330, 13), (6331, 13), (6332, 13), (6333, 13), (6334, 13), (6335, 13), (6336, 13), (6337, 13), (6338, 13), (6339, 13), (6340, 13), (6341, 13), (6342, 13), (6343, 13), (6344, 13), (6345, 13), (6346, 13), (6347, 13), (6348, 13), (6349, 13), (6350, 13), (6351, 13), (6352, 13), (6353, 13), (6354, 13), (6355, 13), (6356, 13), (6357, 13), (6358, 13), (6359, 13), (6360, 13), (6361, 13), (6362, 13), (6363, 13), (6364, 13), (6365, 13), (6366, 13), (6367, 13), (6368, 13), (6369, 13), (6370, 13), (6371, 13), (6372, 13), (6373, 13), (6374, 13), (6375, 13), (6376, 13), (6377, 13), (6378, 13), (6379, 13), (6380, 13), (6381, 13), (6382, 13), (6383, 13), (6384, 13), (6385, 13), (6386, 13), (6387, 13), (6388, 13), (6389, 13), (6390, 13), (6391, 13), (6392, 13), (6393, 13), (6394, 13), (6395, 13), (6396, 13), (6397, 13), (6398, 13), (6399, 13), (6400, 13), (6401, 13), (6402, 13), (6403, 13), (6404, 13), (6405, 13), (6406, 13), (6407, 13), (6408, 13), (6409, 13), (6410, 13), (6411, 13), (6412, 13), (6413, 13), (6414, 13), (6415, 13), (6416, 13), (6417, 13), (6418, 13), (6419, 13), (6420, 13), (6421, 13), (6422, 13), (6423, 13), (6424, 13), (6425, 13), (6426, 13), (6427, 13), (6428, 13), (6429, 13), (6430, 13), (6431, 13), (6432, 13), (6433, 13), (6434, 13), (6435, 13), (6436, 13), (6437, 13), (6438, 13), (6439, 13), (6440, 13), (6441, 13), (6442, 13), (6443, 13), (6444, 13), (6445, 13), (6446, 13), (6447, 13), (6448, 13), (6449, 13), (6450, 13), (6451, 13), (6452, 13), (6453, 13), (6454, 13), (6455, 13), (6456, 13), (6457, 13), (6458, 13), (6459, 13), (6460, 13), (6461, 13), (6462, 13), (6463, 13), (6464, 13), (6465, 13), (6466, 13), (6467, 13), (6468, 13), (6469, 13), (6470, 13), (6471, 13), (6472, 13), (6473, 13), (6474, 13), (6475, 13), (6476, 13), (6477, 13), (6478, 13), (6479, 13), (6480, 13), (6481, 13), (6482, 13), (6483, 13), (6484, 13), (6485, 13), (6486, 13), (6487, 13), (6488, 13), (6489, 13), (6490, 13), (6491, 13), (6492, 13), (6493, 13), (6494, 13), (6495, 13), (6496, 13), (6497, 13), (6498, 13), (6499, 13), (6500, 13), (6501, 13), (6502, 13), (6503, 13), (6504, 13), (6505, 13), (6506, 13), (6507, 13), (6508, 13), (6509, 13), (6510, 13), (6511, 13), (6512, 13), (6513, 13), (6514, 13), (6515, 13), (6516, 13), (6517, 13), (6518, 13), (6519, 13), (6520, 13), (6521, 13), (6522, 13), (6523, 13), (6524, 13), (6525, 13), (6526, 13), (6527, 13), (6528, 13), (6529, 13), (6530, 13), (6531, 13), (6532, 13), (6533, 13), (6534, 13), (6535, 13), (6536, 13), (6537, 13), (6538, 13), (6539, 13), (6540, 13), (6541, 13), (6542, 13), (6543, 13), (6544, 13), (6545, 13), (6546, 13), (6547, 13), (6548, 13), (6549, 13), (6550, 13), (6551, 13), (6552, 13), (6553, 13), (6554, 13), (6555, 13), (6556, 13), (6557, 13), (6558, 13), (6559, 13), (6560, 13), (6561, 13), (6562, 13), (6563, 13), (6564, 13), (6565, 13), (6566, 13), (6567, 13), (6568, 13), (6569, 13), (6570, 13), (6571, 13), (6572, 13), (6573, 13), (6574, 13), (6575, 13), (6576, 13), (6577, 13), (6578, 13), (6579, 13), (6580, 13), (6581, 13), (6582, 13), (6583, 13), (6584, 13), (6585, 13), (6586, 13), (6587, 13), (6588, 13), (6589, 13), (6590, 13), (6591, 13), (6592, 13), (6593, 13), (6594, 13), (6595, 13), (6596, 13), (6597, 13), (6598, 13), (6599, 13), (6600, 13), (6601, 13), (6602, 13), (6603, 13), (6604, 13), (6605, 13), (6606, 13), (6607, 13), (6608, 13), (6609, 13), (6610, 13), (6611, 13), (6612, 13), (6613, 13), (6614, 13), (6615, 13), (6616, 13), (6617, 13), (6618, 13), (6619, 13), (6620, 13), (6621, 13), (6622, 13), (6623, 13), (6624, 13), (6625, 13), (6626, 13), (6627, 13), (6628, 13), (6629, 13), (6630, 13), (6631, 13), (6632, 13), (6633, 13), (6634, 13), (6635, 13), (6636, 13), (6637, 13), (6638, 13), (6639, 13), (6640, 13), (6641, 13), (6642, 13), (6643, 13), (6644, 13), (6645, 13), (6646, 13), (6647, 13), (6648, 13), (6649, 13), (6650, 13), (6651, 13), (6652, 13), (6653, 13), (6654, 13), (6655, 13), (6656, 13), (6657, 13), (6658, 13), (6659, 13), (6660, 13), (6661, 13), (6662, 13), (6663, 13), (6664, 13), (6665, 13), (6666, 13), (6667, 13), (6668, 13), (6669, 13), (6670, 13), (6671, 13), (6672, 13), (6673, 13), (6674, 13), (6675, 13), (6676, 13), (6677, 13), (6678, 13), (6679, 13), (6680, 13), (6681, 13), (6682, 13), (6683, 13), (6684, 13), (6685, 13), (6686, 13), (6687, 13), (6688, 13), (6689, 13), (6690, 13), (6691, 13), (6692, 13), (6693, 13), (6694, 13), (6695, 13), (6696, 13), (6697, 13), (6698, 13), (6699, 13), (6700, 13), (6701, 13), (6702, 13), (6703, 13), (6704, 13), (6705, 13), (6706, 13), (6707, 13), (6708, 13), (6709, 13), (6710, 13), (6711, 13), (6712, 13), (6713, 13), (6714, 13), (6715, 13), (6716, 13), (6717, 13), (6718, 13), (6719, 13), (6720, 13), (6721, 13), (6722, 13), (6723, 13), (6724, 13), (6725, 13), (6726, 13), (6727, 13), (6728, 13), (6729, 13), (6730, 13), (6731, 13), (6732, 13), (6733, 13), (6734, 13), (6735, 13), (6736, 13), (6737, 13), (6738, 13), (6739, 13), (6740, 13), (6741, 13), (6742, 13), (6743, 13), (6744, 13), (6745, 13), (6746, 13), (6747, 13), (6748, 13), (6749, 13), (6750, 13), (6751, 13), (6752, 13), (6753, 13), (6754, 13), (6755, 13), (6756, 13), (6757, 13), (6758, 13), (6759, 13), (6760, 13), (6761, 13), (6762, 13), (6763, 13), (6764, 13), (6765, 13), (6766, 13), (6767, 13), (6768, 13), (6769, 13), (6770, 13), (6771, 13), (6772, 13), (6773, 13), (6774, 13), (6775, 13), (6776, 13), (6777, 13), (6778, 13), (6779, 13), (6780, 13), (6781, 13), (6782, 13), (6783, 13), (6784, 13), (6785, 13), (6786, 13), (6787, 13), (6788, 13), (6789, 13), (6790, 13), (6791, 13), (6792, 13), (6793, 13), (6794, 13), (6795, 13), (6796, 13), (6797, 13), (6798, 13), (6799, 13), (6800, 13), (6801, 13), (6802, 13), (6803, 13), (6804, 13), (6805, 13), (6806, 13), (6807, 13), (6808, 13), (6809, 13), (6810, 13), (6811, 13), (6812, 13), (6813, 13), (6814, 13), (6815, 13), (6816, 13), (6817, 13), (6818, 13), (6819, 13), (6820, 13), (6821, 13), (6822, 13), (6823, 13), (6824, 13), (6825, 13), (6826, 13), (6827, 13), (6828, 13), (6829, 13), (6830, 13), (6831, 13), (6832, 13), (6833, 13), (6834, 13), (6835, 13), (6836, 13), (6837, 13), (6838, 13), (6839, 13), (6840, 13), (6841, 13), (6842, 13), (6843, 13), (6844, 13), (6845, 13), (6846, 13), (6847, 13), (6848, 13), (6849, 13), (6850, 13), (6851, 13), (6852, 13), (6853, 13), (6854, 13), (6855, 13), (6856, 13), (6857, 13), (6858, 13), (6859, 13), (6860, 13), (6861, 13), (6862, 13), (6863, 13), (6864, 13), (6865, 13), (6866, 13), (6867, 13), (6868, 13), (6869, 13), (6870, 13), (6871, 13), (6872, 13), (6873, 13), (6874, 13), (6875, 13), (6876, 13), (6877, 13), (6878, 13), (6879, 13), (6880, 13), (6881, 13), (6882, 13), (6883, 13), (6884, 13), (6885, 13), (6886, 13), (6887, 13), (6888, 13), (6889, 13), (6890, 13), (6891, 13), (6892, 13), (6893, 13), (6894, 13), (6895, 13), (6896, 13), (6897, 13), (6898, 13), (6899, 13), (6900, 13), (6901, 13), (6902, 13), (6903, 13), (6904, 13), (6905, 13), (6906, 13), (6907, 13), (6908, 13), (6909, 13), (6910, 13), (6911, 13), (6912, 13), (6913, 13), (6914, 13), (6915, 13), (6916, 13), (6917, 13), (6918, 13), (6919, 13), (6920, 13), (6921, 13), (6922, 13), (6923, 13), (6924, 13), (6925, 13), (6926, 13), (6927, 13), (6928, 13), (6929, 13), (6930, 13), (6931, 13), (6932, 13), (6933, 13), (6934, 13), (6935, 13), (6936, 13), (6937, 13), (6938, 13), (6939, 13), (6940, 13), (6941, 13), (6942, 13), (6943, 13), (6944, 13), (6945, 13), (6946, 13), (6947, 13), (6948, 13), (6949, 13), (6950, 13), (6951, 13), (6952, 13), (6953, 13), (6954, 13), (6955, 13), (6956, 13), (6957, 13), (6958, 13), (6959, 13), (6960, 13), (6961, 13), (6962, 13), (6963, 13), (6964, 13), (6965, 13), (6966, 13), (6967, 13), (6968, 13), (6969, 13), (6970, 13), (6971, 13), (6972, 13), (6973, 13), (6974, 13), (6975, 13), (6976, 13), (6977, 13), (6978, 13), (6979, 13), (6980, 13), (6981, 13), (6982, 13), (6983, 13), (6984, 13), (6985, 13), (6986, 13), (6987, 13), (6988, 13), (6989, 13), (6990, 13), (6991, 13), (6992, 13), (6993, 13), (6994, 13), (6995, 13), (6996, 13), (6997, 13), (6998, 13), (6999, 13), (7000, 13), (7001, 13), (7002, 13), (7003, 13), (7004, 13), (7005, 13), (7006, 13), (7007, 13), (7008, 13), (7009, 13), (7010, 13), (7011, 13), (7012, 13), (7013, 13), (7014, 13), (7015, 13), (7016, 13), (7017, 13), (7018, 13), (7019, 13), (7020, 13), (7021, 13), (7022, 13), (7023, 13), (7024, 13), (7025, 13), (7026, 13), (7027, 13), (7028, 13), (7029, 13), (7030, 13), (7031, 13), (7032, 13), (7033, 13), (7034, 13), (7035, 13), (7036, 13), (7037, 13), (7038, 13), (7039, 13), (7040, 13), (7041, 13), (7042, 13), (7043, 13), (7044, 13), (7045, 13), (7046, 13), (7047, 13), (7048, 13), (7049, 13), (7050, 13), (7051, 13), (7052, 13), (7053, 13), (7054, 13), (7055, 13), (7056, 13), (7057, 13), (7058, 13), (7059, 13), (7060, 13), (7061, 13), (7062, 13), (7063, 13), (7064, 13), (7065, 13), (7066, 13), (7067, 13), (7068, 13), (7069, 13), (7070, 13), (7071, 13), (7072, 13), (7073, 13), (7074, 13), (7075, 13), (7076, 13), (7077, 13), (7078, 13), (7079, 13), (7080, 13), (7081, 13), (7082, 13), (7083, 13), (7084, 13), (7085, 13), (7086, 13), (7087, 13), (7088, 13), (7089, 13), (7090, 13), (7091, 13), (7092, 13), (7093, 13), (7094, 13), (7095, 13), (7096, 13), (7097, 13), (7098, 13), (7099, 13), (7100, 13), (7101, 13), (7102, 13), (7103, 13), (7104, 13), (7105, 13), (7106, 13), (7107, 13), (7108, 13), (7109, 13), (7110, 13), (7111, 13), (7112, 13), (7113, 13), (7114, 13), (7115, 13), (7116, 13), (7117, 13), (7118, 13), (7119, 13), (7120, 13), (7121, 13), (7122, 13), (7123, 13), (7124, 13), (7125, 13), (7126, 13), (7127, 13), (7128, 13), (7129, 13), (7130, 13), (7131, 13), (7132, 13), (7133, 13), (7134, 13), (7135, 13), (7136, 13), (7137, 13), (7138, 13), (7139, 13), (7140, 13), (7141, 13), (7142, 13), (7143, 13), (7144, 13), (7145, 13), (7146, 13), (7147, 13), (7148, 13), (7149, 13), (7150, 13), (7151, 13), (7152, 13), (7153, 13), (7154, 13), (7155, 13), (7156, 13), (7157, 13), (7158, 13), (7159, 13), (7160, 13), (7161, 13), (7162, 13), (7163, 13), (7164, 13), (7165, 13), (7166, 13), (7167, 13), (7168, 13), (7169, 13), (7170, 13), (7171, 13), (7172, 13), (7173, 13), (7174, 13), (7175, 13), (7176, 13), (7177, 13), (7178, 13), (7179, 13), (7180, 13), (7181, 13), (7182, 13), (7183, 13), (7184, 13), (7185, 13), (7186, 13), (7187, 13), (7188, 13), (7189, 13), (7190, 13), (7191, 13), (7192, 13), (7193, 13), (7194, 13), (7195, 13), (7196, 13), (7197, 13), (7198, 13), (7199, 13), (7200, 13), (7201, 13), (7202, 13), (7203, 13), (7204, 13), (7205, 13), (7206, 13), (7207, 13), (7208, 13), (7209, 13), (7210, 13), (7211, 13), (7212, 13), (7213, 13), (7214, 13), (7215, 13), (7216, 13), (7217, 13), (7218, 13), (7219, 13), (7220, 13), (7221, 13), (7222, 13), (7223, 13), (7224, 13), (7225, 13), (7226, 13), (7227, 13), (7228, 13), (7229, 13), (7230, 13), (7231, 13), (7232, 13), (7233, 13), (7234, 13), (7235, 13), (7236, 13), (7237, 13), (7238, 13), (7239, 13), (7240, 13), (7241, 13), (7242, 13), (7243, 13), (7244, 13), (7245, 13), (7246, 13), (7247, 13), (7248, 13), (7249, 13), (7250, 13), (7251, 13), (7252, 13), (7253, 13), (7254, 13), (7255, 13), (7256, 13), (7257, 13), (7258, 13), (7259, 13), (7260, 13), (7261, 13), (7262, 13), (7263, 13), (7264, 13), (7265, 13), (7266, 13), (7267, 13), (7268, 13), (7269, 13), (7270, 13), (7271, 13), (7272, 13), (7273, 13), (7274, 13), (7275, 13), (7276, 13), (7277, 13), (7278, 13), (7279, 13), (7280, 13), (7281, 13), (7282, 13), (7283, 13), (7284, 13), (7285, 13), (7286, 13), (7287, 13), (7288, 13), (7289, 13), (7290, 13), (7291, 13), (7292, 13), (7293, 13), (7294, 13), (7295, 13), (7296, 13), (7297, 13), (7298, 13), (7299, 13), (7300, 13), (7301, 13), (7302, 13), (7303, 13), (7304, 13), (7305, 13), (7306, 13), (7307, 13), (7308, 13), (7309, 13), (7310, 13), (7311, 13), (7312, 13), (7313, 13), (7314, 13), (7315, 13), (7316, 13), (7317, 13), (7318, 13), (7319, 13), (7320, 13), (7321, 13), (7322, 13), (7323, 13), (7324, 13), (7325, 13), (7326, 13), (7327, 13), (7328, 13), (7329, 13), (7330, 13), (7331, 13), (7332, 13), (7333, 13), (7334, 13), (7335, 13), (7336, 13), (7337, 13), (7338, 13), (7339, 13), (7340, 13), (7341, 13), (7342, 13), (7343, 13), (7344, 13), (7345, 13), (7346, 13), (7347, 13), (7348, 13), (7349, 13), (7350, 13), (7351, 13), (7352, 13), (7353, 13), (7354, 13), (7355, 13), (7356, 13), (7357, 13), (7358, 13), (7359, 13), (7360, 13), (7361, 13), (7362, 13), (7363, 13), (7364, 13), (7365, 13), (7366, 13), (7367, 13), (7368, 13), (7369, 13), (7370, 13), (7371, 13), (7372, 13), (7373, 13), (7374, 13), (7375, 13), (7376, 13), (7377, 13), (7378, 13), (7379, 13), (7380, 13), (7381, 13), (7382, 13), (7383, 13), (7384, 13), (7385, 13), (7386, 13), (7387, 13), (7388, 13), (7389, 13), (7390, 13), (7391, 13), (7392, 13), (7393, 13), (7394, 13), (7395, 13), (7396, 13), (7397, 13), (7398, 13), (7399, 13), (7400, 13), (7401, 13), (7402, 13), (7403, 13), (7404, 13), (7405, 13), (7406, 13), (7407, 13), (7408, 13), (7409, 13), (7410, 13), (7411, 13), (7412, 13), (7413, 13), (7414, 13), (7415, 13), (7416, 13), (7417, 13), (7418, 13), (7419, 13), (7420, 13), (7421, 13), (7422, 13), (7423, 13), (7424, 13), (7425, 13), (7426, 13), (7427, 13), (7428, 13), (7429, 13), (7430, 13), (7431, 13), (7432, 13), (7433, 13), (7434, 13), (7435, 13), (7436, 13), (7437, 13), (7438, 13), (7439, 13), (7440, 13), (7441, 13), (7442, 13), (7443, 13), (7444, 13), (7445, 13), (7446, 13), (7447, 13), (7448, 13), (7449, 13), (7450, 13), (7451, 13), (7452, 13), (7453, 13), (7454, 13), (7455, 13), (7456, 13), (7457, 13), (7458, 13), (7459, 13), (7460, 13), (7461, 13), (7462, 13), (7463, 13), (7464, 13), (7465, 13), (7466, 13), (7467, 13), (7468, 13), (7469, 13), (7470, 13), (7471, 13), (7472, 13), (7473, 13), (7474, 13), (7475, 13), (7476, 13), (7477, 13), (7478, 13), (7479, 13), (7480, 13), (7481, 13), (7482, 13), (7483, 13), (7484, 13), (7485, 13), (7486, 13), (7487, 13), (7488, 13), (7489, 13), (7490, 13), (7491, 13), (7492, 13), (7493, 13), (7494, 13), (7495, 13), (7496, 13), (7497, 13), (7498, 13), (7499, 13), (7500, 13), (7501, 13), (7502, 13), (7503, 13), (7504, 13), (7505, 13), (7506, 13), (7507, 13), (7508, 13), (7509, 13), (7510, 13), (7511, 13), (7512, 13), (7513, 13), (7514, 13), (7515, 13), (7516, 13), (7517, 13), (7518, 13), (7519, 13), (7520, 13), (7521, 13), (7522, 13), (7523, 13), (7524, 13), (7525, 13), (7526, 13), (7527, 13), (7528, 13), (7529, 13), (7530, 13), (7531, 13), (7532, 13), (7533, 13), (7534, 13), (7535, 13), (7536, 13), (7537, 13), (7538, 13), (7539, 13), (7540, 13), (7541, 13), (7542, 13), (7543, 13), (7544, 13), (7545, 13), (7546, 13), (7547, 13), (7548, 13), (7549, 13), (7550, 13), (7551, 13), (7552, 13), (7553, 13), (7554, 13), (7555, 13), (7556, 13), (7557, 13), (7558, 13), (7559, 13), (7560, 13), (7561, 13), (7562, 13), (7563, 13), (7564, 13), (7565, 13), (7566, 13), (7567, 13), (7568, 13), (7569, 13), (7570, 13), (7571, 13), (7572, 13), (7573, 13), (7574, 13), (7575, 13), (7576, 13), (7577, 13), (7578, 13), (7579, 13), (7580, 13), (7581, 13), (7582, 13), (7583, 13), (7584, 13), (7585, 13), (7586, 13), (7587, 13), (7588, 13), (7589, 13), (7590, 13), (7591, 13), (7592, 13), (7593, 13), (7594, 13), (7595, 13), (7596, 13), (7597, 13), (7598, 13), (7599, 13), (7600, 13), (7601, 13), (7602, 13), (7603, 13), (7604, 13), (7605, 13), (7606, 13), (7607, 13), (7608, 13), (7609, 13), (7610, 13), (7611, 13), (7612, 13), (7613, 13), (7614, 13), (7615, 13), (7616, 13), (7617, 13), (7618, 13), (7619, 13), (7620, 13), (7621, 13), (7622, 13), (7623, 13), (7624, 13), (7625, 13), (7626, 13), (7627, 13), (7628, 13), (7629, 13), (7630, 13), (7631, 13), (7632, 13), (7633, 13), (7634, 13), (7635, 13), (7636, 13), (7637, 13), (7638, 13), (7639, 13), (7640, 13), (7641, 13), (7642, 13), (7643, 13), (7644, 13), (7645, 13), (7646, 13), (7647, 13), (7648, 13), (7649, 13), (7650, 13), (7651, 13), (7652, 13), (7653, 13), (7654, 13), (7655, 13), (7656, 13), (7657, 13), (7658, 13), (7659, 13), (7660, 13), (7661, 13), (7662, 13), (7663, 13), (7664, 13), (7665, 13), (7666, 13), (7667, 13), (7668, 13), (7669, 13), (7670, 13), (7671, 13), (7672, 13), (7673, 13), (7674, 13), (7675, 13), (7676, 13), (7677, 13), (7678, 13), (7679, 13), (7680, 13), (7681, 13), (7682, 13), (7683, 13), (7684, 13), (7685, 13), (7686, 13), (7687, 13), (7688, 13), (7689, 13), (7690, 13), (7691, 13), (7692, 13), (7693, 13), (7694, 13), (7695, 13), (7696, 13), (7697, 13), (7698, 13), (7699, 13), (7700, 13), (7701, 13), (7702, 13), (7703, 13), (7704, 13), (7705, 13), (7706, 13), (7707, 13), (7708, 13), (7709, 13), (7710, 13), (7711, 13), (7712, 13), (7713, 13), (7714, 13), (7715, 13), (7716, 13), (7717, 13), (7718, 13), (7719, 13), (7720, 13), (7721, 13), (7722, 13), (7723, 13), (7724, 13), (7725, 13), (7726, 13), (7727, 13), (7728, 13), (7729, 13), (7730, 13), (7731, 13), (7732, 13), (7733, 13), (7734, 13), (7735, 13), (7736, 13), (7737, 13), (7738, 13), (7739, 13), (7740, 13), (7741, 13), (7742, 13), (7743, 13), (7744, 13), (7745, 13), (7746, 13), (7747, 13), (7748, 13), (7749, 13), (7750, 13), (7751, 13), (7752, 13), (7753, 13), (7754, 13), (7755, 13), (7756, 13), (7757, 13), (7758, 13), (7759, 13), (7760, 13), (7761, 13), (7762, 13), (7763, 13), (7764, 13), (7765, 13), (7766, 13), (7767, 13), (7768, 13), (7769, 13), (7770, 13), (7771, 13), (7772, 13), (7773, 13), (7774, 13), (7775, 13), (7776, 13), (7777, 13), (7778, 13), (7779, 13), (7780, 13), (7781, 13), (7782, 13), (7783, 13), (7784, 13), (7785, 13), (7786, 13), (7787, 13), (7788, 13), (7789, 13), (7790, 13), (7791, 13), (7792, 13), (7793, 13), (7794, 13), (7795, 13), (7796, 13), (7797, 13), (7798, 13), (7799, 13), (7800, 13), (7801, 13), (7802, 13), (7803, 13), (7804, 13), (7805, 13), (7806, 13), (7807, 13), (7808, 13), (7809, 13), (7810, 13), (7811, 13), (7812, 13), (7813, 13), (7814, 13), (7815, 13), (7816, 13), (7817, 13), (7818, 13), (7819, 13), (7820, 13), (7821, 13), (7822, 13), (7823, 13), (7824, 13), (7825, 13), (7826, 13), (7827, 13), (7828, 13), (7829, 13), (7830, 13), (7831, 13), (7832, 13), (7833, 13), (7834, 13), (7835, 13), (7836, 13), (7837, 13), (7838, 13), (7839, 13), (7840, 13), (7841, 13), (7842, 13), (7843, 13), (7844, 13), (7845, 13), (7846, 13), (7847, 13), (7848, 13), (7849, 13), (7850, 13), (7851, 13), (7852, 13), (7853, 13), (7854, 13), (7855, 13), (7856, 13), (7857, 13), (7858, 13), (7859, 13), (7860, 13), (7861, 13), (7862, 13), (7863, 13), (7864, 13), (7865, 13), (7866, 13), (7867, 13), (7868, 13), (7869, 13), (7870, 13), (7871, 13), (7872, 13), (7873, 13), (7874, 13), (7875, 13), (7876, 13), (7877, 13), (7878, 13), (7879, 13), (7880, 13), (7881, 13), (7882, 13), (7883, 13), (7884, 13), (7885, 13), (7886, 13), (7887, 13), (7888, 13), (7889, 13), (7890, 13), (7891, 13), (7892, 13), (7893, 13), (7894, 13), (7895, 13), (7896, 13), (7897, 13), (7898, 13), (7899, 13), (7900, 13), (7901, 13), (7902, 13), (7903, 13), (7904, 13), (7905, 13), (7906, 13), (7907, 13), (7908, 13), (7909, 13), (7910, 13), (7911, 13), (7912, 13), (7913, 13), (7914, 13), (7915, 13), (7916, 13), (7917, 13), (7918, 13), (7919, 13), (7920, 13), (7921, 13), (7922, 13), (7923, 13), (7924, 13), (7925, 13), (7926, 13), (7927, 13), (7928, 13), (7929, 13), (7930, 13), (7931, 13), (7932, 13), (7933, 13), (7934, 13), (7935, 13), (7936, 13), (7937, 13), (7938, 13), (7939, 13), (7940, 13), (7941, 13), (7942, 13), (7943, 13), (7944, 13), (7945, 13), (7946, 13), (7947, 13), (7948, 13), (7949, 13), (7950, 13), (7951, 13), (7952, 13), (7953, 13), (7954, 13), (7955, 13), (7956, 13), (7957, 13), (7958, 13), (7959, 13), (7960, 13), (7961, 13), (7962, 13), (7963, 13), (7964, 13), (7965, 13), (7966, 13), (7967, 13), (7968, 13), (7969, 13), (7970, 13), (7971, 13), (7972, 13), (7973, 13), (7974, 13), (7975, 13), (7976, 13), (7977, 13), (7978, 13), (7979, 13), (7980, 13), (7981, 13), (7982, 13), (7983, 13), (7984, 13), (7985, 13), (7986, 13), (7987, 13), (7988, 13), (7989, 13), (7990, 13), (7991, 13), (7992, 13), (7993, 13), (7994, 13), (7995, 13), (7996, 13), (7997, 13), (7998, 13), (7999, 13), (8000, 13), (8001, 13), (8002, 13), (8003, 13), (8004, 13), (8005, 13), (8006, 13), (8007, 13), (8008, 13), (8009, 13), (8010, 13), (8011, 13), (8012, 13), (8013, 13), (8014, 13), (8015, 13), (8016, 13), (8017, 13), (8018, 13), (8019, 13), (8020, 13), (8021, 13), (8022, 13), (8023, 13), (8024, 13), (8025, 13), (8026, 13), (8027, 13), (8028, 13), (8029, 13), (8030, 13), (8031, 13), (8032, 13), (8033, 13), (8034, 13), (8035, 13), (8036, 13), (8037, 13), (8038, 13), (8039, 13), (8040, 13), (8041, 13), (8042, 13), (8043, 13), (8044, 13), (8045, 13), (8046, 13), (8047, 13), (8048, 13), (8049, 13), (8050, 13), (8051, 13), (8052, 13), (8053, 13), (8054, 13), (8055, 13), (8056, 13), (8057, 13), (8058, 13), (8059, 13), (8060, 13), (8061, 13), (8062, 13), (8063, 13), (8064, 13), (8065, 13), (8066, 13), (8067, 13), (8068, 13), (8069, 13), (8070, 13), (8071, 13), (8072, 13), (8073, 13), (8074, 13), (8075, 13), (8076, 13), (8077, 13), (8078, 13), (8079, 13), (8080, 13), (8081, 13), (8082, 13), (8083, 13), (8084, 13), (8085, 13), (8086, 13), (8087, 13), (8088, 13), (8089, 13), (8090, 13), (8091, 13), (8092, 13), (8093, 13), (8094, 13), (8095, 13), (8096, 13), (8097, 13), (8098, 13), (8099, 13), (8100, 13), (8101, 13), (8102, 13), (8103, 13), (8104, 13), (8105, 13), (8106, 13), (8107, 13), (8108, 13), (8109, 13), (8110, 13), (8111, 13), (8112, 13), (8113, 13), (8114, 13), (8115, 13), (8116, 13), (8117, 13), (8118, 13), (8119, 13), (8120, 13), (8121, 13), (8122, 13), (8123, 13), (8124, 13), (8125, 13), (8126, 13), (8127, 13), (8128, 13), (8129, 13), (8130, 13), (8131, 13), (8132, 13), (8133, 13), (8134, 13), (8135, 13), (8136, 13), (8137, 13), (8138, 13), (8139, 13), (8140, 13), (8141, 13), (8142, 13), (8143, 13), (8144, 13), (8145, 13), (8146, 13), (8147, 13), (8148, 13), (8149, 13), (8150, 13), (8151, 13), (8152, 13), (8153, 13), (8154, 13), (8155, 13), (8156, 13), (8157, 13), (8158, 13), (8159, 13), (8160, 13), (8161, 13), (8162, 13), (8163, 13), (8164, 13), (8165, 13), (8166, 13), (8167, 13), (8168, 13), (8169, 13), (8170, 13), (8171, 13), (8172, 13), (8173, 13), (8174, 13), (8175, 13), (8176, 13), (8177, 13), (8178, 13), (8179, 13), (8180, 13), (8181, 13), (8182, 13), (8183, 13), (8184, 13), (8185, 13), (8186, 13), (8187, 13), (8188, 13), (8189, 13), (8190, 13)];
@@ -1,25 +1,35 @@
 use std::collections::{HashMap, VecDeque};
 
-use crate::{png::CompressionLevel, simd_utils::number_of_matching_bytes};
+use crate::simd_utils::number_of_matching_bytes;
 
 use super::backreference::LZSS_MAX_LENGTH;
 
 #[derive(Clone)]
 pub struct LzssHashTable {
     map: HashMap<u32, VecDeque<(usize, usize)>>,
-    compression_level: CompressionLevel,
+    // how many positions are kept for each key, the oldest are dropped first
+    max_chain_length: usize,
+    // a match at least this long ends the search, even if an older position would be longer
+    nice_match_length: usize,
+    // whether far matches count as a byte shorter, since their distance takes more bits
+    prefers_close_matches: bool,
 }
 
-const MAX_SMALL_CHAIN_SIZE: usize = 10;
-
 impl LzssHashTable {
-    pub fn new(compression_level: CompressionLevel) -> Self {
+    pub fn new(max_chain_length: usize, prefers_close_matches: bool) -> Self {
         Self {
             map: HashMap::new(),
-            compression_level,
+            max_chain_length,
+            nice_match_length: LZSS_MAX_LENGTH,
+            prefers_close_matches,
         }
     }
 
+    pub fn with_nice_match_length(mut self, nice_match_length: usize) -> Self {
+        self.nice_match_length = nice_match_length;
+        self
+    }
+
     pub fn search(
         &self,
         whole_input: &[u8],
@@ -28,27 +38,38 @@ impl LzssHashTable {
     ) -> Option<(u16, u16)> {
         let key = Self::get_key(whole_input, cursor)?;
         let chain = self.map.get(&key)?;
-        let (index, length) = chain
-            .iter()
-            .filter(|(idx, _)| *idx < cursor && *idx >= window_start_index)
-            .map(|(idx, _)| {
-                (
-                    idx,
-                    number_of_matching_bytes(
-                        &whole_input[cursor..(cursor + LZSS_MAX_LENGTH).min(whole_input.len())],
-                        &whole_input[*idx..(cursor + LZSS_MAX_LENGTH).min(whole_input.len())],
-                    ),
-                )
-            })
-            .max_by_key(|(idx, length)| match self.compression_level {
-                CompressionLevel::Best => {
+        let mut best_match: Option<(usize, usize, usize)> = None;
+
+        // newest first, so of two equally good matches the closer one is kept
+        for (idx, _) in chain.iter().rev() {
+            if *idx >= cursor || *idx < window_start_index {
+                continue;
+            }
+
+            let length = number_of_matching_bytes(
+                &whole_input[cursor..(cursor + LZSS_MAX_LENGTH).min(whole_input.len())],
+                &whole_input[*idx..(cursor + LZSS_MAX_LENGTH).min(whole_input.len())],
+            );
+            let score = match self.prefers_close_matches {
+                true => {
                     let distance = cursor - *idx;
                     let penalty = if distance > 2048 { 1 } else { 0 };
 
-                    *length - penalty
+                    length - penalty
                 }
-                _ => *length,
-            })?;
+                false => length,
+            };
+
+            if best_match.is_none_or(|(_, _, best_score)| score > best_score) {
+                best_match = Some((*idx, length, score));
+            }
+
+            if length >= self.nice_match_length {
+                break;
+            }
+        }
+
+        let (index, length, _score) = best_match?;
 
         let backreference = ((cursor - index) as u16, length as u16);
 
@@ -76,13 +97,8 @@ impl LzssHashTable {
             Some(chain) => {
                 chain.push_back((cursor, first_byte_repeat_count));
 
-                match self.compression_level {
-                    CompressionLevel::Best => {}
-                    _ => {
-                        if chain.len() > MAX_SMALL_CHAIN_SIZE {
-                            chain.pop_front();
-                        }
-                    }
+                if chain.len() > self.max_chain_length {
+                    chain.pop_front();
                 }
             }
         }
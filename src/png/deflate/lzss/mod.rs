@@ -5,18 +5,17 @@ mod hash;
 use hash::first_byte_repeat_count;
 pub use hash::LzssHashTable;
 
-use std::{collections::HashMap, iter::repeat_n};
+use std::collections::HashMap;
 
 use backreference::{
     DISTANCE_CODE_TO_EXTRA_BITS, DISTANCE_TO_CODE, DISTANCE_TO_EXTRA_BITS,
     LENGTH_CODE_TO_EXTRA_BITS, LENGTH_TO_CODE, LENGTH_TO_EXTRA_BITS, LZSS_DISTANCE_CODES,
-    LZSS_MAX_LENGTH, LZSS_NUMBER_OF_DISTANCES, LZSS_NUMBER_OF_LENGHTS, LZSS_NUMBER_OF_LENGTH_CODES,
-    LZSS_NUMBER_OF_LITERALS,
+    LZSS_MAX_LENGTH, LZSS_MIN_LENGTH, LZSS_NUMBER_OF_DISTANCES, LZSS_NUMBER_OF_LENGHTS,
+    LZSS_NUMBER_OF_LENGTH_CODES, LZSS_NUMBER_OF_LITERALS,
 };
 
 use crate::{
-    png::{deflate::encode_block_type_two, CompressionLevel},
-    simd_utils::number_of_matching_bytes,
+    error::ErrorKind, png::deflate::encode_block_type_two, simd_utils::number_of_matching_bytes,
 };
 
 use super::{
@@ -34,39 +33,92 @@ pub enum LzssSymbol {
     EndOfBlock,
 }
 
-pub fn encode_lzss_greedy(bytes: &[u8], compression_level: CompressionLevel) -> Vec<LzssSymbol> {
-    let mut table = LzssHashTable::new(compression_level);
-    let mut cursor = 0;
-    let mut stream = Vec::with_capacity(bytes.len() / 2);
+// the first history_length bytes were already compressed, they get no symbols of their own but
+// backreferences can still point into them.
+// with a lazy_match_depth of 0 every match is taken as soon as it is found. otherwise the next
+// positions are searched as well, and if one of them starts a longer match the bytes before it are
+// written as literals instead. the depth has to stay below the shortest match length, so that the
+// positions looked ahead at are never searched again
+pub fn encode_lzss_lazy(
+    bytes: &[u8],
+    history_length: usize,
+    mut table: LzssHashTable,
+    lazy_match_depth: usize,
+) -> Vec<LzssSymbol> {
+    assert!(
+        lazy_match_depth < LZSS_MIN_LENGTH,
+        "lazy match depth {lazy_match_depth} must be below {LZSS_MIN_LENGTH}"
+    );
+
+    let mut cursor = history_length;
+    let mut stream = Vec::with_capacity((bytes.len() - history_length) / 2);
+
+    for idx in history_length.saturating_sub(LZSS_WINDOW_SIZE)..history_length {
+        if idx + 2 < bytes.len() {
+            table.insert(idx, bytes, first_byte_repeat_count(&bytes[idx..]));
+        }
+    }
 
     while cursor < bytes.len() {
-        stream.push(
-            match find_backreference_with_table(bytes, cursor, LZSS_WINDOW_SIZE, &mut table) {
-                Some(backreference) => {
-                    let symbol = LzssSymbol::Backreference(backreference.0, backreference.1);
-                    cursor += backreference.1 as usize;
+        let Some(mut backreference) =
+            find_backreference_with_table(bytes, cursor, LZSS_WINDOW_SIZE, &mut table)
+        else {
+            stream.push(LzssSymbol::Literal(bytes[cursor]));
+            cursor += 1;
+            continue;
+        };
+        let mut literal_count = 0;
 
-                    symbol
-                }
-                None => {
-                    let symbol = LzssSymbol::Literal(bytes[cursor]);
-                    cursor += 1;
+        for lookahead in 1..=lazy_match_depth {
+            if backreference.1 as usize == LZSS_MAX_LENGTH || cursor + lookahead >= bytes.len() {
+                break;
+            }
 
-                    symbol
+            let later_backreference = find_backreference_with_table(
+                bytes,
+                cursor + lookahead,
+                LZSS_WINDOW_SIZE,
+                &mut table,
+            );
+
+            if let Some(later_backreference) = later_backreference {
+                if later_backreference.1 > backreference.1 {
+                    backreference = later_backreference;
+                    literal_count = lookahead;
                 }
-            },
+            }
+        }
+
+        stream.extend(
+            bytes[cursor..cursor + literal_count]
+                .iter()
+                .map(|byte| LzssSymbol::Literal(*byte)),
         );
+        stream.push(LzssSymbol::Backreference(backreference.0, backreference.1));
+        cursor += literal_count + backreference.1 as usize;
     }
 
     stream
 }
 
-pub fn encode_lzss_optimized(bytes: &[u8]) -> Vec<LzssSymbol> {
-    let mut lzss_symbols = encode_lzss_greedy(bytes, CompressionLevel::Best);
-    // print!("Initial ");
-    // symbol_stats(&lzss_symbols);
+// starts from matches picked greedily, then repeatedly picks the cheapest symbols for the prefix
+// codes the previous symbols would get. a round can come out bigger than the one before, so the
+// smallest one is kept
+pub fn encode_lzss_optimized(
+    bytes: &[u8],
+    history_length: usize,
+    iterations: usize,
+) -> Vec<LzssSymbol> {
+    let mut lzss_symbols = encode_lzss_lazy(
+        bytes,
+        history_length,
+        LzssHashTable::new(usize::MAX, true),
+        0,
+    );
     // println!("initial lzss symbols {:?}", lzss_symbols);
-    for _i in 0..1 {
+    let mut best_lzss_symbols: Option<(usize, Vec<LzssSymbol>)> = None;
+
+    for _i in 0..iterations {
         let (ll_code_lengths, distance_code_lengths) =
             generate_prefix_codes_from_lzss_stream(append_end_of_block(&lzss_symbols));
         // println!(
@@ -80,15 +132,28 @@ pub fn encode_lzss_optimized(bytes: &[u8]) -> Vec<LzssSymbol> {
         let distance_encoding_costs = construct_distance_encoding_costs(&distance_code_lengths);
         lzss_symbols = encode_lzss_iteration(
             bytes,
+            history_length,
             literal_encoding_costs,
             lengths_encoding_costs,
             distance_encoding_costs,
         );
-        // print!("Round {_i} ");
-        // symbol_stats(&lzss_symbols);
+
+        let encoded_length = encode_block_type_two(&lzss_symbols, 0, true)
+            .bitstream
+            .len();
+
+        if best_lzss_symbols
+            .as_ref()
+            .is_none_or(|(best_length, _)| encoded_length < *best_length)
+        {
+            best_lzss_symbols = Some((encoded_length, lzss_symbols.clone()));
+        }
     }
 
-    lzss_symbols
+    match best_lzss_symbols {
+        Some((_encoded_length, best_lzss_symbols)) => best_lzss_symbols,
+        None => lzss_symbols,
+    }
 }
 
 fn construct_literal_encoding_costs(
@@ -184,38 +249,9 @@ fn construct_distance_encoding_costs(
     distance_encoding_costs
 }
 
-fn table_to_vec(t: &HashMap<u16, u32>, size: usize) -> Vec<Option<u32>> {
-    let mut result: Vec<Option<u32>> = repeat_n(None, size).collect();
-
-    for (k, v) in t {
-        result[*k as usize] = Some(*v);
-    }
-
-    result
-}
-
-pub fn symbol_stats(lzss_symbols: &[LzssSymbol]) {
-    let compressed = encode_block_type_two(lzss_symbols, 0, true)
-        .bitstream
-        .flush_to_bytes();
-    let mut lits = 0;
-    let mut bfs = 0;
-    for sym in lzss_symbols {
-        match sym {
-            LzssSymbol::Literal(_) => lits += 1,
-            LzssSymbol::Backreference(_, _) => bfs += 1,
-            LzssSymbol::EndOfBlock => {}
-        }
-    }
-    println!(
-        "total len {},total symbols {} lits {lits}, bfs {bfs}",
-        compressed.len(),
-        lzss_symbols.len()
-    );
-}
-
 pub fn encode_lzss_iteration(
     bytes: &[u8],
+    history_length: usize,
     literal_encoding_costs: [u32; LZSS_NUMBER_OF_LITERALS],
     lengths_encoding_costs: [u32; LZSS_NUMBER_OF_LENGHTS],
     distance_encoding_costs: [u32; LZSS_NUMBER_OF_DISTANCES],
@@ -228,20 +264,21 @@ pub fn encode_lzss_iteration(
     //and how costly the bytestream is to encode from that point
     let mut best_symbol_costs: Vec<(u32, LzssSymbol)> = vec![(0, LzssSymbol::EndOfBlock)];
 
-    let mut lzss_table = LzssHashTable::new(CompressionLevel::Best);
-    for i in ((bytes.len().max(LZSS_WINDOW_SIZE) - LZSS_WINDOW_SIZE)..(bytes.len() - 2)).rev() {
+    let mut lzss_table = LzssHashTable::new(usize::MAX, true);
+    for i in (bytes.len().saturating_sub(LZSS_WINDOW_SIZE)..bytes.len().saturating_sub(2)).rev() {
         lzss_table.insert(i, bytes, first_byte_repeat_count(&bytes[i..]));
     }
 
+    let number_of_new_bytes = bytes.len() - history_length;
     let mut total_cost = 0;
     //the last two bytes cannot be a backreference since it must have at least length 3
-    for cost_list_index in 1..=2 {
+    for cost_list_index in 1..=number_of_new_bytes.min(2) {
         let byte = &bytes[bytes.len() - cost_list_index];
         total_cost += literal_encoding_costs[*byte as usize];
         best_symbol_costs.push((total_cost, LzssSymbol::Literal(*byte)));
     }
 
-    for cost_list_index in 3..=bytes.len() {
+    for cost_list_index in 3..=number_of_new_bytes {
         let bytes_index = bytes.len() - cost_list_index;
         let byte = &bytes[bytes_index];
 
@@ -250,8 +287,6 @@ pub fn encode_lzss_iteration(
 
         let mut best_bf = (0, 0);
         let mut best_bf_cost = literal_cost;
-        let mut last_idx = usize::MAX;
-        let mut current_bf = (0, 0);
 
         if let Some(chain) = lzss_table.get_all_backreferences(bytes, bytes_index) {
             let max_match_end = (bytes_index + LZSS_MAX_LENGTH).min(bytes.len());
@@ -260,23 +295,20 @@ pub fn encode_lzss_iteration(
             let current_repeating_bytes = first_byte_repeat_count(cursor_slice);
 
             for (idx, match_repeating_bytes) in chain {
-                if *idx + 1 == last_idx {
-                    current_bf.0 += 1;
-                } else {
-                    let bf_length = match current_repeating_bytes.cmp(match_repeating_bytes) {
-                        std::cmp::Ordering::Less => current_repeating_bytes,
-                        std::cmp::Ordering::Equal => {
-                            current_repeating_bytes
-                                + number_of_matching_bytes(
-                                    &cursor_slice[current_repeating_bytes..],
-                                    &to_end[(*idx + current_repeating_bytes)..max_match_end],
-                                )
-                        }
-                        std::cmp::Ordering::Greater => *match_repeating_bytes,
-                    };
-                    current_bf = ((bytes_index - *idx) as u16, bf_length as u16)
+                //both positions start with a run of the same byte, so only the shorter run can
+                //end the match early, and the rest needs to be compared only when they are equal
+                let bf_length = match current_repeating_bytes.cmp(match_repeating_bytes) {
+                    std::cmp::Ordering::Less => current_repeating_bytes,
+                    std::cmp::Ordering::Equal => {
+                        current_repeating_bytes
+                            + number_of_matching_bytes(
+                                &cursor_slice[current_repeating_bytes..],
+                                &to_end[(*idx + current_repeating_bytes)..max_match_end],
+                            )
+                    }
+                    std::cmp::Ordering::Greater => *match_repeating_bytes,
                 };
-                last_idx = *idx;
+                let current_bf = ((bytes_index - *idx) as u16, bf_length as u16);
 
                 let bf_end_cost: u32 = best_symbol_costs[cost_list_index - current_bf.1 as usize].0;
 
@@ -372,9 +404,12 @@ pub fn decode_lzss(
                 let backreference_data_start = match target.len().checked_sub(*distance as usize) {
                     Some(n) => n,
                     None => {
-                        return Err(DeflateDecodeError(format!(
-                        "Invalid backreference for lzss symbol {i}, distance {distance} is too big"
-                    )))
+                        return Err(DeflateDecodeError::new(
+                            ErrorKind::InvalidData,
+                            format!(
+                                "Invalid backreference for lzss symbol {i}, distance {distance} is too big"
+                            ),
+                        ))
                     }
                 };
 
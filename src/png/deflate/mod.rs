@@ -1,31 +1,40 @@
 pub mod bitstream;
 mod consts;
 pub mod decode;
+pub mod gzip;
 pub mod huffman;
 pub mod lzss;
 pub mod prefix_table;
+mod reader;
+mod writer;
 pub mod zlib;
 
-use std::{collections::HashMap, iter, time::Instant};
+use std::{collections::HashMap, iter, mem};
 
 use bitstream::WriteBitStream;
 use consts::{
-    CL_ALPHABET, END_OF_BLOCK_MARKER_VALUE, MAX_CL_CODE_LENGTH, MAX_SYMBOL_CODE_LENGTH,
-    MAX_UNCOMPRESSED_BLOCK_SIZE,
+    BLOCK_INPUT_SIZE, CL_ALPHABET, END_OF_BLOCK_MARKER_VALUE, MAX_CL_CODE_LENGTH,
+    MAX_SYMBOL_CODE_LENGTH, MAX_UNCOMPRESSED_BLOCK_SIZE, WINDOW_SIZE,
+};
+pub use decode::{decode_deflate, DeflateDecodeError, DeflateInflater};
+pub use gzip::{
+    decode_gzip, decode_gzip_members, encode_gzip, GzipEncoder, GzipHeader, GzipInflater,
+    GzipMember,
 };
-use decode::DeflateDecodeError;
 use huffman::{construct_canonical_tree_from_lengths, package_merge::PackageMergeEncoder};
 use lzss::{
-    backreference::{DISTANCE_TO_CODE, LENGTH_TO_CODE},
-    encode_lzss_greedy, encode_lzss_optimized, encode_lzss_to_bitstream, LzssSymbol,
+    backreference::{DISTANCE_TO_CODE, LENGTH_TO_CODE, LZSS_MAX_LENGTH},
+    encode_lzss_lazy, encode_lzss_optimized, encode_lzss_to_bitstream, LzssHashTable, LzssSymbol,
 };
 use prefix_table::{
     generate_static_distance_table, generate_static_lit_len_table, get_cl_codes_for_code_lengths,
     number_of_zero_symbols_at_end,
 };
-use zlib::{decode_zlib, ZlibEncoder};
+pub use reader::{DeflateReader, GzipReader, ZlibReader};
+pub use writer::{DeflateWriter, GzipWriter, ZlibWriter};
+pub use zlib::{decode_zlib, decode_zlib_lenient, ZlibEncoder, ZlibInflater};
 
-use crate::png_assert;
+use crate::{error::ErrorKind, png_assert};
 
 use super::{CompressionLevel, PngParseError};
 
@@ -48,8 +57,7 @@ pub fn uncompress_scanlines(
     width: usize,
     bits_per_pixel: usize,
 ) -> Result<Vec<Vec<u8>>, PngParseError> {
-    let uncompressed_data =
-        decode_zlib(data).map_err(|deflate_err| PngParseError(deflate_err.0))?;
+    let uncompressed_data = decode_zlib(data)?;
 
     let filter_byte_size = 1;
     let bytes_per_scanline = filter_byte_size + ((width * bits_per_pixel) >> 3);
@@ -93,112 +101,276 @@ impl DeflateBlockType {
             1 => DeflateBlockType::FixedHuffman,
             2 => DeflateBlockType::DynamicHuffman,
             n => {
-                return Err(DeflateDecodeError(format!(
-                    "Unrecognized deflate block type - {n}"
-                )))
+                return Err(DeflateDecodeError::new(
+                    ErrorKind::InvalidData,
+                    format!("Unrecognized deflate block type - {n}"),
+                ))
             }
         })
     }
 }
 
+// how hard the encoder works at a compression level, like zlib's configuration table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressionParameters {
+    // how many earlier positions starting with the same bytes are compared for each match
+    pub max_chain_length: usize,
+    // a match at least this long is taken without comparing the older positions
+    pub nice_match_length: usize,
+    // how many following positions are searched for a longer match before taking one, 0 for none
+    pub lazy_match_depth: usize,
+    // rounds of picking the cheapest symbols for the prefix codes, replacing the search above
+    pub optimal_parsing_iterations: usize,
+    // how many pieces the symbols are cut into when looking for better block boundaries, 1 for a
+    // single block
+    pub block_split_count: usize,
+}
+
+impl CompressionParameters {
+    pub fn for_compression_level(compression_level: CompressionLevel) -> Self {
+        let (
+            max_chain_length,
+            nice_match_length,
+            lazy_match_depth,
+            optimal_parsing_iterations,
+            block_split_count,
+        ) = match compression_level.level() {
+            0 => (0, LZSS_MAX_LENGTH, 0, 0, 1),
+            1 => (4, 8, 0, 0, 1),
+            2 => (8, 16, 0, 0, 1),
+            3 => (32, 32, 0, 0, 1),
+            4 => (16, LZSS_MAX_LENGTH, 1, 0, 1),
+            5 => (32, LZSS_MAX_LENGTH, 1, 0, 4),
+            6 => (64, LZSS_MAX_LENGTH, 1, 0, 8),
+            7 => (128, LZSS_MAX_LENGTH, 2, 0, 16),
+            8 => (256, LZSS_MAX_LENGTH, 2, 0, 32),
+            9 => (usize::MAX, LZSS_MAX_LENGTH, 0, 1, 100),
+            _ => (usize::MAX, LZSS_MAX_LENGTH, 0, 3, 100),
+        };
+
+        Self {
+            max_chain_length,
+            nice_match_length,
+            lazy_match_depth,
+            optimal_parsing_iterations,
+            block_split_count,
+        }
+    }
+}
+
+// compresses bytes as they are written, a block at a time, so only the window that later blocks can
+// refer back to and the bytes of the next block are kept in memory
 pub struct DeflateEncoder {
     compression_level: CompressionLevel,
+    // the last bytes of the window followed by the bytes written since the last block
     bytes: Vec<u8>,
+    history_length: usize,
+    // the part of the stream that has been compressed but not returned yet
+    bitstream: WriteBitStream,
 }
 
 impl DeflateEncoder {
     pub fn new(compression_level: CompressionLevel) -> Self {
         Self {
             bytes: vec![],
+            history_length: 0,
+            bitstream: WriteBitStream::new(),
             compression_level,
         }
     }
 
-    pub fn write_bytes(&mut self, bytes: &[u8]) {
-        self.bytes.extend_from_slice(bytes);
+    pub fn write_bytes(&mut self, mut bytes: &[u8]) {
+        let block_input_size = self.block_input_size();
+
+        while !bytes.is_empty() {
+            let free_space = block_input_size - (self.bytes.len() - self.history_length);
+            let (block_bytes, rest) = bytes.split_at(free_space.min(bytes.len()));
+            self.bytes.extend_from_slice(block_bytes);
+            bytes = rest;
+
+            if self.bytes.len() - self.history_length == block_input_size {
+                self.encode_blocks(false);
+            }
+        }
     }
 
+    // the complete bytes of every block finished so far
+    pub fn take_compressed_bytes(&mut self) -> Vec<u8> {
+        self.bitstream.take_bytes()
+    }
+
+    // ends the current block and aligns the stream with an empty stored block, so everything
+    // written so far can be decompressed from the returned bytes
+    pub fn sync_flush(&mut self) -> Vec<u8> {
+        self.encode_blocks(false);
+        push_is_last(&mut self.bitstream, false);
+        self.bitstream
+            .push_u8_rtl(DeflateBlockType::None.to_number(), 2);
+        self.bitstream.align_to_next_byte();
+        self.bitstream.push_u16_ltr_le(0);
+        self.bitstream.push_u16_ltr_le(!0);
+
+        self.bitstream.take_bytes()
+    }
+
+    // a sync flush after which nothing refers back to the earlier bytes, so decompression can also
+    // start from here
+    pub fn full_flush(&mut self) -> Vec<u8> {
+        let bytes = self.sync_flush();
+        self.bytes.clear();
+        self.history_length = 0;
+
+        bytes
+    }
+
+    // ends the stream, returning everything that was not taken yet. the encoder can then be used
+    // for a new stream
     pub fn finish(&mut self) -> WriteBitStream {
+        self.encode_blocks(true);
+        self.bytes.clear();
+        self.history_length = 0;
+
+        mem::take(&mut self.bitstream)
+    }
+
+    fn block_input_size(&self) -> usize {
         match self.compression_level {
-            CompressionLevel::None => encode_block_type_zero(&self.bytes, 0, true).bitstream,
-            CompressionLevel::Best => {
-                let start = Instant::now();
-                let lzss_symbols = encode_lzss_optimized(&self.bytes);
-                let after_lzss = Instant::now();
-                let mut compressed = WriteBitStream::new();
-                let mut last_block = EncodedBlock {
-                    start_index: 0,
-                    block_type: DeflateBlockType::DynamicHuffman,
-                    bitstream: WriteBitStream::new(),
-                };
-                let chunk_size = if lzss_symbols.len() > 1000 {
-                    lzss_symbols.len() / 100
-                } else {
-                    lzss_symbols.len()
-                };
-
-                for (chunk_num, chunk) in lzss_symbols.chunks(chunk_size).enumerate() {
-                    let chunk_start_index = chunk_num * chunk_size;
-                    let is_last_chunk = (chunk_num + 1) * chunk_size >= lzss_symbols.len();
-                    let chunk_end_index = chunk_start_index + chunk.len();
-                    let chunk_encoded_alone = smaller_block(
-                        encode_block_type_two(
-                            &lzss_symbols[chunk_start_index..chunk_end_index],
-                            chunk_start_index,
-                            is_last_chunk,
-                        ),
-                        encode_block_type_one(
-                            &lzss_symbols[chunk_start_index..chunk_end_index],
-                            chunk_start_index,
-                            is_last_chunk,
-                        ),
-                    );
-                    let added_to_last_block = match last_block.block_type {
-                        DeflateBlockType::FixedHuffman => encode_block_type_one(
-                            &lzss_symbols[last_block.start_index..chunk_end_index],
-                            last_block.start_index,
-                            is_last_chunk,
-                        ),
-                        DeflateBlockType::DynamicHuffman => encode_block_type_two(
-                            &lzss_symbols[last_block.start_index..chunk_end_index],
-                            last_block.start_index,
-                            is_last_chunk,
-                        ),
-                        //TODO: sometimes it should
-                        _ => panic!("Block type should never be 0"),
-                    };
-
-                    if added_to_last_block.bitstream.len() - last_block.bitstream.len()
-                        > chunk_encoded_alone.bitstream.len()
-                    {
-                        compressed.extend(&last_block.bitstream);
-                        last_block = chunk_encoded_alone;
-                        last_block.start_index = chunk_start_index;
-                    } else {
-                        last_block = added_to_last_block;
-                    }
-                }
-
-                compressed.extend(&last_block.bitstream);
-                let after_encode = Instant::now();
-                println!("lzss time {:?}", after_lzss - start);
-                println!("encode time {:?}", after_encode - after_lzss);
-
-                compressed
-            }
-            CompressionLevel::Fast => {
-                let lzss_symbols = encode_lzss_greedy(&self.bytes, self.compression_level);
-                encode_block_type_two(&lzss_symbols, 0, true).bitstream
-            }
+            CompressionLevel::NONE => MAX_UNCOMPRESSED_BLOCK_SIZE as usize,
+            _ => BLOCK_INPUT_SIZE,
         }
     }
+
+    // compresses the bytes written since the last block, keeping the end of them as the window
+    fn encode_blocks(&mut self, is_last: bool) {
+        let bitstream = self.encode_new_bytes(is_last);
+        self.bitstream.extend(&bitstream);
+
+        let excess_length = self.bytes.len().saturating_sub(WINDOW_SIZE);
+        self.bytes.drain(..excess_length);
+        self.history_length = self.bytes.len();
+    }
+
+    fn encode_new_bytes(&self, is_last: bool) -> WriteBitStream {
+        let history_length = self.history_length;
+        let bytes = &self.bytes[..];
+
+        if bytes.len() == history_length {
+            // the last block flag needs a block even when there is nothing left to put in it
+            return match (is_last, self.compression_level) {
+                (false, _) => WriteBitStream::new(),
+                (true, CompressionLevel::NONE) => encode_block_type_zero(&[], 0, true).bitstream,
+                (true, _) => encode_block_type_one(&[], 0, true).bitstream,
+            };
+        }
+
+        if self.compression_level == CompressionLevel::NONE {
+            return encode_block_type_zero(&bytes[history_length..], 0, is_last).bitstream;
+        }
+
+        let parameters = CompressionParameters::for_compression_level(self.compression_level);
+
+        if parameters.optimal_parsing_iterations == 0 {
+            let table = LzssHashTable::new(parameters.max_chain_length, false)
+                .with_nice_match_length(parameters.nice_match_length);
+            let lzss_symbols =
+                encode_lzss_lazy(bytes, history_length, table, parameters.lazy_match_depth);
+
+            return encode_split_blocks(&lzss_symbols, is_last, parameters.block_split_count);
+        }
+
+        let lzss_symbols =
+            encode_lzss_optimized(bytes, history_length, parameters.optimal_parsing_iterations);
+
+        encode_split_blocks(&lzss_symbols, is_last, parameters.block_split_count)
+    }
+}
+
+// cuts the symbols into about block_split_count pieces and joins neighbouring pieces into one block
+// whenever that is smaller than starting a new one
+fn encode_split_blocks(
+    lzss_symbols: &[LzssSymbol],
+    is_last: bool,
+    block_split_count: usize,
+) -> WriteBitStream {
+    if block_split_count <= 1 {
+        return encode_block_type_two(lzss_symbols, 0, is_last).bitstream;
+    }
+
+    // too small pieces are not worth a block of their own
+    let chunk_size = if lzss_symbols.len() > 10 * block_split_count {
+        lzss_symbols.len() / block_split_count
+    } else {
+        lzss_symbols.len()
+    };
+
+    let mut compressed = WriteBitStream::new();
+    let mut last_block = EncodedBlock {
+        start_index: 0,
+        block_type: DeflateBlockType::DynamicHuffman,
+        bitstream: WriteBitStream::new(),
+    };
+
+    for (chunk_num, chunk) in lzss_symbols.chunks(chunk_size).enumerate() {
+        let chunk_start_index = chunk_num * chunk_size;
+        let is_last_chunk = is_last && (chunk_num + 1) * chunk_size >= lzss_symbols.len();
+        let chunk_end_index = chunk_start_index + chunk.len();
+        let chunk_encoded_alone = smaller_block(
+            encode_block_type_two(
+                &lzss_symbols[chunk_start_index..chunk_end_index],
+                chunk_start_index,
+                is_last_chunk,
+            ),
+            encode_block_type_one(
+                &lzss_symbols[chunk_start_index..chunk_end_index],
+                chunk_start_index,
+                is_last_chunk,
+            ),
+        );
+        let added_to_last_block = match last_block.block_type {
+            DeflateBlockType::FixedHuffman => encode_block_type_one(
+                &lzss_symbols[last_block.start_index..chunk_end_index],
+                last_block.start_index,
+                is_last_chunk,
+            ),
+            DeflateBlockType::DynamicHuffman => encode_block_type_two(
+                &lzss_symbols[last_block.start_index..chunk_end_index],
+                last_block.start_index,
+                is_last_chunk,
+            ),
+            //TODO: sometimes it should
+            _ => panic!("Block type should never be 0"),
+        };
+
+        if added_to_last_block.bitstream.len() - last_block.bitstream.len()
+            > chunk_encoded_alone.bitstream.len()
+        {
+            compressed.extend(&last_block.bitstream);
+            last_block = chunk_encoded_alone;
+            last_block.start_index = chunk_start_index;
+        } else {
+            last_block = added_to_last_block;
+        }
+    }
+
+    compressed.extend(&last_block.bitstream);
+
+    compressed
 }
 
 fn encode_block_type_zero(bytes: &[u8], start_index: usize, is_last: bool) -> EncodedBlock {
     let mut bitstream = WriteBitStream::new();
-
-    for (block_index, block_bytes) in bytes.chunks(MAX_UNCOMPRESSED_BLOCK_SIZE.into()).enumerate() {
-        let is_last = is_last && block_index == bytes.len() / MAX_UNCOMPRESSED_BLOCK_SIZE as usize;
+    // even without any bytes there has to be a block to hold the last block flag
+    let number_of_blocks = bytes
+        .len()
+        .div_ceil(MAX_UNCOMPRESSED_BLOCK_SIZE as usize)
+        .max(1);
+    let blocks_bytes = bytes
+        .chunks(MAX_UNCOMPRESSED_BLOCK_SIZE.into())
+        .chain(iter::once(&[][..]))
+        .take(number_of_blocks);
+
+    for (block_index, block_bytes) in blocks_bytes.enumerate() {
+        let is_last = is_last && block_index + 1 == number_of_blocks;
         push_is_last(&mut bitstream, is_last);
 
         bitstream.push_u8_rtl(DeflateBlockType::None.to_number(), 2);
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, hash::Hash, iter::repeat_n};
 
-use crate::deflate_read_bits;
+use crate::{deflate_read_bits, error::ErrorKind};
 
 use super::{
     bitstream::{ReadBitStream, WriteBitStream},
@@ -41,7 +41,10 @@ impl CLCode {
                 repeat_count: Self::read_repeat_count(bitstream, 7)?,
             },
             n => {
-                return Err(DeflateDecodeError(format!("Unrecognized cl code {n}")));
+                return Err(DeflateDecodeError::new(
+                    ErrorKind::InvalidData,
+                    format!("Unrecognized cl code {n}"),
+                ));
             }
         })
     }
@@ -50,7 +53,7 @@ impl CLCode {
         bitstream: &mut ReadBitStream,
         len: usize,
     ) -> Result<usize, DeflateDecodeError> {
-        Ok(deflate_read_bits!(bitstream.read_number_lsb(len), "sdsa") as usize)
+        Ok(deflate_read_bits!(bitstream.read_number_lsb(len), "expected CL code repeat count") as usize)
     }
 
     pub fn encode(&self, cl_codes: &HashMap<u32, WriteBitStream>, target: &mut WriteBitStream) {
@@ -0,0 +1,215 @@
+use std::io::{self, Read};
+
+use crate::error::ErrorKind;
+
+use super::{DeflateDecodeError, DeflateInflater, GzipHeader, GzipInflater, ZlibInflater};
+
+// compressed bytes are read in chunks of this size, and decompressed until about this many bytes
+// are waiting to be read
+const INPUT_BUFFER_SIZE: usize = 8 * 1024;
+const OUTPUT_BUFFER_SIZE: usize = 32 * 1024;
+
+fn to_io_error(err: DeflateDecodeError) -> io::Error {
+    let kind = match err.kind {
+        ErrorKind::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+        _ => io::ErrorKind::InvalidData,
+    };
+
+    io::Error::new(kind, err)
+}
+
+// the decompressed bytes that were not read yet
+#[derive(Default)]
+struct OutputBuffer {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl OutputBuffer {
+    fn read_into(&mut self, buf: &mut [u8]) -> usize {
+        let length = buf.len().min(self.bytes.len() - self.position);
+        buf[..length].copy_from_slice(&self.bytes[self.position..self.position + length]);
+        self.position += length;
+
+        length
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn clear(&mut self) {
+        self.bytes.clear();
+        self.position = 0;
+    }
+}
+
+// decompresses a raw deflate stream read from another reader, a chunk at a time
+pub struct DeflateReader<R: Read> {
+    reader: R,
+    inflater: DeflateInflater,
+    output: OutputBuffer,
+}
+
+impl<R: Read> DeflateReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            inflater: DeflateInflater::new(),
+            output: OutputBuffer::default(),
+        }
+    }
+
+    // the reader is left right after the last compressed byte the inflater was given, which can be
+    // past the end of the deflate stream
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for DeflateReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.output.is_empty() && !buf.is_empty() {
+            if self.inflater.is_finished() {
+                return Ok(0);
+            }
+
+            self.output.clear();
+            self.inflater
+                .inflate(&mut self.output.bytes, OUTPUT_BUFFER_SIZE)
+                .map_err(to_io_error)?;
+
+            if self.output.is_empty() && !self.inflater.is_finished() {
+                let mut input = [0; INPUT_BUFFER_SIZE];
+                let length = self.reader.read(&mut input)?;
+
+                if length == 0 {
+                    self.inflater.finish().map_err(to_io_error)?;
+                }
+
+                self.inflater.feed(&input[..length]);
+            }
+        }
+
+        Ok(self.output.read_into(buf))
+    }
+}
+
+// decompresses a zlib stream read from another reader, checking its adler32 checksum at the end
+pub struct ZlibReader<R: Read> {
+    reader: R,
+    inflater: ZlibInflater,
+    output: OutputBuffer,
+    is_checksum_verified: bool,
+}
+
+impl<R: Read> ZlibReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            inflater: ZlibInflater::new(),
+            output: OutputBuffer::default(),
+            is_checksum_verified: false,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for ZlibReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.output.is_empty() && !buf.is_empty() {
+            if self.is_checksum_verified {
+                return Ok(0);
+            }
+
+            if self.inflater.has_checksum() {
+                if let (_, Some(err)) = self.inflater.finish().map_err(to_io_error)? {
+                    return Err(to_io_error(err));
+                }
+
+                self.is_checksum_verified = true;
+                continue;
+            }
+
+            self.output.clear();
+            self.inflater
+                .inflate(&mut self.output.bytes, OUTPUT_BUFFER_SIZE)
+                .map_err(to_io_error)?;
+
+            if self.output.is_empty() {
+                let mut input = [0; INPUT_BUFFER_SIZE];
+                let length = self.reader.read(&mut input)?;
+
+                if length == 0 {
+                    self.inflater.finish().map_err(to_io_error)?;
+                }
+
+                self.inflater.feed(&input[..length]);
+            }
+        }
+
+        Ok(self.output.read_into(buf))
+    }
+}
+
+// decompresses every member of a gzip stream read from another reader, checking the CRC-32 and size
+// of each
+pub struct GzipReader<R: Read> {
+    reader: R,
+    inflater: GzipInflater,
+    output: OutputBuffer,
+    is_finished: bool,
+}
+
+impl<R: Read> GzipReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            inflater: GzipInflater::new(),
+            output: OutputBuffer::default(),
+            is_finished: false,
+        }
+    }
+
+    // the headers of the members that were reached so far
+    pub fn headers(&self) -> &[GzipHeader] {
+        self.inflater.headers()
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for GzipReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.output.is_empty() && !buf.is_empty() {
+            if self.is_finished {
+                return Ok(0);
+            }
+
+            self.output.clear();
+            self.inflater
+                .inflate(&mut self.output.bytes, OUTPUT_BUFFER_SIZE)
+                .map_err(to_io_error)?;
+
+            if self.output.is_empty() {
+                let mut input = [0; INPUT_BUFFER_SIZE];
+                let length = self.reader.read(&mut input)?;
+
+                // another member may follow, so the end is only known once the reader runs dry
+                if length == 0 {
+                    self.output.bytes = self.inflater.finish().map_err(to_io_error)?;
+                    self.is_finished = true;
+                }
+
+                self.inflater.feed(&input[..length]);
+            }
+        }
+
+        Ok(self.output.read_into(buf))
+    }
+}
@@ -0,0 +1,150 @@
+use std::io::{self, Write};
+
+use crate::png::CompressionLevel;
+
+use super::{DeflateEncoder, GzipEncoder, GzipHeader, ZlibEncoder};
+
+// compresses everything written to it into a raw deflate stream. flushing ends the current block
+// with a sync flush, and the stream is only complete once finish is called
+pub struct DeflateWriter<W: Write> {
+    writer: W,
+    encoder: DeflateEncoder,
+}
+
+impl<W: Write> DeflateWriter<W> {
+    pub fn new(writer: W, compression_level: CompressionLevel) -> Self {
+        Self {
+            writer,
+            encoder: DeflateEncoder::new(compression_level),
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    // like flush, but the output after it does not refer back to anything before it
+    pub fn full_flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.full_flush())?;
+        self.writer.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer
+            .write_all(&self.encoder.finish().flush_to_bytes())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Write for DeflateWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.encoder.write_bytes(buf);
+        self.writer
+            .write_all(&self.encoder.take_compressed_bytes())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.sync_flush())?;
+        self.writer.flush()
+    }
+}
+
+// the zlib counterpart of DeflateWriter, finish also writes the adler32 checksum
+pub struct ZlibWriter<W: Write> {
+    writer: W,
+    encoder: ZlibEncoder,
+}
+
+impl<W: Write> ZlibWriter<W> {
+    pub fn new(writer: W, compression_level: CompressionLevel) -> Self {
+        Self {
+            writer,
+            encoder: ZlibEncoder::new(compression_level),
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn full_flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.full_flush())?;
+        self.writer.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&self.encoder.flush())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Write for ZlibWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.encoder.write_bytes(buf);
+        self.writer
+            .write_all(&self.encoder.take_compressed_bytes())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.sync_flush())?;
+        self.writer.flush()
+    }
+}
+
+// the gzip counterpart of DeflateWriter, finish ends the member with its CRC-32 and size
+pub struct GzipWriter<W: Write> {
+    writer: W,
+    encoder: GzipEncoder,
+}
+
+impl<W: Write> GzipWriter<W> {
+    pub fn new(
+        writer: W,
+        header: GzipHeader,
+        compression_level: CompressionLevel,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            writer,
+            encoder: GzipEncoder::new(header, compression_level)?,
+        })
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn full_flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.full_flush())?;
+        self.writer.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&self.encoder.finish())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Write for GzipWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.encoder.write_bytes(buf);
+        self.writer
+            .write_all(&self.encoder.take_compressed_bytes())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.write_all(&self.encoder.sync_flush())?;
+        self.writer.flush()
+    }
+}
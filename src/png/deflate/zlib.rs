@@ -1,12 +1,13 @@
 use crate::{
     algebra::align_up,
     deflate_read_bits,
+    error::ErrorKind,
     png::{adler32::Adler32Calculator, CompressionLevel},
 };
 
 use super::{
     bitstream::{ReadBitStream, WriteBitStream},
-    decode::{decode_deflate, DeflateDecodeError},
+    decode::{DeflateDecodeError, DeflateInflater},
     DeflateEncoder,
 };
 
@@ -14,6 +15,7 @@ pub struct ZlibEncoder {
     compression_level: CompressionLevel,
     deflate_encoder: DeflateEncoder,
     adler32_calculator: Adler32Calculator,
+    is_header_written: bool,
 }
 
 impl ZlibEncoder {
@@ -22,6 +24,7 @@ impl ZlibEncoder {
             deflate_encoder: DeflateEncoder::new(compression_level),
             adler32_calculator: Adler32Calculator::new(),
             compression_level,
+            is_header_written: false,
         }
     }
 
@@ -30,40 +33,227 @@ impl ZlibEncoder {
         self.adler32_calculator.update_adler32(bytes);
     }
 
+    // the complete bytes of the stream compressed so far, without ending the current block
+    pub fn take_compressed_bytes(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.take_compressed_bytes());
+
+        result
+    }
+
+    pub fn sync_flush(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.sync_flush());
+
+        result
+    }
+
+    pub fn full_flush(&mut self) -> Vec<u8> {
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.full_flush());
+
+        result
+    }
+
+    // ends the stream with its adler32 checksum, after which the encoder starts a new one
     pub fn flush(&mut self) -> Vec<u8> {
         let adler32 = self.adler32_calculator.get_adler32();
         self.adler32_calculator.reset();
 
-        let mut zlib_bitstream = WriteBitStream::new();
-        let cm = 8;
-        let cminfo = 7;
-        let cmf = (cminfo << 4) + cm;
-        let fdict = 0;
-        let flevel = self.compression_level.to_zlib_u8();
-        let flg = ((cmf as u32) << 8) + ((flevel as u32) << 6) + ((fdict as u32) << 5);
-        let fcheck = (align_up(flg as usize, 31) - flg as usize) as u8;
-
-        zlib_bitstream.push_u8_rtl(cm, 4);
-        zlib_bitstream.push_u8_rtl(cminfo, 4);
-        zlib_bitstream.push_u8_rtl(fcheck, 5);
-        zlib_bitstream.push_u8_rtl(fdict, 1);
-        zlib_bitstream.push_u8_rtl(flevel, 2);
-
-        let mut result = zlib_bitstream.flush_to_bytes();
-        result.extend_from_slice(&self.deflate_encoder.finish().flush_to_bytes());
+        let mut result = self.take_header();
+        result.extend(self.deflate_encoder.finish().flush_to_bytes());
         result.extend_from_slice(&adler32.to_be_bytes());
+        self.is_header_written = false;
 
         result
     }
+
+    fn take_header(&mut self) -> Vec<u8> {
+        if self.is_header_written {
+            return Vec::new();
+        }
+
+        self.is_header_written = true;
+
+        encode_zlib_header(self.compression_level).flush_to_bytes()
+    }
+}
+
+fn encode_zlib_header(compression_level: CompressionLevel) -> WriteBitStream {
+    let mut zlib_bitstream = WriteBitStream::new();
+    let cm = 8;
+    let cminfo = 7;
+    let cmf = (cminfo << 4) + cm;
+    let fdict = 0;
+    let flevel = compression_level.to_zlib_u8();
+    let flg = ((cmf as u32) << 8) + ((flevel as u32) << 6) + ((fdict as u32) << 5);
+    let fcheck = (align_up(flg as usize, 31) - flg as usize) as u8;
+
+    zlib_bitstream.push_u8_rtl(cm, 4);
+    zlib_bitstream.push_u8_rtl(cminfo, 4);
+    zlib_bitstream.push_u8_rtl(fcheck, 5);
+    zlib_bitstream.push_u8_rtl(fdict, 1);
+    zlib_bitstream.push_u8_rtl(flevel, 2);
+
+    zlib_bitstream
 }
 
 pub fn decode_zlib(bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
-    let data_start_index = decode_zlib_header(bytes)?;
-    let uncompressed_data = decode_deflate(&bytes[data_start_index..bytes.len() - 4])?;
-    let _adler32 = u32::from_be_bytes(bytes[bytes.len() - 4..].try_into().unwrap());
-    //TODO: validate adler 32
+    let (uncompressed_data, checksum_error) = decode_zlib_lenient(bytes)?;
+
+    match checksum_error {
+        Some(err) => Err(err),
+        None => Ok(uncompressed_data),
+    }
+}
+
+// returns the data even if the adler32 check failed, along with the mismatch error
+pub fn decode_zlib_lenient(
+    bytes: &[u8],
+) -> Result<(Vec<u8>, Option<DeflateDecodeError>), DeflateDecodeError> {
+    let mut zlib_inflater = ZlibInflater::new();
+    let mut uncompressed_data = zlib_inflater.push(bytes)?;
+    let (remaining_data, checksum_error) = zlib_inflater.finish()?;
+    uncompressed_data.extend(remaining_data);
+
+    Ok((uncompressed_data, checksum_error))
+}
+
+// decompresses a zlib stream whose bytes arrive over time
+pub struct ZlibInflater {
+    header: Vec<u8>,
+    data_start_index: Option<usize>,
+    deflate_inflater: DeflateInflater,
+    adler32_calculator: Adler32Calculator,
+}
+
+impl ZlibInflater {
+    pub fn new() -> Self {
+        Self {
+            header: Vec::new(),
+            data_start_index: None,
+            deflate_inflater: DeflateInflater::new(),
+            adler32_calculator: Adler32Calculator::new(),
+        }
+    }
 
-    Ok(uncompressed_data)
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<u8>, DeflateDecodeError> {
+        let mut output = Vec::new();
+        self.feed(bytes);
+        self.inflate(&mut output, usize::MAX)?;
+
+        Ok(output)
+    }
+
+    // like decode_zlib_lenient, an adler32 mismatch is returned next to the remaining data
+    pub fn finish(&mut self) -> Result<(Vec<u8>, Option<DeflateDecodeError>), DeflateDecodeError> {
+        let Some(data_start_index) = self.read_header()? else {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::UnexpectedEof,
+                "ZLIB stream ended before its header",
+            ));
+        };
+        let output = self
+            .deflate_inflater
+            .finish()
+            .map_err(|err| offset_error(err, data_start_index))?;
+        self.adler32_calculator.update_adler32(&output);
+
+        let adler32_start_index = data_start_index + self.deflate_inflater.input_offset();
+        let trailing_bytes = self.deflate_inflater.trailing_bytes();
+
+        if trailing_bytes.len() < 4 {
+            return Err(DeflateDecodeError::new(
+                ErrorKind::UnexpectedEof,
+                "ZLIB stream is too short to contain an adler32 checksum",
+            )
+            .at_offset(adler32_start_index + trailing_bytes.len()));
+        }
+
+        let expected_adler32 = u32::from_be_bytes(trailing_bytes[..4].try_into().unwrap());
+        let adler32 = self.adler32_calculator.get_adler32();
+        let checksum_error = (adler32 != expected_adler32).then(|| {
+            DeflateDecodeError::new(
+                ErrorKind::ChecksumMismatch,
+                format!(
+                    "Adler-32 mismatch, stream stores {expected_adler32:#010x} but data hashes to {adler32:#010x}"
+                ),
+            )
+            .at_offset(adler32_start_index)
+        });
+
+        Ok((output, checksum_error))
+    }
+
+    // buffers compressed bytes without decompressing them yet
+    pub fn feed(&mut self, bytes: &[u8]) {
+        match self.data_start_index {
+            Some(_) => self.deflate_inflater.feed(bytes),
+            None => self.header.extend_from_slice(bytes),
+        }
+    }
+
+    // decompresses the buffered input into output, stopping once it holds max_output_length bytes
+    pub fn inflate(
+        &mut self,
+        output: &mut Vec<u8>,
+        max_output_length: usize,
+    ) -> Result<(), DeflateDecodeError> {
+        let Some(data_start_index) = self.read_header()? else {
+            return Ok(());
+        };
+        let output_length = output.len();
+
+        self.deflate_inflater
+            .inflate(output, max_output_length)
+            .map_err(|err| offset_error(err, data_start_index))?;
+        self.adler32_calculator
+            .update_adler32(&output[output_length..]);
+
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.deflate_inflater.is_finished()
+    }
+
+    // whether the adler32 checksum after the last block has arrived as well
+    pub fn has_checksum(&self) -> bool {
+        self.deflate_inflater.trailing_bytes().len() >= 4
+    }
+
+    // the index the compressed data starts at, once enough of the header has arrived
+    fn read_header(&mut self) -> Result<Option<usize>, DeflateDecodeError> {
+        if self.data_start_index.is_some() {
+            return Ok(self.data_start_index);
+        }
+
+        let data_start_index = match decode_zlib_header(&self.header) {
+            Ok(data_start_index) if data_start_index <= self.header.len() => data_start_index,
+            Ok(_) => return Ok(None),
+            Err(err) if err.kind == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        self.data_start_index = Some(data_start_index);
+        let data = self.header.split_off(data_start_index);
+        self.deflate_inflater.feed(&data);
+
+        Ok(Some(data_start_index))
+    }
+}
+
+impl Default for ZlibInflater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(super) fn offset_error(
+    mut err: DeflateDecodeError,
+    data_start_index: usize,
+) -> DeflateDecodeError {
+    err.offset = err.offset.map(|offset| offset + data_start_index);
+    err
 }
 
 pub fn decode_zlib_header(bytes: &[u8]) -> Result<usize, DeflateDecodeError> {
@@ -74,9 +264,10 @@ pub fn decode_zlib_header(bytes: &[u8]) -> Result<usize, DeflateDecodeError> {
     );
 
     if cm != 8 {
-        return Err(DeflateDecodeError(format!(
-            "Unsupported compression method in ZLIB header {cm}"
-        )));
+        return Err(DeflateDecodeError::new(
+            ErrorKind::Unsupported,
+            format!("Unsupported compression method in ZLIB header {cm}"),
+        ));
     }
 
     let cminfo = deflate_read_bits!(
@@ -85,9 +276,10 @@ pub fn decode_zlib_header(bytes: &[u8]) -> Result<usize, DeflateDecodeError> {
     );
 
     if cminfo > 7 {
-        return Err(DeflateDecodeError(format!(
-            "Unsupported compression info value in ZLIB header: {cminfo}"
-        )));
+        return Err(DeflateDecodeError::new(
+            ErrorKind::Unsupported,
+            format!("Unsupported compression info value in ZLIB header: {cminfo}"),
+        ));
     }
     let cmf = (cminfo << 4) as u8 + cm as u8;
 
@@ -97,12 +289,19 @@ pub fn decode_zlib_header(bytes: &[u8]) -> Result<usize, DeflateDecodeError> {
     let flg = (flevel << 6) + ((fdict as u16) << 5) + fcheck;
 
     if !(((cmf as u16) << 8) + flg).is_multiple_of(31) {
-        return Err(DeflateDecodeError(
-            "ZLIB CMF + FLG is not a multiple of 31".to_string(),
+        return Err(DeflateDecodeError::new(
+            ErrorKind::InvalidData,
+            "ZLIB CMF + FLG is not a multiple of 31",
         ));
     }
 
-    let data_start_index = 2 + if fdict == 1 { 4 } else { 0 };
+    // the data would refer back into a dictionary that only the caller knows
+    if fdict == 1 {
+        return Err(DeflateDecodeError::new(
+            ErrorKind::Unsupported,
+            "preset dictionaries are not supported",
+        ));
+    }
 
-    Ok(data_start_index)
+    Ok(2)
 }
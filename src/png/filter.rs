@@ -1,11 +1,14 @@
 use std::iter::{self, repeat_n};
 
-use crate::simd_utils::{paeth_predictor_simd, png_average_simd, subtract_simd};
+use crate::{
+    error::ErrorKind,
+    simd_utils::{paeth_predictor_simd, png_average_simd, subtract_simd},
+};
 
-use super::{deflate::DeflateEncoder, CompressionLevel, PngParseError};
+use super::{deflate::DeflateEncoder, CompressionLevel, FilterStrategy, PngParseError};
 
-#[derive(Debug, Clone)]
-enum AdaptiveFilterType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveFilterType {
     None,
     Sub,
     Up,
@@ -37,7 +40,7 @@ impl AdaptiveFilterType {
         }
     }
 
-    fn to_byte(&self) -> u8 {
+    fn to_byte(self) -> u8 {
         match self {
             AdaptiveFilterType::None => 0,
             AdaptiveFilterType::Sub => 1,
@@ -55,9 +58,10 @@ impl AdaptiveFilterType {
             3 => AdaptiveFilterType::Average,
             4 => AdaptiveFilterType::Paeth,
             f => {
-                return Err(PngParseError(format!(
-                    "Unrecognized adaptive filter type {f}",
-                )));
+                return Err(PngParseError::new(
+                    ErrorKind::InvalidData,
+                    format!("Unrecognized adaptive filter type {f}"),
+                ));
             }
         })
     }
@@ -92,88 +96,142 @@ type FilteredScenaline = (AdaptiveFilterType, Vec<u8>);
 pub fn filter_scanlines(
     scanlines: &[Vec<u8>],
     bbp: usize,
-    compression_level: CompressionLevel,
-) -> Vec<Vec<u8>> {
-    let filters_to_test = match compression_level {
-        CompressionLevel::None => vec![AdaptiveFilterType::None],
-        CompressionLevel::Best => ALL_FILTERS.to_vec(),
-        CompressionLevel::Fast => vec![AdaptiveFilterType::Paeth],
-    };
-    let other_byte_offsets = if bbp <= 8 { 1 } else { (bbp >> 3) as i16 };
-    let mut filtered_scanelines: Vec<Vec<u8>> = Vec::with_capacity(scanlines.len());
-    let empty_row: Vec<u8> = repeat_n(0u8, scanlines[0].len()).collect();
-    let mut b = &empty_row[..];
-    let mut c: Vec<u8> = empty_row.clone();
+    filter_strategy: FilterStrategy,
+) -> Vec<FilteredScenaline> {
+    let mut previous_scanline: &[u8] = &[];
 
-    for x in scanlines.iter() {
-        let mut filter_results: Vec<FilteredScenaline> = Vec::with_capacity(ALL_FILTERS.len());
-        let mut a: Vec<u8> = iter::repeat_n(0u8, other_byte_offsets as usize).collect();
+    scanlines
+        .iter()
+        .map(|scanline| {
+            let filtered_scanline =
+                filter_scanline(scanline, previous_scanline, bbp, filter_strategy);
+            previous_scanline = scanline;
 
-        for filter in filters_to_test.iter() {
-            a.extend_from_slice(&x[..x.len() - other_byte_offsets as usize]);
+            filtered_scanline
+        })
+        .collect()
+}
 
-            let current_filter_result = filter.apply_filter_simd(x, &a.clone(), b, &c.clone());
+// previous_scanline is the unfiltered row above, or empty for the first row of an image. the
+// filtered scanline starts with its filter type byte
+pub fn filter_scanline(
+    x: &[u8],
+    previous_scanline: &[u8],
+    bbp: usize,
+    filter_strategy: FilterStrategy,
+) -> FilteredScenaline {
+    let filters_to_test = match filter_strategy {
+        FilterStrategy::Fixed(filter) => vec![filter],
+        _ => ALL_FILTERS.to_vec(),
+    };
+    let other_byte_offsets = if bbp <= 8 { 1 } else { bbp >> 3 };
+    let shift_right = |bytes: &[u8]| -> Vec<u8> {
+        iter::repeat_n(0u8, other_byte_offsets)
+            .chain(bytes[..bytes.len() - other_byte_offsets].iter().cloned())
+            .collect()
+    };
+    let b: Vec<u8> = match previous_scanline {
+        [] => repeat_n(0u8, x.len()).collect(),
+        previous_scanline => previous_scanline.to_vec(),
+    };
+    let a = shift_right(x);
+    let c = shift_right(&b);
 
-            filter_results.push((filter.clone(), current_filter_result));
-        }
+    let filter_results: Vec<FilteredScenaline> = filters_to_test
+        .into_iter()
+        .map(|filter| {
+            let current_filter_result = filter.apply_filter_simd(x, &a, &b, &c);
 
-        b = x;
-        c = a;
-        let (filter, mut scanline) = if filter_results.len() == 1 {
-            filter_results.into_iter().next().unwrap()
-        } else {
-            filter_results
-                .into_iter()
-                .min_by_key(|filtered_row| {
-                    let mut encoder = DeflateEncoder::new(CompressionLevel::Fast);
-                    encoder.write_bytes(&filtered_row.1);
-                    encoder.finish().len()
-                })
-                .unwrap()
-        };
-
-        scanline.insert(0, filter.to_byte());
-        filtered_scanelines.push(scanline);
-    }
+            (filter, current_filter_result)
+        })
+        .collect();
+    let (filter, mut scanline) = match filter_strategy {
+        FilterStrategy::Fixed(_) => filter_results.into_iter().next().unwrap(),
+        FilterStrategy::MinimumSumOfAbsoluteDifferences => filter_results
+            .into_iter()
+            .min_by_key(|filtered_row| sum_of_absolute_differences(&filtered_row.1))
+            .unwrap(),
+        FilterStrategy::Entropy => filter_results
+            .into_iter()
+            .min_by(|a, b| entropy(&a.1).total_cmp(&entropy(&b.1)))
+            .unwrap(),
+        FilterStrategy::BruteForce => filter_results
+            .into_iter()
+            .min_by_key(|filtered_row| {
+                let mut encoder = DeflateEncoder::new(CompressionLevel::FAST);
+                encoder.write_bytes(&filtered_row.1);
+                encoder.finish().len()
+            })
+            .unwrap(),
+    };
+
+    scanline.insert(0, filter.to_byte());
 
-    filtered_scanelines
+    (filter, scanline)
 }
 
-fn get_byte(scanlines: &[Vec<u8>], row: i16, col: i16) -> u8 {
-    if row < 0 || col < 0 {
-        return 0;
+// the filtered bytes read as signed differences, so small negative values count as small too
+fn sum_of_absolute_differences(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .map(|&byte| (byte as i8).unsigned_abs() as u64)
+        .sum()
+}
+
+// shannon entropy of the byte histogram in bits per byte
+fn entropy(bytes: &[u8]) -> f64 {
+    let mut counts = [0usize; 256];
+
+    for &byte in bytes {
+        counts[byte as usize] += 1;
     }
 
-    scanlines
-        .get(row as usize)
-        .and_then(|scanline| scanline.get(col as usize).cloned())
-        .unwrap_or(0)
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let probability = count as f64 / bytes.len() as f64;
+            -probability * probability.log2()
+        })
+        .sum()
 }
 
 pub fn remove_scanlines_filter(
     scanlines: &[Vec<u8>],
     bbp: usize,
 ) -> Result<Vec<Vec<u8>>, PngParseError> {
-    let mut unfiltered_scanlines = Vec::with_capacity(scanlines.len());
-    let other_byte_offsets = if bbp <= 8 { 1 } else { (bbp >> 3) as i16 };
-
-    for (row, scanline) in scanlines.iter().enumerate() {
-        let filter_type = AdaptiveFilterType::from_byte(scanline[0])?;
-        unfiltered_scanlines.push(Vec::with_capacity(scanline.len()));
+    let mut unfiltered_scanlines: Vec<Vec<u8>> = Vec::with_capacity(scanlines.len());
 
-        for (col, &x) in scanline.iter().enumerate() {
-            //subtract 1 from col because there is no filter type byte in unfiltered_scanlines
-            let (row, col) = (row as i16, col as i16 - 1);
+    for scanline in scanlines {
+        let previous_scanline = unfiltered_scanlines.last().map_or(&[][..], Vec::as_slice);
+        let unfiltered_scanline = remove_scanline_filter(scanline, previous_scanline, bbp)?;
 
-            let a = get_byte(&unfiltered_scanlines, row, col - other_byte_offsets);
-            let b = get_byte(&unfiltered_scanlines, row - 1, col);
-            let c = get_byte(&unfiltered_scanlines, row - 1, col - other_byte_offsets);
+        unfiltered_scanlines.push(unfiltered_scanline);
+    }
 
-            let val = filter_type.revert_filter(x, a, b, c);
+    Ok(unfiltered_scanlines)
+}
 
-            unfiltered_scanlines[row as usize].push(val);
-        }
+// previous_scanline is the already unfiltered row above, or empty for the first row of an image
+pub fn remove_scanline_filter(
+    scanline: &[u8],
+    previous_scanline: &[u8],
+    bbp: usize,
+) -> Result<Vec<u8>, PngParseError> {
+    let filter_type = AdaptiveFilterType::from_byte(scanline[0])?;
+    let other_byte_offsets = if bbp <= 8 { 1 } else { bbp >> 3 };
+    let mut unfiltered_scanline: Vec<u8> = Vec::with_capacity(scanline.len() - 1);
+    let get_byte =
+        |bytes: &[u8], col: Option<usize>| col.and_then(|col| bytes.get(col).cloned()).unwrap_or(0);
+
+    //skip the filter type byte, which has no counterpart in the unfiltered scanline
+    for (col, &x) in scanline.iter().skip(1).enumerate() {
+        let a = get_byte(&unfiltered_scanline, col.checked_sub(other_byte_offsets));
+        let b = get_byte(previous_scanline, Some(col));
+        let c = get_byte(previous_scanline, col.checked_sub(other_byte_offsets));
+
+        unfiltered_scanline.push(filter_type.revert_filter(x, a, b, c));
     }
 
-    Ok(unfiltered_scanlines)
+    Ok(unfiltered_scanline)
 }
@@ -1,6 +1,6 @@
 use std::iter::repeat_n;
 
-use crate::{binary::byte_reader::ByteReader, colors::Rgba, png_assert};
+use crate::{binary::byte_reader::ByteReader, error::ErrorKind, png_assert};
 
 use super::PngParseError;
 
@@ -12,14 +12,14 @@ pub enum InterlaceMethod {
 }
 
 impl InterlaceMethod {
-    pub fn perform_pass_extraction(&self, pixels: Vec<Vec<Rgba>>) -> Vec<Vec<Vec<Rgba>>> {
+    pub fn perform_pass_extraction<P: Clone>(&self, pixels: Vec<Vec<P>>) -> Vec<Vec<Vec<P>>> {
         match self {
             InterlaceMethod::NoInterlace => vec![pixels],
             InterlaceMethod::Adam7 => {
-                let mut reduced_images: Vec<Vec<Vec<Rgba>>> = Vec::new();
+                let mut reduced_images: Vec<Vec<Vec<P>>> = Vec::new();
 
                 for pass in ADAM7_PASSES.iter() {
-                    let mut reduced_image: Vec<Vec<Rgba>> = Vec::new();
+                    let mut reduced_image: Vec<Vec<P>> = Vec::new();
 
                     for subset_top in (0..pixels.len()).step_by(8) {
                         for (row_num, pass_row) in *pass {
@@ -28,7 +28,7 @@ impl InterlaceMethod {
                                 break;
                             }
 
-                            let mut row: Vec<Rgba> = Vec::new();
+                            let mut row: Vec<P> = Vec::new();
 
                             for subset_left in (0..pixels[y].len()).step_by(8) {
                                 for col in *pass_row {
@@ -41,7 +41,10 @@ impl InterlaceMethod {
                                 }
                             }
 
-                            reduced_image.push(row);
+                            // passes that miss every column of a narrow image are left out
+                            if !row.is_empty() {
+                                reduced_image.push(row);
+                            }
                         }
                     }
 
@@ -64,7 +67,7 @@ impl InterlaceMethod {
 
         match self {
             InterlaceMethod::NoInterlace => {
-                let bytes_per_scanline = filter_byte_size + ((width * bits_per_pixel) >> 3);
+                let bytes_per_scanline = filter_byte_size + (width * bits_per_pixel).div_ceil(8);
                 let expected_data_size = height * bytes_per_scanline;
                 png_assert!(
                     data.len() == expected_data_size,
@@ -90,6 +93,11 @@ impl InterlaceMethod {
                 for (pass, (number_of_scanlines, scanline_number_of_pixels)) in
                     scanline_dimensions_by_pass.into_iter().enumerate()
                 {
+                    // a pass without any columns has no scanlines, not even filter bytes
+                    let number_of_scanlines = match scanline_number_of_pixels {
+                        0 => 0,
+                        _ => number_of_scanlines,
+                    };
                     let scanline_width_bytes = filter_byte_size
                         + ((scanline_number_of_pixels as f32 * bits_per_pixel as f32) / 8.).ceil()
                             as usize;
@@ -103,10 +111,13 @@ impl InterlaceMethod {
                             .map(|slice| slice.to_vec())
                             .collect(),
                         None => {
-                            return Err(PngParseError(format!(
-                                        "Expected {expected_data_size} bytes for adam7 pass #{pass}, but only had {} bytes left in the buffer",
-                                        data_reader.number_of_bytes_left()
-                            )));
+                            return Err(PngParseError::new(
+                                ErrorKind::UnexpectedEof,
+                                format!(
+                                    "Expected {expected_data_size} bytes for adam7 pass #{pass}, but only had {} bytes left in the buffer",
+                                    data_reader.number_of_bytes_left()
+                                ),
+                            ));
                         }
                     };
 
@@ -125,16 +136,50 @@ impl InterlaceMethod {
         }
     }
 
-    pub fn deinterlace_image(
+    // the scanlines and pixels per scanline of each pass, where a pass without any columns has no
+    // scanlines either
+    pub fn pass_dimensions(&self, height: usize, width: usize) -> Vec<(usize, usize)> {
+        match self {
+            InterlaceMethod::NoInterlace => vec![(height, width)],
+            InterlaceMethod::Adam7 => adam7_scanlines_dimensions_by_pass(height, width)
+                .into_iter()
+                .map(|(number_of_scanlines, scanline_number_of_pixels)| {
+                    match scanline_number_of_pixels {
+                        0 => (0, 0),
+                        _ => (number_of_scanlines, scanline_number_of_pixels),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    // the (x, y) of the first pixel of each pass, followed by the horizontal and vertical distance
+    // between its pixels
+    pub fn pass_origins(&self) -> &'static [(usize, usize, usize, usize)] {
+        match self {
+            InterlaceMethod::NoInterlace => &[(0, 0, 1, 1)],
+            InterlaceMethod::Adam7 => &ADAM7_PASS_ORIGINS,
+        }
+    }
+
+    // how much of the image each pixel of a pass can cover until the later passes fill it in
+    pub fn pass_preview_block_sizes(&self) -> &'static [(usize, usize)] {
+        match self {
+            InterlaceMethod::NoInterlace => &[(1, 1)],
+            InterlaceMethod::Adam7 => &ADAM7_PREVIEW_BLOCK_SIZES,
+        }
+    }
+
+    pub fn deinterlace_image<P: Clone>(
         &self,
-        mut reduced_images: Vec<Vec<Vec<Rgba>>>,
+        mut reduced_images: Vec<Vec<Vec<P>>>,
         image_height: usize,
         image_width: usize,
-    ) -> Vec<Vec<Rgba>> {
+    ) -> Vec<Vec<P>> {
         match self {
             InterlaceMethod::NoInterlace => reduced_images.pop().unwrap(),
             InterlaceMethod::Adam7 => {
-                let mut image: Vec<Vec<Rgba>> = Vec::with_capacity(image_height);
+                let mut image: Vec<Vec<P>> = Vec::with_capacity(image_height);
                 let mut pass_indexes: Vec<Option<((usize, usize), usize)>> =
                     repeat_n(None, ADAM7_PASSES.len()).collect();
 
@@ -177,9 +222,10 @@ impl TryFrom<u8> for InterlaceMethod {
         match value {
             0 => Ok(Self::NoInterlace),
             1 => Ok(Self::Adam7),
-            _ => Err(PngParseError(format!(
-                "Unrecognized interlace method {value}"
-            ))),
+            _ => Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                format!("Unrecognized interlace method {value}"),
+            )),
         }
     }
 }
@@ -213,6 +259,19 @@ const ADAM7_PASSES: [&[(usize, &[usize])]; 7] = [
     ],
 ];
 
+const ADAM7_PASS_ORIGINS: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+const ADAM7_PREVIEW_BLOCK_SIZES: [(usize, usize); 7] =
+    [(8, 8), (4, 8), (4, 4), (2, 4), (2, 2), (1, 2), (1, 1)];
+
 const ADAM7_BLOCK_PASSES: [[usize; 8]; 8] = [
     [0, 5, 3, 5, 1, 5, 3, 5],
     [6, 6, 6, 6, 6, 6, 6, 6],
@@ -1,26 +1,67 @@
-use binary_utils::read_bytes;
+use std::{
+    collections::{HashMap, HashSet},
+    error, fmt, io, mem,
+    path::Path,
+};
+
+pub use apng::{
+    decode_apng, decode_apng16, decode_apng16_with_config, decode_apng_with_config, encode_apng,
+    encode_apng16, DecodedApng, PngFrame,
+};
 use chunks::{
+    animation::{Actl, AnimationControl, Fctl, Fdat, FrameControl},
     idat::Idat,
     iend::Iend,
     ihdr::{self},
+    metadata::Hist,
     plte::Plte,
+    text::Text,
+    trns::{Transparency, Trns},
+    unknown::encode_unknown_chunks,
     Chunk,
 };
+pub use chunks::{
+    animation::{BlendOp, DisposeOp},
+    color_space::{Chromaticities, ColorSpace, IccProfile, RenderingIntent},
+    metadata::{
+        ModificationTime, PhysicalDimensions, PhysicalUnit, PngMetadata, SignificantBits,
+        SuggestedPalette, SuggestedPaletteEntry,
+    },
+    text::PngText,
+    unknown::{ChunkPosition, UnknownChunk},
+};
 pub use color_type::ColorType;
-pub use config::{CompressionLevel, PartialPngConfig, PngConfig};
+use color_type::InvalidBitDepthError;
+pub use config::{
+    ChecksumPolicy, CompressionLevel, Dithering, FilterStrategy, PaletteOrder, PartialPngConfig,
+    PngConfig, PngDecodeConfig, Quantization,
+};
 use consts::{
-    IDAT_CHUNK_MAX_SIZE, IDAT_CHUNK_TYPE, IEND_CHUNK_TYPE, PLTE_CHUNK_TYPE, PNG_SIGNATURE,
+    ACTL_CHUNK_TYPE, BKGD_CHUNK_TYPE, CHRM_CHUNK_TYPE, EXIF_CHUNK_TYPE, FCTL_CHUNK_TYPE,
+    FDAT_CHUNK_TYPE, GAMA_CHUNK_TYPE, HIST_CHUNK_TYPE, ICCP_CHUNK_TYPE, IDAT_CHUNK_MAX_SIZE,
+    IDAT_CHUNK_TYPE, IEND_CHUNK_TYPE, IHDR_CHUNK_TYPE, IMAGE_DIMENSION_MAX, ITXT_CHUNK_TYPE,
+    PHYS_CHUNK_TYPE, PLTE_CHUNK_TYPE, PNG_SIGNATURE, SBIT_CHUNK_TYPE, SPLT_CHUNK_TYPE,
+    SRGB_CHUNK_TYPE, TEXT_CHUNK_TYPE, TIME_CHUNK_TYPE, TRNS_CHUNK_TYPE, ZTXT_CHUNK_TYPE,
 };
 use crc::CrcCalculator;
-use deflate::{compress_scanlines, zlib::decode_zlib};
+use deflate::{compress_scanlines, decode_zlib_lenient, DeflateDecodeError};
+pub use filter::AdaptiveFilterType;
 use filter::{filter_scanlines, remove_scanlines_filter};
 use ihdr::Ihdr;
 pub use interlace::InterlaceMethod;
-use palette::{create_pallete_from_colors_median_cut, get_unique_colors};
+pub use optimize::{optimize_png, optimize_png16};
+use palette::{create_pallete_from_colors_median_cut, get_unique_colors, reorder_palette};
+use quantize::quantize_images;
+pub use stream_decoder::{read_png_rows, PngRow, PngRowReader, PngStreamDecoder};
+pub use stream_encoder::PngStreamEncoder;
 
-use crate::colors::Rgba;
+use crate::{
+    colors::{Rgba, Rgba16},
+    error::ErrorKind,
+};
 
 mod adler32;
+mod apng;
 mod binary_utils;
 mod chunks;
 mod color_type;
@@ -30,159 +71,1016 @@ mod crc;
 pub mod deflate;
 mod filter;
 mod interlace;
+mod optimize;
 mod palette;
+mod quantize;
+mod stream_decoder;
+mod stream_encoder;
 
 #[derive(Debug)]
-pub struct PngParseError(String);
+pub struct PngParseError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub offset: Option<usize>,
+    pub chunk_type: Option<[u8; 4]>,
+    source: Option<DeflateDecodeError>,
+}
+
+impl PngParseError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            offset: None,
+            chunk_type: None,
+            source: None,
+        }
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+
+    pub fn in_chunk(mut self, chunk_type: &[u8]) -> Self {
+        if self.chunk_type.is_none() {
+            self.chunk_type = chunk_type.try_into().ok();
+        }
+        self
+    }
+}
+
+impl From<DeflateDecodeError> for PngParseError {
+    fn from(err: DeflateDecodeError) -> Self {
+        let mut png_error = Self::new(err.kind, "failed to decompress image data");
+        png_error.source = Some(err);
+
+        png_error
+    }
+}
+
+impl fmt::Display for PngParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "png parse error ({}): {}", self.kind, self.message)?;
+
+        if let Some(chunk_type) = self.chunk_type {
+            write!(f, " in {} chunk", String::from_utf8_lossy(&chunk_type))?;
+        }
+
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {offset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for PngParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|err| err as &(dyn error::Error + 'static))
+    }
+}
+
+#[derive(Debug)]
+pub enum PngEncodeError {
+    // a color type and bit depth pair that png does not have
+    InvalidBitDepth(ColorType, u8),
+    // a text, sPLT or unknown chunk from the config, or a frame placement, that cannot be written
+    InvalidChunk(PngParseError),
+    // a config option out of range, or one that cannot be used for this image
+    InvalidOption(String),
+    // an indexed color image without a palette to look its colors up in
+    MissingPalette,
+    // a color of an indexed color image that its palette does not hold
+    ColorNotInPalette(Rgba),
+    EmptyImage,
+    RaggedRows {
+        row: usize,
+        width: usize,
+        expected_width: usize,
+    },
+    DimensionsTooLarge {
+        width: usize,
+        height: usize,
+    },
+    // a streamed image was given more or fewer rows than its height
+    RowCount {
+        rows: usize,
+        height: usize,
+    },
+    Io(io::Error),
+}
+
+impl PngEncodeError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            PngEncodeError::InvalidChunk(err) => err.kind,
+            PngEncodeError::DimensionsTooLarge { .. } => ErrorKind::Unsupported,
+            PngEncodeError::Io(_) => ErrorKind::Io,
+            _ => ErrorKind::InvalidData,
+        }
+    }
+}
+
+impl From<InvalidBitDepthError> for PngEncodeError {
+    fn from(InvalidBitDepthError(color_type, bit_depth): InvalidBitDepthError) -> Self {
+        PngEncodeError::InvalidBitDepth(color_type, bit_depth)
+    }
+}
+
+impl From<PngParseError> for PngEncodeError {
+    fn from(err: PngParseError) -> Self {
+        PngEncodeError::InvalidChunk(err)
+    }
+}
+
+impl From<io::Error> for PngEncodeError {
+    fn from(err: io::Error) -> Self {
+        PngEncodeError::Io(err)
+    }
+}
+
+impl fmt::Display for PngEncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "png encode error ({}): ", self.kind())?;
+
+        match self {
+            PngEncodeError::InvalidBitDepth(color_type, bit_depth) => write!(
+                f,
+                "invalid bit depth {bit_depth} for color type {}",
+                u8::from(color_type)
+            ),
+            PngEncodeError::InvalidChunk(err) => f.write_str(&err.message),
+            PngEncodeError::InvalidOption(message) => f.write_str(message),
+            PngEncodeError::MissingPalette => {
+                f.write_str("an indexed color image needs a palette to be encoded")
+            }
+            PngEncodeError::ColorNotInPalette(color) => write!(
+                f,
+                "the palette has no entry for the color ({}, {}, {}, {})",
+                color.r, color.g, color.b, color.a
+            ),
+            PngEncodeError::EmptyImage => f.write_str("the image has no pixels"),
+            PngEncodeError::RaggedRows {
+                row,
+                width,
+                expected_width,
+            } => write!(
+                f,
+                "row {row} has {width} pixels but the image is {expected_width} pixels wide"
+            ),
+            PngEncodeError::DimensionsTooLarge { width, height } => write!(
+                f,
+                "{width}x{height} is larger than the {IMAGE_DIMENSION_MAX} pixels png allows on \
+                 each side"
+            ),
+            PngEncodeError::RowCount { rows, height } => {
+                write!(f, "{rows} rows were written to an image {height} rows high")
+            }
+            PngEncodeError::Io(_) => f.write_str("failed to write the png"),
+        }
+    }
+}
+
+impl error::Error for PngEncodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            PngEncodeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// the width and height of an image, which has to be a non empty rectangle png can hold
+fn check_image_dimensions<P>(pixels: &[Vec<P>]) -> Result<(u32, u32), PngEncodeError> {
+    let expected_width = pixels.first().map_or(0, Vec::len);
+
+    if expected_width == 0 {
+        return Err(PngEncodeError::EmptyImage);
+    }
+
+    if let Some((row, pixel_row)) = pixels
+        .iter()
+        .enumerate()
+        .find(|(_row, pixel_row)| pixel_row.len() != expected_width)
+    {
+        return Err(PngEncodeError::RaggedRows {
+            row,
+            width: pixel_row.len(),
+            expected_width,
+        });
+    }
+
+    if expected_width > IMAGE_DIMENSION_MAX as usize || pixels.len() > IMAGE_DIMENSION_MAX as usize
+    {
+        return Err(PngEncodeError::DimensionsTooLarge {
+            width: expected_width,
+            height: pixels.len(),
+        });
+    }
+
+    Ok((expected_width as u32, pixels.len() as u32))
+}
 
 #[macro_export]
 macro_rules! png_assert {
     ($assert_value:expr, $msg:expr) => {
         if !$assert_value {
-            return Err(PngParseError(format!("png parse error: {}", $msg)));
+            return Err($crate::png::PngParseError::new(
+                $crate::ErrorKind::InvalidData,
+                $msg,
+            ));
         }
     };
 }
 
+pub fn is_png_by_signature(file: &[u8]) -> bool {
+    file.starts_with(PNG_SIGNATURE)
+}
+
+pub fn is_png_by_extension(filename: &str) -> bool {
+    Path::new(filename)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("png"))
+}
+
+#[derive(Debug)]
+pub struct DecodedPng<P = Rgba> {
+    pub pixels: Vec<Vec<P>>,
+    pub text: Vec<PngText>,
+    pub color_space: ColorSpace,
+    pub metadata: PngMetadata,
+    pub unknown_chunks: Vec<UnknownChunk>,
+    pub warnings: Vec<PngParseError>,
+}
+
+#[derive(Debug)]
+pub struct EncodedPng {
+    pub bytes: Vec<u8>,
+    // the filter each scanline got, in the order the scanlines are stored. that is pass by pass for
+    // an interlaced image, and frame after frame for an animated one
+    pub row_filters: Vec<AdaptiveFilterType>,
+}
+
 pub fn decode_png(bytes: &[u8]) -> Result<Vec<Vec<Rgba>>, PngParseError> {
-    let mut offset: usize = 0;
-    let siganture = read_bytes(&mut offset, bytes, PNG_SIGNATURE.len());
+    Ok(decode_png_with_config(bytes, PngDecodeConfig::new())?.pixels)
+}
+
+pub fn decode_png16(bytes: &[u8]) -> Result<Vec<Vec<Rgba16>>, PngParseError> {
+    Ok(decode_png16_with_config(bytes, PngDecodeConfig::new())?.pixels)
+}
+
+pub fn decode_png_with_config(
+    bytes: &[u8],
+    config: PngDecodeConfig,
+) -> Result<DecodedPng, PngParseError> {
+    let decoded_png = decode_png16_with_config(bytes, config)?;
+
+    Ok(DecodedPng {
+        pixels: to_8_bit_pixels(decoded_png.pixels),
+        text: decoded_png.text,
+        color_space: decoded_png.color_space,
+        metadata: decoded_png.metadata,
+        unknown_chunks: decoded_png.unknown_chunks,
+        warnings: decoded_png.warnings,
+    })
+}
 
-    if *siganture != *PNG_SIGNATURE {
-        return Err(PngParseError(
-            "File does not appear to be a png file (signature missing)".to_string(),
-        ));
+pub fn decode_png16_with_config(
+    bytes: &[u8],
+    config: PngDecodeConfig,
+) -> Result<DecodedPng<Rgba16>, PngParseError> {
+    let mut png_chunks = read_chunks(bytes, &config)?;
+    let image_data = mem::take(&mut png_chunks.image_data);
+    let mut image = png_chunks.decode_image(
+        &image_data,
+        png_chunks.ihdr.width,
+        png_chunks.ihdr.height,
+        png_chunks.image_data_offset.unwrap_or(bytes.len()),
+        IDAT_CHUNK_TYPE,
+    )?;
+
+    if config.convert_to_srgb && png_chunks.color_space.convert_to_srgb(&mut image) {
+        png_chunks.color_space = ColorSpace::srgb(RenderingIntent::Perceptual);
+    }
+
+    Ok(png_chunks.into_decoded_png(image))
+}
+
+// everything gathered from the chunks of a file before any image data is decompressed
+struct PngChunks {
+    ihdr: Ihdr,
+    palette: Option<Vec<Rgba>>,
+    transparency: Option<Transparency>,
+    text: Vec<PngText>,
+    color_space: ColorSpace,
+    metadata: PngMetadata,
+    unknown_chunks: Vec<UnknownChunk>,
+    warnings: Vec<PngParseError>,
+    checksum_policy: ChecksumPolicy,
+    crc_calculator: CrcCalculator,
+    image_data: Vec<u8>,
+    image_data_offset: Option<usize>,
+    animation_control: Option<AnimationControl>,
+    frames: Vec<FrameData>,
+    next_sequence_number: u32,
+}
+
+struct FrameData {
+    frame_control: FrameControl,
+    compressed_data: Vec<u8>,
+    // the first frame can reuse the IDAT stream instead of having fdAT chunks of its own
+    is_default_image: bool,
+    offset: usize,
+}
+
+impl PngChunks {
+    fn decode_image(
+        &mut self,
+        compressed_data: &[u8],
+        width: u32,
+        height: u32,
+        data_offset: usize,
+        chunk_type: &[u8],
+    ) -> Result<Vec<Vec<Rgba16>>, PngParseError> {
+        let (width, height) = (width as usize, height as usize);
+        let bbp = self.ihdr.get_bits_per_pixel();
+        let (uncompressed_data, checksum_error) =
+            decode_zlib_lenient(compressed_data).map_err(|err| {
+                PngParseError::from(err)
+                    .in_chunk(chunk_type)
+                    .at_offset(data_offset)
+            })?;
+
+        if let Some(err) = checksum_error {
+            apply_checksum_policy(
+                Err(PngParseError::from(err)
+                    .in_chunk(chunk_type)
+                    .at_offset(data_offset)),
+                self.checksum_policy,
+                &mut self.warnings,
+            )?;
+        }
+
+        let reduced_images_scanlines = self.ihdr.interlace_method.reconstruct_filtered_scanlines(
+            &uncompressed_data,
+            height,
+            width,
+            bbp,
+        )?;
+
+        let reduced_images = reduced_images_scanlines
+            .into_iter()
+            .map(|reduced_image_scanlines| {
+                let scanlines = remove_scanlines_filter(&reduced_image_scanlines, bbp)?;
+                let reduced_image_pixels = self.ihdr.color_type.scanline_to_pixels(
+                    &scanlines,
+                    self.ihdr.bit_depth,
+                    width,
+                    &self.palette,
+                    &self.transparency,
+                )?;
+
+                Ok(reduced_image_pixels)
+            })
+            .collect::<Result<Vec<Vec<Vec<Rgba16>>>, PngParseError>>()?;
+
+        Ok(self
+            .ihdr
+            .interlace_method
+            .deinterlace_image(reduced_images, height, width))
     }
-    let ihdr_chunk = Ihdr::from_chunk(Chunk::from_bytes(bytes, &mut offset)?)?;
-    ihdr_chunk.check_compatibility()?;
-    let mut palette: Option<Vec<Rgba>> = None;
-    let mut compressed_data: Vec<u8> = Vec::new();
+
+    fn into_decoded_png<P>(self, pixels: Vec<Vec<P>>) -> DecodedPng<P> {
+        DecodedPng {
+            pixels,
+            text: self.text,
+            color_space: self.color_space,
+            metadata: self.metadata,
+            unknown_chunks: self.unknown_chunks,
+            warnings: self.warnings,
+        }
+    }
+}
+
+fn read_chunks(bytes: &[u8], config: &PngDecodeConfig) -> Result<PngChunks, PngParseError> {
+    let mut warnings = Vec::new();
+    let mut offset: usize = 0;
+    let ihdr_chunk = read_png_header(bytes, &mut offset, config, &mut warnings)?;
+    let mut png_chunks = PngChunks::new(ihdr_chunk, config.checksum_policy, warnings);
 
     loop {
+        let chunk_offset = offset;
         let chunk = Chunk::from_bytes(bytes, &mut offset)?;
 
+        if !png_chunks.read_chunk(&chunk, chunk_offset)? {
+            break;
+        }
+    }
+
+    Ok(png_chunks)
+}
+
+// checks the signature and reads the IHDR chunk that must follow it
+fn read_png_header(
+    bytes: &[u8],
+    offset: &mut usize,
+    config: &PngDecodeConfig,
+    warnings: &mut Vec<PngParseError>,
+) -> Result<Ihdr, PngParseError> {
+    let mut crc_calculator = CrcCalculator::new();
+    let remaining_bytes = bytes.get(*offset..).unwrap_or_default();
+
+    match remaining_bytes.get(..PNG_SIGNATURE.len()) {
+        Some(signature) if *signature == *PNG_SIGNATURE => *offset += PNG_SIGNATURE.len(),
+        None if PNG_SIGNATURE.starts_with(remaining_bytes) => {
+            return Err(PngParseError::new(
+                ErrorKind::UnexpectedEof,
+                "File ends before the png signature is complete",
+            )
+            .at_offset(*offset + remaining_bytes.len()))
+        }
+        _ => {
+            return Err(PngParseError::new(
+                ErrorKind::InvalidSignature,
+                "File does not appear to be a png file (signature missing)",
+            ))
+        }
+    }
+    let ihdr_offset = *offset;
+
+    Chunk::from_bytes(bytes, offset)
+        .and_then(|chunk| {
+            apply_checksum_policy(
+                chunk.verify_crc(&mut crc_calculator),
+                config.checksum_policy,
+                warnings,
+            )?;
+            Ihdr::from_chunk(chunk)
+        })
+        .and_then(|ihdr_chunk| ihdr_chunk.check_compatibility().map(|_| ihdr_chunk))
+        .map_err(|err| err.in_chunk(IHDR_CHUNK_TYPE).at_offset(ihdr_offset))
+}
+
+impl PngChunks {
+    fn new(ihdr: Ihdr, checksum_policy: ChecksumPolicy, warnings: Vec<PngParseError>) -> Self {
+        Self {
+            ihdr,
+            palette: None,
+            transparency: None,
+            text: Vec::new(),
+            color_space: ColorSpace::default(),
+            metadata: PngMetadata::default(),
+            unknown_chunks: Vec::new(),
+            warnings,
+            checksum_policy,
+            crc_calculator: CrcCalculator::new(),
+            image_data: Vec::new(),
+            image_data_offset: None,
+            animation_control: None,
+            frames: Vec::new(),
+            next_sequence_number: 0,
+        }
+    }
+
+    // reads any chunk after IHDR, returning false once IEND is reached
+    fn read_chunk(&mut self, chunk: &Chunk, chunk_offset: usize) -> Result<bool, PngParseError> {
+        apply_checksum_policy(
+            chunk
+                .verify_crc(&mut self.crc_calculator)
+                .map_err(|err| err.at_offset(chunk_offset)),
+            self.checksum_policy,
+            &mut self.warnings,
+        )?;
+
         match chunk.chunk_type {
-            IDAT_CHUNK_TYPE => compressed_data.extend_from_slice(chunk.chunk_data),
+            IDAT_CHUNK_TYPE => {
+                self.start_image_data(chunk_offset)?;
+                self.image_data.extend_from_slice(chunk.chunk_data);
+            }
             IEND_CHUNK_TYPE => {
-                break;
+                return Ok(false);
             }
-            PLTE_CHUNK_TYPE => match palette {
+            PLTE_CHUNK_TYPE => match self.palette {
                 Some(_) => {
-                    return Err(PngParseError(
-                        "PLTE chunks appears more than once".to_string(),
-                    ))
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        "PLTE chunks appears more than once",
+                    )
+                    .in_chunk(PLTE_CHUNK_TYPE)
+                    .at_offset(chunk_offset))
+                }
+                None => {
+                    self.palette = Some(
+                        Plte::decode_palette(chunk.chunk_data)
+                            .map_err(|err| err.in_chunk(PLTE_CHUNK_TYPE).at_offset(chunk_offset))?,
+                    )
                 }
-                None => palette = Some(Plte::decode_palette(chunk.chunk_data)?),
             },
+            TRNS_CHUNK_TYPE => {
+                if self.transparency.is_some() || self.image_data_offset.is_some() {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        "tRNS chunk must appear at most once and before the first IDAT chunk",
+                    )
+                    .in_chunk(TRNS_CHUNK_TYPE)
+                    .at_offset(chunk_offset));
+                }
+
+                self.transparency = Some(
+                    Trns::decode_transparency(
+                        chunk.chunk_data,
+                        self.ihdr.color_type,
+                        &self.palette,
+                    )
+                    .map_err(|err| err.in_chunk(TRNS_CHUNK_TYPE).at_offset(chunk_offset))?,
+                );
+            }
+            GAMA_CHUNK_TYPE | CHRM_CHUNK_TYPE | SRGB_CHUNK_TYPE | ICCP_CHUNK_TYPE => {
+                if self.palette.is_some() || self.image_data_offset.is_some() {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        "color space chunks must come before the PLTE and IDAT chunks",
+                    )
+                    .in_chunk(chunk.chunk_type)
+                    .at_offset(chunk_offset));
+                }
+
+                self.color_space
+                    .decode_chunk(chunk.chunk_type, chunk.chunk_data)
+                    .map_err(|err| err.in_chunk(chunk.chunk_type).at_offset(chunk_offset))?;
+            }
+            SBIT_CHUNK_TYPE | BKGD_CHUNK_TYPE | HIST_CHUNK_TYPE | PHYS_CHUNK_TYPE
+            | SPLT_CHUNK_TYPE | TIME_CHUNK_TYPE | EXIF_CHUNK_TYPE => {
+                let is_misplaced = match chunk.chunk_type {
+                    SBIT_CHUNK_TYPE => self.palette.is_some() || self.image_data_offset.is_some(),
+                    BKGD_CHUNK_TYPE | HIST_CHUNK_TYPE | PHYS_CHUNK_TYPE | SPLT_CHUNK_TYPE => {
+                        self.image_data_offset.is_some()
+                    }
+                    _ => false,
+                };
+
+                if is_misplaced {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "{} chunk must come before the IDAT chunks",
+                            String::from_utf8_lossy(chunk.chunk_type)
+                        ),
+                    )
+                    .in_chunk(chunk.chunk_type)
+                    .at_offset(chunk_offset));
+                }
+
+                self.metadata
+                    .decode_chunk(
+                        chunk.chunk_type,
+                        chunk.chunk_data,
+                        self.ihdr.color_type,
+                        self.ihdr.bit_depth,
+                        &self.palette,
+                    )
+                    .map_err(|err| err.in_chunk(chunk.chunk_type).at_offset(chunk_offset))?;
+            }
+            ACTL_CHUNK_TYPE => {
+                if self.animation_control.is_some() || self.image_data_offset.is_some() {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        "acTL chunk must appear at most once and before the first IDAT chunk",
+                    )
+                    .in_chunk(ACTL_CHUNK_TYPE)
+                    .at_offset(chunk_offset));
+                }
+
+                self.animation_control = Some(
+                    Actl::decode_animation_control(chunk.chunk_data)
+                        .map_err(|err| err.in_chunk(ACTL_CHUNK_TYPE).at_offset(chunk_offset))?,
+                );
+            }
+            FCTL_CHUNK_TYPE => {
+                let is_default_image = self.image_data_offset.is_none();
+                let frame_control = Fctl::decode_frame_control(chunk.chunk_data)
+                    .and_then(|frame_control| {
+                        png_assert!(
+                            !(is_default_image && !self.frames.is_empty()),
+                            "only one fcTL chunk can come before the IDAT chunks"
+                        );
+                        check_sequence_number(
+                            frame_control.sequence_number,
+                            &mut self.next_sequence_number,
+                        )?;
+                        Fctl::check_fits_canvas(&frame_control, self.ihdr.width, self.ihdr.height)?;
+
+                        Ok(frame_control)
+                    })
+                    .map_err(|err| err.in_chunk(FCTL_CHUNK_TYPE).at_offset(chunk_offset))?;
+
+                self.frames.push(FrameData {
+                    frame_control,
+                    compressed_data: Vec::new(),
+                    is_default_image,
+                    offset: chunk_offset,
+                });
+            }
+            FDAT_CHUNK_TYPE => {
+                let frame_data = Fdat::decode_bytes(chunk.chunk_data)
+                    .and_then(|(sequence_number, frame_data)| {
+                        check_sequence_number(sequence_number, &mut self.next_sequence_number)?;
+
+                        match self.frames.last_mut() {
+                        Some(frame) if !frame.is_default_image => Ok((frame, frame_data)),
+                        _ => Err(PngParseError::new(
+                            ErrorKind::InvalidData,
+                            "fdAT chunk must follow an fcTL chunk that comes after the IDAT chunks",
+                        )),
+                    }
+                    })
+                    .map_err(|err| err.in_chunk(FDAT_CHUNK_TYPE).at_offset(chunk_offset))?;
+                let (frame, frame_data) = frame_data;
+                frame.compressed_data.extend_from_slice(frame_data);
+            }
+            TEXT_CHUNK_TYPE | ZTXT_CHUNK_TYPE | ITXT_CHUNK_TYPE => {
+                self.text.push(
+                    Text::decode_text(chunk.chunk_type, chunk.chunk_data)
+                        .map_err(|err| err.in_chunk(chunk.chunk_type).at_offset(chunk_offset))?,
+                );
+            }
             chunk_type => {
-                if chunk_type[0] & 32 == 0 {
-                    return Err(PngParseError(format!(
-                        "Unrecognized critical chunk: {:?}",
-                        chunk.chunk_type
-                    )));
+                if !UnknownChunk::is_ancillary(chunk_type) {
+                    return Err(PngParseError::new(
+                        ErrorKind::Unsupported,
+                        format!(
+                            "Unrecognized critical chunk: {}",
+                            String::from_utf8_lossy(chunk_type)
+                        ),
+                    )
+                    .in_chunk(chunk_type)
+                    .at_offset(chunk_offset));
+                }
+
+                // chunks that are not safe to copy may depend on image data we could change
+                if UnknownChunk::is_safe_to_copy(chunk_type) {
+                    let position = if self.image_data_offset.is_some() {
+                        ChunkPosition::AfterImageData
+                    } else if self.palette.is_some() {
+                        ChunkPosition::BeforeImageData
+                    } else {
+                        ChunkPosition::BeforePalette
+                    };
+
+                    self.unknown_chunks.push(UnknownChunk::new(
+                        [chunk_type[0], chunk_type[1], chunk_type[2], chunk_type[3]],
+                        chunk.chunk_data.to_vec(),
+                        position,
+                    ));
                 }
             }
         }
+
+        Ok(true)
+    }
+
+    // the palette has to be complete by the first IDAT chunk
+    fn start_image_data(&mut self, chunk_offset: usize) -> Result<(), PngParseError> {
+        if self.image_data_offset.is_some() {
+            return Ok(());
+        }
+
+        if matches!(self.ihdr.color_type, ColorType::IndexedColor) && self.palette.is_none() {
+            return Err(PngParseError::new(
+                ErrorKind::InvalidData,
+                "No PLTE chunk for indexed color",
+            )
+            .in_chunk(IDAT_CHUNK_TYPE)
+            .at_offset(chunk_offset));
+        }
+
+        if let (Some(palette), Some(transparency)) = (&mut self.palette, &self.transparency) {
+            transparency.apply_to_palette(palette);
+        }
+        self.image_data_offset = Some(chunk_offset);
+
+        Ok(())
     }
+}
 
+fn check_sequence_number(
+    sequence_number: u32,
+    next_sequence_number: &mut u32,
+) -> Result<(), PngParseError> {
     png_assert!(
-        !(matches!(ihdr_chunk.color_type, ColorType::IndexedColor) && palette.is_none()),
-        "No PLTE chunk for indexed color".to_string()
+        sequence_number == *next_sequence_number,
+        format!(
+            "sequence number {sequence_number} is out of order, expected {}",
+            *next_sequence_number
+        )
     );
+    *next_sequence_number += 1;
 
-    let bbp = ihdr_chunk.get_bits_per_pixel();
-    let uncompressed_data = match decode_zlib(&compressed_data) {
-        Ok(data) => data,
-        Err(deflate_error) => {
-            return Err(PngParseError(deflate_error.0));
+    Ok(())
+}
+
+fn apply_checksum_policy(
+    checksum_result: Result<(), PngParseError>,
+    checksum_policy: ChecksumPolicy,
+    warnings: &mut Vec<PngParseError>,
+) -> Result<(), PngParseError> {
+    match (checksum_result, checksum_policy) {
+        (Err(err), ChecksumPolicy::Warn) => {
+            warnings.push(err);
+            Ok(())
         }
-    };
-    let reduced_images_scanlines = ihdr_chunk.interlace_method.reconstruct_filtered_scanlines(
-        &uncompressed_data,
-        ihdr_chunk.height as usize,
-        ihdr_chunk.width as usize,
-        ihdr_chunk.get_bits_per_pixel(),
-    )?;
+        (checksum_result, _) => checksum_result,
+    }
+}
 
-    let reduced_images = reduced_images_scanlines
+fn to_8_bit_pixels(pixels: Vec<Vec<Rgba16>>) -> Vec<Vec<Rgba>> {
+    pixels
         .into_iter()
-        .map(|reduced_image_scanlines| {
-            let scanlines = remove_scanlines_filter(&reduced_image_scanlines, bbp)?;
-            let reduced_image_pixels = ihdr_chunk.color_type.scanline_to_pixels(
-                &scanlines,
-                ihdr_chunk.bit_depth,
-                ihdr_chunk.width as usize,
-                &palette,
-            )?;
+        .map(|row| row.iter().map(Rgba::from).collect())
+        .collect()
+}
 
-            Ok(reduced_image_pixels)
-        })
-        .collect::<Result<Vec<Vec<Vec<Rgba>>>, PngParseError>>()?;
-    let image = ihdr_chunk.interlace_method.deinterlace_image(
-        reduced_images,
-        ihdr_chunk.height as usize,
-        ihdr_chunk.width as usize,
-    );
+pub fn encode_png(
+    pixels: Vec<Vec<Rgba>>,
+    partial_config: PartialPngConfig,
+) -> Result<Vec<u8>, PngEncodeError> {
+    Ok(encode_png_with_report(pixels, partial_config)?.bytes)
+}
+
+pub fn encode_png16(
+    pixels: Vec<Vec<Rgba16>>,
+    partial_config: PartialPngConfig,
+) -> Result<Vec<u8>, PngEncodeError> {
+    Ok(encode_png16_with_report(pixels, partial_config)?.bytes)
+}
+
+pub fn encode_png_with_report(
+    pixels: Vec<Vec<Rgba>>,
+    partial_config: PartialPngConfig,
+) -> Result<EncodedPng, PngEncodeError> {
+    let pixels = pixels
+        .into_iter()
+        .map(|row| row.iter().map(Rgba16::from).collect())
+        .collect();
+
+    encode_png16_with_report(pixels, partial_config)
+}
 
-    Ok(image)
+pub fn encode_png16_with_report(
+    pixels: Vec<Vec<Rgba16>>,
+    partial_config: PartialPngConfig,
+) -> Result<EncodedPng, PngEncodeError> {
+    encode_images(vec![pixels], None, partial_config)
 }
 
-pub fn encode_png(pixels: Vec<Vec<Rgba>>, partial_config: PartialPngConfig) -> Vec<u8> {
-    let unique_colors = get_unique_colors(&pixels[..]);
-    let config = PngConfig::create_from_partial(partial_config, &unique_colors[..]);
+// a still image is a single image without animation control, an animated one has an fcTL chunk for
+// each image and the first image doubles as the default image in IDAT
+fn encode_images(
+    images: Vec<Vec<Vec<Rgba16>>>,
+    animation: Option<(AnimationControl, Vec<FrameControl>)>,
+    partial_config: PartialPngConfig,
+) -> Result<EncodedPng, PngEncodeError> {
+    let (width, height) = match images.first() {
+        Some(image) => check_image_dimensions(image)?,
+        None => return Err(PngEncodeError::EmptyImage),
+    };
+
+    for image in images.iter().skip(1) {
+        check_image_dimensions(image)?;
+    }
+
+    let unique_colors = get_unique_colors(images.iter().flatten());
+    let config = PngConfig::create_from_partial(partial_config, &unique_colors[..])?;
+    let (images, unique_colors) = match config.quantization {
+        Some(ref quantization) => {
+            let images = quantize_images(images, quantization, 1 << config.bit_depth);
+            let unique_colors = get_unique_colors(images.iter().flatten());
+
+            (images, unique_colors)
+        }
+        None => (images, unique_colors),
+    };
     let palette = match config.color_type {
         ColorType::IndexedColor => {
+            let unique_colors: Vec<Rgba> = unique_colors
+                .iter()
+                .map(Rgba::from)
+                .collect::<HashSet<Rgba>>()
+                .into_iter()
+                .collect();
             let palette =
                 create_pallete_from_colors_median_cut(&unique_colors, config.bit_depth as usize);
 
-            Some(palette)
+            match config.palette_order {
+                Some(palette_order) => Some(reorder_palette(
+                    palette,
+                    palette_order,
+                    images.iter().flatten().flatten(),
+                )),
+                None => Some(palette),
+            }
         }
         _ => None,
     };
 
     let mut crc = CrcCalculator::new();
     let ihdr = Ihdr::new(
-        pixels[0].len() as u32,
-        pixels.len() as u32,
+        width,
+        height,
         config.color_type,
         config.bit_depth,
         config.interlace_method,
     );
+    let palette_frequencies = palette.as_ref().map(|palette| {
+        let mut counts = vec![
+            0;
+            palette
+                .values()
+                .map(|(idx, _color)| idx + 1)
+                .max()
+                .unwrap_or(0)
+        ];
+
+        for pixel in images[0].iter().flatten() {
+            if let Some((idx, _color)) = palette.get(&Rgba::from(pixel)) {
+                counts[*idx] += 1;
+            }
+        }
+
+        Hist::palette_frequencies(&counts)
+    });
+    let transparency = match palette {
+        Some(ref palette) => Transparency::from_palette(palette),
+        None => config.transparency_key_color.as_ref().and_then(|color| {
+            config
+                .color_type
+                .create_transparency_key(color, config.bit_depth)
+        }),
+    };
+
+    let mut encoded_png = encode_chunks_before_image_data(
+        &ihdr,
+        animation
+            .as_ref()
+            .map(|(animation_control, _)| animation_control),
+        &config,
+        &palette,
+        &palette_frequencies,
+        &transparency,
+        &mut crc,
+    )?;
+
+    // fcTL and fdAT chunks share one sequence
+    let mut sequence_number = 0;
+    let mut row_filters = Vec::new();
+
+    for (idx, image) in images.into_iter().enumerate() {
+        if let Some((_, ref frame_controls)) = animation {
+            let frame_control = FrameControl {
+                sequence_number,
+                ..frame_controls[idx]
+            };
+            encoded_png.extend_from_slice(&Fctl::encode_frame_control(&frame_control, &mut crc));
+            sequence_number += 1;
+        }
+
+        let (compressed_data, image_row_filters) = compress_image(image, &config, &palette)?;
+        row_filters.extend(image_row_filters);
+
+        if idx == 0 {
+            compressed_data
+                .chunks(config.idat_size.unwrap_or(IDAT_CHUNK_MAX_SIZE as usize))
+                .for_each(|chunk_data| {
+                    let chunk = Idat::encode_bytes(chunk_data, &mut crc);
+
+                    encoded_png.extend_from_slice(&chunk);
+                });
+        } else {
+            compressed_data
+                .chunks(config.idat_size.map_or(Fdat::MAX_DATA_SIZE, |idat_size| {
+                    idat_size.min(Fdat::MAX_DATA_SIZE)
+                }))
+                .for_each(|chunk_data| {
+                    let chunk = Fdat::encode_bytes(sequence_number, chunk_data, &mut crc);
+                    sequence_number += 1;
+
+                    encoded_png.extend_from_slice(&chunk);
+                });
+        }
+    }
+
+    encoded_png.extend_from_slice(&encode_chunks_after_image_data(&config, &mut crc));
+
+    Ok(EncodedPng {
+        bytes: encoded_png,
+        row_filters,
+    })
+}
+
+// the signature, IHDR and every chunk that has to come before the image data
+fn encode_chunks_before_image_data(
+    ihdr: &Ihdr,
+    animation_control: Option<&AnimationControl>,
+    config: &PngConfig,
+    palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
+    palette_frequencies: &Option<Vec<u16>>,
+    transparency: &Option<Transparency>,
+    crc: &mut CrcCalculator,
+) -> Result<Vec<u8>, PngEncodeError> {
     let mut encoded_png: Vec<u8> = Vec::new();
     encoded_png.extend_from_slice(PNG_SIGNATURE);
-    encoded_png.extend_from_slice(&ihdr.to_bytes(&mut crc));
+    encoded_png.extend_from_slice(&ihdr.to_bytes(crc));
 
-    let reduced_images = config.interlace_method.perform_pass_extraction(pixels);
+    if let Some(animation_control) = animation_control {
+        encoded_png.extend_from_slice(&Actl::encode_animation_control(animation_control, crc));
+    }
 
-    let mut all_filtered_scanlines: Vec<Vec<u8>> = Vec::new();
+    encoded_png.extend_from_slice(
+        &config
+            .color_space
+            .encode_chunks(config.compression_level, crc),
+    );
+    encoded_png.extend_from_slice(&config.metadata.encode_chunks_before_palette(
+        config.color_type,
+        config.bit_depth,
+        crc,
+    ));
+    encoded_png.extend_from_slice(&encode_unknown_chunks(
+        &config.unknown_chunks,
+        ChunkPosition::BeforePalette,
+        crc,
+    ));
+
+    if let Some(palette) = palette {
+        encoded_png.extend_from_slice(&Plte::encode_palette(palette, crc));
+    }
 
-    for reduced_image in reduced_images.iter() {
-        let scanlines = config
-            .color_type
-            .create_scanlines(reduced_image, ihdr.bit_depth, &palette);
-        let filtered_scanlines = filter_scanlines(
-            &scanlines,
-            ihdr.get_bits_per_pixel(),
-            config.compression_level,
-        );
-        all_filtered_scanlines.extend_from_slice(&filtered_scanlines);
+    if let Some(transparency) = transparency {
+        encoded_png.extend_from_slice(&Trns::encode_transparency(transparency, crc));
     }
 
-    let compressed_data = compress_scanlines(&all_filtered_scanlines, config.compression_level);
+    encoded_png.extend_from_slice(&config.metadata.encode_chunks_before_image_data(
+        config.color_type,
+        config.bit_depth,
+        palette,
+        palette_frequencies,
+        crc,
+    )?);
 
-    if let Some(ref palette) = palette {
-        encoded_png.extend_from_slice(&Plte::encode_palette(palette, &mut crc));
+    for text in config.text.iter() {
+        encoded_png.extend_from_slice(&Text::encode_text(text, config.compression_level, crc));
     }
 
-    compressed_data
-        .chunks(IDAT_CHUNK_MAX_SIZE as usize)
-        .for_each(|chunk_data| {
-            let chunk = Idat::encode_bytes(chunk_data, &mut crc);
+    encoded_png.extend_from_slice(&encode_unknown_chunks(
+        &config.unknown_chunks,
+        ChunkPosition::BeforeImageData,
+        crc,
+    ));
 
-            encoded_png.extend_from_slice(&chunk);
-        });
+    Ok(encoded_png)
+}
 
-    encoded_png.extend_from_slice(&Iend::to_bytes(&mut crc));
+fn encode_chunks_after_image_data(config: &PngConfig, crc: &mut CrcCalculator) -> Vec<u8> {
+    let mut encoded_png = config.metadata.encode_chunks_after_image_data(crc);
+    encoded_png.extend_from_slice(&encode_unknown_chunks(
+        &config.unknown_chunks,
+        ChunkPosition::AfterImageData,
+        crc,
+    ));
+    encoded_png.extend_from_slice(&Iend::to_bytes(crc));
 
     encoded_png
 }
+
+fn compress_image(
+    pixels: Vec<Vec<Rgba16>>,
+    config: &PngConfig,
+    palette: &Option<HashMap<Rgba, (usize, Rgba)>>,
+) -> Result<(Vec<u8>, Vec<AdaptiveFilterType>), PngEncodeError> {
+    let bits_per_pixel = config.bit_depth as usize * config.color_type.samples_per_pixel();
+    let reduced_images = config.interlace_method.perform_pass_extraction(pixels);
+
+    let mut all_filtered_scanlines: Vec<Vec<u8>> = Vec::new();
+    let mut row_filters: Vec<AdaptiveFilterType> = Vec::new();
+
+    for reduced_image in reduced_images.iter().filter(|image| !image.is_empty()) {
+        let scanlines =
+            config
+                .color_type
+                .create_scanlines(reduced_image, config.bit_depth, palette)?;
+        for (filter, filtered_scanline) in
+            filter_scanlines(&scanlines, bits_per_pixel, config.filter_strategy)
+        {
+            row_filters.push(filter);
+            all_filtered_scanlines.push(filtered_scanline);
+        }
+    }
+
+    Ok((
+        compress_scanlines(&all_filtered_scanlines, config.compression_level),
+        row_filters,
+    ))
+}
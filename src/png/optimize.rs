@@ -0,0 +1,189 @@
+use crate::{colors::Rgba16, error::ErrorKind, Error};
+
+use super::{
+    check_image_dimensions, decode_png16, decode_png16_with_config, encode_png16,
+    palette::find_transparency_key_color, palette::get_unique_colors, read_chunks,
+    AdaptiveFilterType, ColorType, CompressionLevel, FilterStrategy, InterlaceMethod, PaletteOrder,
+    PartialPngConfig, PngDecodeConfig, PngEncodeError, PngParseError,
+};
+
+const FILTER_STRATEGIES: [FilterStrategy; 8] = [
+    FilterStrategy::Fixed(AdaptiveFilterType::None),
+    FilterStrategy::Fixed(AdaptiveFilterType::Sub),
+    FilterStrategy::Fixed(AdaptiveFilterType::Up),
+    FilterStrategy::Fixed(AdaptiveFilterType::Average),
+    FilterStrategy::Fixed(AdaptiveFilterType::Paeth),
+    FilterStrategy::MinimumSumOfAbsoluteDifferences,
+    FilterStrategy::Entropy,
+    FilterStrategy::BruteForce,
+];
+// color types are compared with a filter strategy that is quick but close to the best one
+const QUICK_FILTER_STRATEGY: FilterStrategy = FilterStrategy::MinimumSumOfAbsoluteDifferences;
+
+// re-encodes a png as small as this encoder can make it, keeping its text, color space, metadata
+// and unknown chunks. the original bytes come back when nothing beats them
+pub fn optimize_png(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    if read_chunks(bytes, &PngDecodeConfig::new())?
+        .animation_control
+        .is_some()
+    {
+        return Err(PngParseError::new(
+            ErrorKind::Unsupported,
+            "Optimizing an animated png is not supported",
+        )
+        .into());
+    }
+
+    let image = decode_png16_with_config(bytes, PngDecodeConfig::new())?;
+    let mut partial_config = PartialPngConfig::new()
+        .color_space(image.color_space)
+        .metadata(image.metadata);
+
+    for text in image.text {
+        partial_config = partial_config.text(text);
+    }
+
+    for unknown_chunk in image.unknown_chunks {
+        partial_config = partial_config.unknown_chunk(unknown_chunk);
+    }
+
+    let optimized_png = optimize_png16(image.pixels, partial_config)?;
+
+    Ok(if optimized_png.len() < bytes.len() {
+        optimized_png
+    } else {
+        bytes.to_vec()
+    })
+}
+
+// tries color type and bit depth reductions, palette orders, filter strategies and compression
+// levels, one after the other, and keeps the smallest encoding that decodes to the same pixels.
+// the color type, bit depth, interlacing, filter strategy and compression level of the config are
+// all picked here
+pub fn optimize_png16(
+    pixels: Vec<Vec<Rgba16>>,
+    partial_config: PartialPngConfig,
+) -> Result<Vec<u8>, PngEncodeError> {
+    check_image_dimensions(&pixels)?;
+
+    let unique_colors = get_unique_colors(pixels.iter());
+    let base_config = partial_config.interlace_method(InterlaceMethod::NoInterlace);
+    let mut smallest: Option<(PartialPngConfig, Vec<u8>)> = None;
+    let mut first_error = None;
+
+    for (color_type, bit_depth, palette_order) in color_candidates(&unique_colors) {
+        let mut config = base_config
+            .clone()
+            .color_type(color_type)
+            .bit_depth(bit_depth)
+            .compression_level(CompressionLevel::FAST)
+            .filter_strategy(QUICK_FILTER_STRATEGY);
+
+        if let Some(palette_order) = palette_order {
+            config = config.palette_order(palette_order);
+        }
+
+        if let Err(err) = keep_if_smaller(&mut smallest, config, &pixels) {
+            first_error.get_or_insert(err);
+        }
+    }
+
+    // only an option that no candidate can encode with gets here without a smallest encoding
+    let Some((color_config, _bytes)) = smallest.clone() else {
+        return Err(first_error.expect(
+            "Truecolor with alpha at full depth holds the pixels exactly when it encodes",
+        ));
+    };
+
+    // from here on every candidate only changes how an encoding that works is compressed, so one
+    // that fails is simply not kept
+    for filter_strategy in FILTER_STRATEGIES
+        .into_iter()
+        .filter(|filter_strategy| *filter_strategy != QUICK_FILTER_STRATEGY)
+    {
+        keep_if_smaller(
+            &mut smallest,
+            color_config.clone().filter_strategy(filter_strategy),
+            &pixels,
+        )
+        .ok();
+    }
+
+    let (filter_config, _bytes) = smallest.clone().unwrap();
+    keep_if_smaller(
+        &mut smallest,
+        filter_config.compression_level(CompressionLevel::BEST),
+        &pixels,
+    )
+    .ok();
+
+    Ok(smallest.unwrap().1)
+}
+
+// a candidate that fails to encode leaves the smallest encoding as it was
+fn keep_if_smaller(
+    smallest: &mut Option<(PartialPngConfig, Vec<u8>)>,
+    config: PartialPngConfig,
+    pixels: &[Vec<Rgba16>],
+) -> Result<(), PngEncodeError> {
+    let bytes = encode_png16(pixels.to_vec(), config.clone())?;
+    // a reduction that loses anything is not a candidate. this round trip cannot notice a packing
+    // mistake the decoder shares, so the tests check sub-byte candidates with a separate decoder
+    let is_exact = decode_png16(&bytes).is_ok_and(|decoded_pixels| decoded_pixels == pixels);
+    let is_smaller = smallest
+        .as_ref()
+        .is_none_or(|(_config, smallest_bytes)| bytes.len() < smallest_bytes.len());
+
+    if is_exact && is_smaller {
+        *smallest = Some((config, bytes));
+    }
+
+    Ok(())
+}
+
+// the color types and bit depths that can hold these colors exactly, truecolor with alpha first
+// since it always can
+fn color_candidates(unique_colors: &[Rgba16]) -> Vec<(ColorType, u8, Option<PaletteOrder>)> {
+    let is_8_bit_exact = unique_colors.iter().all(Rgba16::is_8_bit_exact);
+    let is_greyscale = unique_colors.iter().all(Rgba16::is_greyscale);
+    let needs_alpha_channel = unique_colors.iter().any(|color| !color.is_opaque())
+        && find_transparency_key_color(unique_colors).is_none();
+    let full_bit_depth = if is_8_bit_exact { 8 } else { 16 };
+
+    let mut candidates = vec![(ColorType::TrueColorAlpha, full_bit_depth, None)];
+
+    if !needs_alpha_channel {
+        candidates.push((ColorType::Truecolor, full_bit_depth, None));
+    }
+
+    if is_greyscale {
+        candidates.push((ColorType::GreyscaleAlpha, full_bit_depth, None));
+
+        if !needs_alpha_channel {
+            for bit_depth in [1, 2, 4, 8, 16] {
+                if unique_colors
+                    .iter()
+                    .all(|color| ColorType::is_exact_at_bit_depth(color.r, bit_depth))
+                {
+                    candidates.push((ColorType::Greyscale, bit_depth, None));
+                }
+            }
+        }
+    }
+
+    if is_8_bit_exact && unique_colors.len() <= 256 {
+        for bit_depth in [1, 2, 4, 8] {
+            if unique_colors.len() <= 1 << bit_depth {
+                for palette_order in [
+                    None,
+                    Some(PaletteOrder::Frequency),
+                    Some(PaletteOrder::Luminance),
+                ] {
+                    candidates.push((ColorType::IndexedColor, bit_depth, palette_order));
+                }
+            }
+        }
+    }
+
+    candidates
+}
@@ -1,11 +1,19 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 
-use crate::colors::{Rgb, Rgba};
+use crate::colors::{Rgb16, Rgba, Rgba16};
 
-pub fn get_unique_colors(pixels: &[Vec<Rgba>]) -> Vec<Rgba> {
+use super::PaletteOrder;
+
+pub fn get_unique_colors<'a, P: 'a + Clone + Eq + Hash>(
+    rows: impl IntoIterator<Item = &'a Vec<P>>,
+) -> Vec<P> {
     let mut colors = HashSet::new();
 
-    for row in pixels {
+    for row in rows {
         for pixel in row {
             colors.insert(pixel.clone());
         }
@@ -14,6 +22,24 @@ pub fn get_unique_colors(pixels: &[Vec<Rgba>]) -> Vec<Rgba> {
     colors.into_iter().collect()
 }
 
+// a single fully transparent color that no opaque pixel shares can be stored as a tRNS color key
+// instead of a whole alpha channel
+pub fn find_transparency_key_color(unique_colors: &[Rgba16]) -> Option<Rgba16> {
+    let mut translucent_colors = unique_colors.iter().filter(|color| !color.is_opaque());
+    let key_color = translucent_colors.next()?;
+
+    if key_color.a != 0 || translucent_colors.next().is_some() {
+        return None;
+    }
+
+    let key_rgb = Rgb16::from(key_color);
+    let is_key_shared = unique_colors
+        .iter()
+        .any(|color| color.is_opaque() && Rgb16::from(color) == key_rgb);
+
+    (!is_key_shared).then(|| key_color.clone())
+}
+
 pub fn create_pallete_from_colors_median_cut(
     unique_colors: &[Rgba],
     number_of_colors_log2: usize,
@@ -27,12 +53,17 @@ pub fn create_pallete_from_colors_median_cut(
             .collect()
     };
 
-    color_buckets
+    let mut averaged_buckets: Vec<(Rgba, Vec<Rgba>)> = color_buckets
         .into_iter()
-        .enumerate()
-        .flat_map(|(i, bucket)| {
-            let avg_color = get_bucket_average_color(&bucket);
+        .map(|bucket| (get_bucket_average_color(&bucket), bucket))
+        .collect();
+    // translucent entries go first so the tRNS chunk can stop at the last one of them
+    averaged_buckets.sort_by_key(|(avg_color, _bucket)| avg_color.is_opaque());
 
+    averaged_buckets
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, (avg_color, bucket))| {
             let avg_color_per_color: Vec<(Rgba, (usize, Rgba))> = bucket
                 .into_iter()
                 .map(|color| (color, (i, avg_color.clone())))
@@ -43,6 +74,53 @@ pub fn create_pallete_from_colors_median_cut(
         .collect()
 }
 
+// renumbers the palette entries, keeping the translucent ones first so the tRNS chunk stays short
+pub fn reorder_palette<'a>(
+    palette: HashMap<Rgba, (usize, Rgba)>,
+    palette_order: PaletteOrder,
+    pixels: impl IntoIterator<Item = &'a Rgba16>,
+) -> HashMap<Rgba, (usize, Rgba)> {
+    let number_of_entries = palette
+        .values()
+        .map(|(idx, _color)| idx + 1)
+        .max()
+        .unwrap_or(0);
+    let mut entry_colors = vec![Rgba::new(0, 0, 0, 0); number_of_entries];
+    let mut counts = vec![0usize; number_of_entries];
+
+    for (idx, color) in palette.values() {
+        entry_colors[*idx] = color.clone();
+    }
+
+    for pixel in pixels {
+        if let Some((idx, _color)) = palette.get(&Rgba::from(pixel)) {
+            counts[*idx] += 1;
+        }
+    }
+
+    let mut old_indices: Vec<usize> = (0..number_of_entries).collect();
+
+    match palette_order {
+        PaletteOrder::Frequency => old_indices.sort_by_key(|&idx| Reverse(counts[idx])),
+        PaletteOrder::Luminance => {
+            old_indices.sort_by_key(|&idx| Rgba16::from(&entry_colors[idx]).luma())
+        }
+    }
+    // a stable sort, so the order above holds within the translucent and the opaque entries
+    old_indices.sort_by_key(|&idx| entry_colors[idx].is_opaque());
+
+    let mut new_indices = vec![0; number_of_entries];
+
+    for (new_idx, &old_idx) in old_indices.iter().enumerate() {
+        new_indices[old_idx] = new_idx;
+    }
+
+    palette
+        .into_iter()
+        .map(|(color, (idx, entry_color))| (color, (new_indices[idx], entry_color)))
+        .collect()
+}
+
 fn divide_into_buckets(unique_colors: &[Rgba], number_of_colors_log2: usize) -> Vec<Vec<Rgba>> {
     let mut color_buckets: Vec<Vec<Rgba>> = vec![unique_colors.to_vec()];
 
@@ -61,8 +139,11 @@ fn median_cut_bucket(mut bucket: Vec<Rgba>) -> Vec<Vec<Rgba>> {
     let r_range = range_size(bucket.iter().map(|px| px.r).collect());
     let g_range = range_size(bucket.iter().map(|px| px.g).collect());
     let b_range = range_size(bucket.iter().map(|px| px.b).collect());
+    let a_range = range_size(bucket.iter().map(|px| px.a).collect());
 
-    if r_range > g_range && r_range > b_range {
+    if a_range > r_range && a_range > g_range && a_range > b_range {
+        bucket.sort_by_key(|pix| pix.a);
+    } else if r_range > g_range && r_range > b_range {
         bucket.sort_by_key(|pix| pix.r);
     } else if g_range > b_range {
         bucket.sort_by_key(|pix| pix.g);
@@ -95,24 +176,19 @@ fn get_bucket_average_color(bucket: &[Rgba]) -> Rgba {
     let mut r_sum: f32 = 0.;
     let mut g_sum: f32 = 0.;
     let mut b_sum: f32 = 0.;
+    let mut a_sum: f32 = 0.;
 
     for color in bucket.iter() {
         r_sum += color.r as f32;
         g_sum += color.g as f32;
         b_sum += color.b as f32;
+        a_sum += color.a as f32;
     }
 
     let r = (r_sum / bucket.len() as f32).round() as u8;
     let g = (g_sum / bucket.len() as f32).round() as u8;
     let b = (b_sum / bucket.len() as f32).round() as u8;
+    let a = (a_sum / bucket.len() as f32).round() as u8;
 
-    Rgba::new(r, g, b, u8::MAX)
-}
-
-pub fn index_palette(palette: HashMap<Rgb, Rgb>) -> HashMap<Rgb, (Rgb, usize)> {
-    palette
-        .into_iter()
-        .enumerate()
-        .map(|(i, (k, v))| (k, (v, i)))
-        .collect()
+    Rgba::new(r, g, b, a)
 }
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+use crate::colors::{Rgba, Rgba16};
+
+use super::{Dithering, Quantization};
+
+// colors are compared with their rgb premultiplied by alpha, so the more transparent two colors are
+// the less their rgb matters, and fully transparent colors all end up as one
+type Premultiplied = [f32; 4];
+
+const BAYER_MATRIX: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+fn premultiply(color: &Rgba) -> Premultiplied {
+    let alpha = color.a as f32 / 255.;
+
+    [
+        color.r as f32 * alpha,
+        color.g as f32 * alpha,
+        color.b as f32 * alpha,
+        color.a as f32,
+    ]
+}
+
+fn unpremultiply(values: &Premultiplied) -> Rgba {
+    let alpha = values[3].round().clamp(0., 255.);
+
+    if alpha == 0. {
+        return Rgba::new(0, 0, 0, 0);
+    }
+
+    let channel = |value: f32| (value * 255. / alpha).round().clamp(0., 255.) as u8;
+
+    Rgba::new(
+        channel(values[0]),
+        channel(values[1]),
+        channel(values[2]),
+        alpha as u8,
+    )
+}
+
+fn distance(a: &Premultiplied, b: &Premultiplied) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+fn find_nearest(centroids: &[Premultiplied], values: &Premultiplied) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a, values).total_cmp(&distance(b, values)))
+        .map(|(idx, _centroid)| idx)
+        .unwrap_or(0)
+}
+
+// reduces the images to at most max_colors colors, one palette shared between all of them
+pub fn quantize_images(
+    images: Vec<Vec<Vec<Rgba16>>>,
+    quantization: &Quantization,
+    max_colors: usize,
+) -> Vec<Vec<Vec<Rgba16>>> {
+    let mut histogram: HashMap<Rgba, usize> = HashMap::new();
+
+    for pixel in images.iter().flatten().flatten() {
+        *histogram.entry(Rgba::from(pixel)).or_default() += 1;
+    }
+
+    let weighted_colors: Vec<(Premultiplied, f32)> = histogram
+        .iter()
+        .map(|(color, &count)| (premultiply(color), count as f32))
+        .collect();
+    let mut centroids = median_cut(
+        weighted_colors.clone(),
+        quantization.number_of_colors.min(max_colors),
+    );
+    refine_k_means(
+        &weighted_colors,
+        &mut centroids,
+        quantization.k_means_iterations,
+    );
+
+    // the centroids snap to the colors that will actually be stored, so dithering sees real errors
+    let palette: Vec<Rgba> = centroids.iter().map(unpremultiply).collect();
+    let palette_values: Vec<Premultiplied> = palette.iter().map(premultiply).collect();
+    let palette: Vec<Rgba16> = palette.iter().map(Rgba16::from).collect();
+
+    images
+        .into_iter()
+        .map(|image| match quantization.dithering {
+            Dithering::None => map_to_nearest(&image, &palette_values, &palette),
+            Dithering::FloydSteinberg => dither_floyd_steinberg(&image, &palette_values, &palette),
+            Dithering::Ordered => dither_ordered(&image, &palette_values, &palette),
+        })
+        .collect()
+}
+
+// splits the box of colors with the widest channel at its weighted median, until there are enough
+// boxes or none of them can be split anymore
+fn median_cut(colors: Vec<(Premultiplied, f32)>, number_of_colors: usize) -> Vec<Premultiplied> {
+    let mut boxes = vec![colors];
+
+    while boxes.len() < number_of_colors {
+        let widest_box = boxes
+            .iter()
+            .enumerate()
+            .map(|(idx, colors)| {
+                let (channel, range) = find_widest_channel(colors);
+
+                (idx, channel, range)
+            })
+            .filter(|(_idx, _channel, range)| *range > 0.)
+            .max_by(|a, b| a.2.total_cmp(&b.2));
+        let Some((idx, channel, _range)) = widest_box else {
+            break;
+        };
+
+        let mut colors = boxes.swap_remove(idx);
+        colors.sort_by(|a, b| a.0[channel].total_cmp(&b.0[channel]));
+
+        let total_weight: f32 = colors.iter().map(|(_values, weight)| weight).sum();
+        let mut cumulative_weight = 0.;
+        let median = colors
+            .iter()
+            .position(|(_values, weight)| {
+                cumulative_weight += weight;
+                cumulative_weight >= total_weight / 2.
+            })
+            .unwrap_or(0);
+        // the range is not zero, so there are at least two distinct values to split between
+        let other_colors = colors.split_off((median + 1).clamp(1, colors.len() - 1));
+
+        boxes.push(colors);
+        boxes.push(other_colors);
+    }
+
+    boxes
+        .iter()
+        .filter(|colors| !colors.is_empty())
+        .map(|colors| weighted_mean(colors))
+        .collect()
+}
+
+fn find_widest_channel(colors: &[(Premultiplied, f32)]) -> (usize, f32) {
+    (0..4)
+        .map(|channel| {
+            let (min, max) =
+                colors
+                    .iter()
+                    .fold((f32::MAX, f32::MIN), |(min, max), (values, _weight)| {
+                        (min.min(values[channel]), max.max(values[channel]))
+                    });
+
+            (channel, max - min)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+}
+
+#[derive(Clone, Default)]
+struct WeightedSum {
+    sums: [f64; 4],
+    total_weight: f64,
+}
+
+impl WeightedSum {
+    fn add(&mut self, values: &Premultiplied, weight: f32) {
+        for (sum, value) in self.sums.iter_mut().zip(values) {
+            *sum += *value as f64 * weight as f64;
+        }
+        self.total_weight += weight as f64;
+    }
+
+    fn mean(&self) -> Premultiplied {
+        self.sums.map(|sum| (sum / self.total_weight) as f32)
+    }
+}
+
+fn weighted_mean(colors: &[(Premultiplied, f32)]) -> Premultiplied {
+    let mut weighted_sum = WeightedSum::default();
+
+    for (values, weight) in colors {
+        weighted_sum.add(values, *weight);
+    }
+
+    weighted_sum.mean()
+}
+
+fn refine_k_means(
+    colors: &[(Premultiplied, f32)],
+    centroids: &mut [Premultiplied],
+    iterations: usize,
+) {
+    for _iteration in 0..iterations {
+        let mut clusters = vec![WeightedSum::default(); centroids.len()];
+
+        for (values, weight) in colors {
+            clusters[find_nearest(centroids, values)].add(values, *weight);
+        }
+
+        let mut has_moved = false;
+
+        for (centroid, cluster) in centroids.iter_mut().zip(clusters) {
+            // a centroid nothing is closest to stays where it is
+            if cluster.total_weight == 0. {
+                continue;
+            }
+
+            let mean = cluster.mean();
+            has_moved |= mean != *centroid;
+            *centroid = mean;
+        }
+
+        if !has_moved {
+            break;
+        }
+    }
+}
+
+fn map_to_nearest(
+    image: &[Vec<Rgba16>],
+    palette_values: &[Premultiplied],
+    palette: &[Rgba16],
+) -> Vec<Vec<Rgba16>> {
+    let mut nearest_colors: HashMap<Rgba16, usize> = HashMap::new();
+
+    image
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|pixel| {
+                    let idx = *nearest_colors.entry(pixel.clone()).or_insert_with(|| {
+                        find_nearest(palette_values, &premultiply(&Rgba::from(pixel)))
+                    });
+
+                    palette[idx].clone()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// premultiplied rgb cannot be more than alpha
+fn clamp_premultiplied(mut values: Premultiplied) -> Premultiplied {
+    values[3] = values[3].clamp(0., 255.);
+
+    for channel in 0..3 {
+        values[channel] = values[channel].clamp(0., values[3]);
+    }
+
+    values
+}
+
+// only part of the error is passed on, the full error piles up into speckles wherever the palette
+// has no color close to the image
+const ERROR_DAMPING: f32 = 7. / 8.;
+
+fn dither_floyd_steinberg(
+    image: &[Vec<Rgba16>],
+    palette_values: &[Premultiplied],
+    palette: &[Rgba16],
+) -> Vec<Vec<Rgba16>> {
+    let width = image.first().map_or(0, Vec::len);
+    // padded by a pixel on each side, so the error spreading needs no bounds checks
+    let mut errors = vec![[0f32; 4]; width + 2];
+    let mut dithered_image = Vec::with_capacity(image.len());
+
+    for row in image {
+        let mut next_errors = vec![[0f32; 4]; width + 2];
+        let mut dithered_row = Vec::with_capacity(width);
+
+        for (x, pixel) in row.iter().enumerate() {
+            let mut values = premultiply(&Rgba::from(pixel));
+
+            for (value, error) in values.iter_mut().zip(errors[x + 1]) {
+                *value += error;
+            }
+
+            let values = clamp_premultiplied(values);
+            let idx = find_nearest(palette_values, &values);
+
+            for channel in 0..4 {
+                let error = (values[channel] - palette_values[idx][channel]) * ERROR_DAMPING;
+
+                errors[x + 2][channel] += error * 7. / 16.;
+                next_errors[x][channel] += error * 3. / 16.;
+                next_errors[x + 1][channel] += error * 5. / 16.;
+                next_errors[x + 2][channel] += error / 16.;
+            }
+
+            dithered_row.push(palette[idx].clone());
+        }
+
+        errors = next_errors;
+        dithered_image.push(dithered_row);
+    }
+
+    dithered_image
+}
+
+// the average distance from each palette color to the closest other one
+fn mean_nearest_distance(palette_values: &[Premultiplied]) -> f32 {
+    if palette_values.len() < 2 {
+        return 0.;
+    }
+
+    let total_distance: f32 = palette_values
+        .iter()
+        .enumerate()
+        .map(|(idx, values)| {
+            palette_values
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, _other_values)| *other_idx != idx)
+                .map(|(_other_idx, other_values)| distance(values, other_values))
+                .fold(f32::MAX, f32::min)
+                .sqrt()
+        })
+        .sum();
+
+    total_distance / palette_values.len() as f32
+}
+
+fn dither_ordered(
+    image: &[Vec<Rgba16>],
+    palette_values: &[Premultiplied],
+    palette: &[Rgba16],
+) -> Vec<Vec<Rgba16>> {
+    // a little under the spacing of the palette, more than that only adds noise
+    let spread = mean_nearest_distance(palette_values) * 0.75;
+
+    image
+        .iter()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, pixel)| {
+                    let threshold = (BAYER_MATRIX[y % 8][x % 8] as f32 + 0.5) / 64. - 0.5;
+                    let mut values = premultiply(&Rgba::from(pixel));
+                    let offset = threshold * spread * values[3] / 255.;
+
+                    // only rgb is dithered, noise in alpha would speckle opaque areas with
+                    // translucent pixels
+                    for value in values.iter_mut().take(3) {
+                        *value += offset;
+                    }
+
+                    let idx = find_nearest(palette_values, &clamp_premultiplied(values));
+
+                    palette[idx].clone()
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,506 @@
+use std::{collections::VecDeque, io::Read, slice};
+
+use crate::{colors::Rgba16, error::ErrorKind, png_assert};
+
+use super::{
+    apply_checksum_policy,
+    binary_utils::read_u32,
+    chunks::{check_crc, color_space::apply_lookup_table, Chunk},
+    consts::{
+        CHUNK_DATA_MAX_LENGTH, CHUNK_METADATA_LENGTH, IDAT_CHUNK_TYPE, IHDR_DATA_LENGTH,
+        PNG_SIGNATURE,
+    },
+    crc::CrcCalculator,
+    deflate::ZlibInflater,
+    filter::remove_scanline_filter,
+    read_png_header, ColorSpace, DecodedPng, InterlaceMethod, PngChunks, PngDecodeConfig,
+    PngParseError, RenderingIntent,
+};
+
+const READ_BUFFER_SIZE: usize = 16 * 1024;
+// the length and type that start every chunk
+const CHUNK_HEADER_LENGTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PngRow {
+    // the adam7 pass the row belongs to, always 0 for images that are not interlaced
+    pub pass: usize,
+    pub y: usize,
+    pub x_offset: usize,
+    pub x_step: usize,
+    // the area each pixel stands for until the later passes are decoded
+    pub block_width: usize,
+    pub block_height: usize,
+    pub pixels: Vec<Rgba16>,
+}
+
+impl PngRow {
+    pub fn x(&self, index: usize) -> usize {
+        self.x_offset + index * self.x_step
+    }
+
+    pub fn draw(&self, image: &mut [Vec<Rgba16>]) {
+        let row = &mut image[self.y];
+
+        for (index, pixel) in self.pixels.iter().enumerate() {
+            row[self.x(index)] = pixel.clone();
+        }
+    }
+
+    // fills every pixel's whole block, so a partially decoded interlaced image shows up blurry
+    // instead of with holes
+    pub fn draw_preview(&self, image: &mut [Vec<Rgba16>]) {
+        let bottom = (self.y + self.block_height).min(image.len());
+
+        for row in image[self.y..bottom].iter_mut() {
+            for (index, pixel) in self.pixels.iter().enumerate() {
+                let left = self.x(index);
+                let right = (left + self.block_width).min(row.len());
+
+                row[left..right].fill(pixel.clone());
+            }
+        }
+    }
+}
+
+// decodes a png whose bytes arrive in pieces, handing out each row as soon as it is decompressed
+pub struct PngStreamDecoder {
+    config: PngDecodeConfig,
+    input: Vec<u8>,
+    input_offset: usize,
+    png_chunks: Option<PngChunks>,
+    scanline_reader: Option<ScanlineReader>,
+    image_data_chunk: Option<ImageDataChunk>,
+    rows: VecDeque<PngRow>,
+    is_image_data_finished: bool,
+    is_finished: bool,
+}
+
+// the IDAT chunk whose data is currently arriving
+struct ImageDataChunk {
+    remaining_length: usize,
+    crc_calculator: CrcCalculator,
+    offset: usize,
+}
+
+impl PngStreamDecoder {
+    pub fn new(config: PngDecodeConfig) -> Self {
+        Self {
+            config,
+            input: Vec::new(),
+            input_offset: 0,
+            png_chunks: None,
+            scanline_reader: None,
+            image_data_chunk: None,
+            rows: VecDeque::new(),
+            is_image_data_finished: false,
+            is_finished: false,
+        }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) -> Result<(), PngParseError> {
+        if self.is_finished {
+            return Ok(());
+        }
+
+        self.input.extend_from_slice(bytes);
+        let mut offset = 0;
+
+        let png_chunks = match &mut self.png_chunks {
+            Some(png_chunks) => png_chunks,
+            None => {
+                if self.input.len() < PNG_SIGNATURE.len() + CHUNK_METADATA_LENGTH + IHDR_DATA_LENGTH
+                {
+                    return Ok(());
+                }
+
+                let mut warnings = Vec::new();
+                let ihdr = read_png_header(&self.input, &mut offset, &self.config, &mut warnings)?;
+
+                self.png_chunks
+                    .insert(PngChunks::new(ihdr, self.config.checksum_policy, warnings))
+            }
+        };
+
+        while !self.is_finished {
+            // image data is decompressed as it arrives, without waiting for the end of its chunk
+            if let (Some(image_data_chunk), Some(scanline_reader)) =
+                (&mut self.image_data_chunk, &mut self.scanline_reader)
+            {
+                let data = &self.input[offset..];
+                let data = &data[..image_data_chunk.remaining_length.min(data.len())];
+                image_data_chunk.crc_calculator.update_crc(data);
+                image_data_chunk.remaining_length -= data.len();
+                offset += data.len();
+                scanline_reader.push(data, png_chunks, &mut self.rows)?;
+
+                if image_data_chunk.remaining_length > 0 || self.input.len() < offset + 4 {
+                    break;
+                }
+
+                let stored_crc = read_u32(&mut offset, &self.input)?;
+                apply_checksum_policy(
+                    check_crc(
+                        IDAT_CHUNK_TYPE,
+                        stored_crc,
+                        image_data_chunk.crc_calculator.get_crc(),
+                    )
+                    .map_err(|err| err.at_offset(image_data_chunk.offset)),
+                    png_chunks.checksum_policy,
+                    &mut png_chunks.warnings,
+                )?;
+                self.image_data_chunk = None;
+                continue;
+            }
+
+            let chunk_offset = offset;
+            let file_offset = self.input_offset + chunk_offset;
+
+            if self.input.len() < chunk_offset + CHUNK_HEADER_LENGTH {
+                break;
+            }
+
+            if &self.input[chunk_offset + 4..chunk_offset + CHUNK_HEADER_LENGTH] == IDAT_CHUNK_TYPE
+            {
+                if self.is_image_data_finished {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        "IDAT chunks must not be separated by other chunks",
+                    )
+                    .in_chunk(IDAT_CHUNK_TYPE)
+                    .at_offset(file_offset));
+                }
+
+                png_chunks.start_image_data(file_offset)?;
+                let remaining_length = read_u32(&mut offset, &self.input)?;
+                if remaining_length > CHUNK_DATA_MAX_LENGTH {
+                    return Err(PngParseError::new(
+                        ErrorKind::InvalidData,
+                        format!("Chunk length {remaining_length} is larger than {CHUNK_DATA_MAX_LENGTH}"),
+                    )
+                    .in_chunk(IDAT_CHUNK_TYPE)
+                    .at_offset(file_offset));
+                }
+
+                offset += IDAT_CHUNK_TYPE.len();
+                let mut crc_calculator = CrcCalculator::new();
+                crc_calculator.update_crc(IDAT_CHUNK_TYPE);
+
+                self.image_data_chunk = Some(ImageDataChunk {
+                    remaining_length: remaining_length as usize,
+                    crc_calculator,
+                    offset: file_offset,
+                });
+                self.scanline_reader.get_or_insert_with(|| {
+                    ScanlineReader::new(png_chunks, file_offset, self.config.convert_to_srgb)
+                });
+                continue;
+            }
+
+            // other chunks are only read once all of their bytes have arrived
+            let chunk = match Chunk::from_bytes(&self.input, &mut offset) {
+                Ok(chunk) => chunk,
+                Err(err) if err.kind == ErrorKind::UnexpectedEof => {
+                    offset = chunk_offset;
+                    break;
+                }
+                Err(mut err) => {
+                    // the offset from the chunk is into the buffered input, not the whole stream
+                    err.offset = Some(file_offset);
+                    return Err(err);
+                }
+            };
+
+            if !self.is_image_data_finished {
+                if let Some(scanline_reader) = &mut self.scanline_reader {
+                    self.is_image_data_finished = true;
+                    scanline_reader.finish(png_chunks, &mut self.rows)?;
+                }
+            }
+
+            self.is_finished = !png_chunks.read_chunk(&chunk, file_offset)?;
+            png_assert!(
+                !(self.is_finished && self.scanline_reader.is_none()),
+                "png stream has no IDAT chunk"
+            );
+
+            // only the default image is streamed, so the data of animation frames is dropped
+            if let Some(frame) = png_chunks.frames.last_mut() {
+                frame.compressed_data.clear();
+            }
+        }
+
+        self.input.drain(..offset);
+        self.input_offset += offset;
+
+        Ok(())
+    }
+
+    pub fn next_row(&mut self) -> Option<PngRow> {
+        self.rows.pop_front()
+    }
+
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        self.png_chunks
+            .as_ref()
+            .map(|png_chunks| (png_chunks.ihdr.width, png_chunks.ihdr.height))
+    }
+
+    pub fn interlace_method(&self) -> Option<InterlaceMethod> {
+        self.png_chunks
+            .as_ref()
+            .map(|png_chunks| png_chunks.ihdr.interlace_method)
+    }
+
+    // true once the IEND chunk was read
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    // returns everything but the pixels, which were already handed out row by row
+    pub fn finish(self) -> Result<DecodedPng<Rgba16>, PngParseError> {
+        let png_chunks = match self.png_chunks {
+            Some(png_chunks) if self.is_finished => png_chunks,
+            _ => {
+                return Err(PngParseError::new(
+                    ErrorKind::UnexpectedEof,
+                    "png stream ended before the IEND chunk",
+                )
+                .at_offset(self.input_offset + self.input.len()))
+            }
+        };
+        let mut decoded_png = png_chunks.into_decoded_png(Vec::new());
+
+        if self
+            .scanline_reader
+            .is_some_and(|scanline_reader| scanline_reader.srgb_lookup_table.is_some())
+        {
+            decoded_png.color_space = ColorSpace::srgb(RenderingIntent::Perceptual);
+        }
+
+        Ok(decoded_png)
+    }
+}
+
+// turns the decompressed image data into rows, one scanline at a time
+struct ScanlineReader {
+    zlib_inflater: ZlibInflater,
+    data_offset: usize,
+    pass_dimensions: Vec<(usize, usize)>,
+    pass: usize,
+    pass_row: usize,
+    previous_scanline: Vec<u8>,
+    pending_data: Vec<u8>,
+    srgb_lookup_table: Option<Vec<u16>>,
+}
+
+impl ScanlineReader {
+    fn new(png_chunks: &PngChunks, data_offset: usize, convert_to_srgb: bool) -> Self {
+        let ihdr = &png_chunks.ihdr;
+
+        Self {
+            zlib_inflater: ZlibInflater::new(),
+            data_offset,
+            pass_dimensions: ihdr
+                .interlace_method
+                .pass_dimensions(ihdr.height as usize, ihdr.width as usize),
+            pass: 0,
+            pass_row: 0,
+            previous_scanline: Vec::new(),
+            pending_data: Vec::new(),
+            srgb_lookup_table: convert_to_srgb
+                .then(|| png_chunks.color_space.srgb_lookup_table())
+                .flatten(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        compressed_data: &[u8],
+        png_chunks: &PngChunks,
+        rows: &mut VecDeque<PngRow>,
+    ) -> Result<(), PngParseError> {
+        let data = self
+            .zlib_inflater
+            .push(compressed_data)
+            .map_err(|err| self.image_data_error(err.into()))?;
+        self.pending_data.extend_from_slice(&data);
+
+        self.read_scanlines(png_chunks, rows)
+            .map_err(|err| self.image_data_error(err))
+    }
+
+    fn finish(
+        &mut self,
+        png_chunks: &mut PngChunks,
+        rows: &mut VecDeque<PngRow>,
+    ) -> Result<(), PngParseError> {
+        let (data, checksum_error) = self
+            .zlib_inflater
+            .finish()
+            .map_err(|err| self.image_data_error(err.into()))?;
+
+        if let Some(err) = checksum_error {
+            apply_checksum_policy(
+                Err(self.image_data_error(err.into())),
+                png_chunks.checksum_policy,
+                &mut png_chunks.warnings,
+            )?;
+        }
+
+        self.pending_data.extend_from_slice(&data);
+        self.read_scanlines(png_chunks, rows)
+            .and_then(|_| {
+                png_assert!(
+                    self.pending_data.is_empty(),
+                    format!(
+                        "Decompressed data is {} bytes longer than the image",
+                        self.pending_data.len()
+                    )
+                );
+
+                if self.pass < self.pass_dimensions.len() {
+                    return Err(PngParseError::new(
+                        ErrorKind::UnexpectedEof,
+                        format!(
+                            "Image data ended at row {} of pass #{}",
+                            self.pass_row, self.pass
+                        ),
+                    ));
+                }
+
+                Ok(())
+            })
+            .map_err(|err| self.image_data_error(err))
+    }
+
+    fn read_scanlines(
+        &mut self,
+        png_chunks: &PngChunks,
+        rows: &mut VecDeque<PngRow>,
+    ) -> Result<(), PngParseError> {
+        let ihdr = &png_chunks.ihdr;
+        let bbp = ihdr.get_bits_per_pixel();
+        let mut start = 0;
+
+        while let Some(&(number_of_scanlines, scanline_number_of_pixels)) =
+            self.pass_dimensions.get(self.pass)
+        {
+            if self.pass_row == number_of_scanlines {
+                self.pass += 1;
+                self.pass_row = 0;
+                self.previous_scanline.clear();
+                continue;
+            }
+
+            let scanline_length = 1 + (scanline_number_of_pixels * bbp).div_ceil(8);
+            let Some(scanline) = self.pending_data.get(start..start + scanline_length) else {
+                break;
+            };
+            start += scanline_length;
+
+            let unfiltered_scanline =
+                remove_scanline_filter(scanline, &self.previous_scanline, bbp)?;
+            let mut pixels = ihdr
+                .color_type
+                .scanline_to_pixels(
+                    slice::from_ref(&unfiltered_scanline),
+                    ihdr.bit_depth,
+                    scanline_number_of_pixels,
+                    &png_chunks.palette,
+                    &png_chunks.transparency,
+                )?
+                .pop()
+                .unwrap_or_default();
+
+            if let Some(lookup_table) = &self.srgb_lookup_table {
+                for pixel in pixels.iter_mut() {
+                    apply_lookup_table(pixel, lookup_table);
+                }
+            }
+
+            let (x_offset, y_offset, x_step, y_step) =
+                ihdr.interlace_method.pass_origins()[self.pass];
+            let (block_width, block_height) =
+                ihdr.interlace_method.pass_preview_block_sizes()[self.pass];
+
+            rows.push_back(PngRow {
+                pass: self.pass,
+                y: y_offset + self.pass_row * y_step,
+                x_offset,
+                x_step,
+                block_width,
+                block_height,
+                pixels,
+            });
+            self.previous_scanline = unfiltered_scanline;
+            self.pass_row += 1;
+        }
+
+        self.pending_data.drain(..start);
+
+        Ok(())
+    }
+
+    fn image_data_error(&self, err: PngParseError) -> PngParseError {
+        err.in_chunk(IDAT_CHUNK_TYPE).at_offset(self.data_offset)
+    }
+}
+
+// decodes the rows of a png as they are read, for example from a file or a socket
+pub struct PngRowReader<R> {
+    reader: R,
+    decoder: PngStreamDecoder,
+    buffer: Vec<u8>,
+    is_done: bool,
+}
+
+pub fn read_png_rows<R: Read>(reader: R, config: PngDecodeConfig) -> PngRowReader<R> {
+    PngRowReader {
+        reader,
+        decoder: PngStreamDecoder::new(config),
+        buffer: vec![0; READ_BUFFER_SIZE],
+        is_done: false,
+    }
+}
+
+impl<R: Read> PngRowReader<R> {
+    pub fn decoder(&self) -> &PngStreamDecoder {
+        &self.decoder
+    }
+
+    pub fn finish(self) -> Result<DecodedPng<Rgba16>, PngParseError> {
+        self.decoder.finish()
+    }
+}
+
+impl<R: Read> Iterator for PngRowReader<R> {
+    type Item = Result<PngRow, PngParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.decoder.next_row() {
+                return Some(Ok(row));
+            }
+
+            if self.is_done || self.decoder.is_finished() {
+                return None;
+            }
+
+            let read_result = match self.reader.read(&mut self.buffer) {
+                Ok(0) => Err(PngParseError::new(
+                    ErrorKind::UnexpectedEof,
+                    "png stream ended before the IEND chunk",
+                )),
+                Ok(length) => self.decoder.push(&self.buffer[..length]),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => Ok(()),
+                Err(err) => Err(PngParseError::new(ErrorKind::Io, err.to_string())),
+            };
+
+            if let Err(err) = read_result {
+                self.is_done = true;
+                return Some(Err(err));
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::io::{self, Write};
+
+use crate::colors::{Rgba, Rgba16};
+
+use super::{
+    chunks::{idat::Idat, ihdr::Ihdr},
+    crc::CrcCalculator,
+    deflate::ZlibEncoder,
+    encode_chunks_after_image_data, encode_chunks_before_image_data,
+    filter::filter_scanline,
+    AdaptiveFilterType, PartialPngConfig, PngConfig, PngEncodeError, IMAGE_DIMENSION_MAX,
+};
+
+const DEFAULT_IDAT_SIZE: usize = 64 * 1024;
+
+// encodes a png one row at a time into a writer, so the whole image never has to be in memory
+pub struct PngStreamEncoder<W: Write> {
+    writer: W,
+    config: PngConfig,
+    width: usize,
+    height: usize,
+    rows_written: usize,
+    previous_scanline: Vec<u8>,
+    zlib_encoder: ZlibEncoder,
+    compressed_data: Vec<u8>,
+    idat_size: usize,
+    crc: CrcCalculator,
+}
+
+impl<W: Write> PngStreamEncoder<W> {
+    // writes everything that comes before the image data right away
+    pub fn new(
+        mut writer: W,
+        width: u32,
+        height: u32,
+        partial_config: PartialPngConfig,
+    ) -> Result<Self, PngEncodeError> {
+        if width == 0 || height == 0 {
+            return Err(PngEncodeError::EmptyImage);
+        }
+
+        if width > IMAGE_DIMENSION_MAX || height > IMAGE_DIMENSION_MAX {
+            return Err(PngEncodeError::DimensionsTooLarge {
+                width: width as usize,
+                height: height as usize,
+            });
+        }
+
+        let config = PngConfig::create_for_stream(partial_config)?;
+        let mut crc = CrcCalculator::new();
+        let ihdr = Ihdr::new(
+            width,
+            height,
+            config.color_type,
+            config.bit_depth,
+            config.interlace_method,
+        );
+        writer.write_all(&encode_chunks_before_image_data(
+            &ihdr, None, &config, &None, &None, &None, &mut crc,
+        )?)?;
+
+        Ok(Self {
+            writer,
+            width: width as usize,
+            height: height as usize,
+            rows_written: 0,
+            previous_scanline: Vec::new(),
+            zlib_encoder: ZlibEncoder::new(config.compression_level),
+            compressed_data: Vec::new(),
+            idat_size: config.idat_size.unwrap_or(DEFAULT_IDAT_SIZE),
+            config,
+            crc,
+        })
+    }
+
+    // returns the filter the row got
+    pub fn write_row(&mut self, row: &[Rgba]) -> Result<AdaptiveFilterType, PngEncodeError> {
+        let row: Vec<Rgba16> = row.iter().map(Rgba16::from).collect();
+
+        self.write_row16(&row)
+    }
+
+    pub fn write_row16(&mut self, row: &[Rgba16]) -> Result<AdaptiveFilterType, PngEncodeError> {
+        if row.len() != self.width {
+            return Err(PngEncodeError::RaggedRows {
+                row: self.rows_written,
+                width: row.len(),
+                expected_width: self.width,
+            });
+        }
+
+        if self.rows_written == self.height {
+            return Err(PngEncodeError::RowCount {
+                rows: self.rows_written + 1,
+                height: self.height,
+            });
+        }
+
+        let bits_per_pixel =
+            self.config.bit_depth as usize * self.config.color_type.samples_per_pixel();
+        let scanline = self
+            .config
+            .color_type
+            .create_scanlines(&[row.to_vec()], self.config.bit_depth, &None)?
+            .remove(0);
+        let (filter, filtered_scanline) = filter_scanline(
+            &scanline,
+            &self.previous_scanline,
+            bits_per_pixel,
+            self.config.filter_strategy,
+        );
+
+        self.zlib_encoder.write_bytes(&filtered_scanline);
+        self.previous_scanline = scanline;
+        self.rows_written += 1;
+
+        let compressed_data = self.zlib_encoder.take_compressed_bytes();
+        self.compressed_data.extend_from_slice(&compressed_data);
+        self.write_image_data_chunks(false)?;
+
+        Ok(filter)
+    }
+
+    // ends the image data, writes the chunks that follow it and hands back the writer
+    pub fn finish(mut self) -> Result<W, PngEncodeError> {
+        if self.rows_written != self.height {
+            return Err(PngEncodeError::RowCount {
+                rows: self.rows_written,
+                height: self.height,
+            });
+        }
+
+        let compressed_data = self.zlib_encoder.flush();
+        self.compressed_data.extend_from_slice(&compressed_data);
+        self.write_image_data_chunks(true)?;
+        self.writer
+            .write_all(&encode_chunks_after_image_data(&self.config, &mut self.crc))?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    // writes every full IDAT chunk, and the last partial one as well once the data is complete
+    fn write_image_data_chunks(&mut self, is_data_complete: bool) -> io::Result<()> {
+        let mut start = 0;
+
+        while self.compressed_data.len() - start >= self.idat_size
+            || (is_data_complete && start < self.compressed_data.len())
+        {
+            let end = (start + self.idat_size).min(self.compressed_data.len());
+            let chunk = Idat::encode_bytes(&self.compressed_data[start..end], &mut self.crc);
+
+            self.writer.write_all(&chunk)?;
+            start = end;
+        }
+
+        self.compressed_data.drain(..start);
+
+        Ok(())
+    }
+}
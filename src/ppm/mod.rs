@@ -1,17 +1,56 @@
-use crate::{binary::byte_reader::ByteReader, colors::Rgb};
+use std::{error, fmt, path::Path};
+
+use crate::{
+    binary::byte_reader::ByteReader,
+    colors::{Rgb, Rgb16},
+    error::ErrorKind,
+};
 
 #[derive(Debug)]
-pub struct PpmParseError(String);
+pub struct PpmParseError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub offset: Option<usize>,
+}
+
+impl PpmParseError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            offset: None,
+        }
+    }
+
+    pub fn at_offset(mut self, offset: usize) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl fmt::Display for PpmParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PPM parse error ({}): {}", self.kind, self.message)?;
+
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {offset}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for PpmParseError {}
 
 macro_rules! ppm_read_bytes {
     ($read_value:expr, $msg:expr) => {
         match $read_value {
             Some(value) => value,
             None => {
-                return Err(PpmParseError(format!(
-                    "PPM stream ended unexpectedly: {}",
-                    $msg
-                )));
+                return Err(PpmParseError::new(
+                    ErrorKind::UnexpectedEof,
+                    format!("PPM stream ended unexpectedly: {}", $msg),
+                ));
             }
         }
     };
@@ -22,32 +61,53 @@ pub fn is_ppm_by_signature(file: &[u8]) -> bool {
 }
 
 pub fn is_ppm_by_extension(filename: &str) -> bool {
-    filename.ends_with("ppm")
+    Path::new(filename)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("ppm"))
 }
 
 const PPM_SIGNATURE: &[u8] = &[80, 54];
 const LINE_FEED: u8 = 10;
 
 pub fn decode_ppm(bytes: &[u8]) -> Result<Vec<Vec<Rgb>>, PpmParseError> {
+    let pixels = decode_ppm16(bytes)?;
+
+    Ok(pixels
+        .into_iter()
+        .map(|row| row.iter().map(Rgb::from).collect())
+        .collect())
+}
+
+pub fn decode_ppm16(bytes: &[u8]) -> Result<Vec<Vec<Rgb16>>, PpmParseError> {
     let mut reader = ByteReader::new(bytes);
+
+    read_ppm(&mut reader).map_err(|err| err.at_offset(reader.offset.min(bytes.len())))
+}
+
+fn read_ppm(reader: &mut ByteReader) -> Result<Vec<Vec<Rgb16>>, PpmParseError> {
     let signature = ppm_read_bytes!(reader.read_ppm_symbol(), "expected magic number");
 
     if signature != PPM_SIGNATURE {
-        return Err(PpmParseError(
-            "File does not look like a PPM file (magic number missing)".to_string(),
-        ));
+        return Err(PpmParseError::new(
+            ErrorKind::InvalidSignature,
+            "File does not look like a PPM file (magic number missing)",
+        )
+        .at_offset(0));
     }
 
-    let width = read_ascii_integer(&mut reader, "width")?;
-    let height = read_ascii_integer(&mut reader, "height")?;
-    let max_color_value = read_ascii_integer(&mut reader, "maxval")?;
-
-    if max_color_value > u16::MAX.into() {
-        return Err(PpmParseError(format!(
-            "Invalid Maxval, expected value between 0 and {}, found {}",
-            u16::MAX,
-            max_color_value
-        )));
+    let width = read_ascii_integer(reader, "width")?;
+    let height = read_ascii_integer(reader, "height")?;
+    let max_color_value = read_ascii_integer(reader, "maxval")?;
+
+    if max_color_value == 0 || max_color_value > u16::MAX.into() {
+        return Err(PpmParseError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Invalid Maxval, expected value between 1 and {}, found {}",
+                u16::MAX,
+                max_color_value
+            ),
+        ));
     }
 
     let bytes_per_pixel = if max_color_value > u8::MAX.into() {
@@ -77,11 +137,10 @@ pub fn decode_ppm(bytes: &[u8]) -> Result<Vec<Vec<Rgb>>, PpmParseError> {
         .map(|row| {
             row.chunks_exact(3 * bytes_per_pixel)
                 .map(|rgb_bytes| {
-                    let normalization_factor = 255. / max_color_value as f32;
                     if bytes_per_pixel == 1 {
-                        rgb24_from_bytes(rgb_bytes, normalization_factor)
+                        rgb24_from_bytes(rgb_bytes, max_color_value)
                     } else {
-                        rgb48_from_bytes(rgb_bytes, normalization_factor)
+                        rgb48_from_bytes(rgb_bytes, max_color_value)
                     }
                 })
                 .collect()
@@ -92,33 +151,86 @@ pub fn decode_ppm(bytes: &[u8]) -> Result<Vec<Vec<Rgb>>, PpmParseError> {
     Ok(pixels)
 }
 
-fn rgb24_from_bytes(rgb_bytes: &[u8], normalization_factor: f32) -> Rgb {
-    Rgb::new(
-        ((rgb_bytes[0] as f32) * normalization_factor) as u8,
-        ((rgb_bytes[1] as f32) * normalization_factor) as u8,
-        ((rgb_bytes[2] as f32) * normalization_factor) as u8,
+fn rgb24_from_bytes(rgb_bytes: &[u8], max_color_value: u32) -> Rgb16 {
+    Rgb16::new(
+        normalize_sample(rgb_bytes[0] as u32, max_color_value),
+        normalize_sample(rgb_bytes[1] as u32, max_color_value),
+        normalize_sample(rgb_bytes[2] as u32, max_color_value),
     )
 }
 
-fn rgb48_from_bytes(rgb_bytes: &[u8], normalization_factor: f32) -> Rgb {
-    Rgb::new(
-        ((((rgb_bytes[0] as u16) << 8) + rgb_bytes[1] as u16) as f32 * normalization_factor) as u8,
-        ((((rgb_bytes[2] as u16) << 8) + rgb_bytes[3] as u16) as f32 * normalization_factor) as u8,
-        ((((rgb_bytes[4] as u16) << 8) + rgb_bytes[5] as u16) as f32 * normalization_factor) as u8,
+fn rgb48_from_bytes(rgb_bytes: &[u8], max_color_value: u32) -> Rgb16 {
+    Rgb16::new(
+        normalize_sample(
+            u16::from_be_bytes([rgb_bytes[0], rgb_bytes[1]]) as u32,
+            max_color_value,
+        ),
+        normalize_sample(
+            u16::from_be_bytes([rgb_bytes[2], rgb_bytes[3]]) as u32,
+            max_color_value,
+        ),
+        normalize_sample(
+            u16::from_be_bytes([rgb_bytes[4], rgb_bytes[5]]) as u32,
+            max_color_value,
+        ),
     )
 }
 
+fn normalize_sample(value: u32, max_color_value: u32) -> u16 {
+    let value = value.min(max_color_value);
+
+    ((value * u16::MAX as u32 + (max_color_value >> 1)) / max_color_value) as u16
+}
+
 fn read_ascii_integer(reader: &mut ByteReader, field_name: &str) -> Result<u32, PpmParseError> {
     let bytes = ppm_read_bytes!(reader.read_ppm_symbol(), format!("expected {field_name}"));
     let number = String::from_utf8(bytes.to_vec())
-        .map_err(|_e| PpmParseError(format!("{field_name} is not valid utf8")))?
+        .map_err(|_e| {
+            PpmParseError::new(
+                ErrorKind::InvalidData,
+                format!("{field_name} is not valid utf8"),
+            )
+        })?
         .parse::<u32>()
-        .map_err(|_e| PpmParseError(format!("{field_name} is not a valid unsigned integer")))?;
+        .map_err(|_e| {
+            PpmParseError::new(
+                ErrorKind::InvalidData,
+                format!("{field_name} is not a valid unsigned integer"),
+            )
+        })?;
 
     Ok(number)
 }
 
 pub fn encode_ppm(pixels: &[Vec<Rgb>]) -> Vec<u8> {
+    let mut result = encode_ppm_header(pixels, u8::MAX.into());
+
+    for row in pixels {
+        for pixel in row {
+            result.push(pixel.r);
+            result.push(pixel.g);
+            result.push(pixel.b);
+        }
+    }
+
+    result
+}
+
+pub fn encode_ppm16(pixels: &[Vec<Rgb16>]) -> Vec<u8> {
+    let mut result = encode_ppm_header(pixels, u16::MAX);
+
+    for row in pixels {
+        for pixel in row {
+            result.extend_from_slice(&pixel.r.to_be_bytes());
+            result.extend_from_slice(&pixel.g.to_be_bytes());
+            result.extend_from_slice(&pixel.b.to_be_bytes());
+        }
+    }
+
+    result
+}
+
+fn encode_ppm_header<T>(pixels: &[Vec<T>], maxval: u16) -> Vec<u8> {
     let mut result = Vec::with_capacity(20 + pixels.len() * pixels[0].len());
 
     result.extend_from_slice(PPM_SIGNATURE);
@@ -132,17 +244,8 @@ pub fn encode_ppm(pixels: &[Vec<Rgb>]) -> Vec<u8> {
     result.extend_from_slice(height.to_string().as_bytes());
     result.push(LINE_FEED);
 
-    let maxval = 255;
     result.extend_from_slice(maxval.to_string().as_bytes());
     result.push(LINE_FEED);
 
-    for row in pixels {
-        for pixel in row {
-            result.push(pixel.r);
-            result.push(pixel.g);
-            result.push(pixel.b);
-        }
-    }
-
     result
 }
@@ -0,0 +1,66 @@
+use std::{env, fs, path::PathBuf, process::Command};
+
+fn gimg(args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_gimg"));
+    command.args(args);
+    command
+}
+
+fn output_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("gimg-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn picks_the_output_format_from_an_upper_case_extension() {
+    let output = output_path("small.PNG");
+    let status = gimg(&["files/small.ppm", output.to_str().unwrap()])
+        .status()
+        .unwrap();
+    let png = fs::read(&output).unwrap();
+    fs::remove_file(&output).unwrap();
+
+    assert!(status.success());
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[test]
+fn rejects_an_output_name_that_only_ends_like_an_extension() {
+    for name in ["small.apng", "smallpng"] {
+        let output = output_path(name);
+        let result = gimg(&["files/small.ppm", output.to_str().unwrap()])
+            .output()
+            .unwrap();
+
+        assert!(!result.status.success(), "{name}");
+        assert!(!output.exists(), "{name}");
+        assert!(String::from_utf8_lossy(&result.stderr).contains("Unrecognized output file format"));
+    }
+}
+
+#[test]
+fn reads_gif_input_through_the_format_registry() {
+    let output = output_path("example.ppm");
+    let status = gimg(&["files/example.gif", output.to_str().unwrap()])
+        .status()
+        .unwrap();
+    let ppm = fs::read(&output).unwrap();
+    fs::remove_file(&output).unwrap();
+
+    assert!(status.success());
+    assert!(ppm.starts_with(b"P6"));
+}
+
+#[test]
+fn refuses_to_write_a_format_without_an_encoder() {
+    let output = output_path("small.gif");
+    let result = gimg(&["files/small.ppm", output.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(!result.status.success());
+    assert!(!output.exists());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("GIF encoding is not supported"));
+
+    let formats = gimg(&["--help", "formats"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&formats.stdout).contains("GIF   read\n"));
+}
@@ -0,0 +1,91 @@
+// helpers shared by the integration tests, not every test file uses all of them
+#![allow(dead_code)]
+
+use gimg::png::deflate::decode_zlib;
+
+pub const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+// a zlib stream holding the bytes in a single stored block
+pub fn stored_zlib(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01, 0x01];
+    zlib.extend_from_slice(&(data.len() as u16).to_le_bytes());
+    zlib.extend_from_slice(&(!(data.len() as u16)).to_le_bytes());
+    zlib.extend_from_slice(data);
+    zlib.extend_from_slice(&adler32(data).to_be_bytes());
+    zlib
+}
+
+pub fn chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut bytes = (data.len() as u32).to_be_bytes().to_vec();
+    bytes.extend_from_slice(chunk_type);
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(&crc32(&bytes[4..]).to_be_bytes());
+    bytes
+}
+
+// a single row png whose image data is the given (already filtered) scanline in a stored block
+pub fn hand_built_png(
+    width: u32,
+    bit_depth: u8,
+    color_type: u8,
+    palette: &[u8],
+    data: &[u8],
+) -> Vec<u8> {
+    let mut ihdr = width.to_be_bytes().to_vec();
+    ihdr.extend_from_slice(&1u32.to_be_bytes());
+    ihdr.extend_from_slice(&[bit_depth, color_type, 0, 0, 0]);
+
+    let mut bytes = PNG_SIGNATURE.to_vec();
+    bytes.extend(chunk(b"IHDR", &ihdr));
+    if !palette.is_empty() {
+        bytes.extend(chunk(b"PLTE", palette));
+    }
+    bytes.extend(chunk(b"IDAT", &stored_zlib(data)));
+    bytes.extend(chunk(b"IEND", &[]));
+    bytes
+}
+
+pub fn chunks(png: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    let mut offset = PNG_SIGNATURE.len();
+    let mut chunks = vec![];
+    while offset < png.len() {
+        let length = u32::from_be_bytes(png[offset..offset + 4].try_into().unwrap()) as usize;
+        let chunk_type = png[offset + 4..offset + 8].try_into().unwrap();
+        chunks.push((chunk_type, &png[offset + 8..offset + 8 + length]));
+        offset += length + 12;
+    }
+    chunks
+}
+
+// the decompressed contents of all IDAT chunks
+pub fn image_data(png: &[u8]) -> Vec<u8> {
+    let data: Vec<u8> = chunks(png)
+        .iter()
+        .filter(|(chunk_type, _data)| chunk_type == b"IDAT")
+        .flat_map(|(_chunk_type, data)| data.iter().copied())
+        .collect();
+    decode_zlib(&data).unwrap()
+}
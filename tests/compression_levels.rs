@@ -0,0 +1,67 @@
+use std::io::Write;
+
+use gimg::png::{
+    deflate::{decode_zlib, ZlibWriter},
+    CompressionLevel,
+};
+
+fn data() -> Vec<u8> {
+    (0..6_000u32)
+        .map(|index| b"abcdefgh"[(index * index / 97 % 8) as usize])
+        .collect()
+}
+
+fn compress(compression_level: CompressionLevel) -> Vec<u8> {
+    let mut writer = ZlibWriter::new(Vec::new(), compression_level);
+    writer.write_all(&data()).unwrap();
+    writer.finish().unwrap()
+}
+
+#[test]
+fn every_level_round_trips() {
+    for level in 0..=9 {
+        let compression_level = CompressionLevel::new(level).unwrap();
+        assert_eq!(decode_zlib(&compress(compression_level)).unwrap(), data());
+    }
+    assert_eq!(
+        decode_zlib(&compress(CompressionLevel::EXTREME)).unwrap(),
+        data()
+    );
+    assert!(CompressionLevel::new(11).is_none());
+}
+
+#[test]
+fn best_and_extreme_are_no_larger_than_fast() {
+    let sizes = [
+        CompressionLevel::NONE,
+        CompressionLevel::FAST,
+        CompressionLevel::BEST,
+        CompressionLevel::EXTREME,
+    ]
+    .map(|compression_level| compress(compression_level).len());
+
+    assert!(sizes.is_sorted_by(|a, b| a >= b), "sizes {sizes:?}");
+    assert!(sizes[1] < sizes[0]);
+}
+
+#[test]
+fn the_fast_levels_search_more_as_they_go_up() {
+    let text = std::fs::read("files/text.txt").unwrap();
+    let sizes = [1, 2, 3].map(|level| {
+        let mut writer = ZlibWriter::new(Vec::new(), CompressionLevel::new(level).unwrap());
+        writer.write_all(&text[..20_000]).unwrap();
+        writer.finish().unwrap().len()
+    });
+
+    assert!(sizes.is_sorted_by(|a, b| a > b), "sizes {sizes:?}");
+}
+
+#[test]
+fn the_zlib_header_groups_levels_like_zlib() {
+    let flevels = [0, 1, 2, 6, 9].map(|level| {
+        let zlib = compress(CompressionLevel::new(level).unwrap());
+        zlib[1] >> 6
+    });
+
+    assert_eq!(flevels, [0, 0, 1, 2, 3]);
+}
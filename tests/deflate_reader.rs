@@ -0,0 +1,82 @@
+use std::io::{self, Read, Write};
+
+use gimg::png::{
+    deflate::{DeflateReader, DeflateWriter, ZlibReader, ZlibWriter},
+    CompressionLevel,
+};
+
+// hands out its bytes one at a time, so every read stops somewhere in the middle of a block
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(first)) => {
+                *first = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+fn data() -> Vec<u8> {
+    (0..100_000u32)
+        .map(|index| ((index % 251) ^ (index / 1000)) as u8)
+        .collect()
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut writer = ZlibWriter::new(Vec::new(), CompressionLevel::BEST);
+    writer.write_all(data).unwrap();
+    writer.finish().unwrap()
+}
+
+#[test]
+fn zlib_reader_decodes_input_that_arrives_a_byte_at_a_time() {
+    let zlib = zlib(&data());
+    let mut decoded = Vec::new();
+    ZlibReader::new(OneByteReader(&zlib))
+        .read_to_end(&mut decoded)
+        .unwrap();
+
+    assert_eq!(decoded, data());
+}
+
+#[test]
+fn deflate_reader_decodes_into_small_buffers() {
+    let mut writer = DeflateWriter::new(Vec::new(), CompressionLevel::FAST);
+    writer.write_all(&data()).unwrap();
+    let deflate = writer.finish().unwrap();
+
+    let mut reader = DeflateReader::new(&deflate[..]);
+    let mut decoded = Vec::new();
+    let mut buf = [0; 7];
+    loop {
+        let length = reader.read(&mut buf).unwrap();
+        if length == 0 {
+            break;
+        }
+        decoded.extend_from_slice(&buf[..length]);
+    }
+
+    assert_eq!(decoded, data());
+}
+
+#[test]
+fn zlib_reader_reports_bad_checksums_and_truncated_streams() {
+    let mut zlib = zlib(&data());
+    let last = zlib.len() - 1;
+    zlib[last] ^= 1;
+    let err = ZlibReader::new(&zlib[..])
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let zlib = self::zlib(&data());
+    let err = ZlibReader::new(&zlib[..zlib.len() / 2])
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,61 @@
+use std::io::Write;
+
+use gimg::png::{
+    deflate::{decode_deflate, decode_zlib, DeflateWriter, ZlibWriter},
+    CompressionLevel,
+};
+
+fn text(repeats: usize) -> Vec<u8> {
+    b"the quick brown fox jumps over the lazy dog. "
+        .iter()
+        .copied()
+        .cycle()
+        .take(45 * repeats)
+        .collect()
+}
+
+#[test]
+fn zlib_writer_round_trips_across_writes_and_flushes() {
+    for compression_level in [
+        CompressionLevel::NONE,
+        CompressionLevel::FAST,
+        CompressionLevel::BEST,
+    ] {
+        let data = text(500);
+        let mut writer = ZlibWriter::new(Vec::new(), compression_level);
+
+        for (index, piece) in data.chunks(997).enumerate() {
+            writer.write_all(piece).unwrap();
+            if index % 3 == 0 {
+                writer.flush().unwrap();
+            }
+        }
+        let zlib = writer.finish().unwrap();
+
+        assert_eq!(decode_zlib(&zlib).unwrap(), data);
+    }
+}
+
+#[test]
+fn sync_flush_ends_on_an_empty_stored_block() {
+    let mut writer = DeflateWriter::new(Vec::new(), CompressionLevel::FAST);
+    writer.write_all(&text(3)).unwrap();
+    writer.flush().unwrap();
+
+    assert!(writer.get_ref().ends_with(&[0x00, 0x00, 0xff, 0xff]));
+}
+
+#[test]
+fn output_after_a_full_flush_decodes_on_its_own() {
+    let mut writer = DeflateWriter::new(Vec::new(), CompressionLevel::BEST);
+    writer.write_all(&text(20)).unwrap();
+    writer.full_flush().unwrap();
+    let flush_end = writer.get_ref().len();
+
+    // the same text again would be a back reference without the full flush
+    writer.write_all(&text(20)).unwrap();
+    let deflate = writer.finish().unwrap();
+
+    assert_eq!(decode_deflate(&deflate).unwrap(), text(40));
+    assert_eq!(decode_deflate(&deflate[flush_end..]).unwrap(), text(20));
+}
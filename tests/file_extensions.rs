@@ -0,0 +1,19 @@
+use gimg::{gif::is_gif_by_extension, png::is_png_by_extension, ppm::is_ppm_by_extension};
+
+#[test]
+fn matches_the_extension_ignoring_case() {
+    assert!(is_png_by_extension("image.png"));
+    assert!(is_png_by_extension("dir.d/IMAGE.PNG"));
+    assert!(is_gif_by_extension("image.Gif"));
+    assert!(is_ppm_by_extension("image.PPM"));
+}
+
+#[test]
+fn needs_a_dot_before_the_extension() {
+    assert!(!is_png_by_extension("png"));
+    assert!(!is_png_by_extension("notapng"));
+    assert!(!is_png_by_extension("image.apng"));
+    assert!(!is_png_by_extension("image.png.bak"));
+    assert!(!is_gif_by_extension("jif"));
+    assert!(!is_ppm_by_extension("image_ppm"));
+}
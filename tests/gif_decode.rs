@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use gimg::{colors::Rgba, gif::decode_gif, ErrorKind};
+
+const COLORS: [Rgba; 4] = [
+    Rgba {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    },
+    Rgba {
+        r: 255,
+        g: 0,
+        b: 0,
+        a: 255,
+    },
+    Rgba {
+        r: 0,
+        g: 0,
+        b: 255,
+        a: 255,
+    },
+    Rgba {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+    },
+];
+const CLEAR: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 0,
+    a: 0,
+};
+
+// packs codes least significant bit first, growing the code size the way gif decoders expect
+fn lzw(indices: &[u8], minimum_code_size: u8) -> Vec<u8> {
+    let clear_code = 1u16 << minimum_code_size;
+    let mut code_size = minimum_code_size + 1;
+    let mut next_code = clear_code + 2;
+    let mut table: HashMap<Vec<u8>, u16> = HashMap::new();
+    let (mut bytes, mut bit_offset) = (vec![], 0);
+    let mut emit = |code: u16, code_size: u8| {
+        for bit in 0..code_size as usize {
+            if bit_offset % 8 == 0 {
+                bytes.push(0);
+            }
+            *bytes.last_mut().unwrap() |= (((code >> bit) & 1) as u8) << (bit_offset % 8);
+            bit_offset += 1;
+        }
+    };
+    let code_of = |table: &HashMap<Vec<u8>, u16>, string: &[u8]| match string {
+        [index] => *index as u16,
+        _ => table[string],
+    };
+
+    emit(clear_code, code_size);
+    let mut string = vec![indices[0]];
+    for &index in &indices[1..] {
+        let mut longer_string = string.clone();
+        longer_string.push(index);
+
+        if table.contains_key(&longer_string) {
+            string = longer_string;
+        } else {
+            emit(code_of(&table, &string), code_size);
+            if next_code >= 1 << code_size {
+                code_size += 1;
+            }
+            table.insert(longer_string, next_code);
+            next_code += 1;
+            string = vec![index];
+        }
+    }
+    emit(code_of(&table, &string), code_size);
+    emit(clear_code + 1, code_size);
+
+    bytes
+}
+
+fn sub_blocks(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![];
+    for block in data.chunks(255) {
+        bytes.push(block.len() as u8);
+        bytes.extend_from_slice(block);
+    }
+    bytes.push(0);
+    bytes
+}
+
+// a 5x4 screen with a 3x3 interlaced image at (1, 1) whose last color is transparent
+fn hand_built_gif(minimum_code_size: u8, image_data: &[u8]) -> Vec<u8> {
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend_from_slice(&[5, 0, 4, 0, 0b1000_0001, 0, 0]);
+    for color in COLORS {
+        gif.extend_from_slice(&[color.r, color.g, color.b]);
+    }
+    gif.extend_from_slice(&[0x21, 0xf9, 4, 0b0000_0001, 0, 0, 3, 0]);
+    gif.extend_from_slice(&[0x21, 0xfe]);
+    gif.extend(sub_blocks(b"a comment to skip"));
+    gif.extend_from_slice(&[0x2c, 1, 0, 1, 0, 3, 0, 3, 0, 0b0100_0000, minimum_code_size]);
+    gif.extend(sub_blocks(image_data));
+    gif.push(0x3b);
+    gif
+}
+
+#[test]
+fn decodes_the_first_image_of_a_gif_file() {
+    let bytes = std::fs::read("files/example.gif").unwrap();
+    let pixels = decode_gif(&bytes).unwrap();
+
+    assert_eq!((pixels[0].len(), pixels.len()), (400, 400));
+    assert_eq!(pixels[0][0], Rgba::new(0, 0, 0, 255));
+    assert_eq!(pixels[200][200], Rgba::new(197, 172, 106, 255));
+}
+
+#[test]
+fn draws_interlaced_transparent_images_on_the_screen() {
+    // stored rows are 0, 2 and then 1
+    let indices = [1, 1, 1, 2, 3, 2, 1, 1, 0];
+    let pixels = decode_gif(&hand_built_gif(2, &lzw(&indices, 2))).unwrap();
+
+    let (r, b, k) = (COLORS[1].clone(), COLORS[2].clone(), COLORS[0].clone());
+    assert_eq!(
+        pixels,
+        [
+            vec![CLEAR; 5],
+            vec![CLEAR, r.clone(), r.clone(), r.clone(), CLEAR],
+            vec![CLEAR, r.clone(), r, k, CLEAR],
+            vec![CLEAR, b.clone(), CLEAR, b, CLEAR],
+        ]
+    );
+}
+
+#[test]
+fn invalid_gifs_are_errors() {
+    let gif = hand_built_gif(2, &lzw(&[1; 9], 2));
+    assert_eq!(
+        decode_gif(&gif[..gif.len() - 6]).unwrap_err().kind,
+        ErrorKind::UnexpectedEof
+    );
+
+    // index 7 is past the end of the four color table
+    let gif = hand_built_gif(3, &lzw(&[7; 9], 3));
+    assert_eq!(decode_gif(&gif).unwrap_err().kind, ErrorKind::InvalidData);
+
+    // a clear code followed by code 6, which nothing has defined yet
+    let gif = hand_built_gif(2, &[0b0011_0100, 0]);
+    assert_eq!(decode_gif(&gif).unwrap_err().kind, ErrorKind::InvalidData);
+
+    let mut gif = hand_built_gif(2, &lzw(&[1; 9], 2));
+    gif.truncate(13 + 12);
+    gif.push(0x3b);
+    assert_eq!(decode_gif(&gif).unwrap_err().kind, ErrorKind::InvalidData);
+
+    assert_eq!(
+        decode_gif(b"GIF87a").unwrap_err().kind,
+        ErrorKind::InvalidSignature
+    );
+}
@@ -0,0 +1,116 @@
+use std::io::{Read, Write};
+
+use gimg::{
+    png::{
+        deflate::{
+            decode_gzip, decode_gzip_members, encode_gzip, GzipHeader, GzipReader, GzipWriter,
+        },
+        CompressionLevel,
+    },
+    ErrorKind,
+};
+
+fn data() -> Vec<u8> {
+    b"gzip members hold deflate data between a header and a trailer. "
+        .repeat(200)
+        .to_vec()
+}
+
+fn header() -> GzipHeader {
+    GzipHeader::new()
+        .file_name("café.txt")
+        .comment("a test file")
+        .modification_time(1_700_000_000)
+        .extra_field(vec![b'A', b'P', 2, 0, 1, 2])
+        .text(true)
+        .header_crc(true)
+}
+
+#[test]
+fn headers_and_data_round_trip() {
+    let gzip = encode_gzip(&data(), header(), CompressionLevel::BEST).unwrap();
+    let members = decode_gzip_members(&gzip).unwrap();
+
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].header, header());
+    assert_eq!(members[0].data, data());
+}
+
+#[test]
+fn members_are_decoded_one_after_the_other() {
+    let mut gzip = encode_gzip(b"first member, ", header(), CompressionLevel::FAST).unwrap();
+    gzip.extend(encode_gzip(b"second member", GzipHeader::new(), CompressionLevel::NONE).unwrap());
+
+    assert_eq!(decode_gzip(&gzip).unwrap(), b"first member, second member");
+
+    let members = decode_gzip_members(&gzip).unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].header.file_name.as_deref(), Some("café.txt"));
+    assert_eq!(members[1].header, GzipHeader::new());
+    assert_eq!(members[1].data, b"second member");
+}
+
+#[test]
+fn checksum_and_length_mismatches_are_errors() {
+    let gzip = encode_gzip(&data(), header(), CompressionLevel::FAST).unwrap();
+    let trailer_start = gzip.len() - 8;
+
+    // a byte of the file name, which the header crc covers
+    let mut bad_header = gzip.clone();
+    bad_header[10 + 2 + 6] ^= 1;
+    // the crc32 of the data, then its length
+    let mut bad_crc = gzip.clone();
+    bad_crc[trailer_start] ^= 1;
+    let mut bad_length = gzip.clone();
+    bad_length[trailer_start + 4] ^= 1;
+
+    for bad_gzip in [bad_header, bad_crc, bad_length] {
+        assert_eq!(
+            decode_gzip(&bad_gzip).unwrap_err().kind,
+            ErrorKind::ChecksumMismatch
+        );
+    }
+
+    assert_eq!(
+        decode_gzip(&gzip[..trailer_start + 3]).unwrap_err().kind,
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn zero_padding_after_the_last_member_is_skipped() {
+    let mut gzip = encode_gzip(&data(), header(), CompressionLevel::FAST).unwrap();
+    let member_length = gzip.len();
+    gzip.extend_from_slice(&[0; 512]);
+
+    assert_eq!(decode_gzip(&gzip).unwrap(), data());
+    assert_eq!(decode_gzip_members(&gzip).unwrap().len(), 1);
+
+    let mut decoded = Vec::new();
+    GzipReader::new(&gzip[..])
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, data());
+
+    // padding holds nothing but zeros, and only comes after a member
+    gzip.push(1);
+    let err = decode_gzip(&gzip).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidData);
+    assert_eq!(err.offset, Some(member_length + 512));
+    assert!(decode_gzip(&[0; 32]).is_err());
+}
+
+#[test]
+fn reader_and_writer_round_trip() {
+    let mut writer = GzipWriter::new(Vec::new(), header(), CompressionLevel::BEST).unwrap();
+    for piece in data().chunks(1000) {
+        writer.write_all(piece).unwrap();
+    }
+    let gzip = writer.finish().unwrap();
+
+    let mut decoded = Vec::new();
+    GzipReader::new(&gzip[..])
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, data());
+}
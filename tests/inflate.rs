@@ -0,0 +1,133 @@
+use gimg::{
+    png::deflate::{decode_deflate, decode_gzip, decode_zlib, DeflateInflater, GzipInflater},
+    ErrorKind,
+};
+
+fn hex(bytes: &str) -> Vec<u8> {
+    bytes
+        .split_whitespace()
+        .map(|byte| u8::from_str_radix(byte, 16).unwrap())
+        .collect()
+}
+
+fn assert_invalid(bytes: &str, reason: &str) {
+    let err = decode_deflate(&hex(bytes)).expect_err(reason);
+    assert_eq!(err.kind, ErrorKind::InvalidData, "{reason}: {err}");
+}
+
+// most of the streams below come from zlib's infcover.c, with the message zlib gives them
+
+#[test]
+fn rejects_over_subscribed_code_sets() {
+    assert_invalid(
+        "04 80 49 92 24 49 92 24 71 ff ff 93 11 00",
+        "invalid literal/lengths set",
+    );
+}
+
+#[test]
+fn rejects_incomplete_code_sets() {
+    assert_invalid("04 00 fe ff", "invalid code lengths set");
+    assert_invalid(
+        "04 80 49 92 24 49 92 24 0f b4 ff ff c3 84",
+        "invalid distances set",
+    );
+}
+
+#[test]
+fn rejects_malformed_dynamic_block_headers() {
+    assert_invalid("fc 00 00", "too many length or distance symbols");
+    assert_invalid("04 00 24 49 00", "invalid bit length repeat");
+    assert_invalid("04 00 24 e9 ff ff", "invalid bit length repeat");
+    assert_invalid("04 00 24 e9 ff 6d", "invalid code -- missing end-of-block");
+}
+
+#[test]
+fn rejects_invalid_codes_and_distances() {
+    assert_invalid("06", "invalid block type");
+    assert_invalid("00 00 00 00 00", "invalid stored block lengths");
+    assert_invalid("02 7e ff ff", "invalid distance code");
+    assert_invalid(
+        "0c c0 81 00 00 00 00 00 90 ff 6b 04 00",
+        "invalid distance too far back",
+    );
+}
+
+#[test]
+fn decodes_valid_edge_cases() {
+    assert_eq!(decode_deflate(&hex("03 00")).unwrap(), b"");
+    assert_eq!(decode_deflate(&hex("01 01 00 fe ff 00")).unwrap(), [0]);
+
+    let output = decode_deflate(&hex("ed c0 01 01 00 00 00 40 20 ff 57 1b 42 2c 4f")).unwrap();
+    assert_eq!(output, [0; 516]);
+}
+
+#[test]
+fn decodes_a_single_one_bit_distance_code() {
+    // a dynamic block with one distance code, which leaves the other one bit code unused. zlib
+    // never writes these but accepts them
+    let stream = hex("0d c0 81 00 00 00 00 80 20 d6 fc 25 3e 0b");
+
+    assert_eq!(decode_deflate(&stream).unwrap(), b"aaaa");
+}
+
+#[test]
+fn rejects_zlib_streams_with_a_preset_dictionary() {
+    // FDICT is set and the dictionary id follows, then an empty stored block and its adler32
+    let err = decode_zlib(&hex("78 bb 00 00 00 01 01 00 00 ff ff 00 00 00 01")).unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::Unsupported);
+    assert_eq!(err.message, "preset dictionaries are not supported");
+    assert_eq!(decode_zlib(&hex("78 9c 03 00 00 00 00 01")).unwrap(), b"");
+}
+
+#[test]
+fn decodes_stored_blocks_split_across_feed_calls() {
+    let stream = hex("00 03 00 fc ff 61 62 63 01 02 00 fd ff 64 65");
+
+    for piece_length in 1..stream.len() {
+        let mut inflater = DeflateInflater::new();
+        let mut output = Vec::new();
+
+        for piece in stream.chunks(piece_length) {
+            inflater.feed(piece);
+
+            // a small output limit also stops it in the middle of the stored bytes
+            loop {
+                let length = output.len();
+                inflater.inflate(&mut output, length + 2).unwrap();
+
+                if output.len() == length {
+                    break;
+                }
+            }
+        }
+
+        output.extend(inflater.finish().unwrap());
+        assert_eq!(output, b"abcde", "pieces of {piece_length} bytes");
+    }
+}
+
+#[test]
+fn reports_gzip_trailer_mismatches() {
+    // an empty member with a CRC of 1 instead of 0
+    let bad_crc = hex("1f 8b 08 00 00 00 00 00 00 03 03 00 01 00 00 00 00 00 00 00");
+    // an empty member with an ISIZE of 1
+    let bad_size = hex("1f 8b 08 00 00 00 00 00 00 03 03 00 00 00 00 00 01 00 00 00");
+
+    for gzip in [bad_crc, bad_size] {
+        let err = decode_gzip(&gzip).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ChecksumMismatch, "{err}");
+
+        let mut inflater = GzipInflater::new();
+        let err = gzip
+            .chunks(3)
+            .map(|piece| inflater.push(piece))
+            .find_map(Result::err)
+            .unwrap_or_else(|| inflater.finish().unwrap_err());
+        assert_eq!(err.kind, ErrorKind::ChecksumMismatch, "{err}");
+    }
+
+    let gzip = hex("1f 8b 08 00 00 00 00 00 00 03 03 00 00 00 00 00 00 00 00 00");
+    assert_eq!(decode_gzip(&gzip).unwrap(), b"");
+}
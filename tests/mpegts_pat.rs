@@ -0,0 +1,63 @@
+use gimg::{
+    mpegts::{parse_pat, PatEntry},
+    ErrorKind,
+};
+
+// a section holding the network pid and two programs, followed by a crc that is not checked
+fn pat_section() -> Vec<u8> {
+    let mut section = vec![0x00, 0xb0, 5 + 3 * 4 + 4];
+    // transport stream id 1, version 3 that is current, section 0 of 0
+    section.extend_from_slice(&[0x00, 0x01, 0b1100_0111, 0, 0]);
+    section.extend_from_slice(&[0x00, 0x00, 0xe0, 0x10]);
+    section.extend_from_slice(&[0x00, 0x01, 0xf0, 0x00]);
+    section.extend_from_slice(&[0x00, 0x02, 0xe1, 0x00]);
+    section.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    section
+}
+
+#[test]
+fn parses_a_program_association_section() {
+    let pat = parse_pat(&pat_section()).unwrap();
+
+    assert_eq!(pat.transport_stream_id, 1);
+    assert_eq!(pat.version_number, 3);
+    assert!(pat.current_next_indicator);
+    assert_eq!((pat.section_number, pat.last_section_number), (0, 0));
+    assert_eq!(
+        pat.entries,
+        [
+            PatEntry::NetworkPid(0x10),
+            PatEntry::ProgramMap {
+                program_number: 1,
+                pid: 0x1000,
+            },
+            PatEntry::ProgramMap {
+                program_number: 2,
+                pid: 0x100,
+            },
+        ]
+    );
+}
+
+#[test]
+fn invalid_sections_are_errors() {
+    let section = pat_section();
+    assert_eq!(
+        parse_pat(&section[..section.len() - 1]).unwrap_err().kind,
+        ErrorKind::UnexpectedEof
+    );
+
+    let mut wrong_table = section.clone();
+    wrong_table[0] = 0x02;
+    assert_eq!(
+        parse_pat(&wrong_table).unwrap_err().kind,
+        ErrorKind::InvalidData
+    );
+
+    let mut partial_entry = section;
+    partial_entry[2] -= 1;
+    assert_eq!(
+        parse_pat(&partial_entry).unwrap_err().kind,
+        ErrorKind::InvalidData
+    );
+}
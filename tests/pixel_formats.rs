@@ -0,0 +1,23 @@
+use gimg::{
+    colors::Rgb,
+    pixel_formats::{Greyscale, PixelFormat, RGB24P},
+};
+
+#[test]
+fn converts_between_rgb_and_greyscale() {
+    let rgb = RGB24P::new(vec![vec![
+        Rgb::new(255, 255, 255),
+        Rgb::new(255, 0, 0),
+        Rgb::new(0, 0, 255),
+    ]]);
+    let greyscale = rgb.to_grayscale();
+
+    assert_eq!(greyscale.bit_depth(), 8);
+    assert_eq!(greyscale.pixels(), [vec![255, 76, 29]]);
+
+    let rgb: RGB24P = Greyscale::new(vec![vec![0, 128]]).into();
+    assert_eq!(
+        rgb.pixels(),
+        [vec![Rgb::new(0, 0, 0), Rgb::new(128, 128, 128)]]
+    );
+}
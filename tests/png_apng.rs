@@ -0,0 +1,98 @@
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_apng, decode_apng_with_config, decode_png, encode_apng, BlendOp, DisposeOp,
+        PartialPngConfig, PngDecodeConfig, PngFrame,
+    },
+};
+
+const W: Rgba = Rgba {
+    r: 255,
+    g: 255,
+    b: 255,
+    a: 255,
+};
+const R: Rgba = Rgba {
+    r: 255,
+    g: 0,
+    b: 0,
+    a: 255,
+};
+const B: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 255,
+    a: 255,
+};
+const T: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 0,
+    a: 0,
+};
+
+fn frames() -> Vec<PngFrame> {
+    vec![
+        PngFrame::new(vec![vec![W; 4]; 2]).delay(1, 10),
+        // over keeps the canvas under the transparent pixel, then the canvas is restored
+        PngFrame::new(vec![vec![R, T]])
+            .offset(1, 0)
+            .delay(1, 4)
+            .blend_op(BlendOp::Over)
+            .dispose_op(DisposeOp::Previous),
+        // source copies the transparent pixel, then the frame's area is cleared
+        PngFrame::new(vec![vec![B, T]])
+            .offset(2, 1)
+            .delay(3, 100)
+            .blend_op(BlendOp::Source)
+            .dispose_op(DisposeOp::Background),
+        PngFrame::new(vec![vec![R]])
+            .delay(1, 1)
+            .blend_op(BlendOp::Over),
+    ]
+}
+
+#[test]
+fn frames_round_trip() {
+    let png = encode_apng(frames(), 3, PartialPngConfig::new()).unwrap();
+    let decoded_apng = decode_apng(&png).unwrap();
+
+    assert_eq!(decoded_apng.num_plays, 3);
+    assert_eq!(decoded_apng.image.pixels, frames()[0].pixels);
+    assert_eq!(decoded_apng.frames.len(), 4);
+    for (decoded_frame, frame) in decoded_apng.frames.iter().zip(frames()) {
+        assert_eq!(decoded_frame.pixels, frame.pixels);
+        assert_eq!(
+            (decoded_frame.x_offset, decoded_frame.y_offset),
+            (frame.x_offset, frame.y_offset)
+        );
+        assert_eq!(decoded_frame.delay_seconds(), frame.delay_seconds());
+        assert_eq!(decoded_frame.dispose_op, frame.dispose_op);
+        assert_eq!(decoded_frame.blend_op, frame.blend_op);
+    }
+
+    // decoders without APNG support see the first frame
+    assert_eq!(decode_png(&png).unwrap(), frames()[0].pixels);
+}
+
+#[test]
+fn composites_frames_with_their_dispose_and_blend_ops() {
+    let png = encode_apng(frames(), 0, PartialPngConfig::new()).unwrap();
+    let config = PngDecodeConfig::new().composite_frames(true);
+    let decoded_apng = decode_apng_with_config(&png, config).unwrap();
+
+    let canvases: Vec<_> = decoded_apng
+        .frames
+        .iter()
+        .map(|frame| frame.pixels.clone())
+        .collect();
+    assert_eq!(
+        canvases,
+        [
+            vec![vec![W, W, W, W], vec![W, W, W, W]],
+            vec![vec![W, R, W, W], vec![W, W, W, W]],
+            vec![vec![W, W, W, W], vec![W, W, B, T]],
+            vec![vec![R, W, W, W], vec![W, W, T, T]],
+        ]
+    );
+}
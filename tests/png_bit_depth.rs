@@ -0,0 +1,98 @@
+use gimg::{
+    colors::{Rgba, Rgba16},
+    png::{
+        decode_png, encode_png, AdaptiveFilterType, ColorType, CompressionLevel, FilterStrategy,
+        PartialPngConfig, PngDecodeConfig, PngMetadata, PngStreamDecoder, SignificantBits,
+    },
+};
+
+use common::{chunks, hand_built_png, image_data};
+
+mod common;
+
+fn grey(luma: u8) -> Rgba {
+    Rgba::new(luma, luma, luma, u8::MAX)
+}
+
+#[test]
+fn packs_sub_byte_samples_leftmost_in_the_high_bits() {
+    let pixels = vec![vec![grey(0), grey(85), grey(170), grey(255)]];
+    let config = PartialPngConfig::new()
+        .color_type(ColorType::Greyscale)
+        .bit_depth(2)
+        .compression_level(CompressionLevel::NONE)
+        .filter_strategy(FilterStrategy::Fixed(AdaptiveFilterType::None));
+    let png = encode_png(pixels, config).unwrap();
+
+    assert_eq!(image_data(&png), [0x00, 0x1b]);
+
+    let rows = vec![vec![0, 0x5555, 0xaaaa, 0xffff]
+        .into_iter()
+        .map(|luma| Rgba16 {
+            r: luma,
+            g: luma,
+            b: luma,
+            a: u16::MAX,
+        })
+        .collect()];
+    assert_eq!(
+        ColorType::Greyscale
+            .create_scanlines(&rows, 2, &None)
+            .unwrap(),
+        [vec![0x1b]]
+    );
+}
+
+#[test]
+fn reads_sub_byte_samples_leftmost_from_the_high_bits() {
+    let png = hand_built_png(4, 2, 0, &[], &[0x00, 0x1b]);
+    let expected = vec![vec![grey(0), grey(85), grey(170), grey(255)]];
+    assert_eq!(decode_png(&png).unwrap(), expected);
+
+    let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+    let mut rows = vec![];
+    for byte in &png {
+        decoder.push(&[*byte]).unwrap();
+        rows.extend(std::iter::from_fn(|| decoder.next_row()));
+    }
+    let lumas: Vec<u16> = rows[0].pixels.iter().map(|pixel| pixel.r).collect();
+    assert_eq!(lumas, [0, 0x5555, 0xaaaa, 0xffff]);
+
+    let png = hand_built_png(8, 1, 0, &[], &[0x00, 0xc0]);
+    let expected = vec![(0..8)
+        .map(|x| grey(if x < 2 { 255 } else { 0 }))
+        .collect::<Vec<_>>()];
+    assert_eq!(decode_png(&png).unwrap(), expected);
+
+    let palette = [10, 20, 30, 40, 50, 60];
+    let png = hand_built_png(2, 4, 3, &palette, &[0x00, 0x01]);
+    let expected = vec![vec![Rgba::new(10, 20, 30, 255), Rgba::new(40, 50, 60, 255)]];
+    assert_eq!(decode_png(&png).unwrap(), expected);
+}
+
+#[test]
+fn writes_significant_bits_only_when_asked_to() {
+    let pixels = vec![vec![grey(0), grey(17), grey(34), grey(255)]];
+    let has_sbit = |png: &[u8]| {
+        chunks(png)
+            .iter()
+            .any(|(chunk_type, _data)| chunk_type == b"sBIT")
+    };
+
+    let config = PartialPngConfig::new().bit_depth(16);
+    let png = encode_png(pixels.clone(), config).unwrap();
+    assert!(!has_sbit(&png));
+
+    let significant_bits = SignificantBits {
+        red: 4,
+        green: 4,
+        blue: 4,
+        alpha: None,
+    };
+    let metadata = PngMetadata {
+        significant_bits: Some(significant_bits),
+        ..PngMetadata::default()
+    };
+    let png = encode_png(pixels, PartialPngConfig::new().metadata(metadata)).unwrap();
+    assert!(has_sbit(&png));
+}
@@ -0,0 +1,78 @@
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_png_with_config, encode_png, Chromaticities, ColorSpace, IccProfile,
+        PartialPngConfig, PngDecodeConfig, RenderingIntent,
+    },
+};
+
+fn pixels() -> Vec<Vec<Rgba>> {
+    vec![vec![
+        Rgba::new(128, 0, 255, 255),
+        Rgba::new(0, 128, 255, 255),
+    ]]
+}
+
+fn round_trip(color_space: ColorSpace, decode_config: PngDecodeConfig) -> (ColorSpace, Vec<Rgba>) {
+    let config = PartialPngConfig::new().color_space(color_space);
+    let png = encode_png(pixels(), config).unwrap();
+    let decoded_png = decode_png_with_config(&png, decode_config).unwrap();
+
+    (decoded_png.color_space, decoded_png.pixels[0].clone())
+}
+
+#[test]
+fn color_space_chunks_round_trip() {
+    let color_space = ColorSpace {
+        gamma: Some(45_455),
+        chromaticities: Some(Chromaticities {
+            white_x: 31_270,
+            white_y: 32_900,
+            red_x: 64_000,
+            red_y: 33_000,
+            green_x: 30_000,
+            green_y: 60_000,
+            blue_x: 15_000,
+            blue_y: 6_000,
+        }),
+        srgb_intent: None,
+        icc_profile: Some(IccProfile {
+            name: "Display profile".to_string(),
+            profile: (0..=255).collect(),
+        }),
+    };
+
+    for color_space in [color_space, ColorSpace::srgb(RenderingIntent::Saturation)] {
+        let (decoded_color_space, decoded_pixels) =
+            round_trip(color_space.clone(), PngDecodeConfig::new());
+
+        assert_eq!(decoded_color_space, color_space);
+        assert_eq!(decoded_pixels, pixels()[0]);
+    }
+}
+
+#[test]
+fn converts_a_lone_gamma_to_srgb_only_when_asked_to() {
+    // a gamma of 1.0, so the samples are linear light
+    let linear = ColorSpace {
+        gamma: Some(100_000),
+        ..ColorSpace::default()
+    };
+
+    let (color_space, pixels) = round_trip(linear.clone(), PngDecodeConfig::new());
+    assert_eq!(color_space, linear);
+    assert_eq!(pixels, self::pixels()[0]);
+
+    let config = PngDecodeConfig::new().convert_to_srgb(true);
+    let (color_space, pixels) = round_trip(linear, config);
+    assert_eq!(color_space, ColorSpace::srgb(RenderingIntent::Perceptual));
+    // linear 128 / 255 is sRGB 188 / 255, black, white and alpha stay put
+    assert_eq!(pixels[0], Rgba::new(188, 0, 255, 255));
+    assert_eq!(pixels[1], Rgba::new(0, 188, 255, 255));
+
+    // with an sRGB chunk the gamma is only a fallback, so nothing is converted
+    let srgb = ColorSpace::srgb(RenderingIntent::Perceptual);
+    let config = PngDecodeConfig::new().convert_to_srgb(true);
+    let (_color_space, pixels) = round_trip(srgb, config);
+    assert_eq!(pixels, self::pixels()[0]);
+}
@@ -0,0 +1,95 @@
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_apng_with_config, decode_png, decode_png_with_config, encode_png, ChecksumPolicy,
+        PartialPngConfig, PngDecodeConfig, PngStreamDecoder,
+    },
+    ErrorKind,
+};
+
+use common::{chunk, hand_built_png, stored_zlib, PNG_SIGNATURE};
+
+mod common;
+
+fn small_png() -> Vec<u8> {
+    let pixels = (0..4)
+        .map(|y| (0..5).map(|x| Rgba::new(x * 50, y * 60, 7, 255)).collect())
+        .collect();
+    encode_png(pixels, PartialPngConfig::new()).unwrap()
+}
+
+#[test]
+fn input_shorter_than_the_signature_is_an_error() {
+    for length in 0..PNG_SIGNATURE.len() {
+        let err = decode_png(&PNG_SIGNATURE[..length]).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnexpectedEof, "{length} bytes");
+    }
+
+    let err = decode_png(b"GIF").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidSignature);
+}
+
+#[test]
+fn truncated_png_is_an_error() {
+    let png = small_png();
+
+    for length in 0..png.len() {
+        assert!(decode_png(&png[..length]).is_err(), "{length} bytes");
+
+        let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+        decoder.push(&png[..length]).unwrap();
+        assert!(decoder.finish().is_err(), "{length} bytes");
+    }
+}
+
+#[test]
+fn palette_index_past_the_palette_is_an_error() {
+    let palette = [255, 0, 0, 0, 0, 255];
+    let pngs = [
+        hand_built_png(2, 8, 3, &palette, &[0, 1, 245]),
+        hand_built_png(4, 2, 3, &palette, &[0, 0b01_00_11_00]),
+    ];
+
+    for png in pngs {
+        let err = decode_png(&png).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidData);
+
+        let config = PngDecodeConfig::new().checksum_policy(ChecksumPolicy::Warn);
+        let err = decode_png_with_config(&png, config).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidData);
+
+        let config = PngDecodeConfig::new().checksum_policy(ChecksumPolicy::Warn);
+        let err = decode_apng_with_config(&png, config).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidData);
+
+        let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+        let err = decoder.push(&png).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn checksum_mismatches_follow_the_checksum_policy() {
+    let scanline = [0, 10, 20, 30];
+
+    // the last byte of the IHDR crc
+    let mut bad_crc = hand_built_png(1, 8, 2, &[], &scanline);
+    bad_crc[32] ^= 1;
+
+    let mut image_data = stored_zlib(&scanline);
+    *image_data.last_mut().unwrap() ^= 1;
+    let mut bad_adler = hand_built_png(1, 8, 2, &[], &scanline);
+    let idat_range = 33..bad_adler.len() - 12;
+    bad_adler.splice(idat_range, chunk(b"IDAT", &image_data));
+
+    for png in [bad_crc, bad_adler] {
+        let err = decode_png(&png).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ChecksumMismatch);
+
+        let config = PngDecodeConfig::new().checksum_policy(ChecksumPolicy::Warn);
+        let decoded_png = decode_png_with_config(&png, config).unwrap();
+        assert_eq!(decoded_png.pixels, [[Rgba::new(10, 20, 30, 255)]]);
+        assert_eq!(decoded_png.warnings.len(), 1);
+        assert_eq!(decoded_png.warnings[0].kind, ErrorKind::ChecksumMismatch);
+    }
+}
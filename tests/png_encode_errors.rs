@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use gimg::{
+    colors::{Rgba, Rgba16},
+    png::{encode_png, ColorType, PartialPngConfig, PngEncodeError, PngStreamEncoder, PngText},
+    ErrorKind,
+};
+
+fn pixels() -> Vec<Vec<Rgba>> {
+    vec![vec![Rgba::new(1, 2, 3, 255), Rgba::new(4, 5, 6, 255)]; 2]
+}
+
+#[test]
+fn invalid_images_are_errors() {
+    assert!(matches!(
+        encode_png(vec![], PartialPngConfig::new()),
+        Err(PngEncodeError::EmptyImage)
+    ));
+
+    let mut ragged_pixels = pixels();
+    ragged_pixels[1].pop();
+    assert!(matches!(
+        encode_png(ragged_pixels, PartialPngConfig::new()),
+        Err(PngEncodeError::RaggedRows {
+            row: 1,
+            width: 1,
+            expected_width: 2,
+        })
+    ));
+}
+
+#[test]
+fn invalid_configs_are_errors() {
+    let config = PartialPngConfig::new()
+        .color_type(ColorType::Truecolor)
+        .bit_depth(4);
+    assert!(matches!(
+        encode_png(pixels(), config),
+        Err(PngEncodeError::InvalidBitDepth(ColorType::Truecolor, 4))
+    ));
+
+    let config = PartialPngConfig::new().text(PngText::new("", "no keyword"));
+    let err = encode_png(pixels(), config).unwrap_err();
+    assert!(matches!(err, PngEncodeError::InvalidChunk(_)));
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn streams_with_the_wrong_number_of_rows_are_errors() {
+    let row = &pixels()[0];
+    let mut encoder = PngStreamEncoder::new(Vec::new(), 2, 1, PartialPngConfig::new()).unwrap();
+    encoder.write_row(row).unwrap();
+    assert!(matches!(
+        encoder.write_row(row),
+        Err(PngEncodeError::RowCount { rows: 2, height: 1 })
+    ));
+
+    let encoder = PngStreamEncoder::new(Vec::new(), 2, 2, PartialPngConfig::new()).unwrap();
+    assert!(matches!(
+        encoder.finish(),
+        Err(PngEncodeError::RowCount { rows: 0, height: 2 })
+    ));
+}
+
+#[test]
+fn scanlines_that_cannot_be_packed_are_errors() {
+    let pixels: Vec<Vec<Rgba16>> = vec![pixels()[0].iter().map(Rgba16::from).collect()];
+
+    assert!(matches!(
+        ColorType::IndexedColor.create_scanlines(&pixels, 8, &None),
+        Err(PngEncodeError::MissingPalette)
+    ));
+
+    let palette = HashMap::from([(Rgba::new(1, 2, 3, 255), (0, Rgba::new(1, 2, 3, 255)))]);
+    assert!(matches!(
+        ColorType::IndexedColor.create_scanlines(&pixels, 2, &Some(palette)),
+        Err(PngEncodeError::ColorNotInPalette(Rgba {
+            r: 4,
+            g: 5,
+            b: 6,
+            a: 255
+        }))
+    ));
+
+    assert!(matches!(
+        ColorType::Truecolor.create_scanlines(&pixels, 4, &None),
+        Err(PngEncodeError::InvalidBitDepth(ColorType::Truecolor, 4))
+    ));
+}
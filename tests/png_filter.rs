@@ -0,0 +1,75 @@
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_png, encode_png_with_report, AdaptiveFilterType, CompressionLevel, FilterStrategy,
+        InterlaceMethod, PartialPngConfig,
+    },
+};
+
+use common::image_data;
+
+mod common;
+
+fn pixels() -> Vec<Vec<Rgba>> {
+    (0..7u8)
+        .map(|y| {
+            (0..9u8)
+                .map(|x| Rgba::new(x * 25, y * 30, (x * y) % 7 * 30, 255))
+                .collect()
+        })
+        .collect()
+}
+
+fn filter_byte(filter: AdaptiveFilterType) -> u8 {
+    match filter {
+        AdaptiveFilterType::None => 0,
+        AdaptiveFilterType::Sub => 1,
+        AdaptiveFilterType::Up => 2,
+        AdaptiveFilterType::Average => 3,
+        AdaptiveFilterType::Paeth => 4,
+    }
+}
+
+#[test]
+fn every_strategy_round_trips_and_reports_the_stored_filters() {
+    for filter_strategy in [
+        FilterStrategy::Fixed(AdaptiveFilterType::Average),
+        FilterStrategy::MinimumSumOfAbsoluteDifferences,
+        FilterStrategy::Entropy,
+        FilterStrategy::BruteForce,
+    ] {
+        let config = PartialPngConfig::new().filter_strategy(filter_strategy);
+        let encoded_png = encode_png_with_report(pixels(), config).unwrap();
+
+        assert_eq!(decode_png(&encoded_png.bytes).unwrap(), pixels());
+
+        let data = image_data(&encoded_png.bytes);
+        let stored_filters: Vec<u8> = data.chunks(data.len() / 7).map(|row| row[0]).collect();
+        let reported_filters: Vec<u8> = encoded_png
+            .row_filters
+            .iter()
+            .map(|&filter| filter_byte(filter))
+            .collect();
+        assert_eq!(stored_filters, reported_filters);
+    }
+}
+
+#[test]
+fn fixed_filters_are_used_for_every_row() {
+    let config = PartialPngConfig::new()
+        .filter_strategy(FilterStrategy::Fixed(AdaptiveFilterType::Sub))
+        .interlace_method(InterlaceMethod::Adam7);
+    let encoded_png = encode_png_with_report(pixels(), config).unwrap();
+
+    // the 9x7 image has 1 + 1 + 1 + 2 + 2 + 4 + 3 rows across the adam7 passes
+    assert_eq!(encoded_png.row_filters, [AdaptiveFilterType::Sub; 14]);
+    assert_eq!(decode_png(&encoded_png.bytes).unwrap(), pixels());
+}
+
+#[test]
+fn the_default_strategy_follows_the_compression_level() {
+    let config = PartialPngConfig::new().compression_level(CompressionLevel::NONE);
+    let encoded_png = encode_png_with_report(pixels(), config).unwrap();
+
+    assert_eq!(encoded_png.row_filters, [AdaptiveFilterType::None; 7]);
+}
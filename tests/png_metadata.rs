@@ -0,0 +1,130 @@
+use gimg::{
+    colors::{Rgb16, Rgba},
+    png::{
+        decode_png_with_config, encode_png, ColorType, ModificationTime, PartialPngConfig,
+        PhysicalDimensions, PngDecodeConfig, PngEncodeError, PngMetadata, SignificantBits,
+        SuggestedPalette, SuggestedPaletteEntry,
+    },
+};
+
+// a little endian exif block holding a single IFD with an orientation entry
+fn exif_with_orientation(orientation: u16) -> Vec<u8> {
+    let mut exif = b"II*\0".to_vec();
+    exif.extend_from_slice(&8u32.to_le_bytes());
+    exif.extend_from_slice(&1u16.to_le_bytes());
+    // the orientation tag, a SHORT type and a count of 1
+    exif.extend_from_slice(&0x0112u16.to_le_bytes());
+    exif.extend_from_slice(&3u16.to_le_bytes());
+    exif.extend_from_slice(&1u32.to_le_bytes());
+    exif.extend_from_slice(&orientation.to_le_bytes());
+    exif.extend_from_slice(&[0; 2]);
+    exif.extend_from_slice(&0u32.to_le_bytes());
+    exif
+}
+
+fn suggested_palette(name: &str, sample_depth: u8) -> SuggestedPalette {
+    let max = if sample_depth == 8 { 255 } else { u16::MAX };
+
+    SuggestedPalette {
+        name: name.to_string(),
+        sample_depth,
+        entries: vec![
+            SuggestedPaletteEntry {
+                red: max,
+                green: 0,
+                blue: 0,
+                alpha: max,
+                frequency: 10,
+            },
+            SuggestedPaletteEntry {
+                red: 0,
+                green: max / 2,
+                blue: max,
+                alpha: max / 3,
+                frequency: 2,
+            },
+        ],
+    }
+}
+
+#[test]
+fn metadata_chunks_round_trip() {
+    let metadata = PngMetadata {
+        physical_dimensions: Some(PhysicalDimensions::from_dpi(300.0, 300.0)),
+        modification_time: Some(ModificationTime {
+            year: 2024,
+            month: 2,
+            day: 29,
+            hour: 23,
+            minute: 59,
+            second: 30,
+        }),
+        background: Some(Rgb16::new(0x1212, 0x3434, 0x5656)),
+        significant_bits: Some(SignificantBits {
+            red: 5,
+            green: 6,
+            blue: 5,
+            alpha: None,
+        }),
+        histogram: None,
+        suggested_palettes: vec![
+            suggested_palette("Web safe", 8),
+            suggested_palette("Deep", 16),
+        ],
+        exif: Some(exif_with_orientation(6)),
+    };
+    let pixels = vec![vec![Rgba::new(10, 20, 30, 255), Rgba::new(40, 50, 60, 255)]];
+    let config = PartialPngConfig::new()
+        .color_type(ColorType::Truecolor)
+        .metadata(metadata.clone());
+    let png = encode_png(pixels, config).unwrap();
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    assert_eq!(decoded_png.metadata, metadata);
+    assert_eq!(decoded_png.metadata.exif_orientation(), Some(6));
+
+    let (dpi_x, dpi_y) = metadata.physical_dimensions.unwrap().dpi().unwrap();
+    assert!((dpi_x - 300.0).abs() < 0.01 && (dpi_y - 300.0).abs() < 0.01);
+}
+
+#[test]
+fn indexed_images_get_a_recounted_histogram_and_a_palette_background() {
+    let (red, blue) = (Rgba::new(255, 0, 0, 255), Rgba::new(0, 0, 255, 255));
+    let pixels = vec![vec![red.clone(), red.clone(), blue.clone(), red]];
+    let metadata = PngMetadata {
+        background: Some(Rgb16::new(0, 0, u16::MAX)),
+        histogram: Some(Vec::new()),
+        ..PngMetadata::default()
+    };
+    let config = PartialPngConfig::new()
+        .color_type(ColorType::IndexedColor)
+        .metadata(metadata);
+    let png = encode_png(pixels, config).unwrap();
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    let mut histogram = decoded_png.metadata.histogram.unwrap();
+    histogram.sort();
+    assert_eq!(histogram, [1, 3]);
+    assert_eq!(
+        decoded_png.metadata.background,
+        Some(Rgb16::new(0, 0, u16::MAX))
+    );
+}
+
+#[test]
+fn suggested_palettes_must_have_a_sample_depth_of_8_or_16() {
+    let pixels = vec![vec![Rgba::new(10, 20, 30, 255)]];
+
+    for sample_depth in [0, 4, 12] {
+        let metadata = PngMetadata {
+            suggested_palettes: vec![suggested_palette("Odd", sample_depth)],
+            ..PngMetadata::default()
+        };
+        let config = PartialPngConfig::new().metadata(metadata);
+
+        assert!(matches!(
+            encode_png(pixels.clone(), config),
+            Err(PngEncodeError::InvalidChunk(_))
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+use gimg::{
+    colors::{Rgba, Rgba16},
+    png::{
+        decode_png, decode_png16_with_config, encode_png16, optimize_png, optimize_png16,
+        ChunkPosition, ColorSpace, PartialPngConfig, PhysicalDimensions, PngDecodeConfig,
+        PngEncodeError, PngMetadata, PngText, Quantization, RenderingIntent, UnknownChunk,
+    },
+};
+
+use common::{chunks, image_data};
+
+mod common;
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+// a reference decoder for non-interlaced greyscale and indexed pngs at 8 bits or less, written
+// straight from the spec so that it shares nothing with the encoder it checks
+fn decode_low_bit_depth_png(png: &[u8]) -> Vec<Vec<Rgba>> {
+    let chunks = chunks(png);
+    let ihdr = chunks[0].1;
+    let width = u32::from_be_bytes(ihdr[0..4].try_into().unwrap()) as usize;
+    let height = u32::from_be_bytes(ihdr[4..8].try_into().unwrap()) as usize;
+    let (bit_depth, color_type) = (ihdr[8] as usize, ihdr[9]);
+    assert!(bit_depth <= 8 && (color_type == 0 || color_type == 3) && ihdr[12] == 0);
+
+    let palette: Vec<Rgba> = chunks
+        .iter()
+        .find(|(chunk_type, _data)| chunk_type == b"PLTE")
+        .map(|(_chunk_type, data)| {
+            data.chunks(3)
+                .map(|rgb| Rgba::new(rgb[0], rgb[1], rgb[2], 255))
+                .collect()
+        })
+        .unwrap_or_default();
+    let image_data = image_data(png);
+
+    let stride = (width * bit_depth).div_ceil(8);
+    let mut previous = vec![0u8; stride];
+    let mut rows = vec![];
+    for filtered in image_data.chunks(stride + 1).take(height) {
+        let mut scanline = filtered[1..].to_vec();
+        for x in 0..stride {
+            let a = if x > 0 { scanline[x - 1] } else { 0 };
+            let (b, c) = (previous[x], if x > 0 { previous[x - 1] } else { 0 });
+            let prediction = match filtered[0] {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => paeth(a, b, c),
+                filter_type => panic!("invalid filter type {filter_type}"),
+            };
+            scanline[x] = scanline[x].wrapping_add(prediction);
+        }
+
+        let row = (0..width)
+            .map(|x| {
+                let bit_index = x * bit_depth;
+                let shift = 8 - bit_depth - bit_index % 8;
+                let value = (scanline[bit_index / 8] >> shift) & ((1u16 << bit_depth) - 1) as u8;
+                match color_type {
+                    0 => {
+                        let luma = (value as u32 * 255 / ((1 << bit_depth) - 1)) as u8;
+                        Rgba::new(luma, luma, luma, 255)
+                    }
+                    _ => palette[value as usize].clone(),
+                }
+            })
+            .collect();
+        rows.push(row);
+        previous = scanline;
+    }
+    rows
+}
+
+fn to_16_bit(pixels: &[Vec<Rgba>]) -> Vec<Vec<Rgba16>> {
+    pixels
+        .iter()
+        .map(|row| row.iter().map(Rgba16::from).collect())
+        .collect()
+}
+
+#[test]
+fn optimized_low_bit_depth_candidates_match_a_reference_decoder() {
+    let greys = [0, 85, 170, 255].map(|luma| Rgba::new(luma, luma, luma, 255));
+    let colors = [
+        Rgba::new(200, 10, 10, 255),
+        Rgba::new(10, 200, 10, 255),
+        Rgba::new(10, 10, 200, 255),
+    ];
+
+    for palette in [&greys[..2], &greys[..], &colors[..]] {
+        let pixels: Vec<Vec<Rgba>> = (0..64)
+            .map(|y| {
+                (0..125)
+                    .map(|x| palette[(x * x + y) % palette.len()].clone())
+                    .collect()
+            })
+            .collect();
+        let png = optimize_png16(to_16_bit(&pixels), PartialPngConfig::new()).unwrap();
+
+        assert!(chunks(&png)[0].1[8] < 8, "expected a sub-byte bit depth");
+        assert_eq!(decode_low_bit_depth_png(&png), pixels);
+    }
+}
+
+#[test]
+fn optimized_pngs_are_exact_and_no_larger_than_the_default_encoding() {
+    let pixels: Vec<Vec<Rgba>> = (0..40)
+        .map(|y| {
+            (0..50)
+                .map(|x| {
+                    let luma = ((x / 5 + y / 4) % 3 * 85) as u8;
+                    Rgba::new(luma, luma, luma, 255)
+                })
+                .collect()
+        })
+        .collect();
+    let png = encode_png16(to_16_bit(&pixels), PartialPngConfig::new()).unwrap();
+    let optimized_png = optimize_png(&png).unwrap();
+
+    assert!(optimized_png.len() <= png.len());
+    assert_eq!(decode_png(&optimized_png).unwrap(), pixels);
+}
+
+#[test]
+fn candidates_that_fail_to_encode_are_skipped() {
+    let colors = [
+        Rgba::new(255, 0, 0, 255),
+        Rgba::new(0, 255, 0, 255),
+        Rgba::new(0, 0, 255, 255),
+    ];
+    let pixels: Vec<Vec<Rgba>> = (0..8)
+        .map(|y| (0..8).map(|x| colors[(x + y) % 3].clone()).collect())
+        .collect();
+
+    // quantization only works with indexed color, so every other color type fails
+    let config = PartialPngConfig::new().quantization(Quantization::new(4));
+    let optimized_png = optimize_png16(to_16_bit(&pixels), config).unwrap();
+    assert_eq!(decode_png(&optimized_png).unwrap(), pixels);
+
+    // nothing can encode an invalid keyword, so the first failure comes back
+    let config = PartialPngConfig::new().text(PngText::new("", "no keyword"));
+    assert!(matches!(
+        optimize_png16(to_16_bit(&pixels), config),
+        Err(PngEncodeError::InvalidChunk(_))
+    ));
+}
+
+#[test]
+fn optimizing_a_png_keeps_its_ancillary_chunks() {
+    let pixels: Vec<Vec<Rgba16>> = (0..16u16)
+        .map(|y| {
+            (0..16u16)
+                .map(|x| Rgba16 {
+                    r: x * 4096,
+                    g: y * 4096,
+                    b: 0,
+                    a: u16::MAX,
+                })
+                .collect()
+        })
+        .collect();
+    let metadata = PngMetadata {
+        physical_dimensions: Some(PhysicalDimensions::from_dpi(300.0, 300.0)),
+        ..PngMetadata::default()
+    };
+    let unknown_chunk = UnknownChunk::new(*b"prVt", vec![1, 2, 3], ChunkPosition::AfterImageData);
+    let config = PartialPngConfig::new()
+        .text(PngText::new("Title", "A gradient"))
+        .text(PngText::new("Author", "Someone").language("en", "Autor"))
+        .color_space(ColorSpace::srgb(RenderingIntent::Perceptual))
+        .metadata(metadata.clone())
+        .unknown_chunk(unknown_chunk.clone());
+    let png = encode_png16(pixels.clone(), config).unwrap();
+
+    let optimized_png = optimize_png(&png).unwrap();
+    let decoded_png = decode_png16_with_config(&optimized_png, PngDecodeConfig::new()).unwrap();
+
+    assert_eq!(decoded_png.pixels, pixels);
+    assert_eq!(decoded_png.text.len(), 2);
+    assert_eq!(
+        decoded_png.color_space.srgb_intent,
+        Some(RenderingIntent::Perceptual)
+    );
+    assert_eq!(
+        decoded_png.metadata.physical_dimensions,
+        metadata.physical_dimensions
+    );
+    assert_eq!(decoded_png.unknown_chunks, [unknown_chunk]);
+}
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use gimg::{
+    colors::Rgba,
+    png::{decode_png, encode_png, Dithering, PartialPngConfig, Quantization},
+};
+
+use common::chunks;
+
+mod common;
+
+fn gradient() -> Vec<Vec<Rgba>> {
+    (0..32u8)
+        .map(|y| {
+            (0..48u8)
+                .map(|x| Rgba::new(x * 5, y * 8, 255 - x * 5, 128 + y * 4))
+                .collect()
+        })
+        .collect()
+}
+
+fn unique_colors(pixels: &[Vec<Rgba>]) -> HashSet<Rgba> {
+    pixels.iter().flatten().cloned().collect()
+}
+
+#[test]
+fn quantized_images_stay_within_the_color_count() {
+    for dithering in [
+        Dithering::None,
+        Dithering::FloydSteinberg,
+        Dithering::Ordered,
+    ] {
+        let quantization = Quantization::new(16).dithering(dithering);
+        let png = encode_png(
+            gradient(),
+            PartialPngConfig::new().quantization(quantization),
+        )
+        .unwrap();
+
+        let chunks = chunks(&png);
+        let ihdr = chunks[0].1;
+        assert_eq!((ihdr[8], ihdr[9]), (4, 3), "expected 4-bit indexed color");
+        let (_chunk_type, palette) = chunks
+            .iter()
+            .find(|(chunk_type, _data)| chunk_type == b"PLTE")
+            .unwrap();
+        assert!(palette.len() <= 16 * 3);
+
+        let pixels = decode_png(&png).unwrap();
+        assert_eq!(pixels.len(), 32);
+        assert!(unique_colors(&pixels).len() <= 16);
+    }
+}
+
+#[test]
+fn images_with_few_colors_keep_them_exactly() {
+    let colors = [
+        Rgba::new(10, 20, 30, 255),
+        Rgba::new(200, 100, 0, 255),
+        Rgba::new(0, 0, 0, 0),
+    ];
+    let pixels: Vec<Vec<Rgba>> = (0..9)
+        .map(|y| (0..10).map(|x| colors[(x + y) % 3].clone()).collect())
+        .collect();
+    let png = encode_png(
+        pixels.clone(),
+        PartialPngConfig::new().quantization(Quantization::new(64)),
+    )
+    .unwrap();
+
+    assert_eq!(chunks(&png)[0].1[8], 2);
+    assert_eq!(decode_png(&png).unwrap(), pixels);
+}
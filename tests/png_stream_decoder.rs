@@ -0,0 +1,118 @@
+use gimg::{
+    colors::{Rgba, Rgba16},
+    png::{
+        decode_png16, encode_png, read_png_rows, InterlaceMethod, PartialPngConfig,
+        PngDecodeConfig, PngStreamDecoder, PngText,
+    },
+    ErrorKind,
+};
+
+fn encoded_png(interlace_method: InterlaceMethod) -> Vec<u8> {
+    let pixels = (0..13u8)
+        .map(|y| {
+            (0..11u8)
+                .map(|x| Rgba::new(x * 20, y * 15, x ^ y, 255 - x))
+                .collect()
+        })
+        .collect();
+    let config = PartialPngConfig::new()
+        .interlace_method(interlace_method)
+        .text(PngText::new("Comment", "streamed"));
+
+    encode_png(pixels, config).unwrap()
+}
+
+fn blank_image(decoder: &PngStreamDecoder) -> Vec<Vec<Rgba16>> {
+    let (width, height) = decoder.dimensions().unwrap();
+
+    vec![vec![Rgba16::new(0, 0, 0, 0); width as usize]; height as usize]
+}
+
+#[test]
+fn decodes_a_png_pushed_one_byte_at_a_time() {
+    for (interlace_method, last_pass) in [
+        (InterlaceMethod::NoInterlace, 0),
+        (InterlaceMethod::Adam7, 6),
+    ] {
+        let png = encoded_png(interlace_method);
+        let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+        let mut image = Vec::new();
+        let mut passes = Vec::new();
+
+        for byte in png.iter() {
+            decoder.push(&[*byte]).unwrap();
+
+            while let Some(row) = decoder.next_row() {
+                if image.is_empty() {
+                    image = blank_image(&decoder);
+                }
+                passes.push(row.pass);
+                row.draw(&mut image);
+            }
+        }
+
+        assert!(decoder.is_finished());
+        assert_eq!(image, decode_png16(&png).unwrap());
+        assert!(passes.is_sorted());
+        assert_eq!(passes.last(), Some(&last_pass));
+
+        let decoded_png = decoder.finish().unwrap();
+        assert_eq!(decoded_png.text, [PngText::new("Comment", "streamed")]);
+        assert!(decoded_png.pixels.is_empty());
+    }
+}
+
+#[test]
+fn reads_rows_from_a_reader() {
+    let png = encoded_png(InterlaceMethod::Adam7);
+    let mut rows = read_png_rows(&png[..], PngDecodeConfig::new());
+    let mut image = Vec::new();
+
+    while let Some(row) = rows.next() {
+        let row = row.unwrap();
+        if image.is_empty() {
+            image = blank_image(rows.decoder());
+        }
+        row.draw(&mut image);
+    }
+
+    assert_eq!(image, decode_png16(&png).unwrap());
+    assert_eq!(rows.finish().unwrap().text.len(), 1);
+}
+
+#[test]
+fn previews_fill_the_blocks_of_an_interlaced_image() {
+    let png = encoded_png(InterlaceMethod::Adam7);
+    let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+    decoder.push(&png).unwrap();
+
+    // the first pass has a single pixel for every 8x8 block
+    let first_row = decoder.next_row().unwrap();
+    let mut preview = blank_image(&decoder);
+    first_row.draw_preview(&mut preview);
+
+    assert_eq!((first_row.block_width, first_row.block_height), (8, 8));
+    assert_eq!(preview[7][7], first_row.pixels[0]);
+    assert_eq!(preview[7][8], first_row.pixels[1]);
+    assert_eq!(preview[8][0], Rgba16::new(0, 0, 0, 0));
+}
+
+#[test]
+fn malformed_chunks_are_errors_instead_of_waiting_for_more_data() {
+    let png = encoded_png(InterlaceMethod::NoInterlace);
+    // right after the signature and the IHDR chunk
+    let chunk_offset = 33;
+
+    for chunk_type in [b"tEXt", b"IDAT"] {
+        let mut malformed_png = png[..chunk_offset].to_vec();
+        malformed_png.extend_from_slice(&u32::MAX.to_be_bytes());
+        malformed_png.extend_from_slice(chunk_type);
+        malformed_png.extend_from_slice(&png[chunk_offset..]);
+
+        let mut decoder = PngStreamDecoder::new(PngDecodeConfig::new());
+        let err = decoder.push(&malformed_png).unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::InvalidData);
+        assert_eq!(err.offset, Some(chunk_offset));
+    }
+}
@@ -0,0 +1,62 @@
+use gimg::{
+    colors::{Rgba, Rgba16},
+    png::{
+        decode_png16, decode_png_with_config, PartialPngConfig, PngDecodeConfig, PngStreamEncoder,
+        PngText,
+    },
+};
+
+use common::chunks;
+
+mod common;
+
+#[test]
+fn streamed_rows_round_trip() {
+    let (width, height) = (37, 29);
+    let rows: Vec<Vec<Rgba>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| Rgba::new((x * 7) as u8, (y * 9) as u8, (x * y) as u8, 200))
+                .collect()
+        })
+        .collect();
+    let config = PartialPngConfig::new()
+        .idat_size(100)
+        .text(PngText::new("Software", "gimg"));
+    let mut encoder = PngStreamEncoder::new(Vec::new(), width, height, config).unwrap();
+    for row in rows.iter() {
+        encoder.write_row(row).unwrap();
+    }
+    let png = encoder.finish().unwrap();
+
+    let idat_lengths: Vec<usize> = chunks(&png)
+        .iter()
+        .filter(|(chunk_type, _data)| chunk_type == b"IDAT")
+        .map(|(_chunk_type, data)| data.len())
+        .collect();
+    assert!(idat_lengths.len() > 1);
+    assert!(idat_lengths.iter().all(|length| *length <= 100));
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    assert_eq!(decoded_png.pixels, rows);
+    assert_eq!(decoded_png.text, [PngText::new("Software", "gimg")]);
+}
+
+#[test]
+fn streams_16_bit_rows() {
+    let rows: Vec<Vec<Rgba16>> = (0..5u16)
+        .map(|y| {
+            (0..6u16)
+                .map(|x| Rgba16::new(x * 10_001, y * 12_345, 0x0102, u16::MAX - x))
+                .collect()
+        })
+        .collect();
+    let config = PartialPngConfig::new().bit_depth(16);
+    let mut encoder = PngStreamEncoder::new(Vec::new(), 6, 5, config).unwrap();
+    for row in rows.iter() {
+        encoder.write_row16(row).unwrap();
+    }
+    let png = encoder.finish().unwrap();
+
+    assert_eq!(decode_png16(&png).unwrap(), rows);
+}
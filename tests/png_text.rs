@@ -0,0 +1,60 @@
+use gimg::{
+    colors::Rgba,
+    png::{decode_png_with_config, encode_png, PartialPngConfig, PngDecodeConfig, PngText},
+    ErrorKind,
+};
+
+use common::{chunk, chunks, hand_built_png};
+
+mod common;
+
+#[test]
+fn text_chunks_round_trip() {
+    let entries = vec![
+        PngText::new("Title", "Café au lait"),
+        PngText::new("Comment", "a long comment ".repeat(40)).compressed(true),
+        PngText::new("Author", "山田太郎").language("ja", "著者"),
+        PngText::new("Description", "kurz")
+            .compressed(true)
+            .language("de", "Beschreibung"),
+    ];
+    let config = entries
+        .iter()
+        .cloned()
+        .fold(PartialPngConfig::new(), PartialPngConfig::text);
+    let pixels = vec![vec![Rgba::new(1, 2, 3, 255), Rgba::new(4, 5, 6, 255)]];
+    let png = encode_png(pixels, config).unwrap();
+
+    let text_chunk_types: Vec<[u8; 4]> = chunks(&png)
+        .iter()
+        .map(|(chunk_type, _data)| *chunk_type)
+        .filter(|chunk_type| chunk_type.ends_with(b"Xt"))
+        .collect();
+    assert_eq!(text_chunk_types, [*b"tEXt", *b"zTXt", *b"iTXt", *b"iTXt"]);
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    assert_eq!(decoded_png.text, entries);
+}
+
+#[test]
+fn rejects_invalid_text_chunks() {
+    let invalid_chunks = [
+        chunk(b"tEXt", b"\0no keyword"),
+        chunk(b"tEXt", b" Title\0leading space"),
+        chunk(b"tEXt", b"Title without a separator"),
+        chunk(b"zTXt", b"Title\0\0\x78\x01\xff"),
+        chunk(b"iTXt", b"Title\0\0\0en\0\0\xff\xfe"),
+    ];
+
+    for text_chunk in invalid_chunks {
+        let mut png = hand_built_png(1, 8, 2, &[], &[0, 1, 2, 3]);
+        // straight after IHDR
+        png.splice(33..33, text_chunk);
+
+        let err = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap_err();
+        assert!(
+            matches!(err.kind, ErrorKind::InvalidData | ErrorKind::UnexpectedEof),
+            "{err}"
+        );
+    }
+}
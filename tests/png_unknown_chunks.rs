@@ -0,0 +1,67 @@
+use gimg::{
+    colors::Rgba,
+    png::{
+        decode_png_with_config, encode_png, ChunkPosition, ColorType, PartialPngConfig,
+        PngDecodeConfig, UnknownChunk,
+    },
+    ErrorKind,
+};
+
+use common::{chunk, chunks, hand_built_png};
+
+mod common;
+
+#[test]
+fn unknown_chunks_are_written_back_in_place() {
+    let unknown_chunks = [
+        UnknownChunk::new(*b"prVa", vec![1], ChunkPosition::BeforePalette),
+        UnknownChunk::new(*b"prVb", vec![2, 2], ChunkPosition::BeforeImageData),
+        UnknownChunk::new(*b"prVc", vec![], ChunkPosition::AfterImageData),
+    ];
+    let config = unknown_chunks
+        .iter()
+        .cloned()
+        .fold(PartialPngConfig::new(), PartialPngConfig::unknown_chunk)
+        .color_type(ColorType::IndexedColor);
+    let pixels = vec![vec![Rgba::new(255, 0, 0, 255), Rgba::new(0, 0, 255, 255)]];
+    let png = encode_png(pixels, config).unwrap();
+
+    let chunk_types: Vec<[u8; 4]> = chunks(&png)
+        .iter()
+        .map(|(chunk_type, _data)| *chunk_type)
+        .filter(|chunk_type| matches!(chunk_type, b"PLTE" | b"IDAT" | b"prVa" | b"prVb" | b"prVc"))
+        .collect();
+    assert_eq!(
+        chunk_types,
+        [*b"prVa", *b"PLTE", *b"prVb", *b"IDAT", *b"prVc"]
+    );
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    assert_eq!(decoded_png.unknown_chunks, unknown_chunks);
+}
+
+#[test]
+fn keeps_only_safe_to_copy_chunks_and_rejects_unknown_critical_ones() {
+    let mut png = hand_built_png(1, 8, 2, &[], &[0, 1, 2, 3]);
+    // straight after IHDR
+    png.splice(
+        33..33,
+        [chunk(b"prVt", &[7]), chunk(b"prVT", &[8])].concat(),
+    );
+
+    let decoded_png = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap();
+    assert_eq!(
+        decoded_png.unknown_chunks,
+        [UnknownChunk::new(
+            *b"prVt",
+            vec![7],
+            ChunkPosition::BeforePalette
+        )]
+    );
+
+    let mut png = hand_built_png(1, 8, 2, &[], &[0, 1, 2, 3]);
+    png.splice(33..33, chunk(b"PRVt", &[9]));
+
+    let err = decode_png_with_config(&png, PngDecodeConfig::new()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Unsupported);
+}